@@ -0,0 +1,45 @@
+use ratatui::{style::Stylize, text::Line};
+use ratatui_kit::{prelude::*, ratatui};
+use std::process::Command;
+
+#[tokio::main]
+async fn main() {
+    element!(GitLog)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn GitLog(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    // 读取带颜色的 git log 输出
+    let output = hooks.use_memo(
+        || {
+            Command::new("git")
+                .args(["log", "--color=always", "--graph", "--stat", "-20"])
+                .output()
+                .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+                .unwrap_or_else(|err| format!("\x1b[31m无法运行 git：{err}\x1b[0m"))
+        },
+        (),
+    );
+
+    let scroll_view_state = hooks.use_state(ScrollViewState::default);
+    hooks.use_local_events(move |event| {
+        scroll_view_state.write().handle_event(&event);
+    });
+
+    element!(
+        Border(
+            top_title: Some(Line::from("git log (AnsiText 示例)").centered().bold()),
+            bottom_title: Some(Line::from("上下/翻页滚动，Ctrl+C 退出").centered()),
+        ){
+            ScrollView(
+                scroll_view_state: scroll_view_state.get(),
+                on_scroll: move |offset| scroll_view_state.write().set_offset(offset),
+            ){
+                AnsiText(text: output, wrap: false)
+            }
+        }
+    )
+}
@@ -0,0 +1,72 @@
+use ratatui_kit::{
+    prelude::*,
+    ratatui::{
+        layout::{Constraint, Direction},
+        style::{Style, Stylize},
+        text::Line,
+    },
+};
+
+const LANGUAGES: &[&str] = &[
+    "c",
+    "cpp",
+    "csharp",
+    "elixir",
+    "erlang",
+    "go",
+    "haskell",
+    "java",
+    "javascript",
+    "kotlin",
+    "lua",
+    "ocaml",
+    "python",
+    "ruby",
+    "rust",
+    "scala",
+    "swift",
+    "typescript",
+    "zig",
+];
+
+#[tokio::main]
+async fn main() {
+    element!(App)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn App(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let mut value = hooks.use_state(String::new);
+    // 候选项按匹配程度排序，例如输入 "sc" 时 scala 排在 javascript 和 typescript 前面
+    let autocomplete = hooks.use_autocomplete(|_query| {
+        LANGUAGES
+            .iter()
+            .map(|language| language.to_string())
+            .collect()
+    });
+
+    element!(View(flex_direction: Direction::Vertical, gap: 1) {
+        Border(
+            height: Constraint::Length(5),
+            border_style: Style::default().green(),
+            top_title: Some(Line::from("你喜欢的语言")),
+            bottom_title: Some(Line::from("↑/↓ 选择，Enter/Tab 补全，Esc 关闭，Ctrl+C 退出").centered()),
+        ) {
+            AutocompletePopup(autocomplete: Some(autocomplete)) {
+                TextArea(
+                    value: value.read().to_string(),
+                    is_focus: true,
+                    on_change: move |new_value: String| value.set(new_value),
+                    multiline: true,
+                    cursor_style: Style::default().on_green(),
+                    placeholder: Some("输入语言名称，用空格分隔...".to_string()),
+                    placeholder_style: Style::default().dim(),
+                )
+            }
+        }
+        Text(text: format!("已输入：{}", *value.read()))
+    })
+}
@@ -0,0 +1,65 @@
+use ratatui_kit::{
+    crossterm::event::{Event, KeyCode, KeyEventKind},
+    prelude::*,
+    ratatui::{
+        style::{Color, Style, Stylize},
+        text::Line,
+    },
+};
+
+#[tokio::main]
+async fn main() {
+    element!(App)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn App(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let mut points = hooks.use_state(Vec::<(f64, f64)>::new);
+    let mut radius = hooks.use_state(|| 10.0_f64);
+    hooks.use_mouse_capture();
+
+    hooks.use_events(move |event| {
+        let Event::Key(key) = event else {
+            return;
+        };
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+        match key.code {
+            KeyCode::Char('+') => radius.set((radius.get() + 2.0).min(40.0)),
+            KeyCode::Char('-') => radius.set((radius.get() - 2.0).max(2.0)),
+            KeyCode::Char('c') => points.set(Vec::new()),
+            _ => {}
+        }
+    });
+
+    // 只有点击或调整半径时图形列表才会变化，其余重新渲染都复用上次的绘制结果
+    let mut shapes = vec![
+        CanvasShape::rect(5.0, 5.0, 90.0, 90.0, Color::DarkGray),
+        CanvasShape::circle(50.0, 50.0, radius.get(), Color::Cyan),
+        CanvasShape::line(5.0, 50.0, 95.0, 50.0, Color::DarkGray),
+        CanvasShape::line(50.0, 5.0, 50.0, 95.0, Color::DarkGray),
+        CanvasShape::points(points.read().clone(), Color::Yellow),
+    ];
+    if let Some(&(x, y)) = points.read().last() {
+        shapes.push(CanvasShape::label(
+            x + 2.0,
+            y,
+            Line::from(format!("({x:.0}, {y:.0})")).yellow(),
+        ));
+    }
+
+    element!(Border(
+        border_style: Style::default().green(),
+        top_title: Some(Line::from("Canvas")),
+        bottom_title: Some(Line::from("点击添加点，+/- 调整圆的半径，c 清空").centered()),
+    ) {
+        Canvas(
+            shapes: shapes,
+            on_click: move |point| points.write().push(point),
+        )
+    })
+}
@@ -0,0 +1,78 @@
+use ratatui::{layout::Direction, style::Stylize, text::Line};
+use ratatui_kit::{
+    crossterm::event::{Event, KeyCode, KeyEventKind},
+    prelude::*,
+    ratatui,
+};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Repo {
+    full_name: String,
+    description: Option<String>,
+    stargazers_count: u64,
+}
+
+#[derive(Deserialize)]
+struct Release {
+    tag_name: String,
+}
+
+fn github(path: &str) -> FetchRequest {
+    FetchRequest::get(format!("https://api.github.com/{path}")).header("User-Agent", "ratatui-kit")
+}
+
+#[tokio::main]
+async fn main() {
+    element!(GitHub)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn GitHub(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let repo = hooks.use_fetch::<Repo>(github("repos/ratatui/ratatui"));
+
+    hooks.use_events(move |event| {
+        if let Event::Key(key) = event {
+            if key.kind == KeyEventKind::Press && key.code == KeyCode::Char('r') {
+                repo.refetch();
+            }
+        }
+    });
+
+    let summary = match (repo.data(), repo.error()) {
+        (_, Some(err)) => format!("请求失败：{err}"),
+        (Some(repo), None) => format!(
+            "{} ★{}\n{}",
+            repo.full_name,
+            repo.stargazers_count,
+            repo.description.as_deref().unwrap_or_default()
+        ),
+        (None, None) => "加载中...".to_string(),
+    };
+    let status = if repo.is_loading() {
+        "刷新中..."
+    } else {
+        ""
+    };
+
+    element!(
+        Border(
+            flex_direction: Direction::Vertical,
+            top_title: Some(Line::from("GitHub").centered().bold()),
+            bottom_title: Some(Line::from("r 重新请求，Ctrl+C 退出").centered()),
+        ){
+            Text(text: summary)
+            Text(text: status)
+            #(await fetch::<Release>(github("repos/ratatui/ratatui/releases/latest")) => |release| {
+                let text = match release {
+                    Ok(release) => format!("最新版本：{}", release.tag_name),
+                    Err(err) => format!("最新版本获取失败：{err}"),
+                };
+                element!(Text(text: text))
+            })
+        }
+    )
+}
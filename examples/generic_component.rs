@@ -0,0 +1,120 @@
+use std::fmt::Display;
+
+use ratatui::{
+    layout::{Constraint, Direction},
+    style::{Style, Stylize},
+    text::Line,
+};
+use ratatui_kit::crossterm::event::{Event, KeyCode, KeyEventKind};
+use ratatui_kit::prelude::*;
+use ratatui_kit::ratatui;
+
+#[tokio::main]
+async fn main() {
+    element!(App)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Level {
+    Debug,
+    Info,
+    Warn,
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Level::Debug => "debug",
+            Level::Info => "info",
+            Level::Warn => "warn",
+        };
+        f.write_str(text)
+    }
+}
+
+#[derive(Props)]
+struct SelectProps<T> {
+    options: Vec<T>,
+    on_select: Handler<'static, T>,
+}
+
+impl<T> Default for SelectProps<T> {
+    fn default() -> Self {
+        Self {
+            options: Vec::new(),
+            on_select: Handler::default(),
+        }
+    }
+}
+
+/// 泛型选择器：任意可显示的选项类型都能复用同一个组件。
+#[component]
+fn Select<T>(props: &mut SelectProps<T>, mut hooks: Hooks) -> impl Into<AnyElement<'static>>
+where
+    T: Display + Clone,
+{
+    let mut cursor = hooks.use_state(|| 0usize);
+    let len = props.options.len();
+    let current = props.options.get(cursor.get()).cloned();
+
+    hooks.use_events({
+        let mut on_select = props.on_select.take();
+        move |event| {
+            if let Event::Key(key) = event {
+                if key.kind != KeyEventKind::Press {
+                    return;
+                }
+                match key.code {
+                    KeyCode::Up => cursor.set(cursor.get().saturating_sub(1)),
+                    KeyCode::Down => cursor.set((cursor.get() + 1).min(len.saturating_sub(1))),
+                    KeyCode::Enter => {
+                        if let Some(value) = current.clone() {
+                            on_select(value);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    });
+
+    let rows = props
+        .options
+        .iter()
+        .enumerate()
+        .map(|(index, option)| {
+            let line = Line::from(option.to_string());
+            let line = if index == cursor.get() {
+                line.style(Style::default().reversed())
+            } else {
+                line
+            };
+            element!(View(height: Constraint::Length(1)) { $line })
+        })
+        .collect::<Vec<_>>();
+
+    element!(View(flex_direction: Direction::Vertical) {
+        #(rows)
+    })
+}
+
+#[component]
+fn App(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let mut level = hooks.use_state(|| Level::Info);
+
+    let title = Line::from(format!(
+        "当前日志级别：{}（↑/↓ 选择，Enter 确认）",
+        level.get()
+    ))
+    .centered();
+
+    element!(Border(top_title: Some(title)) {
+        Select<Level>(
+            options: vec![Level::Debug, Level::Info, Level::Warn],
+            on_select: move |value| level.set(value),
+        )
+    })
+}
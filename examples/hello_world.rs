@@ -6,6 +6,14 @@ use ratatui_kit::ratatui;
 async fn main() {
     element!(Border{
         $Line::from("Hello, World!").centered()
+        RichText(
+            text: "[b]ratatui-kit[/b] supports [green]inline[/] [i yellow]markup[/]",
+            alignment: ratatui::layout::Alignment::Center,
+        )
+        Link(
+            text: "ratatui-kit".to_string(),
+            url: "https://github.com/yexiyue/ratatui-kit".to_string(),
+        )
     })
     .fullscreen()
     .await
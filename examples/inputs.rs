@@ -0,0 +1,121 @@
+use ratatui_kit::{
+    crossterm::event::Event,
+    prelude::*,
+    ratatui::{
+        layout::{Constraint, Direction},
+        style::{Style, Stylize},
+        text::Line,
+    },
+};
+
+#[tokio::main]
+async fn main() {
+    element!(App)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn App(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let mut port = hooks.use_state(|| 8080.0);
+    let mut ratio = hooks.use_state(|| 0.5);
+    let mut password = hooks.use_state(String::new);
+    let mut date = hooks.use_state(String::new);
+    let mut phone = hooks.use_state(String::new);
+    let mut volume = hooks.use_state(|| 40.0);
+    let mut stars = hooks.use_state(|| 3usize);
+    // 日期填写完整后才能输入电话
+    let phone_disabled = date.read().len() < 10;
+    let focus = hooks.use_focus_order(&[false, false, false, false, phone_disabled, false, false]);
+
+    hooks.use_mouse_capture();
+
+    // Tab/Shift+Tab 在输入框间切换焦点，跳过禁用的输入框
+    hooks.use_events({
+        let mut focus = focus.clone();
+        move |event| {
+            if let Event::Key(key) = event {
+                focus.handle_key(&key);
+            }
+        }
+    });
+
+    let summary = format!(
+        "port={} ratio={:.2} password={} date={} phone={} volume={} stars={}",
+        port.get(),
+        ratio.get(),
+        "*".repeat(password.read().chars().count()),
+        *date.read(),
+        *phone.read(),
+        volume.get(),
+        stars.get(),
+    );
+
+    element!(Border(
+        border_style: Style::default().green(),
+        top_title: Some(Line::from("输入框")),
+        bottom_title: Some(Line::from("Tab 切换焦点，↑/↓ 增减数字，←/→ 调整滑块和评分，Ctrl+R 显示密码").centered()),
+    ) {
+        View(flex_direction: Direction::Vertical, gap: 1, height: Constraint::Length(16)) {
+            NumberInput(
+                label: "端口",
+                value: port.get(),
+                min: 1.0,
+                max: 65535.0,
+                is_focus: focus.is_focus(0),
+                on_change: move |value| port.set(value),
+            )
+            NumberInput(
+                label: "比例",
+                value: ratio.get(),
+                min: 0.0,
+                max: 1.0,
+                step: 0.05,
+                precision: 2usize,
+                is_focus: focus.is_focus(1),
+                on_change: move |value| ratio.set(value),
+            )
+            PasswordInput(
+                label: "密码",
+                value: password.read().clone(),
+                placeholder: "至少 8 个字符",
+                is_focus: focus.is_focus(2),
+                on_change: move |value| password.set(value),
+                validate: |value: String| (value.chars().count() < 8).then(|| "密码太短".to_string()),
+            )
+            MaskedInput(
+                label: "日期",
+                pattern: "####-##-##",
+                value: date.read().clone(),
+                is_focus: focus.is_focus(3),
+                on_change: move |value| date.set(value),
+                validate: |value: String| {
+                    let month = value[5..7].parse::<u32>().unwrap_or_default();
+                    (!(1..=12).contains(&month)).then(|| "月份无效".to_string())
+                },
+            )
+            MaskedInput(
+                label: "电话",
+                pattern: "(###) ###-####",
+                value: phone.read().clone(),
+                disabled: phone_disabled,
+                is_focus: focus.is_focus(4),
+                on_change: move |value| phone.set(value),
+            )
+            Slider(
+                label: "音量",
+                value: volume.get(),
+                step: 5.0,
+                is_focus: focus.is_focus(5),
+                on_change: move |value| volume.set(value),
+            )
+            Rating(
+                value: stars.get(),
+                is_focus: focus.is_focus(6),
+                on_change: move |value| stars.set(value),
+            )
+        }
+        Text(text: summary, style: Style::default().dim())
+    })
+}
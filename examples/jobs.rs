@@ -0,0 +1,61 @@
+use ratatui::{layout::Direction, style::Stylize, text::Line};
+use ratatui_kit::{
+    crossterm::event::{Event, KeyCode, KeyEventKind},
+    prelude::*,
+    ratatui,
+};
+use std::time::Duration;
+
+#[tokio::main]
+async fn main() {
+    element!(Jobs)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn Jobs(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let download = hooks.use_job("下载", |progress| async move {
+        for i in 0..=100 {
+            progress.set_progress(i as f64 / 100.0);
+            progress.set_status(format!("{i}/100 MB"));
+            tokio::time::sleep(Duration::from_millis(40)).await;
+        }
+        Ok::<_, String>(())
+    });
+    let build = hooks.use_job("构建", |progress| async move {
+        for step in ["解析", "检查", "生成"] {
+            progress.set_status(step);
+            tokio::time::sleep(Duration::from_secs(1)).await;
+        }
+        Err("链接失败")
+    });
+
+    hooks.use_events(move |event| {
+        if let Event::Key(key) = event {
+            if key.kind != KeyEventKind::Press {
+                return;
+            }
+            match key.code {
+                KeyCode::Char('d') => download.start(),
+                KeyCode::Char('b') => build.start(),
+                KeyCode::Char('c') => {
+                    download.cancel();
+                    build.cancel();
+                }
+                _ => {}
+            }
+        }
+    });
+
+    element!(
+        Border(
+            flex_direction: Direction::Vertical,
+            top_title: Some(Line::from("后台任务").centered().bold()),
+            bottom_title: Some(Line::from("d 下载，b 构建，c 取消，Ctrl+C 退出").centered()),
+        ){
+            JobsPanel
+        }
+    )
+}
@@ -0,0 +1,71 @@
+use ratatui::{
+    layout::{Constraint, Direction},
+    style::Stylize,
+    text::Line,
+};
+use ratatui_kit::{prelude::*, ratatui};
+use std::sync::LazyLock;
+
+const KEYMAP_FILE: &str = "keymap.json";
+
+static KEYMAP: LazyLock<Keymap> = LazyLock::new(|| {
+    let keymap = Keymap::new()
+        .with_action("down", "下移", &["j", "down"])
+        .with_action("up", "上移", &["k", "up"])
+        .with_action("top", "跳到开头", &["g g", "home"])
+        .with_action("bottom", "跳到末尾", &["G", "end"])
+        .with_action("editor", "打开/关闭快捷键设置", &["ctrl+e"]);
+    keymap
+        .load_file(KEYMAP_FILE)
+        .expect("Failed to load keymap");
+    keymap
+});
+
+#[tokio::main]
+async fn main() {
+    element!(App)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn App(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let mut line = hooks.use_state(|| 0usize);
+    let mut editing = hooks.use_state(|| false);
+    let pending = hooks.use_keymap(&KEYMAP, move |action| match action {
+        "editor" => editing.set(!editing.get()),
+        _ if editing.get() => {}
+        "down" => line.set((line.get() + 1).min(99)),
+        "up" => line.set(line.get().saturating_sub(1)),
+        "top" => line.set(0),
+        "bottom" => line.set(99),
+        _ => {}
+    });
+
+    let pending = KeySequence(pending);
+    let status = if pending.0.is_empty() {
+        format!("第 {} / 100 行", line.get() + 1)
+    } else {
+        format!("第 {} / 100 行  {pending}", line.get() + 1)
+    };
+
+    element!(
+        Border(
+            flex_direction: Direction::Vertical,
+            top_title: Some(Line::from("快捷键示例").centered().bold()),
+            bottom_title: Some(Line::from("Ctrl+E 设置快捷键，Ctrl+C 退出").centered()),
+        ){
+            View(height: Constraint::Length(2)) {
+                Text(text: status)
+            }
+            #(editing.get().then(|| element!(KeymapEditor(
+                keymap: Some(KEYMAP.clone()),
+                is_focus: true,
+                on_change: move |_| {
+                    let _ = KEYMAP.save_file(KEYMAP_FILE);
+                },
+            ))))
+        }
+    )
+}
@@ -0,0 +1,19 @@
+use ratatui_kit::{interpreter::ComponentRegistry, prelude::*};
+
+#[tokio::main]
+async fn main() {
+    element!(App)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn App() -> impl Into<AnyElement<'static>> {
+    let registry = ComponentRegistry::default();
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/layout.json");
+
+    element!(HotReload(path: path) {
+        move |source: String| registry.build_json_or_error(&source)
+    })
+}
@@ -0,0 +1,68 @@
+use ratatui_kit::{
+    prelude::*,
+    ratatui::{
+        layout::{Constraint, Direction},
+        style::{Style, Stylize},
+        text::Line,
+    },
+};
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+static FRAMES: AtomicUsize = AtomicUsize::new(0);
+
+// 使用 RATATUI_KIT_HUD=1 运行可在右上角查看帧率和输入延迟
+#[tokio::main]
+async fn main() {
+    element!(LogFlood)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn LogFlood(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let lines = hooks.use_state(Vec::<Line<'static>>::new);
+    let mut input = hooks.use_state(String::new);
+    let frames = FRAMES.fetch_add(1, Ordering::Relaxed) + 1;
+
+    // 每毫秒写入一批日志，这些后台更新被合并为约 10 帧每秒，输入仍然立即重绘
+    hooks.use_future(async move {
+        let mut count = 0u64;
+        loop {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            let mut lines = lines.write();
+            for _ in 0..20 {
+                count += 1;
+                lines.push(Line::from(format!("[{count:>8}] worker finished a task")));
+            }
+            let overflow = lines.len().saturating_sub(1000);
+            lines.drain(..overflow);
+        }
+    });
+
+    element!(
+        View(flex_direction: Direction::Vertical) {
+            Border(
+                height: Constraint::Fill(1),
+                top_title: Some(Line::from(format!("日志（已绘制 {frames} 帧）"))),
+            ) {
+                LogView(lines: lines.read().clone())
+            }
+            Border(
+                height: Constraint::Length(3),
+                border_style: Style::default().green(),
+                bottom_title: Some(Line::from("日志刷屏时输入依然流畅，Ctrl+C 退出").centered()),
+            ) {
+                TextArea(
+                    value: input.read().clone(),
+                    is_focus: true,
+                    on_change: move |value: String| input.set(value),
+                    placeholder: Some("在这里输入".to_string()),
+                )
+            }
+        }
+    )
+}
@@ -0,0 +1,70 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+
+use ratatui::{style::Stylize, text::Line};
+use ratatui_kit::{
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    prelude::*,
+    ratatui,
+};
+
+// 不使用 render_loop，由自己的循环以固定帧率推进“游戏世界”并绘制界面
+fn main() -> std::io::Result<()> {
+    let ticks = Arc::new(AtomicU64::new(0));
+    let mut root = element!(Hud(ticks: ticks.clone()));
+    let mut app = App::new(&mut root, Terminal::new(CrossTerminal::new()?)?)?;
+    app.render_once()?;
+
+    while !app.should_exit() {
+        if event::poll(Duration::from_millis(16))? {
+            app.handle_event(event::read()?);
+        }
+        ticks.fetch_add(1, Ordering::Relaxed);
+        app.poll_until_idle();
+        app.render_once()?;
+    }
+    Ok(())
+}
+
+#[derive(Default, Props)]
+struct HudProps {
+    ticks: Arc<AtomicU64>,
+}
+
+#[component]
+fn Hud(props: &HudProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let mut presses = hooks.use_state(|| 0u32);
+    let mut should_exit = hooks.use_state(|| false);
+    let mut system = hooks.use_context_mut::<SystemContext>();
+    if should_exit.get() {
+        system.exit();
+    }
+
+    hooks.use_events(move |event| {
+        if let Event::Key(key) = event {
+            if key.kind != KeyEventKind::Press {
+                return;
+            }
+            match key.code {
+                KeyCode::Char(' ') => presses += 1,
+                KeyCode::Char('q') => should_exit.set(true),
+                _ => {}
+            }
+        }
+    });
+
+    let ticks = props.ticks.load(Ordering::Relaxed);
+    element!(
+        Border(
+            top_title: Some(Line::from("自定义事件循环").centered().bold()),
+            bottom_title: Some(Line::from("Space 计数，q 退出").centered()),
+        ){
+            Text(text: format!("帧数：{ticks}\n按键：{}", presses.get()))
+        }
+    )
+}
@@ -21,19 +21,18 @@ async fn main() {
 fn JsonEditor(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
     let mut json_text = hooks.use_state(|| String::from("{\n  \"key\": \"value\"\n}"));
     let mut open = hooks.use_state(|| false);
-    let mut formatted = hooks.use_state(String::new);
+    let mut parsed = hooks.use_state(|| serde_json::Value::Null);
     let mut error = hooks.use_state(String::new);
 
     // 实时解析 JSON
     hooks.use_effect(
         move || match serde_json::from_str::<serde_json::Value>(&json_text.read()) {
             Ok(val) => {
-                let pretty = serde_json::to_string_pretty(&val).unwrap_or_default();
-                formatted.set(pretty);
+                parsed.set(val);
                 error.set(String::new());
             }
             Err(e) => {
-                formatted.set(String::new());
+                parsed.set(serde_json::Value::Null);
                 error.set(e.to_string());
             }
         },
@@ -65,10 +64,12 @@ fn JsonEditor(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
         Line::styled("JSON 错误：", Style::default().red())
     };
 
-    let modal_content = if error.read().is_empty() {
-        Paragraph::new(formatted.read().clone())
+    // 格式正确时用 JsonView 浏览结构，y/Y 复制值/路径
+    let modal_content: AnyElement<'static> = if error.read().is_empty() {
+        element!(JsonView(value: parsed.read().clone(), is_focus: open.get())).into()
     } else {
-        Paragraph::new(error.read().clone())
+        let paragraph = Paragraph::new(error.read().clone());
+        element!(View { $paragraph }).into()
     };
 
     element!(
@@ -81,7 +82,7 @@ fn JsonEditor(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
                 ){
                     TextArea(
                         value: json_text.read().to_string(),
-                        is_focus: true,
+                        is_focus: !open.get(),
                         on_change: move |new_value: String| {
                             json_text.set(new_value);
                         },
@@ -99,7 +100,7 @@ fn JsonEditor(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
                 open:open.get(),
                 width:ratatui::layout::Constraint::Percentage(60),
                 height:ratatui::layout::Constraint::Percentage(60),
-                style:Style::default().dim(),
+                backdrop:Backdrop::Dim(Style::default().dim()),
             ){
                 Border(
                     top_title:Some(Line::from("格式化/校验结果").centered().yellow()),
@@ -109,7 +110,7 @@ fn JsonEditor(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
                         $modal_title
                     }
                     View{
-                        $modal_content
+                        #(modal_content)
                     }
                 }
             }
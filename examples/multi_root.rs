@@ -0,0 +1,47 @@
+use std::time::Duration;
+
+use ratatui::{
+    layout::Rect,
+    style::{Style, Stylize},
+    text::Line,
+};
+use ratatui_kit::prelude::*;
+use ratatui_kit::ratatui;
+
+#[tokio::main]
+async fn main() {
+    // 主界面和状态栏是两棵独立的元素树，状态栏覆盖在终端最底部一行
+    Roots::new()
+        .mount(element!(MainView))
+        .mount_in(element!(StatusBar), |area| Rect {
+            y: area.bottom().saturating_sub(1),
+            height: 1,
+            ..area
+        })
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn MainView() -> impl Into<AnyElement<'static>> {
+    element!(Border(top_title: Some(Line::from("主界面").centered())) {
+        $Line::from("状态栏由另一棵元素树渲染，Ctrl+C 退出").centered()
+    })
+}
+
+#[component]
+fn StatusBar(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let mut seconds = hooks.use_state(|| 0u64);
+
+    hooks.use_future(async move {
+        loop {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            seconds += 1;
+        }
+    });
+
+    element!(View {
+        $Line::from(format!(" 已运行 {} 秒", seconds.get())).style(Style::default().on_blue())
+    })
+}
@@ -0,0 +1,64 @@
+use std::{ops::Range, time::Duration};
+
+use ratatui::{layout::Direction, style::Stylize, text::Line};
+use ratatui_kit::{prelude::*, ratatui};
+
+// 模拟数据库中的 10000 行记录
+const TOTAL: usize = 10_000;
+
+async fn query(range: Range<usize>) -> Result<Vec<String>, String> {
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    Ok(range
+        .filter(|i| *i < TOTAL)
+        .map(|i| format!("#{i:05} 用户 {}", i * 7 % 997))
+        .collect())
+}
+
+#[tokio::main]
+async fn main() {
+    element!(Users)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn Users(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let users = hooks.use_paginated_source(
+        query,
+        PaginationOptions {
+            page_size: 100,
+            ..Default::default()
+        },
+    );
+    let mut selected = hooks.use_state(|| None::<usize>);
+
+    let status = match (users.is_loading(), users.is_complete()) {
+        (true, _) => "加载中...".to_string(),
+        (false, true) => format!("共 {} 行", users.len()),
+        (false, false) => format!("已加载 {}+ 行", users.len()),
+    };
+    let title = match selected.get() {
+        Some(index) => format!("分页数据 · {status} · 选中 #{index}"),
+        None => format!("分页数据 · {status}"),
+    };
+
+    element!(
+        Border(
+            flex_direction: Direction::Vertical,
+            top_title: Some(Line::from(title).centered().bold()),
+            bottom_title: Some(Line::from("↑/↓ 移动，PageUp/PageDown 翻页，Enter 选择，Ctrl+C 退出").centered()),
+        ){
+            VirtualList(
+                len: users.len(),
+                render_item: {
+                    let users = users.clone();
+                    move |index| users.get(index).map(Line::from)
+                },
+                on_viewport_change: move |range| users.set_viewport(range),
+                on_select: move |index| selected.set(Some(index)),
+                is_focus: true,
+            )
+        }
+    )
+}
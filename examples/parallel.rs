@@ -0,0 +1,87 @@
+use ratatui::{
+    layout::{Constraint, Direction},
+    style::{Style, Stylize},
+    text::Line,
+};
+use ratatui_kit::{prelude::*, ratatui};
+use std::time::{Duration, Instant};
+
+#[tokio::main]
+async fn main() {
+    element!(Dashboard)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn Dashboard(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let mut tick = hooks.use_state(|| 0u64);
+
+    hooks.use_future(async move {
+        loop {
+            tokio::time::sleep(Duration::from_millis(500)).await;
+            tick += 1;
+        }
+    });
+
+    // 四列各自在更新时做一次耗时计算，由 Parallel 分派到线程池
+    element!(
+        Border(
+            border_style: Style::default().green(),
+            bottom_title: Some(Line::from("每列在线程池中并行更新，Ctrl+C 退出").centered()),
+        ){
+            Parallel(flex_direction: Direction::Horizontal, gap: 1) {
+                #((1..=4u64).map(|column| element!(Primes(
+                    key: column,
+                    limit: 2_000_000 + column * 500_000 + tick.get() % 7,
+                ))))
+            }
+        }
+    )
+}
+
+#[derive(Default, Props)]
+struct PrimesProps {
+    limit: u64,
+}
+
+#[component]
+fn Primes(props: &PrimesProps) -> impl Into<AnyElement<'static>> {
+    let started = Instant::now();
+    let count = count_primes(props.limit);
+    let elapsed = started.elapsed();
+
+    element!(
+        Border(
+            width: Constraint::Fill(1),
+            top_title: Some(Line::from(format!("≤ {}", props.limit)).centered()),
+        ){
+            Text(text: format!(
+                "{count} 个质数\n耗时 {:.1} ms\n线程 {:?}",
+                elapsed.as_secs_f64() * 1000.0,
+                std::thread::current().id(),
+            ))
+        }
+    )
+}
+
+// 埃拉托斯特尼筛法
+fn count_primes(limit: u64) -> usize {
+    let limit = limit as usize;
+    let mut sieve = vec![true; limit + 1];
+    sieve[0] = false;
+    if limit >= 1 {
+        sieve[1] = false;
+    }
+    let mut i = 2;
+    while i * i <= limit {
+        if sieve[i] {
+            for j in (i * i..=limit).step_by(i) {
+                sieve[j] = false;
+            }
+        }
+        i += 1;
+    }
+    sieve.iter().filter(|&&prime| prime).count()
+}
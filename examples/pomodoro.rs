@@ -0,0 +1,78 @@
+use std::time::Duration;
+
+use ratatui_kit::{
+    crossterm::event::{Event, KeyCode, KeyEventKind},
+    prelude::*,
+    ratatui::{
+        layout::{Constraint, Direction},
+        style::{Style, Stylize},
+        text::Line,
+    },
+};
+
+#[tokio::main]
+async fn main() {
+    element!(App)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn App(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let mut rounds = hooks.use_state(|| 0u32);
+    let mut on_break = hooks.use_state(|| false);
+    // 专注计时器由外部控制：每轮结束后重新开始
+    let focus_timer = hooks.use_timer(Duration::from_millis(250));
+    let total = hooks.use_timer(Duration::from_millis(100));
+
+    hooks.use_events({
+        let focus_timer = focus_timer.clone();
+        let total = total.clone();
+        move |event| {
+            let Event::Key(key) = event else {
+                return;
+            };
+            if key.kind == KeyEventKind::Press && key.code == KeyCode::Char('s') {
+                focus_timer.toggle();
+                total.toggle();
+            }
+        }
+    });
+
+    let duration = if on_break.get() {
+        Duration::from_secs(5)
+    } else {
+        Duration::from_secs(25)
+    };
+    let label = if on_break.get() { "休息" } else { "专注" };
+
+    element!(Border(
+        border_style: Style::default().green(),
+        top_title: Some(Line::from("番茄钟（演示用缩短为 25 秒 / 5 秒）")),
+        bottom_title: Some(Line::from("s 开始/暂停，Ctrl+C 退出").centered()),
+    ) {
+        View(flex_direction: Direction::Vertical, gap: 1) {
+            View(height: Constraint::Length(1)) {
+                Countdown(
+                    duration: duration,
+                    timer: Some(focus_timer.clone()),
+                    label: label,
+                    on_finish: move |_| {
+                        if !on_break.get() {
+                            rounds += 1;
+                        }
+                        on_break.set(!on_break.get());
+                        focus_timer.restart();
+                    },
+                )
+            }
+            View(height: Constraint::Length(1)) {
+                Stopwatch(timer: Some(total), label: "总计")
+            }
+            View(height: Constraint::Length(1)) {
+                Text(text: format!("已完成 {} 轮", rounds.get()), style: Style::default().dim())
+            }
+        }
+    })
+}
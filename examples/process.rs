@@ -0,0 +1,66 @@
+use ratatui::{
+    layout::Direction,
+    style::{Style, Stylize},
+    text::Line,
+};
+use ratatui_kit::{
+    crossterm::event::{Event, KeyCode, KeyEventKind},
+    prelude::*,
+    ratatui,
+};
+
+#[tokio::main]
+async fn main() {
+    element!(Build)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn Build(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let process = hooks.use_command(
+        ProcessCommand::new("cargo")
+            .args(["build", "--examples", "--color=always"])
+            .env("CARGO_TERM_PROGRESS_WHEN", "never"),
+    );
+
+    hooks.use_events({
+        let process = process.clone();
+        move |event| {
+            if let Event::Key(key) = event {
+                if key.kind != KeyEventKind::Press {
+                    return;
+                }
+                match key.code {
+                    KeyCode::Char('k') => process.kill(),
+                    KeyCode::Char('r') => process.restart(),
+                    _ => {}
+                }
+            }
+        }
+    });
+
+    let (status, style) = match process.status() {
+        ProcessStatus::Running => ("构建中...".to_string(), Style::default().yellow()),
+        ProcessStatus::Exited(Some(0)) => ("构建成功".to_string(), Style::default().green()),
+        ProcessStatus::Exited(code) => (format!("构建失败：{code:?}"), Style::default().red()),
+        ProcessStatus::Killed => ("已结束".to_string(), Style::default().dim()),
+        ProcessStatus::Failed(err) => (format!("启动失败：{err}"), Style::default().red()),
+    };
+    let lines = process
+        .lines()
+        .iter()
+        .map(ProcessLine::to_line)
+        .collect::<Vec<_>>();
+
+    element!(
+        Border(
+            flex_direction: Direction::Vertical,
+            top_title: Some(Line::styled(format!("cargo build · {status}"), style).centered()),
+            bottom_title: Some(Line::from("↑/↓ 滚动，k 结束，r 重新构建，Ctrl+C 退出").centered()),
+        ){
+            LogView(lines: lines, is_focus: true)
+        }
+    )
+}
@@ -0,0 +1,80 @@
+use ratatui::{
+    layout::{Constraint, Direction},
+    style::{Style, Stylize},
+    text::Line,
+};
+use ratatui_kit::crossterm::event::{Event, KeyCode, KeyEventKind};
+use ratatui_kit::prelude::*;
+use ratatui_kit::ratatui;
+
+#[tokio::main]
+async fn main() {
+    element!(App)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[derive(Default, Props)]
+struct EachProps<'a> {
+    items: Vec<String>,
+    selected: usize,
+    // 渲染函数由调用方提供，参数为 (序号, 条目, 是否选中)
+    children: RenderProp<'a, (usize, String, bool)>,
+}
+
+/// 无头列表组件：只负责选中逻辑和布局，条目如何渲染交给调用方。
+#[component]
+fn Each<'a>(props: &mut EachProps<'a>) -> impl Into<AnyElement<'a>> {
+    let rows = props
+        .items
+        .iter()
+        .enumerate()
+        .filter_map(|(index, item)| {
+            props
+                .children
+                .render((index, item.clone(), index == props.selected))
+        })
+        .map(|row| element!(View(height: Constraint::Length(1)) { #(row) }))
+        .collect::<Vec<_>>();
+
+    element!(View(flex_direction: Direction::Vertical) {
+        #(rows)
+    })
+}
+
+#[component]
+fn App(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let mut selected = hooks.use_state(|| 0usize);
+    let items = vec!["苹果", "香蕉", "橘子"]
+        .into_iter()
+        .map(String::from)
+        .collect::<Vec<_>>();
+    let len = items.len();
+
+    hooks.use_events(move |event| {
+        if let Event::Key(key) = event {
+            if key.kind == KeyEventKind::Press {
+                match key.code {
+                    KeyCode::Up => selected.set(selected.get().saturating_sub(1)),
+                    KeyCode::Down => selected.set((selected.get() + 1).min(len - 1)),
+                    _ => {}
+                }
+            }
+        }
+    });
+
+    element!(Border(top_title: Some(Line::from("↑/↓ 选择，Ctrl+C 退出").centered())) {
+        Each(items: items, selected: selected.get()) {
+            |(index, item, is_selected)| {
+                let line = Line::from(format!("{}. {item}", index + 1));
+                let line = if is_selected {
+                    line.style(Style::default().reversed())
+                } else {
+                    line
+                };
+                element!(View { $line })
+            }
+        }
+    })
+}
@@ -0,0 +1,85 @@
+use ratatui_kit::{
+    crossterm::event::{Event, KeyCode, KeyEventKind},
+    prelude::*,
+    ratatui::{
+        TerminalOptions, Viewport,
+        style::{Style, Stylize},
+        text::Line,
+    },
+};
+
+#[tokio::main]
+async fn main() {
+    element!(Repl)
+        .render_loop(TerminalOptions {
+            viewport: Viewport::Inline(3),
+        })
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn Repl(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let history = hooks.use_history();
+    let viewer_open = history.is_viewer_open();
+
+    hooks.use_events({
+        let history = history.clone();
+        move |event| {
+            let Event::Key(key) = event else {
+                return;
+            };
+            if key.kind != KeyEventKind::Press || history.is_viewer_open() {
+                return;
+            }
+            match key.code {
+                // F3 重新输出最近两条记录，F2 打开回看视图
+                KeyCode::F(3) => history.replay(2),
+                KeyCode::F(2) => history.open_viewer(),
+                _ => {}
+            }
+        }
+    });
+
+    let on_submit = {
+        let history = history.clone();
+        move |input: String| {
+            for line in input.lines() {
+                history.push(Line::from(format!("> {line}")).cyan());
+            }
+            history.push(Line::from(format!("{} 个字符", input.chars().count())));
+        }
+    };
+
+    element!(History(history: Some(history.clone()), height: 3u16) {
+        Border(
+            border_style: Style::default().green(),
+            bottom_title: Some(Line::from("Enter 提交，行尾 \\ 续行，Ctrl+R 搜索历史，F2 回看，F3 重放").centered()),
+        ) {
+            PromptInput(
+                is_focus: !viewer_open,
+                history_file: Some(std::env::temp_dir().join("ratatui_kit_repl_history")),
+                on_submit: on_submit,
+                on_complete: |before_cursor: String| complete(&before_cursor),
+            )
+        }
+    })
+}
+
+const KEYWORDS: &[&str] = &["help", "history", "hello", "exit", "echo"];
+
+// 按前缀补全光标前的词
+fn complete(before_cursor: &str) -> Vec<String> {
+    let word = before_cursor
+        .rsplit(char::is_whitespace)
+        .next()
+        .unwrap_or_default();
+    if word.is_empty() {
+        return Vec::new();
+    }
+    KEYWORDS
+        .iter()
+        .filter(|keyword| keyword.starts_with(word))
+        .map(|keyword| keyword.to_string())
+        .collect()
+}
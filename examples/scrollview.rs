@@ -2,7 +2,7 @@ use ratatui::{
     style::{Style, Stylize},
     text::Line,
 };
-use ratatui_kit::ratatui::{self, layout::Constraint};
+use ratatui_kit::ratatui;
 use ratatui_kit::{prelude::*, ratatui::layout::Direction};
 use std::fs;
 
@@ -16,6 +16,10 @@ async fn main() {
 
 #[component]
 fn MarkdownReader(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    // README.md 变化后重新读取
+    let mut version = hooks.use_state(|| 0);
+    hooks.use_watch_file("README.md", move |_| version += 1);
+
     // 读取 README.md 内容
     let lines = hooks.use_memo(
         || {
@@ -23,10 +27,12 @@ fn MarkdownReader(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
                 .unwrap_or_else(|_| "无法读取 README.md".to_string());
             content.lines().map(|l| l.to_string()).collect::<Vec<_>>()
         },
-        (),
+        version.get(),
     );
 
     let scroll_view_state = hooks.use_state(ScrollViewState::default);
+    // 开启鼠标捕获，支持拖拽滚动条
+    hooks.use_mouse_capture();
     hooks.use_local_events(move |event| {
         scroll_view_state.write().handle_event(&event);
     });
@@ -47,15 +53,10 @@ fn MarkdownReader(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
         })
         .collect();
 
-    // 渲染每一行为 AnyElement
+    // 渲染每一行为 AnyElement，过长的行自动换行
     let rendered_elements: Vec<AnyElement> = rendered
         .into_iter()
-        .map(|line| {
-            element!(View(height:Constraint::Length(1)){
-                $line
-            })
-            .into_any()
-        })
+        .map(|line| element!(Text(text: line)).into_any())
         .collect();
 
     element!(
@@ -71,6 +72,7 @@ fn MarkdownReader(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
                 ScrollView(
                     flex_direction:Direction::Vertical,
                     scroll_view_state: scroll_view_state.get(),
+                    on_scroll: move |offset| scroll_view_state.write().set_offset(offset),
                 ){
                     #(rendered_elements)
                 }
@@ -0,0 +1,53 @@
+use ratatui::{
+    layout::{Constraint, Direction},
+    style::Stylize,
+    text::Line,
+};
+use ratatui_kit::{prelude::*, ratatui};
+use serde_json::json;
+use std::sync::LazyLock;
+
+// 可以通过 DEMO_THEME=light 等环境变量覆盖配置
+static CONFIG: LazyLock<Config> = LazyLock::new(|| {
+    Config::new(json!({
+        "theme": "dark",
+        "editor": {
+            "tab_size": 4,
+            "line_numbers": true,
+            "font": "Fira Code",
+        },
+        "auto_save": false,
+    }))
+    .with_file("settings.json")
+    .with_env_prefix("DEMO")
+    .load()
+    .expect("Failed to load settings")
+});
+
+#[tokio::main]
+async fn main() {
+    element!(App)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn App(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let config = hooks.use_config(&CONFIG);
+    let theme = config.get::<String>("theme").unwrap_or_default();
+    let tab_size = config.get::<u16>("editor.tab_size").unwrap_or(4);
+
+    element!(
+        Border(
+            flex_direction: Direction::Vertical,
+            top_title: Some(Line::from("设置 (保存到 settings.json)").centered().bold()),
+            bottom_title: Some(Line::from("Ctrl+C 退出").centered()),
+        ){
+            View(height: Constraint::Length(2)) {
+                Text(text: format!("当前主题：{theme}，缩进：{tab_size}"))
+            }
+            Settings(config: Some(CONFIG.clone()), is_focus: true)
+        }
+    )
+}
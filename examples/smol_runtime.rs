@@ -0,0 +1,44 @@
+use ratatui::{
+    layout::Direction,
+    style::{Style, Stylize},
+    text::Line,
+};
+use ratatui_kit::{prelude::*, ratatui};
+use std::time::Duration;
+
+// 在 smol 中运行，use_command 的子进程 I/O 由内置的后台 tokio 运行时驱动
+fn main() {
+    smol::block_on(element!(Uptime).fullscreen()).expect("Failed to run the application");
+}
+
+#[component]
+fn Uptime(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let mut seconds = hooks.use_state(|| 0u64);
+    let process = hooks.use_command(ProcessCommand::shell(
+        "for i in 1 2 3 4 5; do echo \"line $i\"; sleep 1; done",
+    ));
+
+    hooks.use_future(async move {
+        loop {
+            smol::Timer::after(Duration::from_secs(1)).await;
+            seconds += 1;
+        }
+    });
+
+    let lines = process
+        .lines()
+        .iter()
+        .map(ProcessLine::to_line)
+        .collect::<Vec<_>>();
+
+    element!(
+        Border(
+            flex_direction: Direction::Vertical,
+            border_style: Style::default().green(),
+            top_title: Some(Line::from(format!("smol · 已运行 {seconds} 秒 · {:?}", process.status())).centered()),
+            bottom_title: Some(Line::from("Ctrl+C 退出").centered()),
+        ){
+            LogView(lines: lines, is_focus: true)
+        }
+    )
+}
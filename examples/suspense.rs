@@ -0,0 +1,27 @@
+use std::time::Duration;
+
+use ratatui::{text::Line, widgets::Paragraph};
+use ratatui_kit::prelude::*;
+use ratatui_kit::ratatui;
+
+#[tokio::main]
+async fn main() {
+    element!(Border(top_title: Some(Line::from("Cargo.toml").centered())) {
+        #(await async {
+            // 模拟较慢的读取
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            tokio::fs::read_to_string("Cargo.toml").await
+        } => |result| {
+            let text = match result {
+                Ok(text) => text.clone(),
+                Err(err) => format!("读取失败：{err}"),
+            };
+            element!(View {
+                $Paragraph::new(text)
+            })
+        })
+    })
+    .fullscreen()
+    .await
+    .expect("Failed to run the application");
+}
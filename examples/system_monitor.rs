@@ -0,0 +1,40 @@
+use ratatui::{
+    layout::{Constraint, Direction},
+    style::{Style, Stylize},
+    text::Line,
+};
+use ratatui_kit::{prelude::*, ratatui};
+
+#[tokio::main]
+async fn main() {
+    element!(SystemMonitor)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn SystemMonitor() -> impl Into<AnyElement<'static>> {
+    element!(
+        Border(
+            flex_direction: Direction::Vertical,
+            top_title: Some(Line::from("系统监控").centered().bold()),
+            bottom_title: Some(Line::from("Ctrl+C 退出").centered()),
+        ){
+            View(height: Constraint::Length(1)) {
+                CpuMeter(gauge_style: Style::default().cyan())
+            }
+            View(height: Constraint::Length(1)) {
+                MemMeter(gauge_style: Style::default().green())
+            }
+            Text(text: "接收")
+            View(height: Constraint::Length(4)) {
+                NetSparkline(direction: NetDirection::Received, style: Style::default().yellow())
+            }
+            Text(text: "发送")
+            View(height: Constraint::Length(4)) {
+                NetSparkline(direction: NetDirection::Transmitted, style: Style::default().magenta())
+            }
+        }
+    )
+}
@@ -0,0 +1,27 @@
+use ratatui::{style::Stylize, text::Line};
+use ratatui_kit::{prelude::*, ratatui};
+
+#[tokio::main]
+async fn main() {
+    element!(Shell)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn Shell(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let shell = hooks.use_memo(
+        || std::env::var("SHELL").unwrap_or_else(|_| "sh".to_string()),
+        (),
+    );
+
+    element!(
+        Border(
+            top_title: Some(Line::from(format!(" {shell} ")).centered().bold()),
+            bottom_title: Some(Line::from("Ctrl+C 退出").centered()),
+        ){
+            TerminalPane(command: shell, is_focus: true)
+        }
+    )
+}
@@ -1,11 +1,12 @@
 use ratatui_kit::{
-    crossterm::event::{Event, KeyCode, KeyEventKind},
+    crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers},
     prelude::*,
     ratatui::{
         TerminalOptions, Viewport,
         layout::Constraint,
         style::{Style, Stylize},
         text::Line,
+        widgets::Paragraph,
     },
 };
 
@@ -13,7 +14,7 @@ use ratatui_kit::{
 async fn main() {
     element!(MyTextInput)
         .render_loop(TerminalOptions {
-            viewport: Viewport::Inline(4),
+            viewport: Viewport::Inline(3),
         })
         .await
         .expect("Failed to run the application");
@@ -27,11 +28,25 @@ fn MyTextInput(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
 
     let mut system_ctx = hooks.use_context_mut::<SystemContext>();
     let insert_before = hooks.use_insert_before();
+    let control = hooks.use_terminal_control();
+
+    // 输入时使用闪烁的竖线光标，退出时标题和光标自动恢复
+    hooks.use_effect(
+        || {
+            control.set_title("ratatui-kit textarea");
+            control.set_cursor_shape(CursorShape::Bar, true);
+        },
+        (),
+    );
 
     if should_exit.get() {
         system_ctx.exit();
     }
 
+    // 视口高度随输入行数增长，加上上下边框两行
+    let height = value.read().split('\n').count() as u16 + 2;
+    hooks.use_inline_height(height);
+
     hooks.use_events(move |event| {
         if let Event::Key(key_event) = event {
             if key_event.kind == KeyEventKind::Press {
@@ -39,13 +54,27 @@ fn MyTextInput(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
                     KeyCode::Esc => {
                         should_exit.set(true);
                     }
-                    KeyCode::Enter => {
+                    KeyCode::Char('d') if key_event.modifiers == KeyModifiers::CONTROL => {
                         if !value.read().is_empty() {
+                            let lines = value.read().split('\n').count() as u16;
+                            let message = Paragraph::new(value.read().clone());
+                            // 已提交的消息通过组件管线渲染，带边框插入到输入框上方
                             insert_before
-                                .render_before(Line::from(format!("message: {value}")), 1)
+                                .insert_element_with_height(
+                                    element!(Border(
+                                        border_style: Style::default().dim(),
+                                        top_title: Some(Line::from("message")),
+                                    ) {
+                                        $message
+                                    }),
+                                    lines + 2,
+                                )
                                 .finish();
 
                             value.set(String::new());
+                        } else {
+                            // 空内容无法提交，响铃提示
+                            control.bell();
                         }
                     }
                     _ => {}
@@ -55,10 +84,10 @@ fn MyTextInput(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
     });
 
     element!(Border(
-        height: Constraint::Length(4),
+        height: Constraint::Length(height),
         style: Style::default().green(),
         bottom_title: Line::styled(
-            "Press 'Enter' to submit, 'Esc' to exit",
+            "Press 'Ctrl+D' to submit, 'Esc' to exit",
             Style::default().yellow(),
         ).centered(),
     ) {
@@ -68,7 +97,7 @@ fn MyTextInput(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
             on_change: move |new_value: String| {
                 value.set(new_value);
             },
-            multiline: false,
+            multiline: true,
             cursor_style: Style::default().on_green(),
             placeholder: Some("Type something...".to_string()),
             placeholder_style: Style::default().green(),
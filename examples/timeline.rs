@@ -0,0 +1,93 @@
+use std::time::Instant;
+
+use ratatui_kit::{
+    prelude::*,
+    ratatui::{
+        layout::{Constraint, Direction},
+        style::{Style, Stylize},
+        text::Line,
+    },
+};
+
+// 流水线各阶段的名称和耗时（秒）
+const STAGES: &[(&str, f64)] = &[
+    ("checkout", 1.5),
+    ("deps", 6.0),
+    ("build", 12.0),
+    ("unit tests", 8.0),
+    ("lint", 3.0),
+    ("package", 4.0),
+    ("deploy", 5.0),
+];
+
+#[tokio::main]
+async fn main() {
+    element!(App)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn App(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let started = hooks.use_state(Instant::now);
+    let mut elapsed = hooks.use_state(|| 0.0);
+    let mut selected = hooks.use_state(|| None::<usize>);
+    hooks.use_mouse_capture();
+
+    hooks.use_future(async move {
+        loop {
+            tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+            elapsed.set(started.get().elapsed().as_secs_f64());
+        }
+    });
+
+    // lint 与 unit tests 并行，其余阶段依次执行
+    let now = elapsed.get();
+    let mut items = Vec::new();
+    let mut start = 0.0;
+    for &(name, duration) in STAGES {
+        let begin = if name == "lint" { start - 8.0 } else { start };
+        if begin > now {
+            break;
+        }
+        let end = begin + duration;
+        let item = TimelineItem::new(name, begin, (end <= now).then_some(end));
+        items.push(if end <= now {
+            item.style(Style::default().green())
+        } else {
+            item.style(Style::default().yellow())
+        });
+        if name != "lint" {
+            start = end;
+        }
+    }
+
+    let status = match selected.get().and_then(|i| items.get(i)) {
+        Some(item) => format!(
+            "{}: {} → {}",
+            item.label,
+            format_duration(item.start),
+            item.end.map_or("running".to_string(), format_duration)
+        ),
+        None => format!("elapsed {}", format_duration(now)),
+    };
+
+    element!(View(flex_direction: Direction::Vertical) {
+        Border(
+            border_style: Style::default().green(),
+            top_title: Some(Line::from("CI pipeline")),
+            bottom_title: Some(Line::from("↑/↓ 选择，Enter 查看，←/→ 平移，+/- 缩放，0 自动适配").centered()),
+        ) {
+            Timeline(
+                items: items,
+                now: Some(now),
+                is_focus: true,
+                on_select: move |index| selected.set(Some(index)),
+            )
+        }
+        View(height: Constraint::Length(1)) {
+            Text(text: status, style: Style::default().dim())
+        }
+    })
+}
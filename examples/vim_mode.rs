@@ -0,0 +1,176 @@
+use ratatui::{
+    layout::{Constraint, Direction},
+    style::{Style, Stylize},
+    text::{Line, Span},
+};
+use ratatui_kit::{
+    crossterm::event::{Event, KeyCode, KeyEventKind},
+    prelude::*,
+    ratatui,
+};
+use std::sync::LazyLock;
+
+static KEYMAP: LazyLock<Keymap> = LazyLock::new(|| {
+    Keymap::new()
+        .with_action("left", "左移", &["h", "left"])
+        .in_modes(&[InputMode::Normal, InputMode::Visual])
+        .with_action("right", "右移", &["l", "right"])
+        .in_modes(&[InputMode::Normal, InputMode::Visual])
+        .with_action("start", "行首", &["0"])
+        .in_modes(&[InputMode::Normal, InputMode::Visual])
+        .with_action("end", "行尾", &["$"])
+        .in_modes(&[InputMode::Normal, InputMode::Visual])
+        .with_action("delete_char", "删除字符", &["x"])
+        .in_modes(&[InputMode::Normal])
+        .with_action("delete_line", "删除整行", &["d d"])
+        .in_modes(&[InputMode::Normal])
+        .with_action("delete_selection", "删除选区", &["d"])
+        .in_modes(&[InputMode::Visual])
+});
+
+#[tokio::main]
+async fn main() {
+    element!(ModeProvider { CommandLine { Editor } })
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn Editor(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let mode = hooks.use_mode();
+    let mut text = hooks.use_state(|| "hello modal world".chars().collect::<Vec<_>>());
+    let mut cursor = hooks.use_state(|| 0usize);
+    let mut anchor = hooks.use_state(|| None::<usize>);
+
+    // 进入可视模式时记录选区起点
+    match (mode.get(), anchor.get()) {
+        (InputMode::Visual, None) => anchor.set(Some(cursor.get())),
+        (InputMode::Normal | InputMode::Insert, Some(_)) => anchor.set(None),
+        _ => {}
+    }
+    let selection = anchor.get().map(|anchor| {
+        let (start, end) = (anchor.min(cursor.get()), anchor.max(cursor.get()));
+        start..(end + 1).min(text.read().len())
+    });
+
+    let pending = hooks.use_keymap(&KEYMAP, {
+        let mode = mode.clone();
+        let selection = selection.clone();
+        move |action| {
+            let len = text.read().len();
+            match action {
+                "left" => cursor.set(cursor.get().saturating_sub(1)),
+                "right" => cursor.set((cursor.get() + 1).min(len.saturating_sub(1))),
+                "start" => cursor.set(0),
+                "end" => cursor.set(len.saturating_sub(1)),
+                "delete_char" if cursor.get() < len => {
+                    text.write().remove(cursor.get());
+                }
+                "delete_line" => {
+                    text.set(Vec::new());
+                    cursor.set(0);
+                }
+                "delete_selection" => {
+                    if let Some(selection) = selection.clone() {
+                        cursor.set(selection.start);
+                        text.write().drain(selection);
+                    }
+                    mode.set(InputMode::Normal);
+                }
+                _ => {}
+            }
+        }
+    });
+
+    let commands = hooks.use_commands();
+    hooks.use_ex_command(
+        Command::new("write", "写入文件")
+            .alias("w")
+            .usage("[path]")
+            .complete_with(complete_path),
+        {
+            let commands = commands.clone();
+            move |args| {
+                let path = args.get(0).unwrap_or("vim_mode.txt");
+                let content = text.read().iter().collect::<String>();
+                std::fs::write(path, content).map_err(|err| err.to_string())?;
+                commands.echo(format!("已写入 {path}"));
+                Ok(())
+            }
+        },
+    );
+    hooks.use_ex_command(
+        Command::new("edit", "打开文件")
+            .alias("e")
+            .usage("<path>")
+            .complete_with(complete_path),
+        move |args| {
+            let path = args.get(0).ok_or("缺少路径")?;
+            let content = std::fs::read_to_string(path).map_err(|err| err.to_string())?;
+            text.set(content.lines().next().unwrap_or_default().chars().collect());
+            cursor.set(0);
+            Ok(())
+        },
+    );
+
+    // 插入模式下把按键作为文本输入
+    hooks.use_events({
+        let mode = mode.clone();
+        move |event| {
+            let Event::Key(key) = event else {
+                return;
+            };
+            if key.kind != KeyEventKind::Press || !mode.is(InputMode::Insert) {
+                return;
+            }
+            match key.code {
+                KeyCode::Char(c) => {
+                    text.write().insert(cursor.get(), c);
+                    cursor.set(cursor.get() + 1);
+                }
+                KeyCode::Backspace if cursor.get() > 0 => {
+                    cursor.set(cursor.get() - 1);
+                    text.write().remove(cursor.get());
+                }
+                _ => {}
+            }
+        }
+    });
+
+    let spans = text
+        .read()
+        .iter()
+        .chain([&' '])
+        .enumerate()
+        .map(|(i, c)| {
+            let style = if i == cursor.get() {
+                Style::default().reversed()
+            } else if selection.as_ref().is_some_and(|s| s.contains(&i)) {
+                Style::default().on_dark_gray()
+            } else {
+                Style::default()
+            };
+            Span::styled(c.to_string(), style)
+        })
+        .collect::<Vec<_>>();
+
+    element!(
+        Border(
+            flex_direction: Direction::Vertical,
+            top_title: Some(Line::from("输入模式示例").centered().bold()),
+            bottom_title: Some(
+                Line::from("i 插入  v 可视  Esc 普通  h/l 移动  x/d d 删除  :w/:e 读写文件  Ctrl+C 退出")
+                    .centered()
+            ),
+        ){
+            View(height: Constraint::Fill(1)) {
+                Text(text: Line::from(spans))
+            }
+            View(flex_direction: Direction::Horizontal, height: Constraint::Length(1), gap: 1) {
+                ModeIndicator
+                Text(text: KeySequence(pending).to_string(), wrap: false)
+            }
+        }
+    )
+}
@@ -0,0 +1,84 @@
+use ratatui::{
+    layout::{Constraint, Direction},
+    style::{Style, Stylize},
+    text::Line,
+};
+use ratatui_kit::{
+    crossterm::event::{Event, KeyCode, KeyEventKind},
+    prelude::*,
+    ratatui,
+};
+
+#[tokio::main]
+async fn main() {
+    element!(Echo)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn Echo(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let socket = hooks.use_websocket(WebSocketConfig::new("wss://echo.websocket.org").reconnect(
+        ReconnectStrategy {
+            max_attempts: Some(5),
+            ..Default::default()
+        },
+    ));
+
+    hooks.use_events({
+        let socket = socket.clone();
+        move |event| {
+            if let Event::Key(key) = event {
+                if key.kind != KeyEventKind::Press {
+                    return;
+                }
+                match key.code {
+                    KeyCode::F(2) => socket.reconnect(),
+                    KeyCode::F(3) => socket.close(),
+                    _ => {}
+                }
+            }
+        }
+    });
+
+    let status = match socket.state() {
+        WebSocketState::Connecting => "连接中...".to_string(),
+        WebSocketState::Open => "已连接".to_string(),
+        WebSocketState::Reconnecting { attempt } => format!(
+            "第 {attempt} 次重连{}",
+            socket
+                .error()
+                .map(|err| format!("（{err}）"))
+                .unwrap_or_default()
+        ),
+        WebSocketState::Closed => "已关闭".to_string(),
+    };
+    let messages = socket
+        .messages()
+        .into_iter()
+        .map(|message| match message {
+            WebSocketMessage::Text(text) => format!("< {text}"),
+            WebSocketMessage::Binary(data) => format!("< {} 字节", data.len()),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    element!(
+        Border(
+            flex_direction: Direction::Vertical,
+            top_title: Some(Line::from(format!("Echo · {status}")).centered().bold()),
+            bottom_title: Some(Line::from("Enter 发送，F2 重连，F3 关闭，Ctrl+C 退出").centered()),
+        ){
+            View(height: Constraint::Fill(1)) {
+                Text(text: messages)
+            }
+            Border(height: Constraint::Length(3), border_style: Style::default().green()) {
+                PromptInput(
+                    is_focus: true,
+                    on_submit: move |input: String| socket.send(input),
+                )
+            }
+        }
+    )
+}
@@ -0,0 +1,65 @@
+use ratatui_kit::{
+    prelude::*,
+    ratatui::{
+        layout::{Constraint, Direction},
+        style::{Color, Style, Stylize},
+        text::Line,
+    },
+};
+
+const NODES: &[(&str, f64, f64, bool)] = &[
+    ("us-east", -77.04, 38.9, true),
+    ("us-west", -122.42, 37.77, true),
+    ("sa-east", -46.63, -23.55, false),
+    ("eu-central", 8.68, 50.11, true),
+    ("ap-northeast", 139.69, 35.68, true),
+    ("ap-southeast", 103.82, 1.35, false),
+];
+
+#[tokio::main]
+async fn main() {
+    element!(App)
+        .fullscreen()
+        .await
+        .expect("Failed to run the application");
+}
+
+#[component]
+fn App(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let mut selected = hooks.use_state(|| None::<usize>);
+    hooks.use_mouse_capture();
+
+    let markers = NODES
+        .iter()
+        .map(|&(name, lon, lat, up)| {
+            MapMarker::new(name, lon, lat).color(if up { Color::Green } else { Color::Red })
+        })
+        .collect::<Vec<_>>();
+    let status = match selected.get() {
+        Some(i) => {
+            let (name, lon, lat, up) = NODES[i];
+            format!(
+                "{name}  ({lon:.2}, {lat:.2})  {}",
+                if up { "online" } else { "offline" }
+            )
+        }
+        None => "Tab 切换节点，Enter 选择".to_string(),
+    };
+
+    element!(View(flex_direction: Direction::Vertical) {
+        Border(
+            border_style: Style::default().green(),
+            top_title: Some(Line::from("节点分布")),
+            bottom_title: Some(Line::from("方向键平移，+/- 缩放，0 全图，滚轮缩放，拖拽平移").centered()),
+        ) {
+            WorldMap(
+                markers: markers,
+                is_focus: true,
+                on_marker_select: move |index| selected.set(Some(index)),
+            )
+        }
+        View(height: Constraint::Length(1)) {
+            Text(text: status, style: Style::default().dim())
+        }
+    })
+}
@@ -2,6 +2,8 @@ use quote::{ToTokens, quote};
 use syn::{Expr, parse::Parse};
 use uuid::Uuid;
 
+use crate::utils::static_key;
+
 pub struct ParsedAdapter {
     pub expr: syn::Expr,
 }
@@ -15,13 +17,13 @@ impl Parse for ParsedAdapter {
 
 impl ToTokens for ParsedAdapter {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        let decl_key = Uuid::new_v4().as_u128();
+        let key = static_key(Uuid::new_v4().as_u128());
         let expr = &self.expr;
 
         tokens.extend(quote! {
             {
                 let mut _element=::ratatui_kit::Element::<::ratatui_kit::components::Adapter>{
-                    key: ::ratatui_kit::ElementKey::new(#decl_key),
+                    key: #key,
                     props: ::ratatui_kit::components::AdapterProps{
                         inner: std::sync::Arc::new(#expr)
                     },
@@ -1,9 +1,101 @@
-use quote::{ToTokens, quote};
+use quote::{ToTokens, quote, quote_spanned};
 use syn::{
-    FnArg, GenericParam, Generics, ItemFn, Pat, PatIdent, PatType, Signature, Type, WhereClause,
-    WherePredicate, parse::Parse, spanned::Spanned,
+    Arm, Block, Expr, ExprClosure, ExprForLoop, ExprIf, ExprLoop, ExprMatch, ExprMethodCall,
+    ExprWhile, FnArg, GenericParam, Generics, Item, ItemFn, Pat, PatIdent, PatType, Signature,
+    Type, WhereClause, WherePredicate,
+    parse::Parse,
+    spanned::Spanned,
+    visit::{self, Visit},
 };
 
+// 查找写在条件分支、循环或闭包中的 `hooks.use_*` 调用，这类调用会破坏 hook 顺序
+#[derive(Default)]
+struct ConditionalHookFinder {
+    depth: usize,
+    found: Vec<(proc_macro2::Span, String)>,
+}
+
+impl ConditionalHookFinder {
+    fn nested(&mut self, f: impl FnOnce(&mut Self)) {
+        self.depth += 1;
+        f(self);
+        self.depth -= 1;
+    }
+}
+
+impl<'ast> Visit<'ast> for ConditionalHookFinder {
+    fn visit_expr_if(&mut self, node: &'ast ExprIf) {
+        self.visit_expr(&node.cond);
+        self.nested(|this| {
+            this.visit_block(&node.then_branch);
+            if let Some((_, else_branch)) = &node.else_branch {
+                this.visit_expr(else_branch);
+            }
+        });
+    }
+
+    fn visit_expr_match(&mut self, node: &'ast ExprMatch) {
+        self.visit_expr(&node.expr);
+        self.nested(|this| node.arms.iter().for_each(|arm: &Arm| this.visit_arm(arm)));
+    }
+
+    fn visit_expr_for_loop(&mut self, node: &'ast ExprForLoop) {
+        self.visit_expr(&node.expr);
+        self.nested(|this| this.visit_block(&node.body));
+    }
+
+    fn visit_expr_while(&mut self, node: &'ast ExprWhile) {
+        self.nested(|this| {
+            this.visit_expr(&node.cond);
+            this.visit_block(&node.body);
+        });
+    }
+
+    fn visit_expr_loop(&mut self, node: &'ast ExprLoop) {
+        self.nested(|this| this.visit_block(&node.body));
+    }
+
+    fn visit_expr_closure(&mut self, node: &'ast ExprClosure) {
+        self.nested(|this| this.visit_expr(&node.body));
+    }
+
+    fn visit_item(&mut self, _node: &'ast Item) {}
+
+    fn visit_expr_method_call(&mut self, node: &'ast ExprMethodCall) {
+        let method = node.method.to_string();
+        let on_hooks = matches!(&*node.receiver, Expr::Path(path)
+            if path.path.is_ident("hooks") || path.path.is_ident("_hooks"));
+        if self.depth > 0 && on_hooks && method.starts_with("use_") {
+            self.found.push((node.method.span(), method));
+        }
+        visit::visit_expr_method_call(self, node);
+    }
+}
+
+fn conditional_hook_warnings(block: &Block) -> proc_macro2::TokenStream {
+    let mut finder = ConditionalHookFinder::default();
+    finder.visit_block(block);
+    finder
+        .found
+        .into_iter()
+        .map(|(span, method)| {
+            let note = format!(
+                "`hooks.{method}` is called inside a condition, loop or closure; \
+                 hooks must be called unconditionally and in the same order on every render"
+            );
+            // 通过使用一个已弃用的常量在调用处产生编译警告
+            quote_spanned! {span=>
+                const _: () = {
+                    #[deprecated(note = #note)]
+                    #[allow(non_upper_case_globals)]
+                    const conditional_hook_call: () = ();
+                    conditional_hook_call
+                };
+            }
+        })
+        .collect()
+}
+
 pub struct ParsedComponent {
     f: ItemFn,
     props_type: Option<Box<Type>>,
@@ -116,7 +208,7 @@ impl ToTokens for ParsedComponent {
             ..generics.clone()
         };
 
-        let type_generics = Generics {
+        let mut type_generics = Generics {
             params: generics
                 .params
                 .iter()
@@ -138,6 +230,18 @@ impl ToTokens for ParsedComponent {
             ..generics.clone()
         };
 
+        // Component 要求 Any + Send + Sync，为类型参数自动补上约束
+        let type_params = type_generics
+            .type_params()
+            .map(|param| param.ident.clone())
+            .collect::<Vec<_>>();
+        let type_where_clause = type_generics.make_where_clause();
+        for ident in type_params {
+            type_where_clause.predicates.push(syn::parse_quote!(
+                #ident: ::core::marker::Send + ::core::marker::Sync + 'static
+            ));
+        }
+
         let (lifetime_generics, _, lifetime_where_clause) = lifetime_generics.split_for_impl();
         let (impl_generics, ty_generics, where_clause) = type_generics.split_for_impl();
 
@@ -155,7 +259,11 @@ impl ToTokens for ParsedComponent {
             .map(|ty| ty.to_token_stream())
             .unwrap_or_else(|| quote!(::ratatui_kit::NoProps));
 
+        let hook_warnings = conditional_hook_warnings(block);
+
         tokens.extend(quote! {
+            #hook_warnings
+
             #(#attrs)*
             #vis struct #ident #impl_generics {
                 _marker: std::marker::PhantomData<fn(#(#ty_generics_names),*)>,
@@ -6,11 +6,45 @@ use syn::{
 };
 use uuid::Uuid;
 
-use crate::adapter::ParsedAdapter;
+use crate::{adapter::ParsedAdapter, utils::static_key};
 
 enum ParsedElementChild {
     Element(ElementOrAdapter),
     Expr(Expr),
+    Await(ParsedAwait),
+    Closure(syn::ExprClosure),
+}
+
+// `#(await future => |result| element!(...))`，展开为挂载一次性异步任务的 Suspense 元素
+struct ParsedAwait {
+    future: Expr,
+    render: Expr,
+}
+
+impl Parse for ParsedAwait {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![await]>()?;
+        let future: Expr = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let render: Expr = input.parse()?;
+        Ok(Self { future, render })
+    }
+}
+
+impl ToTokens for ParsedAwait {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let Self { future, render } = self;
+        let key = static_key(Uuid::new_v4().as_u128());
+        tokens.extend(quote! {
+            ::ratatui_kit::Element::<::ratatui_kit::components::Suspense> {
+                key: #key,
+                props: ::ratatui_kit::components::SuspenseProps {
+                    task: Some(::ratatui_kit::components::SuspenseTask::new(#future, #render)),
+                    ..Default::default()
+                },
+            }
+        });
+    }
 }
 
 pub enum PropsItem {
@@ -96,7 +130,17 @@ impl Parse for ParsedElement {
                     children_input.parse::<Token![#]>()?;
                     let expr;
                     syn::parenthesized!(expr in children_input);
-                    children.push(ParsedElementChild::Expr(expr.parse()?));
+                    if expr.peek(Token![await]) {
+                        children.push(ParsedElementChild::Await(expr.parse()?));
+                    } else {
+                        children.push(ParsedElementChild::Expr(expr.parse()?));
+                    }
+                } else if children_input.peek(Token![|])
+                    || children_input.peek(Token![||])
+                    || children_input.peek(Token![move])
+                {
+                    children.push(ParsedElementChild::Closure(children_input.parse()?));
+                    children_input.parse::<Option<Token![,]>>()?;
                 } else {
                     children.push(ParsedElementChild::Element(children_input.parse()?));
                 }
@@ -121,7 +165,9 @@ impl ToTokens for ParsedElement {
             .iter()
             .find_map(|props_item: &PropsItem| match props_item {
                 PropsItem::FieldValue(FieldValue { member, expr, .. }) => match member {
-                    Member::Named(ident) if ident == "key" => Some(quote!((#decl_key,#expr))),
+                    Member::Named(ident) if ident == "key" => {
+                        Some(quote!(::ratatui_kit::ElementKey::new((#decl_key,#expr))))
+                    }
                     _ => None,
                 },
                 PropsItem::Rest(_) => {
@@ -129,7 +175,7 @@ impl ToTokens for ParsedElement {
                     None
                 }
             })
-            .unwrap_or_else(|| quote!(#decl_key));
+            .unwrap_or_else(|| static_key(decl_key));
 
         let props_assignments = self
             .props
@@ -145,11 +191,22 @@ impl ToTokens for ParsedElement {
 
         let set_children = if !self.children.is_empty() {
             let children = self.children.iter().map(|child| match child {
-                ParsedElementChild::Expr(expr) => quote!(#expr),
-                ParsedElementChild::Element(element) => quote!(#element),
+                // 闭包子元素作为 render prop 直接赋给 children
+                ParsedElementChild::Closure(closure) => quote! {
+                    _element.props.children = ::ratatui_kit::RenderProp::new(#closure);
+                },
+                ParsedElementChild::Expr(expr) => quote! {
+                    ::ratatui_kit::extend_with_elements(&mut _element.props.children, #expr);
+                },
+                ParsedElementChild::Await(await_child) => quote! {
+                    ::ratatui_kit::extend_with_elements(&mut _element.props.children, #await_child);
+                },
+                ParsedElementChild::Element(element) => quote! {
+                    ::ratatui_kit::extend_with_elements(&mut _element.props.children, #element);
+                },
             });
             Some(quote! {
-                #(::ratatui_kit::extend_with_elements(&mut _element.props.children,#children);)*
+                #(#children)*
             })
         } else {
             None
@@ -172,12 +229,13 @@ impl ToTokens for ParsedElement {
             tokens.extend(quote! {
                 {
                     type Props<'a>= <#ty as ::ratatui_kit::ElementType>::Props<'a>;
+                    #[allow(clippy::needless_update)]
                     let mut _props = Props{
                         #default_rest
                     };
 
                     let mut _element=::ratatui_kit::Element::<#ty>{
-                        key: ::ratatui_kit::ElementKey::new(#key),
+                        key: #key,
                         props: _props,
                     };
                     #set_children
@@ -190,7 +248,7 @@ impl ToTokens for ParsedElement {
                     type Props<'a>= <#ty as ::ratatui_kit::ElementType>::Props<'a>;
                     let mut _props = Props::default();
                     let mut _element=::ratatui_kit::Element::<#ty>{
-                        key: ::ratatui_kit::ElementKey::new(#key),
+                        key: #key,
                         props: _props,
                     };
                     #set_children
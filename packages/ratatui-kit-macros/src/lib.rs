@@ -8,6 +8,8 @@
 //!   - 支持嵌套、props、children、条件渲染、列表渲染。
 //!   - 条件渲染、列表渲染、动态子组件等均需写在 `#(...expr)` 语法块中，表达式可返回 Option/Vec/impl Iterator。
 //!   - 通过 `$` 前缀可兼容任何实现 WidgetRef 的 ratatui 原生组件或自定义组件，便于无缝集成 ratatui 能力。
+//!   - 子元素位置可直接写闭包 `|item| element!(...)`，赋给组件类型为 `RenderProp` 的 `children` 属性。
+//!   - `#(await future => |result| element!(...))` 声明一次性异步内容，展开为 Suspense 组件，任务完成后用结果渲染。
 //!   - 适用于声明式构建终端 UI 组件树。
 //!
 //! ## element! 宏语法
@@ -57,6 +59,10 @@ pub fn derive_props(item: TokenStream) -> TokenStream {
 /// - 语法风格类似 React JSX，但为 Rust 语法友好设计。
 /// - 支持 `if/else` 条件渲染、`#(for ...)` 列表渲染、props 传递、children 嵌套。
 /// - 通过 `$` 前缀可兼容任何实现 WidgetRef 的 ratatui 原生组件或自定义组件，便于无缝集成 ratatui 能力。
+/// - 子元素位置可直接写闭包 `|item| element!(...)`，作为 render prop 赋给组件的 `children`（类型为 `RenderProp<'a, T>`），
+///   适合由调用方决定条目渲染方式的无头组件。
+/// - `#(await future => |result| element!(...))` 声明一次性异步内容（读取文件、请求接口等），
+///   展开为 Suspense 组件：首次挂载时执行 future，完成前不渲染，完成后以结果的引用调用闭包渲染。
 /// - 适用于声明式构建终端 UI 组件树。
 ///
 /// ## element! 宏语法
@@ -77,6 +83,19 @@ pub fn element(input: TokenStream) -> TokenStream {
 }
 
 /// 简化组件函数定义，自动实现 Component trait。
+///
+/// 支持泛型组件，类型参数会自动补上 `Send + Sync + 'static` 约束，
+/// 在 `element!` 中以 `Select::<Level>(...)` 或 `Select<Level>(...)` 实例化：
+///
+/// ```rust
+/// #[component]
+/// fn Select<T>(props: &mut SelectProps<T>, mut hooks: Hooks) -> impl Into<AnyElement<'static>>
+/// where
+///     T: Display + Clone,
+/// {
+///     ...
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn component(_attr: TokenStream, item: TokenStream) -> TokenStream {
     let component = syn::parse_macro_input!(item as component::ParsedComponent);
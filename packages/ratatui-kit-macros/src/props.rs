@@ -31,10 +31,57 @@ impl ToTokens for ParsedProps {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         let def = &self.def;
         let name = &def.ident;
-        let (impl_generics, type_generics, where_clause) = def.generics.split_for_impl();
+        // Props 要求 Send + Sync，为泛型参数自动补上约束，使用方无需在结构体上重复声明
+        let mut generics = def.generics.clone();
+        let type_params = def
+            .generics
+            .type_params()
+            .map(|param| param.ident.clone())
+            .collect::<Vec<_>>();
+        let where_clause = generics.make_where_clause();
+        for ident in type_params {
+            where_clause
+                .predicates
+                .push(syn::parse_quote!(#ident: ::core::marker::Send + ::core::marker::Sync));
+        }
+        let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+        // 只输出实现了 Debug 的字段，见 ratatui_kit::PropDebug
+        let debug_fields = get_fields(def)
+            .map(|fields| {
+                fields
+                    .iter()
+                    .filter_map(|field| field.ident.as_ref())
+                    .map(|ident| {
+                        let field_name = ident.to_string();
+                        let field_name = field_name.trim_start_matches("r#");
+                        quote! {
+                            if let ::std::option::Option::Some(value) =
+                                (&&::ratatui_kit::PropDebug(&self.#ident)).prop_debug()
+                            {
+                                fields.push((#field_name, value));
+                            }
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let debug_fields = (!debug_fields.is_empty()).then(|| {
+            quote! {
+                fn debug_fields(&self) -> ::std::vec::Vec<(&'static str, ::std::string::String)> {
+                    #[allow(unused_imports)]
+                    use ::ratatui_kit::{PropDebugNone as _, PropDebugSome as _};
+                    let mut fields = ::std::vec::Vec::new();
+                    #(#debug_fields)*
+                    fields
+                }
+            }
+        });
 
         tokens.extend(quote! {
-            unsafe impl #impl_generics ::ratatui_kit::Props for #name #type_generics #where_clause {}
+            unsafe impl #impl_generics ::ratatui_kit::Props for #name #type_generics #where_clause {
+                #debug_fields
+            }
         });
     }
 }
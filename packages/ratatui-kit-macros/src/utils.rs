@@ -1,3 +1,5 @@
+use proc_macro2::TokenStream;
+use quote::quote;
 use syn::{Field, Fields, ItemStruct, Result, punctuated::Punctuated, token::Comma};
 
 pub fn get_fields(input: &ItemStruct) -> Result<Punctuated<Field, Comma>> {
@@ -10,3 +12,14 @@ pub fn get_fields(input: &ItemStruct) -> Result<Punctuated<Field, Comma>> {
         Fields::Named(fields) => Ok(fields.named.clone()),
     }
 }
+
+// 声明位置生成的键不随渲染变化，缓存在静态变量中，每次渲染只需克隆，无需重新分配
+pub fn static_key(decl_key: u128) -> TokenStream {
+    quote! {
+        {
+            static KEY: ::std::sync::LazyLock<::ratatui_kit::ElementKey> =
+                ::std::sync::LazyLock::new(|| ::ratatui_kit::ElementKey::new(#decl_key));
+            ::std::clone::Clone::clone(&*KEY)
+        }
+    }
+}
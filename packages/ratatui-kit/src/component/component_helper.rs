@@ -1,6 +1,10 @@
 use std::any::{Any, TypeId};
 
-use crate::{hooks::Hooks, props::AnyProps, render::ComponentUpdater};
+use crate::{
+    hooks::Hooks,
+    props::{AnyProps, Props},
+    render::ComponentUpdater,
+};
 
 use super::{AnyComponent, Component};
 
@@ -17,6 +21,12 @@ pub trait ComponentHelperExt: Any + Send + Sync {
 
     fn component_type_id(&self) -> TypeId;
 
+    fn component_name(&self) -> &'static str;
+
+    fn props_type_name(&self) -> &'static str;
+
+    fn props_debug(&self, props: &AnyProps) -> Vec<(&'static str, String)>;
+
     fn copy(&self) -> Box<dyn ComponentHelperExt>;
 }
 
@@ -53,6 +63,18 @@ where
         TypeId::of::<T>()
     }
 
+    fn component_name(&self) -> &'static str {
+        std::any::type_name::<T>()
+    }
+
+    fn props_type_name(&self) -> &'static str {
+        std::any::type_name::<T::Props<'static>>()
+    }
+
+    fn props_debug(&self, props: &AnyProps) -> Vec<(&'static str, String)> {
+        unsafe { props.downcast_ref_unchecked::<T::Props<'_>>() }.debug_fields()
+    }
+
     fn update_component(
         &self,
         component: &mut Box<dyn AnyComponent>,
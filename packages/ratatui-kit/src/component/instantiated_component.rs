@@ -1,24 +1,36 @@
-use super::{AnyComponent, ComponentHelperExt};
+use super::{AnyComponent, ComponentHelperExt, TaskScope};
 use crate::{
+    components::StrictModeContext,
     context::ContextStack,
     element::ElementKey,
-    hooks::{AnyHook, Hook, Hooks},
-    multimap::RemoveOnlyMultimap,
+    hooks::{AnyHook, Hook, Hooks, batch_updates, check_hook_count},
+    multimap::{AppendOnlyMultimap, RemoveOnlyMultimap},
     props::AnyProps,
-    render::{ComponentDrawer, ComponentUpdater, layout_style::LayoutStyle},
+    render::{
+        ComponentDrawer, ComponentUpdater, RenderCause, TerminalAccess, layout_style::LayoutStyle,
+        loop_guard, why_render,
+    },
     terminal::Terminal,
 };
-use ratatui::layout::{Constraint, Direction};
+use generational_box::{Owner, SyncStorage};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Rect, Size},
+};
 use std::{
+    collections::VecDeque,
     future::poll_fn,
     ops::{Deref, DerefMut},
     pin::Pin,
+    sync::atomic::{AtomicU64, Ordering},
     task::{Context, Poll},
 };
 
 #[derive(Default)]
 pub struct Components {
     pub components: RemoveOnlyMultimap<ElementKey, InstantiatedComponent>,
+    // 上一次更新留下的空 multimap，下次更新时复用其存储空间
+    spare: RemoveOnlyMultimap<ElementKey, InstantiatedComponent>,
 }
 
 impl Deref for Components {
@@ -46,6 +58,34 @@ impl Components {
             .collect()
     }
 
+    /// 同 [`Components::get_constraints`]，但有固有尺寸的子组件使用测量得到的长度。
+    pub fn get_measured_constraints(&self, direction: Direction, max: Size) -> Vec<Constraint> {
+        self.components
+            .iter()
+            .map(|c| match (c.measure(max), direction) {
+                (Some(size), Direction::Horizontal) => Constraint::Length(size.width),
+                (Some(size), Direction::Vertical) => Constraint::Length(size.height),
+                (None, Direction::Horizontal) => c.layout_style.get_width(),
+                (None, Direction::Vertical) => c.layout_style.get_height(),
+            })
+            .collect()
+    }
+
+    /// 开始一次子组件更新，返回复用上次存储空间的空 multimap。
+    pub(crate) fn begin_update(&mut self) -> AppendOnlyMultimap<ElementKey, InstantiatedComponent> {
+        AppendOnlyMultimap::recycle(std::mem::take(&mut self.spare))
+    }
+
+    /// 用本次更新使用的组件替换旧组件，丢弃未再使用的组件并保留空间供下次复用。
+    pub(crate) fn finish_update(
+        &mut self,
+        used: AppendOnlyMultimap<ElementKey, InstantiatedComponent>,
+    ) {
+        let mut unused = std::mem::replace(&mut self.components, used.into());
+        unused.clear();
+        self.spare = unused;
+    }
+
     fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
         let mut is_ready = false;
         for component in self.components.iter_mut() {
@@ -63,61 +103,274 @@ impl Components {
 }
 
 pub struct InstantiatedComponent {
+    id: u64,
     key: ElementKey,
     hooks: Vec<Box<dyn AnyHook>>,
+    hook_index: usize,
     component: Box<dyn AnyComponent>,
     helper: Box<dyn ComponentHelperExt>,
     children: Components,
     first_update: bool,
     layout_style: LayoutStyle,
     has_transparent_layout: bool,
+    // 透明组件中决定自身尺寸的子组件，其余子组件（如浮层）不参与测量
+    measured_child: Option<usize>,
+    // 子组件被暂停时不轮询也不绘制
+    children_suspended: bool,
+    tasks: TaskScope,
+    // 最近一次绘制时的区域
+    area: Rect,
+    // 最近一次绘制时父组件分配的区域（含外边距）
+    draw_area: Rect,
+    // 自上次更新以来组件自身或其 hook 发生了变化
+    changed: bool,
+    // 自上次绘制以来子树中有组件发生变化，保留缓冲区的父组件据此跳过未变化的子组件
+    dirty: bool,
+    // 序列化元素树时记录的属性调试输出
+    #[cfg(feature = "json")]
+    props_debug: Option<Vec<(&'static str, String)>>,
+    // 本组件所有 use_state 共用的存储，首次创建状态时分配；放在最后，其它字段（含 hook）丢弃时状态仍可读取
+    states: Option<Owner<SyncStorage>>,
 }
 
 impl InstantiatedComponent {
     pub fn new(key: ElementKey, mut props: AnyProps, helper: Box<dyn ComponentHelperExt>) -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
         let component = helper.new_component(props.borrow());
         Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
             key,
             hooks: Default::default(),
+            hook_index: 0,
             layout_style: LayoutStyle::default(),
             component,
             children: Components::default(),
             helper,
             first_update: true,
             has_transparent_layout: false,
+            measured_child: None,
+            children_suspended: false,
+            tasks: TaskScope::default(),
+            area: Rect::default(),
+            draw_area: Rect::default(),
+            changed: true,
+            dirty: true,
+            #[cfg(feature = "json")]
+            props_debug: None,
+            states: None,
+        }
+    }
+
+    /// 组件实例 id，进程内唯一，组件卸载重建后会变化。
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// 组件的键。
+    pub fn key(&self) -> &ElementKey {
+        &self.key
+    }
+
+    /// 组件类型名，不含模块路径和泛型参数，如 `List`。
+    pub fn name(&self) -> &'static str {
+        short_type_name(self.helper.component_name())
+    }
+
+    /// 组件的完整类型名。
+    pub fn type_name(&self) -> &'static str {
+        self.helper.component_name()
+    }
+
+    /// 属性的完整类型名。
+    pub fn props_type_name(&self) -> &'static str {
+        self.helper.props_type_name()
+    }
+
+    /// 最近一次绘制时的区域（不含外边距），尚未绘制时为空。
+    pub fn area(&self) -> Rect {
+        self.area
+    }
+
+    /// 序列化元素树时记录的属性调试输出，见 [`AnyElement::to_json`](crate::AnyElement::to_json)。
+    #[cfg(feature = "json")]
+    pub(crate) fn props_debug(&self) -> Option<&[(&'static str, String)]> {
+        self.props_debug.as_deref()
+    }
+
+    /// 已注册的 hook 数量。
+    pub fn hook_count(&self) -> usize {
+        self.hooks.len()
+    }
+
+    /// 子组件，按渲染顺序排列。
+    pub fn children(&self) -> impl Iterator<Item = &InstantiatedComponent> {
+        self.children.iter()
+    }
+
+    /// 按键路径查找后代组件，如 `"/sidebar/list"`。
+    ///
+    /// 路径按 `/` 分段，每段匹配键或组件类型名（如 `List`），从上一段匹配的组件开始按层级由浅到深查找，
+    /// 因此中间的包装组件可以省略。找不到时返回 None。
+    pub fn find(&self, path: &str) -> Option<&InstantiatedComponent> {
+        path.split('/')
+            .filter(|segment| !segment.is_empty())
+            .try_fold(self, |component, segment| {
+                component.find_descendant(segment)
+            })
+    }
+
+    fn find_descendant(&self, segment: &str) -> Option<&InstantiatedComponent> {
+        let mut queue = self.children.iter().collect::<VecDeque<_>>();
+        while let Some(component) = queue.pop_front() {
+            if component.name() == segment || component.key.label() == segment {
+                return Some(component);
+            }
+            queue.extend(component.children.iter());
         }
+        None
     }
 
     pub fn component(&self) -> &dyn AnyComponent {
         &*self.component
     }
 
+    /// 测量组件的固有尺寸，结果包含外边距。
+    ///
+    /// 只包裹一个子组件的透明组件（如 `#[component]` 函数组件）没有固有尺寸时使用子组件的测量结果，
+    /// 指定了测量子组件的透明组件（如 Popover）使用该子组件的测量结果。
+    pub fn measure(&self, max: Size) -> Option<Size> {
+        if self.has_transparent_layout {
+            let mut children = self.children.iter();
+            let child = match self.measured_child {
+                Some(index) => children.nth(index),
+                None => match (children.next(), children.next()) {
+                    (Some(child), None) => Some(child),
+                    _ => None,
+                },
+            };
+            if let Some(child) = child {
+                return self.component.measure(max).or_else(|| child.measure(max));
+            }
+        }
+        let margin = self.layout_style.margin;
+        let (horizontal, vertical) = (margin.horizontal * 2, margin.vertical * 2);
+        let inner = Size::new(
+            max.width.saturating_sub(horizontal),
+            max.height.saturating_sub(vertical),
+        );
+        self.component.measure(inner).map(|size| {
+            Size::new(
+                size.width.saturating_add(horizontal),
+                size.height.saturating_add(vertical),
+            )
+        })
+    }
+
     pub fn update(
         &mut self,
         terminal: &mut Terminal,
-        context_stack: &mut ContextStack,
+        context_stack: &ContextStack,
+        props: AnyProps,
+    ) {
+        self.update_with(
+            TerminalAccess::Exclusive(terminal),
+            context_stack,
+            props,
+            false,
+        );
+    }
+
+    pub(crate) fn update_with(
+        &mut self,
+        terminal: TerminalAccess,
+        context_stack: &ContextStack,
         mut props: AnyProps,
+        parent_changed: bool,
     ) {
+        #[cfg(feature = "json")]
+        if crate::element::capturing_props() {
+            self.props_debug = Some(self.helper.props_debug(&props));
+        }
+        // 父组件重新渲染时传入的属性可能变化，视为本组件也发生了变化
+        let changed = std::mem::take(&mut self.changed) || parent_changed;
         let mut updater = ComponentUpdater::new(
             self.key.clone(),
             context_stack,
             terminal,
             &mut self.children,
             &mut self.layout_style,
+            changed,
         );
-        self.hooks.pre_component_update(&mut updater);
-        self.helper.update_component(
-            &mut self.component,
-            props.borrow(),
-            Hooks::new(&mut self.hooks, self.first_update),
-            &mut updater,
-        );
-        self.hooks.post_component_update(&mut updater);
+        let strict = cfg!(debug_assertions) && updater.get_context::<StrictModeContext>().is_some();
+        // 更新期间调用的异步处理器把任务派生到本组件的作用域，状态修改计入本组件
+        loop_guard::updating(short_type_name(self.helper.component_name()), || {
+            self.tasks.enter(|| {
+                self.hooks.pre_component_update(&mut updater);
+                if strict {
+                    // 严格模式下先空跑一次更新函数，不更新子组件，暴露不幂等的更新逻辑
+                    updater.set_dry_run(true);
+                    self.helper.update_component(
+                        &mut self.component,
+                        props.borrow(),
+                        Hooks::new(
+                            &mut self.hooks,
+                            &mut self.hook_index,
+                            &mut self.states,
+                            self.first_update,
+                            self.id,
+                            self.helper.component_name(),
+                            context_stack,
+                        ),
+                        &mut updater,
+                    );
+                    updater.set_dry_run(false);
+                }
+                self.helper.update_component(
+                    &mut self.component,
+                    props.borrow(),
+                    Hooks::new(
+                        &mut self.hooks,
+                        &mut self.hook_index,
+                        &mut self.states,
+                        self.first_update && !strict,
+                        self.id,
+                        self.helper.component_name(),
+                        context_stack,
+                    ),
+                    &mut updater,
+                );
+                self.hooks.post_component_update(&mut updater);
+            })
+        });
+        check_hook_count(&self.hooks, self.hook_index, self.helper.component_name());
         self.first_update = false;
         self.has_transparent_layout = updater.has_transparent_layout();
+        self.measured_child = updater.measured_child();
+        let suspended = updater.children_suspended();
+        // 释放更新期间可能锁住的共享终端
+        drop(updater);
+        if suspended != self.children_suspended {
+            self.children_suspended = suspended;
+            for child in self.children.iter_mut() {
+                child.notify_active(!suspended);
+            }
+        }
+        self.dirty |= changed || self.children.iter().any(|child| child.dirty);
+    }
+
+    // 通知子树中的 hook 启用状态变化，已暂停的子树保持不变
+    fn notify_active(&mut self, active: bool) {
+        self.hooks.on_active_change(active);
+        if !self.children_suspended {
+            for child in self.children.iter_mut() {
+                child.notify_active(active);
+            }
+        }
     }
 
     pub fn draw(&mut self, drawer: &mut ComponentDrawer) {
+        self.draw_area = drawer.area;
+        self.dirty = false;
         let layout_style = &self.layout_style;
 
         let area = if self.has_transparent_layout {
@@ -127,42 +380,115 @@ impl InstantiatedComponent {
         };
 
         drawer.area = area;
+        self.area = area;
 
         // 先渲染在计算子组件的areas
         self.hooks.pre_component_draw(drawer);
 
         // drawer.ares可能在组件绘制时改变
         self.component.draw(drawer);
+        if self.children_suspended {
+            self.hooks.post_component_draw(drawer);
+            return;
+        }
         // 计算子组件的区域
         let children_areas =
             self.component
                 .calc_children_areas(&self.children, layout_style, drawer);
 
+        // 缓冲区保留了上一帧的内容且子组件区域未变化时，只重绘发生变化的子组件
+        let retained = std::mem::take(&mut drawer.retain_children)
+            && self.children.iter().count() == children_areas.len()
+            && self
+                .children
+                .iter()
+                .zip(&children_areas)
+                .all(|(child, area)| child.draw_area == *area);
         for (child, area) in self
             .children
             .components
             .iter_mut()
             .zip(children_areas.iter())
         {
+            if retained {
+                if !child.dirty {
+                    continue;
+                }
+                clear_area(drawer.buffer_mut(), *area);
+            }
             drawer.area = *area;
             child.draw(drawer);
         }
         self.hooks.post_component_draw(drawer);
     }
 
-    pub(crate) fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
-        let component_status = Pin::new(&mut *self.component).poll_change(cx);
-        let children_status = Pin::new(&mut self.children).poll_change(cx);
-        let hooks_status = Pin::new(&mut self.hooks).poll_change(cx);
-        if component_status.is_ready() || children_status.is_ready() || hooks_status.is_ready() {
+    pub(crate) fn poll_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        // 事件处理器等在轮询期间执行，其中的状态修改合并为一次唤醒
+        batch_updates(|| self.poll_change_inner(cx))
+    }
+
+    fn poll_change_inner(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let this = &mut *self;
+        let component = &mut this.component;
+        let component_status = this
+            .tasks
+            .enter(|| Pin::new(&mut **component).poll_change(cx));
+        let children_status = if this.children_suspended {
+            Poll::Pending
+        } else {
+            Pin::new(&mut this.children).poll_change(cx)
+        };
+        if component_status.is_ready() {
+            this.changed = true;
+            this.record_cause(None);
+        }
+        // 逐个轮询 hook，记录就绪的 hook 作为本帧的来源
+        let mut ready_hooks = Vec::new();
+        this.tasks.enter(|| {
+            for (index, hook) in this.hooks.iter_mut().enumerate() {
+                if Pin::new(&mut **hook).poll_change(cx).is_ready() {
+                    ready_hooks.push(index);
+                }
+            }
+        });
+        for &index in &ready_hooks {
+            this.changed = true;
+            this.record_cause(Some(index));
+        }
+        this.tasks.poll(cx);
+        if component_status.is_ready() || children_status.is_ready() || !ready_hooks.is_empty() {
             Poll::Ready(())
         } else {
             Poll::Pending
         }
     }
 
+    // 记录本帧的来源，hook_index 为 None 表示组件自身的轮询
+    fn record_cause(&self, hook_index: Option<usize>) {
+        why_render::record(RenderCause {
+            component_id: self.id,
+            component: self.name(),
+            key: self.key.label(),
+            hook_index,
+            hook: hook_index.map(|index| short_type_name(self.hooks[index].type_name())),
+        });
+    }
+
     pub async fn wait(&mut self) {
         let mut self_mut = Pin::new(self);
         poll_fn(|cx| self_mut.as_mut().poll_change(cx)).await;
     }
 }
+
+// 清空区域内的单元格，超出缓冲区的部分忽略
+fn clear_area(buffer: &mut Buffer, area: Rect) {
+    for position in area.intersection(buffer.area).positions() {
+        buffer[position].reset();
+    }
+}
+
+// 去掉模块路径和泛型参数
+pub(crate) fn short_type_name(name: &'static str) -> &'static str {
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name)
+}
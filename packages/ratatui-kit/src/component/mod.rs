@@ -11,8 +11,12 @@ mod component_helper;
 pub(crate) use component_helper::{ComponentHelper, ComponentHelperExt};
 
 mod instantiated_component;
+pub(crate) use instantiated_component::short_type_name;
 pub use instantiated_component::{Components, InstantiatedComponent};
-use ratatui::layout::{Direction, Layout};
+
+mod task_scope;
+use ratatui::layout::{Direction, Layout, Size};
+pub(crate) use task_scope::TaskScope;
 
 /// 组件系统核心 trait，所有自定义 UI 组件都需实现。
 ///
@@ -20,6 +24,7 @@ use ratatui::layout::{Direction, Layout};
 /// - `new` 创建组件实例。
 /// - `update` 响应 props/hook 变化，适合副作用、事件注册等。
 /// - `draw` 渲染组件内容。
+/// - `measure` 测量内容的固有尺寸，容器布局时用它代替子组件的布局约束。
 /// - `calc_children_areas` 默认 flex 布局计算子组件区域，可重写自定义布局。
 /// - `poll_change` 支持异步/响应式副作用。
 /// - `render_ref` 低级渲染接口，通常无需重写。
@@ -77,16 +82,26 @@ pub trait Component: Any + Send + Sync + Unpin {
         self.render_ref(drawer.area, drawer.buffer_mut());
     }
 
-    // 默认使用flex布局计算子组件的area
+    /// 测量组件在最大尺寸 `max` 内的固有尺寸（不含外边距）。
+    ///
+    /// 返回 None 表示没有固有尺寸，按布局约束分配区域；返回 Some 时，
+    /// 父容器在两个方向上都以测量结果作为该组件的 `Constraint::Length`。
+    /// 高度可以超过 `max.height`，由父容器（如 ScrollView）决定如何处理。
+    fn measure(&self, _max: Size) -> Option<Size> {
+        None
+    }
+
+    // 默认使用flex布局计算子组件的area，先测量子组件的固有尺寸，再按约束分配
     fn calc_children_areas(
         &self,
         children: &Components,
         layout_style: &LayoutStyle,
         drawer: &mut ComponentDrawer<'_, '_>,
     ) -> Vec<ratatui::prelude::Rect> {
+        let max = drawer.area.as_size();
         let layout = layout_style
             .get_layout()
-            .constraints(children.get_constraints(layout_style.flex_direction));
+            .constraints(children.get_measured_constraints(layout_style.flex_direction, max));
 
         let areas = layout.split(drawer.area);
 
@@ -96,7 +111,10 @@ pub trait Component: Any + Send + Sync + Unpin {
             Direction::Horizontal => Direction::Vertical,
             Direction::Vertical => Direction::Horizontal,
         };
-        for (area, constraint) in areas.iter().zip(children.get_constraints(rev_direction)) {
+        for (area, constraint) in areas
+            .iter()
+            .zip(children.get_measured_constraints(rev_direction, max))
+        {
             let area = Layout::new(rev_direction, [constraint]).split(*area)[0];
             children_areas.push(area);
         }
@@ -116,6 +134,8 @@ pub trait AnyComponent: Any + Send + Sync + Unpin {
 
     fn draw(&mut self, drawer: &mut ComponentDrawer);
 
+    fn measure(&self, max: Size) -> Option<Size>;
+
     fn calc_children_areas(
         &self,
         children: &Components,
@@ -152,6 +172,10 @@ where
         Component::draw(self, drawer);
     }
 
+    fn measure(&self, max: Size) -> Option<Size> {
+        Component::measure(self, max)
+    }
+
     fn calc_children_areas(
         &self,
         children: &Components,
@@ -0,0 +1,75 @@
+use futures::future::BoxFuture;
+use std::{
+    cell::RefCell,
+    sync::{Arc, Mutex},
+    task::Context,
+};
+
+thread_local! {
+    // 当前正在更新/轮询的组件的任务作用域
+    static CURRENT_SCOPE: RefCell<Option<TaskScope>> = const { RefCell::new(None) };
+}
+
+/// 组件实例的任务作用域，保存异步处理器等派生出的任务，随组件卸载一起丢弃。
+#[derive(Default, Clone)]
+pub(crate) struct TaskScope {
+    tasks: Arc<Mutex<Vec<BoxFuture<'static, ()>>>>,
+}
+
+impl TaskScope {
+    /// 在此作用域内执行 `f`，期间 [`TaskScope::current`] 返回该组件的作用域。
+    pub(crate) fn enter<R>(&self, f: impl FnOnce() -> R) -> R {
+        Self::enter_scope(Some(self.clone()), f)
+    }
+
+    /// 在指定的作用域内执行 `f`，`None` 表示不属于任何组件。
+    pub(crate) fn enter_scope<R>(scope: Option<TaskScope>, f: impl FnOnce() -> R) -> R {
+        // f panic 时也要恢复之前的作用域
+        struct Restore(Option<TaskScope>);
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                CURRENT_SCOPE.with(|scope| *scope.borrow_mut() = self.0.take());
+            }
+        }
+        let _restore = Restore(CURRENT_SCOPE.with(|current| current.replace(scope)));
+        f()
+    }
+
+    /// 当前线程所在的作用域。
+    pub(crate) fn current() -> Option<TaskScope> {
+        CURRENT_SCOPE.with(|scope| scope.borrow().clone())
+    }
+
+    /// 把任务交给此作用域，在组件下一次轮询时执行。
+    pub(crate) fn push(&self, future: BoxFuture<'static, ()>) {
+        self.tasks.lock().unwrap().push(future);
+    }
+
+    /// 轮询全部任务，移除已完成的任务。
+    pub(crate) fn poll(&self, cx: &mut Context) {
+        // 先取出任务再轮询，避免任务内再次派生任务时死锁
+        let mut tasks = std::mem::take(&mut *self.tasks.lock().unwrap());
+        if tasks.is_empty() {
+            return;
+        }
+        self.enter(|| tasks.retain_mut(|task| task.as_mut().poll(cx).is_pending()));
+        let mut shared = self.tasks.lock().unwrap();
+        tasks.append(&mut shared);
+        *shared = tasks;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{AssertUnwindSafe, catch_unwind};
+
+    use super::{CURRENT_SCOPE, TaskScope};
+
+    #[test]
+    fn restores_scope_after_panic() {
+        let scope = TaskScope::default();
+        let result = catch_unwind(AssertUnwindSafe(|| scope.enter(|| panic!("update failed"))));
+        assert!(result.is_err());
+        assert!(CURRENT_SCOPE.with(|scope| scope.borrow().is_none()));
+    }
+}
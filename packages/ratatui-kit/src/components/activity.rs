@@ -0,0 +1,108 @@
+//! Activity 组件：停用时保持子树挂载（状态不丢失），但暂停其事件、异步任务和计时器，也不占用区域。
+//!
+//! 路由的保活模式用它包裹非当前页面，也可用于标签页等需要保留后台页面状态的场景。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(Fragment {
+//!     Activity(key: "list", active: tab.get() == Tab::List) { ListPage }
+//!     Activity(key: "detail", active: tab.get() == Tab::Detail) { DetailPage }
+//! })
+//! ```
+//! - 停用期间子树不会被轮询：`use_future` 等异步任务和计时器暂停，重新启用后继续；
+//! - 通过 [`ComponentUpdater::events_of`] 订阅的事件（包括 `use_events` 等）在停用期间被丢弃；
+//! - 子树中的 hook 通过 [`Hook::on_active_change`](crate::Hook::on_active_change) 收到通知，
+//!   组件中可用 `hooks.use_active_change(...)` 响应。
+
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+
+use ratatui::layout::Constraint;
+use ratatui_kit_macros::Props;
+
+use crate::{AnyElement, Component, ComponentUpdater, Context, layout_style::LayoutStyle};
+
+#[derive(Props)]
+/// Activity 组件属性。
+pub struct ActivityProps<'a> {
+    /// 子元素列表。
+    pub children: Vec<AnyElement<'a>>,
+    /// 是否启用，外层 Activity 也启用时子树才处于启用状态。
+    pub active: bool,
+}
+
+impl Default for ActivityProps<'_> {
+    fn default() -> Self {
+        Self {
+            children: Vec::new(),
+            active: true,
+        }
+    }
+}
+
+/// 启用状态上下文，由 Activity 提供给子树。
+#[derive(Clone, Default)]
+pub struct ActivityContext {
+    active: Arc<AtomicBool>,
+}
+
+impl ActivityContext {
+    /// 子树是否处于启用状态。
+    pub fn is_active(&self) -> bool {
+        self.active.load(Ordering::Relaxed)
+    }
+
+    /// 当前组件所在的 Activity，不在任何 Activity 中时返回 None。
+    pub fn current(updater: &ComponentUpdater) -> Option<Self> {
+        updater
+            .get_context::<ActivityContext>()
+            .map(|activity| activity.clone())
+    }
+}
+
+/// Activity 组件实现。
+pub struct Activity {
+    context: ActivityContext,
+}
+
+impl Component for Activity {
+    type Props<'a> = ActivityProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self {
+            context: ActivityContext::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        _hooks: crate::Hooks,
+        updater: &mut ComponentUpdater,
+    ) {
+        let parent_active =
+            ActivityContext::current(updater).is_none_or(|parent| parent.is_active());
+        let active = props.active && parent_active;
+        self.context.active.store(active, Ordering::Relaxed);
+
+        if active {
+            updater.set_transparent_layout(true);
+            updater.set_layout_style(LayoutStyle::default());
+        } else {
+            // 停用时不占用父组件布局中的空间
+            updater.set_transparent_layout(false);
+            updater.set_layout_style(LayoutStyle {
+                width: Constraint::Length(0),
+                height: Constraint::Length(0),
+                ..Default::default()
+            });
+        }
+        updater.set_children_suspended(!active);
+        updater.update_children(
+            props.children.iter_mut(),
+            Some(Context::owned(self.context.clone())),
+        );
+    }
+}
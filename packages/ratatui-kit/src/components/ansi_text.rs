@@ -0,0 +1,61 @@
+//! AnsiText 组件：显示带 ANSI 转义序列的文本，例如 `git diff --color`、cargo 等命令的彩色输出。
+//!
+//! ## 用法示例
+//! ```rust
+//! let output = Command::new("git")
+//!     .args(["diff", "--color=always"])
+//!     .output()?;
+//!
+//! element!(ScrollView {
+//!     AnsiText(text: String::from_utf8_lossy(&output.stdout).into_owned())
+//! })
+//! ```
+//! 支持 16 色、256 色、真彩色和常用文字修饰，光标移动等其余控制序列会被忽略。
+//! 与 [`Text`] 一样按可用宽度自动换行，高度由行数决定，可直接放进 ScrollView。
+//! 需要逐行追加输出时可使用 [`AnsiParser`](crate::text::AnsiParser)。
+
+use ratatui::{layout::Alignment, style::Style};
+use ratatui_kit_macros::{Props, component, element};
+
+use super::Text;
+use crate::{AnyElement, Hooks, UseMemo, text::parse_ansi};
+
+#[derive(Props)]
+/// AnsiText 组件属性。
+pub struct AnsiTextProps {
+    /// 带 ANSI 转义序列的文本。
+    pub text: String,
+    /// 基础样式，转义序列重置样式后回到该样式。
+    pub style: Style,
+    /// 对齐方式。
+    pub alignment: Alignment,
+    /// 是否自动换行，默认开启。
+    pub wrap: bool,
+    /// 换行时是否去掉行首空白。
+    pub trim: bool,
+}
+
+impl Default for AnsiTextProps {
+    fn default() -> Self {
+        Self {
+            text: String::new(),
+            style: Style::default(),
+            alignment: Alignment::Left,
+            wrap: true,
+            trim: false,
+        }
+    }
+}
+
+#[component]
+pub fn AnsiText(props: &AnsiTextProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let text = hooks.use_memo(|| parse_ansi(&props.text), props.text.clone());
+
+    element!(Text(
+        text: text,
+        style: props.style,
+        alignment: props.alignment,
+        wrap: props.wrap,
+        trim: props.trim,
+    ))
+}
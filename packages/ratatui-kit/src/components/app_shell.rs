@@ -0,0 +1,291 @@
+//! AppShell 组件：应用骨架，组合顶部菜单栏或标题栏、可折叠的导航侧边栏、路由内容区域和底部状态栏。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(AppShell(
+//!     title: Some(Line::from("My App")),
+//!     menus: vec![Menu::new("File", vec![MenuItem::action("Quit", move || exit.set(true))])],
+//!     nav: vec![NavItem::new("首页", "/"), NavItem::new("设置", "/settings")],
+//!     routes: routes! {
+//!         "/" => HomePage,
+//!         "/settings" => SettingsPage,
+//!     },
+//!     index_path: "/",
+//! ))
+//! ```
+//! - 有菜单时顶部为 MenuBar，否则显示 `title` 标题栏，两者都没有时不占用顶部空间；
+//! - 内容区域为路由的 Outlet，整个骨架位于 RouterProvider 内，子组件可以直接使用 `hooks.use_navigate()`；
+//! - 状态栏左侧显示当前路径和 `status`，右侧显示按键提示。
+//!
+//! 按键：`Ctrl+B`（`toggle_key`）折叠侧边栏，`Alt+1`~`Alt+9` 跳转到对应的导航项，
+//! `F10` 或 `Alt+菜单首字母` 打开菜单。开启鼠标捕获后可以点击导航项。
+
+use std::{
+    any::Any,
+    sync::{Arc, Mutex},
+};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers, MouseButton, MouseEventKind};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Direction, Position, Rect},
+    style::{Style, Stylize},
+    text::Line,
+    widgets::Widget,
+};
+use ratatui_kit_macros::{Props, component, element};
+
+use super::{Menu, MenuBar, Outlet, RouterProvider, SidebarLayout, StatusBar, Text, View};
+use crate::{
+    AnyElement, Component, EventInterest, Hooks, KeyChord, Navigate, State, UseContext, UseEvents,
+    components::router::history::RouterHistory, prelude::Routes, text,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// 侧边栏中的导航项。
+pub struct NavItem {
+    /// 显示的名称。
+    pub label: String,
+    /// 跳转的路由路径。
+    pub path: String,
+}
+
+impl NavItem {
+    /// 创建导航项。
+    pub fn new(label: impl Into<String>, path: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            path: path.into(),
+        }
+    }
+
+    // 当前路径是该导航项或其子路由时高亮
+    fn is_active(&self, path: &str) -> bool {
+        path == self.path
+            || (self.path != "/"
+                && path
+                    .strip_prefix(self.path.as_str())
+                    .is_some_and(|rest| rest.starts_with('/')))
+    }
+}
+
+#[derive(Props)]
+/// AppShell 组件属性。
+pub struct AppShellProps<'a> {
+    /// 没有菜单时显示在顶部的标题。
+    pub title: Option<Line<'static>>,
+    /// 顶部菜单栏的菜单。
+    pub menus: Vec<Menu>,
+    /// 侧边栏中的导航项。
+    pub nav: Vec<NavItem>,
+    /// 路由表。
+    pub routes: Routes,
+    /// 默认首页路径。
+    pub index_path: String,
+    /// 首页的路由状态。
+    pub index_state: Option<Arc<dyn Any + Send + Sync>>,
+    /// 是否保活访问过的页面。
+    pub keep_alive: bool,
+    /// 显示在状态栏左侧、当前路径之后的内容。
+    pub status: Option<AnyElement<'a>>,
+    /// 侧边栏展开时的宽度。
+    pub sidebar_width: u16,
+    /// 切换侧边栏的按键。
+    pub toggle_key: Option<KeyChord>,
+    /// 标题栏样式。
+    pub header_style: Style,
+    /// 状态栏样式。
+    pub status_style: Style,
+    /// 当前导航项的样式。
+    pub highlight_style: Style,
+}
+
+impl Default for AppShellProps<'_> {
+    fn default() -> Self {
+        Self {
+            title: None,
+            menus: Vec::new(),
+            nav: Vec::new(),
+            routes: Routes::default(),
+            index_path: "/".to_string(),
+            index_state: None,
+            keep_alive: false,
+            status: None,
+            sidebar_width: 24,
+            toggle_key: Some(KeyChord::new(KeyCode::Char('b'), KeyModifiers::CONTROL)),
+            header_style: Style::default().reversed().bold(),
+            status_style: Style::default().reversed(),
+            highlight_style: Style::default().reversed(),
+        }
+    }
+}
+
+#[component]
+pub fn AppShell<'a>(props: &mut AppShellProps<'a>, _hooks: Hooks) -> impl Into<AnyElement<'a>> {
+    let header = props
+        .title
+        .clone()
+        .filter(|_| props.menus.is_empty())
+        .map(|title| {
+            element!(Text(
+                text: title,
+                style: props.header_style,
+                alignment: Alignment::Center,
+                wrap: false,
+            ))
+        });
+
+    let mut hints = Vec::new();
+    if let Some(key) = props.toggle_key {
+        hints.push(format!("{key} 侧边栏"));
+    }
+    if !props.nav.is_empty() {
+        hints.push(format!("Alt+1~{} 导航", props.nav.len().min(9)));
+    }
+    if !props.menus.is_empty() {
+        hints.push("F10 菜单".to_string());
+    }
+    let hints = Line::from(format!("{} ", hints.join("  ")));
+
+    let nav = element!(ShellNav(
+        items: props.nav.clone(),
+        highlight_style: props.highlight_style,
+    ));
+    let status = props.status.take();
+
+    let body: AnyElement<'a> = element!(View(flex_direction: Direction::Vertical) {
+        #(header)
+        View(height: Constraint::Fill(1)) {
+            SidebarLayout(
+                sidebar: Some(nav.into()),
+                width: props.sidebar_width,
+                toggle_key: props.toggle_key,
+            ) {
+                Outlet
+            }
+        }
+        StatusBar(style: props.status_style, right: Some(hints)) {
+            ShellPath
+            #(status)
+        }
+    })
+    .into();
+    let body = if props.menus.is_empty() {
+        body
+    } else {
+        element!(MenuBar(menus: props.menus.clone()) {
+            #(body)
+        })
+        .into()
+    };
+
+    element!(RouterProvider(
+        routes: props.routes.borrow(),
+        index_path: props.index_path.clone(),
+        index_state: props.index_state.clone(),
+        keep_alive: props.keep_alive,
+    ) {
+        #(body)
+    })
+}
+
+// 状态栏中的当前路径
+#[component]
+fn ShellPath(hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let path = hooks
+        .use_context::<State<RouterHistory>>()
+        .read()
+        .current_context()
+        .path;
+    element!(Text(text: format!(" {path}"), wrap: false))
+}
+
+#[derive(Default, Props)]
+struct ShellNavProps {
+    items: Vec<NavItem>,
+    highlight_style: Style,
+}
+
+// 侧边栏导航列表，每行一项，当前路由对应的项高亮
+struct ShellNav {
+    items: Vec<NavItem>,
+    highlight_style: Style,
+    current: String,
+    area: Arc<Mutex<Rect>>,
+}
+
+impl Component for ShellNav {
+    type Props<'a> = ShellNavProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            items: props.items.clone(),
+            highlight_style: props.highlight_style,
+            current: String::new(),
+            area: Arc::new(Mutex::new(Rect::default())),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut crate::ComponentUpdater,
+    ) {
+        self.items = props.items.clone();
+        self.highlight_style = props.highlight_style;
+
+        let history = updater
+            .get_context::<State<RouterHistory>>()
+            .map(|history| *history)
+            .expect("ShellNav must be used inside RouterProvider");
+        self.current = history.read().current_context().path;
+
+        hooks.use_events_of(EventInterest::KEY | EventInterest::MOUSE, {
+            let paths: Vec<String> = self.items.iter().map(|item| item.path.clone()).collect();
+            let area = self.area.clone();
+            let mut navigate = Navigate::new(history);
+            move |event| {
+                let index = match event {
+                    Event::Key(key)
+                        if key.kind == KeyEventKind::Press
+                            && key.modifiers == KeyModifiers::ALT =>
+                    {
+                        match key.code {
+                            KeyCode::Char(c @ '1'..='9') => c as usize - '1' as usize,
+                            _ => return,
+                        }
+                    }
+                    Event::Mouse(mouse)
+                        if mouse.kind == MouseEventKind::Down(MouseButton::Left) =>
+                    {
+                        let area = *area.lock().unwrap();
+                        if !area.contains(Position::new(mouse.column, mouse.row)) {
+                            return;
+                        }
+                        (mouse.row - area.y) as usize
+                    }
+                    _ => return,
+                };
+                if let Some(path) = paths.get(index) {
+                    navigate.push(path);
+                }
+            }
+        });
+    }
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        *self.area.lock().unwrap() = area;
+        for (index, (item, row)) in self.items.iter().zip(area.rows()).enumerate() {
+            let label = format!(" {} {}", index + 1, item.label);
+            let label = text::truncate_with_ellipsis(&label, row.width as usize);
+            let style = if item.is_active(&self.current) {
+                self.highlight_style
+            } else {
+                Style::default()
+            };
+            buf.set_style(row, style);
+            Line::styled(label.into_owned(), style).render(row, buf);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! AutocompletePopup 组件：为内部的输入组件显示自动补全候选项，浮于其他内容之上。
+//!
+//! ## 用法示例
+//! ```rust
+//! let autocomplete = hooks.use_autocomplete(|query| {
+//!     COMMANDS.iter().map(|c| c.to_string()).collect()
+//! });
+//! element!(AutocompletePopup(autocomplete: Some(autocomplete)) {
+//!     TextArea(
+//!         value: value.read().to_string(),
+//!         is_focus: true,
+//!         on_change: move |new_value| value.set(new_value),
+//!     )
+//! })
+//! ```
+//! - 输入时根据光标前的词更新候选项，弹窗显示在光标下方，空间不足时显示在上方。
+//! - 弹窗打开时 `↑/↓` 选择，`Enter`/`Tab` 插入选中项，`Esc` 关闭。
+//! - 内部的输入组件通过上下文获取 [`Autocomplete`]，上报光标位置并处理上述按键，目前由 `TextArea` 支持。
+
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{Block, Widget},
+};
+use ratatui_kit_macros::Props;
+
+use crate::{AnyElement, Autocomplete, Component, Context, Hook};
+
+#[derive(Props)]
+/// AutocompletePopup 组件属性。
+pub struct AutocompletePopupProps<'a> {
+    /// 子元素，通常是一个输入组件。
+    pub children: Vec<AnyElement<'a>>,
+    /// 由 `use_autocomplete` 创建的补全状态。
+    pub autocomplete: Option<Autocomplete>,
+    /// 最多同时显示的候选项数。
+    pub max_items: usize,
+    /// 弹窗样式。
+    pub style: Style,
+    /// 选中项样式。
+    pub selected_style: Style,
+    /// 是否在弹窗右下方绘制阴影。
+    pub shadow: bool,
+}
+
+impl Default for AutocompletePopupProps<'_> {
+    fn default() -> Self {
+        Self {
+            children: Vec::new(),
+            autocomplete: None,
+            max_items: 8,
+            style: Style::default(),
+            selected_style: Style::default().reversed(),
+            shadow: false,
+        }
+    }
+}
+
+/// AutocompletePopup 组件实现。
+pub struct AutocompletePopup;
+
+impl Component for AutocompletePopup {
+    type Props<'a> = AutocompletePopupProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: crate::Hooks,
+        updater: &mut crate::ComponentUpdater,
+    ) {
+        let host = hooks.use_hook(|| UseAutocompletePopupImpl {
+            autocomplete: None,
+            max_items: 8,
+            style: Style::default(),
+            selected_style: Style::default(),
+            shadow: false,
+        });
+        host.autocomplete = props.autocomplete.clone();
+        host.max_items = props.max_items.max(1);
+        host.style = props.style;
+        host.selected_style = props.selected_style;
+        host.shadow = props.shadow;
+
+        updater.set_transparent_layout(true);
+        updater.update_children(
+            &mut props.children,
+            props.autocomplete.clone().map(Context::owned),
+        );
+    }
+}
+
+struct UseAutocompletePopupImpl {
+    autocomplete: Option<Autocomplete>,
+    max_items: usize,
+    style: Style,
+    selected_style: Style,
+    shadow: bool,
+}
+
+impl Hook for UseAutocompletePopupImpl {
+    fn post_component_draw(&mut self, drawer: &mut crate::ComponentDrawer) {
+        // 子组件绘制完成后，在最上层绘制候选项
+        let Some(autocomplete) = &self.autocomplete else {
+            return;
+        };
+        let Some(caret) = autocomplete.caret().filter(|_| autocomplete.is_open()) else {
+            return;
+        };
+        let suggestions = autocomplete.suggestions();
+        let selected = autocomplete.selected();
+        let frame = drawer.buffer_mut().area;
+
+        let visible = suggestions.len().min(self.max_items);
+        let height = (visible as u16 + 2).min(frame.height);
+        let width = suggestions
+            .iter()
+            .map(|s| crate::text::width(s))
+            .max()
+            .unwrap_or_default() as u16
+            + 2;
+        let below = frame.bottom().saturating_sub(caret.y + 1);
+        let y = if below >= height || below >= caret.y.saturating_sub(frame.y) {
+            caret.y + 1
+        } else {
+            caret.y.saturating_sub(height)
+        };
+        // 弹窗左边框与光标对齐，超出右侧时向左移动
+        let x = caret
+            .x
+            .saturating_sub(1)
+            .min(frame.right().saturating_sub(width))
+            .max(frame.x);
+        let area = Rect::new(x, y, width, height).intersection(frame);
+        if area.is_empty() {
+            return;
+        }
+
+        let buf = drawer.buffer_mut();
+        let block = Block::bordered().style(self.style);
+        let inner = block.inner(area);
+        super::modal::render_surface(area, buf, None, self.shadow);
+        block.render(area, buf);
+
+        let start = (selected + 1).saturating_sub(visible);
+        for (row, (i, suggestion)) in suggestions
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(inner.height as usize)
+            .enumerate()
+        {
+            let style = if i == selected {
+                self.selected_style
+            } else {
+                self.style
+            };
+            Line::styled(
+                crate::text::fit(suggestion, inner.width as usize, Alignment::Left),
+                style,
+            )
+            .render(
+                Rect {
+                    y: inner.y + row as u16,
+                    height: 1,
+                    ..inner
+                },
+                buf,
+            );
+        }
+    }
+}
@@ -13,16 +13,126 @@
 //! })
 //! ```
 //! 支持自定义边框样式、边框字符集、上下标题、内边距等属性。
+//!
+//! 使用预设边框、每条边单独的样式和多个标题：
+//! ```rust
+//! element!(Border(
+//!     kind: Some(BorderKind::Rounded),
+//!     edge_styles: EdgeStyles { top: Some(Style::new().yellow()), ..Default::default() },
+//!     titles: vec![
+//!         BorderTitle::top("文件"),
+//!         BorderTitle::top("3/12").alignment(Alignment::Right),
+//!         BorderTitle::bottom("q 退出").alignment(Alignment::Center),
+//!     ],
+//! ){
+//!     ChildComponent()
+//! })
+//! ```
+//!
+//! 开启 `collapse` 的相邻边框共用一条边线，交汇处自动换成 `┬`、`├`、`┼` 等连接字符，
+//! 适合拼出表格网格：
+//! ```rust
+//! element!(View(flex_direction: Direction::Horizontal) {
+//!     Border(collapse: true) { Text(text: "A") }
+//!     Border(collapse: true) { Text(text: "B") }
+//! })
+//! ```
 
 use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Position, Rect},
+    style::Style,
     symbols::border,
     text::Line,
-    widgets::{Block, Padding, Widget},
+    widgets::{Block, Borders, Padding, Widget, block::Position as TitlePosition},
 };
 use ratatui_kit_macros::{Props, with_layout_style};
 
 use crate::{AnyElement, Component};
 
+/// 边框预设。
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BorderKind {
+    /// 细线直角：`┌─┐`。
+    #[default]
+    Plain,
+    /// 细线圆角：`╭─╮`。
+    Rounded,
+    /// 双线：`╔═╗`。
+    Double,
+    /// 粗线：`┏━┓`。
+    Thick,
+    /// 纯 ASCII：`+-+`，适合不支持制表符的终端。
+    Ascii,
+}
+
+impl BorderKind {
+    /// 预设对应的边框字符集。
+    pub fn set(self) -> border::Set {
+        match self {
+            BorderKind::Plain => border::PLAIN,
+            BorderKind::Rounded => border::ROUNDED,
+            BorderKind::Double => border::DOUBLE,
+            BorderKind::Thick => border::THICK,
+            BorderKind::Ascii => border::Set {
+                top_left: "+",
+                top_right: "+",
+                bottom_left: "+",
+                bottom_right: "+",
+                vertical_left: "|",
+                vertical_right: "|",
+                horizontal_top: "-",
+                horizontal_bottom: "-",
+            },
+        }
+    }
+}
+
+/// 每条边单独的样式，叠加在 `border_style` 之上；角落跟随上下两条边。
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EdgeStyles {
+    pub top: Option<Style>,
+    pub right: Option<Style>,
+    pub bottom: Option<Style>,
+    pub left: Option<Style>,
+}
+
+/// 边框上的标题，同一条边可以有多个标题，各自对齐。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BorderTitle {
+    /// 标题内容。
+    pub content: Line<'static>,
+    /// 所在的边。
+    pub position: TitlePosition,
+    /// 对齐方式，None 时使用 `title_alignment`。
+    pub alignment: Option<Alignment>,
+}
+
+impl BorderTitle {
+    /// 顶部标题。
+    pub fn top(content: impl Into<Line<'static>>) -> Self {
+        Self {
+            content: content.into(),
+            position: TitlePosition::Top,
+            alignment: None,
+        }
+    }
+
+    /// 底部标题。
+    pub fn bottom(content: impl Into<Line<'static>>) -> Self {
+        Self {
+            position: TitlePosition::Bottom,
+            ..Self::top(content)
+        }
+    }
+
+    /// 设置对齐方式。
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+}
+
 #[with_layout_style]
 #[derive(Props)]
 /// Border 组件属性。
@@ -35,6 +145,10 @@ pub struct BorderProps<'a> {
     pub borders: ratatui::widgets::Borders,
     /// 边框字符集。
     pub border_set: border::Set,
+    /// 边框预设，设置后替换 `border_set`。
+    pub kind: Option<BorderKind>,
+    /// 每条边单独的样式。
+    pub edge_styles: EdgeStyles,
     /// 整体样式。
     pub style: ratatui::style::Style,
     /// 子元素列表。
@@ -43,6 +157,12 @@ pub struct BorderProps<'a> {
     pub top_title: Option<Line<'static>>,
     /// 底部标题。
     pub bottom_title: Option<Line<'static>>,
+    /// 更多标题，排在 `top_title`/`bottom_title` 之后。
+    pub titles: Vec<BorderTitle>,
+    /// 未指定对齐方式的标题的对齐方式。
+    pub title_alignment: Alignment,
+    /// 与左侧、上方相邻的边框共用边线，交汇处换成连接字符。
+    pub collapse: bool,
 }
 
 impl Default for BorderProps<'_> {
@@ -53,9 +173,14 @@ impl Default for BorderProps<'_> {
             borders: ratatui::widgets::Borders::ALL,
             children: Vec::new(),
             border_set: border::Set::default(),
+            kind: None,
+            edge_styles: EdgeStyles::default(),
             style: ratatui::style::Style::default(),
             top_title: None,
             bottom_title: None,
+            titles: Vec::new(),
+            title_alignment: Alignment::Left,
+            collapse: false,
             margin: Default::default(),
             offset: Default::default(),
             width: Default::default(),
@@ -73,26 +198,125 @@ pub struct Border {
     pub border_style: ratatui::style::Style,
     pub borders: ratatui::widgets::Borders,
     pub border_set: border::Set,
+    pub edge_styles: EdgeStyles,
     pub style: ratatui::style::Style,
     pub top_title: Option<Line<'static>>,
     pub bottom_title: Option<Line<'static>>,
+    pub titles: Vec<BorderTitle>,
+    pub title_alignment: Alignment,
+    pub collapse: bool,
 }
 
-impl Component for Border {
-    type Props<'a> = BorderProps<'a>;
-
-    /// 根据属性创建 Border 组件实例
-    fn new(props: &Self::Props<'_>) -> Self {
+impl Border {
+    fn from_props(props: &BorderProps<'_>) -> Self {
         Self {
             padding: props.padding,
             border_style: props.border_style,
             borders: props.borders,
-            border_set: props.border_set,
+            border_set: props.kind.map_or(props.border_set, BorderKind::set),
+            edge_styles: props.edge_styles,
             style: props.style,
             top_title: props.top_title.clone(),
             bottom_title: props.bottom_title.clone(),
+            titles: props.titles.clone(),
+            title_alignment: props.title_alignment,
+            collapse: props.collapse,
+        }
+    }
+
+    // 共用边线时向左、向上扩展一格，与已绘制的相邻边框重叠
+    fn collapsed_area(&self, area: Rect, buf: &Buffer) -> Rect {
+        let mut area = area;
+        let mid_y = area.y + area.height / 2;
+        if self.borders.contains(Borders::LEFT)
+            && area.x > buf.area.x
+            && arms_at(buf, area.x - 1, mid_y).is_some_and(|arms| arms & (UP | DOWN) == UP | DOWN)
+        {
+            area.x -= 1;
+            area.width += 1;
+        }
+        let mid_x = area.x + area.width / 2;
+        if self.borders.contains(Borders::TOP)
+            && area.y > buf.area.y
+            && arms_at(buf, mid_x, area.y - 1)
+                .is_some_and(|arms| arms & (LEFT | RIGHT) == LEFT | RIGHT)
+        {
+            area.y -= 1;
+            area.height += 1;
+        }
+        area
+    }
+
+    // 边框所在的单元格
+    fn edge_cells(&self, area: Rect) -> Vec<Position> {
+        if area.is_empty() {
+            return Vec::new();
+        }
+        let mut cells = Vec::new();
+        let (right, bottom) = (area.right() - 1, area.bottom() - 1);
+        if self.borders.contains(Borders::TOP) {
+            cells.extend(area.columns().map(|column| Position::new(column.x, area.y)));
+        }
+        if self.borders.contains(Borders::BOTTOM) {
+            cells.extend(area.columns().map(|column| Position::new(column.x, bottom)));
+        }
+        if self.borders.contains(Borders::LEFT) {
+            cells.extend(area.rows().map(|row| Position::new(area.x, row.y)));
+        }
+        if self.borders.contains(Borders::RIGHT) {
+            cells.extend(area.rows().map(|row| Position::new(right, row.y)));
+        }
+        cells
+    }
+
+    // 按边叠加样式，只修改边框字符，不影响标题文字；上下两条边最后处理，角落跟随上下边
+    fn apply_edge_styles(&self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+        let (right, bottom) = (area.right() - 1, area.bottom() - 1);
+        let edges = [
+            (
+                self.edge_styles.left,
+                Rect::new(area.x, area.y, 1, area.height),
+            ),
+            (
+                self.edge_styles.right,
+                Rect::new(right, area.y, 1, area.height),
+            ),
+            (
+                self.edge_styles.top,
+                Rect::new(area.x, area.y, area.width, 1),
+            ),
+            (
+                self.edge_styles.bottom,
+                Rect::new(area.x, bottom, area.width, 1),
+            ),
+        ];
+        for (style, edge) in edges {
+            let Some(style) = style else {
+                continue;
+            };
+            for y in edge.top()..edge.bottom() {
+                for x in edge.left()..edge.right() {
+                    if let Some(cell) = buf.cell_mut((x, y)) {
+                        if is_border_symbol(cell.symbol(), &self.border_set) {
+                            cell.set_style(style);
+                        }
+                    }
+                }
+            }
         }
     }
+}
+
+impl Component for Border {
+    type Props<'a> = BorderProps<'a>;
+
+    /// 根据属性创建 Border 组件实例
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self::from_props(props)
+    }
 
     /// 根据最新属性和子组件更新自身状态
     fn update(
@@ -104,15 +328,7 @@ impl Component for Border {
         // 获取布局属性
         let layout_style = props.layout_style();
         // 用新属性重建自身
-        *self = Self {
-            padding: props.padding,
-            border_style: props.border_style,
-            borders: props.borders,
-            border_set: props.border_set,
-            style: props.style,
-            top_title: props.top_title.clone(),
-            bottom_title: props.bottom_title.clone(),
-        };
+        *self = Self::from_props(props);
         // 设置布局样式
         updater.set_layout_style(layout_style);
         // 更新子组件
@@ -127,6 +343,7 @@ impl Component for Border {
             .borders(self.borders)
             .border_set(self.border_set)
             .border_style(self.border_style)
+            .title_alignment(self.title_alignment)
             .padding(self.padding);
 
         // 设置顶部标题（如有）
@@ -139,11 +356,146 @@ impl Component for Border {
             block = block.title_bottom(bottom_title.clone());
         }
 
+        // 其余标题按所在的边和对齐方式添加
+        for title in &self.titles {
+            let mut content = title.content.clone();
+            if let Some(alignment) = title.alignment {
+                content = content.alignment(alignment);
+            }
+            block = match title.position {
+                TitlePosition::Top => block.title_top(content),
+                TitlePosition::Bottom => block.title_bottom(content),
+            };
+        }
+
+        let area = if self.collapse {
+            self.collapsed_area(drawer.area, drawer.buffer_mut())
+        } else {
+            drawer.area
+        };
+        // 共用边线时记下被覆盖的边线字符，绘制后与新的边线合并
+        let covered = if self.collapse {
+            let buf = drawer.buffer_mut();
+            self.edge_cells(area)
+                .into_iter()
+                .filter_map(|position| {
+                    let cell = buf.cell(position)?;
+                    Some((position, cell.symbol().to_string()))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
         // 计算内容区域
-        let inner_area = block.inner(drawer.area);
+        let inner_area = block.inner(area);
         // 渲染边框
-        block.render(drawer.area, drawer.buffer_mut());
+        let buf = drawer.buffer_mut();
+        block.render(area, buf);
+        for (position, symbol) in covered {
+            if let Some(cell) = buf.cell_mut(position) {
+                if let Some(merged) = merge_symbols(&symbol, cell.symbol()) {
+                    cell.set_symbol(merged);
+                }
+            }
+        }
+        self.apply_edge_styles(area, buf);
         // 更新绘制区域为内容区，供子组件使用
         drawer.area = inner_area;
     }
 }
+
+// 制表符的四个方向
+const UP: u8 = 1;
+const DOWN: u8 = 2;
+const LEFT: u8 = 4;
+const RIGHT: u8 = 8;
+
+// 线型相同的制表符才能合并，圆角与细线合并后使用细线
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LineKind {
+    Light,
+    Double,
+    Thick,
+    Ascii,
+}
+
+// 各线型按方向组合排列的字符，下标为 UP | DOWN | LEFT | RIGHT 的组合
+const LIGHT: [&str; 16] = [
+    " ", "│", "│", "│", "─", "┘", "┐", "┤", "─", "└", "┌", "├", "─", "┴", "┬", "┼",
+];
+const DOUBLE: [&str; 16] = [
+    " ", "║", "║", "║", "═", "╝", "╗", "╣", "═", "╚", "╔", "╠", "═", "╩", "╦", "╬",
+];
+const THICK: [&str; 16] = [
+    " ", "┃", "┃", "┃", "━", "┛", "┓", "┫", "━", "┗", "┏", "┣", "━", "┻", "┳", "╋",
+];
+const ASCII: [&str; 16] = [
+    " ", "|", "|", "|", "-", "+", "+", "+", "-", "+", "+", "+", "-", "+", "+", "+",
+];
+
+fn table(kind: LineKind) -> &'static [&'static str; 16] {
+    match kind {
+        LineKind::Light => &LIGHT,
+        LineKind::Double => &DOUBLE,
+        LineKind::Thick => &THICK,
+        LineKind::Ascii => &ASCII,
+    }
+}
+
+// 解析制表符的线型和方向，不是制表符时返回 None
+fn parse_symbol(symbol: &str) -> Option<(LineKind, u8)> {
+    let rounded = match symbol {
+        "╭" => Some(DOWN | RIGHT),
+        "╮" => Some(DOWN | LEFT),
+        "╰" => Some(UP | RIGHT),
+        "╯" => Some(UP | LEFT),
+        // ASCII 的 `+` 无法区分方向，按十字处理
+        "+" => return Some((LineKind::Ascii, UP | DOWN | LEFT | RIGHT)),
+        _ => None,
+    };
+    if let Some(arms) = rounded {
+        return Some((LineKind::Light, arms));
+    }
+    [
+        LineKind::Light,
+        LineKind::Double,
+        LineKind::Thick,
+        LineKind::Ascii,
+    ]
+    .into_iter()
+    .find_map(|kind| {
+        // 同一字符出现多次时取方向最全的组合，如 `─` 为 LEFT | RIGHT
+        let arms = table(kind).iter().rposition(|s| *s == symbol)?;
+        (arms > 0).then_some((kind, arms as u8))
+    })
+}
+
+fn arms_at(buf: &Buffer, x: u16, y: u16) -> Option<u8> {
+    parse_symbol(buf.cell((x, y))?.symbol()).map(|(_, arms)| arms)
+}
+
+// 合并被覆盖的字符和新绘制的字符，线型不同或不是制表符时返回 None
+fn merge_symbols(covered: &str, drawn: &str) -> Option<&'static str> {
+    let (covered_kind, covered_arms) = parse_symbol(covered)?;
+    let (kind, arms) = parse_symbol(drawn)?;
+    if kind != covered_kind || covered_arms | arms == arms {
+        return None;
+    }
+    Some(table(kind)[(covered_arms | arms) as usize])
+}
+
+fn is_border_symbol(symbol: &str, set: &border::Set) -> bool {
+    [
+        set.top_left,
+        set.top_right,
+        set.bottom_left,
+        set.bottom_right,
+        set.vertical_left,
+        set.vertical_right,
+        set.horizontal_top,
+        set.horizontal_bottom,
+    ]
+    .contains(&symbol)
+        || parse_symbol(symbol).is_some()
+}
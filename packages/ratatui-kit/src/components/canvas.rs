@@ -0,0 +1,416 @@
+//! Canvas 组件：基于 ratatui Canvas 的绘图区域，以保留的图形列表描述内容。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(Canvas(
+//!     shapes: vec![
+//!         CanvasShape::rect(10.0, 10.0, 30.0, 20.0, Color::Cyan),
+//!         CanvasShape::line(0.0, 0.0, 100.0, 100.0, Color::Yellow),
+//!         CanvasShape::label(12.0, 25.0, Line::from("hello")),
+//!     ],
+//!     x_bounds: [0.0, 100.0],
+//!     y_bounds: [0.0, 100.0],
+//!     on_click: move |(x, y)| points.write().push((x, y)),
+//! ))
+//! ```
+//! 图形列表、坐标范围和绘制区域都未变化时复用上一次的绘制结果，不重新光栅化。
+//! 标签绘制在其他图形之上。开启鼠标捕获后 `on_click` 收到点击位置的世界坐标。
+
+use std::sync::{Arc, Mutex};
+
+use crossterm::event::{Event, MouseButton, MouseEventKind};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    style::Color,
+    symbols::Marker,
+    text::Line,
+    widgets::{
+        Widget,
+        canvas::{self, Circle, MapResolution, Points, Rectangle},
+    },
+};
+use ratatui_kit_macros::Props;
+
+use crate::{Component, Handler, Hooks, UseEvents};
+
+#[derive(Debug, Clone, PartialEq)]
+/// 画布上的图形，坐标为世界坐标。
+pub enum CanvasShape {
+    Line {
+        x1: f64,
+        y1: f64,
+        x2: f64,
+        y2: f64,
+        color: Color,
+    },
+    Rect {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        color: Color,
+    },
+    Circle {
+        x: f64,
+        y: f64,
+        radius: f64,
+        color: Color,
+    },
+    Points {
+        coords: Vec<(f64, f64)>,
+        color: Color,
+    },
+    /// 世界地图轮廓，坐标为经度（-180~180）和纬度（-90~90）。
+    Map {
+        resolution: MapResolution,
+        color: Color,
+    },
+    /// 文本标签，左端位于 (x, y)。
+    Label { x: f64, y: f64, text: Line<'static> },
+}
+
+impl CanvasShape {
+    pub fn line(x1: f64, y1: f64, x2: f64, y2: f64, color: Color) -> Self {
+        Self::Line {
+            x1,
+            y1,
+            x2,
+            y2,
+            color,
+        }
+    }
+
+    pub fn rect(x: f64, y: f64, width: f64, height: f64, color: Color) -> Self {
+        Self::Rect {
+            x,
+            y,
+            width,
+            height,
+            color,
+        }
+    }
+
+    pub fn circle(x: f64, y: f64, radius: f64, color: Color) -> Self {
+        Self::Circle {
+            x,
+            y,
+            radius,
+            color,
+        }
+    }
+
+    pub fn points(coords: Vec<(f64, f64)>, color: Color) -> Self {
+        Self::Points { coords, color }
+    }
+
+    pub fn map(resolution: MapResolution, color: Color) -> Self {
+        Self::Map { resolution, color }
+    }
+
+    pub fn label(x: f64, y: f64, text: impl Into<Line<'static>>) -> Self {
+        Self::Label {
+            x,
+            y,
+            text: text.into(),
+        }
+    }
+
+    fn paint(&self, ctx: &mut canvas::Context) {
+        match self {
+            Self::Line {
+                x1,
+                y1,
+                x2,
+                y2,
+                color,
+            } => ctx.draw(&canvas::Line::new(*x1, *y1, *x2, *y2, *color)),
+            Self::Rect {
+                x,
+                y,
+                width,
+                height,
+                color,
+            } => ctx.draw(&Rectangle {
+                x: *x,
+                y: *y,
+                width: *width,
+                height: *height,
+                color: *color,
+            }),
+            Self::Circle {
+                x,
+                y,
+                radius,
+                color,
+            } => ctx.draw(&Circle {
+                x: *x,
+                y: *y,
+                radius: *radius,
+                color: *color,
+            }),
+            Self::Points { coords, color } => ctx.draw(&Points {
+                coords,
+                color: *color,
+            }),
+            Self::Map { resolution, color } => ctx.draw(&canvas::Map {
+                resolution: *resolution,
+                color: *color,
+            }),
+            Self::Label { x, y, text } => ctx.print(*x, *y, text.clone()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// 世界坐标与屏幕坐标之间的换算，坐标范围的缩放和平移。
+pub struct CanvasTransform {
+    /// 画布在屏幕上的区域。
+    pub area: Rect,
+    /// 横轴范围。
+    pub x_bounds: [f64; 2],
+    /// 纵轴范围，屏幕上方为最大值。
+    pub y_bounds: [f64; 2],
+}
+
+impl Default for CanvasTransform {
+    fn default() -> Self {
+        Self {
+            area: Rect::default(),
+            x_bounds: [0.0, 1.0],
+            y_bounds: [0.0, 1.0],
+        }
+    }
+}
+
+impl CanvasTransform {
+    pub fn new(area: Rect, x_bounds: [f64; 2], y_bounds: [f64; 2]) -> Self {
+        Self {
+            area,
+            x_bounds,
+            y_bounds,
+        }
+    }
+
+    pub fn width(&self) -> f64 {
+        self.x_bounds[1] - self.x_bounds[0]
+    }
+
+    pub fn height(&self) -> f64 {
+        self.y_bounds[1] - self.y_bounds[0]
+    }
+
+    /// 世界坐标所在的屏幕单元格，超出坐标范围时返回 None。
+    pub fn to_screen(&self, x: f64, y: f64) -> Option<Position> {
+        if self.area.is_empty() || self.width() <= 0.0 || self.height() <= 0.0 {
+            return None;
+        }
+        let rx = (x - self.x_bounds[0]) / self.width();
+        let ry = (self.y_bounds[1] - y) / self.height();
+        if !(0.0..=1.0).contains(&rx) || !(0.0..=1.0).contains(&ry) {
+            return None;
+        }
+        Some(Position::new(
+            self.area.x + (rx * (self.area.width - 1) as f64).round() as u16,
+            self.area.y + (ry * (self.area.height - 1) as f64).round() as u16,
+        ))
+    }
+
+    /// 屏幕单元格中心对应的世界坐标，不在画布区域内时返回 None。
+    pub fn to_world(&self, position: Position) -> Option<(f64, f64)> {
+        if !self.area.contains(position) {
+            return None;
+        }
+        let rx = (position.x - self.area.x) as f64 / (self.area.width - 1).max(1) as f64;
+        let ry = (position.y - self.area.y) as f64 / (self.area.height - 1).max(1) as f64;
+        Some((
+            self.x_bounds[0] + rx * self.width(),
+            self.y_bounds[1] - ry * self.height(),
+        ))
+    }
+
+    /// 以 (x, y) 为中心缩放坐标范围，`factor` 小于 1 时放大。
+    pub fn zoom(&mut self, factor: f64, x: f64, y: f64) {
+        let scale = |[start, end]: [f64; 2], center: f64| {
+            [
+                center - (center - start) * factor,
+                center + (end - center) * factor,
+            ]
+        };
+        self.x_bounds = scale(self.x_bounds, x);
+        self.y_bounds = scale(self.y_bounds, y);
+    }
+
+    /// 平移坐标范围。
+    pub fn pan(&mut self, dx: f64, dy: f64) {
+        self.x_bounds = [self.x_bounds[0] + dx, self.x_bounds[1] + dx];
+        self.y_bounds = [self.y_bounds[0] + dy, self.y_bounds[1] + dy];
+    }
+}
+
+#[derive(Props)]
+/// Canvas 组件属性。
+pub struct CanvasProps {
+    /// 图形列表。
+    pub shapes: Vec<CanvasShape>,
+    /// 横轴范围。
+    pub x_bounds: [f64; 2],
+    /// 纵轴范围。
+    pub y_bounds: [f64; 2],
+    /// 绘制线条和点使用的符号。
+    pub marker: Marker,
+    /// 背景色。
+    pub background_color: Color,
+    /// 点击回调，参数为世界坐标。
+    pub on_click: Handler<'static, (f64, f64)>,
+}
+
+impl Default for CanvasProps {
+    fn default() -> Self {
+        Self {
+            shapes: Vec::new(),
+            x_bounds: [0.0, 100.0],
+            y_bounds: [0.0, 100.0],
+            marker: Marker::Braille,
+            background_color: Color::Reset,
+            on_click: Handler::default(),
+        }
+    }
+}
+
+// 上一次绘制的输入和结果
+struct CanvasCache {
+    shapes: Vec<CanvasShape>,
+    transform: CanvasTransform,
+    marker: Marker,
+    background_color: Color,
+    buffer: Buffer,
+}
+
+/// 绘制图形列表，输入不变时复用上一次的结果，供 Canvas、WorldMap 等组件共用。
+#[derive(Default)]
+pub(crate) struct CanvasRenderer {
+    cache: Option<CanvasCache>,
+}
+
+impl CanvasRenderer {
+    pub fn render(
+        &mut self,
+        shapes: &[CanvasShape],
+        transform: CanvasTransform,
+        marker: Marker,
+        background_color: Color,
+        buf: &mut Buffer,
+    ) {
+        let reusable = self.cache.as_ref().is_some_and(|cache| {
+            cache.transform == transform
+                && cache.marker == marker
+                && cache.background_color == background_color
+                && cache.shapes == shapes
+        });
+        if !reusable {
+            let area = transform.area;
+            let mut buffer = Buffer::empty(area);
+            // 标签在最后一层绘制，位于其他图形之上
+            let (labels, others): (Vec<_>, Vec<_>) = shapes
+                .iter()
+                .partition(|shape| matches!(shape, CanvasShape::Label { .. }));
+            canvas::Canvas::default()
+                .x_bounds(transform.x_bounds)
+                .y_bounds(transform.y_bounds)
+                .marker(marker)
+                .background_color(background_color)
+                .paint(|ctx| {
+                    others.iter().for_each(|shape| shape.paint(ctx));
+                    ctx.layer();
+                    labels.iter().for_each(|shape| shape.paint(ctx));
+                })
+                .render(area, &mut buffer);
+            self.cache = Some(CanvasCache {
+                shapes: shapes.to_vec(),
+                transform,
+                marker,
+                background_color,
+                buffer,
+            });
+        }
+        if let Some(cache) = &self.cache {
+            buf.merge(&cache.buffer);
+        }
+    }
+}
+
+/// Canvas 组件实现。
+pub struct Canvas {
+    shapes: Vec<CanvasShape>,
+    x_bounds: [f64; 2],
+    y_bounds: [f64; 2],
+    marker: Marker,
+    background_color: Color,
+    transform: Arc<Mutex<CanvasTransform>>,
+    renderer: CanvasRenderer,
+}
+
+impl Component for Canvas {
+    type Props<'a> = CanvasProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            shapes: props.shapes.clone(),
+            x_bounds: props.x_bounds,
+            y_bounds: props.y_bounds,
+            marker: props.marker,
+            background_color: props.background_color,
+            transform: Arc::new(Mutex::new(CanvasTransform::default())),
+            renderer: CanvasRenderer::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        _updater: &mut crate::ComponentUpdater,
+    ) {
+        hooks.use_local_events({
+            let transform = self.transform.clone();
+            let mut on_click = props.on_click.take();
+            move |event| {
+                let Event::Mouse(mouse) = event else {
+                    return;
+                };
+                if mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+                    return;
+                }
+                let position = Position::new(mouse.column, mouse.row);
+                let world = transform.lock().unwrap().to_world(position);
+                if let Some(world) = world {
+                    on_click(world);
+                }
+            }
+        });
+
+        // 只在图形列表变化时替换，避免无谓的复制
+        if self.shapes != props.shapes {
+            self.shapes = props.shapes.clone();
+        }
+        self.x_bounds = props.x_bounds;
+        self.y_bounds = props.y_bounds;
+        self.marker = props.marker;
+        self.background_color = props.background_color;
+    }
+
+    fn draw(&mut self, drawer: &mut crate::ComponentDrawer<'_, '_>) {
+        let area = drawer.area;
+        let transform = CanvasTransform::new(area, self.x_bounds, self.y_bounds);
+        *self.transform.lock().unwrap() = transform;
+
+        self.renderer.render(
+            &self.shapes,
+            transform,
+            self.marker,
+            self.background_color,
+            drawer.buffer_mut(),
+        );
+    }
+}
@@ -0,0 +1,365 @@
+//! CommandLine 组件：类似 vim/helix 的 `:` 命令行，占用子元素下方的一行，为子组件提供命令注册表。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(CommandLine {
+//!     Editor()
+//! })
+//!
+//! // 在子组件中
+//! hooks.use_ex_command(Command::new("write", "保存").alias("w"), move |args| {
+//!     save(args.get(0)).map_err(|err| err.to_string())
+//! });
+//! ```
+//! - 按 `:` 打开命令行，`Enter` 执行，`Esc` 取消，`Tab`/`Shift+Tab` 在补全候选项间切换，`↑/↓` 浏览历史。
+//! - 在 ModeProvider 内部时只在普通模式下响应 `:`。
+//! - 执行失败的错误信息和 [`Commands::echo`](crate::Commands::echo) 的消息显示在命令行位置，直到下次打开命令行。
+
+use std::{pin::pin, task::Poll};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers};
+use futures::Stream;
+use ratatui::{
+    layout::Rect,
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::Widget,
+};
+use ratatui_kit_macros::Props;
+
+use crate::{
+    AnyElement, Commands, Component, ComponentDrawer, Context, EventInterest, Hook, InputMode,
+    Mode, TerminalEvents, UseContext,
+};
+
+#[derive(Props)]
+/// CommandLine 组件属性。
+pub struct CommandLineProps<'a> {
+    /// 子元素列表。
+    pub children: Vec<AnyElement<'a>>,
+    /// 打开命令行的字符。
+    pub trigger: char,
+    /// 命令行样式。
+    pub style: Style,
+    /// 错误信息样式。
+    pub error_style: Style,
+    /// 补全候选项样式。
+    pub completion_style: Style,
+    /// 当前补全候选项样式。
+    pub selected_completion_style: Style,
+}
+
+impl Default for CommandLineProps<'_> {
+    fn default() -> Self {
+        Self {
+            children: Vec::new(),
+            trigger: ':',
+            style: Style::default(),
+            error_style: Style::default().red(),
+            completion_style: Style::default().on_dark_gray(),
+            selected_completion_style: Style::default().black().on_yellow(),
+        }
+    }
+}
+
+/// CommandLine 组件实现。
+pub struct CommandLine {
+    commands: Commands,
+    style: Style,
+    error_style: Style,
+    completion_style: Style,
+    selected_completion_style: Style,
+}
+
+impl Component for CommandLine {
+    type Props<'a> = CommandLineProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            commands: Commands::default(),
+            style: props.style,
+            error_style: props.error_style,
+            completion_style: props.completion_style,
+            selected_completion_style: props.selected_completion_style,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: crate::Hooks,
+        updater: &mut crate::ComponentUpdater,
+    ) {
+        let mode = hooks.try_use_context::<Mode>().map(|mode| mode.clone());
+        let host = hooks.use_hook(|| UseCommandLineImpl {
+            commands: Commands::default(),
+            events: None,
+            mode: None,
+            trigger: ':',
+        });
+        host.mode = mode;
+        host.trigger = props.trigger;
+        self.commands = host.commands.clone();
+        self.style = props.style;
+        self.error_style = props.error_style;
+        self.completion_style = props.completion_style;
+        self.selected_completion_style = props.selected_completion_style;
+
+        updater.set_transparent_layout(true);
+        updater.update_children(
+            &mut props.children,
+            Some(Context::owned(self.commands.clone())),
+        );
+    }
+
+    fn draw(&mut self, drawer: &mut ComponentDrawer<'_, '_>) {
+        let area = drawer.area;
+        let shared = self.commands.shared.lock().unwrap();
+        let completions = shared
+            .completion
+            .as_ref()
+            .filter(|completion| completion.candidates.len() > 1);
+        let height = (1 + completions.is_some() as u16).min(area.height);
+        let bottom = Rect {
+            y: area.bottom() - height,
+            height,
+            ..area
+        };
+        let buf = drawer.buffer_mut();
+        buf.set_style(bottom, self.style);
+
+        let line = match (&shared.input, &shared.message) {
+            (Some(input), _) => Line::from(format!(":{input}█")),
+            (None, Some((message, true))) => Line::styled(message.as_str(), self.error_style),
+            (None, Some((message, false))) => Line::from(message.as_str()),
+            (None, None) => Line::default(),
+        };
+        line.render(
+            Rect {
+                y: bottom.bottom().saturating_sub(1),
+                height: 1,
+                ..bottom
+            },
+            buf,
+        );
+        if let Some(completion) = completions.filter(|_| height > 1) {
+            let spans = completion
+                .candidates
+                .iter()
+                .enumerate()
+                .flat_map(|(i, candidate)| {
+                    let style = if i == completion.index {
+                        self.selected_completion_style
+                    } else {
+                        self.completion_style
+                    };
+                    [Span::styled(candidate.clone(), style), Span::raw(" ")]
+                })
+                .collect::<Vec<_>>();
+            Line::from(spans).style(self.completion_style).render(
+                Rect {
+                    height: 1,
+                    ..bottom
+                },
+                buf,
+            );
+        }
+
+        // 子元素占用命令行上方的区域
+        drawer.area = Rect {
+            height: area.height - height,
+            ..area
+        };
+    }
+}
+
+struct UseCommandLineImpl {
+    commands: Commands,
+    events: Option<TerminalEvents<Event>>,
+    mode: Option<Mode>,
+    trigger: char,
+}
+
+// 包含空白或引号的候选项加上引号
+fn quote(candidate: &str) -> String {
+    if candidate.contains(|c: char| c.is_whitespace() || c == '"' || c == '\'') {
+        format!(
+            "\"{}\"",
+            candidate.replace('\\', "\\\\").replace('"', "\\\"")
+        )
+    } else {
+        candidate.to_string()
+    }
+}
+
+impl UseCommandLineImpl {
+    fn handle_event(&mut self, event: Event) {
+        let Event::Key(key) = event else {
+            return;
+        };
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+        let mut shared = self.commands.shared.lock().unwrap();
+        let Some(mut input) = shared.input.clone() else {
+            let is_normal = self
+                .mode
+                .as_ref()
+                .is_none_or(|mode| mode.is(InputMode::Normal));
+            if key.code == KeyCode::Char(self.trigger)
+                && !key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+                && is_normal
+            {
+                drop(shared);
+                self.commands.open("");
+            }
+            return;
+        };
+        match key.code {
+            KeyCode::Tab | KeyCode::BackTab => {
+                let backward = key.code == KeyCode::BackTab;
+                let completion = match shared.completion.take() {
+                    Some(mut completion) if !completion.candidates.is_empty() => {
+                        let len = completion.candidates.len();
+                        completion.index = if backward {
+                            (completion.index + len - 1) % len
+                        } else {
+                            (completion.index + 1) % len
+                        };
+                        completion
+                    }
+                    _ => {
+                        drop(shared);
+                        let mut completion = self.commands.complete(&input);
+                        if backward {
+                            completion.index = completion.candidates.len().saturating_sub(1);
+                        }
+                        shared = self.commands.shared.lock().unwrap();
+                        completion
+                    }
+                };
+                if let Some(candidate) = completion.candidates.get(completion.index) {
+                    shared.input = Some(format!("{}{}", completion.base, quote(candidate)));
+                }
+                shared.completion = Some(completion);
+                shared.wake();
+                return;
+            }
+            KeyCode::Esc => {
+                shared.input = None;
+            }
+            KeyCode::Enter => {
+                let input = shared.input.take().unwrap_or_default();
+                shared.completion = None;
+                shared.wake();
+                drop(shared);
+                let _ = self.commands.execute(&input);
+                return;
+            }
+            KeyCode::Backspace => {
+                // 输入为空时退格关闭命令行，与 vim 一致
+                shared.input = input.pop().map(|_| input);
+            }
+            KeyCode::Up | KeyCode::Down => {
+                let len = shared.history.len();
+                let index = match (shared.history_index, key.code) {
+                    (None, KeyCode::Up) => len.checked_sub(1),
+                    (Some(i), KeyCode::Up) => Some(i.saturating_sub(1)),
+                    (Some(i), _) if i + 1 < len => Some(i + 1),
+                    _ => None,
+                };
+                shared.history_index = index;
+                shared.input = Some(index.map(|i| shared.history[i].clone()).unwrap_or_default());
+            }
+            KeyCode::Char(c) => {
+                input.push(c);
+                shared.input = Some(input);
+            }
+            _ => return,
+        }
+        shared.completion = None;
+        shared.wake();
+    }
+}
+
+impl Hook for UseCommandLineImpl {
+    fn poll_change(self: std::pin::Pin<&mut Self>, cx: &mut std::task::Context) -> Poll<()> {
+        let this = self.get_mut();
+        while let Some(Poll::Ready(Some(event))) = this
+            .events
+            .as_mut()
+            .map(|events| pin!(events).poll_next(cx))
+        {
+            this.handle_event(event);
+        }
+
+        let mut shared = this.commands.shared.lock().unwrap();
+        shared.waker = Some(cx.waker().clone());
+        if std::mem::take(&mut shared.dirty) {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn post_component_update(&mut self, updater: &mut crate::ComponentUpdater) {
+        if self.events.is_none() {
+            self.events = updater.events_of(EventInterest::KEY).ok();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    };
+
+    use crossterm::event::KeyCode;
+
+    use crate::{
+        prelude::*,
+        test_util::{keys, run_events},
+    };
+
+    #[derive(Default, Props)]
+    struct QuitProps {
+        quit: Arc<AtomicBool>,
+    }
+
+    #[component]
+    fn Quit(props: &QuitProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let quit = props.quit.clone();
+        hooks.use_ex_command(Command::new("quit", "退出"), move |_| {
+            quit.store(true, Ordering::SeqCst);
+            Ok(())
+        });
+        element!(View)
+    }
+
+    // 在指定模式下输入 `:quit` 并回车，返回命令是否执行
+    fn run_quit(mode: InputMode) -> bool {
+        let quit = Arc::new(AtomicBool::new(false));
+        let root = element!(ModeProvider(initial_mode: mode) {
+            CommandLine {
+                Quit(quit: quit.clone())
+            }
+        });
+        let codes = ":quit".chars().map(KeyCode::Char).chain([KeyCode::Enter]);
+        run_events(root, keys(codes));
+        quit.load(Ordering::SeqCst)
+    }
+
+    #[test]
+    fn opens_in_normal_mode() {
+        assert!(run_quit(InputMode::Normal));
+    }
+
+    #[test]
+    fn ignores_trigger_in_insert_mode() {
+        assert!(!run_quit(InputMode::Insert));
+    }
+}
@@ -0,0 +1,579 @@
+//! DataTable 组件：数据表格，支持运行时调整列宽、调整列顺序、显示/隐藏列、自定义单元格格式，表头固定在顶部。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(DataTable(
+//!     columns: vec![
+//!         DataColumn::new("名称", 20),
+//!         DataColumn::new("状态", 10)
+//!             .formatter(|value, _row| Cell::from(value.to_string()).green()),
+//!     ],
+//!     rows: vec![vec!["nginx".to_string(), "running".to_string()]],
+//!     is_focus: true,
+//! ))
+//! ```
+//!
+//! 聚焦时支持以下按键：
+//! - `↑/↓`、`k/j`：移动行光标，行选择规则见 [`SelectionState`]
+//! - `←/→`、`h/l`：切换选中列
+//! - `Shift+←/→`、`</>`：移动选中列
+//! - `+/-`：调整选中列宽度
+//! - `x`：隐藏选中列，`X`：显示全部列
+//!
+//! 开启鼠标捕获后，可拖拽表头中的列分隔处调整列宽，点击表头选中列，点击数据行选中行。
+//!
+//! 数据需要按需加载时，`rows` 中未加载的行可先用空行占位，通过 `on_viewport_change` 得知可见行范围后再拉取。
+//!
+//! 单元格内容只能是 ratatui 的 [`Cell`]，不能放置组件元素；需要交互控件时可在表格外根据选中行显示。
+//!
+//! `read_only` 时可以浏览和调整列，但选中行保持不变；`disabled` 时不响应任何事件，并以暗淡样式显示。
+
+use super::SelectionState;
+use crate::{Component, Handler, Hooks, State, UseEffect, UseEvents, UseState, text};
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use ratatui::{
+    layout::{Constraint, Position},
+    style::{Style, Stylize},
+    widgets::{Cell, Row, StatefulWidget, Table, TableState},
+};
+use ratatui_kit_macros::Props;
+use std::{
+    ops::Range,
+    sync::{Arc, Mutex},
+};
+
+/// 单元格格式化函数，参数为单元格文本和行索引，返回绘制用的 [`Cell`]。
+pub type CellFormatter = Arc<dyn Fn(&str, usize) -> Cell<'static> + Send + Sync>;
+
+#[derive(Clone)]
+/// 表格列定义。
+pub struct DataColumn {
+    /// 列标题。
+    pub title: String,
+    /// 初始列宽。
+    pub width: u16,
+    /// 调整列宽时允许的最小宽度。
+    pub min_width: u16,
+    /// 初始是否显示。
+    pub visible: bool,
+    /// 自定义单元格格式，为 None 时直接显示文本。
+    pub formatter: Option<CellFormatter>,
+}
+
+impl DataColumn {
+    /// 创建指定标题和宽度的列。
+    pub fn new(title: impl Into<String>, width: u16) -> Self {
+        Self {
+            title: title.into(),
+            width,
+            min_width: 1,
+            visible: true,
+            formatter: None,
+        }
+    }
+
+    /// 设置最小列宽。
+    pub fn min_width(mut self, min_width: u16) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// 初始隐藏该列。
+    pub fn hidden(mut self) -> Self {
+        self.visible = false;
+        self
+    }
+
+    /// 设置自定义单元格格式，可改变文本和样式，但不能返回组件元素。
+    pub fn formatter<F>(mut self, formatter: F) -> Self
+    where
+        F: Fn(&str, usize) -> Cell<'static> + Send + Sync + 'static,
+    {
+        self.formatter = Some(Arc::new(formatter));
+        self
+    }
+}
+
+#[derive(Props)]
+/// DataTable 组件属性。
+pub struct DataTableProps {
+    /// 列定义。
+    pub columns: Vec<DataColumn>,
+    /// 行数据，每行按列定义的顺序存放单元格文本。
+    pub rows: Vec<Vec<String>>,
+    /// 初始行选择状态，变化时覆盖组件内部状态。
+    pub selection_state: SelectionState,
+    /// 行选择变化回调，参数为按升序排列的全部选中行。
+    pub on_selection_change: Handler<'static, Vec<usize>>,
+    /// 是否聚焦，聚焦时响应按键。
+    pub is_focus: bool,
+    /// 是否只读，只读时不能修改选中行。
+    pub read_only: bool,
+    /// 是否禁用，禁用时忽略 `is_focus`，不响应任何事件。
+    pub disabled: bool,
+    /// 表格整体样式。
+    pub style: Style,
+    /// 表头样式。
+    pub header_style: Style,
+    /// 光标所在行样式。
+    pub highlight_style: Style,
+    /// 选中行样式。
+    pub selected_style: Style,
+    /// 选中列样式。
+    pub selected_column_style: Style,
+    /// 列间距。
+    pub column_spacing: u16,
+    /// 可见行范围变化回调，配合 `use_paginated_source` 按需加载行数据。
+    pub on_viewport_change: Handler<'static, Range<usize>>,
+}
+
+impl Default for DataTableProps {
+    fn default() -> Self {
+        Self {
+            columns: Vec::new(),
+            rows: Vec::new(),
+            selection_state: SelectionState::default(),
+            on_selection_change: Handler::default(),
+            is_focus: false,
+            read_only: false,
+            disabled: false,
+            style: Style::default(),
+            header_style: Style::default(),
+            highlight_style: Style::default(),
+            selected_style: Style::default(),
+            selected_column_style: Style::default(),
+            column_spacing: 1,
+            on_viewport_change: Handler::default(),
+        }
+    }
+}
+
+#[derive(Clone, Default, PartialEq)]
+// 运行时的列布局与选中列
+struct TableViewState {
+    widths: Vec<u16>,
+    min_widths: Vec<u16>,
+    visible: Vec<bool>,
+    // 显示顺序，元素为列索引
+    order: Vec<usize>,
+    // 选中列在 order 中的位置
+    selected_column: usize,
+}
+
+impl TableViewState {
+    fn new(columns: &[DataColumn]) -> Self {
+        let mut state = Self {
+            widths: columns.iter().map(|c| c.width).collect(),
+            min_widths: columns.iter().map(|c| c.min_width).collect(),
+            visible: columns.iter().map(|c| c.visible).collect(),
+            order: (0..columns.len()).collect(),
+            selected_column: 0,
+        };
+        state.select_column(0);
+        state
+    }
+
+    fn visible_columns(&self) -> Vec<usize> {
+        self.order
+            .iter()
+            .copied()
+            .filter(|&i| self.visible[i])
+            .collect()
+    }
+
+    // 选中列在可见列中的位置
+    fn selected_visible_position(&self) -> Option<usize> {
+        let column = *self.order.get(self.selected_column)?;
+        self.visible_columns().iter().position(|&i| i == column)
+    }
+
+    // 按方向查找下一个可见列的位置，delta 为 0 时若当前列不可见则就近查找
+    fn next_visible(&self, from: usize, delta: isize) -> Option<usize> {
+        let len = self.order.len() as isize;
+        let step = if delta < 0 { -1 } else { 1 };
+        let mut pos = from as isize + delta;
+        while (0..len).contains(&pos) {
+            if self.visible[self.order[pos as usize]] {
+                return Some(pos as usize);
+            }
+            pos += step;
+        }
+        None
+    }
+
+    fn select_column(&mut self, delta: isize) -> bool {
+        let next = self
+            .next_visible(self.selected_column, delta)
+            .or_else(|| self.next_visible(self.selected_column, -delta.signum().max(1)));
+        match next {
+            Some(pos) if pos != self.selected_column => {
+                self.selected_column = pos;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn move_column(&mut self, delta: isize) -> bool {
+        match self.next_visible(self.selected_column, delta) {
+            Some(pos) => {
+                self.order.swap(self.selected_column, pos);
+                self.selected_column = pos;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn resize_column(&mut self, column: usize, width: u16) -> bool {
+        let width = width.max(self.min_widths[column]);
+        if self.widths[column] != width {
+            self.widths[column] = width;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn resize_selected(&mut self, delta: i32) -> bool {
+        let Some(&column) = self.order.get(self.selected_column) else {
+            return false;
+        };
+        let width = (self.widths[column] as i32 + delta).clamp(0, u16::MAX as i32) as u16;
+        self.resize_column(column, width)
+    }
+
+    fn hide_selected(&mut self) -> bool {
+        // 至少保留一列可见
+        if self.visible_columns().len() <= 1 {
+            return false;
+        }
+        let column = self.order[self.selected_column];
+        self.visible[column] = false;
+        self.select_column(0);
+        true
+    }
+
+    fn show_all(&mut self) -> bool {
+        if self.visible.iter().all(|v| *v) {
+            return false;
+        }
+        self.visible.iter_mut().for_each(|v| *v = true);
+        true
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        match key.code {
+            KeyCode::Left if shift => self.move_column(-1),
+            KeyCode::Right if shift => self.move_column(1),
+            KeyCode::Char('<') => self.move_column(-1),
+            KeyCode::Char('>') => self.move_column(1),
+            KeyCode::Left | KeyCode::Char('h') => self.select_column(-1),
+            KeyCode::Right | KeyCode::Char('l') => self.select_column(1),
+            KeyCode::Char('+') => self.resize_selected(1),
+            KeyCode::Char('-') => self.resize_selected(-1),
+            KeyCode::Char('x') => self.hide_selected(),
+            KeyCode::Char('X') => self.show_all(),
+            _ => false,
+        }
+    }
+}
+
+#[derive(Default)]
+// 上一次绘制时的表格几何信息，用于鼠标命中判断
+struct TableLayout {
+    header_y: u16,
+    first_row_y: u16,
+    row_offset: usize,
+    // 每个可见列的起止横坐标（不含）和列索引
+    columns: Vec<(u16, u16, usize)>,
+    // 正在拖拽调整宽度的列索引及其起始横坐标
+    resizing: Option<(usize, u16)>,
+}
+
+impl TableLayout {
+    fn handle_mouse(
+        &mut self,
+        event: MouseEvent,
+        view: &mut TableViewState,
+        selection: &mut SelectionState,
+        row_count: usize,
+    ) -> bool {
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) if event.row == self.header_y => {
+                // 列右侧的分隔处开始拖拽调整列宽
+                if let Some(&(start, _, column)) =
+                    self.columns.iter().find(|(_, end, _)| event.column == *end)
+                {
+                    self.resizing = Some((column, start));
+                    return false;
+                }
+                let Some(&(_, _, column)) = self
+                    .columns
+                    .iter()
+                    .find(|(start, end, _)| (*start..*end).contains(&event.column))
+                else {
+                    return false;
+                };
+                let Some(pos) = view.order.iter().position(|&i| i == column) else {
+                    return false;
+                };
+                let changed = view.selected_column != pos;
+                view.selected_column = pos;
+                changed
+            }
+            MouseEventKind::Down(MouseButton::Left) if event.row >= self.first_row_y => {
+                let row = self.row_offset + (event.row - self.first_row_y) as usize;
+                if row < row_count {
+                    selection.handle_click(row, event.modifiers);
+                }
+                false
+            }
+            MouseEventKind::Drag(MouseButton::Left) => match self.resizing {
+                Some((column, start)) => {
+                    view.resize_column(column, event.column.saturating_sub(start))
+                }
+                None => false,
+            },
+            MouseEventKind::Up(MouseButton::Left) => {
+                self.resizing = None;
+                false
+            }
+            _ => false,
+        }
+    }
+}
+
+/// DataTable 组件实现。
+pub struct DataTable {
+    columns: Vec<DataColumn>,
+    rows: Vec<Vec<String>>,
+    view_state: Option<State<TableViewState>>,
+    selection: Option<State<SelectionState>>,
+    table_state: TableState,
+    layout: Arc<Mutex<TableLayout>>,
+    is_focus: bool,
+    disabled: bool,
+    style: Style,
+    header_style: Style,
+    highlight_style: Style,
+    selected_style: Style,
+    selected_column_style: Style,
+    column_spacing: u16,
+    on_viewport_change: Handler<'static, Range<usize>>,
+    viewport: Option<Range<usize>>,
+}
+
+impl Component for DataTable {
+    type Props<'a> = DataTableProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            columns: props.columns.clone(),
+            rows: props.rows.clone(),
+            view_state: None,
+            selection: None,
+            table_state: TableState::default(),
+            layout: Arc::new(Mutex::new(TableLayout::default())),
+            on_viewport_change: Handler::default(),
+            viewport: None,
+            is_focus: props.is_focus && !props.disabled,
+            disabled: props.disabled,
+            style: props.style,
+            header_style: props.header_style,
+            highlight_style: props.highlight_style,
+            selected_style: props.selected_style,
+            selected_column_style: props.selected_column_style,
+            column_spacing: props.column_spacing,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        _updater: &mut crate::ComponentUpdater,
+    ) {
+        let view_state = hooks.use_state(|| TableViewState::new(&props.columns));
+        let mut selection = hooks.use_state(|| props.selection_state.clone());
+
+        hooks.use_effect(
+            || {
+                *selection.write() = props.selection_state.clone();
+            },
+            props.selection_state.clone(),
+        );
+
+        // 行数减少时移除越界的选中行
+        let row_count = props.rows.len();
+        let mut clamped = selection.read().clone();
+        clamped.clamp(row_count);
+        if clamped != *selection.read() {
+            selection.set(clamped);
+        }
+
+        // 列定义变化时重置运行时的列布局
+        hooks.use_effect(
+            || {
+                let state = TableViewState::new(&props.columns);
+                if *view_state.read() != state {
+                    *view_state.write() = state;
+                }
+            },
+            props
+                .columns
+                .iter()
+                .map(|c| (c.title.clone(), c.width, c.min_width, c.visible))
+                .collect::<Vec<_>>(),
+        );
+
+        hooks.use_local_events({
+            let mut view_state = view_state;
+            let layout = self.layout.clone();
+            let mut selection = selection;
+            let is_focus = props.is_focus;
+            let read_only = props.read_only;
+            let disabled = props.disabled;
+            let mut handler = props.on_selection_change.take();
+            move |event| {
+                if disabled {
+                    return;
+                }
+                let mut view = view_state.read().clone();
+                let mut next = selection.read().clone();
+                let changed = match event {
+                    Event::Key(key) if is_focus && key.kind == KeyEventKind::Press => {
+                        view.handle_key(key) || next.handle_key(key, row_count)
+                    }
+                    Event::Mouse(mouse_event) => layout.lock().unwrap().handle_mouse(
+                        mouse_event,
+                        &mut view,
+                        &mut next,
+                        row_count,
+                    ),
+                    _ => false,
+                };
+                if changed {
+                    view_state.set(view);
+                }
+                // 只读时撤销对选中行的修改
+                if read_only && next.selected() != selection.read().selected() {
+                    next = selection.read().clone();
+                }
+                if next != *selection.read() {
+                    let selected = next.selected();
+                    let selection_changed = selected != selection.read().selected();
+                    selection.set(next);
+                    if selection_changed {
+                        handler(selected);
+                    }
+                }
+            }
+        });
+
+        self.columns = props.columns.clone();
+        self.rows = props.rows.clone();
+        self.view_state = Some(view_state);
+        self.selection = Some(selection);
+        self.is_focus = props.is_focus && !props.disabled;
+        self.disabled = props.disabled;
+        self.style = props.style;
+        self.header_style = props.header_style;
+        self.highlight_style = props.highlight_style;
+        self.selected_style = props.selected_style;
+        self.selected_column_style = props.selected_column_style;
+        self.column_spacing = props.column_spacing;
+        self.on_viewport_change = props.on_viewport_change.take();
+    }
+
+    fn draw(&mut self, drawer: &mut crate::ComponentDrawer<'_, '_>) {
+        let (Some(view_state), Some(selection)) = (self.view_state, self.selection) else {
+            return;
+        };
+        let view = view_state.read();
+        let selection = selection.read();
+        let visible_columns = view.visible_columns();
+
+        let header = Row::new(visible_columns.iter().map(|&i| {
+            Cell::from(
+                text::truncate_with_ellipsis(&self.columns[i].title, view.widths[i] as usize)
+                    .into_owned(),
+            )
+        }))
+        .style(self.header_style);
+
+        let rows = self.rows.iter().enumerate().map(|(row_index, row)| {
+            let row = Row::new(visible_columns.iter().map(|&i| {
+                let value = row.get(i).map(String::as_str).unwrap_or_default();
+                match &self.columns[i].formatter {
+                    Some(formatter) => formatter(value, row_index),
+                    // 默认单元格按显示宽度截断，超出列宽时以省略号结尾
+                    None => Cell::from(
+                        text::truncate_with_ellipsis(value, view.widths[i] as usize).into_owned(),
+                    ),
+                }
+            }));
+            if selection.is_selected(row_index) {
+                row.style(self.selected_style)
+            } else {
+                row
+            }
+        });
+
+        let widths = visible_columns
+            .iter()
+            .map(|&i| Constraint::Length(view.widths[i]));
+
+        let table = Table::new(rows, widths)
+            .header(header)
+            .style(self.style)
+            .row_highlight_style(self.highlight_style)
+            .column_highlight_style(self.selected_column_style)
+            .column_spacing(self.column_spacing);
+
+        self.table_state.select(selection.cursor());
+        self.table_state.select_column(if self.is_focus {
+            view.selected_visible_position()
+        } else {
+            None
+        });
+
+        let area = drawer.area;
+        table.render(area, drawer.buffer_mut(), &mut self.table_state);
+        if self.disabled {
+            drawer.buffer_mut().set_style(area, Style::default().dim());
+        }
+
+        // 表头占用一行
+        let offset = self.table_state.offset();
+        let viewport =
+            offset..(offset + area.height.saturating_sub(1) as usize).min(self.rows.len());
+        if self.viewport.as_ref() != Some(&viewport) {
+            self.viewport = Some(viewport.clone());
+            (self.on_viewport_change)(viewport);
+        }
+
+        // 记录几何信息供鼠标交互使用
+        let mut layout = self.layout.lock().unwrap();
+        layout.header_y = area.y;
+        layout.first_row_y = area.y + 1;
+        layout.row_offset = self.table_state.offset();
+        layout.columns.clear();
+        let mut x = area.x;
+        for &i in &visible_columns {
+            let end = x.saturating_add(view.widths[i]).min(area.right());
+            layout.columns.push((x, end, i));
+            x = end.saturating_add(self.column_spacing);
+            if x >= area.right() {
+                break;
+            }
+        }
+        // 鼠标命中坐标不会落在区域之外
+        layout
+            .columns
+            .retain(|(start, _, _)| area.contains(Position::new(*start, area.y)));
+    }
+}
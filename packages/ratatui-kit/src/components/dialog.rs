@@ -0,0 +1,342 @@
+//! DialogProvider 组件：为子组件提供命令式对话框（确认框、输入框），对话框浮于内容之上。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(DialogProvider {
+//!     App()
+//! })
+//!
+//! // 在子组件中
+//! let dialog = hooks.use_dialog();
+//! dialog.spawn({
+//!     let dialog = dialog.clone();
+//!     async move {
+//!         if let Some(name) = dialog.prompt("名称：").await {
+//!             // ...
+//!         }
+//!     }
+//! });
+//! ```
+//! 确认框：`y`/`Enter` 确认，`n`/`Esc` 取消；输入框：`Enter` 确认，`Esc` 取消。
+//! 对话框显示期间子组件中的 `use_events` 等监听器收不到按键和粘贴事件，所有对话框关闭后恢复。
+
+use std::{pin::pin, task::Poll};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind};
+use futures::Stream;
+use ratatui::{
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{Block, Paragraph, Widget, Wrap},
+};
+use ratatui_kit_macros::{Props, element};
+
+use crate::{
+    AnyElement, Component, Context, Dialog, EventInterest, Hook, TerminalEvents,
+    hooks::{DialogKind, DialogRequest},
+};
+
+use super::{ContextProvider, FocusScopeContext};
+
+#[derive(Props)]
+/// DialogProvider 组件属性。
+pub struct DialogProviderProps<'a> {
+    /// 子元素列表。
+    pub children: Vec<AnyElement<'a>>,
+    /// 对话框整体样式。
+    pub style: Style,
+    /// 对话框边框样式。
+    pub border_style: Style,
+    /// 底部按键提示样式。
+    pub hint_style: Style,
+    /// 对话框宽度。
+    pub width: u16,
+    /// 是否在对话框右下方绘制阴影。
+    pub shadow: bool,
+}
+
+impl Default for DialogProviderProps<'_> {
+    fn default() -> Self {
+        Self {
+            children: Vec::new(),
+            style: Style::default(),
+            border_style: Style::default(),
+            hint_style: Style::default().dim(),
+            width: 50,
+            shadow: false,
+        }
+    }
+}
+
+/// DialogProvider 组件实现。
+pub struct DialogProvider;
+
+impl Component for DialogProvider {
+    type Props<'a> = DialogProviderProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: crate::Hooks,
+        updater: &mut crate::ComponentUpdater,
+    ) {
+        let parent = FocusScopeContext::current(updater);
+        let host = hooks.use_hook(|| UseDialogHostImpl {
+            dialog: {
+                let dialog = Dialog::default();
+                dialog.shared.lock().unwrap().scope = FocusScopeContext::nested(parent);
+                dialog
+            },
+            events: None,
+            area: Rect::default(),
+            style: Style::default(),
+            border_style: Style::default(),
+            hint_style: Style::default(),
+            width: 0,
+            shadow: false,
+        });
+        host.style = props.style;
+        host.border_style = props.border_style;
+        host.hint_style = props.hint_style;
+        host.width = props.width;
+        host.shadow = props.shadow;
+        let dialog = host.dialog.clone();
+        let scope = dialog.shared.lock().unwrap().scope.clone();
+
+        // 子组件处于 DialogProvider 的焦点作用域中，对话框显示期间收不到按键
+        updater.set_transparent_layout(true);
+        // 借用子元素，属性保留到下一次更新
+        let children = props.children.iter_mut().map(AnyElement::from);
+        updater.update_children(
+            [
+                element!(ContextProvider(value: Some(Context::owned(scope))) {
+                    #(children)
+                }),
+            ],
+            Some(Context::owned(dialog)),
+        );
+    }
+}
+
+struct UseDialogHostImpl {
+    dialog: Dialog,
+    events: Option<TerminalEvents<Event>>,
+    area: Rect,
+    style: Style,
+    border_style: Style,
+    hint_style: Style,
+    width: u16,
+    shadow: bool,
+}
+
+impl UseDialogHostImpl {
+    // 处理按键，返回是否需要重新渲染
+    fn handle_event(&mut self, event: Event) -> bool {
+        let Event::Key(key) = event else {
+            return false;
+        };
+        if key.kind != KeyEventKind::Press {
+            return false;
+        }
+        let mut shared = self.dialog.shared.lock().unwrap();
+        let Some(request) = shared.queue.front_mut() else {
+            return false;
+        };
+        let response = match (request.kind, key.code) {
+            (DialogKind::Confirm, KeyCode::Char('y' | 'Y') | KeyCode::Enter) => {
+                Some(Some(String::new()))
+            }
+            (DialogKind::Confirm, KeyCode::Char('n' | 'N')) | (_, KeyCode::Esc) => Some(None),
+            (DialogKind::Prompt, KeyCode::Enter) => Some(Some(request.input.clone())),
+            (DialogKind::Prompt, KeyCode::Backspace) => {
+                request.input.pop();
+                None
+            }
+            (DialogKind::Prompt, KeyCode::Char(c)) => {
+                request.input.push(c);
+                None
+            }
+            _ => return false,
+        };
+        if let Some(response) = response {
+            let request = shared.queue.pop_front().unwrap();
+            let _ = request.responder.send(response);
+            let idle = shared.queue.is_empty();
+            shared.scope.set_active(idle);
+        }
+        true
+    }
+
+    fn render_request(&self, request: &DialogRequest, buf: &mut ratatui::buffer::Buffer) {
+        let width = self.width.min(self.area.width);
+        let inner_width = width.saturating_sub(2).max(1);
+        let message_height = request
+            .message
+            .lines()
+            .map(|line| {
+                (Line::from(line).width() as u16)
+                    .div_ceil(inner_width)
+                    .max(1)
+            })
+            .sum::<u16>();
+        let input_height = if request.kind == DialogKind::Prompt {
+            1
+        } else {
+            0
+        };
+        let height = (message_height + input_height + 3).min(self.area.height);
+
+        let [area] = Layout::vertical([Constraint::Length(height)])
+            .flex(Flex::Center)
+            .areas(self.area);
+        let [area] = Layout::horizontal([Constraint::Length(width)])
+            .flex(Flex::Center)
+            .areas(area);
+
+        let block = Block::bordered()
+            .style(self.style)
+            .border_style(self.border_style);
+        let inner = block.inner(area);
+        super::modal::render_surface(area, buf, None, self.shadow);
+        block.render(area, buf);
+
+        let [message_area, input_area, hint_area] = Layout::vertical([
+            Constraint::Fill(1),
+            Constraint::Length(input_height),
+            Constraint::Length(1),
+        ])
+        .areas(inner);
+
+        Paragraph::new(request.message.as_str())
+            .wrap(Wrap { trim: false })
+            .render(message_area, buf);
+
+        let hint = match request.kind {
+            DialogKind::Confirm => "y/Enter 确认  n/Esc 取消",
+            DialogKind::Prompt => {
+                Line::from(format!("> {}█", request.input)).render(input_area, buf);
+                "Enter 确认  Esc 取消"
+            }
+        };
+        Line::styled(hint, self.hint_style)
+            .right_aligned()
+            .render(hint_area, buf);
+    }
+}
+
+impl Hook for UseDialogHostImpl {
+    fn poll_change(self: std::pin::Pin<&mut Self>, cx: &mut std::task::Context) -> Poll<()> {
+        let this = self.get_mut();
+        let mut changed = false;
+
+        while let Some(Poll::Ready(Some(event))) = this
+            .events
+            .as_mut()
+            .map(|events| pin!(events).poll_next(cx))
+        {
+            changed |= this.handle_event(event);
+        }
+
+        // 任务可能会再次调用 spawn，先取出再轮询
+        let mut tasks = std::mem::take(&mut this.dialog.shared.lock().unwrap().tasks);
+        tasks.retain_mut(|task| task.as_mut().poll(cx).is_pending());
+
+        let mut shared = this.dialog.shared.lock().unwrap();
+        shared.tasks.append(&mut tasks);
+        shared.waker = Some(cx.waker().clone());
+        changed |= std::mem::take(&mut shared.dirty);
+
+        if changed {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn post_component_update(&mut self, updater: &mut crate::ComponentUpdater) {
+        if self.events.is_none() {
+            self.events = updater.events_of(EventInterest::KEY).ok();
+        }
+    }
+
+    fn pre_component_draw(&mut self, drawer: &mut crate::ComponentDrawer) {
+        self.area = drawer.area;
+    }
+
+    fn post_component_draw(&mut self, drawer: &mut crate::ComponentDrawer) {
+        // 子组件绘制完成后，在最上层绘制当前对话框
+        let shared = self.dialog.shared.lock().unwrap();
+        if let Some(request) = shared.queue.front() {
+            self.render_request(request, drawer.buffer_mut());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crossterm::event::{Event, KeyCode};
+
+    use crate::{
+        prelude::*,
+        test_util::{keys, mount, send},
+    };
+
+    #[derive(Default, Props)]
+    struct AppProps {
+        seen: Arc<Mutex<Vec<KeyCode>>>,
+        answer: Arc<Mutex<Option<String>>>,
+    }
+
+    // 记录收到的按键，按 p 打开输入框
+    #[component]
+    fn App(props: &AppProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let dialog = hooks.use_dialog();
+        let seen = props.seen.clone();
+        let answer = props.answer.clone();
+        hooks.use_events(move |event| {
+            let Event::Key(key) = event else {
+                return;
+            };
+            seen.lock().unwrap().push(key.code);
+            if key.code == KeyCode::Char('p') {
+                let task_dialog = dialog.clone();
+                let answer = answer.clone();
+                dialog.spawn(async move {
+                    *answer.lock().unwrap() = task_dialog.prompt("名称：").await;
+                });
+            }
+        });
+        element!(Text(text: "app"))
+    }
+
+    #[test]
+    fn open_prompt_captures_keys() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let answer = Arc::new(Mutex::new(None));
+        let mut root = element!(DialogProvider {
+            App(seen: seen.clone(), answer: answer.clone())
+        });
+        let mut app = mount(&mut root);
+        send(
+            &mut app,
+            keys([
+                KeyCode::Char('p'),
+                KeyCode::Char('q'),
+                KeyCode::Enter,
+                KeyCode::Char('x'),
+            ]),
+        );
+        assert_eq!(answer.lock().unwrap().as_deref(), Some("q"));
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![KeyCode::Char('p'), KeyCode::Char('x')]
+        );
+    }
+}
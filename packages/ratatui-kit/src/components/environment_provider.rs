@@ -0,0 +1,62 @@
+//! EnvironmentProvider 组件：为子树提供运行环境（命令行参数、环境变量、工作目录）。
+//!
+//! 组件树默认已提供启动时捕获的进程环境，只有需要替换时才使用本组件，
+//! 例如附加 clap 解析好的参数，或在测试和演示中固定环境变量与工作目录。
+//!
+//! ## 用法示例
+//! ```rust
+//! let environment = Environment::capture()
+//!     .with_var("NO_COLOR", "1")
+//!     .with_cwd("/tmp/project");
+//! element!(EnvironmentProvider(environment: Some(environment)) {
+//!     App()
+//! })
+//! ```
+//! 子组件通过 `hooks.use_environment()` 获取。
+
+use ratatui_kit_macros::Props;
+
+use crate::{AnyElement, Component, Context, Environment};
+
+#[derive(Default, Props)]
+/// EnvironmentProvider 组件属性。
+pub struct EnvironmentProviderProps<'a> {
+    /// 子元素列表。
+    pub children: Vec<AnyElement<'a>>,
+    /// 提供给子树的运行环境，None 时在挂载时捕获当前进程的环境。
+    pub environment: Option<Environment>,
+}
+
+/// EnvironmentProvider 组件实现。
+pub struct EnvironmentProvider {
+    environment: Environment,
+}
+
+impl Component for EnvironmentProvider {
+    type Props<'a> = EnvironmentProviderProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            environment: props
+                .environment
+                .clone()
+                .unwrap_or_else(Environment::capture),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        _hooks: crate::Hooks,
+        updater: &mut crate::ComponentUpdater,
+    ) {
+        if let Some(environment) = &props.environment {
+            self.environment = environment.clone();
+        }
+        updater.set_transparent_layout(true);
+        updater.update_children(
+            props.children.iter_mut(),
+            Some(Context::form_ref(&self.environment)),
+        );
+    }
+}
@@ -0,0 +1,218 @@
+//! 单行输入框共用的编辑状态、校验和绘制逻辑，供 NumberInput、PasswordInput、MaskedInput 使用。
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::Widget,
+};
+
+/// 输入框的编辑状态，光标以字符为单位。
+#[derive(Clone, Default)]
+pub(crate) struct FieldState {
+    pub text: String,
+    pub cursor: usize,
+    // 最近一次从 props 同步的值，props 变化时覆盖编辑中的内容
+    synced: Option<String>,
+    pub error: Option<String>,
+}
+
+impl FieldState {
+    /// props 中的值是否与上次同步的不同，为 true 时需要调用 [`FieldState::sync`]。
+    pub fn needs_sync(&self, value: &str) -> bool {
+        self.synced.as_deref() != Some(value)
+    }
+
+    /// 把 props 中的值同步到编辑内容，并把光标移到末尾。
+    pub fn sync(&mut self, value: &str) {
+        self.synced = Some(value.to_string());
+        self.set_text(value.to_string());
+    }
+
+    /// 标记编辑内容已通过 on_change 提交，避免父组件回传相同的值时重置光标。
+    pub fn commit(&mut self, value: &str) {
+        self.synced = Some(value.to_string());
+    }
+
+    fn byte_index(&self, cursor: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(cursor)
+            .map_or(self.text.len(), |(i, _)| i)
+    }
+
+    pub fn set_text(&mut self, text: String) {
+        self.cursor = text.chars().count();
+        self.text = text;
+    }
+
+    /// 处理通用编辑按键，`accept` 过滤可输入的字符，返回内容是否变化。
+    ///
+    /// 字符只在按下时输入，与其他输入组件一致；退格、删除和光标移动在按住重复时同样生效。
+    pub fn edit(&mut self, key: &KeyEvent, accept: impl Fn(char) -> bool) -> bool {
+        let len = self.text.chars().count();
+        match key.code {
+            KeyCode::Char(c)
+                if key.kind == KeyEventKind::Press
+                    && !key
+                        .modifiers
+                        .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+                    && accept(c) =>
+            {
+                let index = self.byte_index(self.cursor);
+                self.text.insert(index, c);
+                self.cursor += 1;
+                true
+            }
+            KeyCode::Backspace if self.cursor > 0 => {
+                self.cursor -= 1;
+                let index = self.byte_index(self.cursor);
+                self.text.remove(index);
+                true
+            }
+            KeyCode::Delete if self.cursor < len => {
+                let index = self.byte_index(self.cursor);
+                self.text.remove(index);
+                true
+            }
+            KeyCode::Char('u')
+                if key.kind == KeyEventKind::Press && key.modifiers == KeyModifiers::CONTROL =>
+            {
+                let changed = !self.text.is_empty();
+                self.set_text(String::new());
+                changed
+            }
+            _ => {
+                self.navigate(key);
+                false
+            }
+        }
+    }
+
+    /// 处理光标移动按键，只读时也可使用，返回按键是否被处理。
+    pub fn navigate(&mut self, key: &KeyEvent) -> bool {
+        let len = self.text.chars().count();
+        match key.code {
+            KeyCode::Left => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right => self.cursor = (self.cursor + 1).min(len),
+            KeyCode::Home => self.cursor = 0,
+            KeyCode::End => self.cursor = len,
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// 输入框样式。
+#[derive(Clone, Copy)]
+pub(crate) struct FieldStyle {
+    pub label: Style,
+    pub text: Style,
+    pub placeholder: Style,
+    pub cursor: Style,
+    pub error: Style,
+}
+
+/// 待绘制的输入框：标签、显示文本和光标位置（字符索引），有错误时在下一行显示。
+pub(crate) struct Field<'a> {
+    pub label: &'a str,
+    pub display: &'a str,
+    pub placeholder: &'a str,
+    pub cursor: Option<usize>,
+    pub error: Option<&'a str>,
+    pub style: FieldStyle,
+    /// 禁用时整个输入框以暗淡样式显示。
+    pub disabled: bool,
+}
+
+impl Field<'_> {
+    pub fn height(&self) -> u16 {
+        1 + self.error.is_some() as u16
+    }
+
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+        let mut spans = Vec::new();
+        if !self.label.is_empty() {
+            spans.push(Span::styled(format!("{} ", self.label), self.style.label));
+        }
+        if self.display.is_empty() && !self.placeholder.is_empty() {
+            if self.cursor.is_some() {
+                spans.push(Span::styled(" ", self.style.cursor));
+            }
+            spans.push(Span::styled(
+                self.placeholder.to_string(),
+                self.style.placeholder,
+            ));
+        } else {
+            for (i, c) in self.display.chars().enumerate() {
+                let style = if self.cursor == Some(i) {
+                    self.style.cursor
+                } else {
+                    self.style.text
+                };
+                spans.push(Span::styled(c.to_string(), style));
+            }
+            if self.cursor == Some(self.display.chars().count()) {
+                spans.push(Span::styled(" ", self.style.cursor));
+            }
+        }
+        Line::from(spans).render(Rect { height: 1, ..area }, buf);
+
+        if let Some(error) = self.error.filter(|_| area.height > 1) {
+            Line::styled(error.to_string(), self.style.error).render(
+                Rect {
+                    y: area.y + 1,
+                    height: 1,
+                    ..area
+                },
+                buf,
+            );
+        }
+        if self.disabled {
+            buf.set_style(area, Style::default().dim());
+        }
+    }
+}
+
+impl Default for FieldStyle {
+    fn default() -> Self {
+        Self {
+            label: Style::default().bold(),
+            text: Style::default(),
+            placeholder: Style::default().dim(),
+            cursor: Style::default().reversed(),
+            error: Style::default().red(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+
+    use super::FieldState;
+
+    fn key(code: KeyCode, kind: KeyEventKind) -> KeyEvent {
+        KeyEvent {
+            code,
+            modifiers: KeyModifiers::NONE,
+            kind,
+            state: KeyEventState::NONE,
+        }
+    }
+
+    #[test]
+    fn inserts_characters_only_on_press() {
+        let mut field = FieldState::default();
+        assert!(field.edit(&key(KeyCode::Char('a'), KeyEventKind::Press), |_| true));
+        assert!(!field.edit(&key(KeyCode::Char('a'), KeyEventKind::Repeat), |_| true));
+        assert!(!field.edit(&key(KeyCode::Char('a'), KeyEventKind::Release), |_| true));
+        assert!(field.edit(&key(KeyCode::Backspace, KeyEventKind::Repeat), |_| true));
+        assert_eq!(field.text, "");
+    }
+}
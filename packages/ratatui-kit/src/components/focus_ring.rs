@@ -0,0 +1,137 @@
+//! FocusRing 组件：在持有焦点的子孙组件周围绘制高亮边框，让键盘用户始终能看到当前位置。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(FocusRing(kind: BorderKind::Rounded, style: Style::default().yellow()) {
+//!     View(flex_direction: Direction::Vertical, gap: 1, margin: Margin::new(1, 1)) {
+//!         TextArea(value: name.read().clone(), is_focus: focus.get() == 0)
+//!         Slider(value: volume.get(), is_focus: focus.get() == 1)
+//!     }
+//! })
+//! ```
+//! - 子孙组件通过 [`UseFocusRing::use_focus_ring`](crate::UseFocusRing::use_focus_ring) 上报聚焦状态，
+//!   内置的 TextArea、List、Slider、Rating 和 FocusScope 已经接入；
+//! - 多个组件同时聚焦时以最后绘制的为准，因此嵌套的 FocusScope 中内层组件优先；
+//! - 边框画在组件区域外一格，会覆盖相邻的内容，布局时需留出间距；超出 FocusRing 区域的部分画在组件自身的边缘上。
+//!
+//! 与焦点环配套，交互组件约定提供 `focus_style` 属性，聚焦时叠加在组件自身的样式上。
+
+use std::sync::{Arc, Mutex};
+
+use ratatui::{
+    layout::Rect,
+    style::{Style, Stylize},
+    widgets::{Block, Widget},
+};
+use ratatui_kit_macros::Props;
+
+use super::BorderKind;
+use crate::{AnyElement, Component, Context, Hook};
+
+#[derive(Props)]
+/// FocusRing 组件属性。
+pub struct FocusRingProps<'a> {
+    /// 子元素列表。
+    pub children: Vec<AnyElement<'a>>,
+    /// 边框字符集。
+    pub kind: BorderKind,
+    /// 边框样式。
+    pub style: Style,
+}
+
+impl Default for FocusRingProps<'_> {
+    fn default() -> Self {
+        Self {
+            children: Vec::new(),
+            kind: BorderKind::Rounded,
+            style: Style::default().yellow(),
+        }
+    }
+}
+
+/// 焦点环上下文，由 FocusRing 提供给子树，记录本帧中聚焦组件的区域。
+#[derive(Clone, Default)]
+pub struct FocusRingContext {
+    focused: Arc<Mutex<Option<Rect>>>,
+}
+
+impl FocusRingContext {
+    pub(crate) fn report(&self, area: Rect) {
+        *self.focused.lock().unwrap() = Some(area);
+    }
+}
+
+/// FocusRing 组件实现。
+pub struct FocusRing {
+    context: FocusRingContext,
+}
+
+impl Component for FocusRing {
+    type Props<'a> = FocusRingProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self {
+            context: FocusRingContext::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: crate::Hooks,
+        updater: &mut crate::ComponentUpdater,
+    ) {
+        let host = hooks.use_hook(|| UseFocusRingHostImpl {
+            context: FocusRingContext::default(),
+            kind: BorderKind::Rounded,
+            style: Style::default(),
+            area: Rect::default(),
+        });
+        host.context = self.context.clone();
+        host.kind = props.kind;
+        host.style = props.style;
+
+        updater.set_transparent_layout(true);
+        updater.update_children(
+            props.children.iter_mut(),
+            Some(Context::owned(self.context.clone())),
+        );
+    }
+}
+
+struct UseFocusRingHostImpl {
+    context: FocusRingContext,
+    kind: BorderKind,
+    style: Style,
+    // FocusRing 自身的区域，边框不超出该区域
+    area: Rect,
+}
+
+impl Hook for UseFocusRingHostImpl {
+    fn pre_component_draw(&mut self, drawer: &mut crate::ComponentDrawer) {
+        // 每帧重新收集，没有组件上报时不绘制
+        self.area = drawer.area;
+        *self.context.focused.lock().unwrap() = None;
+    }
+
+    fn post_component_draw(&mut self, drawer: &mut crate::ComponentDrawer) {
+        let Some(focused) = self.context.focused.lock().unwrap().take() else {
+            return;
+        };
+        let ring = Rect {
+            x: focused.x.saturating_sub(1),
+            y: focused.y.saturating_sub(1),
+            width: focused.width + 2,
+            height: focused.height + 2,
+        }
+        .intersection(self.area)
+        .intersection(drawer.buffer_mut().area);
+        if ring.width < 2 || ring.height < 2 {
+            return;
+        }
+        Block::bordered()
+            .border_set(self.kind.set())
+            .border_style(self.style)
+            .render(ring, drawer.buffer_mut());
+    }
+}
@@ -0,0 +1,143 @@
+//! FocusScope 组件：按焦点路由按键事件，只有处于焦点链上的子树才能收到按键。
+//!
+//! 默认情况下每个 `use_events` 都会收到每一次按键，多个页面或面板同时挂载时容易互相干扰。
+//! 用 FocusScope 包裹子树即可对该子树开启按焦点路由：
+//! - 子树内的 `use_events`、`use_local_events`、`use_keymap` 只在作用域处于焦点链上时收到按键和粘贴事件；
+//! - 作用域可以嵌套，从根到内层的每一层都 `is_focus` 时内层才处于焦点链上；
+//! - 不在任何 FocusScope 中的监听器是全局监听器，始终收到全部事件；
+//! - 鼠标、尺寸变化等事件不受影响。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(View(flex_direction: Direction::Horizontal) {
+//!     FocusScope(is_focus: focus.get() == Pane::Files) {
+//!         FileTree
+//!     }
+//!     FocusScope(is_focus: focus.get() == Pane::Editor) {
+//!         Editor
+//!     }
+//! })
+//! ```
+
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+
+use crossterm::event::Event;
+use ratatui_kit_macros::Props;
+
+use crate::{AnyElement, Component, ComponentUpdater, Context, UseFocusRing};
+
+#[derive(Default, Props)]
+/// FocusScope 组件属性。
+pub struct FocusScopeProps<'a> {
+    /// 子元素列表。
+    pub children: Vec<AnyElement<'a>>,
+    /// 是否聚焦，外层作用域也聚焦时子树才能收到按键。
+    pub is_focus: bool,
+}
+
+/// 焦点作用域上下文，由 FocusScope 提供给子树。
+#[derive(Clone, Default)]
+pub struct FocusScopeContext {
+    active: Arc<AtomicBool>,
+    parent: Option<Box<FocusScopeContext>>,
+    // 由 Modal 提供的作用域，是否处于焦点链上由弹窗栈决定
+    modal: bool,
+}
+
+impl FocusScopeContext {
+    /// 弹窗内容的作用域，`parent` 为弹窗所在的作用域，入栈后才处于焦点链上。
+    pub(crate) fn modal(parent: Option<Self>) -> Self {
+        Self {
+            active: Arc::new(AtomicBool::new(false)),
+            parent: parent.map(Box::new),
+            modal: true,
+        }
+    }
+
+    /// 嵌套在 `parent` 中、初始处于焦点链上的作用域，供 DialogProvider 等组件在内部控制子树是否接收按键。
+    pub(crate) fn nested(parent: Option<Self>) -> Self {
+        Self {
+            active: Arc::new(AtomicBool::new(true)),
+            parent: parent.map(Box::new),
+            modal: false,
+        }
+    }
+
+    /// 外层作用域，不在任何作用域中时返回始终处于焦点链上的根作用域，其中的监听器与全局监听器一样收到按键。
+    pub(crate) fn outer(&self) -> Self {
+        self.parent.as_deref().cloned().unwrap_or_else(|| Self {
+            active: Arc::new(AtomicBool::new(true)),
+            parent: None,
+            modal: false,
+        })
+    }
+
+    pub(crate) fn set_active(&self, active: bool) {
+        self.active.store(active, Ordering::Relaxed);
+    }
+
+    /// 作用域是否处于焦点链上，外层作用域失去焦点后内层立即随之失去焦点。
+    pub fn is_active(&self) -> bool {
+        self.chain_active(false)
+    }
+
+    // 嵌套在弹窗中的弹窗打开时位于外层弹窗之上，外层弹窗的状态不影响内层，
+    // 因此经过一个弹窗作用域后忽略更外层的弹窗作用域
+    fn chain_active(&self, inside_modal: bool) -> bool {
+        let own = (self.modal && inside_modal) || self.active.load(Ordering::Relaxed);
+        own && self
+            .parent
+            .as_ref()
+            .is_none_or(|parent| parent.chain_active(inside_modal || self.modal))
+    }
+
+    /// 当前组件所在的焦点作用域，不在任何作用域中时返回 None。
+    pub fn current(updater: &ComponentUpdater) -> Option<Self> {
+        updater
+            .get_context::<FocusScopeContext>()
+            .map(|scope| scope.clone())
+    }
+
+    /// 事件是否应该分发给 `scope` 中的监听器：按键和粘贴事件只分发给焦点链上的作用域。
+    pub(crate) fn accepts(scope: Option<&Self>, event: &Event) -> bool {
+        match event {
+            Event::Key(_) | Event::Paste(_) => scope.is_none_or(Self::is_active),
+            _ => true,
+        }
+    }
+}
+
+/// FocusScope 组件实现。
+pub struct FocusScope {
+    context: FocusScopeContext,
+}
+
+impl Component for FocusScope {
+    type Props<'a> = FocusScopeProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self {
+            context: FocusScopeContext::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: crate::Hooks,
+        updater: &mut ComponentUpdater,
+    ) {
+        self.context.parent = FocusScopeContext::current(updater).map(Box::new);
+        self.context.set_active(props.is_focus);
+        hooks.use_focus_ring(self.context.is_active());
+
+        updater.set_transparent_layout(true);
+        updater.update_children(
+            props.children.iter_mut(),
+            Some(Context::owned(self.context.clone())),
+        );
+    }
+}
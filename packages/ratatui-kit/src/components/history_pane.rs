@@ -0,0 +1,214 @@
+//! History 组件：内联视口应用（REPL、聊天客户端等）的历史输出区。
+//!
+//! ## 用法示例
+//! ```rust
+//! let history = hooks.use_history();
+//! // 提交输入时记录，History 会把它插入到内联视口上方
+//! history.push(Line::from(format!("> {input}")));
+//!
+//! element!(History(history: Some(history.clone()), height: 3u16) {
+//!     TextArea(...)
+//! })
+//! ```
+//! 调用 `history.open_viewer()` 后视口扩展到整个终端并显示全部记录，
+//! `↑/↓`、`PageUp/PageDown`、`Home/End` 滚动，`Esc` 或 `q` 关闭并恢复 `height`。
+
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind};
+use ratatui::{
+    layout::Rect,
+    style::{Style, Stylize},
+    text::{Line, Text},
+    widgets::{Block, Borders, Clear, Paragraph, Widget, Wrap},
+};
+use ratatui_kit_macros::Props;
+
+use crate::{
+    AnyElement, Component, ComponentDrawer, ComponentUpdater, EventInterest, HistoryHandle, Hooks,
+    Terminal, UseEvents,
+};
+
+#[derive(Props)]
+/// History 组件属性。
+pub struct HistoryProps<'a> {
+    /// 历史记录句柄，通常由 `use_history` 创建。
+    pub history: Option<HistoryHandle>,
+    /// 内联视口中的实时内容。
+    pub children: Vec<AnyElement<'a>>,
+    /// 实时内容需要的视口高度，关闭回看视图时恢复到该高度，为 0 时不调整。
+    pub height: u16,
+    /// 回看视图标题。
+    pub title: String,
+    /// 回看视图样式。
+    pub viewer_style: Style,
+    /// 回看视图边框样式。
+    pub border_style: Style,
+}
+
+impl Default for HistoryProps<'_> {
+    fn default() -> Self {
+        Self {
+            history: None,
+            children: Vec::new(),
+            height: 0,
+            title: "历史记录".to_string(),
+            viewer_style: Style::default(),
+            border_style: Style::default().dim(),
+        }
+    }
+}
+
+/// History 组件实现。
+pub struct History {
+    history: HistoryHandle,
+    seen: u64,
+    viewer_open: bool,
+    title: String,
+    viewer_style: Style,
+    border_style: Style,
+}
+
+// 按宽度折行后的行数
+fn wrapped_height(text: &Text, width: u16) -> u16 {
+    let width = width.max(1) as usize;
+    text.lines
+        .iter()
+        .map(|line| line.width().max(1).div_ceil(width) as u16)
+        .sum()
+}
+
+fn emit_pending(history: &HistoryHandle, terminal: &mut Terminal) {
+    let pending = history
+        .inner
+        .lock()
+        .unwrap()
+        .pending
+        .drain(..)
+        .collect::<Vec<_>>();
+    let width = terminal.size().map_or(80, |size| size.width);
+    for entry in pending {
+        let height = wrapped_height(&entry, width);
+        let _ = terminal.insert_before(height, move |buf| {
+            Paragraph::new(entry)
+                .wrap(Wrap { trim: false })
+                .render(buf.area, buf);
+        });
+    }
+}
+
+impl Component for History {
+    type Props<'a> = HistoryProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            history: props.history.clone().unwrap_or_default(),
+            seen: 0,
+            viewer_open: false,
+            title: props.title.clone(),
+            viewer_style: props.viewer_style,
+            border_style: props.border_style,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater,
+    ) {
+        if let Some(history) = &props.history {
+            self.history = history.clone();
+        }
+        self.title = props.title.clone();
+        self.viewer_style = props.viewer_style;
+        self.border_style = props.border_style;
+
+        hooks.use_events_of(EventInterest::KEY, {
+            let history = self.history.clone();
+            move |event| {
+                let Event::Key(key) = event else {
+                    return;
+                };
+                if key.kind != KeyEventKind::Press || !history.is_viewer_open() {
+                    return;
+                }
+                history.update(|inner| match key.code {
+                    KeyCode::Up | KeyCode::Char('k') => inner.scroll += 1,
+                    KeyCode::Down | KeyCode::Char('j') => {
+                        inner.scroll = inner.scroll.saturating_sub(1)
+                    }
+                    KeyCode::PageUp => inner.scroll += 10,
+                    KeyCode::PageDown => inner.scroll = inner.scroll.saturating_sub(10),
+                    KeyCode::Home | KeyCode::Char('g') => inner.scroll = usize::MAX,
+                    KeyCode::End | KeyCode::Char('G') => inner.scroll = 0,
+                    KeyCode::Esc | KeyCode::Char('q') => inner.viewer_open = false,
+                    _ => {}
+                });
+            }
+        });
+
+        self.viewer_open = self.history.is_viewer_open();
+        updater.with_terminal(|terminal| {
+            emit_pending(&self.history, terminal);
+            if self.viewer_open {
+                if let Ok(size) = terminal.size() {
+                    terminal.request_inline_height(size.height);
+                }
+            } else if props.height > 0 {
+                terminal.request_inline_height(props.height);
+            }
+        });
+
+        updater.set_transparent_layout(true);
+        updater.update_children(props.children.iter_mut(), None);
+    }
+
+    fn draw(&mut self, drawer: &mut ComponentDrawer<'_, '_>) {
+        if !self.viewer_open {
+            return;
+        }
+        let area = drawer.area;
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(self.border_style)
+            .title(Line::from(self.title.as_str()))
+            .title_bottom(Line::from("↑/↓ 滚动，Esc 关闭").right_aligned());
+        let inner_area = block.inner(area);
+
+        let entries = self.history.entries();
+        let text = Text::from(
+            entries
+                .into_iter()
+                .flat_map(|entry| entry.lines)
+                .collect::<Vec<_>>(),
+        );
+        let total = wrapped_height(&text, inner_area.width) as usize;
+        let max_scroll = total.saturating_sub(inner_area.height as usize);
+        let scroll = {
+            let mut inner = self.history.inner.lock().unwrap();
+            inner.scroll = inner.scroll.min(max_scroll);
+            inner.scroll
+        };
+        let offset = (max_scroll - scroll).min(u16::MAX as usize) as u16;
+
+        Clear.render(area, drawer.buffer_mut());
+        Paragraph::new(text)
+            .style(self.viewer_style)
+            .wrap(Wrap { trim: false })
+            .scroll((offset, 0))
+            .block(block)
+            .render(area, drawer.buffer_mut());
+
+        // 回看视图打开时子元素不占用区域，状态保持不变
+        drawer.area = Rect::default();
+    }
+
+    fn poll_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let this = self.get_mut();
+        this.history.poll_changed(&mut this.seen, cx)
+    }
+}
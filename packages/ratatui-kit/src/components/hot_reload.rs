@@ -0,0 +1,167 @@
+//! HotReload 组件：开发时监视外部文件或动态库，变化后用新内容重新渲染子树，无需重启终端会话。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(HotReload(path: "ui/main.json".into()) {
+//!     |source: String| element!(View {
+//!         $Paragraph::new(source)
+//!     })
+//! })
+//!
+//! // 动态库中导出 `#[unsafe(no_mangle)] pub fn render() -> AnyElement<'static>`
+//! element!(HotReloadLibrary(path: "target/debug/libui.so".into()))
+//! ```
+//! - 通过 `watch` 特性的文件监视检测变化，`path` 改变时立即读取新文件。
+//! - 文件在后台线程读取，不会阻塞渲染循环。
+//! - 只替换本组件下的子树，上层组件的状态和全局 Store 保持不变。
+//! - 读取或加载失败时保留上一次成功的结果，首次成功前显示 `fallback`。
+
+use std::{
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use ratatui_kit_macros::{Props, component, element};
+
+use super::Fragment;
+use crate::{AnyElement, Hooks, RenderProp, UseEffect, UseState, UseWatchFile};
+
+#[derive(Default, Props)]
+/// HotReload 组件属性。
+pub struct HotReloadProps<'a> {
+    /// 监视的文件路径。
+    pub path: PathBuf,
+    /// 用文件内容渲染子树。
+    pub children: RenderProp<'a, String>,
+    /// 首次读取成功前显示的内容。
+    pub fallback: Option<AnyElement<'a>>,
+}
+
+#[component]
+pub fn HotReload<'a>(
+    props: &mut HotReloadProps<'a>,
+    mut hooks: Hooks,
+) -> impl Into<AnyElement<'a>> {
+    let mut version = hooks.use_state(|| 0u64);
+    // 记录内容对应的路径，路径改变后在新文件读取成功前显示 fallback
+    let mut source = hooks.use_state(|| None::<(PathBuf, String)>);
+    hooks.use_watch_file(&props.path, move |_| version += 1);
+
+    let path = props.path.clone();
+    hooks.use_async_effect(
+        async move {
+            let read_path = path.clone();
+            let Ok(content) = blocking::unblock(move || std::fs::read_to_string(read_path)).await
+            else {
+                return;
+            };
+            let unchanged = source
+                .read()
+                .as_ref()
+                .is_some_and(|(current, old)| *current == path && *old == content);
+            if !unchanged {
+                source.set(Some((path, content)));
+            }
+        },
+        (&props.path, version.get()),
+    );
+
+    let content = match source.read().as_ref() {
+        Some((path, source)) if *path == props.path => props.children.render(source.clone()),
+        _ => props.fallback.take(),
+    };
+
+    element!(Fragment {
+        #(content)
+    })
+}
+
+/// 动态库导出的渲染函数，签名为 `fn() -> AnyElement<'static>`。
+pub type HotReloadRender = fn() -> AnyElement<'static>;
+
+#[derive(Props)]
+/// HotReloadLibrary 组件属性。
+pub struct HotReloadLibraryProps<'a> {
+    /// 动态库路径，如 `target/debug/libui.so`。
+    pub path: PathBuf,
+    /// 导出的渲染函数名，缺省为 `render`。
+    pub symbol: String,
+    /// 首次加载成功前显示的内容。
+    pub fallback: Option<AnyElement<'a>>,
+}
+
+impl Default for HotReloadLibraryProps<'_> {
+    fn default() -> Self {
+        Self {
+            path: PathBuf::new(),
+            symbol: "render".to_string(),
+            fallback: None,
+        }
+    }
+}
+
+/// 监视动态库，重新编译后加载新版本并用其导出的渲染函数替换子树。
+///
+/// 动态库必须与应用使用相同的编译器和 ratatui-kit 版本构建，导出的函数签名见 [`HotReloadRender`]。
+/// 每次加载都会重新挂载子树，子树内组件的局部状态会重置，放在 Store 或上层组件中的状态保持不变。
+/// 旧版本的动态库不会被卸载，以免仍在使用的代码失效，适合仅在开发时使用。
+#[component]
+pub fn HotReloadLibrary<'a>(
+    props: &mut HotReloadLibraryProps<'a>,
+    mut hooks: Hooks,
+) -> impl Into<AnyElement<'a>> {
+    let mut version = hooks.use_state(|| 0u64);
+    let mut loaded = hooks.use_state(|| None::<(PathBuf, u64, HotReloadRender)>);
+    hooks.use_watch_file(&props.path, move |_| version += 1);
+
+    let path = props.path.clone();
+    let symbol = props.symbol.clone();
+    hooks.use_async_effect(
+        async move {
+            let load_path = path.clone();
+            let library = blocking::unblock(move || load_library(&load_path, &symbol)).await;
+            if let Some((generation, render)) = library {
+                loaded.set(Some((path, generation, render)));
+            }
+        },
+        (&props.path, &props.symbol, version.get()),
+    );
+
+    let content = match *loaded.read() {
+        // 以加载序号作为 key，使新版本的组件重新挂载而不是复用旧版本的实例
+        Some((ref path, generation, render)) if *path == props.path => Some(
+            element!(Fragment(key: generation) {
+                #(render())
+            })
+            .into_any(),
+        ),
+        _ => props.fallback.take(),
+    };
+
+    element!(Fragment {
+        #(content)
+    })
+}
+
+// 复制到临时文件后加载，避免系统按路径缓存已加载的库，也避免占用构建产物导致无法覆盖。
+// 返回进程内唯一的加载序号和渲染函数。
+fn load_library(path: &Path, symbol: &str) -> Option<(u64, HotReloadRender)> {
+    static LOADS: AtomicU64 = AtomicU64::new(0);
+    let generation = LOADS.fetch_add(1, Ordering::Relaxed);
+    let file_name = path.file_name()?.to_string_lossy();
+    let copy = std::env::temp_dir().join(format!(
+        "ratatui-kit-{}-{generation}-{file_name}",
+        std::process::id()
+    ));
+    std::fs::copy(path, &copy).ok()?;
+    // SAFETY: 加载开发者指定的动态库，要求其与应用使用相同的编译器和依赖版本构建
+    let library = unsafe { libloading::Library::new(&copy) };
+    // 已加载的库不依赖文件本身，删除失败（如 Windows 上文件被占用）时留给系统清理
+    let _ = std::fs::remove_file(&copy);
+    let library = library.ok()?;
+    // SAFETY: 导出函数的签名约定为 HotReloadRender
+    let render = unsafe { *library.get::<HotReloadRender>(symbol.as_bytes()).ok()? };
+    // 已渲染的元素和组件可能仍引用旧库中的代码，不卸载
+    std::mem::forget(library);
+    Some((generation, render))
+}
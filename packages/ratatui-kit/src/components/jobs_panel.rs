@@ -0,0 +1,99 @@
+//! JobsPanel 组件：列出通过 `use_job` 启动的后台任务及其进度。
+//!
+//! ## 用法示例
+//! ```rust
+//! let job = hooks.use_job("索引文件", |progress| async move { ... });
+//!
+//! element!(View(flex_direction: Direction::Vertical) {
+//!     MainContent()
+//!     JobsPanel(linger: Duration::from_secs(5))
+//! })
+//! ```
+//! 正在执行和失败的任务一直显示，完成或取消的任务在 `linger` 时间后隐藏。
+//! 面板高度等于显示的任务数，没有任务时不占空间。
+
+use std::time::{Duration, Instant};
+
+use futures_timer::Delay;
+use ratatui::style::{Style, Stylize};
+use ratatui_kit_macros::{Props, component, element};
+
+use super::{ProgressBar, Text, View};
+use crate::{AnyElement, Hooks, JobInfo, JobState, UseJob, UseResource};
+
+#[derive(Props)]
+/// JobsPanel 组件属性。
+pub struct JobsPanelProps {
+    /// 已完成或取消的任务继续显示的时间。
+    pub linger: Duration,
+    /// 进度条已完成部分样式。
+    pub filled_style: Style,
+}
+
+impl Default for JobsPanelProps {
+    fn default() -> Self {
+        Self {
+            linger: Duration::from_secs(3),
+            filled_style: Style::default().green(),
+        }
+    }
+}
+
+#[component]
+pub fn JobsPanel(props: &JobsPanelProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let now = Instant::now();
+    let linger = props.linger;
+    let jobs = hooks.use_jobs();
+    let expired = |job: &JobInfo| {
+        matches!(job.state, JobState::Done | JobState::Cancelled)
+            && job
+                .finished_at
+                .is_some_and(|at| now.duration_since(at) >= linger)
+    };
+
+    // 到下一个任务过期时重新渲染，把它从面板中移除
+    let next_expiry = jobs
+        .iter()
+        .filter(|job| !expired(job) && matches!(job.state, JobState::Done | JobState::Cancelled))
+        .filter_map(|job| job.finished_at)
+        .map(|at| at + linger)
+        .min();
+    hooks.use_resource(
+        async move {
+            if let Some(at) = next_expiry {
+                Delay::new(at.saturating_duration_since(Instant::now())).await;
+            }
+        },
+        next_expiry,
+    );
+
+    let rows = jobs
+        .into_iter()
+        .filter(|job| !expired(job))
+        .map(|job| {
+            let (status, style) = match &job.state {
+                JobState::Failed(err) => (format!("失败：{err}"), Style::default().red()),
+                JobState::Done => ("完成".to_string(), Style::default().green()),
+                JobState::Cancelled => ("已取消".to_string(), Style::default().dim()),
+                _ => (job.status.clone(), Style::default().dim()),
+            };
+            element!(View(flex_direction: ratatui::layout::Direction::Horizontal, gap: 1) {
+                ProgressBar(
+                    progress: job.progress,
+                    label: job.name,
+                    filled_style: props.filled_style,
+                )
+                Text(text: status, style: style, wrap: false)
+            })
+            .into_any()
+        })
+        .collect::<Vec<_>>();
+    let height = rows.len() as u16;
+
+    element!(View(
+        flex_direction: ratatui::layout::Direction::Vertical,
+        height: ratatui::layout::Constraint::Length(height),
+    ) {
+        #(rows)
+    })
+}
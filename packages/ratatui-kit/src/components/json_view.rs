@@ -0,0 +1,206 @@
+//! JsonView 组件：基于 TreeView 的 JSON 结构查看器，按类型着色，底部显示光标所在节点的路径。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(JsonView(
+//!     value: serde_json::json!({ "name": "ratatui-kit", "tags": ["tui", "react"] }),
+//!     is_focus: true,
+//! ))
+//! ```
+//! 除 TreeView 的按键外，`y` 复制光标所在节点的值，`Y` 复制其路径（通过 OSC 52 写入剪贴板）。
+
+use ratatui::{
+    layout::{Constraint, Direction},
+    style::{Style, Stylize},
+    text::{Line, Span},
+};
+use ratatui_kit_macros::{Props, component, element};
+use serde_json::Value;
+
+use super::{TreeNode, TreeView, View};
+use crate::{AnyElement, EventInterest, Hooks, UseEvents, UseState, copy_to_clipboard};
+use crossterm::event::{Event, KeyCode, KeyEventKind};
+
+#[derive(Props)]
+/// JsonView 组件属性。
+pub struct JsonViewProps {
+    /// 要显示的 JSON 数据。
+    pub value: Value,
+    /// 是否聚焦，聚焦时响应按键。
+    pub is_focus: bool,
+    /// 对象键样式。
+    pub key_style: Style,
+    /// 字符串样式。
+    pub string_style: Style,
+    /// 数字样式。
+    pub number_style: Style,
+    /// 布尔值样式。
+    pub bool_style: Style,
+    /// null 样式。
+    pub null_style: Style,
+    /// 对象/数组摘要样式。
+    pub summary_style: Style,
+    /// 光标所在行样式。
+    pub highlight_style: Style,
+    /// 底部路径栏样式。
+    pub status_style: Style,
+}
+
+impl Default for JsonViewProps {
+    fn default() -> Self {
+        Self {
+            value: Value::Null,
+            is_focus: false,
+            key_style: Style::default().cyan(),
+            string_style: Style::default().green(),
+            number_style: Style::default().yellow(),
+            bool_style: Style::default().magenta(),
+            null_style: Style::default().dim(),
+            summary_style: Style::default().dim(),
+            highlight_style: Style::default().reversed(),
+            status_style: Style::default().dim(),
+        }
+    }
+}
+
+struct JsonStyles {
+    key: Style,
+    string: Style,
+    number: Style,
+    bool: Style,
+    null: Style,
+    summary: Style,
+}
+
+fn value_span(value: &Value, styles: &JsonStyles) -> Span<'static> {
+    match value {
+        Value::Null => Span::styled("null", styles.null),
+        Value::Bool(b) => Span::styled(b.to_string(), styles.bool),
+        Value::Number(n) => Span::styled(n.to_string(), styles.number),
+        Value::String(s) => Span::styled(format!("{s:?}"), styles.string),
+        Value::Array(items) => Span::styled(format!("[{}]", items.len()), styles.summary),
+        Value::Object(map) => Span::styled(format!("{{{}}}", map.len()), styles.summary),
+    }
+}
+
+fn build_node(key: Option<String>, value: &Value, styles: &JsonStyles) -> TreeNode {
+    let mut spans = Vec::new();
+    if let Some(key) = key {
+        spans.push(Span::styled(key, styles.key));
+        spans.push(Span::raw(": "));
+    }
+    spans.push(value_span(value, styles));
+    let children = match value {
+        Value::Array(items) => items
+            .iter()
+            .enumerate()
+            .map(|(i, v)| build_node(Some(i.to_string()), v, styles))
+            .collect(),
+        Value::Object(map) => map
+            .iter()
+            .map(|(k, v)| build_node(Some(format!("{k:?}")), v, styles))
+            .collect(),
+        _ => Vec::new(),
+    };
+    TreeNode::new(Line::from(spans)).with_children(children)
+}
+
+/// 按 TreeView 的索引路径查找 JSON 节点，返回节点值及其 JSONPath 风格的路径。
+fn resolve<'a>(root: &'a Value, path: &[usize]) -> Option<(&'a Value, String)> {
+    // 第一个索引对应唯一的根节点
+    let (_, rest) = path.split_first()?;
+    let mut value = root;
+    let mut text = String::from("$");
+    for &index in rest {
+        value = match value {
+            Value::Array(items) => {
+                text.push_str(&format!("[{index}]"));
+                items.get(index)?
+            }
+            Value::Object(map) => {
+                let (key, child) = map.iter().nth(index)?;
+                if !key.is_empty() && key.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                    text.push_str(&format!(".{key}"));
+                } else {
+                    text.push_str(&format!("[{key:?}]"));
+                }
+                child
+            }
+            _ => return None,
+        };
+    }
+    Some((value, text))
+}
+
+#[component]
+pub fn JsonView<'a>(props: &mut JsonViewProps, mut hooks: Hooks) -> impl Into<AnyElement<'a>> {
+    let mut cursor = hooks.use_state(|| vec![0usize]);
+    let mut message = hooks.use_state(|| None::<String>);
+
+    let styles = JsonStyles {
+        key: props.key_style,
+        string: props.string_style,
+        number: props.number_style,
+        bool: props.bool_style,
+        null: props.null_style,
+        summary: props.summary_style,
+    };
+    let root = build_node(None, &props.value, &styles).expanded();
+
+    let resolved = resolve(&props.value, &cursor.read());
+    let path = resolved
+        .as_ref()
+        .map(|(_, path)| path.clone())
+        .unwrap_or_else(|| "$".to_string());
+    // 复制的值：字符串复制原文，其余复制格式化后的 JSON
+    let copy_value = resolved.map(|(value, _)| match value {
+        Value::String(s) => s.clone(),
+        value => serde_json::to_string_pretty(value).unwrap_or_default(),
+    });
+
+    hooks.use_events_of(EventInterest::KEY, {
+        let is_focus = props.is_focus;
+        let path = path.clone();
+        move |event| {
+            let Event::Key(key) = event else {
+                return;
+            };
+            if !is_focus || key.kind != KeyEventKind::Press {
+                return;
+            }
+            let (text, label) = match key.code {
+                KeyCode::Char('y') => (copy_value.clone().unwrap_or_default(), "值"),
+                KeyCode::Char('Y') => (path.clone(), "路径"),
+                _ => return,
+            };
+            let result = match copy_to_clipboard(&text) {
+                Ok(()) => format!("已复制{label}"),
+                Err(err) => format!("复制失败：{err}"),
+            };
+            message.set(Some(result));
+        }
+    });
+
+    let mut status = vec![Span::raw(path)];
+    if let Some(message) = message.read().as_ref() {
+        status.push(Span::raw(format!("  {message}")));
+    }
+    let status_line = Line::from(status).style(props.status_style);
+
+    element!(View(flex_direction: Direction::Vertical) {
+        View(height: Constraint::Fill(1)) {
+            TreeView(
+                nodes: vec![root],
+                is_focus: props.is_focus,
+                highlight_style: props.highlight_style,
+                on_cursor_change: move |path: Vec<usize>| {
+                    cursor.set(path);
+                    message.set(None);
+                },
+            )
+        }
+        View(height: Constraint::Length(1)) {
+            $status_line
+        }
+    })
+}
@@ -0,0 +1,287 @@
+//! KeymapEditor 组件：列出 [`Keymap`] 中的动作及其按键，支持交互式重新绑定。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(KeymapEditor(
+//!     keymap: Some(KEYMAP.clone()),
+//!     is_focus: true,
+//!     on_change: move |_| {
+//!         let _ = KEYMAP.save_file("keymap.json");
+//!     },
+//! ))
+//! ```
+//! - `↑/↓`（或 `k/j`）选择动作，`Enter` 重新录制按键，`a` 追加一组按键，`d` 清除，`r` 恢复默认。
+//! - 录制时直接按下要绑定的按键，可以是多键序列，停止输入一段时间后自动保存；尚未输入时按 `Esc` 取消。
+//! - 录制期间暂停该快捷键表的分发；同一输入模式下多个动作绑定同一组按键时以醒目样式标出冲突。
+
+use std::sync::{Arc, Mutex};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind};
+use futures_timer::Delay;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction},
+    style::{Style, Stylize},
+    text::{Line, Span},
+};
+use ratatui_kit_macros::{Props, component, element};
+
+use super::{Text, View};
+use crate::{
+    AnyElement, EventInterest, Handler, Hook, Hooks, KeyChord, KeySequence, Keymap,
+    SEQUENCE_TIMEOUT, UseEffect, UseEvents, UseKeymap, UseState, text,
+};
+
+#[derive(Props)]
+/// KeymapEditor 组件属性。
+pub struct KeymapEditorProps {
+    /// 要编辑的快捷键表。
+    pub keymap: Option<Keymap>,
+    /// 是否聚焦，聚焦时响应按键。
+    pub is_focus: bool,
+    /// 绑定变化回调，可在此保存到配置文件。
+    pub on_change: Handler<'static, ()>,
+    /// 动作描述样式。
+    pub name_style: Style,
+    /// 按键样式。
+    pub keys_style: Style,
+    /// 修改过的按键样式。
+    pub modified_style: Style,
+    /// 冲突的按键样式。
+    pub conflict_style: Style,
+    /// 光标所在行样式。
+    pub highlight_style: Style,
+    /// 底部提示样式。
+    pub hint_style: Style,
+}
+
+impl Default for KeymapEditorProps {
+    fn default() -> Self {
+        Self {
+            keymap: None,
+            is_focus: false,
+            on_change: Handler::default(),
+            name_style: Style::default(),
+            keys_style: Style::default().cyan(),
+            modified_style: Style::default().yellow(),
+            conflict_style: Style::default().red().bold(),
+            highlight_style: Style::default().reversed(),
+            hint_style: Style::default().dim(),
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+// 正在录制的绑定：动作名称、是否追加以及已输入的按键
+struct Recording {
+    action: String,
+    append: bool,
+    keys: Vec<KeyChord>,
+}
+
+// 组件卸载时结束录制，恢复快捷键分发
+#[derive(Default)]
+struct RecordingGuard {
+    keymap: Option<Keymap>,
+}
+
+impl Hook for RecordingGuard {}
+
+impl Drop for RecordingGuard {
+    fn drop(&mut self) {
+        if let Some(keymap) = &self.keymap {
+            keymap.set_recording(false);
+        }
+    }
+}
+
+#[component]
+pub fn KeymapEditor(
+    props: &mut KeymapEditorProps,
+    mut hooks: Hooks,
+) -> impl Into<AnyElement<'static>> {
+    let fallback = hooks.use_state(Keymap::new);
+    let keymap = props
+        .keymap
+        .clone()
+        .unwrap_or_else(|| fallback.read().clone());
+    // 只用于在快捷键表变化时重新渲染
+    hooks.use_keymap(&keymap, |_| {});
+    let mut cursor = hooks.use_state(|| 0usize);
+    let mut recording = hooks.use_state(|| None::<Recording>);
+
+    let actions = keymap.actions();
+    if cursor.get() >= actions.len() {
+        cursor.set(actions.len().saturating_sub(1));
+    }
+
+    hooks.use_hook(RecordingGuard::default).keymap = Some(keymap.clone());
+
+    let on_change = Arc::new(Mutex::new(props.on_change.take()));
+    // 停止输入一段时间后保存录制的按键
+    let record_len = recording.read().as_ref().map(|r| r.keys.len());
+    hooks.use_async_effect(
+        {
+            let keymap = keymap.clone();
+            let on_change = on_change.clone();
+            async move {
+                if record_len.is_none_or(|len| len == 0) {
+                    return;
+                }
+                Delay::new(SEQUENCE_TIMEOUT).await;
+                let Some(Recording {
+                    action,
+                    append,
+                    keys,
+                }) = recording.write().take()
+                else {
+                    return;
+                };
+                keymap.set_recording(false);
+                let mut bindings = if append {
+                    keymap.keys(&action)
+                } else {
+                    Vec::new()
+                };
+                let keys = KeySequence(keys);
+                if !bindings.contains(&keys) {
+                    bindings.push(keys);
+                }
+                keymap.set_keys(&action, bindings);
+                (on_change.lock().unwrap())(());
+            }
+        },
+        record_len,
+    );
+
+    hooks.use_events_of(EventInterest::KEY, {
+        let is_focus = props.is_focus;
+        let keymap = keymap.clone();
+        let actions = actions.clone();
+        move |event| {
+            let Event::Key(key) = event else {
+                return;
+            };
+            if !is_focus || key.kind == KeyEventKind::Release {
+                return;
+            }
+            let current = recording.read().clone();
+            if let Some(mut current) = current {
+                if key.code == KeyCode::Esc && current.keys.is_empty() {
+                    keymap.set_recording(false);
+                    recording.set(None);
+                } else {
+                    current.keys.push(key.into());
+                    recording.set(Some(current));
+                }
+                return;
+            }
+            if key.kind != KeyEventKind::Press {
+                return;
+            }
+            let Some(action) = actions.get(cursor.get()) else {
+                return;
+            };
+            match key.code {
+                KeyCode::Up | KeyCode::Char('k') => cursor.set(cursor.get().saturating_sub(1)),
+                KeyCode::Down | KeyCode::Char('j') => {
+                    cursor.set((cursor.get() + 1).min(actions.len() - 1))
+                }
+                KeyCode::Enter | KeyCode::Char('a') => {
+                    keymap.set_recording(true);
+                    recording.set(Some(Recording {
+                        action: action.name.clone(),
+                        append: key.code != KeyCode::Enter,
+                        keys: Vec::new(),
+                    }));
+                }
+                KeyCode::Char('d') | KeyCode::Delete => {
+                    keymap.set_keys(&action.name, Vec::new());
+                    (on_change.lock().unwrap())(());
+                }
+                KeyCode::Char('r') => {
+                    keymap.reset(&action.name);
+                    (on_change.lock().unwrap())(());
+                }
+                _ => {}
+            }
+        }
+    });
+
+    let name_width = actions
+        .iter()
+        .map(|action| text::width(&action.description))
+        .max()
+        .unwrap_or(0);
+    let lines = actions
+        .iter()
+        .enumerate()
+        .map(|(index, action)| {
+            let is_cursor = index == cursor.get();
+            let mut spans = vec![
+                Span::styled(
+                    text::pad(&action.description, name_width, Alignment::Left).into_owned(),
+                    props.name_style,
+                ),
+                Span::raw("  "),
+            ];
+            let current = recording.read().clone().filter(|r| r.action == action.name);
+            if let Some(current) = current {
+                let keys = KeySequence(current.keys);
+                let text = if keys.0.is_empty() {
+                    "请按下按键…".to_string()
+                } else {
+                    format!("{keys}…")
+                };
+                spans.push(Span::styled(text, props.modified_style));
+            } else if action.keys.is_empty() {
+                spans.push(Span::styled("未绑定", props.hint_style));
+            } else {
+                for (i, keys) in action.keys.iter().enumerate() {
+                    if i > 0 {
+                        spans.push(Span::raw(", "));
+                    }
+                    let conflict = keymap
+                        .actions_for(keys)
+                        .iter()
+                        .any(|other| other.name != action.name && other.shares_mode(action));
+                    let style = if conflict {
+                        props.conflict_style
+                    } else if action.is_modified() {
+                        props.modified_style
+                    } else {
+                        props.keys_style
+                    };
+                    spans.push(Span::styled(keys.to_string(), style));
+                }
+            }
+            if !action.modes.is_empty() {
+                let modes = action
+                    .modes
+                    .iter()
+                    .map(|mode| mode.to_string().to_lowercase())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                spans.push(Span::styled(format!("  [{modes}]"), props.hint_style));
+            }
+            let line = Line::from(spans);
+            if is_cursor && props.is_focus {
+                line.patch_style(props.highlight_style)
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let hint = if recording.read().is_some() {
+        "按下要绑定的按键，停止输入后自动保存  Esc 取消"
+    } else {
+        "↑↓ 选择  Enter 重新绑定  a 追加  d 清除  r 恢复默认"
+    };
+
+    element!(View(flex_direction: Direction::Vertical) {
+        View(height: Constraint::Fill(1)) {
+            Text(text: lines, wrap: false)
+        }
+        Text(text: Line::styled(hint, props.hint_style), wrap: false)
+    })
+}
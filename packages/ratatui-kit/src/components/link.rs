@@ -0,0 +1,179 @@
+//! Link 组件：可点击的超链接文本。
+//!
+//! 终端支持 OSC 8 时输出真正的超链接（点击或 Ctrl+点击打开），
+//! 否则降级为普通文本，并可在文本后附上网址。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(Link(
+//!     text: "ratatui-kit".to_string(),
+//!     url: "https://github.com/yexiyue/ratatui-kit".to_string(),
+//! ))
+//! ```
+//! 在自定义绘制中可直接使用 [`Hyperlink`] widget。
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::Widget,
+};
+use ratatui_kit_macros::Props;
+
+use crate::{Component, TerminalCapabilities, UseContext, text};
+
+/// 单行超链接 widget，超出区域宽度的部分被截断。
+#[derive(Debug, Clone)]
+pub struct Hyperlink<'a> {
+    text: &'a str,
+    url: &'a str,
+    style: Style,
+    enabled: bool,
+    show_url: bool,
+}
+
+impl<'a> Hyperlink<'a> {
+    /// 创建超链接，是否启用 OSC 8 取决于 [`TerminalCapabilities::current`]。
+    pub fn new(text: &'a str, url: &'a str) -> Self {
+        Self {
+            text,
+            url,
+            style: Style::default().underlined(),
+            enabled: TerminalCapabilities::current().hyperlinks,
+            show_url: true,
+        }
+    }
+
+    /// 文本样式，默认带下划线。
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// 是否输出 OSC 8 超链接，为 false 时显示为普通文本。
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// 降级为普通文本时是否在文本后显示网址。
+    pub fn show_url(mut self, show_url: bool) -> Self {
+        self.show_url = show_url;
+        self
+    }
+}
+
+impl Widget for Hyperlink<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+
+        if !self.enabled {
+            let mut line = Line::from(Span::styled(self.text, self.style));
+            if self.show_url && self.text != self.url {
+                line.push_span(Span::raw(format!(" ({})", self.url)));
+            }
+            line.render(area, buf);
+            return;
+        }
+
+        // 截取能放进区域的字素
+        let visible = text::truncate(self.text, area.width as usize);
+        let width = text::width(&visible) as u16;
+        if width == 0 {
+            return;
+        }
+
+        // 去掉控制字符，避免提前结束转义序列
+        let url = self.url.replace(|c: char| c.is_control(), "");
+        let link_area = Rect::new(area.x, area.y, width, 1);
+        buf.set_style(link_area, self.style);
+        // 整段链接写入第一个单元格，其余单元格跳过，保证转义序列不被拆开
+        buf[(area.x, area.y)].set_symbol(&format!("\x1b]8;;{url}\x1b\\{visible}\x1b]8;;\x1b\\"));
+        for x in area.x + 1..area.x + width {
+            buf[(x, area.y)].set_skip(true);
+        }
+    }
+}
+
+#[derive(Props)]
+/// Link 组件属性。
+pub struct LinkProps {
+    /// 显示的文本，为空时显示网址。
+    pub text: String,
+    /// 链接地址。
+    pub url: String,
+    /// 文本样式。
+    pub style: Style,
+    /// 强制开启或关闭 OSC 8，缺省时按上下文中的 [`TerminalCapabilities`] 决定。
+    pub hyperlink: Option<bool>,
+    /// 降级为普通文本时是否在文本后显示网址。
+    pub show_url: bool,
+}
+
+impl Default for LinkProps {
+    fn default() -> Self {
+        Self {
+            text: String::new(),
+            url: String::new(),
+            style: Style::default().underlined(),
+            hyperlink: None,
+            show_url: true,
+        }
+    }
+}
+
+/// Link 组件实现。
+pub struct Link {
+    text: String,
+    url: String,
+    style: Style,
+    hyperlink: Option<bool>,
+    show_url: bool,
+}
+
+impl Component for Link {
+    type Props<'a> = LinkProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            text: props.text.clone(),
+            url: props.url.clone(),
+            style: props.style,
+            hyperlink: props.hyperlink,
+            show_url: props.show_url,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        hooks: crate::Hooks,
+        _updater: &mut crate::ComponentUpdater,
+    ) {
+        *self = Self::new(props);
+        if self.hyperlink.is_none() {
+            self.hyperlink = hooks
+                .try_use_context::<TerminalCapabilities>()
+                .map(|caps| caps.hyperlinks);
+        }
+    }
+
+    fn draw(&mut self, drawer: &mut crate::ComponentDrawer<'_, '_>) {
+        let text = if self.text.is_empty() {
+            &self.url
+        } else {
+            &self.text
+        };
+        let mut link = Hyperlink::new(text, &self.url)
+            .style(self.style)
+            .show_url(self.show_url);
+        if let Some(enabled) = self.hyperlink {
+            link = link.enabled(enabled);
+        }
+        link.render(drawer.area, drawer.buffer_mut());
+    }
+}
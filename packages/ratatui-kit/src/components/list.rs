@@ -0,0 +1,316 @@
+//! List 组件：可选择的列表，基于 [`SelectionState`] 支持单选、多选与范围选择。
+//!
+//! ## 用法示例
+//! ```rust
+//! let mut selected = hooks.use_state(Vec::new);
+//! element!(List(
+//!     items: vec![ListItem::new("苹果"), ListItem::new("香蕉")],
+//!     selection_state: SelectionState::new(SelectionMode::Multi),
+//!     on_selection_change: move |items| selected.set(items),
+//!     highlight_style: Style::default().reversed(),
+//!     selected_style: Style::default().green(),
+//!     is_focus: true,
+//! ))
+//! ```
+//! 开启鼠标捕获后支持点击选择。
+//!
+//! 设置 `reorderable: true` 后可调整列表项顺序：按 `Space` 拿起光标所在项，`↑/↓` 移动，
+//! `Enter` 放下，`Esc` 取消；也可用鼠标直接拖动。放下时通过 `on_reorder(from, to)` 通知，
+//! 由调用方据此调整数据顺序。
+//!
+//! `read_only` 时选中项和顺序保持不变；`disabled` 时不响应任何事件，并以暗淡样式显示。
+
+use super::SelectionState;
+use crate::{Component, Handler, Hooks, State, UseEffect, UseEvents, UseFocusRing, UseState};
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseButton, MouseEventKind};
+use ratatui::{
+    style::{Modifier, Style},
+    widgets::{List as TuiList, ListItem, ListState, StatefulWidget},
+};
+use ratatui_kit_macros::Props;
+use std::sync::{Arc, Mutex};
+
+#[derive(Props, Default)]
+/// List 组件属性。
+pub struct ListProps {
+    /// 列表项。
+    pub items: Vec<ListItem<'static>>,
+    /// 初始选择状态，变化时覆盖组件内部状态。
+    pub selection_state: SelectionState,
+    /// 选择变化回调，参数为按升序排列的全部选中项。
+    pub on_selection_change: Handler<'static, Vec<usize>>,
+    /// 是否聚焦，聚焦时响应按键。
+    pub is_focus: bool,
+    /// 列表整体样式。
+    pub style: Style,
+    /// 光标所在项样式。
+    pub highlight_style: Style,
+    /// 选中项样式。
+    pub selected_style: Style,
+    /// 光标所在项前显示的符号。
+    pub highlight_symbol: Option<String>,
+    /// 是否允许拖动调整顺序。
+    pub reorderable: bool,
+    /// 拖动中的列表项样式。
+    pub grab_style: Style,
+    /// 调整顺序回调，参数为列表项的原位置和新位置。
+    pub on_reorder: Handler<'static, (usize, usize)>,
+    /// 是否只读，只读时不能修改选中项和顺序。
+    pub read_only: bool,
+    /// 是否禁用，禁用时忽略 `is_focus`，不响应任何事件。
+    pub disabled: bool,
+    /// 聚焦时叠加在列表整体上的样式。
+    pub focus_style: Style,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+// 正在拖动的列表项：原位置和当前预览位置
+struct Grab {
+    from: usize,
+    to: usize,
+}
+
+#[derive(Default)]
+// 上一次绘制时每个可见项占据的行范围，用于鼠标命中判断
+struct ListLayout {
+    rows: Vec<(u16, u16, usize)>,
+}
+
+impl ListLayout {
+    fn item_at(&self, row: u16) -> Option<usize> {
+        self.rows
+            .iter()
+            .find(|(start, end, _)| (*start..*end).contains(&row))
+            .map(|(_, _, index)| *index)
+    }
+}
+
+/// List 组件实现。
+pub struct List {
+    items: Vec<ListItem<'static>>,
+    selection: Option<State<SelectionState>>,
+    grab: Option<State<Option<Grab>>>,
+    list_state: ListState,
+    layout: Arc<Mutex<ListLayout>>,
+    style: Style,
+    highlight_style: Style,
+    selected_style: Style,
+    highlight_symbol: Option<String>,
+    grab_style: Style,
+    disabled: bool,
+    is_focus: bool,
+    focus_style: Style,
+}
+
+impl Component for List {
+    type Props<'a> = ListProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            items: props.items.clone(),
+            selection: None,
+            grab: None,
+            list_state: ListState::default(),
+            layout: Arc::new(Mutex::new(ListLayout::default())),
+            style: props.style,
+            highlight_style: props.highlight_style,
+            selected_style: props.selected_style,
+            highlight_symbol: props.highlight_symbol.clone(),
+            grab_style: props.grab_style,
+            disabled: props.disabled,
+            is_focus: props.is_focus && !props.disabled,
+            focus_style: props.focus_style,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        _updater: &mut crate::ComponentUpdater,
+    ) {
+        let mut selection = hooks.use_state(|| props.selection_state.clone());
+
+        hooks.use_effect(
+            || {
+                *selection.write() = props.selection_state.clone();
+            },
+            props.selection_state.clone(),
+        );
+
+        // 列表项减少时移除越界的选中项
+        let len = props.items.len();
+        let mut clamped = selection.read().clone();
+        clamped.clamp(len);
+        if clamped != *selection.read() {
+            selection.set(clamped);
+        }
+
+        let grab = hooks.use_state(|| None::<Grab>);
+        hooks.use_focus_ring(props.is_focus && !props.disabled);
+
+        hooks.use_local_events({
+            let mut selection = selection;
+            let mut grab = grab;
+            let layout = self.layout.clone();
+            let is_focus = props.is_focus;
+            let reorderable = props.reorderable && !props.read_only;
+            let read_only = props.read_only;
+            let disabled = props.disabled;
+            let mut handler = props.on_selection_change.take();
+            let mut on_reorder = props.on_reorder.take();
+            move |event| {
+                if disabled {
+                    return;
+                }
+                let mut next = selection.read().clone();
+                let mut next_grab = *grab.read();
+                let mut dropped = None;
+                match event {
+                    Event::Key(key) if is_focus && key.kind == KeyEventKind::Press => {
+                        match (next_grab.as_mut(), key.code) {
+                            (Some(g), KeyCode::Up | KeyCode::Char('k')) => {
+                                g.to = g.to.saturating_sub(1);
+                            }
+                            (Some(g), KeyCode::Down | KeyCode::Char('j')) => {
+                                g.to = (g.to + 1).min(len.saturating_sub(1));
+                            }
+                            (Some(_), KeyCode::Enter) => dropped = next_grab.take(),
+                            (Some(_), KeyCode::Esc) => next_grab = None,
+                            // 拖动期间忽略其它按键
+                            (Some(_), _) => {}
+                            (None, KeyCode::Char(' ')) if reorderable => {
+                                next_grab = next.cursor().map(|c| Grab { from: c, to: c });
+                            }
+                            (None, _) => {
+                                next.handle_key(key, len);
+                            }
+                        }
+                    }
+                    Event::Mouse(mouse_event) => {
+                        let index = layout.lock().unwrap().item_at(mouse_event.row);
+                        match (mouse_event.kind, index) {
+                            (MouseEventKind::Down(MouseButton::Left), Some(index)) => {
+                                next.handle_click(index, mouse_event.modifiers);
+                                if reorderable {
+                                    next_grab = Some(Grab {
+                                        from: index,
+                                        to: index,
+                                    });
+                                }
+                            }
+                            (MouseEventKind::Drag(MouseButton::Left), Some(index)) => {
+                                if let Some(g) = next_grab.as_mut() {
+                                    g.to = index;
+                                }
+                            }
+                            (MouseEventKind::Up(MouseButton::Left), _) => {
+                                dropped = next_grab.take();
+                            }
+                            _ => {}
+                        }
+                    }
+                    _ => {}
+                }
+
+                // 只读时撤销对选中项的修改
+                if read_only && next.selected() != selection.read().selected() {
+                    next = selection.read().clone();
+                }
+                if next_grab != *grab.read() {
+                    grab.set(next_grab);
+                }
+                if let Some(Grab { from, to }) = dropped.filter(|g| g.from != g.to) {
+                    next.move_item(from, to);
+                    on_reorder((from, to));
+                }
+                if next != *selection.read() {
+                    let selected = next.selected();
+                    let selection_changed = selected != selection.read().selected();
+                    selection.set(next);
+                    if selection_changed {
+                        handler(selected);
+                    }
+                }
+            }
+        });
+
+        self.items = props.items.clone();
+        self.selection = Some(selection);
+        self.grab = Some(grab);
+        self.grab_style = props.grab_style;
+        self.disabled = props.disabled;
+        self.is_focus = props.is_focus && !props.disabled;
+        self.focus_style = props.focus_style;
+        self.style = props.style;
+        self.highlight_style = props.highlight_style;
+        self.selected_style = props.selected_style;
+        self.highlight_symbol = props.highlight_symbol.clone();
+    }
+
+    fn draw(&mut self, drawer: &mut crate::ComponentDrawer<'_, '_>) {
+        let (Some(selection), Some(grab)) = (self.selection, self.grab) else {
+            return;
+        };
+        let selection = selection.read();
+        let len = self.items.len();
+        let grab = grab.read().filter(|g| g.from < len && g.to < len);
+
+        // 拖动中按预览位置排列列表项
+        let mut order = (0..len).collect::<Vec<_>>();
+        if let Some(g) = grab {
+            let index = order.remove(g.from);
+            order.insert(g.to, index);
+        }
+
+        let heights = order
+            .iter()
+            .map(|&i| self.items[i].height() as u16)
+            .collect::<Vec<_>>();
+        let items = order.iter().map(|&index| {
+            let item = self.items[index].clone();
+            if grab.is_some_and(|g| g.from == index) {
+                item.style(self.grab_style)
+            } else if selection.is_selected(index) {
+                item.style(self.selected_style)
+            } else {
+                item
+            }
+        });
+
+        let style = if self.is_focus {
+            self.style.patch(self.focus_style)
+        } else {
+            self.style
+        };
+        let mut list = TuiList::new(items)
+            .style(style)
+            .highlight_style(self.highlight_style);
+        if let Some(symbol) = &self.highlight_symbol {
+            list = list.highlight_symbol(symbol);
+        }
+
+        self.list_state
+            .select(grab.map(|g| g.to).or(selection.cursor()));
+        let area = drawer.area;
+        list.render(area, drawer.buffer_mut(), &mut self.list_state);
+        if self.disabled {
+            drawer
+                .buffer_mut()
+                .set_style(area, Style::default().add_modifier(Modifier::DIM));
+        }
+
+        // 记录可见项的位置供鼠标交互使用
+        let mut layout = self.layout.lock().unwrap();
+        layout.rows.clear();
+        let mut y = area.y;
+        for (index, height) in heights.iter().enumerate().skip(self.list_state.offset()) {
+            if y >= area.bottom() {
+                break;
+            }
+            let end = y.saturating_add(*height).min(area.bottom());
+            layout.rows.push((y, end, index));
+            y = end;
+        }
+    }
+}
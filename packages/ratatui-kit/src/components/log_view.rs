@@ -0,0 +1,146 @@
+//! LogView 组件：滚动显示日志行，停在底部时自动跟随新输出。
+//!
+//! ## 用法示例
+//! ```rust
+//! let process = hooks.use_command("cargo build --color=always");
+//!
+//! element!(LogView(
+//!     lines: process.lines().iter().map(ProcessLine::to_line).collect::<Vec<_>>(),
+//!     is_focus: true,
+//! ))
+//! ```
+//! - 聚焦时 `↑/↓` 逐行、`PageUp/PageDown` 翻页、`Home/End` 跳到开头/末尾，鼠标滚轮也可滚动。
+//! - 向上滚动后视图停在原位置，新输出不会打断阅读；回到底部后恢复跟随。
+
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseEventKind};
+use ratatui::{layout::Rect, style::Style, text::Line, widgets::WidgetRef};
+use ratatui_kit_macros::Props;
+
+use crate::{Component, State, UseEvents, UseState};
+
+// 鼠标滚轮每次滚动的行数
+const WHEEL_STEP: usize = 3;
+
+#[derive(Default, Props)]
+/// LogView 组件属性。
+pub struct LogViewProps {
+    /// 日志行。
+    pub lines: Vec<Line<'static>>,
+    /// 是否聚焦，聚焦时响应按键。
+    pub is_focus: bool,
+    /// 整体样式。
+    pub style: Style,
+}
+
+#[derive(Default)]
+struct LogScroll {
+    // 距离末尾的行数，为 0 时跟随新输出
+    from_bottom: usize,
+    // 上次渲染时的行数，用于在新输出到来时保持视图位置
+    len: usize,
+}
+
+/// LogView 组件实现。
+pub struct LogView {
+    lines: Vec<Line<'static>>,
+    style: Style,
+    scroll: Option<State<LogScroll>>,
+    height: Arc<AtomicUsize>,
+}
+
+impl Component for LogView {
+    type Props<'a> = LogViewProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            lines: props.lines.clone(),
+            style: props.style,
+            scroll: None,
+            height: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: crate::Hooks,
+        _updater: &mut crate::ComponentUpdater,
+    ) {
+        let scroll = hooks.use_state(LogScroll::default);
+        let len = props.lines.len();
+        if scroll.read().len != len {
+            let mut scroll = scroll.write();
+            if scroll.from_bottom > 0 {
+                scroll.from_bottom = (scroll.from_bottom + len).saturating_sub(scroll.len);
+            }
+            scroll.len = len;
+        }
+
+        hooks.use_local_events({
+            let is_focus = props.is_focus;
+            let height = self.height.clone();
+            move |event| {
+                let page = height.load(Ordering::Relaxed).max(1);
+                let max = len.saturating_sub(page);
+                let from_bottom = scroll.read().from_bottom;
+                let next = match event {
+                    Event::Key(key) if is_focus && key.kind != KeyEventKind::Release => {
+                        match key.code {
+                            KeyCode::Up => from_bottom + 1,
+                            KeyCode::Down => from_bottom.saturating_sub(1),
+                            KeyCode::PageUp => from_bottom + page,
+                            KeyCode::PageDown => from_bottom.saturating_sub(page),
+                            KeyCode::Home => max,
+                            KeyCode::End => 0,
+                            _ => return,
+                        }
+                    }
+                    Event::Mouse(mouse) => match mouse.kind {
+                        MouseEventKind::ScrollUp => from_bottom + WHEEL_STEP,
+                        MouseEventKind::ScrollDown => from_bottom.saturating_sub(WHEEL_STEP),
+                        _ => return,
+                    },
+                    _ => return,
+                };
+                let next = next.min(max);
+                if next != from_bottom {
+                    scroll.write().from_bottom = next;
+                }
+            }
+        });
+
+        self.lines = std::mem::take(&mut props.lines);
+        self.style = props.style;
+        self.scroll = Some(scroll);
+    }
+
+    fn draw(&mut self, drawer: &mut crate::ComponentDrawer<'_, '_>) {
+        let area = drawer.area;
+        let height = area.height as usize;
+        self.height.store(height, Ordering::Relaxed);
+        let max = self.lines.len().saturating_sub(height);
+        let from_bottom = self
+            .scroll
+            .map_or(0, |scroll| scroll.read().from_bottom)
+            .min(max);
+        let start = max - from_bottom;
+
+        let buf = drawer.buffer_mut();
+        buf.set_style(area, self.style);
+        for (row, line) in self.lines[start..].iter().take(height).enumerate() {
+            line.render_ref(
+                Rect {
+                    y: area.y + row as u16,
+                    height: 1,
+                    ..area
+                },
+                buf,
+            );
+        }
+    }
+}
@@ -0,0 +1,285 @@
+//! MaskedInput 组件：按模式输入格式化文本，例如日期、电话号码、序列号。
+//!
+//! ## 用法示例
+//! ```rust
+//! let mut date = hooks.use_state(String::new);
+//! element!(MaskedInput(
+//!     label: "日期",
+//!     pattern: "##/##/####",
+//!     value: date.read().clone(),
+//!     is_focus: true,
+//!     on_change: move |value| date.set(value),
+//!     on_complete: move |value| println!("{value}"),
+//! ))
+//! ```
+//! 模式字符：`#` 数字，`A` 字母，`*` 字母或数字，其余字符原样显示且自动跳过。
+//! 未填写的位置显示为 `placeholder_char`；只接受与当前位置匹配的字符，`Backspace` 删除最后一个字符。
+//! `on_change` 收到的值包含已填写部分及其间的分隔符，全部填满时触发 `on_complete`。
+//!
+//! `read_only` 时不能修改内容；`disabled` 时不显示光标、不响应按键，并以暗淡样式显示。
+
+use crossterm::event::{Event, KeyEventKind};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Rect, Size},
+    style::{Style, Stylize},
+};
+use ratatui_kit_macros::Props;
+
+use super::field::{Field, FieldState, FieldStyle};
+use crate::{Component, Handler, Hooks, State, UseEvents, UseState};
+
+#[derive(Props)]
+/// MaskedInput 组件属性。
+pub struct MaskedInputProps {
+    /// 输入模式。
+    pub pattern: String,
+    /// 当前值，即格式化后的文本。
+    pub value: String,
+    /// 未填写位置显示的字符。
+    pub placeholder_char: char,
+    /// 输入框前的标签。
+    pub label: String,
+    /// 是否聚焦。
+    pub is_focus: bool,
+    /// 是否只读，只读时不能修改内容。
+    pub read_only: bool,
+    /// 是否禁用，禁用时忽略 `is_focus`，不响应任何事件。
+    pub disabled: bool,
+    /// 内容变更回调。
+    pub on_change: Handler<'static, String>,
+    /// 全部位置填满时触发。
+    pub on_complete: Handler<'static, String>,
+    /// 校验回调，在全部位置填满后调用，返回错误信息，通过时返回 None。
+    pub validate: Handler<'static, String, Option<String>>,
+    /// 标签样式。
+    pub label_style: Style,
+    /// 输入内容样式。
+    pub style: Style,
+    /// 光标样式。
+    pub cursor_style: Style,
+    /// 错误信息样式。
+    pub error_style: Style,
+}
+
+impl Default for MaskedInputProps {
+    fn default() -> Self {
+        Self {
+            pattern: String::new(),
+            value: String::new(),
+            placeholder_char: '_',
+            label: String::new(),
+            is_focus: false,
+            read_only: false,
+            disabled: false,
+            on_change: Handler::default(),
+            on_complete: Handler::default(),
+            validate: Handler::default(),
+            label_style: Style::default().bold(),
+            style: Style::default(),
+            cursor_style: Style::default().reversed(),
+            error_style: Style::default().red(),
+        }
+    }
+}
+
+// 模式中需要填写的位置能否接受该字符，分隔符返回 None
+fn slot_accepts(slot: char, c: char) -> Option<bool> {
+    match slot {
+        '#' => Some(c.is_ascii_digit()),
+        'A' => Some(c.is_alphabetic()),
+        '*' => Some(c.is_alphanumeric()),
+        _ => None,
+    }
+}
+
+#[derive(Clone)]
+struct Mask(Vec<char>);
+
+impl Mask {
+    fn slots(&self) -> usize {
+        self.0
+            .iter()
+            .filter(|&&p| slot_accepts(p, ' ').is_some())
+            .count()
+    }
+
+    // 从格式化文本中取出已填写的字符
+    fn parse(&self, value: &str) -> String {
+        self.0
+            .iter()
+            .zip(value.chars())
+            .filter(|&(&p, c)| slot_accepts(p, c) == Some(true))
+            .map(|(_, c)| c)
+            .collect()
+    }
+
+    // 已填写的字符及其间的分隔符
+    fn format(&self, raw: &str) -> String {
+        let mut raw = raw.chars().peekable();
+        let mut out = String::new();
+        let mut literals = String::new();
+        for &p in &self.0 {
+            if raw.peek().is_none() {
+                break;
+            }
+            if slot_accepts(p, ' ').is_some() {
+                out.push_str(&literals);
+                literals.clear();
+                out.extend(raw.next());
+            } else {
+                literals.push(p);
+            }
+        }
+        out
+    }
+
+    // 完整显示的文本和光标位置
+    fn display(&self, raw: &str, placeholder: char) -> (String, usize) {
+        let mut raw = raw.chars();
+        let mut cursor = None;
+        let display = self
+            .0
+            .iter()
+            .enumerate()
+            .map(|(i, &p)| match slot_accepts(p, ' ') {
+                Some(_) => raw.next().unwrap_or_else(|| {
+                    cursor.get_or_insert(i);
+                    placeholder
+                }),
+                None => p,
+            })
+            .collect::<String>();
+        let len = display.chars().count();
+        (display, cursor.unwrap_or(len))
+    }
+}
+
+/// MaskedInput 组件实现。
+pub struct MaskedInput {
+    state: Option<State<FieldState>>,
+    mask: Mask,
+    placeholder_char: char,
+    label: String,
+    is_focus: bool,
+    disabled: bool,
+    style: FieldStyle,
+}
+
+impl MaskedInput {
+    fn render_field(&self, f: impl FnOnce(&Field)) {
+        let Some(state) = self.state else {
+            return;
+        };
+        let state = state.read();
+        let (display, cursor) = self.mask.display(&state.text, self.placeholder_char);
+        f(&Field {
+            label: &self.label,
+            display: &display,
+            placeholder: "",
+            cursor: self.is_focus.then_some(cursor),
+            error: state.error.as_deref(),
+            style: self.style,
+            disabled: self.disabled,
+        })
+    }
+}
+
+impl Component for MaskedInput {
+    type Props<'a> = MaskedInputProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            state: None,
+            mask: Mask(props.pattern.chars().collect()),
+            placeholder_char: props.placeholder_char,
+            label: props.label.clone(),
+            is_focus: props.is_focus && !props.disabled,
+            disabled: props.disabled,
+            style: FieldStyle::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        _updater: &mut crate::ComponentUpdater,
+    ) {
+        let mask = Mask(props.pattern.chars().collect());
+        // 编辑状态保存已填写的字符，不含分隔符
+        let state = hooks.use_state(FieldState::default);
+        let raw = mask.parse(&props.value);
+        if state.read().needs_sync(&raw) {
+            state.write().sync(&raw);
+        }
+        self.state = Some(state);
+        self.mask = mask.clone();
+        self.placeholder_char = props.placeholder_char;
+        self.label = props.label.clone();
+        self.is_focus = props.is_focus && !props.disabled;
+        self.disabled = props.disabled;
+        self.style = FieldStyle {
+            label: props.label_style,
+            text: props.style,
+            cursor: props.cursor_style,
+            error: props.error_style,
+            ..FieldStyle::default()
+        };
+
+        hooks.use_local_events({
+            // 光标始终位于下一个待填写的位置，只读时没有可处理的按键
+            let is_focus = props.is_focus && !props.disabled && !props.read_only;
+            let mut on_change = props.on_change.take();
+            let mut on_complete = props.on_complete.take();
+            let mut validate = props.validate.take();
+            move |event| {
+                let Event::Key(key) = event else {
+                    return;
+                };
+                if !is_focus || key.kind == KeyEventKind::Release {
+                    return;
+                }
+                let mut s = state.write();
+                // 只在末尾追加或删除，光标始终位于下一个待填写的位置
+                let filled = s.text.chars().count();
+                s.cursor = filled;
+                let next = mask
+                    .0
+                    .iter()
+                    .filter(|&&p| slot_accepts(p, ' ').is_some())
+                    .nth(filled)
+                    .copied();
+                let accept = |c| next.is_some_and(|p| slot_accepts(p, c) == Some(true));
+                if !s.edit(&key, accept) {
+                    s.cursor = s.text.chars().count();
+                    return;
+                }
+                let raw = s.text.clone();
+                let value = mask.format(&raw);
+                let complete = raw.chars().count() == mask.slots();
+                s.error = if complete {
+                    validate(value.clone())
+                } else {
+                    None
+                };
+                s.commit(&raw);
+                drop(s);
+                on_change(value.clone());
+                if complete {
+                    on_complete(value);
+                }
+            }
+        });
+    }
+
+    fn measure(&self, max: Size) -> Option<Size> {
+        let mut height = 1;
+        self.render_field(|field| height = field.height());
+        Some(Size::new(max.width, height))
+    }
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        self.render_field(|field| field.render(area, buf));
+    }
+}
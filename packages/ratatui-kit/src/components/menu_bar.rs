@@ -0,0 +1,718 @@
+//! MenuBar 组件：顶部菜单栏，支持多级下拉菜单、分隔线、禁用项和菜单动作。
+//!
+//! 菜单栏占据顶部一行，子元素绘制在其下方，下拉菜单浮于子元素之上。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(MenuBar(
+//!     menus: vec![
+//!         Menu::new("File", vec![
+//!             MenuItem::action("Open", move || open.set(true)).shortcut("Ctrl+O"),
+//!             MenuItem::submenu("Recent", vec![MenuItem::action("a.txt", || {})]),
+//!             MenuItem::separator(),
+//!             MenuItem::action("Quit", move || exit.set(true)),
+//!         ]),
+//!         Menu::new("Edit", vec![MenuItem::action("Undo", || {}).disabled()]),
+//!     ],
+//! ){
+//!     App()
+//! })
+//! ```
+//!
+//! 按键：`Alt+菜单首字母` 或 `F10` 打开菜单，`←/→` 切换菜单或关闭子菜单，`↑/↓` 选择菜单项，
+//! `Enter`/`→` 打开子菜单，`Enter` 执行动作，`Esc` 逐级关闭。开启鼠标捕获后支持点击。
+//!
+//! 菜单栏整体是一个焦点位置，即包裹它的 [`FocusScope`](super::FocusScope)：作用域处于焦点链上时
+//! 通过[漫游焦点](crate::UseRovingFocus::use_roving_focus)用 `←/→`、`Home/End` 在菜单标题间移动，
+//! `Enter`/`↓` 打开当前菜单；`Tab` 不被处理，交给 [`use_focus_order`](crate::UseFocusOrder::use_focus_order)
+//! 切换作用域的焦点，回到菜单栏时停在上次所在的标题。
+//! 该作用域只属于菜单栏本身，子元素沿用更外层的作用域，可再用各自的 FocusScope 参与焦点切换：
+//!
+//! ```rust
+//! element!(FocusScope(is_focus: order.is_focus(0)) {
+//!     MenuBar(menus: menus) {
+//!         FocusScope(is_focus: order.is_focus(1)) {
+//!             TextArea(is_focus: order.is_focus(1))
+//!         }
+//!     }
+//! })
+//! ```
+
+use std::{
+    pin::pin,
+    sync::{Arc, Mutex},
+    task::Poll,
+};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers, MouseButton, MouseEventKind};
+use futures::Stream;
+use ratatui::{
+    layout::{Position, Rect},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Widget},
+};
+use ratatui_kit_macros::Props;
+
+use super::FocusScopeContext;
+use crate::{
+    AnyElement, Component, Context, EventInterest, Hook, RovingFocus, TerminalEvents,
+    UseRovingFocus,
+};
+
+/// 菜单动作。
+pub type MenuAction = Arc<dyn Fn() + Send + Sync>;
+
+#[derive(Clone)]
+/// 菜单项。
+pub enum MenuItem {
+    /// 可执行的菜单项。
+    Action {
+        label: String,
+        shortcut: Option<String>,
+        disabled: bool,
+        action: MenuAction,
+    },
+    /// 子菜单。
+    Submenu {
+        label: String,
+        disabled: bool,
+        items: Vec<MenuItem>,
+    },
+    /// 分隔线。
+    Separator,
+}
+
+impl MenuItem {
+    /// 创建可执行的菜单项。
+    pub fn action<F>(label: impl Into<String>, action: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        MenuItem::Action {
+            label: label.into(),
+            shortcut: None,
+            disabled: false,
+            action: Arc::new(action),
+        }
+    }
+
+    /// 创建子菜单。
+    pub fn submenu(label: impl Into<String>, items: Vec<MenuItem>) -> Self {
+        MenuItem::Submenu {
+            label: label.into(),
+            disabled: false,
+            items,
+        }
+    }
+
+    /// 创建分隔线。
+    pub fn separator() -> Self {
+        MenuItem::Separator
+    }
+
+    /// 设置快捷键提示文本，仅用于显示。
+    pub fn shortcut(mut self, text: impl Into<String>) -> Self {
+        if let MenuItem::Action { shortcut, .. } = &mut self {
+            *shortcut = Some(text.into());
+        }
+        self
+    }
+
+    /// 禁用该菜单项。
+    pub fn disabled(mut self) -> Self {
+        match &mut self {
+            MenuItem::Action { disabled, .. } | MenuItem::Submenu { disabled, .. } => {
+                *disabled = true
+            }
+            MenuItem::Separator => {}
+        }
+        self
+    }
+
+    fn is_selectable(&self) -> bool {
+        !matches!(self, MenuItem::Separator)
+    }
+
+    fn is_disabled(&self) -> bool {
+        match self {
+            MenuItem::Action { disabled, .. } | MenuItem::Submenu { disabled, .. } => *disabled,
+            MenuItem::Separator => true,
+        }
+    }
+
+    // 菜单项显示宽度：左右留白 + 标签 + 快捷键/子菜单箭头
+    fn width(&self) -> u16 {
+        let width = match self {
+            MenuItem::Action {
+                label, shortcut, ..
+            } => {
+                Line::from(label.as_str()).width()
+                    + shortcut
+                        .as_ref()
+                        .map(|s| Line::from(s.as_str()).width() + 2)
+                        .unwrap_or_default()
+            }
+            MenuItem::Submenu { label, .. } => Line::from(label.as_str()).width() + 2,
+            MenuItem::Separator => 0,
+        };
+        width as u16 + 2
+    }
+}
+
+#[derive(Clone)]
+/// 顶层菜单。
+pub struct Menu {
+    /// 显示在菜单栏上的标题。
+    pub title: String,
+    /// 下拉菜单项。
+    pub items: Vec<MenuItem>,
+}
+
+impl Menu {
+    /// 创建顶层菜单。
+    pub fn new(title: impl Into<String>, items: Vec<MenuItem>) -> Self {
+        Self {
+            title: title.into(),
+            items,
+        }
+    }
+
+    // Alt+首字母激活
+    fn accelerator(&self) -> Option<char> {
+        self.title.chars().next().map(|c| c.to_ascii_lowercase())
+    }
+}
+
+#[derive(Props)]
+/// MenuBar 组件属性。
+pub struct MenuBarProps<'a> {
+    /// 顶层菜单。
+    pub menus: Vec<Menu>,
+    /// 菜单栏下方的内容。
+    pub children: Vec<AnyElement<'a>>,
+    /// 菜单栏样式。
+    pub style: Style,
+    /// 下拉菜单样式。
+    pub menu_style: Style,
+    /// 高亮的菜单/菜单项样式。
+    pub highlight_style: Style,
+    /// 禁用菜单项样式。
+    pub disabled_style: Style,
+    /// 是否在下拉菜单右下方绘制阴影。
+    pub shadow: bool,
+}
+
+impl Default for MenuBarProps<'_> {
+    fn default() -> Self {
+        Self {
+            menus: Vec::new(),
+            children: Vec::new(),
+            style: Style::default().reversed(),
+            menu_style: Style::default(),
+            highlight_style: Style::default().reversed(),
+            disabled_style: Style::default().dim(),
+            shadow: false,
+        }
+    }
+}
+
+#[derive(Default)]
+// 菜单栏共享状态，组件绘制菜单栏，hook 处理事件并绘制下拉菜单
+struct MenuBarState {
+    menus: Vec<Menu>,
+    // 打开的顶层菜单
+    open: Option<usize>,
+    // 每一级下拉菜单中高亮项的索引
+    path: Vec<usize>,
+    // 菜单栏上每个标题的起止横坐标
+    titles: Vec<(u16, u16)>,
+    bar: Rect,
+    // 上一次绘制的各级下拉菜单区域
+    dropdowns: Vec<Rect>,
+    // 菜单栏聚焦时当前的菜单标题
+    roving: Option<RovingFocus>,
+}
+
+impl MenuBarState {
+    fn items_at(&self, level: usize) -> &[MenuItem] {
+        let Some(menu) = self.open.and_then(|i| self.menus.get(i)) else {
+            return &[];
+        };
+        let mut items = menu.items.as_slice();
+        for &index in self.path.iter().take(level) {
+            match items.get(index) {
+                Some(MenuItem::Submenu { items: sub, .. }) => items = sub,
+                _ => return &[],
+            }
+        }
+        items
+    }
+
+    fn first_selectable(items: &[MenuItem]) -> usize {
+        items.iter().position(MenuItem::is_selectable).unwrap_or(0)
+    }
+
+    fn open_menu(&mut self, index: usize) {
+        self.open = Some(index);
+        self.path = vec![Self::first_selectable(&self.menus[index].items)];
+        // 关闭菜单后焦点停留在最后打开的标题上
+        if let Some(roving) = &mut self.roving {
+            roving.focus(index);
+        }
+    }
+
+    // 聚焦且没有打开菜单时高亮的标题
+    fn focused_title(&self) -> Option<usize> {
+        let roving = self
+            .roving
+            .as_ref()
+            .filter(|roving| roving.is_focus() && self.open.is_none())?;
+        (!self.menus.is_empty()).then(|| roving.index())
+    }
+
+    fn close(&mut self) {
+        self.open = None;
+        self.path.clear();
+    }
+
+    fn switch_menu(&mut self, delta: isize) {
+        let len = self.menus.len() as isize;
+        if let Some(open) = self.open {
+            self.open_menu((open as isize + delta).rem_euclid(len) as usize);
+        }
+    }
+
+    // 在当前级别中循环移动高亮项，跳过分隔线
+    fn move_highlight(&mut self, delta: isize) {
+        let level = self.path.len() - 1;
+        let items = self.items_at(level);
+        let len = items.len() as isize;
+        if len == 0 {
+            return;
+        }
+        let mut index = self.path[level] as isize;
+        for _ in 0..len {
+            index = (index + delta).rem_euclid(len);
+            if items[index as usize].is_selectable() {
+                self.path[level] = index as usize;
+                return;
+            }
+        }
+    }
+
+    // 激活高亮项：打开子菜单或返回要执行的动作
+    fn activate(&mut self) -> Option<MenuAction> {
+        let level = self.path.len() - 1;
+        match self.items_at(level).get(self.path[level])? {
+            item if item.is_disabled() => None,
+            MenuItem::Submenu { items, .. } => {
+                let first = Self::first_selectable(items);
+                self.path.push(first);
+                None
+            }
+            MenuItem::Action { action, .. } => {
+                let action = action.clone();
+                self.close();
+                Some(action)
+            }
+            MenuItem::Separator => None,
+        }
+    }
+
+    fn back(&mut self) {
+        if self.path.len() > 1 {
+            self.path.pop();
+        } else {
+            self.close();
+        }
+    }
+
+    // 处理事件，返回是否需要重新渲染以及要执行的动作
+    fn handle_event(&mut self, event: Event) -> (bool, Option<MenuAction>) {
+        match event {
+            Event::Key(key) if key.kind == KeyEventKind::Press => {
+                if self.open.is_none() {
+                    if let Some(focused) = self.focused_title() {
+                        if self.roving.as_mut().is_some_and(|r| r.handle_key(&key)) {
+                            return (true, None);
+                        }
+                        if matches!(key.code, KeyCode::Enter | KeyCode::Down) {
+                            self.open_menu(focused);
+                            return (true, None);
+                        }
+                    }
+                    let index = match key.code {
+                        KeyCode::F(10) if !self.menus.is_empty() => Some(0),
+                        KeyCode::Char(c) if key.modifiers.contains(KeyModifiers::ALT) => self
+                            .menus
+                            .iter()
+                            .position(|m| m.accelerator() == Some(c.to_ascii_lowercase())),
+                        _ => None,
+                    };
+                    return match index {
+                        Some(index) => {
+                            self.open_menu(index);
+                            (true, None)
+                        }
+                        None => (false, None),
+                    };
+                }
+                match key.code {
+                    KeyCode::Up => self.move_highlight(-1),
+                    KeyCode::Down => self.move_highlight(1),
+                    KeyCode::Left if self.path.len() > 1 => self.back(),
+                    KeyCode::Left => self.switch_menu(-1),
+                    KeyCode::Right => {
+                        let level = self.path.len() - 1;
+                        match self.items_at(level).get(self.path[level]) {
+                            Some(item @ MenuItem::Submenu { .. }) if !item.is_disabled() => {
+                                self.activate();
+                            }
+                            _ => self.switch_menu(1),
+                        }
+                    }
+                    KeyCode::Enter => return (true, self.activate()),
+                    KeyCode::Esc | KeyCode::F(10) => self.back(),
+                    _ => return (false, None),
+                }
+                (true, None)
+            }
+            Event::Mouse(mouse_event)
+                if mouse_event.kind == MouseEventKind::Down(MouseButton::Left) =>
+            {
+                let position = Position::new(mouse_event.column, mouse_event.row);
+                // 从最深一级开始查找被点击的下拉菜单
+                for level in (0..self.dropdowns.len()).rev() {
+                    let area = self.dropdowns[level];
+                    if !area.contains(position) {
+                        continue;
+                    }
+                    let index = position.y.saturating_sub(area.y + 1) as usize;
+                    if !self
+                        .items_at(level)
+                        .get(index)
+                        .is_some_and(MenuItem::is_selectable)
+                    {
+                        return (false, None);
+                    }
+                    self.path.truncate(level + 1);
+                    self.path[level] = index;
+                    return (true, self.activate());
+                }
+                if self.bar.contains(position) {
+                    match self
+                        .titles
+                        .iter()
+                        .position(|(start, end)| (*start..*end).contains(&position.x))
+                    {
+                        Some(index) if self.open == Some(index) => self.close(),
+                        Some(index) => self.open_menu(index),
+                        None => self.close(),
+                    }
+                    return (true, None);
+                }
+                // 点击菜单外部关闭菜单
+                if self.open.is_some() {
+                    self.close();
+                    return (true, None);
+                }
+                (false, None)
+            }
+            _ => (false, None),
+        }
+    }
+}
+
+/// MenuBar 组件实现。
+pub struct MenuBar {
+    state: Arc<Mutex<MenuBarState>>,
+    style: Style,
+    highlight_style: Style,
+}
+
+impl Component for MenuBar {
+    type Props<'a> = MenuBarProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(MenuBarState::default())),
+            style: props.style,
+            highlight_style: props.highlight_style,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: crate::Hooks,
+        updater: &mut crate::ComponentUpdater,
+    ) {
+        let roving = hooks.use_roving_focus(props.menus.len());
+        {
+            let mut state = self.state.lock().unwrap();
+            state.menus = props.menus.clone();
+            state.roving = Some(roving);
+            // 菜单结构变化后关闭失效的菜单
+            if state.open.is_some_and(|open| open >= state.menus.len()) {
+                state.close();
+            }
+        }
+        let hook = hooks.use_hook(|| UseMenuBarImpl {
+            state: self.state.clone(),
+            events: None,
+            menu_style: Style::default(),
+            highlight_style: Style::default(),
+            disabled_style: Style::default(),
+            shadow: false,
+        });
+        hook.menu_style = props.menu_style;
+        hook.highlight_style = props.highlight_style;
+        hook.disabled_style = props.disabled_style;
+        hook.shadow = props.shadow;
+
+        self.style = props.style;
+        self.highlight_style = props.highlight_style;
+
+        // 所在的作用域是菜单栏的焦点位置，子元素沿用外层作用域
+        let outer = FocusScopeContext::current(updater).map(|scope| Context::owned(scope.outer()));
+        updater.update_children(&mut props.children, outer);
+    }
+
+    fn draw(&mut self, drawer: &mut crate::ComponentDrawer<'_, '_>) {
+        let mut state = self.state.lock().unwrap();
+        let area = drawer.area;
+        let bar = Rect { height: 1, ..area };
+
+        let mut spans = Vec::new();
+        let mut titles = Vec::new();
+        let mut x = bar.x;
+        let focused = state.focused_title();
+        for (index, menu) in state.menus.iter().enumerate() {
+            let title = format!(" {} ", menu.title);
+            let width = Line::from(title.as_str()).width() as u16;
+            titles.push((x, x + width));
+            x += width;
+            if state.open == Some(index) || focused == Some(index) {
+                spans.push(Span::styled(title, self.highlight_style));
+            } else {
+                spans.push(Span::raw(title));
+            }
+        }
+        Line::from(spans)
+            .style(self.style)
+            .render(bar, drawer.buffer_mut());
+
+        state.bar = bar;
+        state.titles = titles;
+
+        // 子组件绘制在菜单栏下方
+        drawer.area = Rect {
+            y: area.y + bar.height,
+            height: area.height.saturating_sub(bar.height),
+            ..area
+        };
+    }
+}
+
+struct UseMenuBarImpl {
+    state: Arc<Mutex<MenuBarState>>,
+    events: Option<TerminalEvents<Event>>,
+    menu_style: Style,
+    highlight_style: Style,
+    disabled_style: Style,
+    shadow: bool,
+}
+
+impl UseMenuBarImpl {
+    fn render_dropdown(
+        &self,
+        items: &[MenuItem],
+        highlight: usize,
+        x: u16,
+        y: u16,
+        frame_area: Rect,
+        buf: &mut ratatui::buffer::Buffer,
+    ) -> Rect {
+        let width = items.iter().map(MenuItem::width).max().unwrap_or(0) + 2;
+        let area = Rect::new(x, y, width, items.len() as u16 + 2).intersection(frame_area);
+        let block = Block::bordered().style(self.menu_style);
+        let inner = block.inner(area);
+        super::modal::render_surface(area, buf, None, self.shadow);
+        block.render(area, buf);
+
+        for (index, item) in items.iter().enumerate() {
+            let row = Rect {
+                y: inner.y + index as u16,
+                height: 1,
+                ..inner
+            };
+            if row.y >= inner.bottom() {
+                break;
+            }
+            let style = if item.is_disabled() {
+                self.disabled_style
+            } else if index == highlight {
+                self.highlight_style
+            } else {
+                Style::default()
+            };
+            let (label, suffix) = match item {
+                MenuItem::Separator => {
+                    Line::from("─".repeat(row.width as usize)).render(row, buf);
+                    continue;
+                }
+                MenuItem::Action {
+                    label, shortcut, ..
+                } => (label.as_str(), shortcut.clone().unwrap_or_default()),
+                MenuItem::Submenu { label, .. } => (label.as_str(), "▸".to_string()),
+            };
+            let style = if index == highlight && item.is_disabled() {
+                style.patch(self.highlight_style)
+            } else {
+                style
+            };
+            Line::from(format!(" {label}"))
+                .style(style)
+                .render(row, buf);
+            Line::from(format!("{suffix} "))
+                .style(style)
+                .right_aligned()
+                .render(row, buf);
+        }
+        area
+    }
+}
+
+impl Hook for UseMenuBarImpl {
+    fn poll_change(self: std::pin::Pin<&mut Self>, cx: &mut std::task::Context) -> Poll<()> {
+        let this = self.get_mut();
+        let mut changed = false;
+        while let Some(Poll::Ready(Some(event))) = this
+            .events
+            .as_mut()
+            .map(|events| pin!(events).poll_next(cx))
+        {
+            let (redraw, action) = this.state.lock().unwrap().handle_event(event);
+            changed |= redraw;
+            if let Some(action) = action {
+                action();
+            }
+        }
+        if changed {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn post_component_update(&mut self, updater: &mut crate::ComponentUpdater) {
+        if self.events.is_none() {
+            self.events = updater
+                .events_of(EventInterest::KEY | EventInterest::MOUSE)
+                .ok();
+        }
+    }
+
+    fn post_component_draw(&mut self, drawer: &mut crate::ComponentDrawer) {
+        // 子组件绘制完成后，在最上层绘制各级下拉菜单
+        let mut state = self.state.lock().unwrap();
+        let frame_area = drawer.buffer_mut().area;
+        let mut dropdowns = Vec::new();
+        if let Some(open) = state.open {
+            let (mut x, mut y) = (state.titles[open].0, state.bar.bottom());
+            for level in 0..state.path.len() {
+                let items = state.items_at(level);
+                let highlight = state.path[level];
+                let area =
+                    self.render_dropdown(items, highlight, x, y, frame_area, drawer.buffer_mut());
+                dropdowns.push(area);
+                // 子菜单显示在父菜单项右侧
+                x = area.right();
+                y = area.y + 1 + highlight as u16;
+            }
+        }
+        state.dropdowns = dropdowns;
+    }
+}
+
+#[cfg(all(test, feature = "textarea"))]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    };
+
+    use crossterm::event::{Event, KeyCode};
+
+    use crate::{
+        prelude::*,
+        test_util::{keys, run_events},
+    };
+
+    #[derive(Default, Props)]
+    struct EditorProps {
+        undone: Arc<AtomicBool>,
+    }
+
+    #[component]
+    fn Editor(props: &EditorProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let order = hooks.use_focus_order(&[false, false]);
+        hooks.use_events({
+            let mut order = order.clone();
+            move |event| {
+                if let Event::Key(key) = event {
+                    order.handle_key(&key);
+                }
+            }
+        });
+        let undone = props.undone.clone();
+        element!(FocusScope(is_focus: order.is_focus(0)) {
+            MenuBar(
+                menus: vec![
+                    Menu::new("File", vec![MenuItem::action("Quit", || {})]),
+                    Menu::new("Edit", vec![MenuItem::action("Undo", move || {
+                        undone.store(true, Ordering::SeqCst);
+                    })]),
+                ],
+            ) {
+                FocusScope(is_focus: order.is_focus(1)) {
+                    TextArea(is_focus: order.is_focus(1))
+                }
+            }
+        })
+    }
+
+    // 依次按键，返回 Edit 菜单的 Undo 是否被执行
+    fn run(codes: impl IntoIterator<Item = KeyCode>) -> bool {
+        let undone = Arc::new(AtomicBool::new(false));
+        run_events(element!(Editor(undone: undone.clone())), keys(codes));
+        undone.load(Ordering::SeqCst)
+    }
+
+    #[test]
+    fn arrows_move_between_titles_when_focused() {
+        assert!(run([KeyCode::Right, KeyCode::Enter, KeyCode::Enter]));
+    }
+
+    #[test]
+    fn tab_leaves_the_menu_bar() {
+        assert!(!run([
+            KeyCode::Tab,
+            KeyCode::Right,
+            KeyCode::Enter,
+            KeyCode::Enter
+        ]));
+    }
+
+    #[test]
+    fn back_tab_returns_to_the_last_title() {
+        assert!(run([
+            KeyCode::Right,
+            KeyCode::Tab,
+            KeyCode::BackTab,
+            KeyCode::Enter,
+            KeyCode::Enter
+        ]));
+    }
+}
@@ -0,0 +1,139 @@
+//! Meter 组件：条形仪表，显示最新采样值，并按历史最小/最大值自动缩放。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(Meter(
+//!     value: Some(memory_used),
+//!     min: Some(0.0),
+//!     max: Some(memory_total),
+//!     label: Some("MEM".to_string()),
+//!     gauge_style: Style::default().green(),
+//! ))
+//! ```
+//! 与 Sparkline 一样支持通过 `samples` 句柄推送数据，未固定 `min/max` 时按历史数据缩放。
+
+use std::task::{Context, Poll};
+
+use ratatui::{
+    style::Style,
+    widgets::{Gauge, Widget},
+};
+use ratatui_kit_macros::Props;
+
+use crate::{Component, SampleSource, Samples};
+
+#[derive(Props)]
+/// Meter 组件属性。
+pub struct MeterProps {
+    /// 外部采样句柄，设置后忽略 `value`。
+    pub samples: Option<Samples>,
+    /// 响应式采样值，变化时追加到内部缓冲区。
+    pub value: Option<f64>,
+    /// 内部缓冲区容量，仅在组件创建时生效。
+    pub capacity: usize,
+    /// 固定的最小值，缺省时按历史数据自动计算。
+    pub min: Option<f64>,
+    /// 固定的最大值，缺省时按历史数据自动计算。
+    pub max: Option<f64>,
+    /// 显示在数值前的标签。
+    pub label: Option<String>,
+    /// 数值显示的小数位数。
+    pub precision: usize,
+    /// 整体样式。
+    pub style: Style,
+    /// 填充部分样式。
+    pub gauge_style: Style,
+}
+
+impl Default for MeterProps {
+    fn default() -> Self {
+        Self {
+            samples: None,
+            value: None,
+            capacity: 120,
+            min: None,
+            max: None,
+            label: None,
+            precision: 1,
+            style: Style::default(),
+            gauge_style: Style::default(),
+        }
+    }
+}
+
+/// Meter 组件实现。
+pub struct Meter {
+    source: SampleSource,
+    min: Option<f64>,
+    max: Option<f64>,
+    label: Option<String>,
+    precision: usize,
+    style: Style,
+    gauge_style: Style,
+}
+
+impl Component for Meter {
+    type Props<'a> = MeterProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            source: SampleSource::new(props.capacity),
+            min: props.min,
+            max: props.max,
+            label: props.label.clone(),
+            precision: props.precision,
+            style: props.style,
+            gauge_style: props.gauge_style,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        _hooks: crate::Hooks,
+        _updater: &mut crate::ComponentUpdater,
+    ) {
+        self.source.update(props.samples.clone(), props.value);
+        self.min = props.min;
+        self.max = props.max;
+        self.label = props.label.clone();
+        self.precision = props.precision;
+        self.style = props.style;
+        self.gauge_style = props.gauge_style;
+    }
+
+    fn draw(&mut self, drawer: &mut crate::ComponentDrawer<'_, '_>) {
+        let values = self.source.samples().values();
+        let (Some(latest), Some((auto_min, auto_max))) =
+            (values.last().copied(), Samples::bounds(&values))
+        else {
+            return;
+        };
+        let min = self.min.unwrap_or(auto_min);
+        let max = self.max.unwrap_or(auto_max);
+        let ratio = if max > min {
+            ((latest - min) / (max - min)).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+
+        let precision = self.precision;
+        let value = format!("{latest:.precision$}");
+        let label = match &self.label {
+            Some(label) => format!("{label} {value}"),
+            None => value,
+        };
+
+        Gauge::default()
+            .ratio(ratio)
+            .label(label)
+            .use_unicode(true)
+            .style(self.style)
+            .gauge_style(self.gauge_style)
+            .render(drawer.area, drawer.buffer_mut());
+    }
+
+    fn poll_change(self: std::pin::Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        self.get_mut().source.poll_change(cx)
+    }
+}
@@ -13,12 +13,149 @@ pub use border::*;
 // 模态框组件，支持弹窗、遮罩等交互场景。
 mod modal;
 pub use modal::*;
+// 弹窗管理组件，使多个弹窗按打开顺序层叠，Esc 只关闭最上层弹窗。
+mod modal_provider;
+pub use modal_provider::*;
+// 弹出层组件，把浮层内容定位在锚点元素旁边，空间不足时自动翻转。
+mod popover;
+pub use popover::*;
+// 侧边栏布局组件，可折叠的导航面板加主内容区域，窄终端下侧边栏浮于内容之上。
+mod sidebar_layout;
+pub use sidebar_layout::*;
+// 状态栏组件，单行显示状态项和右对齐的按键提示。
+mod status_bar;
+pub use status_bar::*;
 // 滚动视图组件，支持内容滚动，适合长列表、文档阅读等。
 pub mod scroll_view;
 pub use scroll_view::*;
 // 上下文提供者组件，实现依赖注入和全局状态共享。
 mod context_provider;
 pub use context_provider::*;
+// 运行环境提供者组件，为子树替换命令行参数、环境变量和工作目录。
+mod environment_provider;
+pub use environment_provider::*;
+// 焦点作用域组件，只把按键分发给处于焦点链上的子树。
+mod focus_scope;
+pub use focus_scope::*;
+// 焦点环组件，在持有焦点的子孙组件周围绘制高亮边框。
+mod focus_ring;
+pub use focus_ring::*;
+// 活动边界组件，停用时保持子树挂载但暂停事件、异步任务和计时器。
+mod activity;
+pub use activity::*;
+// 严格模式组件，debug 构建中把子树的更新函数执行两次以暴露不幂等的更新逻辑。
+mod strict_mode;
+pub use strict_mode::*;
+// 历史输出组件，把记录插入到内联视口上方，并支持全屏回看。
+mod history_pane;
+pub use history_pane::*;
+// 异步占位组件，任务完成前显示占位内容，对应 element! 中的 #(await ...) 语法。
+mod suspense;
+pub use suspense::*;
+// 启动画面组件，第一帧立即显示启动内容，异步初始化完成后切换为真正的界面。
+mod splash;
+pub use splash::*;
+// 对话框提供者组件，为子组件提供 async 的确认框和输入框。
+mod dialog;
+pub use dialog::*;
+// 选择模型，供列表、表格等组件共用，支持单选、多选和范围选择。
+mod selection;
+pub use selection::*;
+// 列表组件，支持单选、多选和范围选择。
+mod list;
+pub use list::*;
+// 虚拟滚动列表组件，只渲染可见的行，可按可见范围懒加载数据。
+mod virtual_list;
+pub use virtual_list::*;
+// 树形视图组件，支持展开/折叠和多选。
+mod tree_view;
+pub use tree_view::*;
+// 数据表格组件，支持调整列宽、列顺序、列显隐和自定义单元格渲染。
+mod data_table;
+pub use data_table::*;
+// 菜单栏组件，支持多级下拉菜单、分隔线、禁用项和菜单动作。
+mod menu_bar;
+pub use menu_bar::*;
+// 迷你趋势图组件，自行维护采样历史并自动缩放。
+mod sparkline;
+pub use sparkline::*;
+// 条形仪表组件，显示最新采样值并按历史范围缩放。
+mod meter;
+pub use meter::*;
+// 交互式图表组件，支持缩放、平移和十字光标读数。
+mod plot;
+pub use plot::*;
+// 画布组件，以保留的图形列表绘制，图形不变时复用上一次的绘制结果。
+mod canvas;
+pub use canvas::*;
+// 世界地图组件，显示经纬度标记，支持平移、缩放和选择标记。
+mod world_map;
+pub use world_map::*;
+// 时间线组件，以甘特图显示时间段，支持缩放、平移、当前时间指示和选择。
+mod timeline;
+pub use timeline::*;
+// 文本段落组件，按可用宽度自动换行，高度由测量得到的行数决定。
+mod text;
+pub use text::*;
+// 富文本组件，解析行内标记和 ANSI 转义序列。
+mod rich_text;
+pub use rich_text::*;
+// ANSI 文本组件，显示外部命令的彩色输出。
+mod ansi_text;
+pub use ansi_text::*;
+// 日志组件，滚动显示日志行，停在底部时自动跟随新输出。
+mod log_view;
+pub use log_view::*;
+// 进度条组件，单行显示标签、进度和百分比。
+mod progress_bar;
+pub use progress_bar::*;
+// 秒表和倒计时组件，支持开始、暂停、重置，倒计时结束时触发回调。
+mod timer;
+pub use timer::*;
+// 后台任务面板组件，列出 use_job 启动的任务及其进度。
+mod jobs_panel;
+pub use jobs_panel::*;
+// 快捷键编辑组件，列出动作及其按键，支持交互式重新绑定。
+mod keymap_editor;
+pub use keymap_editor::*;
+// 输入模式提供者组件，为子组件提供类似 Vim 的 Normal/Insert/Visual 模式。
+mod mode_provider;
+pub use mode_provider::*;
+// 输入模式指示组件，以状态栏样式显示当前模式。
+mod mode_indicator;
+pub use mode_indicator::*;
+// 命令行组件，类似 vim 的 `:` 命令行，支持补全和历史。
+mod command_line;
+pub use command_line::*;
+// REPL 输入组件，支持持久化历史、反向搜索、补全和多行续行。
+mod prompt_input;
+pub use prompt_input::*;
+// 自动补全弹窗组件，在内部输入组件的光标下方显示排序后的候选项。
+mod autocomplete_popup;
+pub use autocomplete_popup::*;
+// 单行输入框共用的编辑、校验和绘制逻辑。
+mod field;
+// 数字输入组件，支持范围、步长和方向键增减。
+mod number_input;
+pub use number_input::*;
+// 密码输入组件，以掩码显示并可切换明文。
+mod password_input;
+pub use password_input::*;
+// 掩码输入组件，按 `##/##/####` 等模式输入格式化文本。
+mod masked_input;
+pub use masked_input::*;
+// 滑块组件，在范围内按步长选择数值，支持键盘和鼠标调整。
+mod slider;
+pub use slider::*;
+// 星级评分组件，支持键盘和鼠标选择。
+mod rating;
+pub use rating::*;
+// 超链接组件，支持 OSC 8 的终端可点击，否则降级为普通文本。
+mod link;
+pub use link::*;
+// 多步骤向导组件，支持进度显示、前进后退和逐步校验。
+mod wizard;
+pub use wizard::*;
 
 #[cfg(feature = "textarea")]
 // 多行文本输入组件，支持光标、占位符、行号等，适合编辑器、表单等场景。
@@ -26,8 +163,56 @@ mod textarea;
 #[cfg(feature = "textarea")]
 pub use textarea::*;
 
+#[cfg(feature = "json")]
+// JSON 查看器组件，基于树形视图展示结构化数据，支持复制路径和值。
+mod json_view;
+#[cfg(feature = "json")]
+pub use json_view::*;
+
+#[cfg(feature = "hot-reload")]
+// 热重载组件，开发时监视外部文件或动态库，变化后重新渲染子树。
+mod hot_reload;
+#[cfg(feature = "hot-reload")]
+pub use hot_reload::*;
+
+#[cfg(feature = "config")]
+// 设置页面组件，根据配置项自动生成，可在应用内修改并写回配置文件。
+mod settings;
+#[cfg(feature = "config")]
+pub use settings::*;
+
+#[cfg(feature = "metrics")]
+// 系统指标组件，后台采样 CPU、内存和网络数据，以仪表和趋势图展示。
+mod system_metrics;
+#[cfg(feature = "metrics")]
+pub use system_metrics::*;
+
+#[cfg(feature = "layout")]
+// 插件提供者组件，向子组件提供插件宿主并管理插件生命周期。
+mod plugin_provider;
+#[cfg(feature = "layout")]
+pub use plugin_provider::*;
+
+#[cfg(feature = "parallel")]
+// 并行容器组件，子组件在线程池中并行更新，适合相互独立且计算量大的子树。
+mod parallel;
+#[cfg(feature = "parallel")]
+pub use parallel::*;
+
 #[cfg(feature = "router")]
 // 路由组件，支持页面跳转、参数、嵌套路由等，适合多页面终端应用。
 mod router;
 #[cfg(feature = "router")]
 pub use router::*;
+
+#[cfg(feature = "router")]
+// 应用骨架组件，组合菜单栏、导航侧边栏、路由内容区域和状态栏。
+mod app_shell;
+#[cfg(feature = "router")]
+pub use app_shell::*;
+
+#[cfg(feature = "pty")]
+// 伪终端组件，在 PTY 中运行命令并显示其屏幕，聚焦时转发按键。
+mod terminal_pane;
+#[cfg(feature = "pty")]
+pub use terminal_pane::*;
@@ -6,7 +6,8 @@
 //!     open: open.get(),
 //!     width: Constraint::Percentage(60),
 //!     height: Constraint::Percentage(60),
-//!     style: Style::default().dim(),
+//!     backdrop: Backdrop::Dim(Style::default().dim()),
+//!     on_backdrop_click: move |_| open.set(false),
 //! ){
 //!     Border(top_title: Some(Line::from("弹窗内容"))) {
 //!         // ...子内容
@@ -14,15 +15,28 @@
 //! })
 //! ```
 //! 通过 `open` 控制显示，`placement` 控制弹窗位置，`width/height` 控制尺寸。
+//! `background` 填充弹窗区域的背景，`shadow` 在弹窗右下方绘制阴影，突出层次感。
+//! `backdrop` 只作用于弹窗以外的区域，开启鼠标捕获后点击遮罩触发 `on_backdrop_click`。
+//! 打开时按 `Esc` 触发 `on_close`；多个弹窗需要层叠时放在 [`ModalProvider`] 中，
+//! 由它决定层叠顺序和 `Esc` 关闭哪一个弹窗。
 
+use std::sync::{Arc, Mutex};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseButton, MouseEventKind};
 use ratatui::{
-    layout::{Constraint, Flex, Layout, Margin, Offset},
-    style::Style,
-    widgets::{Block, Clear, Widget},
+    buffer::Buffer,
+    layout::{Constraint, Flex, Layout, Margin, Offset, Position, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Clear, Widget},
 };
 use ratatui_kit_macros::{Props, with_layout_style};
 
-use crate::{AnyElement, Component, layout_style::LayoutStyle};
+use crate::{
+    AnyElement, Component, Context, EventInterest, Handler, Hook, UseEvents,
+    layout_style::LayoutStyle,
+};
+
+use super::{FocusScopeContext, ModalLayer, ModalManager};
 
 #[derive(Default, Clone, Copy)]
 /// 弹窗位置枚举。
@@ -55,18 +69,83 @@ impl Placement {
     }
 }
 
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+/// 弹窗以外区域的遮罩。
+pub enum Backdrop {
+    /// 不绘制遮罩，下层内容保持原样。
+    #[default]
+    None,
+    /// 保留下层的字符，叠加样式，例如 `Style::default().dim()`。
+    Dim(Style),
+    /// 用指定字符和样式填满遮罩区域，完全遮住下层内容。
+    Fill(char, Style),
+}
+
+impl Backdrop {
+    /// 在 `area` 中除 `hole` 以外的部分绘制遮罩。
+    pub(crate) fn render(&self, area: Rect, hole: Rect, buf: &mut Buffer) {
+        if matches!(self, Backdrop::None) {
+            return;
+        }
+        let area = area.intersection(buf.area);
+        let hole = hole.intersection(area);
+        // 弹窗上下左右的四块区域
+        let strips = [
+            Rect::new(area.x, area.y, area.width, hole.y.saturating_sub(area.y)),
+            Rect::new(
+                area.x,
+                hole.bottom(),
+                area.width,
+                area.bottom().saturating_sub(hole.bottom()),
+            ),
+            Rect::new(area.x, hole.y, hole.x.saturating_sub(area.x), hole.height),
+            Rect::new(
+                hole.right(),
+                hole.y,
+                area.right().saturating_sub(hole.right()),
+                hole.height,
+            ),
+        ];
+        for strip in strips.into_iter().filter(|strip| !strip.is_empty()) {
+            match self {
+                Backdrop::None => {}
+                Backdrop::Dim(style) => buf.set_style(strip, *style),
+                Backdrop::Fill(symbol, style) => {
+                    let mut symbol_buf = [0; 4];
+                    let symbol = symbol.encode_utf8(&mut symbol_buf);
+                    for position in strip.positions() {
+                        buf[position].reset();
+                        buf[position].set_symbol(symbol).set_style(*style);
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[with_layout_style(margin, offset, width, height)]
 #[derive(Default, Props)]
 /// Modal 组件属性。
 pub struct ModalProps<'a> {
     /// 弹窗内容。
     pub children: Vec<AnyElement<'a>>,
-    /// 弹窗样式。
+    /// 遮罩样式，`backdrop` 为 None 时等同于 `Backdrop::Dim(style)`，保留用于兼容。
+    #[deprecated(note = "use `backdrop: Backdrop::Dim(style)` instead")]
     pub style: Style,
+    /// 弹窗以外区域的遮罩。
+    pub backdrop: Backdrop,
     /// 弹窗位置。
     pub placement: Placement,
     /// 是否显示弹窗。
     pub open: bool,
+    /// 弹窗区域的背景样式，None 时只清空弹窗区域。
+    pub background: Option<Style>,
+    /// 是否在弹窗右下方绘制阴影。
+    pub shadow: bool,
+    /// 点击遮罩（弹窗以外区域）时的回调，需开启鼠标捕获，常用于关闭弹窗。
+    pub on_backdrop_click: Handler<'static, ()>,
+    /// 打开时按 `Esc` 的回调；在 ModalProvider 中只有最上层弹窗会收到。
+    pub on_close: Handler<'static, ()>,
 }
 
 /// Modal 组件实现。
@@ -77,11 +156,71 @@ pub struct Modal {
     pub width: Constraint,
     pub height: Constraint,
     pub placement: Placement,
+    #[deprecated(note = "use `backdrop` instead")]
     pub style: Style,
+    pub backdrop: Backdrop,
+    pub background: Option<Style>,
+    pub shadow: bool,
+    // 最近一次绘制的遮罩区域和弹窗区域，供点击判断使用
+    regions: Arc<Mutex<Option<(Rect, Rect)>>>,
+    on_close: Arc<Mutex<Handler<'static, ()>>>,
+    // 是否由 ModalProvider 管理，此时遮罩和阴影在合成时绘制
+    managed: bool,
+}
+
+impl Modal {
+    #[allow(deprecated)]
+    fn resolve_backdrop(props: &ModalProps) -> Backdrop {
+        match props.backdrop {
+            Backdrop::None if props.style != Style::default() => Backdrop::Dim(props.style),
+            backdrop => backdrop,
+        }
+    }
+}
+
+// 阴影在弹窗右侧的宽度和下方的高度，终端单元格约为 1:2，右侧更宽看起来更协调
+const SHADOW_WIDTH: u16 = 2;
+const SHADOW_HEIGHT: u16 = 1;
+
+/// 绘制浮层的底面：按需在右下方绘制阴影，然后清空 `area` 并填充背景。
+///
+/// 阴影保留下层的字符，只把颜色压暗，Modal、对话框、下拉菜单等浮层共用。
+pub(crate) fn render_surface(
+    area: Rect,
+    buf: &mut Buffer,
+    background: Option<Style>,
+    shadow: bool,
+) {
+    if shadow && !area.is_empty() {
+        let shadow_style = Style::new()
+            .fg(Color::DarkGray)
+            .bg(Color::Black)
+            .add_modifier(Modifier::DIM);
+        let right = Rect::new(
+            area.right(),
+            area.y + SHADOW_HEIGHT,
+            SHADOW_WIDTH,
+            area.height,
+        );
+        let bottom = Rect::new(
+            area.x + SHADOW_WIDTH,
+            area.bottom(),
+            area.width,
+            SHADOW_HEIGHT,
+        );
+        for shadow in [right, bottom] {
+            buf.set_style(shadow.intersection(buf.area), shadow_style);
+        }
+    }
+    Clear.render(area, buf);
+    if let Some(background) = background {
+        buf.set_style(area, background);
+    }
 }
 
 impl Component for Modal {
     type Props<'a> = ModalProps<'a>;
+    #[allow(deprecated)]
     fn new(props: &Self::Props<'_>) -> Self {
         Modal {
             open: props.open,
@@ -89,27 +228,108 @@ impl Component for Modal {
             offset: props.offset,
             width: props.width,
             height: props.height,
-            style: props.style,
             placement: props.placement,
+            style: props.style,
+            backdrop: Self::resolve_backdrop(props),
+            background: props.background,
+            shadow: props.shadow,
+            regions: Arc::new(Mutex::new(None)),
+            on_close: Arc::new(Mutex::new(Handler::default())),
+            managed: false,
         }
     }
 
     fn update(
         &mut self,
         props: &mut Self::Props<'_>,
-        _hooks: crate::Hooks,
+        mut hooks: crate::Hooks,
         updater: &mut crate::ComponentUpdater,
     ) {
+        let manager = updater
+            .get_context::<ModalManager>()
+            .map(|manager| manager.clone());
+        let parent_scope = FocusScopeContext::current(updater);
+        let layer = hooks.use_hook(|| UseModalLayerImpl {
+            id: manager.as_ref().map_or(0, ModalManager::next_id),
+            scope: FocusScopeContext::modal(parent_scope),
+            manager,
+            regions: self.regions.clone(),
+            open: false,
+            backdrop: Backdrop::None,
+            shadow: false,
+            parent_buffer: None,
+        });
+        layer.open = props.open;
+        layer.backdrop = Self::resolve_backdrop(props);
+        layer.shadow = props.shadow;
+        *self.on_close.lock().unwrap() = props.on_close.take();
+        if let Some(manager) = &layer.manager {
+            if props.open {
+                manager.open(layer.id, &layer.scope, &self.on_close);
+            } else {
+                manager.close(layer.id);
+            }
+        }
+        self.managed = layer.manager.is_some();
+        let (manager, id) = (layer.manager.clone(), layer.id);
+        let context = self.managed.then(|| Context::owned(layer.scope.clone()));
+
+        // 弹窗自身不占布局空间，只能通过全局事件判断点击位置
+        hooks.use_events_of(EventInterest::KEY | EventInterest::MOUSE, {
+            let open = props.open;
+            let regions = self.regions.clone();
+            let on_close = self.on_close.clone();
+            let mut on_backdrop_click = props.on_backdrop_click.take();
+            move |event| {
+                if !open {
+                    return;
+                }
+                match event {
+                    // 受管理的弹窗由 ModalProvider 统一处理 Esc
+                    Event::Key(key)
+                        if manager.is_none()
+                            && key.kind == KeyEventKind::Press
+                            && key.code == KeyCode::Esc =>
+                    {
+                        (on_close.lock().unwrap())(());
+                    }
+                    Event::Mouse(mouse)
+                        if mouse.kind == MouseEventKind::Down(MouseButton::Left) =>
+                    {
+                        if !manager.as_ref().is_none_or(|manager| manager.is_top(id)) {
+                            return;
+                        }
+                        let position = Position::new(mouse.column, mouse.row);
+                        let hit = regions.lock().unwrap().is_some_and(|(backdrop, modal)| {
+                            backdrop.contains(position) && !modal.contains(position)
+                        });
+                        if hit {
+                            on_backdrop_click(());
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        });
+
         self.open = props.open;
         self.margin = props.margin;
         self.offset = props.offset;
         self.width = props.width;
         self.height = props.height;
-        self.style = props.style;
         self.placement = props.placement;
+        #[allow(deprecated)]
+        {
+            self.style = props.style;
+        }
+        self.backdrop = Self::resolve_backdrop(props);
+        self.background = props.background;
+        self.shadow = props.shadow;
 
         if self.open {
-            updater.update_children(props.children.iter_mut(), None);
+            updater.update_children(props.children.iter_mut(), context);
+        } else {
+            *self.regions.lock().unwrap() = None;
         }
 
         updater.set_layout_style(LayoutStyle {
@@ -123,16 +343,76 @@ impl Component for Modal {
         if self.open {
             let area = drawer.buffer_mut().area();
             let area = area.inner(self.margin).offset(self.offset);
-            let block = Block::default().style(self.style);
-            block.render(area, drawer.buffer_mut());
 
             let [v, h] = self.placement.to_flex();
 
             let vertical = Layout::vertical([self.height]).flex(v).split(area)[0];
             let horizontal = Layout::horizontal([self.width]).flex(h).split(vertical)[0];
 
-            Clear.render(horizontal, drawer.buffer_mut());
+            *self.regions.lock().unwrap() = Some((area, horizontal));
+            if self.managed {
+                // 绘制在单独的图层中，遮罩和阴影作用于下层内容，合成时再绘制
+                render_surface(horizontal, drawer.buffer_mut(), self.background, false);
+            } else {
+                self.backdrop.render(area, horizontal, drawer.buffer_mut());
+                render_surface(
+                    horizontal,
+                    drawer.buffer_mut(),
+                    self.background,
+                    self.shadow,
+                );
+            }
             drawer.area = horizontal;
         }
     }
 }
+
+struct UseModalLayerImpl {
+    manager: Option<ModalManager>,
+    id: u64,
+    scope: FocusScopeContext,
+    regions: Arc<Mutex<Option<(Rect, Rect)>>>,
+    open: bool,
+    backdrop: Backdrop,
+    shadow: bool,
+    parent_buffer: Option<Option<Buffer>>,
+}
+
+impl Hook for UseModalLayerImpl {
+    fn pre_component_draw(&mut self, drawer: &mut crate::ComponentDrawer) {
+        // 受管理的弹窗绘制到单独的图层，由 ModalProvider 按层叠顺序合成
+        if self.manager.is_some() && self.open {
+            let area = drawer.buffer_mut().area;
+            self.parent_buffer = Some(drawer.scroll_buffer.replace(Buffer::empty(area)));
+        }
+    }
+
+    fn post_component_draw(&mut self, drawer: &mut crate::ComponentDrawer) {
+        let (Some(manager), Some(parent_buffer)) = (&self.manager, self.parent_buffer.take())
+        else {
+            return;
+        };
+        let buffer = std::mem::replace(&mut drawer.scroll_buffer, parent_buffer);
+        let regions = *self.regions.lock().unwrap();
+        if let (Some(buffer), Some((area, modal))) = (buffer, regions) {
+            manager.push_layer(
+                self.id,
+                ModalLayer {
+                    buffer,
+                    backdrop: self.backdrop,
+                    area,
+                    modal,
+                    shadow: self.shadow,
+                },
+            );
+        }
+    }
+}
+
+impl Drop for UseModalLayerImpl {
+    fn drop(&mut self) {
+        if let Some(manager) = &self.manager {
+            manager.close(self.id);
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! ModalProvider 组件：管理子树中的 Modal，使多个弹窗可以层叠显示。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(ModalProvider {
+//!     Settings()
+//! })
+//!
+//! // Settings 中的弹窗可以再打开确认框，确认框显示在设置弹窗之上
+//! element!(Modal(open: settings.get(), on_close: move |_| settings.set(false)) {
+//!     SettingsForm()
+//!     Modal(open: confirm.get(), on_close: move |_| confirm.set(false)) {
+//!         ConfirmReset()
+//!     }
+//! })
+//! ```
+//! - 弹窗按打开顺序层叠，后打开的在上层，与弹窗在组件树中的位置无关；
+//! - `Esc` 只触发最上层弹窗的 `on_close`，遮罩点击同样只对最上层弹窗生效；
+//! - 下层弹窗保持挂载、状态不变，但其中的监听器收不到按键和粘贴事件，
+//!   最上层弹窗关闭后自动恢复；
+//! - 弹窗内容先绘制到单独的缓冲区，子树绘制完成后按层叠顺序合成，
+//!   因此 Modal 不应放在 ScrollView 等使用独立缓冲区的组件中。
+//!
+//! 在子组件中可以通过 `hooks.use_context::<ModalManager>()` 获取弹窗栈，查询打开的弹窗数量或关闭最上层弹窗。
+
+use std::sync::{Arc, Mutex};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind};
+use ratatui::{
+    buffer::{Buffer, Cell},
+    layout::Rect,
+};
+use ratatui_kit_macros::Props;
+
+use crate::{AnyElement, Component, Context, EventInterest, Handler, Hook, UseEvents};
+
+use super::{
+    FocusScopeContext,
+    modal::{Backdrop, render_surface},
+};
+
+#[derive(Props)]
+/// ModalProvider 组件属性。
+pub struct ModalProviderProps<'a> {
+    /// 子元素列表。
+    pub children: Vec<AnyElement<'a>>,
+    /// 是否按 `Esc` 关闭最上层弹窗。
+    pub close_on_esc: bool,
+}
+
+impl Default for ModalProviderProps<'_> {
+    fn default() -> Self {
+        Self {
+            children: Vec::new(),
+            close_on_esc: true,
+        }
+    }
+}
+
+struct ModalEntry {
+    id: u64,
+    scope: FocusScopeContext,
+    on_close: Arc<Mutex<Handler<'static, ()>>>,
+}
+
+/// 弹窗在一帧中绘制的内容，由 ModalProvider 按层叠顺序合成。
+pub(crate) struct ModalLayer {
+    pub buffer: Buffer,
+    pub backdrop: Backdrop,
+    pub area: Rect,
+    pub modal: Rect,
+    pub shadow: bool,
+}
+
+#[derive(Default)]
+struct ModalStack {
+    next_id: u64,
+    // 按打开顺序排列，最后一个在最上层
+    entries: Vec<ModalEntry>,
+    layers: Vec<(u64, ModalLayer)>,
+}
+
+impl ModalStack {
+    // 只有最上层弹窗的作用域处于焦点链上
+    fn refresh(&self) {
+        let top = self.entries.len().saturating_sub(1);
+        for (index, entry) in self.entries.iter().enumerate() {
+            entry.scope.set_active(index == top);
+        }
+    }
+}
+
+/// 弹窗栈上下文，由 ModalProvider 提供给子树。
+#[derive(Clone, Default)]
+pub struct ModalManager {
+    stack: Arc<Mutex<ModalStack>>,
+}
+
+impl ModalManager {
+    /// 当前打开的弹窗数量。
+    pub fn len(&self) -> usize {
+        self.stack.lock().unwrap().entries.len()
+    }
+
+    /// 是否没有打开的弹窗。
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 调用最上层弹窗的 `on_close`，没有打开的弹窗时返回 false。
+    pub fn close_top(&self) -> bool {
+        let on_close = {
+            let stack = self.stack.lock().unwrap();
+            stack.entries.last().map(|entry| entry.on_close.clone())
+        };
+        // 不持有锁调用回调，回调中可以再次访问弹窗栈
+        match on_close {
+            Some(on_close) => {
+                (on_close.lock().unwrap())(());
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub(crate) fn next_id(&self) -> u64 {
+        let mut stack = self.stack.lock().unwrap();
+        stack.next_id += 1;
+        stack.next_id
+    }
+
+    /// 弹窗打开时入栈，已在栈中时保持原有位置。
+    pub(crate) fn open(
+        &self,
+        id: u64,
+        scope: &FocusScopeContext,
+        on_close: &Arc<Mutex<Handler<'static, ()>>>,
+    ) {
+        let mut stack = self.stack.lock().unwrap();
+        if stack.entries.iter().all(|entry| entry.id != id) {
+            stack.entries.push(ModalEntry {
+                id,
+                scope: scope.clone(),
+                on_close: on_close.clone(),
+            });
+        }
+        stack.refresh();
+    }
+
+    /// 弹窗关闭或卸载时出栈，下层弹窗恢复接收按键。
+    pub(crate) fn close(&self, id: u64) {
+        let mut stack = self.stack.lock().unwrap();
+        // 关闭的弹窗保留子组件，其中的监听器不应再收到按键
+        if let Some(entry) = stack.entries.iter().find(|entry| entry.id == id) {
+            entry.scope.set_active(false);
+        }
+        stack.entries.retain(|entry| entry.id != id);
+        stack.layers.retain(|(layer, _)| *layer != id);
+        stack.refresh();
+    }
+
+    pub(crate) fn is_top(&self, id: u64) -> bool {
+        let stack = self.stack.lock().unwrap();
+        stack.entries.last().is_some_and(|entry| entry.id == id)
+    }
+
+    pub(crate) fn push_layer(&self, id: u64, layer: ModalLayer) {
+        self.stack.lock().unwrap().layers.push((id, layer));
+    }
+
+    /// 按层叠顺序把本帧绘制的弹窗合成到 `buf`。
+    fn composite(&self, buf: &mut Buffer) {
+        let mut stack = self.stack.lock().unwrap();
+        let mut layers = std::mem::take(&mut stack.layers);
+        let order = |id: u64| stack.entries.iter().position(|entry| entry.id == id);
+        layers.retain(|(id, _)| order(*id).is_some());
+        layers.sort_by_key(|(id, _)| order(*id));
+        for (_, layer) in layers {
+            layer.backdrop.render(layer.area, layer.modal, buf);
+            render_surface(layer.modal, buf, None, layer.shadow);
+            // 弹窗区域已在图层中清空并填充背景，其余位置只复制子组件绘制过的单元格
+            for position in layer.buffer.area.intersection(buf.area).positions() {
+                let cell = &layer.buffer[position];
+                if layer.modal.contains(position) || *cell != Cell::EMPTY {
+                    buf[position] = cell.clone();
+                }
+            }
+        }
+    }
+}
+
+/// ModalProvider 组件实现。
+pub struct ModalProvider {
+    manager: ModalManager,
+}
+
+impl Component for ModalProvider {
+    type Props<'a> = ModalProviderProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self {
+            manager: ModalManager::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: crate::Hooks,
+        updater: &mut crate::ComponentUpdater,
+    ) {
+        hooks.use_hook({
+            let manager = self.manager.clone();
+            move || UseModalHostImpl { manager }
+        });
+        // 下层弹窗中的监听器收不到按键，由外层统一处理 Esc
+        hooks.use_events_of(EventInterest::KEY, {
+            let manager = self.manager.clone();
+            let close_on_esc = props.close_on_esc;
+            move |event| {
+                let Event::Key(key) = event else {
+                    return;
+                };
+                if close_on_esc && key.kind == KeyEventKind::Press && key.code == KeyCode::Esc {
+                    manager.close_top();
+                }
+            }
+        });
+
+        updater.set_transparent_layout(true);
+        updater.update_children(
+            &mut props.children,
+            Some(Context::owned(self.manager.clone())),
+        );
+    }
+}
+
+struct UseModalHostImpl {
+    manager: ModalManager,
+}
+
+impl Hook for UseModalHostImpl {
+    fn post_component_draw(&mut self, drawer: &mut crate::ComponentDrawer) {
+        // 子树绘制完成后，在最上层按打开顺序合成弹窗
+        self.manager.composite(drawer.buffer_mut());
+    }
+}
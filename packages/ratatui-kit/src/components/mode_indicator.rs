@@ -0,0 +1,55 @@
+//! ModeIndicator 组件：以 Vim 状态栏的样式显示当前输入模式，需在 ModeProvider 内部使用。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(View(flex_direction: Direction::Horizontal) {
+//!     ModeIndicator()
+//!     Text(text: file_name)
+//! })
+//! ```
+//! 宽度由模式名称决定，可直接放在水平排列的状态栏中。
+
+use ratatui::{
+    style::{Style, Stylize},
+    text::Line,
+};
+use ratatui_kit_macros::{Props, component, element};
+
+use super::Text;
+use crate::{AnyElement, Hooks, InputMode, UseMode};
+
+#[derive(Props)]
+/// ModeIndicator 组件属性。
+pub struct ModeIndicatorProps {
+    /// 普通模式样式。
+    pub normal_style: Style,
+    /// 插入模式样式。
+    pub insert_style: Style,
+    /// 可视模式样式。
+    pub visual_style: Style,
+}
+
+impl Default for ModeIndicatorProps {
+    fn default() -> Self {
+        Self {
+            normal_style: Style::default().black().on_blue().bold(),
+            insert_style: Style::default().black().on_green().bold(),
+            visual_style: Style::default().black().on_magenta().bold(),
+        }
+    }
+}
+
+#[component]
+pub fn ModeIndicator(
+    props: &ModeIndicatorProps,
+    mut hooks: Hooks,
+) -> impl Into<AnyElement<'static>> {
+    let mode = hooks.use_mode().get();
+    let style = match mode {
+        InputMode::Normal => props.normal_style,
+        InputMode::Insert => props.insert_style,
+        InputMode::Visual => props.visual_style,
+    };
+
+    element!(Text(text: Line::styled(format!(" {mode} "), style), wrap: false))
+}
@@ -0,0 +1,128 @@
+//! ModeProvider 组件：为子组件提供类似 Vim 的输入模式（Normal/Insert/Visual）。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(ModeProvider {
+//!     Editor()
+//!     ModeIndicator()
+//! })
+//!
+//! // 在子组件中
+//! let mode = hooks.use_mode();
+//! hooks.use_keymap(&KEYMAP, move |action| { ... });
+//! ```
+//! - 开启 `transitions`（默认开启）时内置 Vim 的模式切换：普通模式下 `i`/`a` 进入插入模式，
+//!   `v` 进入可视模式；任意模式下 `Esc` 回到普通模式，可视模式下再按 `v` 也会回到普通模式。
+//! - 子组件先于本组件处理同一按键，因此触发切换的按键（如进入插入模式的 `i`）仍按切换前的模式处理。
+//! - 子组件中的 `use_keymap` 按当前模式匹配动作，见 [`Keymap::in_modes`](crate::Keymap::in_modes)。
+
+use std::{pin::pin, task::Poll};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers};
+use futures::Stream;
+use ratatui_kit_macros::Props;
+
+use crate::{AnyElement, Component, Context, EventInterest, Hook, InputMode, Mode, TerminalEvents};
+
+#[derive(Props)]
+/// ModeProvider 组件属性。
+pub struct ModeProviderProps<'a> {
+    /// 子元素列表。
+    pub children: Vec<AnyElement<'a>>,
+    /// 初始模式。
+    pub initial_mode: InputMode,
+    /// 是否启用内置的模式切换按键。
+    pub transitions: bool,
+}
+
+impl Default for ModeProviderProps<'_> {
+    fn default() -> Self {
+        Self {
+            children: Vec::new(),
+            initial_mode: InputMode::Normal,
+            transitions: true,
+        }
+    }
+}
+
+/// ModeProvider 组件实现。
+pub struct ModeProvider;
+
+impl Component for ModeProvider {
+    type Props<'a> = ModeProviderProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: crate::Hooks,
+        updater: &mut crate::ComponentUpdater,
+    ) {
+        let host = hooks.use_hook(|| {
+            let mode = Mode::default();
+            mode.set(props.initial_mode);
+            UseModeHostImpl {
+                mode,
+                events: None,
+                transitions: true,
+            }
+        });
+        host.transitions = props.transitions;
+        let mode = host.mode.clone();
+
+        updater.set_transparent_layout(true);
+        updater.update_children(&mut props.children, Some(Context::owned(mode)));
+    }
+}
+
+struct UseModeHostImpl {
+    mode: Mode,
+    events: Option<TerminalEvents<Event>>,
+    transitions: bool,
+}
+
+impl UseModeHostImpl {
+    fn handle_event(&self, event: Event) {
+        let Event::Key(key) = event else {
+            return;
+        };
+        if !self.transitions
+            || key.kind != KeyEventKind::Press
+            || !(key.modifiers - KeyModifiers::SHIFT).is_empty()
+        {
+            return;
+        }
+        let next = match (self.mode.get(), key.code) {
+            (_, KeyCode::Esc) => InputMode::Normal,
+            (InputMode::Normal, KeyCode::Char('i' | 'a')) => InputMode::Insert,
+            (InputMode::Normal, KeyCode::Char('v')) => InputMode::Visual,
+            (InputMode::Visual, KeyCode::Char('v')) => InputMode::Normal,
+            _ => return,
+        };
+        self.mode.set(next);
+    }
+}
+
+impl Hook for UseModeHostImpl {
+    fn poll_change(self: std::pin::Pin<&mut Self>, cx: &mut std::task::Context) -> Poll<()> {
+        let this = self.get_mut();
+        while let Some(Poll::Ready(Some(event))) = this
+            .events
+            .as_mut()
+            .map(|events| pin!(events).poll_next(cx))
+        {
+            this.handle_event(event);
+        }
+        // 模式变化由使用 use_mode 的子组件各自重新渲染
+        Poll::Pending
+    }
+
+    fn post_component_update(&mut self, updater: &mut crate::ComponentUpdater) {
+        if self.events.is_none() {
+            self.events = updater.events_of(EventInterest::KEY).ok();
+        }
+    }
+}
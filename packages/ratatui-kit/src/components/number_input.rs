@@ -0,0 +1,223 @@
+//! NumberInput 组件：数字输入框，支持最小/最大值、步长和方向键增减。
+//!
+//! ## 用法示例
+//! ```rust
+//! let mut port = hooks.use_state(|| 8080.0);
+//! element!(NumberInput(
+//!     label: "端口",
+//!     value: port.get(),
+//!     min: 1.0,
+//!     max: 65535.0,
+//!     is_focus: true,
+//!     on_change: move |value| port.set(value),
+//! ))
+//! ```
+//! - `↑/↓` 按步长增减，`PageUp/PageDown` 按十倍步长增减，结果限制在 `min..=max` 内。
+//! - 也可以直接输入数字，解析失败或超出范围时显示错误信息，不触发 `on_change`。
+//! - `precision` 控制显示的小数位数；`validate` 可追加自定义校验。
+//! - `read_only` 时只能移动光标；`disabled` 时不显示光标、不响应按键，并以暗淡样式显示。
+
+use crossterm::event::{Event, KeyCode, KeyEventKind};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Rect, Size},
+    style::{Style, Stylize},
+};
+use ratatui_kit_macros::Props;
+
+use super::field::{Field, FieldState, FieldStyle};
+use crate::{Component, Handler, Hooks, State, UseEvents, UseState};
+
+#[derive(Props)]
+/// NumberInput 组件属性。
+pub struct NumberInputProps {
+    /// 当前值。
+    pub value: f64,
+    /// 最小值。
+    pub min: f64,
+    /// 最大值。
+    pub max: f64,
+    /// 方向键增减的步长。
+    pub step: f64,
+    /// 显示的小数位数。
+    pub precision: usize,
+    /// 输入框前的标签。
+    pub label: String,
+    /// 是否聚焦。
+    pub is_focus: bool,
+    /// 是否只读，只读时只能移动光标，不能修改内容。
+    pub read_only: bool,
+    /// 是否禁用，禁用时忽略 `is_focus`，不响应任何事件。
+    pub disabled: bool,
+    /// 值变更回调，只在输入合法时触发。
+    pub on_change: Handler<'static, f64>,
+    /// 校验回调，返回错误信息，通过时返回 None。
+    pub validate: Handler<'static, f64, Option<String>>,
+    /// 标签样式。
+    pub label_style: Style,
+    /// 输入内容样式。
+    pub style: Style,
+    /// 光标样式。
+    pub cursor_style: Style,
+    /// 错误信息样式。
+    pub error_style: Style,
+}
+
+impl Default for NumberInputProps {
+    fn default() -> Self {
+        Self {
+            value: 0.0,
+            min: f64::NEG_INFINITY,
+            max: f64::INFINITY,
+            step: 1.0,
+            precision: 0,
+            label: String::new(),
+            is_focus: false,
+            read_only: false,
+            disabled: false,
+            on_change: Handler::default(),
+            validate: Handler::default(),
+            label_style: Style::default().bold(),
+            style: Style::default(),
+            cursor_style: Style::default().reversed(),
+            error_style: Style::default().red(),
+        }
+    }
+}
+
+/// NumberInput 组件实现。
+pub struct NumberInput {
+    state: Option<State<FieldState>>,
+    label: String,
+    is_focus: bool,
+    disabled: bool,
+    style: FieldStyle,
+}
+
+impl NumberInput {
+    fn render_field(&self, f: impl FnOnce(&Field)) {
+        let Some(state) = self.state else {
+            return;
+        };
+        let state = state.read();
+        f(&Field {
+            label: &self.label,
+            display: &state.text,
+            placeholder: "",
+            cursor: self.is_focus.then_some(state.cursor),
+            error: state.error.as_deref(),
+            style: self.style,
+            disabled: self.disabled,
+        })
+    }
+}
+
+impl Component for NumberInput {
+    type Props<'a> = NumberInputProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            state: None,
+            label: props.label.clone(),
+            is_focus: props.is_focus && !props.disabled,
+            disabled: props.disabled,
+            style: FieldStyle::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        _updater: &mut crate::ComponentUpdater,
+    ) {
+        let (min, max, step, precision) = (props.min, props.max, props.step, props.precision);
+        let format = move |value: f64| format!("{value:.precision$}");
+        let state = hooks.use_state(FieldState::default);
+        let value = format(props.value);
+        if state.read().needs_sync(&value) {
+            state.write().sync(&value);
+        }
+        self.state = Some(state);
+        self.label = props.label.clone();
+        self.is_focus = props.is_focus && !props.disabled;
+        self.disabled = props.disabled;
+        self.style = FieldStyle {
+            label: props.label_style,
+            text: props.style,
+            cursor: props.cursor_style,
+            error: props.error_style,
+            ..FieldStyle::default()
+        };
+
+        hooks.use_local_events({
+            let is_focus = props.is_focus && !props.disabled;
+            let read_only = props.read_only;
+            let current = props.value;
+            let mut on_change = props.on_change.take();
+            let mut validate = props.validate.take();
+            move |event| {
+                let Event::Key(key) = event else {
+                    return;
+                };
+                if !is_focus || key.kind == KeyEventKind::Release {
+                    return;
+                }
+                let mut s = state.write();
+                if read_only {
+                    s.navigate(&key);
+                    return;
+                }
+                let delta = match key.code {
+                    KeyCode::Up => step,
+                    KeyCode::Down => -step,
+                    KeyCode::PageUp => step * 10.0,
+                    KeyCode::PageDown => -step * 10.0,
+                    _ => 0.0,
+                };
+                let value = if delta != 0.0 {
+                    // 输入内容无法解析时从上次提交的值开始增减
+                    let base = s.text.trim().parse::<f64>().unwrap_or(current);
+                    let value = (base + delta).clamp(min, max);
+                    s.set_text(format(value));
+                    value
+                } else {
+                    let accept = |c: char| c.is_ascii_digit() || matches!(c, '.' | '-' | '+');
+                    if !s.edit(&key, accept) {
+                        return;
+                    }
+                    match s.text.trim().parse::<f64>() {
+                        Ok(value) if (min..=max).contains(&value) => value,
+                        Ok(value) => {
+                            s.error = Some(if value < min {
+                                format!("不能小于 {}", format(min))
+                            } else {
+                                format!("不能大于 {}", format(max))
+                            });
+                            return;
+                        }
+                        Err(_) => {
+                            s.error = Some("请输入数字".to_string());
+                            return;
+                        }
+                    }
+                };
+                s.error = validate(value);
+                let text = format(value);
+                s.commit(&text);
+                drop(s);
+                on_change(value);
+            }
+        });
+    }
+
+    fn measure(&self, max: Size) -> Option<Size> {
+        let mut height = 1;
+        self.render_field(|field| height = field.height());
+        Some(Size::new(max.width, height))
+    }
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        self.render_field(|field| field.render(area, buf));
+    }
+}
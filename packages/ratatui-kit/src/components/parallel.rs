@@ -0,0 +1,109 @@
+//! Parallel 组件：布局与 View 相同的容器，子组件在 rayon 线程池中并行更新。
+//!
+//! 适合并排显示多个相互独立且更新开销较大的子树，如同时渲染多个 Markdown 文档或差异视图。
+//!
+//! ## 示例
+//! ```rust
+//! element!(Parallel(flex_direction: Direction::Horizontal) {
+//!     MarkdownView(source: left),
+//!     MarkdownView(source: right),
+//! })
+//! ```
+//! 祖先提供的上下文（包括 `SystemContext`）在子树中只读，`use_context_mut` 找不到它们时会 panic，
+//! 可改用 `try_use_context_mut`，或通过 store、`State` 修改共享状态；子树内部提供的上下文不受影响。
+//! 终端由各线程通过互斥锁共享，`ComponentUpdater::terminal` 会锁住终端直到组件更新结束，只需短暂访问时可用 `ComponentUpdater::terminal_guard`。绘制仍按顺序串行进行。
+
+use ratatui_kit_macros::{Props, with_layout_style};
+
+use crate::{AnyElement, Component};
+
+#[with_layout_style]
+#[derive(Default, Props)]
+/// Parallel 组件属性。
+pub struct ParallelProps<'a> {
+    /// 子元素列表，每个子元素在线程池中独立更新。
+    pub children: Vec<AnyElement<'a>>,
+}
+
+/// Parallel 组件实现。
+pub struct Parallel;
+
+impl Component for Parallel {
+    type Props<'a> = ParallelProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        _hooks: crate::Hooks,
+        updater: &mut crate::ComponentUpdater,
+    ) {
+        updater.set_layout_style(props.layout_style());
+        updater.update_children_parallel(&mut props.children, None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, test_util::mount};
+
+    #[component]
+    fn Spinner(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut frame = hooks.use_state(|| 0);
+        frame.set(frame.get() + 1);
+        element!(Text(text: frame.get().to_string()))
+    }
+
+    #[derive(Default, Props)]
+    struct ProbeProps {
+        nested: bool,
+    }
+
+    // 更新时访问终端，nested 时再并行更新一个子组件
+    struct Probe;
+
+    impl Component for Probe {
+        type Props<'a> = ProbeProps;
+
+        fn new(_props: &Self::Props<'_>) -> Self {
+            Self
+        }
+
+        fn update(
+            &mut self,
+            props: &mut Self::Props<'_>,
+            mut hooks: Hooks,
+            updater: &mut ComponentUpdater,
+        ) {
+            updater.terminal().render_mode();
+            hooks.use_events(|_| {});
+            if props.nested {
+                updater.update_children_parallel([element!(Probe(key: "inner"))], None);
+            }
+        }
+    }
+
+    #[test]
+    fn terminal_is_available_in_parallel_children() {
+        let mut element = element!(Parallel {
+            Probe(key: "left", nested: true)
+            Probe(key: "right")
+        });
+        let mut app = mount(&mut element);
+        app.poll_until_idle();
+    }
+
+    #[test]
+    #[should_panic(expected = "Render loop detected")]
+    fn detects_render_loops_in_parallel_children() {
+        let mut element = element!(Parallel {
+            Spinner(key: "left")
+            Spinner(key: "right")
+        });
+        let mut app = mount(&mut element);
+        app.poll_until_idle();
+    }
+}
@@ -0,0 +1,204 @@
+//! PasswordInput 组件：密码输入框，以掩码字符显示输入内容，可切换明文显示。
+//!
+//! ## 用法示例
+//! ```rust
+//! let mut password = hooks.use_state(String::new);
+//! element!(PasswordInput(
+//!     label: "密码",
+//!     value: password.read().clone(),
+//!     is_focus: true,
+//!     on_change: move |value| password.set(value),
+//!     validate: |value: String| (value.len() < 8).then(|| "至少 8 个字符".to_string()),
+//! ))
+//! ```
+//! `Ctrl+R` 切换明文显示，`Ctrl+U` 清空；`validate` 返回的错误信息显示在输入框下方。
+//!
+//! `read_only` 时只能移动光标和切换明文显示；`disabled` 时不显示光标、不响应按键，并以暗淡样式显示。
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Rect, Size},
+    style::{Style, Stylize},
+};
+use ratatui_kit_macros::Props;
+
+use super::field::{Field, FieldState, FieldStyle};
+use crate::{Component, Handler, Hooks, State, UseEvents, UseState};
+
+#[derive(Props)]
+/// PasswordInput 组件属性。
+pub struct PasswordInputProps {
+    /// 当前值。
+    pub value: String,
+    /// 输入框前的标签。
+    pub label: String,
+    /// 内容为空时显示的占位符。
+    pub placeholder: String,
+    /// 掩码字符。
+    pub mask: char,
+    /// 初始是否明文显示。
+    pub revealed: bool,
+    /// 是否聚焦。
+    pub is_focus: bool,
+    /// 是否只读，只读时只能移动光标，不能修改内容。
+    pub read_only: bool,
+    /// 是否禁用，禁用时忽略 `is_focus`，不响应任何事件。
+    pub disabled: bool,
+    /// 内容变更回调。
+    pub on_change: Handler<'static, String>,
+    /// 校验回调，返回错误信息，通过时返回 None。
+    pub validate: Handler<'static, String, Option<String>>,
+    /// 标签样式。
+    pub label_style: Style,
+    /// 输入内容样式。
+    pub style: Style,
+    /// 占位符样式。
+    pub placeholder_style: Style,
+    /// 光标样式。
+    pub cursor_style: Style,
+    /// 错误信息样式。
+    pub error_style: Style,
+}
+
+impl Default for PasswordInputProps {
+    fn default() -> Self {
+        Self {
+            value: String::new(),
+            label: String::new(),
+            placeholder: String::new(),
+            mask: '•',
+            revealed: false,
+            is_focus: false,
+            read_only: false,
+            disabled: false,
+            on_change: Handler::default(),
+            validate: Handler::default(),
+            label_style: Style::default().bold(),
+            style: Style::default(),
+            placeholder_style: Style::default().dim(),
+            cursor_style: Style::default().reversed(),
+            error_style: Style::default().red(),
+        }
+    }
+}
+
+/// PasswordInput 组件实现。
+pub struct PasswordInput {
+    state: Option<State<FieldState>>,
+    revealed: bool,
+    mask: char,
+    label: String,
+    placeholder: String,
+    is_focus: bool,
+    disabled: bool,
+    style: FieldStyle,
+}
+
+impl PasswordInput {
+    fn render_field(&self, f: impl FnOnce(&Field)) {
+        let Some(state) = self.state else {
+            return;
+        };
+        let state = state.read();
+        let display = if self.revealed {
+            state.text.clone()
+        } else {
+            self.mask.to_string().repeat(state.text.chars().count())
+        };
+        f(&Field {
+            label: &self.label,
+            display: &display,
+            placeholder: &self.placeholder,
+            cursor: self.is_focus.then_some(state.cursor),
+            error: state.error.as_deref(),
+            style: self.style,
+            disabled: self.disabled,
+        })
+    }
+}
+
+impl Component for PasswordInput {
+    type Props<'a> = PasswordInputProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            state: None,
+            revealed: props.revealed,
+            mask: props.mask,
+            label: props.label.clone(),
+            placeholder: props.placeholder.clone(),
+            is_focus: props.is_focus && !props.disabled,
+            disabled: props.disabled,
+            style: FieldStyle::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        _updater: &mut crate::ComponentUpdater,
+    ) {
+        let state = hooks.use_state(FieldState::default);
+        let mut revealed = hooks.use_state(|| props.revealed);
+        if state.read().needs_sync(&props.value) {
+            state.write().sync(&props.value);
+        }
+        self.state = Some(state);
+        self.revealed = revealed.get();
+        self.mask = props.mask;
+        self.label = props.label.clone();
+        self.placeholder = props.placeholder.clone();
+        self.is_focus = props.is_focus && !props.disabled;
+        self.disabled = props.disabled;
+        self.style = FieldStyle {
+            label: props.label_style,
+            text: props.style,
+            placeholder: props.placeholder_style,
+            cursor: props.cursor_style,
+            error: props.error_style,
+        };
+
+        hooks.use_local_events({
+            let is_focus = props.is_focus && !props.disabled;
+            let read_only = props.read_only;
+            let mut on_change = props.on_change.take();
+            let mut validate = props.validate.take();
+            move |event| {
+                let Event::Key(key) = event else {
+                    return;
+                };
+                if !is_focus || key.kind == KeyEventKind::Release {
+                    return;
+                }
+                if key.code == KeyCode::Char('r') && key.modifiers == KeyModifiers::CONTROL {
+                    revealed.set(!revealed.get());
+                    return;
+                }
+                let mut s = state.write();
+                if read_only {
+                    s.navigate(&key);
+                    return;
+                }
+                if s.edit(&key, |_| true) {
+                    let value = s.text.clone();
+                    s.error = validate(value.clone());
+                    s.commit(&value);
+                    drop(s);
+                    on_change(value);
+                }
+            }
+        });
+    }
+
+    fn measure(&self, max: Size) -> Option<Size> {
+        let mut height = 1;
+        self.render_field(|field| height = field.height());
+        Some(Size::new(max.width, height))
+    }
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        self.render_field(|field| field.render(area, buf));
+    }
+}
@@ -0,0 +1,450 @@
+//! Plot 组件：可交互的折线/散点图，基于 ratatui Chart，自行管理可视范围，支持缩放、平移和十字光标读数。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(Plot(
+//!     series: vec![
+//!         PlotSeries::new("cpu", cpu_points).style(Style::default().cyan()),
+//!         PlotSeries::new("mem", mem_points).style(Style::default().magenta()),
+//!     ],
+//!     x_title: Some("time".to_string()),
+//!     is_focus: true,
+//! ))
+//! ```
+//!
+//! 聚焦时支持以下按键：
+//! - `+/-`：以十字光标为中心缩放横轴，`*`/`/`：缩放纵轴
+//! - `←/→/↑/↓`：平移可视范围
+//! - `h/l`：移动十字光标
+//! - `0`：恢复自动适配数据范围
+//!
+//! 开启鼠标捕获后，滚轮缩放，拖拽平移，点击移动十字光标。底部状态行显示光标处各序列的读数。
+
+use std::sync::{Arc, Mutex};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseButton, MouseEventKind};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    symbols::Marker,
+    text::{Line, Span},
+    widgets::{Axis, Chart, Dataset, GraphType, Widget},
+};
+use ratatui_kit_macros::Props;
+
+use crate::{Component, Hooks, State, UseEvents, UseState, text};
+
+#[derive(Clone)]
+/// 数据序列。
+pub struct PlotSeries {
+    /// 序列名称，显示在图例和读数中。
+    pub name: String,
+    /// 数据点，按横坐标升序排列。
+    pub points: Vec<(f64, f64)>,
+    /// 样式。
+    pub style: Style,
+    /// 绘制方式。
+    pub graph_type: GraphType,
+}
+
+impl PlotSeries {
+    /// 创建折线序列。
+    pub fn new(name: impl Into<String>, points: Vec<(f64, f64)>) -> Self {
+        Self {
+            name: name.into(),
+            points,
+            style: Style::default(),
+            graph_type: GraphType::Line,
+        }
+    }
+
+    /// 设置样式。
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// 设置绘制方式。
+    pub fn graph_type(mut self, graph_type: GraphType) -> Self {
+        self.graph_type = graph_type;
+        self
+    }
+
+    // 横坐标最接近 x 的数据点
+    fn nearest(&self, x: f64) -> Option<(f64, f64)> {
+        self.points
+            .iter()
+            .copied()
+            .min_by(|a, b| (a.0 - x).abs().total_cmp(&(b.0 - x).abs()))
+    }
+}
+
+#[derive(Props)]
+/// Plot 组件属性。
+pub struct PlotProps {
+    /// 数据序列。
+    pub series: Vec<PlotSeries>,
+    /// 是否聚焦，聚焦时响应按键。
+    pub is_focus: bool,
+    /// 横轴标题。
+    pub x_title: Option<String>,
+    /// 纵轴标题。
+    pub y_title: Option<String>,
+    /// 图表整体样式。
+    pub style: Style,
+    /// 坐标轴样式。
+    pub axis_style: Style,
+    /// 十字光标样式。
+    pub crosshair_style: Style,
+    /// 状态行样式。
+    pub status_style: Style,
+    /// 刻度和读数的小数位数。
+    pub precision: usize,
+    /// 绘制数据点使用的符号。
+    pub marker: Marker,
+}
+
+impl Default for PlotProps {
+    fn default() -> Self {
+        Self {
+            series: Vec::new(),
+            is_focus: false,
+            x_title: None,
+            y_title: None,
+            style: Style::default(),
+            axis_style: Style::default().dim(),
+            crosshair_style: Style::default().yellow(),
+            status_style: Style::default().dim(),
+            precision: 2,
+            marker: Marker::Braille,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+// 可视范围与十字光标
+struct Viewport {
+    x: [f64; 2],
+    y: [f64; 2],
+    cursor: Option<f64>,
+    // 未手动缩放/平移时跟随数据范围
+    auto_fit: bool,
+}
+
+impl Default for Viewport {
+    fn default() -> Self {
+        Self {
+            x: [0.0, 1.0],
+            y: [0.0, 1.0],
+            cursor: None,
+            auto_fit: true,
+        }
+    }
+}
+
+impl Viewport {
+    // 自动适配时使用数据范围
+    fn resolve(mut self, data: ([f64; 2], [f64; 2])) -> Self {
+        if self.auto_fit {
+            (self.x, self.y) = data;
+        }
+        self
+    }
+
+    fn zoom(&mut self, factor_x: f64, factor_y: f64, center_x: f64) {
+        let center_y = (self.y[0] + self.y[1]) / 2.0;
+        self.x = scale_range(self.x, center_x, factor_x);
+        self.y = scale_range(self.y, center_y, factor_y);
+        self.auto_fit = false;
+    }
+
+    fn pan(&mut self, dx: f64, dy: f64) {
+        self.x = [self.x[0] + dx, self.x[1] + dx];
+        self.y = [self.y[0] + dy, self.y[1] + dy];
+        self.auto_fit = false;
+    }
+
+    fn width(&self) -> f64 {
+        self.x[1] - self.x[0]
+    }
+
+    fn height(&self) -> f64 {
+        self.y[1] - self.y[0]
+    }
+
+    fn handle_key(&mut self, code: KeyCode) -> bool {
+        let cursor = self.cursor.unwrap_or((self.x[0] + self.x[1]) / 2.0);
+        let (step_x, step_y) = (self.width() / 10.0, self.height() / 10.0);
+        match code {
+            KeyCode::Char('+' | '=') => self.zoom(0.8, 1.0, cursor),
+            KeyCode::Char('-') => self.zoom(1.25, 1.0, cursor),
+            KeyCode::Char('*') => self.zoom(1.0, 0.8, cursor),
+            KeyCode::Char('/') => self.zoom(1.0, 1.25, cursor),
+            KeyCode::Left => self.pan(-step_x, 0.0),
+            KeyCode::Right => self.pan(step_x, 0.0),
+            KeyCode::Up => self.pan(0.0, step_y),
+            KeyCode::Down => self.pan(0.0, -step_y),
+            KeyCode::Char('h') => self.cursor = Some((cursor - step_x / 5.0).max(self.x[0])),
+            KeyCode::Char('l') => self.cursor = Some((cursor + step_x / 5.0).min(self.x[1])),
+            KeyCode::Char('0') => {
+                self.auto_fit = true;
+                self.cursor = None;
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+fn scale_range(range: [f64; 2], center: f64, factor: f64) -> [f64; 2] {
+    [
+        center - (center - range[0]) * factor,
+        center + (range[1] - center) * factor,
+    ]
+}
+
+// 全部序列的数据范围，纵轴上下各留 5% 余量
+fn data_bounds(series: &[PlotSeries]) -> ([f64; 2], [f64; 2]) {
+    let mut x = [f64::INFINITY, f64::NEG_INFINITY];
+    let mut y = [f64::INFINITY, f64::NEG_INFINITY];
+    for (px, py) in series.iter().flat_map(|s| s.points.iter().copied()) {
+        x = [x[0].min(px), x[1].max(px)];
+        y = [y[0].min(py), y[1].max(py)];
+    }
+    if !x[0].is_finite() {
+        return ([0.0, 1.0], [0.0, 1.0]);
+    }
+    if x[0] == x[1] {
+        x = [x[0] - 0.5, x[1] + 0.5];
+    }
+    let pad = if y[0] == y[1] {
+        0.5
+    } else {
+        (y[1] - y[0]) * 0.05
+    };
+    (x, [y[0] - pad, y[1] + pad])
+}
+
+#[derive(Default)]
+// 上一次绘制时的绘图区域，用于鼠标坐标换算
+struct PlotLayout {
+    graph: Rect,
+    // 拖拽起点及当时的可视范围
+    drag: Option<(u16, u16, Viewport)>,
+}
+
+impl PlotLayout {
+    fn to_data_x(&self, column: u16, view: &Viewport) -> f64 {
+        let ratio = column.saturating_sub(self.graph.x) as f64 / self.graph.width.max(1) as f64;
+        view.x[0] + ratio.clamp(0.0, 1.0) * view.width()
+    }
+
+    fn handle_mouse(&mut self, event: crossterm::event::MouseEvent, view: &mut Viewport) -> bool {
+        let graph = self.graph;
+        match event.kind {
+            MouseEventKind::ScrollUp | MouseEventKind::ScrollDown => {
+                let factor = if event.kind == MouseEventKind::ScrollUp {
+                    0.8
+                } else {
+                    1.25
+                };
+                let center = self.to_data_x(event.column, view);
+                view.zoom(factor, 1.0, center);
+                true
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                view.cursor = Some(self.to_data_x(event.column, view));
+                self.drag = Some((event.column, event.row, *view));
+                true
+            }
+            MouseEventKind::Drag(MouseButton::Left) => {
+                let Some((column, row, start)) = self.drag else {
+                    return false;
+                };
+                let dx = (column as f64 - event.column as f64) / graph.width.max(1) as f64;
+                let dy = (event.row as f64 - row as f64) / graph.height.max(1) as f64;
+                *view = start;
+                view.pan(dx * start.width(), dy * start.height());
+                true
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                self.drag = None;
+                false
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Plot 组件实现。
+pub struct Plot {
+    series: Vec<PlotSeries>,
+    viewport: Option<State<Viewport>>,
+    layout: Arc<Mutex<PlotLayout>>,
+    x_title: Option<String>,
+    y_title: Option<String>,
+    style: Style,
+    axis_style: Style,
+    crosshair_style: Style,
+    status_style: Style,
+    precision: usize,
+    marker: Marker,
+}
+
+impl Component for Plot {
+    type Props<'a> = PlotProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            series: props.series.clone(),
+            viewport: None,
+            layout: Arc::new(Mutex::new(PlotLayout::default())),
+            x_title: props.x_title.clone(),
+            y_title: props.y_title.clone(),
+            style: props.style,
+            axis_style: props.axis_style,
+            crosshair_style: props.crosshair_style,
+            status_style: props.status_style,
+            precision: props.precision,
+            marker: props.marker,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        _updater: &mut crate::ComponentUpdater,
+    ) {
+        let viewport = hooks.use_state(Viewport::default);
+        let bounds = data_bounds(&props.series);
+
+        hooks.use_local_events({
+            let mut viewport = viewport;
+            let layout = self.layout.clone();
+            let is_focus = props.is_focus;
+            move |event| {
+                let mut view = viewport.read().resolve(bounds);
+                let changed = match event {
+                    Event::Key(key) if is_focus && key.kind == KeyEventKind::Press => {
+                        view.handle_key(key.code)
+                    }
+                    Event::Mouse(mouse_event) => {
+                        layout.lock().unwrap().handle_mouse(mouse_event, &mut view)
+                    }
+                    _ => false,
+                };
+                if changed {
+                    viewport.set(view);
+                }
+            }
+        });
+
+        self.series = props.series.clone();
+        self.viewport = Some(viewport);
+        self.x_title = props.x_title.clone();
+        self.y_title = props.y_title.clone();
+        self.style = props.style;
+        self.axis_style = props.axis_style;
+        self.crosshair_style = props.crosshair_style;
+        self.status_style = props.status_style;
+        self.precision = props.precision;
+        self.marker = props.marker;
+    }
+
+    fn draw(&mut self, drawer: &mut crate::ComponentDrawer<'_, '_>) {
+        let Some(viewport) = self.viewport else {
+            return;
+        };
+        let view = viewport.read().resolve(data_bounds(&self.series));
+        let precision = self.precision;
+        let format = |v: f64| format!("{v:.precision$}");
+
+        let [chart_area, status_area] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(drawer.area);
+
+        let crosshair = view.cursor.map(|x| [(x, view.y[0]), (x, view.y[1])]);
+        let mut datasets = self
+            .series
+            .iter()
+            .map(|s| {
+                Dataset::default()
+                    .name(s.name.clone())
+                    .data(&s.points)
+                    .style(s.style)
+                    .graph_type(s.graph_type)
+                    .marker(self.marker)
+            })
+            .collect::<Vec<_>>();
+        if let Some(crosshair) = &crosshair {
+            datasets.push(
+                Dataset::default()
+                    .data(crosshair)
+                    .style(self.crosshair_style)
+                    .graph_type(GraphType::Line)
+                    .marker(self.marker),
+            );
+        }
+
+        let axis = |title: &Option<String>, bounds: [f64; 2]| {
+            let mut axis = Axis::default()
+                .style(self.axis_style)
+                .bounds(bounds)
+                .labels([
+                    format(bounds[0]),
+                    format((bounds[0] + bounds[1]) / 2.0),
+                    format(bounds[1]),
+                ]);
+            if let Some(title) = title {
+                axis = axis.title(title.clone());
+            }
+            axis
+        };
+        let y_labels_width = [view.y[0], view.y[1]]
+            .iter()
+            .map(|v| text::width(&format(*v)) as u16)
+            .max()
+            .unwrap_or(0);
+
+        Chart::new(datasets)
+            .style(self.style)
+            .x_axis(axis(&self.x_title, view.x))
+            .y_axis(axis(&self.y_title, view.y))
+            .render(chart_area, drawer.buffer_mut());
+
+        // 近似的绘图区域：左侧为纵轴刻度与轴线，底部为横轴刻度与轴线
+        self.layout.lock().unwrap().graph = Rect {
+            x: chart_area.x + y_labels_width + 1,
+            y: chart_area.y,
+            width: chart_area.width.saturating_sub(y_labels_width + 1),
+            height: chart_area.height.saturating_sub(2),
+        };
+
+        // 状态行：十字光标处各序列的读数
+        let mut spans = Vec::new();
+        match view.cursor {
+            Some(x) => {
+                spans.push(Span::raw(format!("x={}", format(x))));
+                for series in &self.series {
+                    if let Some((_, y)) = series.nearest(x) {
+                        spans.push(Span::raw("  "));
+                        spans.push(Span::styled(
+                            format!("{}={}", series.name, format(y)),
+                            series.style,
+                        ));
+                    }
+                }
+            }
+            None => spans.push(Span::raw(format!(
+                "x: {} ~ {}  y: {} ~ {}",
+                format(view.x[0]),
+                format(view.x[1]),
+                format(view.y[0]),
+                format(view.y[1])
+            ))),
+        }
+        Line::from(spans)
+            .style(self.status_style)
+            .render(status_area, drawer.buffer_mut());
+    }
+}
@@ -0,0 +1,93 @@
+//! PluginProvider 组件：向子组件提供插件宿主，并在挂载/卸载时调用插件的生命周期方法。
+//!
+//! ## 用法示例
+//! ```rust
+//! let mut host = PluginHost::default();
+//! host.install(MyPlugin);
+//!
+//! element!(PluginProvider(host: Arc::new(host)) {
+//!     App()
+//! })
+//! ```
+//! 子组件可通过 `hooks.use_context::<Arc<PluginHost>>()` 获取宿主，用其注册表构建布局或读取共享数据。
+
+use std::sync::Arc;
+
+use ratatui_kit_macros::Props;
+
+use crate::{AnyElement, Component, Context, Hook, interpreter::PluginHost};
+
+#[derive(Default, Props)]
+/// PluginProvider 组件属性。
+pub struct PluginProviderProps<'a> {
+    /// 插件宿主。
+    pub host: Option<Arc<PluginHost>>,
+    /// 子元素列表。
+    pub children: Vec<AnyElement<'a>>,
+}
+
+/// PluginProvider 组件实现。
+pub struct PluginProvider;
+
+// 跟踪当前挂载的宿主，宿主替换或组件卸载时通知插件
+#[derive(Default)]
+struct PluginLifecycle {
+    host: Option<Arc<PluginHost>>,
+}
+
+impl PluginLifecycle {
+    fn set_host(&mut self, host: Option<Arc<PluginHost>>) {
+        let unchanged = match (&self.host, &host) {
+            (Some(current), Some(new)) => Arc::ptr_eq(current, new),
+            (None, None) => true,
+            _ => false,
+        };
+        if unchanged {
+            return;
+        }
+        self.unmount();
+        if let Some(host) = &host {
+            host.plugins().for_each(|plugin| plugin.on_mount(host));
+        }
+        self.host = host;
+    }
+
+    fn unmount(&mut self) {
+        if let Some(host) = self.host.take() {
+            host.plugins().for_each(|plugin| plugin.on_unmount(&host));
+        }
+    }
+}
+
+impl Hook for PluginLifecycle {}
+
+impl Drop for PluginLifecycle {
+    fn drop(&mut self) {
+        self.unmount();
+    }
+}
+
+impl Component for PluginProvider {
+    type Props<'a> = PluginProviderProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: crate::Hooks,
+        updater: &mut crate::ComponentUpdater,
+    ) {
+        hooks
+            .use_hook(PluginLifecycle::default)
+            .set_host(props.host.clone());
+
+        updater.set_transparent_layout(true);
+        updater.update_children(
+            props.children.iter_mut(),
+            props.host.clone().map(Context::owned),
+        );
+    }
+}
@@ -0,0 +1,273 @@
+//! Popover 组件：把浮层内容定位在锚点元素旁边，是下拉选择、补全弹窗、提示框等组件的基础。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(Popover(
+//!     open: open.get(),
+//!     anchor: Some(element!(Text(text: "选择语言 ▾")).into()),
+//!     content: Some(element!(Border {
+//!         Text(text: "Rust\nGo\nZig")
+//!     }).into()),
+//!     placement: PopoverPlacement::BottomStart,
+//!     on_close: move |_| open.set(false),
+//! ))
+//! ```
+//! - Popover 的尺寸与锚点元素相同，打开与否不影响布局；
+//! - 浮层尺寸取内容的固有尺寸，没有固有尺寸时按内容的 `width`/`height` 相对于整个屏幕计算；
+//! - 首选方向空间不足且对侧空间更大时自动翻转到对侧，另一方向上按对齐方式放置并限制在屏幕内；
+//! - 打开时按 `Esc` 或点击锚点和浮层以外的位置触发 `on_close`，点击需开启鼠标捕获；
+//! - 浮层随 Popover 一起绘制，之后绘制的兄弟组件会覆盖浮层，因此锚点下方或右侧的内容应先于 Popover 声明。
+
+use std::sync::{Arc, Mutex};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseButton, MouseEventKind};
+use ratatui::{
+    layout::{Direction, Layout, Position, Rect, Size},
+    style::Style,
+};
+use ratatui_kit_macros::Props;
+
+use crate::{AnyElement, Component, EventInterest, Handler, UseEvents};
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+/// 浮层相对锚点的位置：`Bottom` 等表示位于锚点哪一侧，`Start`/`End` 表示与锚点的哪一端对齐，
+/// 不带后缀时居中对齐。
+pub enum PopoverPlacement {
+    Top,
+    TopStart,
+    TopEnd,
+    Bottom,
+    #[default]
+    BottomStart,
+    BottomEnd,
+    Left,
+    LeftStart,
+    LeftEnd,
+    Right,
+    RightStart,
+    RightEnd,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Align {
+    Start,
+    Center,
+    End,
+}
+
+impl PopoverPlacement {
+    // 浮层是否在锚点的上方或下方，以及是否在锚点之前（上方或左侧）
+    fn side(self) -> (Direction, bool) {
+        use PopoverPlacement::*;
+        match self {
+            Top | TopStart | TopEnd => (Direction::Vertical, true),
+            Bottom | BottomStart | BottomEnd => (Direction::Vertical, false),
+            Left | LeftStart | LeftEnd => (Direction::Horizontal, true),
+            Right | RightStart | RightEnd => (Direction::Horizontal, false),
+        }
+    }
+
+    fn align(self) -> Align {
+        use PopoverPlacement::*;
+        match self {
+            TopStart | BottomStart | LeftStart | RightStart => Align::Start,
+            Top | Bottom | Left | Right => Align::Center,
+            TopEnd | BottomEnd | LeftEnd | RightEnd => Align::End,
+        }
+    }
+
+    /// 在 `frame` 中为尺寸为 `size` 的浮层计算紧贴 `anchor` 的区域。
+    pub fn place(self, anchor: Rect, size: Size, frame: Rect) -> Rect {
+        let (direction, before) = self.side();
+        let align = self.align();
+        match direction {
+            Direction::Vertical => {
+                let (y, height) = main_axis(
+                    (anchor.y, anchor.bottom()),
+                    (frame.y, frame.bottom()),
+                    size.height,
+                    before,
+                );
+                let (x, width) = cross_axis(
+                    (anchor.x, anchor.right()),
+                    (frame.x, frame.right()),
+                    size.width,
+                    align,
+                );
+                Rect::new(x, y, width, height)
+            }
+            Direction::Horizontal => {
+                let (x, width) = main_axis(
+                    (anchor.x, anchor.right()),
+                    (frame.x, frame.right()),
+                    size.width,
+                    before,
+                );
+                let (y, height) = cross_axis(
+                    (anchor.y, anchor.bottom()),
+                    (frame.y, frame.bottom()),
+                    size.height,
+                    align,
+                );
+                Rect::new(x, y, width, height)
+            }
+        }
+    }
+}
+
+// 浮层所在一侧的起点和长度，首选一侧放不下且对侧空间更大时翻转
+fn main_axis(anchor: (u16, u16), frame: (u16, u16), len: u16, before: bool) -> (u16, u16) {
+    let space_before = anchor.0.saturating_sub(frame.0);
+    let space_after = frame.1.saturating_sub(anchor.1);
+    let (preferred, other) = if before {
+        (space_before, space_after)
+    } else {
+        (space_after, space_before)
+    };
+    let before = if len > preferred && other > preferred {
+        !before
+    } else {
+        before
+    };
+    if before {
+        let len = len.min(space_before);
+        (anchor.0 - len, len)
+    } else {
+        (anchor.1, len.min(space_after))
+    }
+}
+
+// 另一方向上按对齐方式放置，超出屏幕时向内移动
+fn cross_axis(anchor: (u16, u16), frame: (u16, u16), len: u16, align: Align) -> (u16, u16) {
+    let len = len.min(frame.1.saturating_sub(frame.0));
+    let start = match align {
+        Align::Start => anchor.0 as i32,
+        Align::Center => (anchor.0 as i32 + anchor.1 as i32 - len as i32) / 2,
+        Align::End => anchor.1 as i32 - len as i32,
+    };
+    let start = start.clamp(frame.0 as i32, (frame.1 - len) as i32);
+    (start as u16, len)
+}
+
+#[derive(Default, Props)]
+/// Popover 组件属性。
+pub struct PopoverProps<'a> {
+    /// 锚点元素，Popover 的尺寸与它相同。
+    pub anchor: Option<AnyElement<'a>>,
+    /// 浮层内容。
+    pub content: Option<AnyElement<'a>>,
+    /// 浮层相对锚点的位置。
+    pub placement: PopoverPlacement,
+    /// 是否显示浮层。
+    pub open: bool,
+    /// 浮层区域的背景样式，None 时只清空浮层区域。
+    pub background: Option<Style>,
+    /// 是否在浮层右下方绘制阴影。
+    pub shadow: bool,
+    /// 打开时按 `Esc` 或点击锚点和浮层以外的位置时的回调。
+    pub on_close: Handler<'static, ()>,
+}
+
+/// Popover 组件实现。
+pub struct Popover {
+    open: bool,
+    has_anchor: bool,
+    placement: PopoverPlacement,
+    background: Option<Style>,
+    shadow: bool,
+    // 最近一次绘制的锚点区域和浮层区域，浮层关闭时为 None
+    regions: Arc<Mutex<Option<(Rect, Rect)>>>,
+}
+
+impl Component for Popover {
+    type Props<'a> = PopoverProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            open: props.open,
+            has_anchor: props.anchor.is_some(),
+            placement: props.placement,
+            background: props.background,
+            shadow: props.shadow,
+            regions: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: crate::Hooks,
+        updater: &mut crate::ComponentUpdater,
+    ) {
+        hooks.use_events_of(EventInterest::KEY | EventInterest::MOUSE, {
+            let open = props.open;
+            let regions = self.regions.clone();
+            let mut on_close = props.on_close.take();
+            move |event| {
+                if !open {
+                    return;
+                }
+                let close = match event {
+                    Event::Key(key) => key.kind == KeyEventKind::Press && key.code == KeyCode::Esc,
+                    Event::Mouse(mouse)
+                        if mouse.kind == MouseEventKind::Down(MouseButton::Left) =>
+                    {
+                        let position = Position::new(mouse.column, mouse.row);
+                        regions.lock().unwrap().is_some_and(|(anchor, content)| {
+                            !anchor.contains(position) && !content.contains(position)
+                        })
+                    }
+                    _ => false,
+                };
+                if close {
+                    on_close(());
+                }
+            }
+        });
+
+        self.open = props.open && props.content.is_some();
+        self.has_anchor = props.anchor.is_some();
+        self.placement = props.placement;
+        self.background = props.background;
+        self.shadow = props.shadow;
+        if !self.open {
+            *self.regions.lock().unwrap() = None;
+        }
+
+        updater.set_transparent_layout(true);
+        if self.has_anchor {
+            updater.set_measured_child(0);
+        }
+        let content = props.content.iter_mut().filter(|_| props.open);
+        updater.update_children(props.anchor.iter_mut().chain(content), None);
+    }
+
+    fn draw(&mut self, _drawer: &mut crate::ComponentDrawer<'_, '_>) {}
+
+    fn calc_children_areas(
+        &self,
+        children: &crate::Components,
+        _layout_style: &crate::layout_style::LayoutStyle,
+        drawer: &mut crate::ComponentDrawer<'_, '_>,
+    ) -> Vec<Rect> {
+        let anchor = drawer.area;
+        let frame = drawer.buffer_mut().area;
+        let mut areas = vec![anchor; self.has_anchor as usize];
+
+        let content = self.has_anchor as usize;
+        if self.open && children.iter().count() > content {
+            // 测量内容的固有尺寸，没有时按内容自身的约束相对于整个屏幕计算
+            let constraint =
+                |direction| children.get_measured_constraints(direction, frame.as_size())[content];
+            let size = Size::new(
+                Layout::horizontal([constraint(Direction::Horizontal)]).split(frame)[0].width,
+                Layout::vertical([constraint(Direction::Vertical)]).split(frame)[0].height,
+            );
+            let area = self.placement.place(anchor, size, frame);
+            super::modal::render_surface(area, drawer.buffer_mut(), self.background, self.shadow);
+            *self.regions.lock().unwrap() = Some((anchor, area));
+            areas.push(area);
+        }
+        areas
+    }
+}
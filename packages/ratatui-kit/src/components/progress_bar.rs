@@ -0,0 +1,117 @@
+//! ProgressBar 组件：单行进度条，左侧显示标签，右侧显示百分比。
+//!
+//! ## 用法示例
+//! ```rust
+//! let info = job.info();
+//! element!(ProgressBar(
+//!     progress: info.progress,
+//!     label: info.name,
+//!     filled_style: Style::default().green(),
+//! ))
+//! ```
+//! `progress` 为 None 时表示进度未知，只显示标签。高度固定为一行。
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Rect, Size},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::Widget,
+};
+use ratatui_kit_macros::Props;
+
+use crate::{Component, text};
+
+#[derive(Props)]
+/// ProgressBar 组件属性。
+pub struct ProgressBarProps {
+    /// 进度（0.0 ~ 1.0），None 表示进度未知。
+    pub progress: Option<f64>,
+    /// 显示在进度条前的标签。
+    pub label: String,
+    /// 整体样式。
+    pub style: Style,
+    /// 已完成部分样式。
+    pub filled_style: Style,
+    /// 未完成部分样式。
+    pub unfilled_style: Style,
+}
+
+impl Default for ProgressBarProps {
+    fn default() -> Self {
+        Self {
+            progress: None,
+            label: String::new(),
+            style: Style::default(),
+            filled_style: Style::default().green(),
+            unfilled_style: Style::default().dark_gray(),
+        }
+    }
+}
+
+/// ProgressBar 组件实现。
+pub struct ProgressBar {
+    progress: Option<f64>,
+    label: String,
+    style: Style,
+    filled_style: Style,
+    unfilled_style: Style,
+}
+
+impl Component for ProgressBar {
+    type Props<'a> = ProgressBarProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            progress: props.progress,
+            label: props.label.clone(),
+            style: props.style,
+            filled_style: props.filled_style,
+            unfilled_style: props.unfilled_style,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        _hooks: crate::Hooks,
+        _updater: &mut crate::ComponentUpdater,
+    ) {
+        *self = Self::new(props);
+    }
+
+    fn measure(&self, max: Size) -> Option<Size> {
+        Some(Size::new(max.width, 1))
+    }
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+        buf.set_style(area, self.style);
+        let percent = self
+            .progress
+            .map(|p| format!(" {:>3.0}%", p.clamp(0.0, 1.0) * 100.0))
+            .unwrap_or_default();
+        // 标签最多占一半宽度
+        let label_width = (area.width / 2) as usize;
+        let label = text::truncate_with_ellipsis(&self.label, label_width);
+        let mut spans = vec![Span::raw(label.into_owned())];
+        if !self.label.is_empty() {
+            spans.push(Span::raw(" "));
+        }
+
+        if let Some(progress) = self.progress {
+            let used = spans.iter().map(Span::width).sum::<usize>() + text::width(&percent);
+            let bar_width = (area.width as usize).saturating_sub(used);
+            let filled = (bar_width as f64 * progress.clamp(0.0, 1.0)).round() as usize;
+            spans.push(Span::styled("━".repeat(filled), self.filled_style));
+            spans.push(Span::styled(
+                "━".repeat(bar_width - filled),
+                self.unfilled_style,
+            ));
+            spans.push(Span::raw(percent));
+        }
+        Line::from(spans).render(area, buf);
+    }
+}
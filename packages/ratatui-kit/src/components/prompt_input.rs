@@ -0,0 +1,555 @@
+//! PromptInput 组件：REPL 风格的输入行，支持历史记录、反向搜索、补全和多行续行。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(PromptInput(
+//!     prompt: "sql> ",
+//!     is_focus: true,
+//!     history_file: Some(".sql_history".into()),
+//!     // 语句没有以 `;` 结尾时继续输入下一行
+//!     needs_continuation: |input: String| !input.trim_end().ends_with(';'),
+//!     on_complete: |before_cursor: String| complete_keyword(&before_cursor),
+//!     on_submit: move |input: String| history.push(Line::from(input)),
+//! ))
+//! ```
+//! - `Enter` 提交；行尾为 `\` 或 `needs_continuation` 返回 true 时换行继续输入，`Alt+Enter` 总是换行。
+//! - `↑/↓` 在首行/末行时浏览历史，`Ctrl+R` 反向搜索（再按一次查找更早的匹配，`Enter` 接受，`Esc` 取消）。
+//! - `Tab` 调用 `on_complete`，候选项替换光标前的词，多个候选项时再按 `Tab` 依次切换。
+//! - 支持 `Ctrl+A/E` 行首/行尾、`Ctrl+U/K` 删除到行首/行尾、`Ctrl+W` 删除前一个词。
+//! - 设置 `history_file` 时挂载后读取历史，每次提交追加写入。高度随内容和可用宽度变化。
+
+use std::{io::Write, path::PathBuf};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Rect, Size},
+    style::{Style, Stylize},
+    text::{Line, Span, Text},
+    widgets::{Paragraph, Widget, Wrap},
+};
+use ratatui_kit_macros::Props;
+
+use crate::{Component, Handler, Hooks, State, UseEvents, UseState};
+
+#[derive(Props)]
+/// PromptInput 组件属性。
+pub struct PromptInputProps {
+    /// 首行提示符。
+    pub prompt: String,
+    /// 续行提示符。
+    pub continuation_prompt: String,
+    /// 是否聚焦，聚焦时响应按键。
+    pub is_focus: bool,
+    /// 提交回调，参数为完整输入。
+    pub on_submit: Handler<'static, String>,
+    /// 补全回调，参数为光标前的文本，返回替换光标前的词的候选项。
+    pub on_complete: Handler<'static, String, Vec<String>>,
+    /// 判断输入是否需要续行，返回 true 时 `Enter` 换行而不是提交。
+    pub needs_continuation: Handler<'static, String, bool>,
+    /// 持久化历史记录的文件。
+    pub history_file: Option<PathBuf>,
+    /// 最多保留的历史记录数。
+    pub max_history: usize,
+    /// 提示符样式。
+    pub prompt_style: Style,
+    /// 输入文本样式。
+    pub style: Style,
+    /// 光标样式。
+    pub cursor_style: Style,
+    /// 补全候选项和搜索提示样式。
+    pub hint_style: Style,
+}
+
+impl Default for PromptInputProps {
+    fn default() -> Self {
+        Self {
+            prompt: "> ".to_string(),
+            continuation_prompt: "... ".to_string(),
+            is_focus: false,
+            on_submit: Handler::default(),
+            on_complete: Handler::default(),
+            needs_continuation: Handler::default(),
+            history_file: None,
+            max_history: 1000,
+            prompt_style: Style::default().green(),
+            style: Style::default(),
+            cursor_style: Style::default().reversed(),
+            hint_style: Style::default().dim(),
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct Search {
+    query: String,
+    // 当前匹配的历史记录
+    index: Option<usize>,
+    // 开始搜索前的输入，取消时恢复
+    saved: String,
+}
+
+#[derive(Clone, Default)]
+struct Completion {
+    // 被替换的词的起始位置
+    start: usize,
+    candidates: Vec<String>,
+    index: usize,
+}
+
+#[derive(Clone, Default)]
+struct PromptState {
+    buffer: String,
+    // 光标的字节位置
+    cursor: usize,
+    history: Vec<String>,
+    history_index: Option<usize>,
+    // 浏览历史前正在编辑的内容
+    draft: String,
+    search: Option<Search>,
+    completion: Option<Completion>,
+}
+
+// 历史文件中每条记录占一行，换行和反斜杠需要转义
+fn escape(entry: &str) -> String {
+    entry.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some(c) => out.push(c),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+impl PromptState {
+    fn load(path: &Option<PathBuf>, max: usize) -> Self {
+        let mut history = path
+            .as_ref()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .map(|content| content.lines().map(unescape).collect::<Vec<_>>())
+            .unwrap_or_default();
+        history.drain(..history.len().saturating_sub(max));
+        Self {
+            history,
+            ..Default::default()
+        }
+    }
+
+    fn set_buffer(&mut self, buffer: String) {
+        self.cursor = buffer.len();
+        self.buffer = buffer;
+    }
+
+    fn insert(&mut self, text: &str) {
+        self.buffer.insert_str(self.cursor, text);
+        self.cursor += text.len();
+    }
+
+    fn prev_boundary(&self) -> usize {
+        self.buffer[..self.cursor]
+            .char_indices()
+            .next_back()
+            .map_or(0, |(i, _)| i)
+    }
+
+    fn next_boundary(&self) -> usize {
+        self.buffer[self.cursor..]
+            .chars()
+            .next()
+            .map_or(self.cursor, |c| self.cursor + c.len_utf8())
+    }
+
+    fn line_start(&self) -> usize {
+        self.buffer[..self.cursor].rfind('\n').map_or(0, |i| i + 1)
+    }
+
+    fn line_end(&self) -> usize {
+        self.buffer[self.cursor..]
+            .find('\n')
+            .map_or(self.buffer.len(), |i| self.cursor + i)
+    }
+
+    // `skip_space` 为 true 时先跳过光标前的空白，用于 Ctrl+W
+    fn word_start(&self, skip_space: bool) -> usize {
+        let before = &self.buffer[..self.cursor];
+        let before = if skip_space {
+            before.trim_end()
+        } else {
+            before
+        };
+        before
+            .rfind(char::is_whitespace)
+            .map_or(0, |i| i + before[i..].chars().next().unwrap().len_utf8())
+    }
+
+    // 在光标所在行上下移动，已在首行/末行时返回 false
+    fn move_line(&mut self, up: bool) -> bool {
+        let start = self.line_start();
+        let column = self.buffer[start..self.cursor].chars().count();
+        let target = if up {
+            if start == 0 {
+                return false;
+            }
+            self.buffer[..start - 1].rfind('\n').map_or(0, |i| i + 1)
+        } else {
+            let end = self.line_end();
+            if end == self.buffer.len() {
+                return false;
+            }
+            end + 1
+        };
+        let line = self.buffer[target..].split('\n').next().unwrap_or_default();
+        self.cursor = target
+            + line
+                .char_indices()
+                .nth(column)
+                .map_or(line.len(), |(i, _)| i);
+        true
+    }
+
+    fn recall(&mut self, up: bool) {
+        let len = self.history.len();
+        let index = match (self.history_index, up) {
+            (None, true) if len > 0 => Some(len - 1),
+            (Some(i), true) => Some(i.saturating_sub(1)),
+            (Some(i), false) if i + 1 < len => Some(i + 1),
+            (Some(_), false) => None,
+            (None, _) => return,
+        };
+        if self.history_index.is_none() {
+            self.draft = self.buffer.clone();
+        }
+        self.history_index = index;
+        let buffer = match index {
+            Some(i) => self.history[i].clone(),
+            None => std::mem::take(&mut self.draft),
+        };
+        self.set_buffer(buffer);
+    }
+
+    // 从 `before` 之前向更早的记录查找包含 query 的历史
+    fn search_from(&self, query: &str, before: usize) -> Option<usize> {
+        self.history[..before.min(self.history.len())]
+            .iter()
+            .rposition(|entry| entry.contains(query))
+    }
+
+    fn push_history(&mut self, entry: &str, max: usize) -> bool {
+        if entry.trim().is_empty() || self.history.last().map(String::as_str) == Some(entry) {
+            return false;
+        }
+        self.history.push(entry.to_string());
+        self.history.drain(..self.history.len().saturating_sub(max));
+        true
+    }
+}
+
+/// PromptInput 组件实现。
+pub struct PromptInput {
+    state: Option<State<PromptState>>,
+    prompt: String,
+    continuation_prompt: String,
+    is_focus: bool,
+    prompt_style: Style,
+    style: Style,
+    cursor_style: Style,
+    hint_style: Style,
+}
+
+impl PromptInput {
+    fn text(&self) -> Text<'static> {
+        let Some(state) = self.state else {
+            return Text::default();
+        };
+        let state = state.read();
+        let mut lines = Vec::new();
+
+        if let Some(search) = &state.search {
+            let matched = search
+                .index
+                .map(|i| state.history[i].clone())
+                .unwrap_or_default();
+            let mut spans = vec![Span::styled(
+                format!("(reverse-i-search)`{}': ", search.query),
+                self.hint_style,
+            )];
+            spans.push(Span::styled(matched, self.style));
+            lines.push(Line::from(spans));
+            return Text::from(lines);
+        }
+
+        let cursor = self.is_focus.then_some(state.cursor);
+        let mut offset = 0;
+        for (i, line) in state.buffer.split('\n').enumerate() {
+            let prompt = if i == 0 {
+                &self.prompt
+            } else {
+                &self.continuation_prompt
+            };
+            let mut spans = vec![Span::styled(prompt.clone(), self.prompt_style)];
+            match cursor.filter(|c| (offset..=offset + line.len()).contains(c)) {
+                Some(c) => {
+                    let at = c - offset;
+                    let (before, rest) = line.split_at(at);
+                    let mut rest = rest.chars();
+                    let under = rest.next().map_or(" ".to_string(), String::from);
+                    spans.push(Span::styled(before.to_string(), self.style));
+                    spans.push(Span::styled(under, self.cursor_style));
+                    spans.push(Span::styled(rest.as_str().to_string(), self.style));
+                }
+                None => spans.push(Span::styled(line.to_string(), self.style)),
+            }
+            lines.push(Line::from(spans));
+            offset += line.len() + 1;
+        }
+
+        if let Some(completion) = state.completion.as_ref().filter(|c| c.candidates.len() > 1) {
+            let spans = completion
+                .candidates
+                .iter()
+                .enumerate()
+                .map(|(i, candidate)| {
+                    let style = if i == completion.index {
+                        self.hint_style.reversed()
+                    } else {
+                        self.hint_style
+                    };
+                    Span::styled(format!("{candidate} "), style)
+                })
+                .collect::<Vec<_>>();
+            lines.push(Line::from(spans));
+        }
+        Text::from(lines)
+    }
+
+    fn paragraph(&self) -> Paragraph<'static> {
+        Paragraph::new(self.text()).wrap(Wrap { trim: false })
+    }
+}
+
+impl Component for PromptInput {
+    type Props<'a> = PromptInputProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            state: None,
+            prompt: props.prompt.clone(),
+            continuation_prompt: props.continuation_prompt.clone(),
+            is_focus: props.is_focus,
+            prompt_style: props.prompt_style,
+            style: props.style,
+            cursor_style: props.cursor_style,
+            hint_style: props.hint_style,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        _updater: &mut crate::ComponentUpdater,
+    ) {
+        let max_history = props.max_history.max(1);
+        let state = hooks.use_state(|| PromptState::load(&props.history_file, max_history));
+        self.state = Some(state);
+        self.prompt = props.prompt.clone();
+        self.continuation_prompt = props.continuation_prompt.clone();
+        self.is_focus = props.is_focus;
+        self.prompt_style = props.prompt_style;
+        self.style = props.style;
+        self.cursor_style = props.cursor_style;
+        self.hint_style = props.hint_style;
+
+        hooks.use_local_events({
+            let is_focus = props.is_focus;
+            let history_file = props.history_file.clone();
+            let mut on_submit = props.on_submit.take();
+            let mut on_complete = props.on_complete.take();
+            let mut needs_continuation = props.needs_continuation.take();
+            move |event| {
+                let Event::Key(key) = event else {
+                    return;
+                };
+                if !is_focus || key.kind == KeyEventKind::Release {
+                    return;
+                }
+                let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+                let mut s = state.write();
+
+                // 反向搜索模式
+                if let Some(mut search) = s.search.take() {
+                    match key.code {
+                        KeyCode::Char('r') if ctrl => {
+                            let before = search.index.unwrap_or(s.history.len());
+                            if let Some(i) = s.search_from(&search.query, before) {
+                                search.index = Some(i);
+                            }
+                        }
+                        KeyCode::Esc => {
+                            s.set_buffer(search.saved);
+                            return;
+                        }
+                        KeyCode::Char('g') if ctrl => {
+                            s.set_buffer(search.saved);
+                            return;
+                        }
+                        KeyCode::Enter | KeyCode::Left | KeyCode::Right => {
+                            let buffer = search
+                                .index
+                                .map(|i| s.history[i].clone())
+                                .unwrap_or(search.saved);
+                            s.set_buffer(buffer);
+                            return;
+                        }
+                        KeyCode::Backspace => {
+                            search.query.pop();
+                            search.index = s.search_from(&search.query, s.history.len());
+                        }
+                        KeyCode::Char(c) if !ctrl => {
+                            search.query.push(c);
+                            let before = search.index.map_or(s.history.len(), |i| i + 1);
+                            search.index = s.search_from(&search.query, before);
+                        }
+                        _ => {}
+                    }
+                    s.search = Some(search);
+                    return;
+                }
+
+                if key.code != KeyCode::Tab {
+                    s.completion = None;
+                }
+                if !matches!(key.code, KeyCode::Up | KeyCode::Down) {
+                    s.history_index = None;
+                }
+                match key.code {
+                    KeyCode::Enter => {
+                        let line = &s.buffer[s.line_start()..s.line_end()];
+                        let explicit = key.modifiers.contains(KeyModifiers::ALT)
+                            || key.modifiers.contains(KeyModifiers::SHIFT);
+                        if line.ends_with('\\') && s.cursor == s.line_end() {
+                            // 去掉续行符后换行
+                            s.buffer.pop();
+                            s.cursor -= 1;
+                            s.insert("\n");
+                        } else if explicit || needs_continuation(s.buffer.clone()) {
+                            s.insert("\n");
+                        } else {
+                            let input = std::mem::take(&mut s.buffer);
+                            s.cursor = 0;
+                            s.draft.clear();
+                            if s.push_history(&input, max_history) {
+                                if let Some(path) = &history_file {
+                                    let _ = std::fs::OpenOptions::new()
+                                        .create(true)
+                                        .append(true)
+                                        .open(path)
+                                        .and_then(|mut file| writeln!(file, "{}", escape(&input)));
+                                }
+                            }
+                            drop(s);
+                            on_submit(input);
+                        }
+                    }
+                    KeyCode::Char('r') if ctrl => {
+                        let saved = s.buffer.clone();
+                        s.search = Some(Search {
+                            query: String::new(),
+                            index: None,
+                            saved,
+                        });
+                    }
+                    KeyCode::Char('a') if ctrl => s.cursor = s.line_start(),
+                    KeyCode::Char('e') if ctrl => s.cursor = s.line_end(),
+                    KeyCode::Char('u') if ctrl => {
+                        let start = s.line_start();
+                        let cursor = s.cursor;
+                        s.buffer.drain(start..cursor);
+                        s.cursor = start;
+                    }
+                    KeyCode::Char('k') if ctrl => {
+                        let end = s.line_end();
+                        let cursor = s.cursor;
+                        s.buffer.drain(cursor..end);
+                    }
+                    KeyCode::Char('w') if ctrl => {
+                        let start = s.word_start(true);
+                        let cursor = s.cursor;
+                        s.buffer.drain(start..cursor);
+                        s.cursor = start;
+                    }
+                    KeyCode::Char(c) if !ctrl => s.insert(c.encode_utf8(&mut [0; 4])),
+                    KeyCode::Backspace if s.cursor > 0 => {
+                        let start = s.prev_boundary();
+                        let cursor = s.cursor;
+                        s.buffer.drain(start..cursor);
+                        s.cursor = start;
+                    }
+                    KeyCode::Delete => {
+                        let end = s.next_boundary();
+                        let cursor = s.cursor;
+                        s.buffer.drain(cursor..end);
+                    }
+                    KeyCode::Left => s.cursor = s.prev_boundary(),
+                    KeyCode::Right => s.cursor = s.next_boundary(),
+                    KeyCode::Home => s.cursor = s.line_start(),
+                    KeyCode::End => s.cursor = s.line_end(),
+                    KeyCode::Up | KeyCode::Down => {
+                        let up = key.code == KeyCode::Up;
+                        // 多行输入时先在行间移动，到达首行/末行后再浏览历史
+                        if s.history_index.is_some() || !s.move_line(up) {
+                            s.recall(up);
+                        }
+                    }
+                    KeyCode::Tab => {
+                        let completion = match s.completion.take() {
+                            Some(mut completion) => {
+                                completion.index =
+                                    (completion.index + 1) % completion.candidates.len();
+                                completion
+                            }
+                            None => {
+                                let start = s.word_start(false);
+                                let candidates = on_complete(s.buffer[..s.cursor].to_string());
+                                if candidates.is_empty() {
+                                    return;
+                                }
+                                Completion {
+                                    start,
+                                    candidates,
+                                    index: 0,
+                                }
+                            }
+                        };
+                        let candidate = completion.candidates[completion.index].clone();
+                        let (start, cursor) = (completion.start, s.cursor);
+                        s.buffer.replace_range(start..cursor, &candidate);
+                        s.cursor = start + candidate.len();
+                        s.completion = Some(completion);
+                    }
+                    _ => {}
+                }
+            }
+        });
+    }
+
+    fn measure(&self, max: Size) -> Option<Size> {
+        let height = self.paragraph().line_count(max.width.max(1)) as u16;
+        Some(Size::new(max.width, height.max(1)))
+    }
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        self.paragraph().render(area, buf);
+    }
+}
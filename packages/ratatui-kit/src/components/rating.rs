@@ -0,0 +1,227 @@
+//! Rating 组件：星级评分，支持键盘和鼠标选择。
+//!
+//! ## 用法示例
+//! ```rust
+//! let mut stars = hooks.use_state(|| 3usize);
+//! element!(Rating(
+//!     value: stars.get(),
+//!     max: 5usize,
+//!     is_focus: true,
+//!     on_change: move |value| stars.set(value),
+//! ))
+//! ```
+//! `←/→`（`h/l`）减少/增加一颗星，数字键直接设置评分，`0` 或 `Backspace` 清空。
+//! 开启鼠标捕获后点击星星设置评分，再次点击当前评分的最后一颗星时清空。
+//!
+//! `read_only` 时保持聚焦样式但不能修改评分；`disabled` 时不响应任何事件，并以暗淡样式显示。
+
+use std::sync::{Arc, Mutex};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseButton, MouseEventKind};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Rect, Size},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::Widget,
+};
+use ratatui_kit_macros::Props;
+
+use crate::{Component, Handler, Hooks, UseEvents, UseFocusRing};
+
+#[derive(Props)]
+/// Rating 组件属性。
+pub struct RatingProps {
+    /// 当前评分。
+    pub value: usize,
+    /// 最高评分，即星星数量。
+    pub max: usize,
+    /// 已选中的符号。
+    pub symbol: String,
+    /// 未选中的符号。
+    pub empty_symbol: String,
+    /// 是否聚焦，聚焦时响应按键。
+    pub is_focus: bool,
+    /// 是否只读，只读时不能修改评分。
+    pub read_only: bool,
+    /// 是否禁用，禁用时忽略 `is_focus`，不响应任何事件。
+    pub disabled: bool,
+    /// 评分变更回调。
+    pub on_change: Handler<'static, usize>,
+    /// 已选中符号样式。
+    pub filled_style: Style,
+    /// 未选中符号样式。
+    pub empty_style: Style,
+    /// 聚焦时已选中符号样式。
+    pub focus_style: Style,
+}
+
+impl Default for RatingProps {
+    fn default() -> Self {
+        Self {
+            value: 0,
+            max: 5,
+            symbol: "★".to_string(),
+            empty_symbol: "☆".to_string(),
+            is_focus: false,
+            read_only: false,
+            disabled: false,
+            on_change: Handler::default(),
+            filled_style: Style::default().yellow(),
+            empty_style: Style::default().dark_gray(),
+            focus_style: Style::default().yellow().bold(),
+        }
+    }
+}
+
+// 当前评分和各星星的起始列，供事件处理时换算鼠标位置
+#[derive(Default)]
+struct RatingLayout {
+    value: usize,
+    area: Rect,
+    cell_width: u16,
+}
+
+/// Rating 组件实现。
+pub struct Rating {
+    layout: Arc<Mutex<RatingLayout>>,
+    value: usize,
+    max: usize,
+    symbol: String,
+    empty_symbol: String,
+    is_focus: bool,
+    disabled: bool,
+    filled_style: Style,
+    empty_style: Style,
+    focus_style: Style,
+}
+
+impl Rating {
+    // 每颗星星及其后的空格占用的列数
+    fn cell_width(&self) -> u16 {
+        crate::text::width(&self.symbol).max(crate::text::width(&self.empty_symbol)) as u16 + 1
+    }
+}
+
+impl Component for Rating {
+    type Props<'a> = RatingProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            layout: Arc::new(Mutex::new(RatingLayout::default())),
+            value: props.value,
+            max: props.max,
+            symbol: props.symbol.clone(),
+            empty_symbol: props.empty_symbol.clone(),
+            is_focus: props.is_focus && !props.disabled,
+            disabled: props.disabled,
+            filled_style: props.filled_style,
+            empty_style: props.empty_style,
+            focus_style: props.focus_style,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        _updater: &mut crate::ComponentUpdater,
+    ) {
+        self.layout.lock().unwrap().value = props.value;
+        let is_focus = props.is_focus && !props.disabled;
+        hooks.use_focus_ring(is_focus);
+
+        hooks.use_local_events({
+            let layout = self.layout.clone();
+            let max = props.max;
+            let locked = props.read_only || props.disabled;
+            let mut on_change = props.on_change.take();
+            move |event| {
+                if locked {
+                    return;
+                }
+                let mut layout = layout.lock().unwrap();
+                let value = match event {
+                    Event::Key(key) if is_focus && key.kind != KeyEventKind::Release => {
+                        match key.code {
+                            KeyCode::Left | KeyCode::Char('h') => layout.value.saturating_sub(1),
+                            KeyCode::Right | KeyCode::Char('l') => layout.value + 1,
+                            KeyCode::Home | KeyCode::Backspace => 0,
+                            KeyCode::End => max,
+                            KeyCode::Char(c) if c.is_ascii_digit() => {
+                                c.to_digit(10).unwrap_or_default() as usize
+                            }
+                            _ => return,
+                        }
+                    }
+                    Event::Mouse(mouse)
+                        if mouse.kind == MouseEventKind::Down(MouseButton::Left) =>
+                    {
+                        let area = layout.area;
+                        if layout.cell_width == 0 || mouse.column < area.x {
+                            return;
+                        }
+                        let index = ((mouse.column - area.x) / layout.cell_width) as usize + 1;
+                        if index > max {
+                            return;
+                        }
+                        // 点击当前评分的最后一颗星时清空
+                        if index == layout.value { 0 } else { index }
+                    }
+                    _ => return,
+                };
+                let value = value.min(max);
+                if value != layout.value {
+                    layout.value = value;
+                    drop(layout);
+                    on_change(value);
+                }
+            }
+        });
+
+        self.value = props.value;
+        self.max = props.max;
+        self.symbol = props.symbol.clone();
+        self.empty_symbol = props.empty_symbol.clone();
+        self.is_focus = is_focus;
+        self.disabled = props.disabled;
+        self.filled_style = props.filled_style;
+        self.empty_style = props.empty_style;
+        self.focus_style = props.focus_style;
+    }
+
+    fn measure(&self, max: Size) -> Option<Size> {
+        let width = (self.cell_width() * self.max as u16).saturating_sub(1);
+        Some(Size::new(width.min(max.width), 1))
+    }
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+        {
+            let mut layout = self.layout.lock().unwrap();
+            layout.area = area;
+            layout.cell_width = self.cell_width();
+        }
+        let filled_style = if self.is_focus {
+            self.focus_style
+        } else {
+            self.filled_style
+        };
+        let spans = (1..=self.max)
+            .flat_map(|i| {
+                let star = if i <= self.value {
+                    Span::styled(self.symbol.clone(), filled_style)
+                } else {
+                    Span::styled(self.empty_symbol.clone(), self.empty_style)
+                };
+                [star, Span::raw(" ")]
+            })
+            .collect::<Vec<_>>();
+        Line::from(spans).render(area, buf);
+        if self.disabled {
+            buf.set_style(area, Style::default().dim());
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! RichText 组件：解析行内标记和 ANSI 转义序列，显示带样式的文本。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(RichText(
+//!     text: "[b]构建完成[/b]，[green]12 通过[/green]，[red on black]1 失败[/]",
+//! ))
+//! ```
+//! 标记语法见 [`parse_markup`](crate::text::parse_markup)，外部命令的彩色输出可以直接传入。
+//! 与 [`Text`] 一样按可用宽度自动换行，高度由行数决定。
+
+use ratatui::{layout::Alignment, style::Style};
+use ratatui_kit_macros::{Props, component, element};
+
+use super::Text;
+use crate::{AnyElement, Hooks, UseMemo, text::parse_markup};
+
+#[derive(Props)]
+/// RichText 组件属性。
+pub struct RichTextProps {
+    /// 带标记的文本。
+    pub text: String,
+    /// 基础样式，标记中的样式叠加在其上。
+    pub style: Style,
+    /// 对齐方式。
+    pub alignment: Alignment,
+    /// 是否自动换行，默认开启。
+    pub wrap: bool,
+    /// 换行时是否去掉行首空白。
+    pub trim: bool,
+}
+
+impl Default for RichTextProps {
+    fn default() -> Self {
+        Self {
+            text: String::new(),
+            style: Style::default(),
+            alignment: Alignment::Left,
+            wrap: true,
+            trim: false,
+        }
+    }
+}
+
+#[component]
+pub fn RichText(props: &RichTextProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let text = hooks.use_memo(|| parse_markup(&props.text), props.text.clone());
+
+    element!(Text(
+        text: text,
+        style: props.style,
+        alignment: props.alignment,
+        wrap: props.wrap,
+        trim: props.trim,
+    ))
+}
@@ -0,0 +1,158 @@
+//! AppLauncher：把 clap 解析出的子命令和参数映射为路由的初始路径，使 `mytool jobs 42` 直接打开对应页面。
+//!
+//! ## 用法示例
+//! ```rust
+//! #[derive(clap::Parser, Clone)]
+//! struct Cli {
+//!     #[command(subcommand)]
+//!     command: Option<Commands>,
+//!     #[arg(long)]
+//!     settings: bool,
+//! }
+//!
+//! #[derive(clap::Subcommand, Clone)]
+//! enum Commands {
+//!     Jobs { id: Option<String> },
+//! }
+//!
+//! let matches = Cli::command().get_matches();
+//! let cli = Cli::from_arg_matches(&matches)?;
+//! let launch = AppLauncher::from_clap(&matches)
+//!     .route("/jobs/:id")
+//!     .route("/jobs")
+//!     .flag("settings", "/settings")
+//!     .state(cli);
+//!
+//! element!(RouterProvider(
+//!     routes: routes,
+//!     index_path: launch.path(),
+//!     index_state: launch.route_state(),
+//! ))
+//! .fullscreen()
+//! .await?;
+//! ```
+//! - 路由模式中的普通段依次与子命令名称比较，必须与完整的子命令链一致；
+//! - `:name` 段取 id 为 `name` 的参数值，从最内层子命令向外查找，缺少任何参数时该模式不匹配；
+//! - 按 `route`/`flag` 的调用顺序取第一个匹配项，都不匹配时使用 `index`（默认为 `/`）；
+//! - `state` 设置的值作为初始页面的路由状态，页面中通过 `hooks.use_route_state::<T>()` 读取。
+
+use std::{any::Any, sync::Arc};
+
+use clap::ArgMatches;
+
+/// 根据命令行参数确定初始路由。
+#[derive(Clone)]
+pub struct AppLauncher {
+    // 根命令及各级子命令的参数，与 subcommands 一一对应（根命令在最前）
+    matches: Vec<ArgMatches>,
+    subcommands: Vec<String>,
+    path: Option<String>,
+    index: String,
+    state: Option<Arc<dyn Any + Send + Sync>>,
+}
+
+impl AppLauncher {
+    /// 从 clap 的解析结果创建。
+    pub fn from_clap(matches: &ArgMatches) -> Self {
+        let mut levels = vec![matches.clone()];
+        let mut subcommands = Vec::new();
+        let mut current = matches;
+        while let Some((name, sub)) = current.subcommand() {
+            subcommands.push(name.to_string());
+            levels.push(sub.clone());
+            current = sub;
+        }
+        Self {
+            matches: levels,
+            subcommands,
+            path: None,
+            index: "/".to_string(),
+            state: None,
+        }
+    }
+
+    /// 尝试用路由模式匹配子命令链，如 `/jobs/:id` 匹配 `mytool jobs 42` 得到 `/jobs/42`。
+    pub fn route(mut self, pattern: &str) -> Self {
+        if self.path.is_none() {
+            self.path = self.resolve(pattern);
+        }
+        self
+    }
+
+    /// 根命令或任意一级子命令的布尔参数 `id` 为 true 时打开 `path`。
+    pub fn flag(mut self, id: &str, path: impl Into<String>) -> Self {
+        let set = self
+            .matches
+            .iter()
+            .any(|matches| matches!(matches.try_get_one::<bool>(id), Ok(Some(true))));
+        if self.path.is_none() && set {
+            self.path = Some(path.into());
+        }
+        self
+    }
+
+    /// 没有匹配项时使用的路径。
+    pub fn index(mut self, path: impl Into<String>) -> Self {
+        self.index = path.into();
+        self
+    }
+
+    /// 设置初始页面的路由状态，通常为解析好的命令行参数。
+    pub fn state<T: Send + Sync + 'static>(mut self, state: T) -> Self {
+        self.state = Some(Arc::new(state));
+        self
+    }
+
+    /// 子命令链，如 `mytool jobs show 42` 为 `["jobs", "show"]`。
+    pub fn subcommands(&self) -> &[String] {
+        &self.subcommands
+    }
+
+    /// 初始路由路径。
+    pub fn path(&self) -> String {
+        self.path.clone().unwrap_or_else(|| self.index.clone())
+    }
+
+    /// 初始页面的路由状态，传给 RouterProvider 的 `index_state`。
+    pub fn route_state(&self) -> Option<Arc<dyn Any + Send + Sync>> {
+        self.state.clone()
+    }
+
+    fn resolve(&self, pattern: &str) -> Option<String> {
+        let segments = pattern
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .collect::<Vec<_>>();
+        let literals = segments
+            .iter()
+            .filter(|segment| !segment.starts_with(':'))
+            .copied();
+        if !literals.eq(self.subcommands.iter().map(String::as_str)) {
+            return None;
+        }
+        let mut path = String::new();
+        for segment in segments {
+            let value = match segment.strip_prefix(':') {
+                Some(name) => self.value(name)?,
+                None => segment.to_string(),
+            };
+            path.push('/');
+            path.push_str(&value);
+        }
+        Some(if path.is_empty() {
+            "/".to_string()
+        } else {
+            path
+        })
+    }
+
+    // 从最内层子命令向外查找参数的第一个值
+    fn value(&self, id: &str) -> Option<String> {
+        self.matches.iter().rev().find_map(|matches| {
+            let mut values = matches.try_get_raw(id).ok()??;
+            values
+                .next()
+                .map(|value| value.to_string_lossy().into_owned())
+        })
+    }
+}
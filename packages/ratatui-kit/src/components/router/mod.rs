@@ -10,6 +10,10 @@ pub use outlet::*;
 mod router_provider;
 pub use router_provider::*;
 pub(crate) mod history;
+#[cfg(feature = "clap")]
+mod launcher;
+#[cfg(feature = "clap")]
+pub use launcher::*;
 
 pub struct Route {
     pub path: String,
@@ -74,3 +78,9 @@ pub(crate) struct RouteContext {
     pub params: HashMap<String, String>,
     pub state: Option<Arc<dyn Any + Send + Sync>>,
 }
+
+// RouterProvider 的配置，供各级 Outlet 读取
+#[derive(Default, Clone, Copy)]
+pub(crate) struct RouterOptions {
+    pub keep_alive: bool,
+}
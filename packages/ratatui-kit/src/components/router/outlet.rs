@@ -3,22 +3,53 @@
 //! 通常与 RouterProvider、Routes 等配合使用，实现多级页面嵌套和动态参数解析。
 //!
 //! 类似于 React Router 的 <Outlet />，用于在父路由中渲染匹配的子路由内容，支持递归嵌套和参数传递。
+//!
+//! RouterProvider 开启 `keep_alive` 时，访问过的路由会一直挂载，非当前路由用停用的
+//! [`Activity`](crate::components::Activity) 包裹，并保留离开时的路由上下文。
 
 use crate::{
-    AnyElement, Context, Hooks, UseContext,
-    prelude::{ContextProvider, RouteContext, Routes},
+    AnyElement, Context, Hook, Hooks, UseContext,
+    prelude::{Activity, ContextProvider, Fragment, RouteContext, RouterOptions, Routes},
 };
 use ratatui_kit_macros::{component, element};
 
+// 保活模式下访问过的路由下标及离开时的路由上下文
+#[derive(Default)]
+struct KeptRoutes {
+    routes: Vec<(usize, RouteContext)>,
+}
+
+impl Hook for KeptRoutes {}
+
+impl KeptRoutes {
+    fn visit(&mut self, index: usize, context: RouteContext) {
+        match self.routes.iter_mut().find(|(i, _)| *i == index) {
+            Some((_, kept)) => *kept = context,
+            None => self.routes.push((index, context)),
+        }
+    }
+
+    fn get(&self, index: usize) -> Option<&RouteContext> {
+        self.routes
+            .iter()
+            .find(|(i, _)| *i == index)
+            .map(|(_, context)| context)
+    }
+}
+
 /// Outlet 组件实现。
 #[component]
-pub fn Outlet<'a>(hooks: Hooks) -> impl Into<AnyElement<'a>> {
+pub fn Outlet<'a>(mut hooks: Hooks) -> impl Into<AnyElement<'a>> {
     // 获取全局路由表和当前路径上下文
     let mut routes = hooks.use_context_mut::<Routes>();
     let mut route_context = hooks.use_context_mut::<RouteContext>();
+    let keep_alive = hooks
+        .try_use_context::<RouterOptions>()
+        .is_some_and(|options| options.keep_alive);
+    let kept = hooks.use_hook(KeptRoutes::default);
 
     // 查找与当前路径匹配的第一个路由
-    let mut current_route = routes.iter_mut().find(|r| {
+    let mut current_index = routes.iter().position(|r| {
         let path = route_context.path.clone();
 
         // 判断路径是否包含动态参数（例如 "/users/:id"）
@@ -78,12 +109,51 @@ pub fn Outlet<'a>(hooks: Hooks) -> impl Into<AnyElement<'a>> {
     });
 
     // 如果没有找到匹配的路由，则尝试匹配根路径 "/"
-    if current_route.is_none() {
-        current_route = routes.iter_mut().find(|r| r.path == "/");
+    if current_index.is_none() {
+        current_index = routes.iter().position(|r| r.path == "/");
     }
 
     // 解包 Option 并确保存在匹配的路由
-    let current_route = current_route.expect("No matching route found");
+    let current_index = current_index.expect("No matching route found");
+
+    if keep_alive {
+        kept.visit(current_index, route_context.clone());
+
+        // 每个访问过的路由都保持挂载，只有当前路由处于启用状态
+        let pages = routes
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(index, route)| {
+                let context = kept.get(index)?.clone();
+                let element = AnyElement::from(&mut route.component);
+                Some(
+                    element!(Activity(key: index, active: index == current_index) {
+                        ContextProvider(
+                            value: Context::owned(route.children.borrow())
+                        ) {
+                            ContextProvider(
+                                value: Context::owned(route.borrow())
+                            ) {
+                                ContextProvider(
+                                    value: Context::owned(context)
+                                ) {
+                                    #(element)
+                                }
+                            }
+                        }
+                    })
+                    .into_any(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        return element!(Fragment {
+            #(pages)
+        })
+        .into_any();
+    }
+
+    let current_route = &mut routes[current_index];
 
     // 构建当前路由对应的 UI 元素
     let current_element = AnyElement::from(&mut current_route.component);
@@ -98,4 +168,5 @@ pub fn Outlet<'a>(hooks: Hooks) -> impl Into<AnyElement<'a>> {
             #(current_element)
         }
     })
+    .into_any()
 }
@@ -10,29 +10,52 @@
 //! ))
 //! ```
 //! 子组件可通过 hooks.use_navigate() 跳转页面，通过 hooks.use_route() 获取当前路由。
+//!
+//! 传入子元素时渲染子元素而不是直接渲染 Outlet，子元素中需要包含 Outlet，
+//! 适合在页面外包裹菜单、侧边栏等不随路由变化的外壳：
+//! ```rust
+//! element!(RouterProvider(routes: my_routes(), index_path: "/") {
+//!     SidebarLayout(sidebar: Some(element!(Nav).into())) {
+//!         Outlet
+//!     }
+//! })
+//! ```
+//!
+//! 设置 `keep_alive: true` 后离开的页面不会被卸载，而是保留状态并暂停其事件监听、异步任务和计时器，
+//! 返回时恢复，见 [`Activity`](crate::components::Activity)。
 
 use crate::{
     AnyElement, Context, Hooks, UseState,
     components::router::history::RouterHistory,
-    prelude::{ContextProvider, Outlet, RouteContext, Routes},
+    prelude::{ContextProvider, Outlet, RouteContext, RouterOptions, Routes},
 };
 use ratatui_kit_macros::{Props, component, element};
-use std::collections::{HashMap, VecDeque};
+use std::{
+    any::Any,
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+};
 
 #[derive(Default, Props)]
 /// RouterProvider 组件属性。
-pub struct RouterProviderProps {
+pub struct RouterProviderProps<'a> {
     /// 路由表。
     pub routes: Routes,
     /// 默认首页路径。
     pub index_path: String,
+    /// 首页的路由状态，页面中通过 `hooks.use_route_state()` 读取。
+    pub index_state: Option<Arc<dyn Any + Send + Sync>>,
     /// 路由历史最大长度。
     pub history_length: Option<usize>,
+    /// 是否保活访问过的页面，默认离开页面即卸载。
+    pub keep_alive: bool,
+    /// 包含 Outlet 的外壳，为空时直接渲染 Outlet。
+    pub children: Vec<AnyElement<'a>>,
 }
 
 #[component]
 pub fn RouterProvider<'a>(
-    props: &mut RouterProviderProps,
+    props: &mut RouterProviderProps<'a>,
     mut hooks: Hooks,
 ) -> impl Into<AnyElement<'a>> {
     let history = hooks.use_state(|| RouterHistory {
@@ -41,11 +64,16 @@ pub fn RouterProvider<'a>(
         history: VecDeque::from(vec![RouteContext {
             params: HashMap::new(),
             path: props.index_path.clone(),
-            state: None,
+            state: props.index_state.clone(),
         }]),
     });
 
     let ctx = history.read().current_context();
+    let children = if props.children.is_empty() {
+        vec![element!(Outlet).into_any()]
+    } else {
+        std::mem::take(&mut props.children)
+    };
 
     element!(
         ContextProvider(
@@ -57,7 +85,13 @@ pub fn RouterProvider<'a>(
                 ContextProvider(
                     value: Context::owned(props.routes.borrow()),
                 ) {
-                    Outlet
+                    ContextProvider(
+                        value: Context::owned(RouterOptions {
+                            keep_alive: props.keep_alive,
+                        }),
+                    ) {
+                        #(children)
+                    }
                 }
             }
         }
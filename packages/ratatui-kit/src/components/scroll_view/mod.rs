@@ -11,19 +11,43 @@
 //! })
 //! ```
 //! 通过 `scroll_view_state` 管理滚动位置，`scroll_bars` 控制滚动条样式和显示。
+//!
+//! 开启鼠标捕获（`hooks.use_mouse_capture()`）后，滚动条支持鼠标交互：点击轨道跳转、
+//! 拖拽滑块滚动、在滚动条上滚动滚轮。鼠标引起的偏移量变化通过 `on_scroll` 通知外部状态。
+//!
+//! 对于宽表格、代码等内容，可通过 `content_width/content_height` 显式指定虚拟内容尺寸，
+//! 并通过 `scroll_axis` 锁定滚动方向，避免根据子组件约束估算内容尺寸时出现偏差：
+//! ```rust
+//! element!(ScrollView(
+//!     scroll_view_state: scroll_state.get(),
+//!     scroll_axis: ScrollAxis::Horizontal,
+//!     content_width: Some(200),
+//! ){
+//!     // 宽内容
+//! })
+//! ```
 
-use crate::{AnyElement, Component, layout_style::LayoutStyle};
-use crate::{Hook, State, UseEffect, UseState};
+use crate::{
+    AnyElement, Component, EventInterest, Handler, TerminalEvents, layout_style::LayoutStyle,
+};
+use crate::{Hook, State, UseEffect, UseState, UserPreferences};
+use crossterm::event::{Event, MouseButton, MouseEvent, MouseEventKind};
+use futures::Stream;
 use ratatui::{
     buffer::Buffer,
-    layout::{Constraint, Direction, Layout, Rect},
-    widgets::StatefulWidgetRef,
+    layout::{Constraint, Direction, Layout, Position, Rect, Size},
 };
 use ratatui_kit_macros::{Props, with_layout_style};
+use std::{pin::pin, task::Poll};
 mod state;
 pub use state::ScrollViewState;
 mod scrollbars;
-pub use scrollbars::{ScrollBars, ScrollbarVisibility};
+pub use scrollbars::{ScrollBars, ScrollbarPreset, ScrollbarVisibility};
+use scrollbars::{ScrollbarAxis, ScrollbarHitArea, ScrollbarInteraction, track_offset};
+mod smooth;
+use smooth::ScrollAnimation;
+pub use smooth::SmoothScroll;
+pub(crate) use smooth::interpolate;
 
 #[with_layout_style]
 #[derive(Default, Props)]
@@ -35,11 +59,75 @@ pub struct ScrollViewProps<'a> {
     pub scroll_bars: ScrollBars<'static>,
     /// 滚动状态。
     pub scroll_view_state: ScrollViewState,
+    /// 显式指定虚拟内容宽度，为 None 时根据子组件约束估算。
+    pub content_width: Option<u16>,
+    /// 显式指定虚拟内容高度，为 None 时根据子组件约束估算。
+    pub content_height: Option<u16>,
+    /// 允许滚动的方向，锁定的方向上内容尺寸与视口一致且不显示滚动条。
+    pub scroll_axis: ScrollAxis,
+    /// 平滑滚动配置，为 None 时偏移量变化立即生效。
+    pub smooth_scroll: Option<SmoothScroll>,
+    /// 鼠标操作滚动条导致偏移量变化时的回调。
+    pub on_scroll: Handler<'static, Position>,
+}
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+/// 滚动方向枚举。
+pub enum ScrollAxis {
+    /// 横向和纵向均可滚动。
+    #[default]
+    Both,
+    /// 仅横向滚动，纵向锁定为视口高度。
+    Horizontal,
+    /// 仅纵向滚动，横向锁定为视口宽度。
+    Vertical,
+}
+
+impl ScrollAxis {
+    /// 隐藏被锁定方向的滚动条。
+    fn lock_scrollbars(&self, mut scroll_bars: ScrollBars<'static>) -> ScrollBars<'static> {
+        match self {
+            ScrollAxis::Both => {}
+            ScrollAxis::Horizontal => {
+                scroll_bars.vertical_scrollbar_visibility = ScrollbarVisibility::Never;
+            }
+            ScrollAxis::Vertical => {
+                scroll_bars.horizontal_scrollbar_visibility = ScrollbarVisibility::Never;
+            }
+        }
+        scroll_bars
+    }
+
+    /// 重置被锁定方向的滚动偏移量。
+    fn lock_state(&self, mut state: ScrollViewState) -> ScrollViewState {
+        match self {
+            ScrollAxis::Both => {}
+            ScrollAxis::Horizontal => state.offset.y = 0,
+            ScrollAxis::Vertical => state.offset.x = 0,
+        }
+        state
+    }
 }
 
 /// ScrollView 组件实现。
 pub struct ScrollView {
     scroll_bars: ScrollBars<'static>,
+    content_width: Option<u16>,
+    content_height: Option<u16>,
+    scroll_axis: ScrollAxis,
+}
+
+impl ScrollView {
+    /// 应用内容尺寸覆盖和方向锁定，返回最终的虚拟内容尺寸。
+    fn content_size(&self, (width, height): (u16, u16), area: Rect) -> (u16, u16) {
+        let width = self.content_width.unwrap_or(width);
+        let height = self.content_height.unwrap_or(height);
+        match self.scroll_axis {
+            ScrollAxis::Both => (width, height),
+            ScrollAxis::Horizontal => (width, area.height),
+            ScrollAxis::Vertical => (area.width, height),
+        }
+    }
 }
 
 impl Component for ScrollView {
@@ -47,7 +135,10 @@ impl Component for ScrollView {
 
     fn new(props: &Self::Props<'_>) -> Self {
         Self {
-            scroll_bars: props.scroll_bars.clone(),
+            scroll_bars: props.scroll_axis.lock_scrollbars(props.scroll_bars.clone()),
+            content_width: props.content_width,
+            content_height: props.content_height,
+            scroll_axis: props.scroll_axis,
         }
     }
 
@@ -59,31 +150,51 @@ impl Component for ScrollView {
     ) {
         let layout_style = props.layout_style();
 
-        let scroll_view_state = hooks.use_state(|| props.scroll_view_state);
+        let scroll_axis = props.scroll_axis;
+        let scroll_bars = scroll_axis.lock_scrollbars(props.scroll_bars.clone());
+
+        let scroll_view_state = hooks.use_state(|| scroll_axis.lock_state(props.scroll_view_state));
 
-        let scrollbars = hooks.use_state(|| props.scroll_bars.clone());
+        let scrollbars = hooks.use_state(|| scroll_bars.clone());
 
         hooks.use_effect(
             || {
-                *scrollbars.write() = props.scroll_bars.clone();
+                *scrollbars.write() = scroll_bars.clone();
             },
-            props.scroll_bars.clone(),
+            scroll_bars.clone(),
         );
 
         hooks.use_effect(
             || {
-                *scroll_view_state.write() = props.scroll_view_state;
+                *scroll_view_state.write() = scroll_axis.lock_state(props.scroll_view_state);
             },
-            props.scroll_view_state,
+            (props.scroll_view_state, scroll_axis),
         );
 
-        hooks.use_hook(|| UseScrollImpl {
+        let scroll_hook = hooks.use_hook(|| UseScrollImpl {
             scroll_view_state,
             scrollbars,
             area: None,
+            buffer: None,
+            parent_buffer: None,
+            smooth_scroll: None,
+            animation: ScrollAnimation::default(),
+            events: None,
+            hit_area: ScrollbarHitArea::default(),
+            interaction: ScrollbarInteraction::default(),
+            interaction_changed: false,
+            on_scroll: Handler::default(),
         });
+        // 用户偏好减少动画时不做平滑滚动
+        scroll_hook.smooth_scroll = props
+            .smooth_scroll
+            .filter(|_| !UserPreferences::current(updater).reduced_motion);
+        scroll_hook.on_scroll = props.on_scroll.take();
 
-        self.scroll_bars = props.scroll_bars.clone();
+        self.scroll_bars = scroll_bars;
+        self.content_width = props.content_width;
+        self.content_height = props.content_height;
+        self.scroll_axis = scroll_axis;
 
         updater.set_layout_style(layout_style);
         updater.update_children(&mut props.children, None);
@@ -95,9 +206,14 @@ impl Component for ScrollView {
         layout_style: &LayoutStyle,
         drawer: &mut crate::ComponentDrawer<'_, '_>,
     ) -> Vec<ratatui::prelude::Rect> {
-        let constraint_sum = |d: Direction, len: u16| {
+        // 按最大尺寸 `max` 测量子组件，并把约束换算为 `d` 方向上的长度
+        let constraint_sum = |d: Direction, max: Size| {
+            let len = match d {
+                Direction::Horizontal => max.width,
+                Direction::Vertical => max.height,
+            };
             children
-                .get_constraints(d)
+                .get_measured_constraints(d, max)
                 .iter()
                 .map(|c| match c {
                     Constraint::Length(h) => *h,
@@ -118,30 +234,31 @@ impl Component for ScrollView {
 
         let old_width_height = {
             let area = drawer.area;
-            match layout_style.flex_direction {
+            let size = match layout_style.flex_direction {
                 Direction::Horizontal => {
-                    let sum_w = constraint_sum(Direction::Horizontal, area.width);
+                    let sum_w = constraint_sum(Direction::Horizontal, area.as_size());
                     let sum_count = sum_w.len();
                     let sum_w = sum_w.iter().sum::<u16>()
                         + ((sum_count as i32 - 1) * layout_style.gap) as u16;
-                    let sum_h = constraint_sum(Direction::Vertical, area.height)
+                    let sum_h = constraint_sum(Direction::Vertical, area.as_size())
                         .into_iter()
                         .max()
                         .unwrap_or_default();
                     (sum_w, sum_h)
                 }
                 Direction::Vertical => {
-                    let sum_h = constraint_sum(Direction::Vertical, area.height);
+                    let sum_h = constraint_sum(Direction::Vertical, area.as_size());
                     let sum_count = sum_h.len();
                     let sum_h = sum_h.iter().sum::<u16>()
                         + ((sum_count as i32 - 1) * layout_style.gap) as u16;
-                    let sum_w = constraint_sum(Direction::Horizontal, area.width)
+                    let sum_w = constraint_sum(Direction::Horizontal, area.as_size())
                         .into_iter()
                         .max()
                         .unwrap_or_default();
                     (sum_w, sum_h)
                 }
-            }
+            };
+            self.content_size(size, area)
         };
 
         let horizontal_space = drawer.area.width as i32 - old_width_height.0 as i32 + 1;
@@ -158,56 +275,70 @@ impl Component for ScrollView {
             if show_vertical {
                 area.width -= 1;
             }
-            match layout_style.flex_direction {
-                Direction::Horizontal => {
-                    let widths = constraint_sum(Direction::Horizontal, area.width);
-                    let sum_count = widths.len();
-
-                    let justify_constraints = widths
-                        .iter()
-                        .map(|c| Constraint::Length(*c))
-                        .collect::<Vec<Constraint>>();
-
-                    let sum_w = widths.iter().sum::<u16>()
-                        + ((sum_count as i32 - 1) * layout_style.gap) as u16;
-
-                    let heights = constraint_sum(Direction::Vertical, area.height);
-                    let sum_h = heights.iter().max().copied().unwrap_or_default();
-
-                    let align_constraints = heights
-                        .iter()
-                        .map(|c| Constraint::Length(*c))
-                        .collect::<Vec<Constraint>>();
-
-                    (sum_w, sum_h, justify_constraints, align_constraints)
-                }
-                Direction::Vertical => {
-                    let heights = constraint_sum(Direction::Vertical, area.height);
-                    let sum_count = heights.len();
+            // 指定了内容尺寸时，子组件的相对约束以内容尺寸为基准
+            let (len_w, len_h) = self.content_size((area.width, area.height), area);
+            let (width, height, justify_constraints, align_constraints) =
+                match layout_style.flex_direction {
+                    Direction::Horizontal => {
+                        let widths = constraint_sum(Direction::Horizontal, Size::new(len_w, len_h));
+                        let sum_count = widths.len();
+
+                        let justify_constraints = widths
+                            .iter()
+                            .map(|c| Constraint::Length(*c))
+                            .collect::<Vec<Constraint>>();
+
+                        let sum_w = widths.iter().sum::<u16>()
+                            + ((sum_count as i32 - 1) * layout_style.gap) as u16;
+
+                        let heights = constraint_sum(Direction::Vertical, Size::new(len_w, len_h));
+                        let sum_h = heights.iter().max().copied().unwrap_or_default();
+
+                        let align_constraints = heights
+                            .iter()
+                            .map(|c| Constraint::Length(*c))
+                            .collect::<Vec<Constraint>>();
+
+                        (sum_w, sum_h, justify_constraints, align_constraints)
+                    }
+                    Direction::Vertical => {
+                        let heights = constraint_sum(Direction::Vertical, Size::new(len_w, len_h));
+                        let sum_count = heights.len();
 
-                    let justify_constraints = heights
-                        .iter()
-                        .map(|c| Constraint::Length(*c))
-                        .collect::<Vec<Constraint>>();
+                        let justify_constraints = heights
+                            .iter()
+                            .map(|c| Constraint::Length(*c))
+                            .collect::<Vec<Constraint>>();
 
-                    let sum_h = heights.iter().sum::<u16>()
-                        + ((sum_count as i32 - 1) * layout_style.gap) as u16;
+                        let sum_h = heights.iter().sum::<u16>()
+                            + ((sum_count as i32 - 1) * layout_style.gap) as u16;
 
-                    let widths = constraint_sum(Direction::Horizontal, area.width);
-                    let sum_w = widths.iter().max().copied().unwrap_or_default();
+                        let widths = constraint_sum(Direction::Horizontal, Size::new(len_w, len_h));
+                        let sum_w = widths.iter().max().copied().unwrap_or_default();
 
-                    let align_constraints = widths
-                        .iter()
-                        .map(|c| Constraint::Length(*c))
-                        .collect::<Vec<Constraint>>();
+                        let align_constraints = widths
+                            .iter()
+                            .map(|c| Constraint::Length(*c))
+                            .collect::<Vec<Constraint>>();
 
-                    (sum_w, sum_h, justify_constraints, align_constraints)
-                }
-            }
+                        (sum_w, sum_h, justify_constraints, align_constraints)
+                    }
+                };
+            let (width, height) = self.content_size((width, height), area);
+            (width, height, justify_constraints, align_constraints)
         };
 
+        // 内容尺寸不变时保留上一帧的滚动缓冲区，只重绘发生变化的子组件；
+        // 尺寸变化时重新分配并完整重绘
         let rect = Rect::new(0, 0, width, height);
-        drawer.scroll_buffer = Some(Buffer::empty(rect));
+        let buffer = match drawer.scroll_buffer.take() {
+            Some(buffer) if buffer.area == rect => {
+                drawer.retain_children = true;
+                buffer
+            }
+            _ => Buffer::empty(rect),
+        };
+        drawer.scroll_buffer = Some(buffer);
 
         drawer.area = drawer.buffer_mut().area;
 
@@ -234,19 +365,292 @@ pub struct UseScrollImpl {
     scroll_view_state: State<ScrollViewState>,
     scrollbars: State<ScrollBars<'static>>,
     area: Option<ratatui::layout::Rect>,
+    // 跨帧保留的滚动缓冲区，未变化的子组件沿用其中上一帧的内容
+    buffer: Option<Buffer>,
+    // 嵌套在其他 ScrollView 中时，暂存父级的滚动缓冲区
+    parent_buffer: Option<Buffer>,
+    smooth_scroll: Option<SmoothScroll>,
+    animation: ScrollAnimation,
+    events: Option<TerminalEvents<Event>>,
+    hit_area: ScrollbarHitArea,
+    // 悬停和正在拖拽的滚动条，变化且设置了对应样式时需要重绘
+    interaction: ScrollbarInteraction,
+    interaction_changed: bool,
+    on_scroll: Handler<'static, Position>,
+}
+
+impl UseScrollImpl {
+    fn handle_mouse(&mut self, event: MouseEvent) {
+        let previous = self.interaction;
+        self.apply_mouse(event);
+        if self.interaction != previous {
+            let scrollbars = self.scrollbars.read();
+            self.interaction_changed |=
+                scrollbars.hover_style.is_some() || scrollbars.active_style.is_some();
+        }
+    }
+
+    fn apply_mouse(&mut self, event: MouseEvent) {
+        let hit = self.hit_area;
+        let position = Position::new(event.column, event.row);
+        let vertical = hit.vertical.filter(|track| track.contains(position));
+        let horizontal = hit.horizontal.filter(|track| track.contains(position));
+        self.interaction.hovered = if vertical.is_some() {
+            Some(ScrollbarAxis::Vertical)
+        } else if horizontal.is_some() {
+            Some(ScrollbarAxis::Horizontal)
+        } else {
+            None
+        };
+
+        let state = *self.scroll_view_state.read();
+        let (mut offset, step) = (state.offset, state.scroll_step);
+
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                if let Some(track) = vertical {
+                    let arrows = hit.vertical_arrows;
+                    let (y, drag) =
+                        press_track(track.y, track.height, event.row, offset.y, step, arrows);
+                    offset.y = y.unwrap_or_else(|| {
+                        track_offset(track.y, track.height, event.row, hit.max_offset.y, arrows)
+                    });
+                    self.interaction.active = drag.then_some(ScrollbarAxis::Vertical);
+                } else if let Some(track) = horizontal {
+                    let arrows = hit.horizontal_arrows;
+                    let (x, drag) =
+                        press_track(track.x, track.width, event.column, offset.x, step, arrows);
+                    offset.x = x.unwrap_or_else(|| {
+                        track_offset(track.x, track.width, event.column, hit.max_offset.x, arrows)
+                    });
+                    self.interaction.active = drag.then_some(ScrollbarAxis::Horizontal);
+                } else {
+                    return;
+                }
+            }
+            MouseEventKind::Drag(MouseButton::Left) => match (self.interaction.active, hit) {
+                (
+                    Some(ScrollbarAxis::Vertical),
+                    ScrollbarHitArea {
+                        vertical: Some(track),
+                        ..
+                    },
+                ) => {
+                    offset.y = track_offset(
+                        track.y,
+                        track.height,
+                        event.row,
+                        hit.max_offset.y,
+                        hit.vertical_arrows,
+                    );
+                }
+                (
+                    Some(ScrollbarAxis::Horizontal),
+                    ScrollbarHitArea {
+                        horizontal: Some(track),
+                        ..
+                    },
+                ) => {
+                    offset.x = track_offset(
+                        track.x,
+                        track.width,
+                        event.column,
+                        hit.max_offset.x,
+                        hit.horizontal_arrows,
+                    );
+                }
+                _ => return,
+            },
+            MouseEventKind::Up(MouseButton::Left) => {
+                self.interaction.active = None;
+                return;
+            }
+            MouseEventKind::ScrollDown | MouseEventKind::ScrollRight => {
+                if vertical.is_some() {
+                    offset.y = offset.y.saturating_add(step);
+                } else if horizontal.is_some() {
+                    offset.x = offset.x.saturating_add(step);
+                } else {
+                    return;
+                }
+            }
+            MouseEventKind::ScrollUp | MouseEventKind::ScrollLeft => {
+                if vertical.is_some() {
+                    offset.y = offset.y.saturating_sub(step);
+                } else if horizontal.is_some() {
+                    offset.x = offset.x.saturating_sub(step);
+                } else {
+                    return;
+                }
+            }
+            _ => return,
+        }
+
+        let offset = Position::new(
+            offset.x.min(hit.max_offset.x),
+            offset.y.min(hit.max_offset.y),
+        );
+        if offset != state.offset {
+            self.scroll_view_state.write().set_offset(offset);
+            (self.on_scroll)(offset);
+        }
+    }
+}
+
+// 按下滚动条：首尾箭头按步长滚动并返回新偏移量，按在轨道上则需要跳转并开始拖拽
+fn press_track(
+    start: u16,
+    len: u16,
+    pos: u16,
+    offset: u16,
+    step: u16,
+    (begin, end): (u16, u16),
+) -> (Option<u16>, bool) {
+    let arrows = begin + end;
+    if len > arrows && pos < start + begin {
+        (Some(offset.saturating_sub(step)), false)
+    } else if len > arrows && pos >= start + len - end {
+        (Some(offset.saturating_add(step)), false)
+    } else {
+        (None, true)
+    }
 }
 
 impl Hook for UseScrollImpl {
+    fn poll_change(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context,
+    ) -> std::task::Poll<()> {
+        let this = self.get_mut();
+        while let Some(Poll::Ready(Some(event))) = this
+            .events
+            .as_mut()
+            .map(|events| pin!(events).poll_next(cx))
+        {
+            if let Event::Mouse(mouse_event) = event {
+                this.handle_mouse(mouse_event);
+            }
+        }
+        if std::mem::take(&mut this.interaction_changed) {
+            return Poll::Ready(());
+        }
+        this.animation.poll_frame(cx)
+    }
+
+    fn post_component_update(&mut self, updater: &mut crate::ComponentUpdater) {
+        if self.events.is_none() {
+            self.events = updater.events_of(EventInterest::MOUSE).ok();
+        }
+    }
+
     fn pre_component_draw(&mut self, drawer: &mut crate::ComponentDrawer) {
         self.area = Some(drawer.area);
+        self.parent_buffer = drawer.scroll_buffer.take();
+        drawer.scroll_buffer = self.buffer.take();
     }
     fn post_component_draw(&mut self, drawer: &mut crate::ComponentDrawer) {
         let buffer = drawer.scroll_buffer.take().unwrap();
+        drawer.scroll_buffer = self.parent_buffer.take();
         let scrollbars = self.scrollbars.read();
-        scrollbars.render_ref(
-            self.area.unwrap_or_default(),
-            drawer.buffer_mut(),
-            &mut (*self.scroll_view_state.write(), buffer),
+        let area = self.area.unwrap_or_default();
+        let mut state = (*self.scroll_view_state.read(), buffer);
+
+        // 目标偏移量不能超过缓冲区末尾，否则动画会朝不可达的位置推进
+        let max_offset = Position::new(
+            state
+                .1
+                .area
+                .width
+                .saturating_sub(area.width.saturating_sub(1)),
+            state
+                .1
+                .area
+                .height
+                .saturating_sub(area.height.saturating_sub(1)),
+        );
+        let target = Position::new(
+            state.0.offset.x.min(max_offset.x),
+            state.0.offset.y.min(max_offset.y),
         );
+        state.0.offset = self.animation.step(target, self.smooth_scroll);
+
+        scrollbars.render_interactive(area, drawer.buffer_mut(), &mut state, self.interaction);
+        self.hit_area = scrollbars.hit_area(area, state.1.area.as_size());
+        self.buffer = Some(state.1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use crossterm::event::{Event, KeyCode};
+    use ratatui::{layout::Constraint, text::Line, widgets::Widget};
+
+    use crate::{
+        prelude::*,
+        test_util::{buffer_text, keys, last_frame, mount, send},
+    };
+
+    #[derive(Default, Props)]
+    struct CountedProps {
+        draws: Arc<AtomicUsize>,
+    }
+
+    // 记录绘制次数的静态子组件
+    struct Counted {
+        draws: Arc<AtomicUsize>,
+    }
+
+    impl Component for Counted {
+        type Props<'a> = CountedProps;
+
+        fn new(props: &Self::Props<'_>) -> Self {
+            Self {
+                draws: props.draws.clone(),
+            }
+        }
+
+        fn draw(&mut self, drawer: &mut ComponentDrawer<'_, '_>) {
+            self.draws.fetch_add(1, Ordering::SeqCst);
+            Line::raw("static").render(drawer.area, drawer.buffer_mut());
+        }
+    }
+
+    #[component]
+    fn Toggle(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut on = hooks.use_state(|| false);
+        hooks.use_events(move |event| {
+            if let Event::Key(key) = event {
+                if key.code == KeyCode::Char('t') {
+                    on.set(!on.get());
+                }
+            }
+        });
+        element!(Text(text: if on.get() { "on" } else { "off" }))
+    }
+
+    #[test]
+    fn redraws_only_changed_children() {
+        let draws = Arc::new(AtomicUsize::new(0));
+        let mut root = element!(ScrollView(content_height: Some(20)) {
+            View(height: Constraint::Length(1)) {
+                Counted(draws: draws.clone())
+            }
+            View(height: Constraint::Length(1)) {
+                Toggle
+            }
+        });
+        let mut app = mount(&mut root);
+        send(&mut app, keys([KeyCode::Char('t')]));
+        let before = draws.load(Ordering::SeqCst);
+        send(&mut app, keys([KeyCode::Char('t'), KeyCode::Char('t')]));
+        let text = buffer_text(&last_frame(&mut app));
+        assert!(text.contains("static"));
+        assert!(text.contains("on"));
+        assert_eq!(draws.load(Ordering::SeqCst), before);
     }
 }
@@ -12,14 +12,30 @@
 //! ))
 //! ```
 //! 可灵活控制滚动条的显示策略和样式，适合长列表、表格、文档等场景。
+//!
+//! 使用预设外观并设置最小滑块长度、鼠标悬停和拖拽时的滑块样式：
+//! ```rust
+//! element!(ScrollView(
+//!     scroll_bars: ScrollBars {
+//!         min_thumb_size: 2,
+//!         hover_style: Some(Style::new().fg(Color::Yellow)),
+//!         active_style: Some(Style::new().fg(Color::LightYellow)),
+//!         ..ScrollBars::from_preset(ScrollbarPreset::Braille)
+//!     },
+//!     // ...
+//! ))
+//! ```
 
 use super::ScrollViewState;
 use ratatui::{
     buffer::Buffer,
-    layout::{Rect, Size},
+    layout::{Position, Rect, Size},
+    style::Style,
+    symbols::{block, line, scrollbar::Set, shade},
     widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState, StatefulWidget, StatefulWidgetRef},
 };
 use ratatui_kit_macros::Props;
+use unicode_width::UnicodeWidthStr;
 
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
 /// 滚动条可见性枚举。
@@ -33,6 +49,66 @@ pub enum ScrollbarVisibility {
     Never,
 }
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+/// 滚动条外观预设。
+pub enum ScrollbarPreset {
+    /// 细线轨道、实心滑块，首尾为箭头 `↑`/`↓`。
+    Line,
+    /// 浅色块轨道、实心滑块，首尾为三角形箭头 `▲`/`▼`。
+    Block,
+    /// 盲文点阵轨道和滑块，没有箭头，只占很少的视觉空间。
+    Braille,
+}
+
+impl ScrollbarPreset {
+    /// 预设在纵向或横向滚动条上使用的符号。
+    pub fn symbols(self, vertical: bool) -> Set {
+        match (self, vertical) {
+            (Self::Line, true) => Set {
+                track: line::VERTICAL,
+                thumb: block::FULL,
+                begin: "↑",
+                end: "↓",
+            },
+            (Self::Line, false) => Set {
+                track: line::HORIZONTAL,
+                thumb: block::FULL,
+                begin: "←",
+                end: "→",
+            },
+            (Self::Block, true) => Set {
+                track: shade::LIGHT,
+                thumb: block::FULL,
+                begin: "▲",
+                end: "▼",
+            },
+            (Self::Block, false) => Set {
+                track: shade::LIGHT,
+                thumb: block::FULL,
+                begin: "◄",
+                end: "►",
+            },
+            (Self::Braille, true) => Set {
+                track: "⡇",
+                thumb: "⣿",
+                begin: "",
+                end: "",
+            },
+            (Self::Braille, false) => Set {
+                track: "⠤",
+                thumb: "⣿",
+                begin: "",
+                end: "",
+            },
+        }
+    }
+
+    // 预设是否显示首尾箭头
+    fn has_arrows(self) -> bool {
+        self != Self::Braille
+    }
+}
+
 #[derive(Props, Clone, Hash)]
 /// 滚动条配置。
 pub struct ScrollBars<'a> {
@@ -44,6 +120,18 @@ pub struct ScrollBars<'a> {
     pub vertical_scrollbar: Scrollbar<'a>,
     /// 横向滚动条样式。
     pub horizontal_scrollbar: Scrollbar<'a>,
+    /// 外观预设，设置后替换两个滚动条的轨道、滑块和箭头符号，样式保持不变。
+    pub preset: Option<ScrollbarPreset>,
+    /// 开头箭头符号，`Some(None)` 不显示箭头，None 沿用预设或滚动条自身的设置。
+    pub begin_symbol: Option<Option<&'a str>>,
+    /// 末尾箭头符号，规则同 `begin_symbol`。
+    pub end_symbol: Option<Option<&'a str>>,
+    /// 滑块的最小长度，内容很长时滑块不会缩小到难以看清和拖拽，默认为 1。
+    pub min_thumb_size: u16,
+    /// 鼠标悬停在滚动条上时的滑块样式。
+    pub hover_style: Option<Style>,
+    /// 拖拽滑块时的滑块样式。
+    pub active_style: Option<Style>,
 }
 
 impl Default for ScrollBars<'_> {
@@ -53,11 +141,137 @@ impl Default for ScrollBars<'_> {
             horizontal_scrollbar_visibility: ScrollbarVisibility::Automatic,
             vertical_scrollbar: Scrollbar::new(ScrollbarOrientation::VerticalRight),
             horizontal_scrollbar: Scrollbar::new(ScrollbarOrientation::HorizontalBottom),
+            preset: None,
+            begin_symbol: None,
+            end_symbol: None,
+            min_thumb_size: 1,
+            hover_style: None,
+            active_style: None,
         }
     }
 }
 
-impl ScrollBars<'_> {
+/// 滚动条方向。
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum ScrollbarAxis {
+    Vertical,
+    Horizontal,
+}
+
+/// 鼠标悬停和正在拖拽的滚动条，用于选择滑块样式。
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct ScrollbarInteraction {
+    pub hovered: Option<ScrollbarAxis>,
+    pub active: Option<ScrollbarAxis>,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+/// 滚动条在屏幕上的命中区域，用于鼠标交互。
+pub(crate) struct ScrollbarHitArea {
+    /// 纵向滚动条区域。
+    pub vertical: Option<Rect>,
+    /// 横向滚动条区域。
+    pub horizontal: Option<Rect>,
+    /// 各方向允许的最大滚动偏移量。
+    pub max_offset: Position,
+    /// 纵向滚动条首尾箭头的长度。
+    pub vertical_arrows: (u16, u16),
+    /// 横向滚动条首尾箭头的长度。
+    pub horizontal_arrows: (u16, u16),
+}
+
+impl<'a> ScrollBars<'a> {
+    /// 使用外观预设的滚动条配置，其余设置为默认值。
+    pub fn from_preset(preset: ScrollbarPreset) -> Self {
+        Self {
+            preset: Some(preset),
+            ..Default::default()
+        }
+    }
+
+    // 应用预设、箭头符号和交互样式后实际绘制的滚动条
+    fn resolve(&self, axis: ScrollbarAxis, interaction: ScrollbarInteraction) -> Scrollbar<'a> {
+        let vertical = axis == ScrollbarAxis::Vertical;
+        let mut scrollbar = match axis {
+            ScrollbarAxis::Vertical => self.vertical_scrollbar.clone(),
+            ScrollbarAxis::Horizontal => self.horizontal_scrollbar.clone(),
+        };
+        if let Some(preset) = self.preset {
+            scrollbar = scrollbar.symbols(preset.symbols(vertical));
+            if !preset.has_arrows() {
+                scrollbar = scrollbar.begin_symbol(None).end_symbol(None);
+            }
+        }
+        if let Some(symbol) = self.begin_symbol {
+            scrollbar = scrollbar.begin_symbol(symbol);
+        }
+        if let Some(symbol) = self.end_symbol {
+            scrollbar = scrollbar.end_symbol(symbol);
+        }
+        let style = if interaction.active == Some(axis) {
+            self.active_style.or(self.hover_style)
+        } else if interaction.hovered == Some(axis) && interaction.active.is_none() {
+            self.hover_style
+        } else {
+            None
+        };
+        if let Some(style) = style {
+            scrollbar = scrollbar.thumb_style(style);
+        }
+        scrollbar
+    }
+
+    // 首尾箭头的长度，与 resolve 的规则一致；未通过预设或 begin/end_symbol 修改时为默认箭头的长度
+    fn arrow_lengths(&self) -> (u16, u16) {
+        let preset = self.preset.map(|preset| preset.has_arrows());
+        let len = |symbol: Option<Option<&str>>| match symbol {
+            Some(symbol) => symbol.map_or(0, |symbol| symbol.width() as u16),
+            None => preset.map_or(1, u16::from),
+        };
+        (len(self.begin_symbol), len(self.end_symbol))
+    }
+
+    // 为保证滑块不短于 min_thumb_size 需要使用的视口长度，不需要时返回 None
+    fn thumb_viewport(&self, track_len: u16, content_len: usize, page_len: u16) -> Option<usize> {
+        let min = f64::from(self.min_thumb_size.min(track_len.saturating_sub(1)));
+        if min <= 1.0 || content_len <= 1 {
+            return None;
+        }
+        // 滑块长度约为 viewport * track / (content - 1 + viewport)，首尾取整各有半格误差
+        let target = min + 0.5;
+        let track = f64::from(track_len);
+        let viewport = if target < track {
+            (target * (content_len - 1) as f64 / (track - target)).ceil() as usize
+        } else {
+            content_len * track_len as usize
+        };
+        Some(viewport.max(page_len as usize))
+    }
+
+    /// 计算滚动条的命中区域，与 `render_scrollbars` 的布局保持一致。
+    pub(crate) fn hit_area(&self, area: Rect, scroll_size: Size) -> ScrollbarHitArea {
+        let horizontal_space = area.width as i32 - scroll_size.width as i32;
+        let vertical_space = area.height as i32 - scroll_size.height as i32;
+        let (show_horizontal, show_vertical) =
+            self.visible_scrollbars(horizontal_space, vertical_space);
+
+        let page_width = area.width.saturating_sub(show_vertical as u16);
+        let page_height = area.height.saturating_sub(show_horizontal as u16);
+
+        ScrollbarHitArea {
+            vertical: show_vertical
+                .then(|| Rect::new(area.right().saturating_sub(1), area.y, 1, page_height)),
+            horizontal: show_horizontal
+                .then(|| Rect::new(area.x, area.bottom().saturating_sub(1), page_width, 1)),
+            max_offset: Position::new(
+                scroll_size.width.saturating_sub(page_width),
+                scroll_size.height.saturating_sub(page_height),
+            ),
+            vertical_arrows: self.arrow_lengths(),
+            horizontal_arrows: self.arrow_lengths(),
+        }
+    }
+
     fn render_visible_area(
         &self,
         area: Rect,
@@ -65,11 +279,26 @@ impl ScrollBars<'_> {
         visible_area: Rect,
         scroll_buffer: &Buffer,
     ) {
-        // TODO: 这里可能有更高效的实现方式
-        for (src_row, dst_row) in visible_area.rows().zip(area.rows()) {
-            for (src_col, dst_col) in src_row.columns().zip(dst_row.columns()) {
-                buf[dst_col] = scroll_buffer[src_col].clone();
-            }
+        // 区域被目标缓冲区裁掉的左侧和上方部分，可见区域同步跳过
+        let clipped = area.intersection(buf.area);
+        let (dx, dy) = (clipped.x - area.x, clipped.y - area.y);
+        let visible_area = Rect::new(
+            visible_area.x + dx,
+            visible_area.y + dy,
+            visible_area.width.saturating_sub(dx),
+            visible_area.height.saturating_sub(dy),
+        );
+        let area = clipped;
+        // 按行整体拷贝可见区域，避免逐个单元格索引
+        let width = visible_area.width.min(area.width) as usize;
+        if width == 0 {
+            return;
+        }
+        for (src_y, dst_y) in visible_area.rows().zip(area.rows()) {
+            let src_start = scroll_buffer.index_of(visible_area.x, src_y.y);
+            let dst_start = buf.index_of(area.x, dst_y.y);
+            buf.content[dst_start..dst_start + width]
+                .clone_from_slice(&scroll_buffer.content[src_start..src_start + width]);
         }
     }
 
@@ -79,13 +308,20 @@ impl ScrollBars<'_> {
         buf: &mut Buffer,
         state: &ScrollViewState,
         scroll_size: Size,
+        interaction: ScrollbarInteraction,
     ) {
         let scrollbar_height = scroll_size.height.saturating_sub(area.height);
         let mut scrollbar_state =
             ScrollbarState::new(scrollbar_height as usize).position(state.offset.y as usize);
+        let (begin, end) = self.arrow_lengths();
+        let track_len = area.height.saturating_sub(begin + end);
+        if let Some(viewport) =
+            self.thumb_viewport(track_len, scrollbar_height as usize, area.height)
+        {
+            scrollbar_state = scrollbar_state.viewport_content_length(viewport);
+        }
 
-        self.vertical_scrollbar
-            .clone()
+        self.resolve(ScrollbarAxis::Vertical, interaction)
             .render(area, buf, &mut scrollbar_state);
     }
 
@@ -95,14 +331,23 @@ impl ScrollBars<'_> {
         buf: &mut Buffer,
         state: &ScrollViewState,
         scroll_size: Size,
+        interaction: ScrollbarInteraction,
     ) {
         let scrollbar_width = scroll_size.width.saturating_sub(area.width);
 
         let mut scrollbar_state =
             ScrollbarState::new(scrollbar_width as usize).position(state.offset.x as usize);
-        self.horizontal_scrollbar
-            .clone()
-            .render(area, buf, &mut scrollbar_state);
+        let (begin, end) = self.arrow_lengths();
+        let track_len = area.width.saturating_sub(begin + end);
+        if let Some(viewport) = self.thumb_viewport(track_len, scrollbar_width as usize, area.width)
+        {
+            scrollbar_state = scrollbar_state.viewport_content_length(viewport);
+        }
+        self.resolve(ScrollbarAxis::Horizontal, interaction).render(
+            area,
+            buf,
+            &mut scrollbar_state,
+        );
     }
 
     pub fn visible_scrollbars(&self, horizontal_space: i32, vertical_space: i32) -> (bool, bool) {
@@ -156,6 +401,7 @@ impl ScrollBars<'_> {
         buf: &mut Buffer,
         state: &mut ScrollViewState,
         scroll_buffer: &Buffer,
+        interaction: ScrollbarInteraction,
     ) -> Rect {
         let size: ratatui::prelude::Size = scroll_buffer.area.as_size();
         // 每个方向的适配值
@@ -181,7 +427,7 @@ impl ScrollBars<'_> {
             let width = area.width.saturating_sub(show_vertical as u16);
             let render_area = Rect { width, ..area };
             // 渲染滚动条，更新可用空间
-            self.render_horizontal_scrollbar(render_area, buf, state, size);
+            self.render_horizontal_scrollbar(render_area, buf, state, size, interaction);
             area.height.saturating_sub(1)
         } else {
             area.height
@@ -192,7 +438,7 @@ impl ScrollBars<'_> {
             let height = area.height.saturating_sub(show_horizontal as u16);
             let render_area = Rect { height, ..area };
             // 渲染滚动条，更新可用空间
-            self.render_vertical_scrollbar(render_area, buf, state, size);
+            self.render_vertical_scrollbar(render_area, buf, state, size, interaction);
             area.width.saturating_sub(1)
         } else {
             area.width
@@ -205,7 +451,20 @@ impl ScrollBars<'_> {
 impl StatefulWidgetRef for ScrollBars<'_> {
     type State = (ScrollViewState, Buffer);
 
-    fn render_ref(&self, area: Rect, buf: &mut Buffer, (state, scroll_buffer): &mut Self::State) {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_interactive(area, buf, state, ScrollbarInteraction::default());
+    }
+}
+
+impl ScrollBars<'_> {
+    /// 按鼠标交互状态选择滑块样式并绘制。
+    pub(crate) fn render_interactive(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        (state, scroll_buffer): &mut (ScrollViewState, Buffer),
+        interaction: ScrollbarInteraction,
+    ) {
         let (mut x, mut y) = state.offset.into();
         // 确保不会在任一方向上滚动超过缓冲区末尾
         let max_x_offset = scroll_buffer
@@ -223,8 +482,46 @@ impl StatefulWidgetRef for ScrollBars<'_> {
         state.size = Some(scroll_buffer.area.as_size());
         state.page_size = Some(area.into());
         let visible_area = self
-            .render_scrollbars(area, buf, state, scroll_buffer)
+            .render_scrollbars(area, buf, state, scroll_buffer, interaction)
             .intersection(scroll_buffer.area);
         self.render_visible_area(area, buf, visible_area, scroll_buffer);
     }
 }
+
+/// 将滚动条轨道上的位置按比例换算为滚动偏移量，`arrows` 为轨道首尾箭头的长度。
+pub(crate) fn track_offset(
+    track_start: u16,
+    track_len: u16,
+    pos: u16,
+    max_offset: u16,
+    (begin, end): (u16, u16),
+) -> u16 {
+    let inner = track_len.saturating_sub(begin + end) as u32;
+    if inner < 2 {
+        return 0;
+    }
+    let rel = (pos.saturating_sub(track_start + begin) as u32).min(inner - 1);
+    ((rel * max_offset as u32 + (inner - 1) / 2) / (inner - 1)) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{buffer::Buffer, layout::Rect};
+
+    use super::ScrollBars;
+
+    #[test]
+    fn visible_area_follows_left_clipping() {
+        let scroll_buffer = Buffer::with_lines(["abcdefgh", "ijklmnop"]);
+        let mut buf = Buffer::empty(Rect::new(3, 0, 6, 2));
+        ScrollBars::default().render_visible_area(
+            Rect::new(0, 0, 8, 2),
+            &mut buf,
+            scroll_buffer.area,
+            &scroll_buffer,
+        );
+        let mut expected = Buffer::with_lines(["defgh ", "lmnop "]);
+        expected.area.x = 3;
+        assert_eq!(buf, expected);
+    }
+}
@@ -0,0 +1,107 @@
+//! 平滑滚动：将滚动偏移量的变化分摊到多帧完成，由定时器逐帧驱动重新渲染。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(ScrollView(
+//!     scroll_view_state: scroll_state.get(),
+//!     smooth_scroll: Some(SmoothScroll::default()),
+//! ){
+//!     // 子内容
+//! })
+//! ```
+
+use futures_timer::Delay;
+use ratatui::layout::Position;
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use crate::{UpdatePriority, render::scheduler};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+/// 平滑滚动配置。
+pub struct SmoothScroll {
+    /// 一次偏移量变化分多少帧完成，为 0 时等同于关闭平滑滚动。
+    pub frames: u16,
+    /// 相邻两帧之间的间隔。
+    pub frame_interval: Duration,
+}
+
+impl Default for SmoothScroll {
+    fn default() -> Self {
+        Self {
+            frames: 6,
+            frame_interval: Duration::from_millis(16),
+        }
+    }
+}
+
+#[derive(Default)]
+/// 滚动动画状态，记录当前渲染的偏移量与目标偏移量。
+pub(crate) struct ScrollAnimation {
+    current: Option<Position>,
+    target: Position,
+    frames_left: u16,
+    delay: Option<Delay>,
+}
+
+impl ScrollAnimation {
+    /// 向目标偏移量推进一帧，返回本帧应渲染的偏移量。
+    pub(crate) fn step(&mut self, target: Position, smooth: Option<SmoothScroll>) -> Position {
+        let Some(smooth) = smooth.filter(|smooth| smooth.frames > 0) else {
+            self.current = Some(target);
+            self.target = target;
+            self.frames_left = 0;
+            self.delay = None;
+            return target;
+        };
+
+        let current = *self.current.get_or_insert(target);
+        if target != self.target {
+            self.target = target;
+            self.frames_left = smooth.frames;
+        }
+
+        let next = if self.frames_left > 0 {
+            let frames = self.frames_left;
+            self.frames_left -= 1;
+            Position::new(
+                interpolate(current.x, target.x, frames),
+                interpolate(current.y, target.y, frames),
+            )
+        } else {
+            target
+        };
+
+        self.current = Some(next);
+        if next != target {
+            // 还未到达目标，预约下一帧
+            self.delay = Some(Delay::new(smooth.frame_interval));
+        } else {
+            self.frames_left = 0;
+        }
+        next
+    }
+
+    /// 下一帧的定时器到期时返回 Ready，触发重新渲染。
+    pub(crate) fn poll_frame(&mut self, cx: &mut Context) -> Poll<()> {
+        if let Some(delay) = self.delay.as_mut() {
+            if Pin::new(delay).poll(cx).is_ready() {
+                self.delay = None;
+                scheduler::mark(UpdatePriority::Animation);
+                return Poll::Ready(());
+            }
+        }
+        Poll::Pending
+    }
+}
+
+// 在剩余帧数内线性逼近目标值，每帧至少移动一格
+pub(crate) fn interpolate(from: u16, to: u16, frames: u16) -> u16 {
+    let diff = to as i32 - from as i32;
+    let step = diff / frames as i32;
+    let step = if step == 0 { diff.signum() } else { step };
+    (from as i32 + step) as u16
+}
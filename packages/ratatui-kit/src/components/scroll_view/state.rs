@@ -13,7 +13,7 @@
 use crossterm::event::{Event, KeyCode, KeyEventKind, MouseEventKind};
 use ratatui::layout::{Position, Size};
 
-#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 /// 滚动视图状态。
 pub struct ScrollViewState {
     /// 偏移量是滚动视图需要移动的行数和列数。
@@ -22,6 +22,22 @@ pub struct ScrollViewState {
     pub(crate) size: Option<Size>,
     /// 滚动视图一页的尺寸。在第一次渲染调用前不会被设置。
     pub(crate) page_size: Option<Size>,
+    /// 单次滚动的行数/列数。
+    pub(crate) scroll_step: u16,
+    /// 翻页时与上一页保留的重叠行数。
+    pub(crate) page_overlap: u16,
+}
+
+impl Default for ScrollViewState {
+    fn default() -> Self {
+        Self {
+            offset: Position::ORIGIN,
+            size: None,
+            page_size: None,
+            scroll_step: 1,
+            page_overlap: 1,
+        }
+    }
 }
 
 impl ScrollViewState {
@@ -38,6 +54,18 @@ impl ScrollViewState {
         }
     }
 
+    /// 设置单次滚动的行数/列数，默认为 1
+    pub const fn with_scroll_step(mut self, scroll_step: u16) -> Self {
+        self.scroll_step = scroll_step;
+        self
+    }
+
+    /// 设置翻页时保留的重叠行数，默认为 1，不小于页高时每次翻页只移动一行
+    pub const fn with_page_overlap(mut self, page_overlap: u16) -> Self {
+        self.page_overlap = page_overlap;
+        self
+    }
+
     /// 设置滚动视图状态的偏移量
     pub const fn set_offset(&mut self, offset: Position) {
         self.offset = offset;
@@ -48,38 +76,40 @@ impl ScrollViewState {
         self.offset
     }
 
-    /// 向上滚动一行
+    /// 向上滚动一步
     pub const fn scroll_up(&mut self) {
-        self.offset.y = self.offset.y.saturating_sub(1);
+        self.offset.y = self.offset.y.saturating_sub(self.scroll_step);
     }
 
-    /// 向下滚动一行
+    /// 向下滚动一步
     pub const fn scroll_down(&mut self) {
-        self.offset.y = self.offset.y.saturating_add(1);
+        self.offset.y = self.offset.y.saturating_add(self.scroll_step);
     }
 
     /// 向下滚动一页
     pub fn scroll_page_down(&mut self) {
-        let page_size = self.page_size.map_or(1, |size| size.height);
-        // 我们减去 1 以确保页面之间有一行重叠
-        self.offset.y = self.offset.y.saturating_add(page_size).saturating_sub(1);
+        self.offset.y = self.offset.y.saturating_add(self.page_step());
     }
 
     /// 向上滚动一页
     pub fn scroll_page_up(&mut self) {
+        self.offset.y = self.offset.y.saturating_sub(self.page_step());
+    }
+
+    // 翻页的行数：页高减去重叠行数，保证页面之间有内容重叠，且至少移动一行
+    fn page_step(&self) -> u16 {
         let page_size = self.page_size.map_or(1, |size| size.height);
-        // 我们加上 1 以确保页面之间有一行重叠
-        self.offset.y = self.offset.y.saturating_add(1).saturating_sub(page_size);
+        page_size.saturating_sub(self.page_overlap).max(1)
     }
 
-    /// 向左滚动一列
+    /// 向左滚动一步
     pub const fn scroll_left(&mut self) {
-        self.offset.x = self.offset.x.saturating_sub(1);
+        self.offset.x = self.offset.x.saturating_sub(self.scroll_step);
     }
 
-    /// 向右滚动一列
+    /// 向右滚动一步
     pub const fn scroll_right(&mut self) {
-        self.offset.x = self.offset.x.saturating_add(1);
+        self.offset.x = self.offset.x.saturating_add(self.scroll_step);
     }
 
     /// 滚动到缓冲区顶部
@@ -144,3 +174,25 @@ impl ScrollViewState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui::layout::{Position, Size};
+
+    use super::ScrollViewState;
+
+    #[test]
+    fn page_step_moves_at_least_one_row() {
+        let mut state = ScrollViewState::with_offset(Position::new(0, 10)).with_page_overlap(5);
+        state.page_size = Some(Size::new(10, 3));
+        state.scroll_page_down();
+        assert_eq!(state.offset().y, 11);
+        state.scroll_page_up();
+        assert_eq!(state.offset().y, 10);
+
+        // 布局前没有页高，默认的重叠行数也不能让翻页停住
+        let mut state = ScrollViewState::default();
+        state.scroll_page_down();
+        assert_eq!(state.offset().y, 1);
+    }
+}
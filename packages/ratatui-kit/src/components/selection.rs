@@ -0,0 +1,207 @@
+//! SelectionState：列表/表格/树形视图共用的选择模型，支持单选、多选（Space 切换）和范围选择（Shift）。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(List(
+//!     items: items,
+//!     selection_state: SelectionState::new(SelectionMode::Multi),
+//!     on_selection_change: move |selected: Vec<usize>| selection.set(selected),
+//!     is_focus: true,
+//! ))
+//! ```
+//!
+//! 多选模式下支持以下按键：
+//! - `↑/↓`、`k/j`：移动光标
+//! - `Shift+↑/↓`：从锚点扩展范围选择
+//! - `Space`：切换光标所在项的选中状态
+//! - `Ctrl+A`：全选，`Esc`：清空选择
+//!
+//! 鼠标点击时，按住 `Ctrl` 切换单项，按住 `Shift` 选择范围。
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use std::collections::BTreeSet;
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+/// 选择模式。
+pub enum SelectionMode {
+    /// 单选，选中项始终跟随光标。
+    #[default]
+    Single,
+    /// 多选，支持 Space 切换与 Shift 范围选择。
+    Multi,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+/// 选择状态，记录光标位置、范围选择锚点和选中项。
+pub struct SelectionState {
+    mode: SelectionMode,
+    cursor: Option<usize>,
+    anchor: Option<usize>,
+    selected: BTreeSet<usize>,
+}
+
+impl SelectionState {
+    /// 创建指定模式的空选择状态。
+    pub fn new(mode: SelectionMode) -> Self {
+        Self {
+            mode,
+            ..Default::default()
+        }
+    }
+
+    /// 当前选择模式。
+    pub fn mode(&self) -> SelectionMode {
+        self.mode
+    }
+
+    /// 光标所在项。
+    pub fn cursor(&self) -> Option<usize> {
+        self.cursor
+    }
+
+    /// 按升序返回全部选中项。
+    pub fn selected(&self) -> Vec<usize> {
+        self.selected.iter().copied().collect()
+    }
+
+    /// 判断某项是否选中。
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.selected.contains(&index)
+    }
+
+    /// 将光标移动到指定项，单选模式下同时选中该项。
+    pub fn select(&mut self, index: usize) {
+        self.cursor = Some(index);
+        self.anchor = Some(index);
+        if self.mode == SelectionMode::Single {
+            self.selected = BTreeSet::from([index]);
+        }
+    }
+
+    /// 切换光标所在项的选中状态，仅多选模式有效。
+    pub fn toggle(&mut self) {
+        if self.mode != SelectionMode::Multi {
+            return;
+        }
+        if let Some(cursor) = self.cursor {
+            if !self.selected.remove(&cursor) {
+                self.selected.insert(cursor);
+            }
+            self.anchor = Some(cursor);
+        }
+    }
+
+    /// 选中从锚点到指定项的范围，仅多选模式有效，单选模式等同于 `select`。
+    pub fn select_range(&mut self, index: usize) {
+        if self.mode != SelectionMode::Multi {
+            self.select(index);
+            return;
+        }
+        let anchor = *self.anchor.get_or_insert(index);
+        self.cursor = Some(index);
+        self.selected = (anchor.min(index)..=anchor.max(index)).collect();
+    }
+
+    /// 全选，仅多选模式有效。
+    pub fn select_all(&mut self, len: usize) {
+        if self.mode == SelectionMode::Multi {
+            self.selected = (0..len).collect();
+        }
+    }
+
+    /// 清空选择，光标位置保持不变。
+    pub fn clear(&mut self) {
+        self.selected.clear();
+        self.anchor = self.cursor;
+    }
+
+    /// 按方向移动光标，`extend` 为 true 时扩展范围选择。
+    pub fn move_cursor(&mut self, delta: isize, len: usize, extend: bool) {
+        if len == 0 {
+            return;
+        }
+        let next = match self.cursor {
+            Some(cursor) => (cursor as isize + delta).clamp(0, len as isize - 1) as usize,
+            None => 0,
+        };
+        match self.mode {
+            SelectionMode::Multi if extend => self.select_range(next),
+            SelectionMode::Multi => {
+                self.cursor = Some(next);
+                self.anchor = Some(next);
+            }
+            SelectionMode::Single => self.select(next),
+        }
+    }
+
+    /// 移除超出数据长度的光标和选中项，数据变化后调用。
+    pub fn clamp(&mut self, len: usize) {
+        self.selected.retain(|&i| i < len);
+        let last = len.checked_sub(1);
+        self.cursor = self.cursor.and_then(|c| last.map(|last| c.min(last)));
+        self.anchor = self.anchor.and_then(|a| last.map(|last| a.min(last)));
+    }
+
+    /// 数据结构变化后按映射调整光标和选中项，映射为 None 的项被移除。
+    pub fn remap(&mut self, f: impl Fn(usize) -> Option<usize>) {
+        self.selected = self.selected.iter().filter_map(|&i| f(i)).collect();
+        self.cursor = self.cursor.and_then(&f);
+        self.anchor = self.anchor.and_then(&f);
+    }
+
+    /// 列表项从 `from` 移动到 `to` 后，同步调整光标和选中项的位置。
+    pub fn move_item(&mut self, from: usize, to: usize) {
+        let remap = |i: usize| {
+            if i == from {
+                to
+            } else if from < to && (from + 1..=to).contains(&i) {
+                i - 1
+            } else if to < from && (to..from).contains(&i) {
+                i + 1
+            } else {
+                i
+            }
+        };
+        self.selected = self.selected.iter().map(|&i| remap(i)).collect();
+        self.cursor = self.cursor.map(remap);
+        self.anchor = self.anchor.map(remap);
+    }
+
+    /// 处理按键事件，返回选择状态是否发生变化。
+    pub fn handle_key(&mut self, key: KeyEvent, len: usize) -> bool {
+        if key.kind != KeyEventKind::Press {
+            return false;
+        }
+        let before = self.clone();
+        let extend = key.modifiers.contains(KeyModifiers::SHIFT);
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => self.move_cursor(-1, len, extend),
+            KeyCode::Down | KeyCode::Char('j') => self.move_cursor(1, len, extend),
+            KeyCode::Home => self.move_cursor(isize::MIN / 2, len, extend),
+            KeyCode::End => self.move_cursor(isize::MAX / 2, len, extend),
+            KeyCode::Char(' ') => self.toggle(),
+            KeyCode::Char('a') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.select_all(len)
+            }
+            KeyCode::Esc if self.mode == SelectionMode::Multi => self.clear(),
+            _ => return false,
+        }
+        *self != before
+    }
+
+    /// 处理鼠标点击，按住 Ctrl 切换单项，按住 Shift 选择范围。
+    pub fn handle_click(&mut self, index: usize, modifiers: KeyModifiers) {
+        if self.mode == SelectionMode::Single {
+            self.select(index);
+        } else if modifiers.contains(KeyModifiers::SHIFT) {
+            self.select_range(index);
+        } else if modifiers.contains(KeyModifiers::CONTROL) {
+            self.cursor = Some(index);
+            self.toggle();
+        } else {
+            self.cursor = Some(index);
+            self.anchor = Some(index);
+            self.selected = BTreeSet::from([index]);
+        }
+    }
+}
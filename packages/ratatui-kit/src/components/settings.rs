@@ -0,0 +1,230 @@
+//! Settings 组件：根据 [`Config`] 的配置项自动生成的设置页面，可在应用内直接修改配置。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(Settings(config: Some(CONFIG.clone()), is_focus: true))
+//! ```
+//! - `↑/↓`（或 `k/j`）选择配置项，`Enter`/`Space` 切换布尔值或编辑其他值，`r` 恢复默认值。
+//! - 编辑时 `Enter` 保存，`Esc` 取消；数字和布尔值按默认值的类型校验，其他类型按 JSON 解析。
+//! - 修改立即写回配置文件；来自环境变量的值会标注出来，修改后仍以环境变量为准。
+
+use crossterm::event::{Event, KeyCode, KeyEventKind};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction},
+    style::{Style, Stylize},
+    text::{Line, Span},
+};
+use ratatui_kit_macros::{Props, component, element};
+use serde_json::Value;
+
+use super::{Text, View};
+use crate::{
+    AnyElement, Config, ConfigEntry, ConfigSource, EventInterest, Hooks, UseConfig, UseEvents,
+    UseMemo, UseState, text,
+};
+
+#[derive(Props)]
+/// Settings 组件属性。
+pub struct SettingsProps {
+    /// 要编辑的配置，组件挂载后不应更换。
+    pub config: Option<Config>,
+    /// 是否聚焦，聚焦时响应按键。
+    pub is_focus: bool,
+    /// 键名样式。
+    pub key_style: Style,
+    /// 默认值样式。
+    pub value_style: Style,
+    /// 来自配置文件的值样式。
+    pub modified_style: Style,
+    /// 来自环境变量的值样式。
+    pub env_style: Style,
+    /// 光标所在行样式。
+    pub highlight_style: Style,
+    /// 底部提示样式。
+    pub hint_style: Style,
+    /// 错误信息样式。
+    pub error_style: Style,
+}
+
+impl Default for SettingsProps {
+    fn default() -> Self {
+        Self {
+            config: None,
+            is_focus: false,
+            key_style: Style::default().cyan(),
+            value_style: Style::default(),
+            modified_style: Style::default().yellow(),
+            env_style: Style::default().magenta(),
+            highlight_style: Style::default().reversed(),
+            hint_style: Style::default().dim(),
+            error_style: Style::default().red(),
+        }
+    }
+}
+
+// 编辑时显示的文本：字符串显示原文，其他值显示 JSON
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+// 按默认值的类型解析输入
+fn parse_input(input: &str, default: &Value) -> Result<Value, String> {
+    match default {
+        Value::String(_) => Ok(Value::String(input.to_string())),
+        Value::Bool(_) => input
+            .trim()
+            .parse::<bool>()
+            .map(Value::Bool)
+            .map_err(|_| "请输入 true 或 false".to_string()),
+        Value::Number(_) => match serde_json::from_str(input.trim()) {
+            Ok(Value::Number(n)) => Ok(Value::Number(n)),
+            _ => Err("请输入数字".to_string()),
+        },
+        _ => serde_json::from_str(input).map_err(|err| format!("无效的 JSON：{err}")),
+    }
+}
+
+#[component]
+pub fn Settings(props: &SettingsProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let fallback = hooks.use_memo(|| Config::new(Value::Null), ());
+    let config = hooks.use_config(props.config.as_ref().unwrap_or(&fallback));
+    let mut cursor = hooks.use_state(|| 0usize);
+    let mut editing = hooks.use_state(|| None::<String>);
+    let mut message = hooks.use_state(|| None::<String>);
+
+    let entries = config.entries();
+    if cursor.get() >= entries.len() {
+        cursor.set(entries.len().saturating_sub(1));
+    }
+
+    hooks.use_events_of(EventInterest::KEY, {
+        let is_focus = props.is_focus;
+        let entries = entries.clone();
+        move |event| {
+            let Event::Key(key) = event else {
+                return;
+            };
+            if !is_focus || key.kind != KeyEventKind::Press {
+                return;
+            }
+            let Some(ConfigEntry {
+                key: name,
+                value,
+                default,
+                ..
+            }) = entries.get(cursor.get())
+            else {
+                return;
+            };
+            let input = editing.read().clone();
+            let result = if let Some(input) = input {
+                match key.code {
+                    KeyCode::Char(c) => {
+                        editing.set(Some(format!("{input}{c}")));
+                        return;
+                    }
+                    KeyCode::Backspace => {
+                        let mut input = input;
+                        input.pop();
+                        editing.set(Some(input));
+                        return;
+                    }
+                    KeyCode::Esc => {
+                        editing.set(None);
+                        message.set(None);
+                        return;
+                    }
+                    KeyCode::Enter => match parse_input(&input, default) {
+                        Ok(value) => {
+                            editing.set(None);
+                            config.set(name.clone(), value)
+                        }
+                        Err(err) => {
+                            message.set(Some(err));
+                            return;
+                        }
+                    },
+                    _ => return,
+                }
+            } else {
+                match key.code {
+                    KeyCode::Up | KeyCode::Char('k') => {
+                        cursor.set(cursor.get().saturating_sub(1));
+                        message.set(None);
+                        return;
+                    }
+                    KeyCode::Down | KeyCode::Char('j') => {
+                        cursor.set((cursor.get() + 1).min(entries.len() - 1));
+                        message.set(None);
+                        return;
+                    }
+                    KeyCode::Enter | KeyCode::Char(' ') => match value {
+                        Value::Bool(b) => config.set(name.clone(), !b),
+                        value => {
+                            editing.set(Some(value_text(value)));
+                            message.set(None);
+                            return;
+                        }
+                    },
+                    KeyCode::Char('r') => config.reset(name),
+                    _ => return,
+                }
+            };
+            message.set(result.err().map(|err| format!("保存失败：{err}")));
+        }
+    });
+
+    let key_width = entries
+        .iter()
+        .map(|entry| text::width(&entry.key))
+        .max()
+        .unwrap_or(0);
+    let lines = entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let is_cursor = index == cursor.get();
+            let value_style = match entry.source {
+                ConfigSource::Default => props.value_style,
+                ConfigSource::File => props.modified_style,
+                ConfigSource::Env => props.env_style,
+            };
+            let mut spans = vec![
+                Span::styled(
+                    text::pad(&entry.key, key_width, Alignment::Left).into_owned(),
+                    props.key_style,
+                ),
+                Span::raw("  "),
+            ];
+            match editing.read().as_ref().filter(|_| is_cursor) {
+                Some(input) => spans.push(Span::styled(format!("{input}▏"), value_style)),
+                None => spans.push(Span::styled(value_text(&entry.value), value_style)),
+            }
+            if entry.source == ConfigSource::Env {
+                spans.push(Span::styled("  (环境变量)", props.hint_style));
+            }
+            let line = Line::from(spans);
+            if is_cursor && props.is_focus {
+                line.patch_style(props.highlight_style)
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let status = match (message.read().clone(), editing.read().is_some()) {
+        (Some(message), _) => Line::styled(message, props.error_style),
+        (None, true) => Line::styled("Enter 保存  Esc 取消", props.hint_style),
+        (None, false) => Line::styled("↑↓ 选择  Enter 编辑  r 恢复默认", props.hint_style),
+    };
+
+    element!(View(flex_direction: Direction::Vertical) {
+        View(height: Constraint::Fill(1)) {
+            Text(text: lines, wrap: false)
+        }
+        Text(text: status, wrap: false)
+    })
+}
@@ -0,0 +1,362 @@
+//! SidebarLayout 组件：应用外壳布局，左侧为可折叠的导航面板，右侧为主内容区域。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(SidebarLayout(
+//!     sidebar: Some(element!(Border(top_title: Some(Line::from("导航"))) {
+//!         NavList()
+//!     }).into()),
+//!     width: 24,
+//! ) {
+//!     Outlet
+//! })
+//! ```
+//! - `toggle_key`（默认 `Ctrl+B`）切换展开和折叠，宽度变化分 `animation_frames` 帧完成；
+//! - 区域宽度小于 `overlay_below` 时进入覆盖模式：主内容区域不再让出空间，
+//!   展开的侧边栏浮于主内容之上，按 `Esc`、点击侧边栏以外的位置或路由跳转后自动折叠；
+//! - 子组件可以通过 `hooks.use_context::<Sidebar>()` 查询和切换侧边栏状态，例如在导航项中跳转后折叠。
+
+use std::{
+    pin::Pin,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    task::{Context as TaskContext, Poll},
+    time::Duration,
+};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers, MouseButton, MouseEventKind};
+use futures_timer::Delay;
+use ratatui::{
+    layout::{Position, Rect},
+    style::Style,
+};
+use ratatui_kit_macros::{Props, element};
+
+use crate::{
+    AnyElement, Component, Context, EventInterest, KeyChord, State, UpdatePriority, UseEvents,
+    UseState, UserPreferences, render::scheduler,
+};
+
+use super::scroll_view::interpolate;
+
+// 动画相邻两帧之间的间隔
+const FRAME_INTERVAL: Duration = Duration::from_millis(16);
+
+#[derive(Props)]
+/// SidebarLayout 组件属性。
+pub struct SidebarLayoutProps<'a> {
+    /// 侧边栏内容。
+    pub sidebar: Option<AnyElement<'a>>,
+    /// 主内容区域，通常是路由的 Outlet。
+    pub children: Vec<AnyElement<'a>>,
+    /// 展开时侧边栏的宽度。
+    pub width: u16,
+    /// 折叠时侧边栏的宽度，可保留一列图标栏，为 0 时完全隐藏。
+    pub collapsed_width: u16,
+    /// 初始是否折叠。
+    pub default_collapsed: bool,
+    /// 切换展开和折叠的按键，None 时不响应按键。
+    pub toggle_key: Option<KeyChord>,
+    /// 宽度变化分多少帧完成，为 0 时立即切换。
+    pub animation_frames: u16,
+    /// 区域宽度小于该值时进入覆盖模式。
+    pub overlay_below: u16,
+    /// 侧边栏区域的背景样式。
+    pub background: Option<Style>,
+    /// 覆盖模式下是否在侧边栏右下方绘制阴影。
+    pub shadow: bool,
+}
+
+impl Default for SidebarLayoutProps<'_> {
+    fn default() -> Self {
+        Self {
+            sidebar: None,
+            children: Vec::new(),
+            width: 24,
+            collapsed_width: 0,
+            default_collapsed: false,
+            toggle_key: Some(KeyChord::new(KeyCode::Char('b'), KeyModifiers::CONTROL)),
+            animation_frames: 6,
+            overlay_below: 80,
+            background: None,
+            shadow: true,
+        }
+    }
+}
+
+/// 侧边栏状态上下文，由 SidebarLayout 提供给子树。
+#[derive(Clone)]
+pub struct Sidebar {
+    collapsed: State<bool>,
+    overlay: Arc<AtomicBool>,
+}
+
+impl Sidebar {
+    /// 侧边栏是否折叠。
+    pub fn is_collapsed(&self) -> bool {
+        self.collapsed.get()
+    }
+
+    /// 展开或折叠侧边栏。
+    pub fn set_collapsed(&mut self, collapsed: bool) {
+        if self.collapsed.get() != collapsed {
+            self.collapsed.set(collapsed);
+        }
+    }
+
+    /// 切换展开和折叠。
+    pub fn toggle(&mut self) {
+        self.collapsed.set(!self.collapsed.get());
+    }
+
+    /// 是否处于覆盖模式，取最近一次绘制时的结果。
+    pub fn is_overlay(&self) -> bool {
+        self.overlay.load(Ordering::Relaxed)
+    }
+}
+
+/// SidebarLayout 组件实现。
+pub struct SidebarLayout {
+    has_sidebar: bool,
+    target: u16,
+    // 当前绘制的宽度，动画进行中时介于折叠和展开的宽度之间
+    current: Option<u16>,
+    frames_left: u16,
+    delay: Option<Delay>,
+    overlay_below: u16,
+    collapsed_width: u16,
+    overlay: Arc<AtomicBool>,
+    // 最近一次绘制的侧边栏区域，供点击判断使用
+    sidebar_area: Arc<Mutex<Rect>>,
+}
+
+impl SidebarLayout {
+    // 向目标宽度推进一帧
+    fn step(&mut self) -> u16 {
+        let current = *self.current.get_or_insert(self.target);
+        let next = if self.frames_left > 0 && current != self.target {
+            let frames = self.frames_left;
+            self.frames_left -= 1;
+            interpolate(current, self.target, frames)
+        } else {
+            self.target
+        };
+        self.current = Some(next);
+        if next != self.target {
+            self.delay = Some(Delay::new(FRAME_INTERVAL));
+        } else {
+            self.frames_left = 0;
+        }
+        next
+    }
+}
+
+impl Component for SidebarLayout {
+    type Props<'a> = SidebarLayoutProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            has_sidebar: props.sidebar.is_some(),
+            target: 0,
+            current: None,
+            frames_left: 0,
+            delay: None,
+            overlay_below: props.overlay_below,
+            collapsed_width: props.collapsed_width,
+            overlay: Arc::new(AtomicBool::new(false)),
+            sidebar_area: Arc::new(Mutex::new(Rect::default())),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: crate::Hooks,
+        updater: &mut crate::ComponentUpdater,
+    ) {
+        let mut collapsed = hooks.use_state(|| props.default_collapsed);
+
+        hooks.use_events_of(EventInterest::KEY | EventInterest::MOUSE, {
+            let toggle_key = props.toggle_key;
+            let overlay = self.overlay.clone();
+            let sidebar_area = self.sidebar_area.clone();
+            move |event| {
+                let expanded_overlay = overlay.load(Ordering::Relaxed) && !collapsed.get();
+                match event {
+                    Event::Key(key) if key.kind == KeyEventKind::Press => {
+                        if toggle_key == Some(KeyChord::from(key)) {
+                            collapsed.set(!collapsed.get());
+                        } else if expanded_overlay && key.code == KeyCode::Esc {
+                            collapsed.set(true);
+                        }
+                    }
+                    Event::Mouse(mouse)
+                        if expanded_overlay
+                            && mouse.kind == MouseEventKind::Down(MouseButton::Left) =>
+                    {
+                        let position = Position::new(mouse.column, mouse.row);
+                        if !sidebar_area.lock().unwrap().contains(position) {
+                            collapsed.set(true);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        });
+
+        // 覆盖模式下路由跳转后折叠，避免侧边栏遮住新页面
+        #[cfg(feature = "router")]
+        {
+            use crate::UseEffect;
+
+            let path = updater
+                .get_context::<State<crate::components::router::history::RouterHistory>>()
+                .map(|history| history.read().current_context().path);
+            hooks.use_effect(
+                {
+                    let overlay = self.overlay.clone();
+                    move || {
+                        if overlay.load(Ordering::Relaxed) && !collapsed.get() {
+                            collapsed.set(true);
+                        }
+                    }
+                },
+                path,
+            );
+        }
+
+        let target = if collapsed.get() {
+            props.collapsed_width
+        } else {
+            props.width
+        };
+        // 用户偏好减少动画时直接切换到目标宽度
+        let animation_frames = if UserPreferences::current(updater).reduced_motion {
+            0
+        } else {
+            props.animation_frames
+        };
+        if target != self.target {
+            self.target = target;
+            self.frames_left = animation_frames;
+        }
+        if animation_frames == 0 {
+            self.current = Some(target);
+        }
+        self.has_sidebar = props.sidebar.is_some();
+        self.overlay_below = props.overlay_below;
+        self.collapsed_width = props.collapsed_width;
+
+        let sidebar = Context::owned(Sidebar {
+            collapsed,
+            overlay: self.overlay.clone(),
+        });
+        // 侧边栏放在最后绘制，覆盖模式下浮于主内容之上
+        let mut panel: Option<AnyElement> = props.sidebar.take().map(|sidebar| {
+            element!(SidebarPanel(
+                background: props.background,
+                shadow: props.shadow,
+                overlay: self.overlay.clone(),
+            ) {
+                #(sidebar)
+            })
+            .into()
+        });
+        updater.update_children(
+            props.children.iter_mut().chain(panel.as_mut()),
+            Some(sidebar),
+        );
+    }
+
+    fn draw(&mut self, drawer: &mut crate::ComponentDrawer<'_, '_>) {
+        let overlay = drawer.area.width < self.overlay_below;
+        self.overlay.store(overlay, Ordering::Relaxed);
+        self.step();
+    }
+
+    fn calc_children_areas(
+        &self,
+        children: &crate::Components,
+        _layout_style: &crate::layout_style::LayoutStyle,
+        drawer: &mut crate::ComponentDrawer<'_, '_>,
+    ) -> Vec<Rect> {
+        let area = drawer.area;
+        let width = self.current.unwrap_or(self.target).min(area.width);
+        let sidebar = Rect { width, ..area };
+        *self.sidebar_area.lock().unwrap() = sidebar;
+        // 覆盖模式下主内容区域只让出折叠时的宽度
+        let reserved = if self.overlay.load(Ordering::Relaxed) {
+            self.collapsed_width.min(width)
+        } else {
+            width
+        };
+        let main = Rect {
+            x: area.x + reserved,
+            width: area.width - reserved,
+            ..area
+        };
+        let count = children.iter().count();
+        let mut areas = vec![main; count.saturating_sub(self.has_sidebar as usize)];
+        if self.has_sidebar && count > 0 {
+            areas.push(sidebar);
+        }
+        areas
+    }
+
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<()> {
+        if let Some(delay) = self.delay.as_mut() {
+            if Pin::new(delay).poll(cx).is_ready() {
+                self.delay = None;
+                scheduler::mark(UpdatePriority::Animation);
+                return Poll::Ready(());
+            }
+        }
+        Poll::Pending
+    }
+}
+
+#[derive(Default, Props)]
+struct SidebarPanelProps<'a> {
+    children: Vec<AnyElement<'a>>,
+    background: Option<Style>,
+    shadow: bool,
+    overlay: Arc<AtomicBool>,
+}
+
+// 侧边栏面板：清空所在区域后绘制侧边栏内容，覆盖模式下绘制阴影
+struct SidebarPanel {
+    background: Option<Style>,
+    shadow: bool,
+    overlay: Arc<AtomicBool>,
+}
+
+impl Component for SidebarPanel {
+    type Props<'a> = SidebarPanelProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            background: props.background,
+            shadow: props.shadow,
+            overlay: props.overlay.clone(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        _hooks: crate::Hooks,
+        updater: &mut crate::ComponentUpdater,
+    ) {
+        self.background = props.background;
+        self.shadow = props.shadow;
+        self.overlay = props.overlay.clone();
+        updater.update_children(props.children.iter_mut(), None);
+    }
+
+    fn draw(&mut self, drawer: &mut crate::ComponentDrawer<'_, '_>) {
+        let shadow = self.shadow && self.overlay.load(Ordering::Relaxed);
+        super::modal::render_surface(drawer.area, drawer.buffer_mut(), self.background, shadow);
+    }
+}
@@ -0,0 +1,309 @@
+//! Slider 组件：水平滑块，在范围内按步长选择数值，支持键盘和鼠标调整。
+//!
+//! ## 用法示例
+//! ```rust
+//! let mut volume = hooks.use_state(|| 50.0);
+//! element!(Slider(
+//!     label: "音量",
+//!     value: volume.get(),
+//!     min: 0.0,
+//!     max: 100.0,
+//!     step: 5.0,
+//!     is_focus: true,
+//!     on_change: move |value| volume.set(value),
+//! ))
+//! ```
+//! `←/→`（`h/l`）按步长调整，`PageUp/PageDown` 按十倍步长调整，`Home/End` 跳到最小/最大值。
+//! 开启鼠标捕获后支持点击和拖拽。
+//!
+//! `read_only` 时保持聚焦样式但不能调整数值；`disabled` 时不响应任何事件，并以暗淡样式显示。
+
+use std::sync::{Arc, Mutex};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseButton, MouseEventKind};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Rect, Size},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::Widget,
+};
+use ratatui_kit_macros::Props;
+
+use crate::{Component, Handler, Hooks, UseEvents, UseFocusRing};
+
+#[derive(Props)]
+/// Slider 组件属性。
+pub struct SliderProps {
+    /// 当前值。
+    pub value: f64,
+    /// 最小值。
+    pub min: f64,
+    /// 最大值。
+    pub max: f64,
+    /// 调整的步长，鼠标选择的值也会对齐到步长。
+    pub step: f64,
+    /// 数值显示的小数位数。
+    pub precision: usize,
+    /// 滑块前的标签。
+    pub label: String,
+    /// 是否在滑块后显示数值。
+    pub show_value: bool,
+    /// 是否聚焦，聚焦时响应按键。
+    pub is_focus: bool,
+    /// 是否只读，只读时不能调整数值。
+    pub read_only: bool,
+    /// 是否禁用，禁用时忽略 `is_focus`，不响应任何事件。
+    pub disabled: bool,
+    /// 值变更回调。
+    pub on_change: Handler<'static, f64>,
+    /// 标签和数值样式。
+    pub style: Style,
+    /// 已填充部分样式。
+    pub filled_style: Style,
+    /// 未填充部分样式。
+    pub unfilled_style: Style,
+    /// 滑块手柄样式。
+    pub thumb_style: Style,
+    /// 聚焦时滑块手柄样式。
+    pub focus_thumb_style: Style,
+    /// 聚焦时叠加在整行上的样式。
+    pub focus_style: Style,
+}
+
+impl Default for SliderProps {
+    fn default() -> Self {
+        Self {
+            value: 0.0,
+            min: 0.0,
+            max: 100.0,
+            step: 1.0,
+            precision: 0,
+            label: String::new(),
+            show_value: true,
+            is_focus: false,
+            read_only: false,
+            disabled: false,
+            on_change: Handler::default(),
+            style: Style::default(),
+            filled_style: Style::default().cyan(),
+            unfilled_style: Style::default().dark_gray(),
+            thumb_style: Style::default().cyan(),
+            focus_thumb_style: Style::default().yellow().bold(),
+            focus_style: Style::default(),
+        }
+    }
+}
+
+// 当前值和轨道区域，供事件处理时换算鼠标位置
+#[derive(Default)]
+struct SliderLayout {
+    value: f64,
+    track: Rect,
+}
+
+/// Slider 组件实现。
+pub struct Slider {
+    layout: Arc<Mutex<SliderLayout>>,
+    value: f64,
+    min: f64,
+    max: f64,
+    precision: usize,
+    label: String,
+    show_value: bool,
+    is_focus: bool,
+    disabled: bool,
+    style: Style,
+    filled_style: Style,
+    unfilled_style: Style,
+    thumb_style: Style,
+    focus_thumb_style: Style,
+    focus_style: Style,
+}
+
+impl Slider {
+    fn value_text(&self) -> String {
+        let precision = self.precision;
+        let width = format!("{:.precision$}", self.min)
+            .len()
+            .max(format!("{:.precision$}", self.max).len());
+        format!(" {:>width$.precision$}", self.value)
+    }
+
+    fn ratio(&self) -> f64 {
+        if self.max > self.min {
+            ((self.value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+// 对齐到步长并限制在范围内
+fn snap(value: f64, min: f64, max: f64, step: f64) -> f64 {
+    let value = if step > 0.0 {
+        min + ((value - min) / step).round() * step
+    } else {
+        value
+    };
+    value.clamp(min, max)
+}
+
+impl Component for Slider {
+    type Props<'a> = SliderProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            layout: Arc::new(Mutex::new(SliderLayout::default())),
+            value: props.value,
+            min: props.min,
+            max: props.max,
+            precision: props.precision,
+            label: props.label.clone(),
+            show_value: props.show_value,
+            is_focus: props.is_focus && !props.disabled,
+            disabled: props.disabled,
+            style: props.style,
+            filled_style: props.filled_style,
+            unfilled_style: props.unfilled_style,
+            thumb_style: props.thumb_style,
+            focus_thumb_style: props.focus_thumb_style,
+            focus_style: props.focus_style,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        _updater: &mut crate::ComponentUpdater,
+    ) {
+        self.layout.lock().unwrap().value = props.value;
+        hooks.use_focus_ring(props.is_focus && !props.disabled);
+
+        hooks.use_local_events({
+            let layout = self.layout.clone();
+            let (min, max, step) = (props.min, props.max, props.step);
+            let is_focus = props.is_focus;
+            let locked = props.read_only || props.disabled;
+            let mut on_change = props.on_change.take();
+            move |event| {
+                if locked {
+                    return;
+                }
+                let mut layout = layout.lock().unwrap();
+                let value = match event {
+                    Event::Key(key) if is_focus && key.kind != KeyEventKind::Release => {
+                        match key.code {
+                            KeyCode::Left | KeyCode::Char('h') => layout.value - step,
+                            KeyCode::Right | KeyCode::Char('l') => layout.value + step,
+                            KeyCode::PageDown => layout.value - step * 10.0,
+                            KeyCode::PageUp => layout.value + step * 10.0,
+                            KeyCode::Home => min,
+                            KeyCode::End => max,
+                            _ => return,
+                        }
+                    }
+                    Event::Mouse(mouse) => match mouse.kind {
+                        MouseEventKind::Down(MouseButton::Left)
+                        | MouseEventKind::Drag(MouseButton::Left) => {
+                            let track = layout.track;
+                            if track.width == 0 {
+                                return;
+                            }
+                            let offset = mouse.column.saturating_sub(track.x).min(track.width - 1);
+                            let ratio = offset as f64 / (track.width - 1).max(1) as f64;
+                            min + ratio * (max - min)
+                        }
+                        _ => return,
+                    },
+                    _ => return,
+                };
+                let value = snap(value, min, max, step);
+                if value != layout.value {
+                    layout.value = value;
+                    drop(layout);
+                    on_change(value);
+                }
+            }
+        });
+
+        self.value = props.value;
+        self.min = props.min;
+        self.max = props.max;
+        self.precision = props.precision;
+        self.label = props.label.clone();
+        self.show_value = props.show_value;
+        self.is_focus = props.is_focus && !props.disabled;
+        self.disabled = props.disabled;
+        self.style = props.style;
+        self.filled_style = props.filled_style;
+        self.unfilled_style = props.unfilled_style;
+        self.thumb_style = props.thumb_style;
+        self.focus_thumb_style = props.focus_thumb_style;
+        self.focus_style = props.focus_style;
+    }
+
+    fn measure(&self, max: Size) -> Option<Size> {
+        Some(Size::new(max.width, 1))
+    }
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+        let area = Rect { height: 1, ..area };
+        let label = if self.label.is_empty() {
+            String::new()
+        } else {
+            format!("{} ", self.label)
+        };
+        let value = if self.show_value {
+            self.value_text()
+        } else {
+            String::new()
+        };
+        let label_width = (crate::text::width(&label) as u16).min(area.width);
+        let value_width =
+            (crate::text::width(&value) as u16).min(area.width.saturating_sub(label_width));
+        let track = Rect {
+            x: area.x + label_width,
+            width: area.width - label_width - value_width,
+            ..area
+        };
+        self.layout.lock().unwrap().track = track;
+        if self.is_focus {
+            buf.set_style(area, self.focus_style);
+        }
+
+        Line::styled(label, self.style).render(area, buf);
+        if track.width > 0 {
+            let thumb = (self.ratio() * (track.width - 1) as f64).round() as usize;
+            let thumb_style = if self.is_focus {
+                self.focus_thumb_style
+            } else {
+                self.thumb_style
+            };
+            Line::from(vec![
+                Span::styled("━".repeat(thumb), self.filled_style),
+                Span::styled("●", thumb_style),
+                Span::styled(
+                    "─".repeat(track.width as usize - thumb - 1),
+                    self.unfilled_style,
+                ),
+            ])
+            .render(track, buf);
+        }
+        Line::styled(value, self.style).render(
+            Rect {
+                x: track.right(),
+                width: value_width,
+                ..area
+            },
+            buf,
+        );
+        if self.disabled {
+            buf.set_style(area, Style::default().dim());
+        }
+    }
+}
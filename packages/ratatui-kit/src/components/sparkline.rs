@@ -0,0 +1,128 @@
+//! Sparkline 组件：迷你趋势图，自行维护采样历史，按最小/最大值自动缩放。
+//!
+//! ## 用法示例
+//! ```rust
+//! // 方式一：通过句柄推送采样
+//! let samples = hooks.use_samples(120);
+//! element!(Sparkline(samples: Some(samples.clone())))
+//!
+//! // 方式二：每次 value 变化时自动记录
+//! element!(Sparkline(value: Some(cpu_usage), capacity: 60))
+//! ```
+//! 只显示最近的、与组件宽度相同数量的采样。
+
+use std::task::{Context, Poll};
+
+use ratatui::{
+    style::Style,
+    widgets::{RenderDirection, Sparkline as TuiSparkline, Widget},
+};
+use ratatui_kit_macros::Props;
+
+use crate::{Component, SampleSource, Samples};
+
+// 缩放后的精度，数值越大柱高越细腻
+const SCALE: f64 = 1000.0;
+
+#[derive(Props)]
+/// Sparkline 组件属性。
+pub struct SparklineProps {
+    /// 外部采样句柄，设置后忽略 `value`。
+    pub samples: Option<Samples>,
+    /// 响应式采样值，变化时追加到内部缓冲区。
+    pub value: Option<f64>,
+    /// 内部缓冲区容量，仅在组件创建时生效。
+    pub capacity: usize,
+    /// 固定的最小值，缺省时按历史数据自动计算。
+    pub min: Option<f64>,
+    /// 固定的最大值，缺省时按历史数据自动计算。
+    pub max: Option<f64>,
+    /// 样式。
+    pub style: Style,
+    /// 绘制方向，默认从左到右（最新数据在右侧），从右到左时最新数据靠左。
+    pub direction: RenderDirection,
+}
+
+impl Default for SparklineProps {
+    fn default() -> Self {
+        Self {
+            samples: None,
+            value: None,
+            capacity: 120,
+            min: None,
+            max: None,
+            style: Style::default(),
+            direction: RenderDirection::LeftToRight,
+        }
+    }
+}
+
+/// Sparkline 组件实现。
+pub struct Sparkline {
+    source: SampleSource,
+    min: Option<f64>,
+    max: Option<f64>,
+    style: Style,
+    direction: RenderDirection,
+}
+
+impl Component for Sparkline {
+    type Props<'a> = SparklineProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            source: SampleSource::new(props.capacity),
+            min: props.min,
+            max: props.max,
+            style: props.style,
+            direction: props.direction,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        _hooks: crate::Hooks,
+        _updater: &mut crate::ComponentUpdater,
+    ) {
+        self.source.update(props.samples.clone(), props.value);
+        self.min = props.min;
+        self.max = props.max;
+        self.style = props.style;
+        self.direction = props.direction;
+    }
+
+    fn draw(&mut self, drawer: &mut crate::ComponentDrawer<'_, '_>) {
+        let values = self.source.samples().values();
+        let visible = &values[values.len().saturating_sub(drawer.area.width as usize)..];
+        let Some((auto_min, auto_max)) = Samples::bounds(visible) else {
+            return;
+        };
+        let min = self.min.unwrap_or(auto_min);
+        let max = self.max.unwrap_or(auto_max);
+        let range = max - min;
+
+        // 映射到 [1, SCALE]，让最小值也保留一格可见的柱
+        let data = visible
+            .iter()
+            .map(|v| {
+                if range > 0.0 {
+                    (((v - min) / range).clamp(0.0, 1.0) * (SCALE - 1.0) + 1.0) as u64
+                } else {
+                    (SCALE / 2.0) as u64
+                }
+            })
+            .collect::<Vec<_>>();
+
+        TuiSparkline::default()
+            .data(&data)
+            .max(SCALE as u64)
+            .style(self.style)
+            .direction(self.direction)
+            .render(drawer.area, drawer.buffer_mut());
+    }
+
+    fn poll_change(self: std::pin::Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        self.get_mut().source.poll_change(cx)
+    }
+}
@@ -0,0 +1,109 @@
+//! Splash 组件：启动画面，第一帧立即显示启动内容，同时执行异步初始化，完成后切换为真正的界面。
+//!
+//! 在 `main` 中先 `await` 初始化再调用 `fullscreen` 时，初始化期间终端一片空白，看起来像是卡住了；
+//! 把初始化交给 Splash 即可在第一帧显示启动画面。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(Splash(
+//!     init: SplashTask::new(async { Arc::new(Config::load().await) }),
+//!     splash: element!(Text(text: "正在加载配置...", alignment: Alignment::Center)),
+//!     min_duration: Duration::from_millis(300),
+//! ) {
+//!     MainApp()
+//! })
+//! ```
+//! - 初始化结果作为上下文提供给子元素，子组件通过 `hooks.use_context::<Arc<Config>>()` 获取；
+//! - 结果每次渲染时克隆一份放入上下文，较大的数据应使用 `Arc` 包裹；
+//! - `min_duration` 为启动画面的最短显示时间，避免初始化很快时一闪而过；
+//! - 子元素在初始化完成后才挂载，其中的 hook 不会在启动画面期间执行。
+
+use std::{any::Any, sync::Mutex, time::Duration};
+
+use futures::{FutureExt, future::BoxFuture};
+use futures_timer::Delay;
+use ratatui_kit_macros::{Props, component, element};
+
+use super::ContextProvider;
+use crate::{AnyElement, Context, Hooks, UseResource};
+
+type AnyValue = Box<dyn Any + Send + Sync>;
+type ProvideFn = Box<dyn Fn(&AnyValue) -> Option<Context<'static>> + Send + Sync>;
+
+/// Splash 的异步初始化任务。
+pub struct SplashTask {
+    // BoxFuture 不是 Sync，用 Mutex 包裹以满足 Props 的要求
+    future: Mutex<Option<BoxFuture<'static, AnyValue>>>,
+    provide: ProvideFn,
+}
+
+impl SplashTask {
+    /// 创建初始化任务，任务的结果作为上下文提供给子元素。
+    pub fn new<F, T>(future: F) -> Self
+    where
+        F: Future<Output = T> + Send + 'static,
+        T: Clone + Send + Sync + 'static,
+    {
+        Self {
+            future: Mutex::new(Some(
+                future.map(|value| Box::new(value) as AnyValue).boxed(),
+            )),
+            provide: Box::new(|value| {
+                value
+                    .downcast_ref::<T>()
+                    .map(|value| Context::owned(value.clone()))
+            }),
+        }
+    }
+}
+
+#[derive(Default, Props)]
+/// Splash 组件属性。
+pub struct SplashProps<'a> {
+    /// 初始化任务，None 时启动画面只显示 `min_duration`。
+    pub init: Option<SplashTask>,
+    /// 初始化完成前显示的内容。
+    pub splash: Option<AnyElement<'a>>,
+    /// 启动画面的最短显示时间。
+    pub min_duration: Duration,
+    /// 初始化完成后显示的内容。
+    pub children: Vec<AnyElement<'a>>,
+}
+
+#[component]
+pub fn Splash<'a>(props: &mut SplashProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'a>> {
+    // 任务只在首次挂载时执行，之后传入的新 future 直接丢弃
+    let future = props
+        .init
+        .as_mut()
+        .and_then(|task| task.future.get_mut().unwrap().take());
+    let min_duration = props.min_duration;
+    let resource = hooks.use_resource(
+        async move {
+            let init = async move {
+                match future {
+                    Some(future) => Some(future.await),
+                    None => None,
+                }
+            };
+            let (value, _) = futures::join!(init, Delay::new(min_duration));
+            value
+        },
+        (),
+    );
+
+    let (context, content) = match resource.read().as_ref() {
+        Some(value) => (
+            value
+                .as_ref()
+                .zip(props.init.as_ref())
+                .and_then(|(value, task)| (task.provide)(value)),
+            std::mem::take(&mut props.children),
+        ),
+        None => (None, props.splash.take().into_iter().collect()),
+    };
+
+    element!(ContextProvider(value: context) {
+        #(content)
+    })
+}
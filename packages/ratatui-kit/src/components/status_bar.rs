@@ -0,0 +1,90 @@
+//! StatusBar 组件：单行状态栏，左侧水平排列子元素，右侧显示提示文本。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(StatusBar(right: Some(Line::from("Ctrl+B 侧边栏  F10 菜单"))) {
+//!     ModeIndicator()
+//!     Text(text: file_name, wrap: false)
+//! })
+//! ```
+//! 高度固定为一行，整行填充 `style`，子元素之间间隔一列，右侧文本空间不足时截断。
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Direction, Rect, Size},
+    style::{Style, Stylize},
+    text::Line,
+    widgets::Widget,
+};
+use ratatui_kit_macros::Props;
+
+use crate::{AnyElement, Component, layout_style::LayoutStyle};
+
+#[derive(Props)]
+/// StatusBar 组件属性。
+pub struct StatusBarProps<'a> {
+    /// 左侧的状态项，按水平方向排列。
+    pub children: Vec<AnyElement<'a>>,
+    /// 右对齐显示的文本，常用于按键提示。
+    pub right: Option<Line<'static>>,
+    /// 整行样式。
+    pub style: Style,
+}
+
+impl Default for StatusBarProps<'_> {
+    fn default() -> Self {
+        Self {
+            children: Vec::new(),
+            right: None,
+            style: Style::default().reversed(),
+        }
+    }
+}
+
+/// StatusBar 组件实现。
+pub struct StatusBar {
+    right: Option<Line<'static>>,
+    style: Style,
+}
+
+impl Component for StatusBar {
+    type Props<'a> = StatusBarProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            right: props.right.clone(),
+            style: props.style,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        _hooks: crate::Hooks,
+        updater: &mut crate::ComponentUpdater,
+    ) {
+        self.right = props.right.clone();
+        self.style = props.style;
+        updater.set_layout_style(LayoutStyle {
+            flex_direction: Direction::Horizontal,
+            gap: 1,
+            height: Constraint::Length(1),
+            ..Default::default()
+        });
+        updater.update_children(&mut props.children, None);
+    }
+
+    fn measure(&self, max: Size) -> Option<Size> {
+        Some(Size::new(max.width, 1))
+    }
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+        buf.set_style(area, self.style);
+        if let Some(right) = &self.right {
+            right.clone().right_aligned().render(area, buf);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! StrictMode 组件：在 debug 构建中把子树中每个组件的更新函数执行两次，类似 React.StrictMode。
+//!
+//! 更新函数应当只根据属性、状态和上下文计算界面，重复执行不应产生不同的结果。严格模式下
+//! 每次更新先空跑一次更新函数（不更新子组件），再正常执行一次，从而暴露以下问题：
+//! - 在更新函数中直接执行的副作用（打印日志、写文件、发送请求、派生线程）会执行两次，
+//!   应移到事件处理器、`use_effect` 或 `use_future` 中；
+//! - 在更新函数中无条件修改状态：每次更新都会修改两次，并由渲染循环检测报告涉及的组件；
+//! - 依据状态以外的可变数据（全局变量、`Cell`、组件结构体字段）决定是否调用 hook：
+//!   两次执行调用的 hook 数量或顺序不同，触发 hook 顺序错误的 panic；
+//! - 依据上述可变数据累加的计算（如每次更新加一的计数器）：两次执行后的结果与预期不同，
+//!   画面上能直接看到。
+//!
+//! hook 的 `pre_component_update`/`post_component_update` 只执行一次，release 构建中
+//! StrictMode 只是一个透明容器，没有额外开销。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(StrictMode {
+//!     App
+//! })
+//! ```
+
+use ratatui_kit_macros::Props;
+
+use crate::{AnyElement, Component, ComponentUpdater, Context, Hooks};
+
+#[derive(Default, Props)]
+/// StrictMode 组件属性。
+pub struct StrictModeProps<'a> {
+    /// 子元素列表。
+    pub children: Vec<AnyElement<'a>>,
+}
+
+// 标记子树处于严格模式
+pub(crate) struct StrictModeContext;
+
+/// StrictMode 组件实现。
+pub struct StrictMode;
+
+impl Component for StrictMode {
+    type Props<'a> = StrictModeProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        _hooks: Hooks,
+        updater: &mut ComponentUpdater,
+    ) {
+        updater.set_transparent_layout(true);
+        updater.update_children(
+            props.children.iter_mut(),
+            Some(Context::owned(StrictModeContext)),
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! Suspense 组件：执行一次性异步任务，完成前显示占位内容，完成后用结果渲染子元素。
+//!
+//! ## 用法示例
+//! 通常通过 `element!` 的 `#(await ...)` 语法使用：
+//! ```rust
+//! element!(View {
+//!     #(await tokio::fs::read_to_string("Cargo.toml") => |result| element!(View {
+//!         $Paragraph::new(result.as_deref().unwrap_or("读取失败").to_string())
+//!     }))
+//! })
+//! ```
+//! 也可以直接声明并指定占位内容：
+//! ```rust
+//! element!(Suspense(
+//!     task: SuspenseTask::new(fetch_user(id), |user| element!(UserCard(user: user.clone()))),
+//!     fallback: element!(View { $Line::from("加载中...") }),
+//!     key: id,
+//! ))
+//! ```
+//! 任务只在首次挂载时执行，需要重新执行时更换 `key`。
+
+use std::{any::Any, sync::Mutex};
+
+use futures::{FutureExt, future::BoxFuture};
+use ratatui_kit_macros::{Props, component, element};
+
+use super::Fragment;
+use crate::{AnyElement, Hooks, UseResource};
+
+type AnyValue = Box<dyn Any + Send + Sync>;
+type RenderFn = Box<dyn Fn(&AnyValue) -> Option<AnyElement<'static>> + Send + Sync>;
+
+/// Suspense 的异步任务及结果渲染函数。
+pub struct SuspenseTask {
+    // BoxFuture 不是 Sync，用 Mutex 包裹以满足 Props 的要求
+    future: Mutex<Option<BoxFuture<'static, AnyValue>>>,
+    render: RenderFn,
+}
+
+impl SuspenseTask {
+    /// 创建任务，`render` 在每次渲染时以任务结果的引用调用。
+    pub fn new<F, T, R, E>(future: F, render: R) -> Self
+    where
+        F: Future<Output = T> + Send + 'static,
+        T: Send + Sync + 'static,
+        R: Fn(&T) -> E + Send + Sync + 'static,
+        E: Into<AnyElement<'static>>,
+    {
+        Self {
+            future: Mutex::new(Some(
+                future.map(|value| Box::new(value) as AnyValue).boxed(),
+            )),
+            render: Box::new(move |value| {
+                value.downcast_ref::<T>().map(|value| render(value).into())
+            }),
+        }
+    }
+}
+
+#[derive(Default, Props)]
+/// Suspense 组件属性。
+pub struct SuspenseProps {
+    /// 异步任务。
+    pub task: Option<SuspenseTask>,
+    /// 任务完成前显示的内容。
+    pub fallback: Option<AnyElement<'static>>,
+}
+
+#[component]
+pub fn Suspense(props: &mut SuspenseProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    // 任务只在首次挂载时执行，之后传入的新 future 直接丢弃
+    let future = props
+        .task
+        .as_mut()
+        .and_then(|task| task.future.get_mut().unwrap().take());
+    let resource = hooks.use_resource(
+        async move {
+            match future {
+                Some(future) => Some(future.await),
+                None => None,
+            }
+        },
+        (),
+    );
+
+    let content = match resource.read().as_ref() {
+        Some(value) => value
+            .as_ref()
+            .zip(props.task.as_ref())
+            .and_then(|(value, task)| (task.render)(value)),
+        None => props.fallback.take(),
+    };
+
+    element!(Fragment {
+        #(content)
+    })
+}
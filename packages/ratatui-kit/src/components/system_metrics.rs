@@ -0,0 +1,124 @@
+//! 系统指标组件：CpuMeter、MemMeter 和 NetSparkline，数据来自 `use_system_monitor` 的后台采样。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(View(flex_direction: Direction::Vertical) {
+//!     CpuMeter()
+//!     MemMeter(gauge_style: Style::default().green())
+//!     NetSparkline(direction: NetDirection::Received)
+//! })
+//! ```
+//! 多个组件共用同一个采样线程，采样间隔为 [`SYSTEM_SAMPLE_INTERVAL`](crate::SYSTEM_SAMPLE_INTERVAL)。
+
+use ratatui::style::Style;
+use ratatui_kit_macros::{Props, component, element};
+
+use super::{Meter, Sparkline};
+use crate::{AnyElement, Hooks, UseSystemMonitor};
+
+#[derive(Props)]
+/// CpuMeter 组件属性。
+pub struct CpuMeterProps {
+    /// 显示在数值前的标签。
+    pub label: String,
+    /// 整体样式。
+    pub style: Style,
+    /// 填充部分样式。
+    pub gauge_style: Style,
+}
+
+impl Default for CpuMeterProps {
+    fn default() -> Self {
+        Self {
+            label: "CPU".to_string(),
+            style: Style::default(),
+            gauge_style: Style::default(),
+        }
+    }
+}
+
+/// 全局 CPU 使用率仪表（0 ~ 100）。
+#[component]
+pub fn CpuMeter(props: &CpuMeterProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let monitor = hooks.use_system_monitor();
+    element!(Meter(
+        samples: Some(monitor.cpu()),
+        min: Some(0.0),
+        max: Some(100.0),
+        label: Some(props.label.clone()),
+        style: props.style,
+        gauge_style: props.gauge_style,
+    ))
+}
+
+#[derive(Props)]
+/// MemMeter 组件属性。
+pub struct MemMeterProps {
+    /// 显示在数值前的标签。
+    pub label: String,
+    /// 数值显示的小数位数。
+    pub precision: usize,
+    /// 整体样式。
+    pub style: Style,
+    /// 填充部分样式。
+    pub gauge_style: Style,
+}
+
+impl Default for MemMeterProps {
+    fn default() -> Self {
+        Self {
+            label: "MEM".to_string(),
+            precision: 1,
+            style: Style::default(),
+            gauge_style: Style::default(),
+        }
+    }
+}
+
+/// 已用内存仪表，单位 GiB，范围为 0 到内存总量。
+#[component]
+pub fn MemMeter(props: &MemMeterProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let monitor = hooks.use_system_monitor();
+    let total = monitor.memory_total();
+    element!(Meter(
+        samples: Some(monitor.memory()),
+        min: Some(0.0),
+        max: (total > 0.0).then_some(total),
+        label: Some(props.label.clone()),
+        precision: props.precision,
+        style: props.style,
+        gauge_style: props.gauge_style,
+    ))
+}
+
+/// 网络流量方向。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NetDirection {
+    #[default]
+    Received,
+    Transmitted,
+}
+
+#[derive(Default, Props)]
+/// NetSparkline 组件属性。
+pub struct NetSparklineProps {
+    /// 显示接收还是发送速率。
+    pub direction: NetDirection,
+    /// 趋势图样式。
+    pub style: Style,
+}
+
+/// 所有网卡合计的网络速率趋势图（字节/秒），按历史最大值缩放。
+#[component]
+pub fn NetSparkline(props: &NetSparklineProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let monitor = hooks.use_system_monitor();
+    let samples = match props.direction {
+        NetDirection::Received => monitor.received(),
+        NetDirection::Transmitted => monitor.transmitted(),
+    };
+    element!(Sparkline(
+        samples: Some(samples),
+        min: Some(0.0),
+        style: props.style,
+    ))
+}
@@ -0,0 +1,406 @@
+//! TerminalPane 组件：在伪终端（PTY）中运行命令并显示其屏幕，可以嵌入 shell、编辑器等交互程序。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(View(flex_direction: Direction::Horizontal) {
+//!     Border(top_title: Some(Line::from("shell"))) {
+//!         TerminalPane(command: "bash", is_focus: true)
+//!     }
+//!     Border(top_title: Some(Line::from("top"))) {
+//!         TerminalPane(command: "top")
+//!     }
+//! })
+//! ```
+//! 命令在第一次绘制时按组件区域大小启动，区域变化时同步调整伪终端大小；
+//! 聚焦时按键和粘贴内容转发给子进程，组件卸载时结束子进程。
+
+use std::{
+    io::{Read, Write},
+    path::PathBuf,
+    pin::Pin,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    task::{Context, Poll, Waker},
+    thread,
+};
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use portable_pty::{Child, CommandBuilder, MasterPty, PtySize, native_pty_system};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    text::Line,
+    widgets::Widget,
+};
+use ratatui_kit_macros::Props;
+
+use crate::{Component, UseEvents};
+
+#[derive(Props)]
+/// TerminalPane 组件属性。
+pub struct TerminalPaneProps {
+    /// 要运行的程序。
+    pub command: String,
+    /// 程序参数。
+    pub args: Vec<String>,
+    /// 工作目录，缺省时使用当前目录。
+    pub cwd: Option<PathBuf>,
+    /// 额外的环境变量。
+    pub env: Vec<(String, String)>,
+    /// 是否聚焦，聚焦时转发按键并显示光标。
+    pub is_focus: bool,
+    /// 回滚缓冲区保留的行数。
+    pub scrollback: usize,
+    /// 整体样式。
+    pub style: Style,
+}
+
+impl Default for TerminalPaneProps {
+    fn default() -> Self {
+        Self {
+            command: String::new(),
+            args: Vec::new(),
+            cwd: None,
+            env: Vec::new(),
+            is_focus: false,
+            scrollback: 1000,
+            style: Style::default(),
+        }
+    }
+}
+
+// 读取线程与组件共享的状态
+struct Shared {
+    parser: Mutex<vt100::Parser>,
+    dirty: AtomicBool,
+    exited: AtomicBool,
+    waker: Mutex<Option<Waker>>,
+}
+
+impl Shared {
+    fn wake(&self) {
+        self.dirty.store(true, Ordering::Release);
+        if let Some(waker) = self.waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+}
+
+// 正在运行的子进程
+struct Session {
+    master: Mutex<Box<dyn MasterPty + Send>>,
+    child: Mutex<Box<dyn Child + Send + Sync>>,
+    size: (u16, u16),
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        let mut child = self.child.lock().unwrap();
+        if let Ok(None) = child.try_wait() {
+            let _ = child.kill();
+        }
+    }
+}
+
+/// TerminalPane 组件实现。
+pub struct TerminalPane {
+    command: String,
+    args: Vec<String>,
+    cwd: Option<PathBuf>,
+    env: Vec<(String, String)>,
+    is_focus: bool,
+    style: Style,
+    shared: Arc<Shared>,
+    session: Option<Session>,
+    error: Option<String>,
+    writer: Arc<Mutex<Option<Box<dyn Write + Send>>>>,
+}
+
+impl TerminalPane {
+    fn spawn(&mut self, rows: u16, cols: u16) -> Result<(), String> {
+        let pair = native_pty_system()
+            .openpty(pty_size(rows, cols))
+            .map_err(|err| err.to_string())?;
+        let mut command = CommandBuilder::new(&self.command);
+        command.args(&self.args);
+        if let Some(cwd) = &self.cwd {
+            command.cwd(cwd);
+        }
+        for (key, value) in &self.env {
+            command.env(key, value);
+        }
+        let child = pair
+            .slave
+            .spawn_command(command)
+            .map_err(|err| err.to_string())?;
+        // 关闭父进程中的从端，子进程退出后读取才会结束
+        drop(pair.slave);
+
+        let mut reader = pair
+            .master
+            .try_clone_reader()
+            .map_err(|err| err.to_string())?;
+        let writer = pair.master.take_writer().map_err(|err| err.to_string())?;
+        *self.writer.lock().unwrap() = Some(writer);
+        self.shared.parser.lock().unwrap().set_size(rows, cols);
+
+        let shared = self.shared.clone();
+        thread::spawn(move || {
+            let mut buf = [0u8; 8192];
+            loop {
+                match reader.read(&mut buf) {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => {
+                        shared.parser.lock().unwrap().process(&buf[..n]);
+                        shared.wake();
+                    }
+                }
+            }
+            shared.exited.store(true, Ordering::Release);
+            shared.wake();
+        });
+
+        self.session = Some(Session {
+            master: Mutex::new(pair.master),
+            child: Mutex::new(child),
+            size: (rows, cols),
+        });
+        Ok(())
+    }
+
+    fn resize(&mut self, rows: u16, cols: u16) {
+        let Some(session) = &mut self.session else {
+            return;
+        };
+        if session.size == (rows, cols) {
+            return;
+        }
+        session.size = (rows, cols);
+        let _ = session.master.lock().unwrap().resize(pty_size(rows, cols));
+        self.shared.parser.lock().unwrap().set_size(rows, cols);
+    }
+}
+
+impl Component for TerminalPane {
+    type Props<'a> = TerminalPaneProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            command: props.command.clone(),
+            args: props.args.clone(),
+            cwd: props.cwd.clone(),
+            env: props.env.clone(),
+            is_focus: props.is_focus,
+            style: props.style,
+            shared: Arc::new(Shared {
+                parser: Mutex::new(vt100::Parser::new(24, 80, props.scrollback)),
+                dirty: AtomicBool::new(false),
+                exited: AtomicBool::new(false),
+                waker: Mutex::new(None),
+            }),
+            session: None,
+            error: None,
+            writer: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: crate::Hooks,
+        _updater: &mut crate::ComponentUpdater,
+    ) {
+        // 命令只在启动时读取，之后修改不会重启子进程
+        self.is_focus = props.is_focus;
+        self.style = props.style;
+
+        hooks.use_local_events({
+            let is_focus = props.is_focus;
+            let writer = self.writer.clone();
+            let shared = self.shared.clone();
+            move |event| {
+                if !is_focus {
+                    return;
+                }
+                let bytes = match event {
+                    Event::Key(key) if key.kind != KeyEventKind::Release => {
+                        let application_cursor =
+                            shared.parser.lock().unwrap().screen().application_cursor();
+                        key_bytes(key, application_cursor)
+                    }
+                    Event::Paste(text) => {
+                        if shared.parser.lock().unwrap().screen().bracketed_paste() {
+                            format!("\x1b[200~{text}\x1b[201~").into_bytes()
+                        } else {
+                            text.into_bytes()
+                        }
+                    }
+                    _ => return,
+                };
+                if let Some(writer) = writer.lock().unwrap().as_mut() {
+                    let _ = writer.write_all(&bytes);
+                    let _ = writer.flush();
+                }
+            }
+        });
+    }
+
+    fn draw(&mut self, drawer: &mut crate::ComponentDrawer<'_, '_>) {
+        let area = drawer.area;
+        if area.is_empty() {
+            return;
+        }
+        if self.session.is_none() && self.error.is_none() {
+            if let Err(err) = self.spawn(area.height, area.width) {
+                self.error = Some(format!("无法启动 {}：{err}", self.command));
+            }
+        }
+        if let Some(error) = &self.error {
+            Line::from(error.as_str())
+                .red()
+                .render(area, drawer.buffer_mut());
+            return;
+        }
+        self.resize(area.height, area.width);
+
+        let buf = drawer.buffer_mut();
+        buf.set_style(area, self.style);
+        let parser = self.shared.parser.lock().unwrap();
+        let screen = parser.screen();
+        render_screen(screen, area, buf);
+
+        let exited = self.shared.exited.load(Ordering::Acquire);
+        if exited {
+            let message = Line::from(" 进程已退出 ").reversed();
+            let y = area.bottom() - 1;
+            message.render(Rect::new(area.x, y, area.width, 1), buf);
+        } else if self.is_focus && !screen.hide_cursor() {
+            let (row, col) = screen.cursor_position();
+            let position = Position::new(area.x + col, area.y + row);
+            if area.contains(position) {
+                drawer.frame.set_cursor_position(position);
+            }
+        }
+    }
+
+    fn poll_change(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        *self.shared.waker.lock().unwrap() = Some(cx.waker().clone());
+        if self.shared.dirty.swap(false, Ordering::AcqRel) {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+fn pty_size(rows: u16, cols: u16) -> PtySize {
+    PtySize {
+        rows,
+        cols,
+        pixel_width: 0,
+        pixel_height: 0,
+    }
+}
+
+fn render_screen(screen: &vt100::Screen, area: Rect, buf: &mut Buffer) {
+    let (rows, cols) = screen.size();
+    for row in 0..rows.min(area.height) {
+        for col in 0..cols.min(area.width) {
+            let Some(cell) = screen.cell(row, col) else {
+                continue;
+            };
+            let target = &mut buf[(area.x + col, area.y + row)];
+            // 宽字符的后半部分由前一个单元格绘制
+            if cell.is_wide_continuation() {
+                target.set_skip(true);
+                continue;
+            }
+            let contents = cell.contents();
+            target.set_symbol(if contents.is_empty() {
+                " "
+            } else {
+                &contents[..]
+            });
+            let mut style = Style::default();
+            if let Some(fg) = convert_color(cell.fgcolor()) {
+                style = style.fg(fg);
+            }
+            if let Some(bg) = convert_color(cell.bgcolor()) {
+                style = style.bg(bg);
+            }
+            for (enabled, modifier) in [
+                (cell.bold(), Modifier::BOLD),
+                (cell.italic(), Modifier::ITALIC),
+                (cell.underline(), Modifier::UNDERLINED),
+                (cell.inverse(), Modifier::REVERSED),
+            ] {
+                if enabled {
+                    style = style.add_modifier(modifier);
+                }
+            }
+            target.set_style(style);
+        }
+    }
+}
+
+// 默认颜色返回 None，沿用组件样式
+fn convert_color(color: vt100::Color) -> Option<Color> {
+    match color {
+        vt100::Color::Default => None,
+        vt100::Color::Idx(index) => Some(Color::Indexed(index)),
+        vt100::Color::Rgb(r, g, b) => Some(Color::Rgb(r, g, b)),
+    }
+}
+
+// 按键对应的输入字节
+fn key_bytes(key: KeyEvent, application_cursor: bool) -> Vec<u8> {
+    let cursor = |code: u8| {
+        if application_cursor {
+            vec![0x1b, b'O', code]
+        } else {
+            vec![0x1b, b'[', code]
+        }
+    };
+    let mut bytes = match key.code {
+        KeyCode::Char(c) if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            match c.to_ascii_lowercase() {
+                c @ 'a'..='z' => vec![c as u8 - b'a' + 1],
+                '@' | ' ' => vec![0],
+                '[' => vec![0x1b],
+                '\\' => vec![0x1c],
+                ']' => vec![0x1d],
+                _ => c.to_string().into_bytes(),
+            }
+        }
+        KeyCode::Char(c) => c.to_string().into_bytes(),
+        KeyCode::Enter => vec![b'\r'],
+        KeyCode::Tab => vec![b'\t'],
+        KeyCode::BackTab => b"\x1b[Z".to_vec(),
+        KeyCode::Backspace => vec![0x7f],
+        KeyCode::Esc => vec![0x1b],
+        KeyCode::Up => cursor(b'A'),
+        KeyCode::Down => cursor(b'B'),
+        KeyCode::Right => cursor(b'C'),
+        KeyCode::Left => cursor(b'D'),
+        KeyCode::Home => cursor(b'H'),
+        KeyCode::End => cursor(b'F'),
+        KeyCode::Insert => b"\x1b[2~".to_vec(),
+        KeyCode::Delete => b"\x1b[3~".to_vec(),
+        KeyCode::PageUp => b"\x1b[5~".to_vec(),
+        KeyCode::PageDown => b"\x1b[6~".to_vec(),
+        KeyCode::F(n @ 1..=4) => vec![0x1b, b'O', b'P' + n - 1],
+        KeyCode::F(n @ 5..=12) => {
+            const CODES: [u8; 8] = [15, 17, 18, 19, 20, 21, 23, 24];
+            format!("\x1b[{}~", CODES[(n - 5) as usize]).into_bytes()
+        }
+        _ => Vec::new(),
+    };
+    // Alt 组合键以 ESC 前缀发送
+    if key.modifiers.contains(KeyModifiers::ALT) && !bytes.is_empty() {
+        bytes.insert(0, 0x1b);
+    }
+    bytes
+}
@@ -0,0 +1,100 @@
+//! Text 组件：按可用宽度自动换行的段落，高度由换行后的行数决定。
+//!
+//! 父容器无需再为每行文本包一层 `View(height: Constraint::Length(1))`。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(View(flex_direction: Direction::Vertical) {
+//!     Text(text: "第一段很长的文字，会按宽度自动换行……")
+//!     Text(text: Line::from("第二段").bold(), alignment: Alignment::Center)
+//! })
+//! ```
+//! 布局时按父容器分配的宽度测量行数；不换行且左对齐时宽度也按内容收缩，可作为水平排列中的标签。
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect, Size},
+    style::Style,
+    widgets::{Paragraph, Widget, Wrap},
+};
+use ratatui_kit_macros::Props;
+
+use crate::Component;
+
+#[derive(Props)]
+/// Text 组件属性。
+pub struct TextProps {
+    /// 文本内容。
+    pub text: ratatui::text::Text<'static>,
+    /// 文本样式。
+    pub style: Style,
+    /// 对齐方式。
+    pub alignment: Alignment,
+    /// 是否自动换行，默认开启。
+    pub wrap: bool,
+    /// 换行时是否去掉行首空白。
+    pub trim: bool,
+}
+
+impl Default for TextProps {
+    fn default() -> Self {
+        Self {
+            text: Default::default(),
+            style: Style::default(),
+            alignment: Alignment::Left,
+            wrap: true,
+            trim: false,
+        }
+    }
+}
+
+/// Text 组件实现。
+pub struct Text {
+    paragraph: Paragraph<'static>,
+    // 不换行且左对齐时按内容宽度布局，适合作为标签
+    shrink_width: bool,
+    content_width: u16,
+}
+
+impl Component for Text {
+    type Props<'a> = TextProps;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self {
+            paragraph: Paragraph::default(),
+            shrink_width: false,
+            content_width: 0,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        _hooks: crate::Hooks,
+        _updater: &mut crate::ComponentUpdater,
+    ) {
+        let mut paragraph = Paragraph::new(props.text.clone())
+            .style(props.style)
+            .alignment(props.alignment);
+        if props.wrap {
+            paragraph = paragraph.wrap(Wrap { trim: props.trim });
+        }
+        self.paragraph = paragraph;
+        self.shrink_width = !props.wrap && props.alignment == Alignment::Left;
+        self.content_width = props.text.width() as u16;
+    }
+
+    fn measure(&self, max: Size) -> Option<Size> {
+        let width = if self.shrink_width {
+            self.content_width.min(max.width)
+        } else {
+            max.width
+        };
+        let height = self.paragraph.line_count(max.width.max(1)) as u16;
+        Some(Size::new(width, height))
+    }
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        (&self.paragraph).render(area, buf);
+    }
+}
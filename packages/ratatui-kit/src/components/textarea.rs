@@ -10,19 +10,129 @@
 //!     multiline: true,
 //!     placeholder: Some("请输入内容...".to_string()),
 //!     line_number_style: Some(Style::default().dim()),
+//!     wrap: true,
 //! ))
 //! ```
-//! 适合编辑器、表单、聊天输入等场景。
+//! `wrap` 开启时长行按宽度在单词边界折行，`↑`/`↓` 按显示行移动光标；关闭时长行水平滚动，光标始终可见。
+//!
+//! `highlighter` 为每个可见行返回带样式的 [`Line`]，用于语法高亮或标记错误：
+//! ```rust
+//! let highlighter: LineHighlighter = Arc::new(move |line, row| {
+//!     if Some(row) == error_row {
+//!         Line::from(vec![line.to_string().red(), "  ← 缺少逗号".dark_gray()])
+//!     } else {
+//!         Line::raw(line.to_string())
+//!     }
+//! });
+//! ```
+//! 返回内容按字符与原行对应，只取样式；超出原行的部分作为附加文本显示在行尾，不可编辑。
+//!
+//! `diagnostics` 在行号左侧显示严重程度图标，并给标记的范围加上下划线；
+//! 光标所在行（或鼠标悬停的行）有诊断时，底部一行显示其中最严重的一条：
+//! ```rust
+//! element!(TextArea(
+//!     value: config.read().clone(),
+//!     diagnostics: vec![Diagnostic::error(2, 8..12, "未知的字段 `nmae`")],
+//! ))
+//! ```
+//!
+//! `read_only` 时可以移动光标和滚动，但不能修改内容；`disabled` 时不响应任何事件、不显示光标，并以暗淡样式显示。
+//!
+//! 适合编辑器、表单、聊天输入等场景。放在 [`AutocompletePopup`](crate::components::AutocompletePopup) 中时支持自动补全。
 
-use crate::{Component, Handler, Hooks, UseEvents};
-use ratatui::{style::Style, widgets::Widget};
+use crate::{
+    Autocomplete, Component, Handler, Hooks, UseContext, UseEvents, UseFocusRing, UseState,
+};
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseEventKind};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
 use ratatui_kit_macros::Props;
 use std::{
     borrow::Cow,
-    sync::{Arc, RwLock},
+    ops::Range,
+    sync::{
+        Arc, Mutex, RwLock,
+        atomic::{AtomicU16, Ordering},
+    },
 };
 pub use tui_textarea::Key;
 use tui_textarea::{CursorMove, Input, TextArea as TUITextArea};
+use unicode_width::UnicodeWidthChar;
+
+/// 行高亮函数，参数为行文本和行号（从 0 开始）。
+pub type LineHighlighter = Arc<dyn Fn(&str, usize) -> Line<'static> + Send + Sync>;
+
+/// 诊断的严重程度，从高到低排列。
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Error,
+    Warning,
+    Info,
+    Hint,
+}
+
+impl Severity {
+    fn icon(self) -> &'static str {
+        match self {
+            Self::Error => "✘",
+            Self::Warning => "▲",
+            Self::Info => "●",
+            Self::Hint => "·",
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            Self::Error => Color::Red,
+            Self::Warning => Color::Yellow,
+            Self::Info => Color::Blue,
+            Self::Hint => Color::DarkGray,
+        }
+    }
+}
+
+/// TextArea 中某一行的诊断信息，如配置文件的解析错误。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// 所在行，从 0 开始。
+    pub line: usize,
+    /// 标记的字符范围（按字符计数），为空时标记整行。
+    pub span: Range<usize>,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// 创建诊断信息。
+    pub fn new(
+        line: usize,
+        span: Range<usize>,
+        severity: Severity,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            line,
+            span,
+            severity,
+            message: message.into(),
+        }
+    }
+
+    /// 创建错误。
+    pub fn error(line: usize, span: Range<usize>, message: impl Into<String>) -> Self {
+        Self::new(line, span, Severity::Error, message)
+    }
+
+    /// 创建警告。
+    pub fn warning(line: usize, span: Range<usize>, message: impl Into<String>) -> Self {
+        Self::new(line, span, Severity::Warning, message)
+    }
+}
+
 #[derive(Props, Default)]
 /// TextArea 组件属性。
 pub struct TextAreaProps<'a> {
@@ -48,11 +158,39 @@ pub struct TextAreaProps<'a> {
     pub disable_keys: Vec<Key>,
     /// 行号样式。
     pub line_number_style: Option<Style>,
+    /// 是否自动折行，关闭时长行水平滚动。
+    pub wrap: bool,
+    /// 自定义每行的样式，为 None 时使用 `style`。
+    pub highlighter: Option<LineHighlighter>,
+    /// 诊断信息，显示为行首图标、下划线和底部的消息。
+    pub diagnostics: Vec<Diagnostic>,
+    /// 是否只读，只读时可以移动光标但不能修改内容。
+    pub read_only: bool,
+    /// 是否禁用，禁用时忽略 `is_focus`，不响应任何事件。
+    pub disabled: bool,
+    /// 聚焦时叠加在输入框整体上的样式。
+    pub focus_style: Style,
 }
 
 /// TextArea 组件实现。
 pub struct TextArea {
     inner: Arc<RwLock<TUITextArea<'static>>>,
+    autocomplete: Option<Autocomplete>,
+    is_focus: bool,
+    disabled: bool,
+    focus_style: Style,
+    line_number: bool,
+    wrap: bool,
+    highlighter: Option<LineHighlighter>,
+    diagnostics: Vec<Diagnostic>,
+    // 鼠标悬停的行
+    hover: Option<usize>,
+    // 上次绘制时文本区域的顶部位置及每个屏幕行对应的逻辑行，用于确定鼠标悬停的行
+    visible_rows: Arc<Mutex<(u16, Vec<usize>)>>,
+    // 上次绘制时文本区域的宽度，折行模式下按显示行移动光标时使用
+    text_width: Arc<AtomicU16>,
+    // 顶部的显示行和左侧滚动的列数
+    scroll: (usize, usize),
 }
 
 impl Component for TextArea {
@@ -62,6 +200,18 @@ impl Component for TextArea {
 
         Self {
             inner: Arc::new(RwLock::new(inner)),
+            autocomplete: None,
+            is_focus: props.is_focus && !props.disabled,
+            disabled: props.disabled,
+            focus_style: props.focus_style,
+            line_number: props.line_number_style.is_some(),
+            wrap: props.wrap,
+            highlighter: props.highlighter.clone(),
+            diagnostics: props.diagnostics.clone(),
+            hover: None,
+            visible_rows: Arc::default(),
+            text_width: Arc::new(AtomicU16::new(0)),
+            scroll: (0, 0),
         }
     }
 
@@ -71,14 +221,77 @@ impl Component for TextArea {
         mut hooks: Hooks,
         _updater: &mut crate::ComponentUpdater,
     ) {
+        self.autocomplete = hooks.try_use_context::<Autocomplete>().map(|a| a.clone());
+        self.is_focus = props.is_focus && !props.disabled;
+        self.disabled = props.disabled;
+        self.focus_style = props.focus_style;
+        self.line_number = props.line_number_style.is_some();
+        self.wrap = props.wrap;
+        self.highlighter = props.highlighter.clone();
+        self.diagnostics = props.diagnostics.clone();
+        let mut hover = hooks.use_state(|| None::<usize>);
+        self.hover = hover.get();
+        // 只读时光标移动不触发 on_change，通过该状态重新渲染
+        let mut read_only_cursor = hooks.use_state(|| (0usize, 0usize));
+        hooks.use_focus_ring(self.is_focus);
+
         hooks.use_local_events({
             let inner = self.inner.clone();
+            let autocomplete = self.autocomplete.clone();
             let is_focus = props.is_focus;
+            let read_only = props.read_only;
+            let disabled = props.disabled;
             let multiline = props.multiline;
             let disable_keys = props.disable_keys.clone();
+            let wrap = props.wrap;
+            let text_width = self.text_width.clone();
+            let visible_rows = self.visible_rows.clone();
             let mut handler = props.on_change.take();
             move |event| {
+                if disabled {
+                    return;
+                }
+                match &event {
+                    Event::Mouse(mouse) if mouse.kind == MouseEventKind::Moved => {
+                        let row = {
+                            let (top, rows) = &*visible_rows.lock().unwrap();
+                            mouse
+                                .row
+                                .checked_sub(*top)
+                                .and_then(|y| rows.get(y as usize).copied())
+                        };
+                        if hover.get() != row {
+                            hover.set(row);
+                        }
+                        return;
+                    }
+                    Event::Key(_) if hover.get().is_some() => hover.set(None),
+                    _ => {}
+                }
                 if is_focus {
+                    // 补全弹窗打开时由弹窗处理选择相关的按键
+                    if let (Some(autocomplete), Event::Key(key)) = (&autocomplete, &event) {
+                        if autocomplete.is_open() && key.kind != KeyEventKind::Release {
+                            match key.code {
+                                KeyCode::Up => return autocomplete.select(-1),
+                                KeyCode::Down => return autocomplete.select(1),
+                                KeyCode::Esc => return autocomplete.dismiss(),
+                                KeyCode::Enter | KeyCode::Tab if !read_only => {
+                                    if let Some(suggestion) = autocomplete.accept() {
+                                        let mut inner = inner.write().unwrap();
+                                        for _ in 0..suggestion.replace {
+                                            inner.delete_char();
+                                        }
+                                        inner.insert_str(&suggestion.text);
+                                        handler(inner.lines().join("\n"));
+                                    }
+                                    return;
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+
                     let input = Input::from(event);
                     let key = input.key;
 
@@ -92,7 +305,51 @@ impl Component for TextArea {
 
                     let mut inner = inner.write().unwrap();
 
-                    inner.input(input);
+                    // 折行时上下键在显示行之间移动，而不是跳到相邻的逻辑行
+                    let delta = match key {
+                        Key::Up => -1,
+                        Key::Down => 1,
+                        _ => 0,
+                    };
+                    let target = (wrap && delta != 0 && !input.ctrl && !input.alt && !input.shift)
+                        .then(|| {
+                            let width = text_width.load(Ordering::Relaxed) as usize;
+                            move_visual(inner.lines(), inner.cursor(), width, delta)
+                        })
+                        .flatten();
+                    match target {
+                        Some((row, col)) => {
+                            inner.move_cursor(CursorMove::Jump(row as u16, col as u16))
+                        }
+                        // 只读时在输入前过滤掉编辑按键，光标移动、滚动和复制照常处理
+                        None if read_only && is_edit(&input) => {}
+                        None => {
+                            inner.input(input);
+                        }
+                    }
+
+                    if read_only {
+                        if let Some(autocomplete) = &autocomplete {
+                            autocomplete.dismiss();
+                        }
+                        if read_only_cursor.get() != inner.cursor() {
+                            read_only_cursor.set(inner.cursor());
+                        }
+                        return;
+                    }
+
+                    if let Some(autocomplete) = &autocomplete {
+                        match key {
+                            Key::Char(_) | Key::Backspace | Key::Delete => {
+                                let (row, col) = inner.cursor();
+                                let before =
+                                    inner.lines()[row].chars().take(col).collect::<String>();
+                                autocomplete.update(&before);
+                            }
+                            Key::Null => {}
+                            _ => autocomplete.dismiss(),
+                        }
+                    }
 
                     let mut string = inner.lines().join("\n");
 
@@ -108,9 +365,17 @@ impl Component for TextArea {
         let mut inner = self.inner.write().unwrap();
 
         let cursor = inner.cursor();
+        // 选区的起点是选区两端中不在光标处的一端
+        let anchor = inner
+            .selection_range()
+            .map(|(start, end)| if start == cursor { end } else { start });
 
         *inner = TUITextArea::from(props.value.lines());
 
+        if let Some((row, col)) = anchor {
+            inner.move_cursor(CursorMove::Jump(row as u16, col as u16));
+            inner.start_selection();
+        }
         inner.move_cursor(CursorMove::Jump(cursor.0 as u16, cursor.1 as u16));
         inner.set_cursor_style(props.cursor_style);
         inner.set_cursor_line_style(props.cursor_line_style);
@@ -127,7 +392,472 @@ impl Component for TextArea {
     }
 
     fn draw(&mut self, drawer: &mut crate::ComponentDrawer<'_, '_>) {
-        let inner = self.inner.read().unwrap();
-        inner.render(drawer.area, drawer.buffer_mut());
+        let area = drawer.area;
+        let drawer_area = area;
+        let mut inner = self.inner.write().unwrap();
+        let selection_style = inner.selection_style();
+        let selection = inner.selection_range();
+        let lines = inner.lines();
+        let (row, col) = inner.cursor();
+        let buf = drawer.buffer_mut();
+        buf.set_style(area, inner.style());
+        if self.is_focus {
+            buf.set_style(area, self.focus_style);
+        }
+        if area.is_empty() {
+            return;
+        }
+
+        // 光标所在行或鼠标悬停行中最严重的诊断，显示在底部一行
+        let focus_row = self.hover.unwrap_or(row);
+        let focused = self
+            .diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.line == focus_row)
+            .collect::<Vec<_>>();
+        let message = focused
+            .iter()
+            .min_by_key(|diagnostic| diagnostic.severity)
+            .filter(|_| area.height > 1);
+        let area = if let Some(diagnostic) = message {
+            let message_area = Rect::new(area.x, area.bottom() - 1, area.width, 1);
+            let mut text = format!("{} {}", diagnostic.severity.icon(), diagnostic.message);
+            if focused.len() > 1 {
+                text.push_str(&format!(" (+{})", focused.len() - 1));
+            }
+            let text = crate::text::truncate_with_ellipsis(&text, area.width as usize);
+            buf.set_stringn(
+                message_area.x,
+                message_area.y,
+                text,
+                area.width as usize,
+                Style::default().fg(diagnostic.severity.color()),
+            );
+            Rect {
+                height: area.height - 1,
+                ..area
+            }
+        } else {
+            area
+        };
+
+        let marker = if self.diagnostics.is_empty() { 0 } else { 2 };
+        let digits = lines.len().to_string().len();
+        let gutter = marker + if self.line_number { digits + 2 } else { 0 };
+        let text_area = Rect {
+            x: area.x + (gutter as u16).min(area.width),
+            width: area.width.saturating_sub(gutter as u16),
+            ..area
+        };
+        self.text_width.store(text_area.width, Ordering::Relaxed);
+
+        let width = if self.wrap {
+            text_area.width as usize
+        } else {
+            0
+        };
+        let visual = visual_lines(lines, width);
+        let cursor_index = cursor_line(&visual, row, col);
+        let cursor_line = visual[cursor_index];
+        let cursor_x = columns(&lines[row], cursor_line.start, col);
+
+        // 滚动到光标可见，折行时不需要水平滚动
+        let (mut top, mut left) = self.scroll;
+        let height = area.height as usize;
+        top = top.min(cursor_index);
+        if cursor_index >= top + height {
+            top = cursor_index + 1 - height;
+        }
+        if self.wrap {
+            left = 0;
+        } else {
+            let text_width = (text_area.width as usize).max(1);
+            left = left.min(cursor_x);
+            if cursor_x >= left + text_width {
+                left = cursor_x + 1 - text_width;
+            }
+        }
+        self.scroll = (top, left);
+
+        let placeholder = inner.placeholder_text();
+        if lines.len() == 1 && lines[0].is_empty() && !placeholder.is_empty() {
+            let style = inner
+                .style()
+                .patch(inner.placeholder_style().unwrap_or_default());
+            if !self.disabled {
+                buf.set_style(Rect::new(area.x, area.y, 1, 1), inner.cursor_style());
+            }
+            buf.set_stringn(
+                area.x + 1,
+                area.y,
+                placeholder,
+                area.width.saturating_sub(1) as usize,
+                style,
+            );
+        } else {
+            // 折行后同一逻辑行的显示行相邻，只需缓存最近一行的高亮结果
+            let mut styled: Option<(usize, Vec<(char, Style)>)> = None;
+            let mut rows = Vec::new();
+            for (y, line) in (area.top()..area.bottom()).zip(visual.iter().skip(top)) {
+                rows.push(line.row);
+                let row_area = Rect::new(text_area.x, y, text_area.width, 1);
+                if line.row == row {
+                    buf.set_style(row_area, inner.cursor_line_style());
+                }
+                let severity = self
+                    .diagnostics
+                    .iter()
+                    .filter(|diagnostic| diagnostic.line == line.row)
+                    .map(|diagnostic| diagnostic.severity)
+                    .min();
+                if let Some(severity) = severity.filter(|_| line.start == 0) {
+                    let style = Style::default().fg(severity.color());
+                    buf.set_stringn(area.x, y, severity.icon(), marker, style);
+                }
+                if let Some(style) = inner.line_number_style().filter(|_| line.start == 0) {
+                    let number = format!(" {:>digits$} ", line.row + 1);
+                    buf.set_stringn(area.x + marker as u16, y, number, gutter - marker, style);
+                }
+                if styled.as_ref().is_none_or(|(row, _)| *row != line.row) {
+                    let mut chars =
+                        styled_chars(&lines[line.row], line.row, self.highlighter.as_ref());
+                    let len = lines[line.row].chars().count();
+                    self.underline(&mut chars, line.row, len);
+                    select(&mut chars, line.row, len, selection, selection_style);
+                    styled = Some((line.row, chars));
+                }
+                if let Some((_, chars)) = &styled {
+                    // 附加文本跟在逻辑行的最后一个显示行之后
+                    let len = lines[line.row].chars().count();
+                    let end = if line.end == len {
+                        chars.len()
+                    } else {
+                        line.end
+                    };
+                    render_segment(&chars[line.start..end], left, row_area, buf);
+                }
+            }
+
+            *self.visible_rows.lock().unwrap() = (area.y, rows);
+
+            let y = cursor_index - top;
+            let x = cursor_x - left;
+            if (x as u16) < text_area.width && !self.disabled {
+                buf.set_style(
+                    Rect::new(text_area.x + x as u16, area.y + y as u16, 1, 1),
+                    inner.cursor_style(),
+                );
+            }
+        }
+
+        if let Some(autocomplete) = &self.autocomplete {
+            let caret = self.is_focus.then(|| {
+                Position::new(
+                    (text_area.x + (cursor_x - left) as u16).min(area.right().saturating_sub(1)),
+                    area.y + (cursor_index - top) as u16,
+                )
+            });
+            autocomplete.set_caret(caret);
+        }
+
+        if self.disabled {
+            buf.set_style(drawer_area, Style::default().add_modifier(Modifier::DIM));
+        }
+    }
+}
+
+impl TextArea {
+    // 给诊断标记的字符加上对应颜色的下划线，范围为空时标记整行
+    fn underline(&self, chars: &mut [(char, Style)], row: usize, len: usize) {
+        for diagnostic in self.diagnostics.iter().filter(|d| d.line == row) {
+            let span = if diagnostic.span.is_empty() {
+                0..len
+            } else {
+                diagnostic.span.start.min(len)..diagnostic.span.end.min(len)
+            };
+            let style = Style::default()
+                .add_modifier(Modifier::UNDERLINED)
+                .underline_color(diagnostic.severity.color());
+            for (_, char_style) in &mut chars[span] {
+                *char_style = char_style.patch(style);
+            }
+        }
+    }
+}
+
+// 给选区内的字符加上选区样式，选区的结束位置不包含在内
+fn select(
+    chars: &mut [(char, Style)],
+    row: usize,
+    len: usize,
+    selection: Option<((usize, usize), (usize, usize))>,
+    style: Style,
+) {
+    let Some(((start_row, start_col), (end_row, end_col))) = selection else {
+        return;
+    };
+    if row < start_row || row > end_row {
+        return;
+    }
+    let start = if row == start_row {
+        start_col.min(len)
+    } else {
+        0
+    };
+    let end = if row == end_row {
+        end_col.min(len)
+    } else {
+        len
+    };
+    for (_, char_style) in &mut chars[start..end.max(start)] {
+        *char_style = char_style.patch(style);
+    }
+}
+
+// 一个显示行：逻辑行号及其中的字符范围 [start, end)
+#[derive(Clone, Copy)]
+struct VisualLine {
+    row: usize,
+    start: usize,
+    end: usize,
+}
+
+// 按宽度把逻辑行切分为显示行，优先在空白之后断开；宽度为 0 时不折行
+fn visual_lines(lines: &[String], width: usize) -> Vec<VisualLine> {
+    let mut visual = Vec::with_capacity(lines.len());
+    for (row, line) in lines.iter().enumerate() {
+        let chars = line.chars().collect::<Vec<_>>();
+        let mut start = 0;
+        if width > 0 {
+            let mut used = 0;
+            let mut last_space = None;
+            let mut index = start;
+            while index < chars.len() {
+                let char_width = chars[index].width().unwrap_or(0);
+                if used + char_width > width && index > start {
+                    // 超出宽度的空白留在行尾，不换到下一行开头
+                    let end = if chars[index].is_whitespace() {
+                        index + 1
+                    } else {
+                        last_space.map_or(index, |space| space + 1)
+                    };
+                    visual.push(VisualLine { row, start, end });
+                    start = end;
+                    index = end;
+                    used = 0;
+                    last_space = None;
+                    continue;
+                }
+                if chars[index].is_whitespace() {
+                    last_space = Some(index);
+                }
+                used += char_width;
+                index += 1;
+            }
+        }
+        visual.push(VisualLine {
+            row,
+            start,
+            end: chars.len(),
+        });
+    }
+    visual
+}
+
+// 光标所在的显示行，光标位于折行处时属于下一行
+fn cursor_line(visual: &[VisualLine], row: usize, col: usize) -> usize {
+    visual
+        .iter()
+        .rposition(|line| line.row == row && line.start <= col)
+        .unwrap_or(0)
+}
+
+// 逻辑行中 [start, end) 范围内字符的显示宽度
+fn columns(line: &str, start: usize, end: usize) -> usize {
+    line.chars()
+        .skip(start)
+        .take(end.saturating_sub(start))
+        .map(|c| c.width().unwrap_or(0))
+        .sum()
+}
+
+// 按显示行上下移动光标并尽量保持所在列，已在首行或末行时返回 None
+fn move_visual(
+    lines: &[String],
+    (row, col): (usize, usize),
+    width: usize,
+    delta: isize,
+) -> Option<(usize, usize)> {
+    let visual = visual_lines(lines, width);
+    let current = cursor_line(&visual, row, col);
+    let x = columns(&lines[row], visual[current].start, col);
+    let target = *visual.get(current.checked_add_signed(delta)?)?;
+    let mut col = target.start;
+    let mut used = 0;
+    for c in lines[target.row].chars().skip(target.start) {
+        let char_width = c.width().unwrap_or(0);
+        // 折行处的末尾字符之后属于下一行，停在它之前
+        let last = col + 1 == target.end && target.end < lines[target.row].chars().count();
+        if used + char_width > x || col >= target.end || last {
+            break;
+        }
+        used += char_width;
+        col += 1;
+    }
+    Some((target.row, col))
+}
+
+// 按 tui-textarea 的默认键位判断输入是否会修改内容
+fn is_edit(input: &Input) -> bool {
+    matches!(
+        (input.key, input.ctrl, input.alt),
+        (Key::Enter | Key::Paste | Key::Cut, _, _)
+            | (Key::Char(_) | Key::Tab, false, false)
+            | (Key::Backspace | Key::Delete, false, _)
+            | (
+                Key::Char('m' | 'h' | 'd' | 'k' | 'j' | 'w' | 'u' | 'r' | 'y' | 'x'),
+                true,
+                false
+            )
+            | (Key::Char('h' | 'd'), false, true)
+    )
+}
+
+// 逐字符的样式，高亮结果比原行短时其余字符不加样式
+fn styled_chars(
+    line: &str,
+    row: usize,
+    highlighter: Option<&LineHighlighter>,
+) -> Vec<(char, Style)> {
+    let Some(highlighter) = highlighter else {
+        return line.chars().map(|c| (c, Style::default())).collect();
+    };
+    let highlighted = highlighter(line, row);
+    let mut styles = highlighted.spans.iter().flat_map(|span| {
+        let style = highlighted.style.patch(span.style);
+        span.content.chars().map(move |c| (c, style))
+    });
+    let mut chars = line
+        .chars()
+        .map(|c| {
+            (
+                c,
+                styles.next().map_or(Style::default(), |(_, style)| style),
+            )
+        })
+        .collect::<Vec<_>>();
+    chars.extend(styles);
+    chars
+}
+
+// 绘制显示行，跳过左侧滚动掉的列，被截断的宽字符用空格代替
+fn render_segment(chars: &[(char, Style)], left: usize, area: Rect, buf: &mut Buffer) {
+    let mut x = 0;
+    let mut spans: Vec<Span> = Vec::new();
+    for &(c, style) in chars {
+        let char_width = c.width().unwrap_or(0);
+        let text = if x >= left {
+            c.to_string()
+        } else if x + char_width > left {
+            " ".repeat(x + char_width - left)
+        } else {
+            String::new()
+        };
+        x += char_width;
+        match spans.last_mut() {
+            Some(span) if span.style == style => span.content.to_mut().push_str(&text),
+            _ if !text.is_empty() => spans.push(Span::styled(text, style)),
+            _ => {}
+        }
+    }
+    buf.set_line(area.x, area.y, &Line::from(spans), area.width);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+    use ratatui::style::Color;
+
+    use crate::{
+        prelude::*,
+        test_util::{keys, run_events},
+    };
+
+    #[derive(Default, Props)]
+    struct EditorProps {
+        value: Arc<Mutex<String>>,
+    }
+
+    #[component]
+    fn Editor(props: &EditorProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let autocomplete =
+            hooks.use_autocomplete(|_| vec!["hello".to_string(), "help".to_string()]);
+        let mut value = hooks.use_state(String::new);
+        let shared = props.value.clone();
+        element!(AutocompletePopup(autocomplete: Some(autocomplete)) {
+            TextArea(
+                value: value.read().clone(),
+                is_focus: true,
+                on_change: move |new_value: String| {
+                    *shared.lock().unwrap() = new_value.clone();
+                    value.set(new_value);
+                },
+            )
+        })
+    }
+
+    #[test]
+    fn accepts_suggestion_from_autocomplete_popup() {
+        let value = Arc::new(Mutex::new(String::new()));
+        run_events(
+            element!(Editor(value: value.clone())),
+            keys([KeyCode::Char('h'), KeyCode::Char('e'), KeyCode::Tab]),
+        );
+        assert_eq!(*value.lock().unwrap(), "help");
+    }
+
+    #[test]
+    fn read_only_ignores_editing_keys() {
+        let changes = Arc::new(Mutex::new(Vec::<String>::new()));
+        let root = element!(TextArea(
+            value: "hello".to_string(),
+            is_focus: true,
+            read_only: true,
+            on_change: {
+                let changes = changes.clone();
+                move |value: String| changes.lock().unwrap().push(value)
+            },
+        ));
+        let keys = [
+            KeyEvent::new(KeyCode::Left, KeyModifiers::NONE),
+            KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE),
+            KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE),
+            KeyEvent::new(KeyCode::Char('k'), KeyModifiers::CONTROL),
+            KeyEvent::new(KeyCode::Char('y'), KeyModifiers::CONTROL),
+        ];
+        run_events(root, keys.map(Event::Key));
+        assert!(changes.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn shift_down_selects_in_wrap_mode() {
+        let root = element!(TextArea(
+            value: "abcdef\nghijkl".to_string(),
+            is_focus: true,
+            multiline: true,
+            wrap: true,
+        ));
+        let frame = run_events(
+            root,
+            [Event::Key(KeyEvent::new(
+                KeyCode::Down,
+                KeyModifiers::SHIFT,
+            ))],
+        );
+        let selected = (0..6).all(|x| frame[(x, 0)].bg == Color::LightBlue);
+        assert!(selected);
+        assert_ne!(frame[(1, 1)].bg, Color::LightBlue);
     }
 }
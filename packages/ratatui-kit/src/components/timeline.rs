@@ -0,0 +1,487 @@
+//! Timeline 组件：甘特图式的时间线，每行一个时间段，支持缩放/平移时间轴、当前时间指示和选择。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(Timeline(
+//!     items: vec![
+//!         TimelineItem::new("checkout", 0.0, Some(4.2)),
+//!         TimelineItem::new("build", 4.2, Some(95.0)).style(Style::default().green()),
+//!         TimelineItem::new("test", 95.0, None).style(Style::default().yellow()),
+//!     ],
+//!     now: Some(elapsed),
+//!     is_focus: true,
+//!     on_select: move |index| selected.set(Some(index)),
+//! ))
+//! ```
+//! 时间以秒为单位，起点任意（例如相对于流水线开始的时间），刻度按时长格式显示。
+//! 未结束的时间段（`end` 为 None）延伸到 `now`。
+//!
+//! 聚焦时支持以下按键：
+//! - `↑/↓`：选择时间段，`Enter`：触发 `on_select`
+//! - `←/→`：平移时间轴，`+/-`：以当前时间段为中心缩放，`0`：恢复自动适配
+//!
+//! 开启鼠标捕获后，点击选择时间段，滚轮上下滚动，拖拽平移时间轴。
+
+use std::sync::{Arc, Mutex};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseButton, MouseEventKind};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    style::{Style, Stylize},
+    text::Line,
+    widgets::Widget,
+};
+use ratatui_kit_macros::Props;
+
+use crate::{Component, Handler, Hooks, State, UseEvents, UseState, text};
+
+#[derive(Debug, Clone, PartialEq)]
+/// 时间线上的一个时间段。
+pub struct TimelineItem {
+    /// 标签，显示在左侧。
+    pub label: String,
+    /// 开始时间（秒）。
+    pub start: f64,
+    /// 结束时间（秒），未结束时为 None。
+    pub end: Option<f64>,
+    /// 时间段样式。
+    pub style: Style,
+}
+
+impl TimelineItem {
+    pub fn new(label: impl Into<String>, start: f64, end: Option<f64>) -> Self {
+        Self {
+            label: label.into(),
+            start,
+            end,
+            style: Style::default().cyan(),
+        }
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn end_or(&self, now: Option<f64>) -> f64 {
+        self.end.or(now).unwrap_or(self.start).max(self.start)
+    }
+}
+
+#[derive(Props)]
+/// Timeline 组件属性。
+pub struct TimelineProps {
+    /// 时间段列表。
+    pub items: Vec<TimelineItem>,
+    /// 当前时间，显示为竖线。
+    pub now: Option<f64>,
+    /// 是否聚焦，聚焦时响应按键。
+    pub is_focus: bool,
+    /// 按 Enter 或点击选择时间段时的回调，参数为索引。
+    pub on_select: Handler<'static, usize>,
+    /// 标签列宽度，为 0 时按最长标签计算。
+    pub label_width: u16,
+    /// 标签样式。
+    pub label_style: Style,
+    /// 选中行样式。
+    pub selected_style: Style,
+    /// 时间轴样式。
+    pub axis_style: Style,
+    /// 当前时间竖线样式。
+    pub now_style: Style,
+}
+
+impl Default for TimelineProps {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            now: None,
+            is_focus: false,
+            on_select: Handler::default(),
+            label_width: 0,
+            label_style: Style::default(),
+            selected_style: Style::default().bold().on_dark_gray(),
+            axis_style: Style::default().dim(),
+            now_style: Style::default().red(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+// 可视时间范围、选中行和滚动位置
+struct TimelineView {
+    range: [f64; 2],
+    // 未手动缩放/平移时跟随数据范围
+    auto_fit: bool,
+    selected: Option<usize>,
+    offset: usize,
+}
+
+impl Default for TimelineView {
+    fn default() -> Self {
+        Self {
+            range: [0.0, 1.0],
+            auto_fit: true,
+            selected: None,
+            offset: 0,
+        }
+    }
+}
+
+impl TimelineView {
+    fn resolve(mut self, bounds: [f64; 2]) -> Self {
+        if self.auto_fit {
+            self.range = bounds;
+        }
+        self
+    }
+
+    fn span(&self) -> f64 {
+        self.range[1] - self.range[0]
+    }
+
+    fn zoom(&mut self, factor: f64, center: f64) {
+        let [start, end] = self.range;
+        let start = center - (center - start) * factor;
+        let end = center + (end - center) * factor;
+        if end - start > f64::EPSILON {
+            self.range = [start, end];
+            self.auto_fit = false;
+        }
+    }
+
+    fn pan(&mut self, delta: f64) {
+        self.range = [self.range[0] + delta, self.range[1] + delta];
+        self.auto_fit = false;
+    }
+
+    fn select(&mut self, index: usize, visible_rows: usize) {
+        self.selected = Some(index);
+        if index < self.offset {
+            self.offset = index;
+        } else if visible_rows > 0 && index >= self.offset + visible_rows {
+            self.offset = index + 1 - visible_rows;
+        }
+    }
+}
+
+// 所有时间段和当前时间的范围
+fn time_bounds(items: &[TimelineItem], now: Option<f64>) -> [f64; 2] {
+    let mut bounds = items
+        .iter()
+        .fold([f64::INFINITY, f64::NEG_INFINITY], |[min, max], item| {
+            [min.min(item.start), max.max(item.end_or(now))]
+        });
+    if let Some(now) = now {
+        bounds = [bounds[0].min(now), bounds[1].max(now)];
+    }
+    if !bounds[0].is_finite() {
+        return [0.0, 1.0];
+    }
+    if bounds[1] <= bounds[0] {
+        bounds[1] = bounds[0] + 1.0;
+    }
+    bounds
+}
+
+/// 把秒数格式化为时长，例如 `850ms`、`4.2s`、`1m35s`、`2h05m`。
+pub fn format_duration(seconds: f64) -> String {
+    let sign = if seconds < 0.0 { "-" } else { "" };
+    let seconds = seconds.abs();
+    if seconds < 1.0 {
+        format!("{sign}{:.0}ms", seconds * 1000.0)
+    } else if seconds < 60.0 {
+        let text = format!("{seconds:.1}");
+        format!("{sign}{}s", text.trim_end_matches(".0"))
+    } else if seconds < 3600.0 {
+        let total = seconds.round() as u64;
+        format!("{sign}{}m{:02}s", total / 60, total % 60)
+    } else {
+        let total = (seconds / 60.0).round() as u64;
+        format!("{sign}{}h{:02}m", total / 60, total % 60)
+    }
+}
+
+// 1、2、5 乘以 10 的幂中不小于 `rough` 的最小值
+fn nice_step(rough: f64) -> f64 {
+    let power = 10f64.powf(rough.log10().floor());
+    [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|m| m * power)
+        .find(|step| *step >= rough)
+        .unwrap_or(10.0 * power)
+}
+
+#[derive(Default)]
+struct TimelineLayout {
+    // 时间段区域（不含标签列和时间轴）
+    bars: Rect,
+    visible_rows: usize,
+    // 拖拽起点及当时的可视范围
+    drag: Option<(u16, TimelineView)>,
+}
+
+/// Timeline 组件实现。
+pub struct Timeline {
+    items: Vec<TimelineItem>,
+    now: Option<f64>,
+    view: Option<State<TimelineView>>,
+    layout: Arc<Mutex<TimelineLayout>>,
+    label_width: u16,
+    label_style: Style,
+    selected_style: Style,
+    axis_style: Style,
+    now_style: Style,
+}
+
+impl Timeline {
+    fn column(&self, time: f64, view: &TimelineView, bars: Rect) -> f64 {
+        (time - view.range[0]) / view.span() * bars.width as f64
+    }
+
+    fn render_axis(&self, view: &TimelineView, area: Rect, bars: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.axis_style);
+        if bars.width == 0 {
+            return;
+        }
+        // 每个刻度标签大约占 10 列
+        let step = nice_step(view.span() / (bars.width as f64 / 10.0).max(1.0));
+        let mut tick = (view.range[0] / step).ceil() * step;
+        while tick <= view.range[1] {
+            let x = bars.x + self.column(tick, view, bars).floor() as u16;
+            let label = format!("┬{}", format_duration(tick));
+            let width = (bars.right().saturating_sub(x)) as usize;
+            if width > 0 {
+                buf.set_string(x, area.y, text::truncate(&label, width), self.axis_style);
+            }
+            tick += step;
+        }
+    }
+}
+
+impl Component for Timeline {
+    type Props<'a> = TimelineProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            items: props.items.clone(),
+            now: props.now,
+            view: None,
+            layout: Arc::new(Mutex::new(TimelineLayout::default())),
+            label_width: props.label_width,
+            label_style: props.label_style,
+            selected_style: props.selected_style,
+            axis_style: props.axis_style,
+            now_style: props.now_style,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        _updater: &mut crate::ComponentUpdater,
+    ) {
+        let mut view = hooks.use_state(TimelineView::default);
+        let len = props.items.len();
+        if view.read().selected.is_some_and(|i| i >= len) {
+            view.write().selected = len.checked_sub(1);
+        }
+        let bounds = time_bounds(&props.items, props.now);
+
+        hooks.use_local_events({
+            let layout = self.layout.clone();
+            let centers = props
+                .items
+                .iter()
+                .map(|item| (item.start + item.end_or(props.now)) / 2.0)
+                .collect::<Vec<_>>();
+            let is_focus = props.is_focus;
+            let mut on_select = props.on_select.take();
+            move |event| {
+                let mut next = view.read().resolve(bounds);
+                let mut layout = layout.lock().unwrap();
+                let visible_rows = layout.visible_rows;
+                let mut select = None;
+                match event {
+                    Event::Key(key) if is_focus && key.kind != KeyEventKind::Release => {
+                        let step = next.span() / 10.0;
+                        let center = next
+                            .selected
+                            .map_or((next.range[0] + next.range[1]) / 2.0, |i| centers[i]);
+                        match key.code {
+                            KeyCode::Up if len > 0 => {
+                                let index = next.selected.map_or(0, |i| i.saturating_sub(1));
+                                next.select(index, visible_rows);
+                            }
+                            KeyCode::Down if len > 0 => {
+                                let index = next.selected.map_or(0, |i| (i + 1).min(len - 1));
+                                next.select(index, visible_rows);
+                            }
+                            KeyCode::Left => next.pan(-step),
+                            KeyCode::Right => next.pan(step),
+                            KeyCode::Char('+' | '=') => next.zoom(0.8, center),
+                            KeyCode::Char('-') => next.zoom(1.25, center),
+                            KeyCode::Char('0') => next.auto_fit = true,
+                            KeyCode::Enter => select = next.selected,
+                            _ => return,
+                        }
+                    }
+                    Event::Mouse(mouse) => match mouse.kind {
+                        MouseEventKind::ScrollUp => next.offset = next.offset.saturating_sub(1),
+                        MouseEventKind::ScrollDown => {
+                            next.offset =
+                                (next.offset + 1).min(len.saturating_sub(visible_rows.max(1)));
+                        }
+                        MouseEventKind::Down(MouseButton::Left) => {
+                            let bars = layout.bars;
+                            let row = mouse
+                                .row
+                                .checked_sub(bars.y)
+                                .map(|r| next.offset + r as usize);
+                            if let Some(index) =
+                                row.filter(|&i| i < len && mouse.row < bars.bottom())
+                            {
+                                next.select(index, visible_rows);
+                                select = Some(index);
+                            }
+                            if bars.contains(Position::new(mouse.column, mouse.row)) {
+                                layout.drag = Some((mouse.column, next));
+                            }
+                        }
+                        MouseEventKind::Drag(MouseButton::Left) => {
+                            let Some((column, start)) = layout.drag else {
+                                return;
+                            };
+                            let delta = (column as f64 - mouse.column as f64)
+                                / layout.bars.width.max(1) as f64
+                                * start.span();
+                            let selected = next.selected;
+                            next = start;
+                            next.selected = selected;
+                            next.pan(delta);
+                        }
+                        MouseEventKind::Up(MouseButton::Left) => {
+                            layout.drag = None;
+                            return;
+                        }
+                        _ => return,
+                    },
+                    _ => return,
+                }
+                drop(layout);
+                if next != *view.read() {
+                    view.set(next);
+                }
+                if let Some(index) = select {
+                    on_select(index);
+                }
+            }
+        });
+
+        self.items = props.items.clone();
+        self.now = props.now;
+        self.view = Some(view);
+        self.label_width = props.label_width;
+        self.label_style = props.label_style;
+        self.selected_style = props.selected_style;
+        self.axis_style = props.axis_style;
+        self.now_style = props.now_style;
+    }
+
+    fn draw(&mut self, drawer: &mut crate::ComponentDrawer<'_, '_>) {
+        let Some(view) = self.view else {
+            return;
+        };
+        let view = view.read().resolve(time_bounds(&self.items, self.now));
+        let area = drawer.area;
+        if area.is_empty() {
+            return;
+        }
+        let label_width = if self.label_width > 0 {
+            self.label_width
+        } else {
+            self.items
+                .iter()
+                .map(|item| text::width(&item.label) as u16 + 1)
+                .max()
+                .unwrap_or(0)
+        }
+        .min(area.width / 2);
+        let axis = Rect { height: 1, ..area };
+        let bars = Rect {
+            x: area.x + label_width,
+            y: area.y + 1,
+            width: area.width - label_width,
+            height: area.height - 1,
+        };
+        let visible_rows = bars.height as usize;
+        {
+            let mut layout = self.layout.lock().unwrap();
+            layout.bars = bars;
+            layout.visible_rows = visible_rows;
+        }
+
+        let buf = drawer.buffer_mut();
+        self.render_axis(&view, axis, bars, buf);
+
+        for (row, (index, item)) in self
+            .items
+            .iter()
+            .enumerate()
+            .skip(view.offset)
+            .take(visible_rows)
+            .enumerate()
+        {
+            let y = bars.y + row as u16;
+            let line = Rect {
+                y,
+                height: 1,
+                ..area
+            };
+            if view.selected == Some(index) {
+                buf.set_style(line, self.selected_style);
+            }
+            Line::styled(
+                text::fit(&item.label, label_width as usize, Default::default()),
+                self.label_style,
+            )
+            .render(
+                Rect {
+                    width: label_width,
+                    ..line
+                },
+                buf,
+            );
+
+            // 时间段至少占一列，超出可视范围的部分截断
+            let start = self.column(item.start, &view, bars).floor();
+            let end = self
+                .column(item.end_or(self.now), &view, bars)
+                .ceil()
+                .max(start + 1.0);
+            let start = start.clamp(0.0, bars.width as f64) as u16;
+            let end = end.clamp(0.0, bars.width as f64) as u16;
+            if end > start {
+                let bar = "█".repeat((end - start) as usize);
+                buf.set_string(bars.x + start, y, bar, item.style);
+            }
+        }
+
+        if let Some(now) = self
+            .now
+            .filter(|now| (view.range[0]..=view.range[1]).contains(now))
+        {
+            let x = bars.x
+                + (self.column(now, &view, bars).floor() as u16).min(bars.width.saturating_sub(1));
+            for y in bars.y..bars.bottom() {
+                let cell = &mut buf[(x, y)];
+                // 竖线只画在空白处，不遮挡时间段
+                if cell.symbol() == " " {
+                    cell.set_symbol("│").set_style(self.now_style);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,299 @@
+//! Stopwatch 和 Countdown 组件：秒表和倒计时，支持开始/暂停/重置，倒计时结束时触发回调。
+//!
+//! ## 用法示例
+//! ```rust
+//! // 番茄钟：25 分钟倒计时，结束后切换到休息
+//! element!(Countdown(
+//!     duration: Duration::from_secs(25 * 60),
+//!     label: "专注",
+//!     is_focus: true,
+//!     on_finish: move |_| phase.set(Phase::Break),
+//! ))
+//!
+//! // 由外部控制的秒表
+//! let timer = hooks.use_timer(Duration::from_millis(100));
+//! element!(Stopwatch(timer: Some(timer.clone())))
+//! ```
+//! 聚焦时 `Space` 开始/暂停，`r` 重置。传入 `timer` 时使用外部计时器，否则组件自行创建。
+
+use std::time::Duration;
+
+use crossterm::event::{Event, KeyCode, KeyEventKind};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Rect, Size},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::Widget,
+};
+use ratatui_kit_macros::Props;
+
+use crate::{Component, Handler, Hooks, Timer, TimerWatch, UseEffect, UseEvents, UseMemo};
+
+// 不足一小时时显示为 `MM:SS`，否则为 `H:MM:SS`，`tenths` 为 true 时追加十分之一秒
+fn format_clock(duration: Duration, tenths: bool) -> String {
+    let total = duration.as_secs();
+    let (hours, minutes, seconds) = (total / 3600, total / 60 % 60, total % 60);
+    let mut text = if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes:02}:{seconds:02}")
+    };
+    if tenths {
+        text.push_str(&format!(".{}", duration.subsec_millis() / 100));
+    }
+    text
+}
+
+// 使用外部计时器或组件自己的计时器，并订阅其变化
+fn use_component_timer(hooks: &mut Hooks, external: Option<Timer>, tick: Duration) -> Timer {
+    let own = hooks.use_memo(Timer::new, ());
+    let watch = hooks.use_hook(|| TimerWatch::new(own.clone(), tick));
+    let timer = external.unwrap_or(own);
+    watch.timer = timer.clone();
+    watch.tick = tick;
+    timer
+}
+
+// Space 开始/暂停，r 重置
+fn use_timer_keys(hooks: &mut Hooks, timer: &Timer, is_focus: bool) {
+    hooks.use_local_events({
+        let timer = timer.clone();
+        move |event| {
+            let Event::Key(key) = event else {
+                return;
+            };
+            if !is_focus || key.kind != KeyEventKind::Press {
+                return;
+            }
+            match key.code {
+                KeyCode::Char(' ') => timer.toggle(),
+                KeyCode::Char('r') => timer.reset(),
+                _ => {}
+            }
+        }
+    });
+}
+
+fn clock_line(label: &str, time: String, style: Style) -> Line<'static> {
+    let mut spans = Vec::new();
+    if !label.is_empty() {
+        spans.push(Span::raw(format!("{label} ")));
+    }
+    spans.push(Span::styled(time, style));
+    Line::from(spans)
+}
+
+#[derive(Props)]
+/// Stopwatch 组件属性。
+pub struct StopwatchProps {
+    /// 外部计时器，为 None 时组件自行创建。
+    pub timer: Option<Timer>,
+    /// 挂载时是否自动开始。
+    pub auto_start: bool,
+    /// 是否显示十分之一秒。
+    pub show_tenths: bool,
+    /// 时间前的标签。
+    pub label: String,
+    /// 是否聚焦，聚焦时响应按键。
+    pub is_focus: bool,
+    /// 运行时样式。
+    pub style: Style,
+    /// 暂停时样式。
+    pub paused_style: Style,
+}
+
+impl Default for StopwatchProps {
+    fn default() -> Self {
+        Self {
+            timer: None,
+            auto_start: false,
+            show_tenths: true,
+            label: String::new(),
+            is_focus: false,
+            style: Style::default().bold(),
+            paused_style: Style::default().dim(),
+        }
+    }
+}
+
+/// Stopwatch 组件实现。
+pub struct Stopwatch {
+    line: Line<'static>,
+}
+
+impl Component for Stopwatch {
+    type Props<'a> = StopwatchProps;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self {
+            line: Line::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        _updater: &mut crate::ComponentUpdater,
+    ) {
+        let tick = if props.show_tenths {
+            Duration::from_millis(100)
+        } else {
+            Duration::from_secs(1)
+        };
+        let timer = use_component_timer(&mut hooks, props.timer.clone(), tick);
+        hooks.use_effect(
+            {
+                let timer = timer.clone();
+                let auto_start = props.auto_start;
+                move || {
+                    if auto_start {
+                        timer.start();
+                    }
+                }
+            },
+            (),
+        );
+        use_timer_keys(&mut hooks, &timer, props.is_focus);
+
+        let style = if timer.is_running() {
+            props.style
+        } else {
+            props.paused_style
+        };
+        self.line = clock_line(
+            &props.label,
+            format_clock(timer.elapsed(), props.show_tenths),
+            style,
+        );
+    }
+
+    fn measure(&self, max: Size) -> Option<Size> {
+        Some(Size::new((self.line.width() as u16).min(max.width), 1))
+    }
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        (&self.line).render(area, buf);
+    }
+}
+
+#[derive(Props)]
+/// Countdown 组件属性。
+pub struct CountdownProps {
+    /// 倒计时时长。
+    pub duration: Duration,
+    /// 外部计时器，为 None 时组件自行创建。
+    pub timer: Option<Timer>,
+    /// 挂载时是否自动开始。
+    pub auto_start: bool,
+    /// 是否显示十分之一秒。
+    pub show_tenths: bool,
+    /// 时间前的标签。
+    pub label: String,
+    /// 是否聚焦，聚焦时响应按键。
+    pub is_focus: bool,
+    /// 倒计时结束时触发。
+    pub on_finish: Handler<'static, ()>,
+    /// 运行时样式。
+    pub style: Style,
+    /// 暂停时样式。
+    pub paused_style: Style,
+    /// 结束后样式。
+    pub finished_style: Style,
+}
+
+impl Default for CountdownProps {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_secs(60),
+            timer: None,
+            auto_start: false,
+            show_tenths: false,
+            label: String::new(),
+            is_focus: false,
+            on_finish: Handler::default(),
+            style: Style::default().bold(),
+            paused_style: Style::default().dim(),
+            finished_style: Style::default().red().bold(),
+        }
+    }
+}
+
+/// Countdown 组件实现。
+pub struct Countdown {
+    line: Line<'static>,
+}
+
+impl Component for Countdown {
+    type Props<'a> = CountdownProps;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self {
+            line: Line::default(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        _updater: &mut crate::ComponentUpdater,
+    ) {
+        let tick = if props.show_tenths {
+            Duration::from_millis(100)
+        } else {
+            // 以较短的间隔刷新，避免整秒显示的跳变滞后
+            Duration::from_millis(250)
+        };
+        let timer = use_component_timer(&mut hooks, props.timer.clone(), tick);
+        hooks.use_effect(
+            {
+                let timer = timer.clone();
+                let auto_start = props.auto_start;
+                move || {
+                    if auto_start {
+                        timer.start();
+                    }
+                }
+            },
+            (),
+        );
+        use_timer_keys(&mut hooks, &timer, props.is_focus);
+
+        let elapsed = timer.elapsed();
+        let finished = elapsed >= props.duration;
+        if finished && timer.is_running() {
+            timer.pause();
+            (props.on_finish)(());
+        }
+        let remaining = props.duration.saturating_sub(elapsed);
+        // 不显示十分之一秒时向上取整，剩余 0.5 秒显示为 00:01
+        let remaining = if props.show_tenths || remaining.subsec_nanos() == 0 {
+            remaining
+        } else {
+            Duration::from_secs(remaining.as_secs() + 1)
+        };
+
+        let style = if finished {
+            props.finished_style
+        } else if timer.is_running() {
+            props.style
+        } else {
+            props.paused_style
+        };
+        self.line = clock_line(
+            &props.label,
+            format_clock(remaining, props.show_tenths),
+            style,
+        );
+    }
+
+    fn measure(&self, max: Size) -> Option<Size> {
+        Some(Size::new((self.line.width() as u16).min(max.width), 1))
+    }
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        (&self.line).render(area, buf);
+    }
+}
@@ -0,0 +1,431 @@
+//! TreeView 组件：树形视图，支持展开/折叠、键盘与鼠标导航，选择规则与 [`SelectionState`] 一致。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(TreeView(
+//!     nodes: vec![
+//!         TreeNode::new("src").expanded().with_children(vec![
+//!             TreeNode::new("main.rs"),
+//!             TreeNode::new("lib.rs"),
+//!         ]),
+//!         TreeNode::new("Cargo.toml"),
+//!     ],
+//!     on_cursor_change: move |path: Vec<usize>| current.set(path),
+//!     is_focus: true,
+//! ))
+//! ```
+//!
+//! 聚焦时支持以下按键：
+//! - `↑/↓`、`k/j`：移动光标，`Shift`、`Space` 等多选按键见 [`SelectionState`]
+//! - `→`、`l`、`Enter`：展开节点，已展开时进入第一个子节点
+//! - `←`、`h`：折叠节点，已折叠时回到父节点
+//!
+//! 节点通过从根开始的索引路径标识，如 `[0, 1]` 表示第一个根节点的第二个子节点。
+//!
+//! `read_only` 时可以展开/折叠节点，但选中节点保持不变；`disabled` 时不响应任何事件，并以暗淡样式显示。
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex},
+};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseButton, MouseEventKind};
+use ratatui::{
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{List as TuiList, ListItem, ListState, StatefulWidget},
+};
+use ratatui_kit_macros::Props;
+
+use super::SelectionState;
+use crate::{Component, Handler, Hooks, State, UseEffect, UseEvents, UseState};
+
+#[derive(Clone, Default)]
+/// 树节点。
+pub struct TreeNode {
+    /// 节点显示内容。
+    pub label: Line<'static>,
+    /// 子节点。
+    pub children: Vec<TreeNode>,
+    /// 初始是否展开。
+    pub expanded: bool,
+}
+
+impl TreeNode {
+    /// 创建叶子节点。
+    pub fn new(label: impl Into<Line<'static>>) -> Self {
+        Self {
+            label: label.into(),
+            children: Vec::new(),
+            expanded: false,
+        }
+    }
+
+    /// 设置子节点。
+    pub fn with_children(mut self, children: Vec<TreeNode>) -> Self {
+        self.children = children;
+        self
+    }
+
+    /// 初始展开该节点。
+    pub fn expanded(mut self) -> Self {
+        self.expanded = true;
+        self
+    }
+}
+
+/// 按索引路径查找节点。
+pub fn tree_node_at<'a>(nodes: &'a [TreeNode], path: &[usize]) -> Option<&'a TreeNode> {
+    let (first, rest) = path.split_first()?;
+    let node = nodes.get(*first)?;
+    if rest.is_empty() {
+        Some(node)
+    } else {
+        tree_node_at(&node.children, rest)
+    }
+}
+
+#[derive(Props, Default)]
+/// TreeView 组件属性。
+pub struct TreeViewProps {
+    /// 根节点列表。
+    pub nodes: Vec<TreeNode>,
+    /// 初始选择状态，索引对应当前可见的行，变化时覆盖组件内部状态。
+    pub selection_state: SelectionState,
+    /// 选择变化回调，参数为全部选中节点的路径。
+    pub on_selection_change: Handler<'static, Vec<Vec<usize>>>,
+    /// 光标移动回调，参数为光标所在节点的路径。
+    pub on_cursor_change: Handler<'static, Vec<usize>>,
+    /// 是否聚焦，聚焦时响应按键。
+    pub is_focus: bool,
+    /// 是否只读，只读时不能修改选中节点。
+    pub read_only: bool,
+    /// 是否禁用，禁用时忽略 `is_focus`，不响应任何事件。
+    pub disabled: bool,
+    /// 整体样式。
+    pub style: Style,
+    /// 光标所在行样式。
+    pub highlight_style: Style,
+    /// 选中行样式。
+    pub selected_style: Style,
+    /// 缩进与展开标记样式。
+    pub guide_style: Style,
+}
+
+// 展开后可见的一行
+struct FlatRow {
+    path: Vec<usize>,
+    depth: usize,
+    has_children: bool,
+}
+
+fn flatten(nodes: &[TreeNode], expanded: &HashSet<Vec<usize>>) -> Vec<FlatRow> {
+    fn walk(
+        nodes: &[TreeNode],
+        expanded: &HashSet<Vec<usize>>,
+        prefix: &mut Vec<usize>,
+        rows: &mut Vec<FlatRow>,
+    ) {
+        for (index, node) in nodes.iter().enumerate() {
+            prefix.push(index);
+            rows.push(FlatRow {
+                path: prefix.clone(),
+                depth: prefix.len() - 1,
+                has_children: !node.children.is_empty(),
+            });
+            if expanded.contains(prefix) {
+                walk(&node.children, expanded, prefix, rows);
+            }
+            prefix.pop();
+        }
+    }
+    let mut rows = Vec::new();
+    walk(nodes, expanded, &mut Vec::new(), &mut rows);
+    rows
+}
+
+fn initial_expanded(nodes: &[TreeNode], prefix: &mut Vec<usize>, set: &mut HashSet<Vec<usize>>) {
+    for (index, node) in nodes.iter().enumerate() {
+        prefix.push(index);
+        if node.expanded {
+            set.insert(prefix.clone());
+        }
+        initial_expanded(&node.children, prefix, set);
+        prefix.pop();
+    }
+}
+
+#[derive(Clone, Default, PartialEq)]
+// 展开的节点和可见行上的选择状态
+struct TreeState {
+    expanded: HashSet<Vec<usize>>,
+    selection: SelectionState,
+}
+
+impl TreeState {
+    fn new(nodes: &[TreeNode], selection: SelectionState) -> Self {
+        let mut expanded = HashSet::new();
+        initial_expanded(nodes, &mut Vec::new(), &mut expanded);
+        Self {
+            expanded,
+            selection,
+        }
+    }
+
+    fn cursor_path(&self, rows: &[FlatRow]) -> Option<Vec<usize>> {
+        self.selection
+            .cursor()
+            .and_then(|i| rows.get(i))
+            .map(|row| row.path.clone())
+    }
+
+    fn selected_paths(&self, rows: &[FlatRow]) -> Vec<Vec<usize>> {
+        self.selection
+            .selected()
+            .into_iter()
+            .filter_map(|i| rows.get(i).map(|row| row.path.clone()))
+            .collect()
+    }
+
+    // 展开或折叠节点，并把选择状态映射到新的可见行上
+    fn set_expanded(&mut self, nodes: &[TreeNode], path: &[usize], expand: bool) {
+        let old_rows = flatten(nodes, &self.expanded);
+        if expand {
+            self.expanded.insert(path.to_vec());
+        } else {
+            self.expanded.remove(path);
+        }
+        let new_rows = flatten(nodes, &self.expanded);
+        let index_of = new_rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| (row.path.as_slice(), i))
+            .collect::<HashMap<_, _>>();
+        self.selection
+            .remap(|i| index_of.get(old_rows.get(i)?.path.as_slice()).copied());
+        // 光标所在节点被折叠隐藏时移到被折叠的节点上
+        if self.selection.cursor().is_none() {
+            if let Some(&index) = index_of.get(path) {
+                self.selection.select(index);
+            }
+        }
+    }
+
+    fn handle_key(&mut self, nodes: &[TreeNode], key: crossterm::event::KeyEvent) {
+        let rows = flatten(nodes, &self.expanded);
+        let cursor = self.selection.cursor();
+        let row = cursor.and_then(|i| rows.get(i));
+        match (key.code, row) {
+            (KeyCode::Right | KeyCode::Char('l') | KeyCode::Enter, Some(row))
+                if row.has_children =>
+            {
+                if self.expanded.contains(&row.path) {
+                    self.selection.move_cursor(1, rows.len(), false);
+                } else {
+                    let path = row.path.clone();
+                    self.set_expanded(nodes, &path, true);
+                }
+            }
+            (KeyCode::Left | KeyCode::Char('h'), Some(row)) => {
+                if self.expanded.contains(&row.path) {
+                    let path = row.path.clone();
+                    self.set_expanded(nodes, &path, false);
+                } else if row.depth > 0 {
+                    let parent = &row.path[..row.path.len() - 1];
+                    if let Some(index) = rows.iter().position(|r| r.path == parent) {
+                        self.selection.select(index);
+                    }
+                }
+            }
+            _ => {
+                self.selection.handle_key(key, rows.len());
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+// 上一次绘制时每个可见行的纵坐标、行索引和展开标记的横坐标
+struct TreeLayout {
+    rows: Vec<(u16, usize, u16)>,
+}
+
+/// TreeView 组件实现。
+pub struct TreeView {
+    nodes: Arc<Vec<TreeNode>>,
+    state: Option<State<TreeState>>,
+    list_state: ListState,
+    layout: Arc<Mutex<TreeLayout>>,
+    disabled: bool,
+    style: Style,
+    highlight_style: Style,
+    selected_style: Style,
+    guide_style: Style,
+}
+
+impl Component for TreeView {
+    type Props<'a> = TreeViewProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            nodes: Arc::new(props.nodes.clone()),
+            state: None,
+            list_state: ListState::default(),
+            layout: Arc::new(Mutex::new(TreeLayout::default())),
+            disabled: props.disabled,
+            style: props.style,
+            highlight_style: props.highlight_style,
+            selected_style: props.selected_style,
+            guide_style: props.guide_style,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        _updater: &mut crate::ComponentUpdater,
+    ) {
+        let nodes = Arc::new(props.nodes.clone());
+        let state = hooks.use_state(|| TreeState::new(&nodes, props.selection_state.clone()));
+
+        hooks.use_effect(
+            || {
+                state.write().selection = props.selection_state.clone();
+            },
+            props.selection_state.clone(),
+        );
+
+        hooks.use_local_events({
+            let mut state = state;
+            let nodes = nodes.clone();
+            let layout = self.layout.clone();
+            let is_focus = props.is_focus;
+            let read_only = props.read_only;
+            let disabled = props.disabled;
+            let mut on_selection_change = props.on_selection_change.take();
+            let mut on_cursor_change = props.on_cursor_change.take();
+            move |event| {
+                if disabled {
+                    return;
+                }
+                let current = state.read().clone();
+                let mut next = current.clone();
+                match event {
+                    Event::Key(key) if is_focus && key.kind == KeyEventKind::Press => {
+                        next.handle_key(&nodes, key);
+                    }
+                    Event::Mouse(mouse_event)
+                        if mouse_event.kind == MouseEventKind::Down(MouseButton::Left) =>
+                    {
+                        let hit = layout
+                            .lock()
+                            .unwrap()
+                            .rows
+                            .iter()
+                            .find(|(y, _, _)| *y == mouse_event.row)
+                            .map(|(_, index, arrow_x)| (*index, *arrow_x));
+                        let rows = flatten(&nodes, &next.expanded);
+                        if let Some((index, arrow_x)) = hit {
+                            match rows.get(index) {
+                                // 点击展开标记切换展开状态
+                                Some(row) if row.has_children && mouse_event.column == arrow_x => {
+                                    let expand = !next.expanded.contains(&row.path);
+                                    let path = row.path.clone();
+                                    next.set_expanded(&nodes, &path, expand);
+                                }
+                                Some(_) => {
+                                    next.selection.handle_click(index, mouse_event.modifiers)
+                                }
+                                None => {}
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+                if next == current {
+                    return;
+                }
+
+                let old_rows = flatten(&nodes, &current.expanded);
+                let new_rows = flatten(&nodes, &next.expanded);
+                let cursor = next.cursor_path(&new_rows);
+                let selected = next.selected_paths(&new_rows);
+                let cursor_changed = cursor != current.cursor_path(&old_rows);
+                let selection_changed = selected != current.selected_paths(&old_rows);
+                // 只读时忽略会修改选中节点的操作
+                if read_only && selection_changed {
+                    return;
+                }
+                state.set(next);
+                if let (true, Some(cursor)) = (cursor_changed, cursor) {
+                    on_cursor_change(cursor);
+                }
+                if selection_changed {
+                    on_selection_change(selected);
+                }
+            }
+        });
+
+        self.nodes = nodes;
+        self.state = Some(state);
+        self.disabled = props.disabled;
+        self.style = props.style;
+        self.highlight_style = props.highlight_style;
+        self.selected_style = props.selected_style;
+        self.guide_style = props.guide_style;
+    }
+
+    fn draw(&mut self, drawer: &mut crate::ComponentDrawer<'_, '_>) {
+        let Some(state) = self.state else {
+            return;
+        };
+        let state = state.read();
+        let rows = flatten(&self.nodes, &state.expanded);
+
+        let items = rows.iter().enumerate().map(|(index, row)| {
+            let Some(node) = tree_node_at(&self.nodes, &row.path) else {
+                return ListItem::new("");
+            };
+            let marker = match (row.has_children, state.expanded.contains(&row.path)) {
+                (true, true) => "▾ ",
+                (true, false) => "▸ ",
+                (false, _) => "  ",
+            };
+            let mut spans = vec![Span::styled(
+                format!("{}{marker}", "  ".repeat(row.depth)),
+                self.guide_style,
+            )];
+            spans.extend(node.label.spans.iter().cloned());
+            let item = ListItem::new(Line::from(spans).style(node.label.style));
+            if state.selection.is_selected(index) {
+                item.style(self.selected_style)
+            } else {
+                item
+            }
+        });
+
+        let list = TuiList::new(items)
+            .style(self.style)
+            .highlight_style(self.highlight_style);
+        self.list_state.select(state.selection.cursor());
+        let area = drawer.area;
+        list.render(area, drawer.buffer_mut(), &mut self.list_state);
+        if self.disabled {
+            drawer.buffer_mut().set_style(area, Style::default().dim());
+        }
+
+        // 记录可见行位置供鼠标交互使用
+        let mut layout = self.layout.lock().unwrap();
+        layout.rows = rows
+            .iter()
+            .enumerate()
+            .skip(self.list_state.offset())
+            .take(area.height as usize)
+            .enumerate()
+            .map(|(line, (index, row))| {
+                (area.y + line as u16, index, area.x + row.depth as u16 * 2)
+            })
+            .collect();
+    }
+}
@@ -0,0 +1,243 @@
+//! VirtualList 组件：虚拟滚动列表，只渲染可见的行，适合配合 `use_paginated_source` 显示大量或懒加载的数据。
+//!
+//! ## 用法示例
+//! ```rust
+//! let users = hooks.use_paginated_source(Users(pool), PaginationOptions::default());
+//!
+//! element!(VirtualList(
+//!     len: users.len(),
+//!     render_item: move |index| users.get(index).map(|user| Line::from(user.name.clone())),
+//!     on_viewport_change: move |range| users.set_viewport(range),
+//!     on_select: move |index| open_user(index),
+//!     is_focus: true,
+//! ))
+//! ```
+//! - 每个条目占一行；`render_item` 返回 None 时显示占位文本。
+//! - 聚焦时 `↑/↓`、`k/j` 移动光标，`PageUp/PageDown` 翻页，`Home/End` 跳到开头/末尾，`Enter` 触发 `on_select`。
+//! - 开启鼠标捕获后支持滚轮滚动和点击选择。
+
+use std::{
+    ops::Range,
+    sync::{Arc, Mutex},
+};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseButton, MouseEventKind};
+use ratatui::{
+    layout::Rect,
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::Widget,
+};
+use ratatui_kit_macros::Props;
+
+use crate::{Component, Handler, State, UseEvents, UseState};
+
+// 鼠标滚轮每次滚动的行数
+const WHEEL_STEP: usize = 3;
+
+#[derive(Props)]
+/// VirtualList 组件属性。
+pub struct VirtualListProps {
+    /// 条目总数。
+    pub len: usize,
+    /// 渲染指定索引的条目，数据尚未就绪时返回 None。
+    pub render_item: Handler<'static, usize, Option<Line<'static>>>,
+    /// 可见范围变化回调，用于按需加载数据。
+    pub on_viewport_change: Handler<'static, Range<usize>>,
+    /// 按 `Enter` 或点击条目时的回调，参数为条目索引。
+    pub on_select: Handler<'static, usize>,
+    /// 是否聚焦，聚焦时响应按键。
+    pub is_focus: bool,
+    /// 数据未就绪时显示的占位文本。
+    pub placeholder: String,
+    /// 整体样式。
+    pub style: Style,
+    /// 光标所在行样式。
+    pub highlight_style: Style,
+    /// 占位文本样式。
+    pub placeholder_style: Style,
+}
+
+impl Default for VirtualListProps {
+    fn default() -> Self {
+        Self {
+            len: 0,
+            render_item: Handler::default(),
+            on_viewport_change: Handler::default(),
+            on_select: Handler::default(),
+            is_focus: false,
+            placeholder: "加载中...".to_string(),
+            style: Style::default(),
+            highlight_style: Style::default().reversed(),
+            placeholder_style: Style::default().dim(),
+        }
+    }
+}
+
+#[derive(Default)]
+// 上一次绘制时的滚动位置和区域，供事件处理使用
+struct VirtualLayout {
+    offset: usize,
+    height: usize,
+    area: Rect,
+}
+
+/// VirtualList 组件实现。
+pub struct VirtualList {
+    len: usize,
+    cursor: Option<State<usize>>,
+    render_item: Handler<'static, usize, Option<Line<'static>>>,
+    on_viewport_change: Handler<'static, Range<usize>>,
+    viewport: Option<Range<usize>>,
+    layout: Arc<Mutex<VirtualLayout>>,
+    placeholder: String,
+    style: Style,
+    highlight_style: Style,
+    placeholder_style: Style,
+}
+
+impl Component for VirtualList {
+    type Props<'a> = VirtualListProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            len: props.len,
+            cursor: None,
+            render_item: Handler::default(),
+            on_viewport_change: Handler::default(),
+            viewport: None,
+            layout: Arc::new(Mutex::new(VirtualLayout::default())),
+            placeholder: props.placeholder.clone(),
+            style: props.style,
+            highlight_style: props.highlight_style,
+            placeholder_style: props.placeholder_style,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: crate::Hooks,
+        _updater: &mut crate::ComponentUpdater,
+    ) {
+        let mut cursor = hooks.use_state(|| 0usize);
+        let len = props.len;
+        if len > 0 && cursor.get() >= len {
+            cursor.set(len - 1);
+        }
+
+        hooks.use_local_events({
+            let layout = self.layout.clone();
+            let is_focus = props.is_focus;
+            let mut on_select = props.on_select.take();
+            move |event| {
+                if len == 0 {
+                    return;
+                }
+                let (page, row_index) = {
+                    let layout = layout.lock().unwrap();
+                    let row_index = |row: u16| {
+                        let index = layout.offset + row.saturating_sub(layout.area.y) as usize;
+                        (index < len).then_some(index)
+                    };
+                    let row_index = match &event {
+                        Event::Mouse(mouse) => row_index(mouse.row),
+                        _ => None,
+                    };
+                    (layout.height.max(1), row_index)
+                };
+                let current = cursor.get();
+                let next = match event {
+                    Event::Key(key) if is_focus && key.kind != KeyEventKind::Release => {
+                        match key.code {
+                            KeyCode::Up | KeyCode::Char('k') => current.saturating_sub(1),
+                            KeyCode::Down | KeyCode::Char('j') => current + 1,
+                            KeyCode::PageUp => current.saturating_sub(page),
+                            KeyCode::PageDown => current + page,
+                            KeyCode::Home => 0,
+                            KeyCode::End => len - 1,
+                            KeyCode::Enter if key.kind == KeyEventKind::Press => {
+                                on_select(current);
+                                return;
+                            }
+                            _ => return,
+                        }
+                    }
+                    Event::Mouse(mouse) => match mouse.kind {
+                        MouseEventKind::ScrollUp => current.saturating_sub(WHEEL_STEP),
+                        MouseEventKind::ScrollDown => current + WHEEL_STEP,
+                        MouseEventKind::Down(MouseButton::Left) => {
+                            let Some(index) = row_index else {
+                                return;
+                            };
+                            on_select(index);
+                            index
+                        }
+                        _ => return,
+                    },
+                    _ => return,
+                };
+                let next = next.min(len - 1);
+                if next != current {
+                    cursor.set(next);
+                }
+            }
+        });
+
+        self.len = len;
+        self.cursor = Some(cursor);
+        self.render_item = props.render_item.take();
+        self.on_viewport_change = props.on_viewport_change.take();
+        self.placeholder = props.placeholder.clone();
+        self.style = props.style;
+        self.highlight_style = props.highlight_style;
+        self.placeholder_style = props.placeholder_style;
+    }
+
+    fn draw(&mut self, drawer: &mut crate::ComponentDrawer<'_, '_>) {
+        let area = drawer.area;
+        let height = area.height as usize;
+        let cursor = self.cursor.map_or(0, |cursor| cursor.get());
+
+        // 滚动到光标可见
+        let offset = {
+            let mut layout = self.layout.lock().unwrap();
+            let mut offset = layout.offset.min(self.len.saturating_sub(height));
+            if cursor < offset {
+                offset = cursor;
+            } else if height > 0 && cursor >= offset + height {
+                offset = cursor + 1 - height;
+            }
+            layout.offset = offset;
+            layout.height = height;
+            layout.area = area;
+            offset
+        };
+
+        let viewport = offset..(offset + height).min(self.len);
+        if self.viewport.as_ref() != Some(&viewport) {
+            self.viewport = Some(viewport.clone());
+            (self.on_viewport_change)(viewport.clone());
+        }
+
+        let buf = drawer.buffer_mut();
+        buf.set_style(area, self.style);
+        for (row, index) in viewport.enumerate() {
+            let line = (self.render_item)(index).unwrap_or_else(|| {
+                Line::from(Span::styled(
+                    self.placeholder.clone(),
+                    self.placeholder_style,
+                ))
+            });
+            let row_area = Rect {
+                y: area.y + row as u16,
+                height: 1,
+                ..area
+            };
+            if index == cursor {
+                buf.set_style(row_area, self.highlight_style);
+            }
+            line.render(row_area, buf);
+        }
+    }
+}
@@ -0,0 +1,228 @@
+//! Wizard 组件：多步骤向导，每个子元素为一个步骤，顶部显示进度，支持前进/后退与逐步校验。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(Wizard(
+//!     titles: vec!["欢迎".to_string(), "配置".to_string(), "完成".to_string()],
+//!     valid: vec![true, !name.read().is_empty(), true],
+//!     on_complete: move |_| should_exit.set(true),
+//! ){
+//!     WelcomeStep()
+//!     ConfigStep()
+//!     FinishStep()
+//! })
+//! ```
+//! `Ctrl+N` 前进（最后一步时完成），`Ctrl+P` 后退；当前步骤在 `valid` 中为 false 时无法前进。
+
+use crate::{AnyElement, Component, Handler, Hooks, State, UseEffect, UseEvents, UseState};
+use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::Widget,
+};
+use ratatui_kit_macros::{Props, with_layout_style};
+
+#[with_layout_style]
+#[derive(Props)]
+/// Wizard 组件属性。
+pub struct WizardProps<'a> {
+    /// 步骤内容，每个子元素为一个步骤。
+    pub children: Vec<AnyElement<'a>>,
+    /// 各步骤标题，缺省时显示序号。
+    pub titles: Vec<String>,
+    /// 各步骤是否通过校验，缺省视为通过。
+    pub valid: Vec<bool>,
+    /// 初始步骤，变化时跳转到该步骤。
+    pub initial_step: usize,
+    /// 步骤切换回调。
+    pub on_step_change: Handler<'static, usize>,
+    /// 在最后一步前进时触发。
+    pub on_complete: Handler<'static, ()>,
+    /// 进度标题样式。
+    pub header_style: Style,
+    /// 当前步骤标题样式。
+    pub active_style: Style,
+    /// 已完成步骤标题样式。
+    pub done_style: Style,
+    /// 底部按键提示样式。
+    pub hint_style: Style,
+    /// 是否显示底部按键提示。
+    pub show_hint: bool,
+}
+
+impl Default for WizardProps<'_> {
+    fn default() -> Self {
+        Self {
+            children: Vec::new(),
+            titles: Vec::new(),
+            valid: Vec::new(),
+            initial_step: 0,
+            on_step_change: Handler::default(),
+            on_complete: Handler::default(),
+            header_style: Style::default().dim(),
+            active_style: Style::default().bold(),
+            done_style: Style::default().green(),
+            hint_style: Style::default().dim(),
+            show_hint: true,
+            margin: Default::default(),
+            offset: Default::default(),
+            width: Default::default(),
+            height: Default::default(),
+            gap: Default::default(),
+            flex_direction: Default::default(),
+            justify_content: Default::default(),
+        }
+    }
+}
+
+/// Wizard 组件实现。
+pub struct Wizard {
+    step: Option<State<usize>>,
+    titles: Vec<String>,
+    step_count: usize,
+    can_next: bool,
+    header_style: Style,
+    active_style: Style,
+    done_style: Style,
+    hint_style: Style,
+    show_hint: bool,
+}
+
+impl Component for Wizard {
+    type Props<'a> = WizardProps<'a>;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            step: None,
+            titles: props.titles.clone(),
+            step_count: props.children.len(),
+            can_next: true,
+            header_style: props.header_style,
+            active_style: props.active_style,
+            done_style: props.done_style,
+            hint_style: props.hint_style,
+            show_hint: props.show_hint,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut crate::ComponentUpdater,
+    ) {
+        let layout_style = props.layout_style();
+        let step_count = props.children.len();
+        let step = hooks.use_state(|| props.initial_step);
+
+        hooks.use_effect(
+            || {
+                *step.write() = props.initial_step;
+            },
+            props.initial_step,
+        );
+
+        let current = (*step.read()).min(step_count.saturating_sub(1));
+        let can_next = props.valid.get(current).copied().unwrap_or(true);
+
+        hooks.use_local_events({
+            let mut step = step;
+            let mut on_step_change = props.on_step_change.take();
+            let mut on_complete = props.on_complete.take();
+            move |event| {
+                let Event::Key(key) = event else {
+                    return;
+                };
+                if key.kind != KeyEventKind::Press || !key.modifiers.contains(KeyModifiers::CONTROL)
+                {
+                    return;
+                }
+                match key.code {
+                    KeyCode::Char('n') if can_next => {
+                        if current + 1 < step_count {
+                            step.set(current + 1);
+                            on_step_change(current + 1);
+                        } else {
+                            on_complete(());
+                        }
+                    }
+                    KeyCode::Char('p') if current > 0 => {
+                        step.set(current - 1);
+                        on_step_change(current - 1);
+                    }
+                    _ => {}
+                }
+            }
+        });
+
+        self.step = Some(step);
+        self.titles = props.titles.clone();
+        self.step_count = step_count;
+        self.can_next = can_next;
+        self.header_style = props.header_style;
+        self.active_style = props.active_style;
+        self.done_style = props.done_style;
+        self.hint_style = props.hint_style;
+        self.show_hint = props.show_hint;
+
+        updater.set_layout_style(layout_style);
+        // 只挂载当前步骤
+        updater.update_children(props.children.iter_mut().skip(current).take(1), None);
+    }
+
+    fn draw(&mut self, drawer: &mut crate::ComponentDrawer<'_, '_>) {
+        let Some(step) = self.step else {
+            return;
+        };
+        let current = (*step.read()).min(self.step_count.saturating_sub(1));
+
+        let [header_area, content_area, hint_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Fill(1),
+            Constraint::Length(if self.show_hint { 1 } else { 0 }),
+        ])
+        .areas(drawer.area);
+
+        // 进度标题：已完成 ✔，当前 ●，未开始 ○
+        let mut spans = Vec::new();
+        for index in 0..self.step_count {
+            if index > 0 {
+                spans.push(Span::styled(" ─ ", self.header_style));
+            }
+            let title = self
+                .titles
+                .get(index)
+                .cloned()
+                .unwrap_or_else(|| format!("步骤 {}", index + 1));
+            let span = match index.cmp(&current) {
+                std::cmp::Ordering::Less => Span::styled(format!("✔ {title}"), self.done_style),
+                std::cmp::Ordering::Equal => Span::styled(format!("● {title}"), self.active_style),
+                std::cmp::Ordering::Greater => {
+                    Span::styled(format!("○ {title}"), self.header_style)
+                }
+            };
+            spans.push(span);
+        }
+        Line::from(spans).render(header_area, drawer.buffer_mut());
+
+        if self.show_hint {
+            let mut hints = Vec::new();
+            if current > 0 {
+                hints.push("Ctrl+P 上一步");
+            }
+            if !self.can_next {
+                hints.push("请完成当前步骤");
+            } else if current + 1 < self.step_count {
+                hints.push("Ctrl+N 下一步");
+            } else {
+                hints.push("Ctrl+N 完成");
+            }
+            Line::styled(hints.join("  "), self.hint_style).render(hint_area, drawer.buffer_mut());
+        }
+
+        // 子组件绘制在进度标题与提示之间
+        drawer.area = content_area;
+    }
+}
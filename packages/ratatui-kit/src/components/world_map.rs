@@ -0,0 +1,360 @@
+//! WorldMap 组件：世界地图，显示经纬度标记，支持平移、缩放和选择标记。
+//!
+//! ## 用法示例
+//! ```rust
+//! element!(WorldMap(
+//!     markers: vec![
+//!         MapMarker::new("Tokyo", 139.69, 35.68).color(Color::Green),
+//!         MapMarker::new("Frankfurt", 8.68, 50.11).color(Color::Red),
+//!     ],
+//!     is_focus: true,
+//!     on_marker_select: move |index| selected.set(Some(index)),
+//! ))
+//! ```
+//!
+//! 聚焦时支持以下按键：
+//! - `←/→/↑/↓`：平移，`+/-`：缩放，`0`：显示全图
+//! - `Tab/Shift+Tab`：在标记间切换，`Enter`：选择当前标记
+//!
+//! 开启鼠标捕获后，滚轮以鼠标位置为中心缩放，拖拽平移，点击标记直接选择。
+
+use std::sync::{Arc, Mutex};
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, MouseButton, MouseEventKind};
+use ratatui::{
+    layout::Position,
+    style::{Color, Style, Stylize},
+    symbols::Marker,
+    text::{Line, Span},
+    widgets::canvas::MapResolution,
+};
+use ratatui_kit_macros::Props;
+
+use super::{CanvasShape, CanvasTransform, canvas::CanvasRenderer};
+use crate::{Component, Handler, Hooks, State, UseEvents, UseState};
+
+const WORLD_X: [f64; 2] = [-180.0, 180.0];
+const WORLD_Y: [f64; 2] = [-90.0, 90.0];
+
+#[derive(Debug, Clone, PartialEq)]
+/// 地图标记。
+pub struct MapMarker {
+    /// 标签。
+    pub label: String,
+    /// 经度。
+    pub lon: f64,
+    /// 纬度。
+    pub lat: f64,
+    /// 标记颜色。
+    pub color: Color,
+}
+
+impl MapMarker {
+    pub fn new(label: impl Into<String>, lon: f64, lat: f64) -> Self {
+        Self {
+            label: label.into(),
+            lon,
+            lat,
+            color: Color::Yellow,
+        }
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+#[derive(Props)]
+/// WorldMap 组件属性。
+pub struct WorldMapProps {
+    /// 标记列表。
+    pub markers: Vec<MapMarker>,
+    /// 地图精度。
+    pub resolution: MapResolution,
+    /// 地图轮廓颜色。
+    pub map_color: Color,
+    /// 绘制地图轮廓使用的符号。
+    pub marker: Marker,
+    /// 标记符号。
+    pub symbol: String,
+    /// 是否显示标记的标签。
+    pub show_labels: bool,
+    /// 是否聚焦，聚焦时响应按键。
+    pub is_focus: bool,
+    /// 选择标记时的回调，参数为标记索引。
+    pub on_marker_select: Handler<'static, usize>,
+    /// 当前标记的样式。
+    pub selected_style: Style,
+}
+
+impl Default for WorldMapProps {
+    fn default() -> Self {
+        Self {
+            markers: Vec::new(),
+            resolution: MapResolution::High,
+            map_color: Color::DarkGray,
+            marker: Marker::Braille,
+            symbol: "●".to_string(),
+            show_labels: true,
+            is_focus: false,
+            on_marker_select: Handler::default(),
+            selected_style: Style::default().reversed(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+// 可视范围与当前标记
+struct MapView {
+    x: [f64; 2],
+    y: [f64; 2],
+    selected: Option<usize>,
+}
+
+impl Default for MapView {
+    fn default() -> Self {
+        Self {
+            x: WORLD_X,
+            y: WORLD_Y,
+            selected: None,
+        }
+    }
+}
+
+impl MapView {
+    fn transform(&self) -> CanvasTransform {
+        CanvasTransform::new(Default::default(), self.x, self.y)
+    }
+
+    fn set_transform(&mut self, transform: CanvasTransform) {
+        // 最多缩小到全图，最多放大到横向 1 度，并且不移出地图范围
+        let width = transform.width().clamp(1.0, 360.0);
+        let height = transform.height().clamp(0.5, 180.0);
+        let cx = ((transform.x_bounds[0] + transform.x_bounds[1]) / 2.0)
+            .clamp(WORLD_X[0] + width / 2.0, WORLD_X[1] - width / 2.0);
+        let cy = ((transform.y_bounds[0] + transform.y_bounds[1]) / 2.0)
+            .clamp(WORLD_Y[0] + height / 2.0, WORLD_Y[1] - height / 2.0);
+        self.x = [cx - width / 2.0, cx + width / 2.0];
+        self.y = [cy - height / 2.0, cy + height / 2.0];
+    }
+
+    fn zoom(&mut self, factor: f64, center: (f64, f64)) {
+        let mut transform = self.transform();
+        transform.zoom(factor, center.0, center.1);
+        self.set_transform(transform);
+    }
+
+    fn pan(&mut self, dx: f64, dy: f64) {
+        let mut transform = self.transform();
+        transform.pan(dx, dy);
+        self.set_transform(transform);
+    }
+
+    fn center(&self) -> (f64, f64) {
+        ((self.x[0] + self.x[1]) / 2.0, (self.y[0] + self.y[1]) / 2.0)
+    }
+}
+
+#[derive(Default)]
+struct MapLayout {
+    transform: CanvasTransform,
+    // 标记在屏幕上的位置
+    markers: Vec<Option<Position>>,
+    // 拖拽起点及当时的可视范围
+    drag: Option<(u16, u16, MapView)>,
+}
+
+/// WorldMap 组件实现。
+pub struct WorldMap {
+    markers: Vec<MapMarker>,
+    view: Option<State<MapView>>,
+    layout: Arc<Mutex<MapLayout>>,
+    renderer: CanvasRenderer,
+    resolution: MapResolution,
+    map_color: Color,
+    marker: Marker,
+    symbol: String,
+    show_labels: bool,
+    selected_style: Style,
+}
+
+impl Component for WorldMap {
+    type Props<'a> = WorldMapProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            markers: props.markers.clone(),
+            view: None,
+            layout: Arc::new(Mutex::new(MapLayout::default())),
+            renderer: CanvasRenderer::default(),
+            resolution: props.resolution,
+            map_color: props.map_color,
+            marker: props.marker,
+            symbol: props.symbol.clone(),
+            show_labels: props.show_labels,
+            selected_style: props.selected_style,
+        }
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        _updater: &mut crate::ComponentUpdater,
+    ) {
+        let mut view = hooks.use_state(MapView::default);
+        let len = props.markers.len();
+        if view.read().selected.is_some_and(|i| i >= len) {
+            view.write().selected = None;
+        }
+
+        hooks.use_local_events({
+            let layout = self.layout.clone();
+            let markers = props
+                .markers
+                .iter()
+                .map(|m| (m.lon, m.lat))
+                .collect::<Vec<_>>();
+            let is_focus = props.is_focus;
+            let mut on_marker_select = props.on_marker_select.take();
+            move |event| {
+                let mut next = *view.read();
+                let mut layout = layout.lock().unwrap();
+                let mut select = None;
+                match event {
+                    Event::Key(key) if is_focus && key.kind != KeyEventKind::Release => {
+                        let (step_x, step_y) = (
+                            (next.x[1] - next.x[0]) / 10.0,
+                            (next.y[1] - next.y[0]) / 10.0,
+                        );
+                        // 以当前标记为中心缩放，没有标记时以视图中心缩放
+                        let center = next.selected.map_or(next.center(), |i| markers[i]);
+                        match key.code {
+                            KeyCode::Left => next.pan(-step_x, 0.0),
+                            KeyCode::Right => next.pan(step_x, 0.0),
+                            KeyCode::Up => next.pan(0.0, step_y),
+                            KeyCode::Down => next.pan(0.0, -step_y),
+                            KeyCode::Char('+' | '=') => next.zoom(0.8, center),
+                            KeyCode::Char('-') => next.zoom(1.25, center),
+                            KeyCode::Char('0') => next = MapView::default(),
+                            KeyCode::Tab if len > 0 => {
+                                next.selected = Some(next.selected.map_or(0, |i| (i + 1) % len));
+                            }
+                            KeyCode::BackTab if len > 0 => {
+                                next.selected =
+                                    Some(next.selected.map_or(len - 1, |i| (i + len - 1) % len));
+                            }
+                            KeyCode::Enter => select = next.selected,
+                            _ => return,
+                        }
+                    }
+                    Event::Mouse(mouse) => {
+                        let position = Position::new(mouse.column, mouse.row);
+                        match mouse.kind {
+                            MouseEventKind::ScrollUp | MouseEventKind::ScrollDown => {
+                                let factor = if mouse.kind == MouseEventKind::ScrollUp {
+                                    0.8
+                                } else {
+                                    1.25
+                                };
+                                let Some(center) = layout.transform.to_world(position) else {
+                                    return;
+                                };
+                                next.zoom(factor, center);
+                            }
+                            MouseEventKind::Down(MouseButton::Left) => {
+                                // 点击标记时选择，否则开始拖拽
+                                let hit = layout.markers.iter().position(|p| {
+                                    p.is_some_and(|p| {
+                                        p.y == position.y && p.x.abs_diff(position.x) <= 1
+                                    })
+                                });
+                                match hit {
+                                    Some(index) => {
+                                        next.selected = Some(index);
+                                        select = Some(index);
+                                    }
+                                    None => layout.drag = Some((mouse.column, mouse.row, next)),
+                                }
+                            }
+                            MouseEventKind::Drag(MouseButton::Left) => {
+                                let Some((column, row, start)) = layout.drag else {
+                                    return;
+                                };
+                                let area = layout.transform.area;
+                                let dx = (column as f64 - mouse.column as f64)
+                                    / area.width.max(1) as f64;
+                                let dy =
+                                    (mouse.row as f64 - row as f64) / area.height.max(1) as f64;
+                                next = start;
+                                next.pan(
+                                    dx * (start.x[1] - start.x[0]),
+                                    dy * (start.y[1] - start.y[0]),
+                                );
+                            }
+                            MouseEventKind::Up(MouseButton::Left) => {
+                                layout.drag = None;
+                                return;
+                            }
+                            _ => return,
+                        }
+                    }
+                    _ => return,
+                }
+                drop(layout);
+                if next != *view.read() {
+                    view.set(next);
+                }
+                if let Some(index) = select {
+                    on_marker_select(index);
+                }
+            }
+        });
+
+        self.markers = props.markers.clone();
+        self.view = Some(view);
+        self.resolution = props.resolution;
+        self.map_color = props.map_color;
+        self.marker = props.marker;
+        self.symbol = props.symbol.clone();
+        self.show_labels = props.show_labels;
+        self.selected_style = props.selected_style;
+    }
+
+    fn draw(&mut self, drawer: &mut crate::ComponentDrawer<'_, '_>) {
+        let Some(view) = self.view else {
+            return;
+        };
+        let view = *view.read();
+        let transform = CanvasTransform::new(drawer.area, view.x, view.y);
+
+        let mut shapes = vec![CanvasShape::map(self.resolution, self.map_color)];
+        for (i, marker) in self.markers.iter().enumerate() {
+            let mut spans = vec![Span::styled(self.symbol.clone(), marker.color)];
+            if self.show_labels {
+                spans.push(Span::raw(format!(" {}", marker.label)));
+            }
+            let mut line = Line::from(spans);
+            if view.selected == Some(i) {
+                line = line.patch_style(self.selected_style);
+            }
+            shapes.push(CanvasShape::label(marker.lon, marker.lat, line));
+        }
+        self.renderer.render(
+            &shapes,
+            transform,
+            self.marker,
+            Color::Reset,
+            drawer.buffer_mut(),
+        );
+
+        let mut layout = self.layout.lock().unwrap();
+        layout.transform = transform;
+        layout.markers = self
+            .markers
+            .iter()
+            .map(|m| transform.to_screen(m.lon, m.lat))
+            .collect();
+    }
+}
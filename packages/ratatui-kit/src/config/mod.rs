@@ -0,0 +1,271 @@
+//! 配置模块：分层加载应用设置（默认值 < 配置文件 < 环境变量），以响应式 Store 的形式提供给组件。
+//!
+//! ## 用法示例
+//! ```rust
+//! static CONFIG: LazyLock<Config> = LazyLock::new(|| {
+//!     Config::new(json!({
+//!         "theme": "dark",
+//!         "editor": { "tab_size": 4, "line_numbers": true },
+//!     }))
+//!     .with_file("settings.json")
+//!     .with_env_prefix("MYAPP")
+//!     .load()
+//!     .expect("failed to load settings")
+//! });
+//!
+//! // 在组件中订阅，配置变化（包括外部修改配置文件）时重新渲染
+//! let config = hooks.use_config(&CONFIG);
+//! let tab_size = config.get::<u16>("editor.tab_size").unwrap_or(4);
+//! ```
+//! - 嵌套对象展开为以 `.` 分隔的键，如 `editor.tab_size`。
+//! - 环境变量名为前缀加上大写的键，`.` 替换为 `_`，如 `MYAPP_EDITOR_TAB_SIZE`；
+//!   只读取默认值中存在的键，值按 JSON 解析，解析失败时作为字符串。
+//! - [`Config::set`] 修改配置文件层并立即写回文件，环境变量中设置的键仍以环境变量为准。
+//! - 配置文件为 JSON 格式，不存在时视为空。
+
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use std::{
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::StoreState;
+
+mod use_config;
+pub use use_config::*;
+
+/// 配置项的来源。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigSource {
+    Default,
+    File,
+    Env,
+}
+
+/// 单个配置项，供设置页面等展示。
+#[derive(Debug, Clone)]
+pub struct ConfigEntry {
+    /// 以 `.` 分隔的键。
+    pub key: String,
+    /// 当前生效的值。
+    pub value: Value,
+    /// 默认值。
+    pub default: Value,
+    /// 当前值的来源。
+    pub source: ConfigSource,
+}
+
+#[derive(Default, PartialEq)]
+struct ConfigLayers {
+    file: Map<String, Value>,
+    env: Map<String, Value>,
+}
+
+/// 分层配置，克隆后共享同一份状态。
+#[derive(Clone)]
+pub struct Config {
+    defaults: Arc<Map<String, Value>>,
+    path: Option<PathBuf>,
+    env_prefix: Option<String>,
+    layers: StoreState<ConfigLayers>,
+}
+
+impl Config {
+    /// 以 `defaults` 作为默认值创建配置，`defaults` 应为 JSON 对象。
+    pub fn new(defaults: Value) -> Self {
+        let mut flat = Map::new();
+        flatten("", defaults, &mut flat);
+        Self {
+            defaults: Arc::new(flat),
+            path: None,
+            env_prefix: None,
+            layers: StoreState::new(ConfigLayers::default()),
+        }
+    }
+
+    /// 设置配置文件路径，调用 [`Config::load`] 后生效。
+    pub fn with_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// 设置环境变量前缀，调用 [`Config::load`] 后生效。
+    pub fn with_env_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.env_prefix = Some(prefix.into());
+        self
+    }
+
+    /// 读取配置文件和环境变量。
+    pub fn load(self) -> io::Result<Self> {
+        self.reload()?;
+        Ok(self)
+    }
+
+    /// 重新读取配置文件和环境变量，内容变化时通知订阅的组件。
+    ///
+    /// 配置文件不是合法的 JSON 时返回错误并保留当前值。
+    pub fn reload(&self) -> io::Result<()> {
+        let layers = ConfigLayers {
+            file: self.read_file()?,
+            env: self.read_env(),
+        };
+        if *self.layers.read() != layers {
+            *self.layers.write() = layers;
+        }
+        Ok(())
+    }
+
+    /// 配置文件路径。
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// 读取配置项并反序列化为 `T`，不存在或类型不符时返回 None。
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        serde_json::from_value(self.value(key)?).ok()
+    }
+
+    /// 读取配置项当前生效的值。
+    pub fn value(&self, key: &str) -> Option<Value> {
+        let layers = self.layers.read();
+        layers
+            .env
+            .get(key)
+            .or_else(|| layers.file.get(key))
+            .or_else(|| self.defaults.get(key))
+            .cloned()
+    }
+
+    /// 修改配置项并写回配置文件。
+    pub fn set(&self, key: impl Into<String>, value: impl Into<Value>) -> io::Result<()> {
+        self.layers.write().file.insert(key.into(), value.into());
+        self.save()
+    }
+
+    /// 把配置项恢复为默认值并写回配置文件。
+    pub fn reset(&self, key: &str) -> io::Result<()> {
+        if self.layers.read().file.contains_key(key) {
+            self.layers.write().file.remove(key);
+        }
+        self.save()
+    }
+
+    /// 把配置文件层写回文件，未设置文件路径时不做任何事。
+    pub fn save(&self) -> io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let content = serde_json::to_string_pretty(&unflatten(&self.layers.read().file))?;
+        std::fs::write(path, content + "\n")
+    }
+
+    /// 所有配置项，包括默认值中的键和配置文件中额外的键，按键排序。
+    pub fn entries(&self) -> Vec<ConfigEntry> {
+        let layers = self.layers.read();
+        let mut keys = self
+            .defaults
+            .keys()
+            .chain(layers.file.keys())
+            .cloned()
+            .collect::<Vec<_>>();
+        keys.sort();
+        keys.dedup();
+        keys.into_iter()
+            .map(|key| {
+                let default = self.defaults.get(&key).cloned().unwrap_or(Value::Null);
+                let (value, source) = if let Some(value) = layers.env.get(&key) {
+                    (value.clone(), ConfigSource::Env)
+                } else if let Some(value) = layers.file.get(&key) {
+                    (value.clone(), ConfigSource::File)
+                } else {
+                    (default.clone(), ConfigSource::Default)
+                };
+                ConfigEntry {
+                    key,
+                    value,
+                    default,
+                    source,
+                }
+            })
+            .collect()
+    }
+
+    fn read_file(&self) -> io::Result<Map<String, Value>> {
+        let mut flat = Map::new();
+        let Some(path) = &self.path else {
+            return Ok(flat);
+        };
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(flat),
+            Err(err) => return Err(err),
+        };
+        if !content.trim().is_empty() {
+            flatten("", serde_json::from_str(&content)?, &mut flat);
+        }
+        Ok(flat)
+    }
+
+    fn read_env(&self) -> Map<String, Value> {
+        let Some(prefix) = &self.env_prefix else {
+            return Map::new();
+        };
+        self.defaults
+            .iter()
+            .filter_map(|(key, default)| {
+                let name = format!("{prefix}_{}", key.replace(['.', '-'], "_")).to_uppercase();
+                let raw = std::env::var(name).ok()?;
+                // 字符串类型的配置项保持原样，避免 "123" 之类的值被解析为数字
+                let value = match default {
+                    Value::String(_) => Value::String(raw),
+                    _ => serde_json::from_str(&raw).unwrap_or(Value::String(raw)),
+                };
+                Some((key.clone(), value))
+            })
+            .collect()
+    }
+}
+
+// 把嵌套对象展开为以 `.` 分隔的键，数组和其他值作为叶子
+fn flatten(prefix: &str, value: Value, out: &mut Map<String, Value>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let key = if prefix.is_empty() {
+                    key
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                flatten(&key, value, out);
+            }
+        }
+        value if !prefix.is_empty() => {
+            out.insert(prefix.to_string(), value);
+        }
+        _ => {}
+    }
+}
+
+fn unflatten(flat: &Map<String, Value>) -> Value {
+    let mut root = Map::new();
+    for (key, value) in flat {
+        let mut parts = key.split('.').peekable();
+        let mut map = &mut root;
+        while let Some(part) = parts.next() {
+            if parts.peek().is_none() {
+                map.insert(part.to_string(), value.clone());
+                break;
+            }
+            let entry = map
+                .entry(part.to_string())
+                .or_insert_with(|| Value::Object(Map::new()));
+            if !entry.is_object() {
+                *entry = Value::Object(Map::new());
+            }
+            map = entry.as_object_mut().unwrap();
+        }
+    }
+    Value::Object(root)
+}
@@ -0,0 +1,27 @@
+use std::path::Path;
+
+use crate::{Config, UseStore, UseWatchFile};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+pub trait UseConfig: private::Sealed {
+    /// 订阅配置，配置变化时重新渲染当前组件。
+    ///
+    /// 同时监视配置文件，文件被外部修改后自动重新加载；文件内容无效时保留当前值。
+    fn use_config(&mut self, config: &Config) -> Config;
+}
+
+impl UseConfig for crate::Hooks<'_, '_> {
+    fn use_config(&mut self, config: &Config) -> Config {
+        self.use_store(config.layers);
+        let reload = config.clone();
+        // 没有配置文件时监视空路径，不会产生任何事件
+        self.use_watch_file(config.path().unwrap_or(Path::new("")), move |_| {
+            let _ = reload.reload();
+        });
+        config.clone()
+    }
+}
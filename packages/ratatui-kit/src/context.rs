@@ -4,10 +4,12 @@
 //! - [`Context`]：通用上下文枚举，支持所有权、不可变/可变引用三种模式。
 //! - [`ContextStack`]：上下文栈，支持嵌套作用域和动态查找。
 //! - [`SystemContext`]：系统级上下文，控制全局退出等。
+//! - [`AppExit`]：渲染循环结束时的结果，携带 [`SystemContext::exit_with`] 传出的值。
 
 use std::{
     any::Any,
-    cell::{Ref, RefCell, RefMut},
+    cell::{Ref, RefCell, RefMut, UnsafeCell},
+    rc::Rc,
 };
 
 /// 通用上下文类型，支持所有权、不可变引用、可变引用三种模式。
@@ -49,6 +51,16 @@ impl<'a> Context<'a> {
         }
     }
 
+    // 以不可变引用取出上下文
+    #[cfg(feature = "parallel")]
+    fn as_any(&self) -> &(dyn Any + Send + Sync) {
+        match self {
+            Context::Ref(context) => *context,
+            Context::Mut(context) => &**context,
+            Context::Owned(context) => &**context,
+        }
+    }
+
     pub fn borrow(&mut self) -> Context {
         match self {
             Context::Ref(context) => Context::Ref(*context),
@@ -58,36 +70,87 @@ impl<'a> Context<'a> {
     }
 }
 
+/// 上下文栈，组件更新时沿组件树逐层压入上下文。
+///
+/// 栈通过共享引用同时交给组件的 [`Hooks`](crate::Hooks) 和 [`ComponentUpdater`](crate::ComponentUpdater)，
+/// 压入和弹出只发生在 [`ContextStack::with_context`] 中。
 pub struct ContextStack<'a> {
-    stack: Vec<RefCell<Context<'a>>>,
+    // 每个上下文单独分配在堆上，压栈时 Vec 扩容不会移动已借出的上下文
+    stack: UnsafeCell<Vec<Rc<RefCell<Context<'a>>>>>,
 }
 
 impl<'a> ContextStack<'a> {
     pub(crate) fn root(root_context: &'a mut (dyn Any + Send + Sync)) -> Self {
         ContextStack {
-            stack: vec![RefCell::new(Context::Mut(root_context))],
+            stack: UnsafeCell::new(vec![Rc::new(RefCell::new(Context::Mut(root_context)))]),
         }
     }
+
     // 在上下文栈中临时插入一个新的上下文，并在闭包 f 执行期间可用。
-    pub(crate) fn with_context<'b, F>(&'b mut self, context: Option<Context<'b>>, f: F)
+    pub(crate) fn with_context<'b, F>(&'b self, context: Option<Context<'b>>, f: F)
     where
-        F: FnOnce(&mut ContextStack),
+        F: FnOnce(&ContextStack),
     {
-        if let Some(context) = context {
-            // SAFETY: 可变引用在生命周期上是不变的，为了插入更短生命周期的上下文，需要对 'a 进行转变。
-            // 只有在不允许对栈进行其他更改，并且在调用后立即恢复栈的情况下才是安全的。
-            let shorter_lived_self =
-                unsafe { std::mem::transmute::<&mut Self, &mut ContextStack<'b>>(self) };
-            shorter_lived_self.stack.push(RefCell::new(context));
-            f(shorter_lived_self);
-            shorter_lived_self.stack.pop();
-        } else {
+        let Some(context) = context else {
             f(self);
+            return;
         };
+        // SAFETY: 上下文栈的生命周期参数是不变的，为了插入更短生命周期的上下文，需要对 'a 进行转变。
+        // 插入的上下文只在 f 执行期间可见（闭包参数的借用不能逃逸），f 返回或 panic 时立即弹出。
+        let shorter_lived_self = unsafe { std::mem::transmute::<&Self, &ContextStack<'b>>(self) };
+        struct Pop<'s, 'b>(&'s ContextStack<'b>);
+        impl Drop for Pop<'_, '_> {
+            fn drop(&mut self) {
+                // SAFETY: 同 push，Vec 只在这里和 with_context 中被修改，此时没有对 Vec 本身的引用
+                unsafe { (*self.0.stack.get()).pop() };
+            }
+        }
+        // SAFETY: 借出的上下文引用指向各自的堆分配而不是 Vec，修改 Vec 不会使其失效
+        unsafe { (*shorter_lived_self.stack.get()).push(Rc::new(RefCell::new(context))) };
+        let _pop = Pop(shorter_lived_self);
+        f(shorter_lived_self);
+    }
+
+    // 由栈顶到栈底遍历上下文
+    fn iter(&self) -> impl Iterator<Item = &RefCell<Context<'a>>> {
+        // SAFETY: 每次只临时读取 Vec 取出元素的堆地址，元素在弹出前一直有效，
+        // 而弹出的上下文只在压入它的 with_context 调用期间可见
+        let len = unsafe { (*self.stack.get()).len() };
+        (0..len)
+            .rev()
+            .map(move |index| unsafe { &*Rc::as_ptr(&(&*self.stack.get())[index]) })
+    }
+
+    /// 以只读方式共享栈中当前可借用的上下文，供并行更新的子树通过 [`ContextStack::read_only`] 重建上下文栈。
+    #[cfg(feature = "parallel")]
+    pub(crate) fn with_shared<R>(&self, f: impl FnOnce(&[&(dyn Any + Send + Sync)]) -> R) -> R {
+        let mut borrows = self
+            .iter()
+            .filter_map(|context| context.try_borrow().ok())
+            .collect::<Vec<_>>();
+        borrows.reverse();
+        let contexts = borrows
+            .iter()
+            .map(|context| context.as_any())
+            .collect::<Vec<_>>();
+        f(&contexts)
+    }
+
+    /// 由共享的上下文构建只读的上下文栈，其中的上下文无法通过 `get_context_mut` 获取。
+    #[cfg(feature = "parallel")]
+    pub(crate) fn read_only(contexts: &[&'a (dyn Any + Send + Sync)]) -> Self {
+        ContextStack {
+            stack: UnsafeCell::new(
+                contexts
+                    .iter()
+                    .map(|context| Rc::new(RefCell::new(Context::Ref(*context))))
+                    .collect(),
+            ),
+        }
     }
 
     pub fn get_context<T: Any>(&self) -> Option<Ref<T>> {
-        for context in self.stack.iter().rev() {
+        for context in self.iter() {
             if let Ok(context) = context.try_borrow() {
                 if let Ok(res) = Ref::filter_map(context, |context| context.downcast_ref::<T>()) {
                     return Some(res);
@@ -98,7 +161,7 @@ impl<'a> ContextStack<'a> {
     }
 
     pub fn get_context_mut<T: Any>(&self) -> Option<RefMut<T>> {
-        for context in self.stack.iter().rev() {
+        for context in self.iter() {
             if let Ok(context) = context.try_borrow_mut() {
                 if let Ok(res) = RefMut::filter_map(context, |context| context.downcast_mut::<T>())
                 {
@@ -112,6 +175,7 @@ impl<'a> ContextStack<'a> {
 
 pub struct SystemContext {
     should_exit: bool,
+    exit_value: Option<Box<dyn Any + Send>>,
 }
 
 unsafe impl Send for SystemContext {}
@@ -119,7 +183,10 @@ unsafe impl Sync for SystemContext {}
 
 impl SystemContext {
     pub(crate) fn new() -> Self {
-        Self { should_exit: false }
+        Self {
+            should_exit: false,
+            exit_value: None,
+        }
     }
 
     pub(crate) fn should_exit(&self) -> bool {
@@ -129,4 +196,72 @@ impl SystemContext {
     pub fn exit(&mut self) {
         self.should_exit = true;
     }
+
+    /// 退出并携带结果，渲染循环结束、终端恢复后由 [`AppExit`] 交给调用方。
+    ///
+    /// 结果可以是任意类型，如用户选择的文件路径；传入 `i32` 时作为进程退出码，见 [`AppExit::code`]。
+    /// 多次调用时以最后一次为准。
+    ///
+    /// # 示例
+    /// ```rust
+    /// let mut system = hooks.use_context_mut::<SystemContext>();
+    /// system.exit_with(selected_path.clone());
+    /// ```
+    pub fn exit_with<T: Any + Send>(&mut self, value: T) {
+        self.should_exit = true;
+        self.exit_value = Some(Box::new(value));
+    }
+
+    pub(crate) fn take_exit(&mut self, interrupted: bool) -> AppExit {
+        AppExit {
+            value: self.exit_value.take(),
+            interrupted,
+        }
+    }
+}
+
+/// 渲染循环结束时的结果：组件通过 [`SystemContext::exit_with`] 传出的值，以及是否因 Ctrl+C 中断。
+///
+/// # 示例
+/// ```rust
+/// let exit = element!(FilePicker).fullscreen().await?;
+/// if let Some(path) = exit.value::<PathBuf>() {
+///     println!("{}", path.display());
+/// }
+/// std::process::exit(exit.code());
+/// ```
+#[derive(Default)]
+pub struct AppExit {
+    value: Option<Box<dyn Any + Send>>,
+    interrupted: bool,
+}
+
+impl AppExit {
+    /// 是否因 Ctrl+C 退出。
+    pub fn is_interrupted(&self) -> bool {
+        self.interrupted
+    }
+
+    /// 进程退出码：结果为 `i32` 时取该值，Ctrl+C 中断时为 130，否则为 0。
+    pub fn code(&self) -> i32 {
+        match self.downcast_ref::<i32>() {
+            Some(code) => *code,
+            None if self.interrupted => 130,
+            None => 0,
+        }
+    }
+
+    /// 结果的引用，没有结果或类型不符时为 None。
+    pub fn downcast_ref<R: Any>(&self) -> Option<&R> {
+        self.value.as_ref()?.downcast_ref()
+    }
+
+    pub(crate) fn has_value(&self) -> bool {
+        self.value.is_some()
+    }
+
+    /// 取出结果，没有结果或类型不符时为 None。
+    pub fn value<R: Any>(self) -> Option<R> {
+        self.value?.downcast().ok().map(|value| *value)
+    }
 }
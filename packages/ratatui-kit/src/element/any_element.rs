@@ -3,6 +3,7 @@ use ratatui::TerminalOptions;
 use super::{Element, ElementKey, element_ext::ElementExt};
 use crate::{
     component::{Component, ComponentHelper, ComponentHelperExt},
+    context::AppExit,
     props::AnyProps,
     render::tree::render_loop,
     terminal::{CrossTerminal, Terminal},
@@ -51,6 +52,14 @@ impl<'a, 'b: 'a> From<&'a mut AnyElement<'b>> for AnyElement<'b> {
     }
 }
 
+#[cfg(feature = "layout")]
+impl AnyElement<'_> {
+    // 替换元素的 key，供运行时构建元素树（如布局解释器）使用
+    pub(crate) fn set_key(&mut self, key: ElementKey) {
+        self.key = key;
+    }
+}
+
 impl<'a> ElementExt for AnyElement<'a> {
     fn key(&self) -> &ElementKey {
         &self.key
@@ -64,16 +73,14 @@ impl<'a> ElementExt for AnyElement<'a> {
         self.props.borrow()
     }
 
-    async fn render_loop(&mut self, options: TerminalOptions) -> io::Result<()> {
+    async fn render_loop(&mut self, options: TerminalOptions) -> io::Result<AppExit> {
         let terminal = Terminal::new(CrossTerminal::with_options(options)?)?;
-        render_loop(self, terminal).await?;
-        Ok(())
+        render_loop(self, terminal).await
     }
 
-    async fn fullscreen(&mut self) -> io::Result<()> {
+    async fn fullscreen(&mut self) -> io::Result<AppExit> {
         let terminal = Terminal::new(CrossTerminal::new()?)?;
-        render_loop(self, terminal).await?;
-        Ok(())
+        render_loop(self, terminal).await
     }
 }
 
@@ -90,15 +97,13 @@ impl<'a> ElementExt for &mut AnyElement<'a> {
         self.props.borrow()
     }
 
-    async fn render_loop(&mut self, options: TerminalOptions) -> io::Result<()> {
+    async fn render_loop(&mut self, options: TerminalOptions) -> io::Result<AppExit> {
         let terminal = Terminal::new(CrossTerminal::with_options(options)?)?;
-        render_loop(&mut **self, terminal).await?;
-        Ok(())
+        render_loop(&mut **self, terminal).await
     }
 
-    async fn fullscreen(&mut self) -> io::Result<()> {
+    async fn fullscreen(&mut self) -> io::Result<AppExit> {
         let terminal = Terminal::new(CrossTerminal::new()?)?;
-        render_loop(&mut **self, terminal).await?;
-        Ok(())
+        render_loop(&mut **self, terminal).await
     }
 }
@@ -1,8 +1,8 @@
 use ratatui::TerminalOptions;
 
 use super::ElementKey;
-use crate::{component::ComponentHelperExt, props::AnyProps};
-use std::io;
+use crate::{component::ComponentHelperExt, context::AppExit, props::AnyProps};
+use std::{any::Any, io};
 
 mod private {
     use crate::{
@@ -36,7 +36,27 @@ pub trait ElementExt: private::Sealed + Sized {
     /// 获取组件辅助操作对象，支持动态调度和扩展。
     fn helper(&self) -> Box<dyn ComponentHelperExt>;
     /// 启动渲染主循环，传入终端选项，适合自定义Viewport场景。
-    fn render_loop(&mut self, options: TerminalOptions) -> impl Future<Output = io::Result<()>>;
+    ///
+    /// 终端恢复后返回退出结果，见 [`SystemContext::exit_with`](crate::SystemContext::exit_with)。
+    fn render_loop(
+        &mut self,
+        options: TerminalOptions,
+    ) -> impl Future<Output = io::Result<AppExit>>;
     /// 以全屏模式运行当前元素，适合大多数终端 UI 应用入口。
-    fn fullscreen(&mut self) -> impl Future<Output = io::Result<()>>;
+    ///
+    /// 终端恢复后返回退出结果，见 [`SystemContext::exit_with`](crate::SystemContext::exit_with)。
+    fn fullscreen(&mut self) -> impl Future<Output = io::Result<AppExit>>;
+    /// 以全屏模式运行，终端恢复后取出组件通过 `exit_with` 传出的 `R` 类型结果，
+    /// 没有结果、类型不符或因 Ctrl+C 退出时为 None。
+    ///
+    /// # 示例
+    /// ```rust
+    /// // 组件中：system.exit_with(entry.path());
+    /// if let Some(path) = element!(FilePicker).run::<PathBuf>().await? {
+    ///     println!("{}", path.display());
+    /// }
+    /// ```
+    fn run<R: Any>(&mut self) -> impl Future<Output = io::Result<Option<R>>> {
+        async move { Ok(self.fullscreen().await?.value()) }
+    }
 }
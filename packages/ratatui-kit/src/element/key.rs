@@ -11,4 +11,48 @@ impl ElementKey {
     {
         Self(Arc::new(Box::new(key)))
     }
+
+    /// 键的文本形式，字符串键不带引号，用于按路径查找组件。
+    ///
+    /// `element!` 中指定了 `key:` 时只取指定的部分。
+    pub fn label(&self) -> String {
+        let label = format!("{:?}", self.0);
+        text(&label).to_string()
+    }
+
+    /// 使用者指定的键的文本形式，`element!` 自动生成的键返回 None。
+    ///
+    /// 自动生成的键是每次编译随机产生的编号，不能用于跨进程的持久化。
+    pub fn user_label(&self) -> Option<String> {
+        let label = format!("{:?}", self.0);
+        if is_generated(&label) {
+            return None;
+        }
+        Some(text(&label).to_string())
+    }
+}
+
+// element! 未指定 key 时生成一个编号作为键
+fn is_generated(label: &str) -> bool {
+    !label.is_empty() && label.bytes().all(|b| b.is_ascii_digit())
+}
+
+// element! 指定 key 时键为 (编号, key)，取出 key 部分，字符串去掉引号
+fn user_part(label: &str) -> Option<&str> {
+    let inner = label.strip_prefix('(')?.strip_suffix(')')?;
+    let (generated, key) = inner.split_once(", ")?;
+    if !is_generated(generated) {
+        return None;
+    }
+    Some(strip_quotes(key).unwrap_or(key))
+}
+
+fn strip_quotes(label: &str) -> Option<&str> {
+    label.strip_prefix('"')?.strip_suffix('"')
+}
+
+fn text(label: &str) -> &str {
+    user_part(label)
+        .or_else(|| strip_quotes(label))
+        .unwrap_or(label)
 }
@@ -1,5 +1,5 @@
 use crate::{
-    AnyProps, Component, ComponentHelper, ComponentHelperExt, CrossTerminal, Terminal,
+    AnyProps, AppExit, Component, ComponentHelper, ComponentHelperExt, CrossTerminal, Terminal,
     tree::render_loop,
 };
 use std::io;
@@ -11,7 +11,15 @@ mod element_ext;
 pub use element_ext::ElementExt;
 mod extend_with_elements;
 pub use extend_with_elements::{ExtendWithElements, extend_with_elements};
+mod render_prop;
 use ratatui::TerminalOptions;
+pub use render_prop::RenderProp;
+#[cfg(feature = "json")]
+mod serialize;
+#[cfg(feature = "json")]
+pub(crate) use serialize::capturing_props;
+#[cfg(all(feature = "json", feature = "parallel"))]
+pub(crate) use serialize::with_capture_props;
 
 pub trait ElementType {
     type Props<'a>
@@ -50,16 +58,14 @@ where
         AnyProps::borrowed(&mut self.props)
     }
 
-    async fn render_loop(&mut self, options: TerminalOptions) -> io::Result<()> {
+    async fn render_loop(&mut self, options: TerminalOptions) -> io::Result<AppExit> {
         let terminal = Terminal::new(CrossTerminal::with_options(options)?)?;
-        render_loop(self, terminal).await?;
-        Ok(())
+        render_loop(self, terminal).await
     }
 
-    async fn fullscreen(&mut self) -> io::Result<()> {
+    async fn fullscreen(&mut self) -> io::Result<AppExit> {
         let terminal = Terminal::new(CrossTerminal::new()?)?;
-        render_loop(self, terminal).await?;
-        Ok(())
+        render_loop(self, terminal).await
     }
 }
 
@@ -79,15 +85,13 @@ where
         AnyProps::borrowed(&mut self.props)
     }
 
-    async fn render_loop(&mut self, options: TerminalOptions) -> io::Result<()> {
+    async fn render_loop(&mut self, options: TerminalOptions) -> io::Result<AppExit> {
         let terminal = Terminal::new(CrossTerminal::with_options(options)?)?;
-        render_loop(&mut **self, terminal).await?;
-        Ok(())
+        render_loop(&mut **self, terminal).await
     }
 
-    async fn fullscreen(&mut self) -> io::Result<()> {
+    async fn fullscreen(&mut self) -> io::Result<AppExit> {
         let terminal = Terminal::new(CrossTerminal::new()?)?;
-        render_loop(&mut **self, terminal).await?;
-        Ok(())
+        render_loop(&mut **self, terminal).await
     }
 }
@@ -0,0 +1,47 @@
+use super::AnyElement;
+
+/// 渲染函数属性（render prop），由调用方决定如何把数据渲染为元素，适合无头组件（headless component）。
+///
+/// - 组件通过 `children: RenderProp<'a, T>` 声明，`element!` 中可直接写闭包子元素。
+/// - `render` 调用闭包生成元素，未设置时返回 `None`。
+///
+/// # 示例
+/// ```rust
+/// #[derive(Default, Props)]
+/// struct EachProps<'a> {
+///     items: Vec<String>,
+///     children: RenderProp<'a, String>,
+/// }
+///
+/// element!(Each(items: names) {
+///     |name| element!(View { $Line::from(name) })
+/// })
+/// ```
+pub struct RenderProp<'a, T>(Option<Box<dyn Fn(T) -> AnyElement<'a> + Send + Sync + 'a>>);
+
+impl<'a, T> RenderProp<'a, T> {
+    /// 包装渲染闭包。
+    pub fn new<F, E>(f: F) -> Self
+    where
+        F: Fn(T) -> E + Send + Sync + 'a,
+        E: Into<AnyElement<'a>>,
+    {
+        Self(Some(Box::new(move |value| f(value).into())))
+    }
+
+    /// 是否未设置渲染闭包。
+    pub fn is_default(&self) -> bool {
+        self.0.is_none()
+    }
+
+    /// 调用渲染闭包生成元素。
+    pub fn render(&self, value: T) -> Option<AnyElement<'a>> {
+        self.0.as_ref().map(|f| f(value))
+    }
+}
+
+impl<T> Default for RenderProp<'_, T> {
+    fn default() -> Self {
+        Self(None)
+    }
+}
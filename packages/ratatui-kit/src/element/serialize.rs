@@ -0,0 +1,122 @@
+//! 元素树结构的 JSON 序列化，供检查器、结构对比测试、文档生成等外部工具读取组件树的形状。
+//!
+//! 每个节点的格式如下，`props` 和 `area` 只在可用时出现：
+//! ```json
+//! {
+//!   "name": "List",
+//!   "type": "ratatui_kit::components::list::List",
+//!   "key": "list",
+//!   "props": { "selected": "Some(3)" },
+//!   "area": { "x": 0, "y": 1, "width": 20, "height": 5 },
+//!   "children": []
+//! }
+//! ```
+//! - `props`：实现了 `Debug` 的属性字段及其调试输出，只有 [`AnyElement::to_json`] 会记录；
+//! - `area`：组件最近一次绘制的区域，尚未绘制的组件没有该字段。
+
+use std::{cell::Cell, io};
+
+use serde_json::{Map, Value, json};
+
+use crate::{
+    AnyElement, CrossTerminal, InstantiatedComponent, Terminal,
+    render::tree::{Tree, mount_element},
+};
+
+// 序列化时在挂载用的终端尺寸
+const MOUNT_WIDTH: u16 = 80;
+const MOUNT_HEIGHT: u16 = 24;
+
+thread_local! {
+    // 为 true 时组件更新会记录属性的调试输出
+    static CAPTURE_PROPS: Cell<bool> = const { Cell::new(false) };
+}
+
+pub(crate) fn capturing_props() -> bool {
+    CAPTURE_PROPS.with(Cell::get)
+}
+
+/// 以指定的属性记录开关执行闭包，并行更新在工作线程中以此沿用调用线程的设置。
+#[cfg(feature = "parallel")]
+pub(crate) fn with_capture_props<R>(capture: bool, f: impl FnOnce() -> R) -> R {
+    let previous = CAPTURE_PROPS.with(|current| current.replace(capture));
+    let _guard = CaptureGuard(previous);
+    f()
+}
+
+struct CaptureGuard(bool);
+
+impl Drop for CaptureGuard {
+    fn drop(&mut self) {
+        CAPTURE_PROPS.with(|capture| capture.set(self.0));
+    }
+}
+
+impl AnyElement<'_> {
+    /// 把元素挂载到 80x24 的无界面终端并更新一次，返回展开后的组件树结构，包含各组件的属性。
+    ///
+    /// 只执行一次更新，异步副作用不会继续执行，也不会绘制，因此节点中没有 `area`。
+    /// 格式见 [`InstantiatedComponent::to_json`]。
+    ///
+    /// # 示例
+    /// ```rust
+    /// let mut element: AnyElement = element!(View { Text(text: "hello") }).into();
+    /// let tree = element.to_json()?;
+    /// assert_eq!(tree["children"][0]["name"], "Text");
+    /// ```
+    pub fn to_json(&mut self) -> io::Result<Value> {
+        let mut terminal = Terminal::new(CrossTerminal::headless(MOUNT_WIDTH, MOUNT_HEIGHT)?)?;
+        let previous = CAPTURE_PROPS.with(|capture| capture.replace(true));
+        let _guard = CaptureGuard(previous);
+        let tree = mount_element(self, &mut terminal);
+        Ok(tree.root().to_json())
+    }
+}
+
+impl Tree<'_> {
+    /// 已挂载的组件树结构，格式见 [`InstantiatedComponent::to_json`]。
+    pub fn to_json(&self) -> Value {
+        self.root().to_json()
+    }
+}
+
+impl InstantiatedComponent {
+    /// 以该组件为根的子树结构：
+    ///
+    /// - `name`：组件类型名，不含模块路径和泛型参数；
+    /// - `type`：组件的完整类型名；
+    /// - `key`：组件的键；
+    /// - `props`：实现了 `Debug` 的属性字段及其调试输出，只在通过 [`AnyElement::to_json`] 挂载时记录；
+    /// - `area`：最近一次绘制的区域，尚未绘制时省略；
+    /// - `children`：子组件，按渲染顺序排列。
+    pub fn to_json(&self) -> Value {
+        let mut node = Map::new();
+        node.insert("name".into(), self.name().into());
+        node.insert("type".into(), self.type_name().into());
+        node.insert("key".into(), self.key().label().into());
+        if let Some(props) = self.props_debug() {
+            let props = props
+                .iter()
+                .map(|(name, value)| (name.to_string(), Value::String(value.clone())))
+                .collect::<Map<_, _>>();
+            node.insert("props".into(), props.into());
+        }
+        let area = self.area();
+        if !area.is_empty() {
+            node.insert(
+                "area".into(),
+                json!({
+                    "x": area.x,
+                    "y": area.y,
+                    "width": area.width,
+                    "height": area.height,
+                }),
+            );
+        }
+        node.insert(
+            "children".into(),
+            self.children().map(Self::to_json).collect(),
+        );
+        node.into()
+    }
+}
@@ -0,0 +1,242 @@
+//! 静态导出：离屏渲染一帧元素树，并序列化为 HTML 或 SVG，适合文档截图和视觉回归基线。
+//!
+//! ## 用法示例
+//! ```rust
+//! use ratatui_kit::{export, ratatui::layout::Size};
+//!
+//! let html = export::to_html(element!(App), Size::new(80, 24))?;
+//! std::fs::write("app.html", html)?;
+//! let svg = export::to_svg(element!(App), Size::new(80, 24))?;
+//! ```
+//! 元素只渲染一帧，异步副作用不会执行；终端能力视为支持真彩色、不支持超链接。
+
+use std::{fmt::Write as _, io};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Size,
+    style::{Color, Modifier},
+};
+
+use crate::{AnyElement, CrossTerminal, Terminal, color_rgb, text, tree::render_element_to_buffer};
+
+// 未设置颜色时使用的前景色和背景色
+const DEFAULT_FG: [u8; 3] = [229, 229, 229];
+const DEFAULT_BG: [u8; 3] = [30, 30, 30];
+
+// SVG 中单元格的尺寸和字号
+const FONT_SIZE: f32 = 14.0;
+const CELL_WIDTH: f32 = 8.4;
+const CELL_HEIGHT: f32 = 17.0;
+const PADDING: f32 = 8.0;
+const FONT_FAMILY: &str = "ui-monospace, SFMono-Regular, Menlo, Consolas, monospace";
+
+/// 离屏渲染一帧，返回渲染结果的缓冲区。
+pub fn to_buffer<'a>(element: impl Into<AnyElement<'a>>, size: Size) -> io::Result<Buffer> {
+    let mut element = element.into();
+    let mut terminal = Terminal::new(CrossTerminal::headless(size.width, size.height)?)?;
+    // 避免 RATATUI_KIT_RECORD 开启的录制覆盖真实会话的录制文件
+    terminal.stop_recording();
+    render_element_to_buffer(&mut element, &mut terminal, size.width, Some(size.height))
+}
+
+/// 离屏渲染一帧并导出为 HTML 片段（`<pre>` 元素，样式内联）。
+pub fn to_html<'a>(element: impl Into<AnyElement<'a>>, size: Size) -> io::Result<String> {
+    Ok(buffer_to_html(&to_buffer(element, size)?))
+}
+
+/// 离屏渲染一帧并导出为 SVG 图像。
+pub fn to_svg<'a>(element: impl Into<AnyElement<'a>>, size: Size) -> io::Result<String> {
+    Ok(buffer_to_svg(&to_buffer(element, size)?))
+}
+
+/// 把缓冲区序列化为 HTML 片段。
+pub fn buffer_to_html(buffer: &Buffer) -> String {
+    let mut html = format!(
+        "<pre style=\"font-family: {FONT_FAMILY}; line-height: 1.2; padding: {PADDING}px; \
+         display: inline-block; color: {}; background: {};\">",
+        hex(DEFAULT_FG),
+        hex(DEFAULT_BG)
+    );
+    for row in rows(buffer) {
+        for run in row {
+            let mut style = String::new();
+            if run.fg != DEFAULT_FG {
+                let _ = write!(style, "color: {};", hex(run.fg));
+            }
+            if let Some(bg) = run.bg {
+                let _ = write!(style, "background: {};", hex(bg));
+            }
+            style.push_str(&font_css(run.modifier, ": ", ";"));
+            if style.is_empty() {
+                html.push_str(&escape_xml(&run.text));
+            } else {
+                let _ = write!(
+                    html,
+                    "<span style=\"{style}\">{}</span>",
+                    escape_xml(&run.text)
+                );
+            }
+        }
+        html.push('\n');
+    }
+    html.push_str("</pre>");
+    html
+}
+
+/// 把缓冲区序列化为 SVG 图像。
+pub fn buffer_to_svg(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let width = area.width as f32 * CELL_WIDTH + PADDING * 2.0;
+    let height = area.height as f32 * CELL_HEIGHT + PADDING * 2.0;
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {width} {height}\" font-family=\"{FONT_FAMILY}\" font-size=\"{FONT_SIZE}\">\n\
+         <rect width=\"100%\" height=\"100%\" fill=\"{}\"/>\n",
+        hex(DEFAULT_BG)
+    );
+    let mut texts = String::new();
+    for (y, row) in rows(buffer).into_iter().enumerate() {
+        let top = PADDING + y as f32 * CELL_HEIGHT;
+        for run in row {
+            let x = PADDING + run.col as f32 * CELL_WIDTH;
+            let run_width = run.cols as f32 * CELL_WIDTH;
+            // 背景单独绘制在文字下方
+            if let Some(bg) = run.bg {
+                let _ = writeln!(
+                    svg,
+                    "<rect x=\"{x}\" y=\"{top}\" width=\"{run_width}\" height=\"{CELL_HEIGHT}\" fill=\"{}\"/>",
+                    hex(bg)
+                );
+            }
+            if run.text.trim().is_empty() && !run.modifier.contains(Modifier::UNDERLINED) {
+                continue;
+            }
+            let _ = writeln!(
+                texts,
+                "<text x=\"{x}\" y=\"{}\" fill=\"{}\" textLength=\"{run_width}\" \
+                 lengthAdjust=\"spacingAndGlyphs\" xml:space=\"preserve\"{}>{}</text>",
+                top + CELL_HEIGHT * 0.78,
+                hex(run.fg),
+                font_attrs(run.modifier),
+                escape_xml(&run.text)
+            );
+        }
+    }
+    svg.push_str(&texts);
+    svg.push_str("</svg>\n");
+    svg
+}
+
+// 一段样式相同的连续单元格
+struct Run {
+    col: u16,
+    cols: u16,
+    text: String,
+    fg: [u8; 3],
+    bg: Option<[u8; 3]>,
+    modifier: Modifier,
+}
+
+// 按行把缓冲区切分为样式相同的片段，宽字符占用的后续单元格被跳过
+fn rows(buffer: &Buffer) -> Vec<Vec<Run>> {
+    let area = buffer.area;
+    let mut rows = Vec::with_capacity(area.height as usize);
+    for y in area.top()..area.bottom() {
+        let mut runs: Vec<Run> = Vec::new();
+        let mut x = area.left();
+        while x < area.right() {
+            let cell = &buffer[(x, y)];
+            let width = text::width(cell.symbol()).clamp(1, 2) as u16;
+            let (fg, bg) = resolve_colors(cell.fg, cell.bg, cell.modifier);
+            let col = x - area.left();
+            match runs.last_mut() {
+                Some(run) if run.fg == fg && run.bg == bg && run.modifier == cell.modifier => {
+                    run.text.push_str(cell.symbol());
+                    run.cols += width;
+                }
+                _ => runs.push(Run {
+                    col,
+                    cols: width,
+                    text: cell.symbol().to_string(),
+                    fg,
+                    bg,
+                    modifier: cell.modifier,
+                }),
+            }
+            x += width;
+        }
+        rows.push(runs);
+    }
+    rows
+}
+
+// 处理反色和隐藏后得到实际显示的前景色和背景色，背景为默认色时返回 None
+fn resolve_colors(fg: Color, bg: Color, modifier: Modifier) -> ([u8; 3], Option<[u8; 3]>) {
+    let mut fg_rgb = color_rgb(fg).unwrap_or(DEFAULT_FG);
+    let mut bg_rgb = color_rgb(bg);
+    if modifier.contains(Modifier::REVERSED) {
+        let new_bg = fg_rgb;
+        fg_rgb = bg_rgb.unwrap_or(DEFAULT_BG);
+        bg_rgb = Some(new_bg);
+    }
+    if modifier.contains(Modifier::HIDDEN) {
+        fg_rgb = bg_rgb.unwrap_or(DEFAULT_BG);
+    }
+    (fg_rgb, bg_rgb)
+}
+
+// 修饰符对应的字体样式，`sep` 与 `end` 决定输出为 CSS 声明还是 XML 属性
+fn font_css(modifier: Modifier, sep: &str, end: &str) -> String {
+    let mut css = String::new();
+    if modifier.contains(Modifier::BOLD) {
+        let _ = write!(css, "font-weight{sep}bold{end}");
+    }
+    if modifier.contains(Modifier::ITALIC) {
+        let _ = write!(css, "font-style{sep}italic{end}");
+    }
+    if modifier.contains(Modifier::DIM) {
+        let _ = write!(css, "opacity{sep}0.6{end}");
+    }
+    let decorations = [
+        (Modifier::UNDERLINED, "underline"),
+        (Modifier::CROSSED_OUT, "line-through"),
+    ]
+    .iter()
+    .filter(|(m, _)| modifier.contains(*m))
+    .map(|(_, name)| *name)
+    .collect::<Vec<_>>();
+    if !decorations.is_empty() {
+        let _ = write!(css, "text-decoration{sep}{}{end}", decorations.join(" "));
+    }
+    css
+}
+
+// SVG 的字体属性，每个属性前带一个空格
+fn font_attrs(modifier: Modifier) -> String {
+    let attrs = font_css(modifier, "=\"", "\" ");
+    if attrs.is_empty() {
+        attrs
+    } else {
+        format!(" {}", attrs.trim_end())
+    }
+}
+
+fn hex([r, g, b]: [u8; 3]) -> String {
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
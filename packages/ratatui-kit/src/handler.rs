@@ -1,7 +1,8 @@
 /// 通用事件处理器类型，封装 FnMut 回调闭包，支持动态替换和默认空实现。
 ///
 /// - 可用于组件 props 的事件回调（如 on_change、on_click 等）。
-/// - 支持通过 `Handler::from` 包装任意闭包。
+/// - 支持通过 `Handler::from` 包装任意闭包，通过 `Handler::from_async` 包装异步闭包。
+/// - 第二个类型参数为返回值类型（默认为 `()`），如 `Handler<'static, String, Result<(), String>>` 可用于校验回调。
 /// - `is_default()` 判断是否为默认空实现。
 /// - `take()` 获取并重置 handler。
 /// - 实现 Deref/DerefMut，可直接调用闭包。
@@ -10,46 +11,215 @@
 /// ```rust
 /// let mut handler = Handler::from(|val| println!("changed: {}", val));
 /// handler("hello");
+///
+/// // 异步处理器在创建它的组件内执行，组件卸载时任务随之取消
+/// let on_submit = Handler::from_async(move |text: String| async move {
+///     save(text).await;
+/// });
 /// ```
 use core::ops::{Deref, DerefMut};
 
-pub struct Handler<'a, T>(bool, Box<dyn FnMut(T) + Send + Sync + 'a>);
+use futures::FutureExt;
+
+use crate::{TaskScope, runtime};
+
+pub struct Handler<'a, T, R = ()>(bool, Box<dyn FnMut(T) -> R + Send + Sync + 'a>);
+
+/// 处理器返回值类型，未设置处理器时返回 [`HandlerOutput::default_output`]。
+pub trait HandlerOutput {
+    fn default_output() -> Self;
+}
+
+impl HandlerOutput for () {
+    fn default_output() -> Self {}
+}
+
+impl HandlerOutput for bool {
+    fn default_output() -> Self {
+        false
+    }
+}
+
+impl<T> HandlerOutput for Vec<T> {
+    fn default_output() -> Self {
+        Vec::new()
+    }
+}
+
+impl<T> HandlerOutput for Option<T> {
+    fn default_output() -> Self {
+        None
+    }
+}
+
+// 未设置校验类回调时视为通过
+impl<E> HandlerOutput for Result<(), E> {
+    fn default_output() -> Self {
+        Ok(())
+    }
+}
 
-impl<T> Handler<'_, T> {
+impl<T, R> Handler<'_, T, R> {
     pub fn is_default(&self) -> bool {
         !self.0
     }
+}
 
+impl<T, R: HandlerOutput> Handler<'_, T, R> {
     pub fn take(&mut self) -> Self {
         core::mem::take(self)
     }
 }
 
-impl<'a, T> Default for Handler<'a, T> {
+impl<'a, T> Handler<'a, T> {
+    /// 包装异步闭包，调用时生成的任务交给创建处理器的组件执行。
+    ///
+    /// 传给弹窗等子组件的处理器在子组件卸载后仍会执行完毕，只有创建它的组件卸载时才取消。
+    /// 在组件外创建时任务交给调用它的组件；也不在组件的事件处理或渲染过程中调用时，
+    /// 任务交给 [`set_spawner`](crate::runtime::set_spawner) 设置的派生器，未设置时在独立线程中运行。
+    pub fn from_async<F, Fut>(mut f: F) -> Self
+    where
+        F: FnMut(T) -> Fut + Send + Sync + 'a,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let scope = TaskScope::current();
+        Self(
+            true,
+            Box::new(move |value| {
+                let future = f(value).boxed();
+                match scope.clone().or_else(TaskScope::current) {
+                    Some(scope) => scope.push(future),
+                    None => runtime::spawn_detached(future),
+                }
+            }),
+        )
+    }
+}
+
+impl<'a, T, R: HandlerOutput> Default for Handler<'a, T, R> {
     fn default() -> Self {
-        Self(false, Box::new(|_| {}))
+        Self(false, Box::new(|_| R::default_output()))
     }
 }
 
-impl<'a, F, T> From<F> for Handler<'a, T>
+impl<'a, F, T, R> From<F> for Handler<'a, T, R>
 where
-    F: FnMut(T) + Send + Sync + 'a,
+    F: FnMut(T) -> R + Send + Sync + 'a,
 {
     fn from(f: F) -> Self {
-        Self(false, Box::new(f))
+        Self(true, Box::new(f))
     }
 }
 
-impl<'a, T> Deref for Handler<'a, T> {
-    type Target = Box<dyn FnMut(T) + Send + Sync + 'a>;
+impl<'a, T, R> Deref for Handler<'a, T, R> {
+    type Target = Box<dyn FnMut(T) -> R + Send + Sync + 'a>;
 
     fn deref(&self) -> &Self::Target {
         &self.1
     }
 }
 
-impl<'a, T> DerefMut for Handler<'a, T> {
+impl<'a, T, R> DerefMut for Handler<'a, T, R> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.1
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        future::poll_fn,
+        sync::{
+            Arc,
+            atomic::{AtomicBool, Ordering},
+        },
+        task::Poll,
+        time::Duration,
+    };
+
+    use crossterm::event::{Event, KeyCode};
+
+    use super::Handler;
+    use crate::{
+        prelude::*,
+        test_util::{keys, mount, send},
+    };
+
+    #[derive(Default, Props)]
+    struct SubmitProps {
+        on_submit: Handler<'static, ()>,
+        on_close: Handler<'static, ()>,
+    }
+
+    // 按任意键时提交并关闭自身
+    #[component]
+    fn Submit(props: &mut SubmitProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut on_submit = props.on_submit.take();
+        let mut on_close = props.on_close.take();
+        hooks.use_events(move |event| {
+            if let Event::Key(_) = event {
+                on_submit(());
+                on_close(());
+            }
+        });
+        element!(Text(text: "submit"))
+    }
+
+    #[derive(Default, Props)]
+    struct FormProps {
+        saved: Arc<AtomicBool>,
+    }
+
+    #[component]
+    fn Form(props: &FormProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut open = hooks.use_state(|| true);
+        let saved = props.saved.clone();
+        let submit = open.get().then(|| {
+            element!(Submit(
+                on_submit: Handler::from_async(move |_| {
+                    let saved = saved.clone();
+                    async move {
+                        // 让出一次，任务在 Submit 卸载后才完成
+                        let mut yielded = false;
+                        poll_fn(|cx| {
+                            if yielded {
+                                return Poll::Ready(());
+                            }
+                            yielded = true;
+                            cx.waker().wake_by_ref();
+                            Poll::Pending
+                        })
+                        .await;
+                        saved.store(true, Ordering::SeqCst);
+                    }
+                }),
+                on_close: move |_| open.set(false),
+            ))
+        });
+        element!(View {
+            #(submit)
+        })
+    }
+
+    #[test]
+    fn async_handler_outlives_the_child_that_calls_it() {
+        let saved = Arc::new(AtomicBool::new(false));
+        let mut root = element!(Form(saved: saved.clone()));
+        let mut app = mount(&mut root);
+        send(&mut app, keys([KeyCode::Enter]));
+        assert!(saved.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn async_handler_outside_component() {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let mut handler = Handler::from_async(move |value: i32| {
+            let sender = sender.clone();
+            async move {
+                sender.send(value).unwrap();
+            }
+        });
+        handler(3);
+        assert_eq!(receiver.recv_timeout(Duration::from_secs(5)), Ok(3));
+    }
+}
@@ -0,0 +1,80 @@
+use std::{cell::RefCell, task::Waker};
+
+use crate::{UpdatePriority, render::scheduler};
+
+/// 推迟的 waker 和唤醒时应使用的优先级。
+pub(crate) type Deferred = (Waker, UpdatePriority);
+
+thread_local! {
+    // 批量作用域中推迟的 waker，None 表示不在批量作用域中
+    static PENDING: RefCell<Option<Vec<Deferred>>> = const { RefCell::new(None) };
+}
+
+/// 批量修改状态：闭包内修改 [`State`](crate::State) 或 store 时推迟唤醒渲染循环，
+/// 到最外层闭包结束时统一唤醒，多次修改只触发一次渲染。
+///
+/// 组件树轮询期间（事件处理器、异步处理器、`use_future` 等在此期间执行）已自动处于批量作用域中；
+/// 在线程或自行派生的任务中连续修改多个状态时可以手动使用。作用域可以嵌套。
+pub fn batch_updates<R>(f: impl FnOnce() -> R) -> R {
+    let outermost = PENDING.with(|pending| {
+        let mut pending = pending.borrow_mut();
+        if pending.is_some() {
+            return false;
+        }
+        *pending = Some(Vec::new());
+        true
+    });
+    // 闭包 panic 时也要恢复并唤醒
+    let _guard = outermost.then_some(BatchGuard);
+    f()
+}
+
+struct BatchGuard;
+
+impl Drop for BatchGuard {
+    fn drop(&mut self) {
+        let wakers = PENDING.with(|pending| pending.borrow_mut().take());
+        for (waker, priority) in wakers.into_iter().flatten() {
+            scheduler::with_priority(priority, || waker.wake());
+        }
+    }
+}
+
+/// 替换当前线程的批量作用域并返回原有的，并行更新在工作线程中以此重建调用线程的作用域。
+#[cfg(feature = "parallel")]
+pub(crate) fn replace_pending(pending: Option<Vec<Deferred>>) -> Option<Vec<Deferred>> {
+    PENDING.with(|current| current.replace(pending))
+}
+
+/// 当前线程是否处于批量作用域中。
+#[cfg(feature = "parallel")]
+pub(crate) fn is_batching() -> bool {
+    PENDING.with(|pending| pending.borrow().is_some())
+}
+
+/// 唤醒等待状态变化的任务，批量作用域中推迟到作用域结束，相同的 waker 只唤醒一次。
+///
+/// 推迟唤醒时记下当前线程的优先级，唤醒时组件树按该优先级调度，见 [`with_priority`](crate::with_priority)。
+pub(crate) fn wake(waker: Waker) {
+    defer((waker, scheduler::current_priority()));
+}
+
+/// 以指定优先级唤醒，批量作用域中推迟唤醒，相同的 waker 取较高的优先级。
+pub(crate) fn defer((waker, priority): Deferred) {
+    let waker = PENDING.with(|pending| match pending.borrow_mut().as_mut() {
+        Some(pending) => {
+            match pending
+                .iter_mut()
+                .find(|(pending, _)| pending.will_wake(&waker))
+            {
+                Some((_, pending)) => *pending = (*pending).max(priority),
+                None => pending.push((waker, priority)),
+            }
+            None
+        }
+        None => Some(waker),
+    });
+    if let Some(waker) = waker {
+        scheduler::with_priority(priority, || waker.wake());
+    }
+}
@@ -3,7 +3,7 @@
 //! ## 如何实现一个规范的自定义 hook
 //!
 //! 1. 定义一个实现 [`Hook`] trait 的结构体，管理自己的状态和生命周期。
-//! 2. 在 `poll_change`、`pre_component_update`、`post_component_update`、`pre_component_draw`、`post_component_draw` 等方法中实现副作用或状态逻辑。
+//! 2. 在 `poll_change`、`pre_component_update`、`post_component_update`、`pre_component_draw`、`post_component_draw`、`on_active_change` 等方法中实现副作用或状态逻辑。
 //! 3. 提供 trait（如 `pub trait UseXxx`）暴露给用户，trait 方法通过 `Hooks::use_hook` 注册/获取 hook 实例。
 //! 4. 推荐通过 `private::Sealed` 限制 trait 只对框架内部实现。
 //!
@@ -24,17 +24,28 @@
 //! ```
 //!
 //! 这样可保证 hook 生命周期、类型安全和复用性。
+//!
+//! ## hook 调用规则
+//!
+//! hook 按调用顺序与组件实例绑定，每次渲染都必须以相同顺序无条件调用，不能写在条件分支、循环或闭包中。
+//! 顺序不一致时会 panic 并指出组件名和第一个不一致的 hook；`#[component]` 也会对能识别出的条件调用给出编译警告。
 
 #![allow(unused)]
 use crate::{
     context::ContextStack,
     render::{ComponentDrawer, ComponentUpdater},
 };
+use generational_box::{Owner, SyncStorage};
 use std::{
     any::Any,
     pin::Pin,
     task::{Context, Poll},
 };
+mod batch;
+pub use batch::batch_updates;
+pub(crate) use batch::wake;
+#[cfg(feature = "parallel")]
+pub(crate) use batch::{Deferred, defer, is_batching, replace_pending};
 mod use_context;
 pub use use_context::*;
 mod use_events;
@@ -43,18 +54,90 @@ mod use_future;
 pub use use_future::*;
 mod use_state;
 pub use use_state::*;
+mod state_arena;
+pub use state_arena::*;
 mod use_memo;
 pub use use_memo::*;
 mod use_effect;
 pub use use_effect::*;
 mod use_insert_before;
 pub use use_insert_before::*;
+mod use_inline_height;
+pub use use_inline_height::*;
+mod use_history;
+pub use use_history::*;
+mod use_terminal_control;
+pub use use_terminal_control::*;
+mod use_dialog;
+pub use use_dialog::*;
+mod use_samples;
+pub use use_samples::*;
+mod use_paginated_source;
+pub use use_paginated_source::*;
+mod use_resource;
+pub use use_resource::*;
+mod use_job;
+pub use use_job::*;
+mod use_commands;
+pub use use_commands::*;
+mod use_autocomplete;
+pub use use_autocomplete::*;
+mod use_timer;
+pub use use_timer::*;
+mod use_activity;
+pub use use_activity::*;
+mod use_focus_ring;
+pub use use_focus_ring::*;
+mod use_roving_focus;
+pub use use_roving_focus::*;
+mod use_focus_order;
+pub use use_focus_order::*;
+mod use_announce;
+pub use use_announce::*;
+mod use_render_info;
+pub use use_render_info::*;
+mod use_dev_tools;
+pub use use_dev_tools::*;
+mod use_environment;
+pub use use_environment::*;
 
 #[cfg(feature = "router")]
 mod use_router;
 #[cfg(feature = "router")]
 pub use use_router::*;
 
+#[cfg(feature = "watch")]
+mod use_watch_file;
+#[cfg(feature = "watch")]
+pub use use_watch_file::*;
+
+#[cfg(feature = "metrics")]
+mod use_system_monitor;
+#[cfg(feature = "metrics")]
+pub use use_system_monitor::*;
+
+#[cfg(feature = "fetch")]
+mod use_fetch;
+#[cfg(feature = "fetch")]
+pub use use_fetch::*;
+
+#[cfg(feature = "websocket")]
+mod use_websocket;
+#[cfg(feature = "websocket")]
+pub use use_websocket::*;
+
+#[cfg(feature = "process")]
+mod use_command;
+#[cfg(feature = "process")]
+pub use use_command::*;
+
+#[cfg(feature = "persist")]
+mod use_persisted_state;
+#[cfg(feature = "persist")]
+pub(crate) use use_persisted_state::PersistedStates;
+#[cfg(feature = "persist")]
+pub use use_persisted_state::UsePersistedState;
+
 /// 所有自定义 hook 的 trait 基础，定义生命周期相关回调。
 ///
 /// - `poll_change`：异步/响应式副作用轮询，适合 use_future/use_effect 等。
@@ -72,16 +155,27 @@ pub trait Hook: Unpin + Send {
 
     fn pre_component_draw(&mut self, _drawer: &mut ComponentDrawer) {}
     fn post_component_draw(&mut self, _drawer: &mut ComponentDrawer) {}
+
+    /// 所在子树被 [`Activity`](crate::components::Activity) 停用或重新启用时调用。
+    ///
+    /// 停用期间子树不会被轮询，`poll_change` 暂停，异步任务和计时器随之暂停。
+    fn on_active_change(&mut self, _active: bool) {}
 }
 
 pub(crate) trait AnyHook: Hook {
     fn any_self_mut(&mut self) -> &mut dyn Any;
+
+    fn type_name(&self) -> &'static str;
 }
 
 impl<T: Hook + 'static> AnyHook for T {
     fn any_self_mut(&mut self) -> &mut dyn Any {
         self
     }
+
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<T>()
+    }
 }
 
 impl Hook for Vec<Box<dyn AnyHook>> {
@@ -123,6 +217,12 @@ impl Hook for Vec<Box<dyn AnyHook>> {
             hook.post_component_draw(_updater);
         }
     }
+
+    fn on_active_change(&mut self, active: bool) {
+        for hook in self.iter_mut() {
+            hook.on_active_change(active);
+        }
+    }
 }
 
 /// hooks 管理器，负责组件内所有 hook 的注册、索引和生命周期。
@@ -139,17 +239,34 @@ impl Hook for Vec<Box<dyn AnyHook>> {
 pub struct Hooks<'a, 'b: 'a> {
     hooks: &'a mut Vec<Box<dyn AnyHook>>,
     first_update: bool,
-    hook_index: usize,
+    // 与派生出的 Hooks 共享，更新结束后用于检查本次调用的 hook 数量
+    hook_index: &'a mut usize,
+    // 组件实例的状态存储，由 use_state 按需创建
+    states: &'a mut Option<Owner<SyncStorage>>,
+    component_id: u64,
+    component_name: &'static str,
     pub(crate) context: Option<&'a ContextStack<'b>>,
 }
 
-impl<'a> Hooks<'a, '_> {
-    pub(crate) fn new(hooks: &'a mut Vec<Box<dyn AnyHook>>, first_update: bool) -> Self {
+impl<'a, 'b> Hooks<'a, 'b> {
+    pub(crate) fn new(
+        hooks: &'a mut Vec<Box<dyn AnyHook>>,
+        hook_index: &'a mut usize,
+        states: &'a mut Option<Owner<SyncStorage>>,
+        first_update: bool,
+        component_id: u64,
+        component_name: &'static str,
+        context: &'a ContextStack<'b>,
+    ) -> Self {
+        *hook_index = 0;
         Self {
             hooks,
             first_update,
-            hook_index: 0,
-            context: None,
+            hook_index,
+            states,
+            component_id,
+            component_name,
+            context: Some(context),
         }
     }
 
@@ -161,6 +278,9 @@ impl<'a> Hooks<'a, '_> {
             hooks: self.hooks,
             first_update: self.first_update,
             hook_index: self.hook_index,
+            states: self.states,
+            component_id: self.component_id,
+            component_name: self.component_name,
             context: Some(context),
         }
     }
@@ -173,12 +293,40 @@ impl<'a> Hooks<'a, '_> {
         if self.first_update {
             self.hooks.push(Box::new(f()));
         }
-        let idx = self.hook_index;
-        self.hook_index += 1;
+        let idx = *self.hook_index;
+        *self.hook_index += 1;
+
+        let component_name = self.component_name;
+        let Some(hook) = self.hooks.get_mut(idx) else {
+            panic!(
+                "Hook order mismatch in component `{component_name}`: hook #{} (`{}`) was not called on the first render. \
+                 Hooks must not be called inside conditions or loops.",
+                idx + 1,
+                std::any::type_name::<H>(),
+            );
+        };
+        let expected = hook.type_name();
+        match hook.any_self_mut().downcast_mut::<H>() {
+            Some(hook) => hook,
+            None => panic!(
+                "Hook order mismatch in component `{component_name}`: hook #{} was `{expected}` on the first render but is now `{}`. \
+                 Hooks must not be called inside conditions or loops.",
+                idx + 1,
+                std::any::type_name::<H>(),
+            ),
+        }
+    }
+}
 
-        self.hooks
-            .get_mut(idx)
-            .and_then(|hook| hook.any_self_mut().downcast_mut::<H>())
-            .expect("Hook type mismatch, ensure the hook is of the correct type")
+/// 检查本次更新调用的 hook 数量是否与首次渲染一致，仅在 debug 构建中生效。
+pub(crate) fn check_hook_count(hooks: &[Box<dyn AnyHook>], used: usize, component_name: &str) {
+    if cfg!(debug_assertions) && used < hooks.len() {
+        panic!(
+            "Hook order mismatch in component `{component_name}`: {used} hooks were called but {} were called on the first render, \
+             first missing hook is #{} (`{}`). Hooks must not be called inside conditions or loops.",
+            hooks.len(),
+            used + 1,
+            hooks[used].type_name(),
+        );
     }
 }
@@ -0,0 +1,145 @@
+use std::{
+    any::Any,
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
+
+use crate::State;
+
+/// 状态槽位的键：组件实例 id 和 hook 在组件中的序号。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StateSlotKey {
+    /// 组件实例 id，见 [`InstantiatedComponent::id`](crate::InstantiatedComponent::id)。
+    pub component_id: u64,
+    /// `use_state` 在组件 hook 列表中的序号，从 0 开始。
+    pub hook_index: usize,
+}
+
+/// 状态槽位的描述信息。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StateSlotInfo {
+    pub key: StateSlotKey,
+    /// 组件的完整类型名。
+    pub component_name: &'static str,
+    /// 状态值的类型名。
+    pub type_name: &'static str,
+}
+
+struct Slot {
+    info: StateSlotInfo,
+    // 实际类型为 State<T>
+    state: Box<dyn Any + Send + Sync>,
+}
+
+/// 组件树的状态登记表，记录树中所有 `use_state` 创建的状态，按组件和 hook 序号索引。
+///
+/// 每棵组件树一个，作为根上下文提供，状态随组件卸载自动移除。可用于状态快照和调试工具：
+/// 列出全部槽位、按类型读取或修改某个状态。
+///
+/// # 示例
+/// ```rust
+/// let states = app.tree().states();
+/// for slot in states.slots() {
+///     println!("{} #{}: {}", slot.component_name, slot.key.hook_index, slot.type_name);
+/// }
+/// let counters: Vec<(StateSlotKey, i32)> = states.snapshot::<i32>();
+/// ```
+#[derive(Clone, Default)]
+pub struct StateArena {
+    slots: Arc<Mutex<BTreeMap<StateSlotKey, Slot>>>,
+    // `use_persisted_state` 创建的状态
+    #[cfg(feature = "persist")]
+    persisted: Arc<Mutex<super::PersistedStates>>,
+}
+
+impl StateArena {
+    #[cfg(feature = "persist")]
+    pub(crate) fn persisted(&self) -> Arc<Mutex<super::PersistedStates>> {
+        self.persisted.clone()
+    }
+
+    pub(crate) fn insert<T: Send + Sync + 'static>(
+        &self,
+        key: StateSlotKey,
+        component_name: &'static str,
+        state: State<T>,
+    ) {
+        let info = StateSlotInfo {
+            key,
+            component_name,
+            type_name: std::any::type_name::<T>(),
+        };
+        self.slots.lock().unwrap().insert(
+            key,
+            Slot {
+                info,
+                state: Box::new(state),
+            },
+        );
+    }
+
+    pub(crate) fn remove(&self, key: StateSlotKey) {
+        self.slots.lock().unwrap().remove(&key);
+    }
+
+    /// 已登记的状态数量。
+    pub fn len(&self) -> usize {
+        self.slots.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 全部状态槽位，按组件 id 和 hook 序号排序。
+    pub fn slots(&self) -> Vec<StateSlotInfo> {
+        self.slots
+            .lock()
+            .unwrap()
+            .values()
+            .map(|slot| slot.info)
+            .collect()
+    }
+
+    /// 某个组件实例的状态槽位。
+    pub fn component_slots(&self, component_id: u64) -> Vec<StateSlotInfo> {
+        let start = StateSlotKey {
+            component_id,
+            hook_index: 0,
+        };
+        self.slots
+            .lock()
+            .unwrap()
+            .range(start..)
+            .take_while(|(key, _)| key.component_id == component_id)
+            .map(|(_, slot)| slot.info)
+            .collect()
+    }
+
+    /// 按键取得状态句柄，类型不符或状态已移除时返回 None。
+    ///
+    /// 通过句柄修改状态会像组件内修改一样触发重新渲染。
+    pub fn get<T: Send + Sync + 'static>(&self, key: StateSlotKey) -> Option<State<T>> {
+        self.slots
+            .lock()
+            .unwrap()
+            .get(&key)
+            .and_then(|slot| slot.state.downcast_ref::<State<T>>().copied())
+    }
+
+    /// 复制所有类型为 `T` 的状态的当前值。
+    pub fn snapshot<T: Clone + Send + Sync + 'static>(&self) -> Vec<(StateSlotKey, T)> {
+        // 先取出句柄再读取，避免持有登记表的锁时读取状态
+        let states = self
+            .slots
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|(key, slot)| Some((*key, *slot.state.downcast_ref::<State<T>>()?)))
+            .collect::<Vec<_>>();
+        states
+            .into_iter()
+            .filter_map(|(key, state)| Some((key, state.try_read()?.clone())))
+            .collect()
+    }
+}
@@ -0,0 +1,116 @@
+use crate::components::ActivityContext;
+
+use super::{Hook, Hooks};
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+pub trait UseActivity: private::Sealed {
+    /// 所在子树是否处于启用状态，不在任何 [`Activity`](crate::components::Activity) 中时始终为 true。
+    fn use_is_active(&self) -> bool;
+
+    /// 所在子树被停用或重新启用时调用 `f`，参数为新的启用状态。
+    ///
+    /// 适合在页面隐藏时释放资源、返回时刷新数据。停用期间组件的异步任务已自动暂停。
+    fn use_active_change<F>(&mut self, f: F)
+    where
+        F: FnMut(bool) + Send + 'static;
+}
+
+struct UseActiveChangeImpl {
+    f: Option<Box<dyn FnMut(bool) + Send>>,
+}
+
+impl Hook for UseActiveChangeImpl {
+    fn on_active_change(&mut self, active: bool) {
+        if let Some(f) = &mut self.f {
+            f(active);
+        }
+    }
+}
+
+impl UseActivity for Hooks<'_, '_> {
+    fn use_is_active(&self) -> bool {
+        self.context
+            .and_then(|context| context.get_context::<ActivityContext>())
+            .is_none_or(|activity| activity.is_active())
+    }
+
+    fn use_active_change<F>(&mut self, f: F)
+    where
+        F: FnMut(bool) + Send + 'static,
+    {
+        let hook = self.use_hook(|| UseActiveChangeImpl { f: None });
+        hook.f = Some(Box::new(f));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    };
+
+    use crossterm::event::{Event, KeyCode};
+
+    use crate::{
+        prelude::*,
+        test_util::{keys, run_events},
+    };
+
+    #[derive(Default, Props)]
+    struct StatusProps {
+        active: Arc<AtomicBool>,
+    }
+
+    struct Status;
+
+    impl Component for Status {
+        type Props<'a> = StatusProps;
+
+        fn new(_props: &Self::Props<'_>) -> Self {
+            Self
+        }
+
+        fn update(
+            &mut self,
+            props: &mut Self::Props<'_>,
+            hooks: Hooks,
+            _updater: &mut ComponentUpdater,
+        ) {
+            props.active.store(hooks.use_is_active(), Ordering::SeqCst);
+        }
+    }
+
+    #[component]
+    fn Page(props: &StatusProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut active = hooks.use_state(|| true);
+        hooks.use_events(move |event| {
+            if let Event::Key(key) = event {
+                if key.code == KeyCode::Char('p') {
+                    active.set(!active.get());
+                }
+            }
+        });
+        element!(Activity(active: active.get()) {
+            Status(active: props.active.clone())
+        })
+    }
+
+    fn active_after(codes: impl IntoIterator<Item = KeyCode>) -> bool {
+        let active = Arc::new(AtomicBool::new(false));
+        run_events(element!(Page(active: active.clone())), keys(codes));
+        active.load(Ordering::SeqCst)
+    }
+
+    #[test]
+    fn pauses_class_components() {
+        assert!(active_after([]));
+        assert!(!active_after([KeyCode::Char('p')]));
+        assert!(active_after([KeyCode::Char('p'), KeyCode::Char('p')]));
+    }
+}
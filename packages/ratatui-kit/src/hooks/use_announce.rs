@@ -0,0 +1,38 @@
+use super::{Hook, Hooks};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+pub trait UseAnnounce: private::Sealed {
+    /// 类似 live region 的通知：`message` 变化且不为空时，通过[无障碍输出](crate::AccessibilityOutput)输出一次。
+    ///
+    /// 未开启无障碍输出时没有效果。
+    ///
+    /// ```rust
+    /// hooks.use_announce(if saved.get() { "已保存" } else { "" });
+    /// ```
+    fn use_announce(&mut self, message: impl Into<String>);
+}
+
+struct UseAnnounceImpl {
+    last: String,
+}
+
+impl Hook for UseAnnounceImpl {}
+
+impl UseAnnounce for Hooks<'_, '_> {
+    fn use_announce(&mut self, message: impl Into<String>) {
+        let message = message.into();
+        let hook = self.use_hook(|| UseAnnounceImpl {
+            last: String::new(),
+        });
+        if hook.last != message {
+            if !message.is_empty() {
+                crate::terminal::queue_announcement(message.clone());
+            }
+            hook.last = message;
+        }
+    }
+}
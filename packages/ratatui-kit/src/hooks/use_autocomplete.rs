@@ -0,0 +1,214 @@
+use std::{
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use ratatui::layout::Position;
+
+use crate::{Hook, Hooks};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+pub trait UseAutocomplete: private::Sealed {
+    /// 创建自动补全状态，`source` 根据光标前的词返回候选项。
+    ///
+    /// 候选项按与输入的匹配程度排序（前缀 > 词首 > 包含 > 子序列），不匹配的候选项会被过滤。
+    /// 返回的 [`Autocomplete`] 交给 [`AutocompletePopup`](crate::components::AutocompletePopup)，
+    /// 其内部的输入组件会在输入时更新候选项，并在选中后插入补全内容。
+    fn use_autocomplete<F>(&mut self, source: F) -> Autocomplete
+    where
+        F: Fn(&str) -> Vec<String> + Send + Sync + 'static;
+}
+
+type SourceFn = Box<dyn Fn(&str) -> Vec<String> + Send + Sync>;
+
+/// 选中的补全项，输入组件用 `text` 替换光标前的 `replace` 个字符。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion {
+    pub replace: usize,
+    pub text: String,
+}
+
+struct AutocompleteShared {
+    source: SourceFn,
+    query: String,
+    suggestions: Vec<String>,
+    selected: usize,
+    open: bool,
+    // 输入组件上报的光标屏幕位置
+    caret: Option<Position>,
+    dirty: bool,
+    waker: Option<Waker>,
+}
+
+impl AutocompleteShared {
+    fn wake(&mut self) {
+        self.dirty = true;
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// 自动补全状态句柄，可克隆，由 [`UseAutocomplete::use_autocomplete`] 创建。
+#[derive(Clone)]
+pub struct Autocomplete {
+    shared: Arc<Mutex<AutocompleteShared>>,
+}
+
+// 匹配得分，越小越靠前，不匹配时返回 None
+fn rank(candidate: &str, query: &str) -> Option<u8> {
+    let candidate = candidate.to_lowercase();
+    let query = query.to_lowercase();
+    if candidate.starts_with(&query) {
+        return Some(0);
+    }
+    if candidate
+        .match_indices(&query)
+        .any(|(i, _)| !candidate[..i].ends_with(char::is_alphanumeric))
+    {
+        return Some(1);
+    }
+    if candidate.contains(&query) {
+        return Some(2);
+    }
+    let mut chars = candidate.chars();
+    query.chars().all(|q| chars.any(|c| c == q)).then_some(3)
+}
+
+impl Autocomplete {
+    /// 根据光标前的文本更新候选项，取最后一个词作为查询，词为空时关闭弹窗。
+    pub fn update(&self, before_cursor: &str) {
+        let query = before_cursor
+            .rsplit(char::is_whitespace)
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        let mut shared = self.shared.lock().unwrap();
+        let suggestions = if query.is_empty() {
+            Vec::new()
+        } else {
+            let mut ranked = (shared.source)(&query)
+                .into_iter()
+                .filter(|candidate| *candidate != query)
+                .filter_map(|candidate| rank(&candidate, &query).map(|score| (score, candidate)))
+                .collect::<Vec<_>>();
+            ranked.sort_by(|(a, x), (b, y)| a.cmp(b).then(x.len().cmp(&y.len())).then(x.cmp(y)));
+            ranked.dedup_by(|(_, x), (_, y)| x == y);
+            ranked.into_iter().map(|(_, candidate)| candidate).collect()
+        };
+        shared.open = !suggestions.is_empty();
+        shared.suggestions = suggestions;
+        shared.selected = 0;
+        shared.query = query;
+        shared.wake();
+    }
+
+    /// 弹窗是否打开。
+    pub fn is_open(&self) -> bool {
+        self.shared.lock().unwrap().open
+    }
+
+    /// 当前查询的词。
+    pub fn query(&self) -> String {
+        self.shared.lock().unwrap().query.clone()
+    }
+
+    /// 排序后的候选项。
+    pub fn suggestions(&self) -> Vec<String> {
+        self.shared.lock().unwrap().suggestions.clone()
+    }
+
+    /// 当前选中的候选项索引。
+    pub fn selected(&self) -> usize {
+        self.shared.lock().unwrap().selected
+    }
+
+    /// 循环选择下一个（`delta` 为正）或上一个候选项。
+    pub fn select(&self, delta: isize) {
+        let mut shared = self.shared.lock().unwrap();
+        let len = shared.suggestions.len() as isize;
+        if len == 0 {
+            return;
+        }
+        shared.selected = (shared.selected as isize + delta).rem_euclid(len) as usize;
+        shared.wake();
+    }
+
+    /// 接受当前选中的候选项并关闭弹窗。
+    pub fn accept(&self) -> Option<Suggestion> {
+        let mut shared = self.shared.lock().unwrap();
+        if !shared.open {
+            return None;
+        }
+        shared.open = false;
+        let text = shared.suggestions.get(shared.selected).cloned();
+        let replace = shared.query.chars().count();
+        shared.wake();
+        text.map(|text| Suggestion { replace, text })
+    }
+
+    /// 关闭弹窗，下次输入时重新打开。
+    pub fn dismiss(&self) {
+        let mut shared = self.shared.lock().unwrap();
+        if shared.open {
+            shared.open = false;
+            shared.wake();
+        }
+    }
+
+    /// 上报光标的屏幕位置，弹窗显示在其下方。
+    pub fn set_caret(&self, position: Option<Position>) {
+        self.shared.lock().unwrap().caret = position;
+    }
+
+    /// 输入组件上报的光标位置。
+    pub fn caret(&self) -> Option<Position> {
+        self.shared.lock().unwrap().caret
+    }
+}
+
+struct UseAutocompleteImpl {
+    autocomplete: Autocomplete,
+}
+
+impl Hook for UseAutocompleteImpl {
+    fn poll_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let mut shared = self.autocomplete.shared.lock().unwrap();
+        shared.waker = Some(cx.waker().clone());
+        if std::mem::take(&mut shared.dirty) {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl UseAutocomplete for Hooks<'_, '_> {
+    fn use_autocomplete<F>(&mut self, source: F) -> Autocomplete
+    where
+        F: Fn(&str) -> Vec<String> + Send + Sync + 'static,
+    {
+        let hook = self.use_hook(|| UseAutocompleteImpl {
+            autocomplete: Autocomplete {
+                shared: Arc::new(Mutex::new(AutocompleteShared {
+                    source: Box::new(|_| Vec::new()),
+                    query: String::new(),
+                    suggestions: Vec::new(),
+                    selected: 0,
+                    open: false,
+                    caret: None,
+                    dirty: false,
+                    waker: None,
+                })),
+            },
+        });
+        // 每次渲染更新候选项来源，使其捕获最新的值
+        hook.autocomplete.shared.lock().unwrap().source = Box::new(source);
+        hook.autocomplete.clone()
+    }
+}
@@ -0,0 +1,371 @@
+use std::{
+    collections::VecDeque,
+    path::PathBuf,
+    pin::Pin,
+    process::Stdio,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use futures::{
+    FutureExt, StreamExt,
+    future::BoxFuture,
+    stream::{self, BoxStream},
+};
+use ratatui::text::Line;
+use tokio::{
+    io::{AsyncBufReadExt, AsyncRead, BufReader},
+    process::Command,
+};
+
+use crate::{Hook, Hooks, runtime::spawn_io, text::parse_ansi};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+pub trait UseCommand: private::Sealed {
+    /// 在挂载时启动子进程，逐行收集 stdout/stderr 输出，有新输出或进程退出时重新渲染当前组件。
+    ///
+    /// 组件卸载时结束子进程；`command` 变化不会重新启动，需要时调用 [`Process::restart`]。
+    /// 注册命令行中的 ex 命令请使用 [`use_ex_command`](crate::UseCommands::use_ex_command)。
+    fn use_command(&mut self, command: impl Into<ProcessCommand>) -> Process;
+}
+
+/// 要执行的命令。
+///
+/// # 示例
+/// ```rust
+/// let process = hooks.use_command(
+///     ProcessCommand::new("git")
+///         .args(["log", "--oneline", "--color=always"])
+///         .current_dir(repo),
+/// );
+/// // 简单命令可直接传入字符串，按空白拆分参数
+/// let process = hooks.use_command("docker ps");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProcessCommand {
+    program: String,
+    args: Vec<String>,
+    current_dir: Option<PathBuf>,
+    envs: Vec<(String, String)>,
+    capacity: usize,
+}
+
+impl ProcessCommand {
+    pub fn new(program: impl Into<String>) -> Self {
+        Self {
+            program: program.into(),
+            args: Vec::new(),
+            current_dir: None,
+            envs: Vec::new(),
+            capacity: 10_000,
+        }
+    }
+
+    /// 通过系统 shell 执行命令行，支持管道、重定向等 shell 语法。
+    pub fn shell(command: impl Into<String>) -> Self {
+        if cfg!(windows) {
+            Self::new("cmd").args(["/C".to_string(), command.into()])
+        } else {
+            Self::new("sh").args(["-c".to_string(), command.into()])
+        }
+    }
+
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    pub fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    /// 工作目录。
+    pub fn current_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.current_dir = Some(dir.into());
+        self
+    }
+
+    /// 设置环境变量。
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.envs.push((key.into(), value.into()));
+        self
+    }
+
+    /// 保留的最近输出行数。
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+}
+
+impl From<&str> for ProcessCommand {
+    fn from(command: &str) -> Self {
+        let mut parts = command.split_whitespace();
+        Self::new(parts.next().unwrap_or_default()).args(parts)
+    }
+}
+
+impl From<String> for ProcessCommand {
+    fn from(command: String) -> Self {
+        command.as_str().into()
+    }
+}
+
+/// 子进程状态。
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum ProcessStatus {
+    #[default]
+    Running,
+    /// 进程已退出，被信号结束时没有退出码。
+    Exited(Option<i32>),
+    /// 调用 [`Process::kill`] 结束。
+    Killed,
+    /// 启动或等待进程失败，附带错误信息。
+    Failed(String),
+}
+
+impl ProcessStatus {
+    /// 是否以退出码 0 结束。
+    pub fn is_success(&self) -> bool {
+        *self == Self::Exited(Some(0))
+    }
+}
+
+/// 输出来源。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
+/// 一行输出，保留原始的 ANSI 转义序列。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessLine {
+    pub stream: OutputStream,
+    pub text: String,
+}
+
+impl ProcessLine {
+    /// 解析 ANSI 转义序列，转换为可直接交给 LogView 的行。
+    pub fn to_line(&self) -> Line<'static> {
+        parse_ansi(&self.text)
+            .lines
+            .into_iter()
+            .next()
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Default)]
+struct ProcessShared {
+    status: ProcessStatus,
+    lines: VecDeque<ProcessLine>,
+    kill: bool,
+    restart: bool,
+    dirty: bool,
+    waker: Option<Waker>,
+}
+
+impl ProcessShared {
+    fn wake(&mut self) {
+        self.dirty = true;
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// 子进程句柄，可克隆到事件处理器中结束或重新启动进程。
+///
+/// # 示例
+/// ```rust
+/// let process = hooks.use_command(ProcessCommand::shell("kubectl get pods -w"));
+/// let title = match process.status() {
+///     ProcessStatus::Running => "运行中".to_string(),
+///     ProcessStatus::Exited(code) => format!("已退出：{code:?}"),
+///     ProcessStatus::Killed => "已结束".to_string(),
+///     ProcessStatus::Failed(err) => format!("启动失败：{err}"),
+/// };
+/// ```
+#[derive(Clone)]
+pub struct Process {
+    shared: Arc<Mutex<ProcessShared>>,
+}
+
+impl Process {
+    /// 当前状态。
+    pub fn status(&self) -> ProcessStatus {
+        self.shared.lock().unwrap().status.clone()
+    }
+
+    /// 进程是否仍在运行。
+    pub fn is_running(&self) -> bool {
+        self.status() == ProcessStatus::Running
+    }
+
+    /// 最近的输出行，stdout 和 stderr 按到达顺序交错排列。
+    pub fn lines(&self) -> Vec<ProcessLine> {
+        self.shared.lock().unwrap().lines.iter().cloned().collect()
+    }
+
+    /// 结束子进程。
+    pub fn kill(&self) {
+        let mut shared = self.shared.lock().unwrap();
+        shared.kill = true;
+        shared.wake();
+    }
+
+    /// 结束正在运行的子进程，清空输出后重新启动。
+    pub fn restart(&self) {
+        let mut shared = self.shared.lock().unwrap();
+        shared.restart = true;
+        shared.wake();
+    }
+}
+
+fn update(shared: &Mutex<ProcessShared>, f: impl FnOnce(&mut ProcessShared)) {
+    let mut shared = shared.lock().unwrap();
+    f(&mut shared);
+    shared.wake();
+}
+
+// 按字节读取行，非 UTF-8 内容按有损方式转换，不会中断读取
+fn read_lines<R>(reader: R, stream: OutputStream) -> BoxStream<'static, ProcessLine>
+where
+    R: AsyncRead + Unpin + Send + 'static,
+{
+    stream::unfold(BufReader::new(reader), move |mut reader| async move {
+        let mut buf = Vec::new();
+        match reader.read_until(b'\n', &mut buf).await {
+            Ok(0) | Err(_) => None,
+            Ok(_) => {
+                let text = String::from_utf8_lossy(&buf);
+                let text = text.trim_end_matches(['\n', '\r']).to_string();
+                Some((ProcessLine { stream, text }, reader))
+            }
+        }
+    })
+    .boxed()
+}
+
+async fn run(command: ProcessCommand, shared: Arc<Mutex<ProcessShared>>) {
+    let mut cmd = Command::new(&command.program);
+    cmd.args(&command.args)
+        .envs(command.envs.iter().map(|(k, v)| (k, v)))
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        // 丢弃 future（组件卸载、kill、restart）时结束子进程
+        .kill_on_drop(true);
+    if let Some(dir) = &command.current_dir {
+        cmd.current_dir(dir);
+    }
+    let mut child = match cmd.spawn() {
+        Ok(child) => child,
+        Err(err) => {
+            update(&shared, |shared| {
+                shared.status = ProcessStatus::Failed(err.to_string())
+            });
+            return;
+        }
+    };
+
+    let stdout = child
+        .stdout
+        .take()
+        .map(|stdout| read_lines(stdout, OutputStream::Stdout));
+    let stderr = child
+        .stderr
+        .take()
+        .map(|stderr| read_lines(stderr, OutputStream::Stderr));
+    let mut output = stream::select(
+        stream::iter(stdout).flatten(),
+        stream::iter(stderr).flatten(),
+    );
+    while let Some(line) = output.next().await {
+        update(&shared, |shared| {
+            if shared.lines.len() >= command.capacity {
+                shared.lines.pop_front();
+            }
+            shared.lines.push_back(line);
+        });
+    }
+
+    let status = match child.wait().await {
+        Ok(status) => ProcessStatus::Exited(status.code()),
+        Err(err) => ProcessStatus::Failed(err.to_string()),
+    };
+    update(&shared, |shared| shared.status = status);
+}
+
+struct UseCommandImpl {
+    command: ProcessCommand,
+    shared: Arc<Mutex<ProcessShared>>,
+    future: Option<BoxFuture<'static, ()>>,
+}
+
+impl UseCommandImpl {
+    fn spawn(&mut self) {
+        update(&self.shared, |shared| {
+            shared.status = ProcessStatus::Running;
+            shared.lines.clear();
+        });
+        self.future = Some(spawn_io(run(self.command.clone(), self.shared.clone())));
+    }
+}
+
+impl Hook for UseCommandImpl {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let this = &mut *self;
+        let (kill, restart) = {
+            let mut shared = this.shared.lock().unwrap();
+            (
+                std::mem::take(&mut shared.kill),
+                std::mem::take(&mut shared.restart),
+            )
+        };
+        if restart {
+            this.spawn();
+        } else if kill && this.future.take().is_some() {
+            update(&this.shared, |shared| shared.status = ProcessStatus::Killed);
+        }
+        if let Some(future) = this.future.as_mut() {
+            if future.as_mut().poll(cx).is_ready() {
+                this.future = None;
+            }
+        }
+        let mut shared = this.shared.lock().unwrap();
+        shared.waker = Some(cx.waker().clone());
+        if std::mem::take(&mut shared.dirty) {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl UseCommand for Hooks<'_, '_> {
+    fn use_command(&mut self, command: impl Into<ProcessCommand>) -> Process {
+        let hook = self.use_hook(|| {
+            let mut hook = UseCommandImpl {
+                command: command.into(),
+                shared: Arc::new(Mutex::new(ProcessShared::default())),
+                future: None,
+            };
+            hook.spawn();
+            hook
+        });
+        Process {
+            shared: hook.shared.clone(),
+        }
+    }
+}
@@ -0,0 +1,409 @@
+use std::{
+    sync::{Arc, Mutex},
+    task::Waker,
+};
+
+use crate::{Hook, Hooks, UseContext};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+pub trait UseCommands: private::Sealed {
+    /// 获取命令注册表，需在 CommandLine 内部使用。
+    fn use_commands(&self) -> Commands;
+
+    /// 注册在命令行中以 `:` 执行的 ex 命令，组件卸载时自动注销；每次渲染更新处理函数，使其捕获最新的值。
+    ///
+    /// 启动子进程请使用 `process` 特性提供的 `use_command`。
+    ///
+    /// # 示例
+    /// ```rust
+    /// hooks.use_ex_command(
+    ///     Command::new("open", "打开文件").alias("o").usage("<path>").complete_with(complete_path),
+    ///     move |args| {
+    ///         let path = args.get(0).ok_or("缺少路径")?;
+    ///         open(path).map_err(|err| err.to_string())
+    ///     },
+    /// );
+    /// ```
+    fn use_ex_command<F>(&mut self, command: Command, f: F)
+    where
+        F: FnMut(CommandArgs) -> Result<(), String> + Send + 'static;
+}
+
+type CommandHandler = Box<dyn FnMut(CommandArgs) -> Result<(), String> + Send>;
+type Completer = Arc<dyn Fn(&str, usize) -> Vec<String> + Send + Sync>;
+
+/// 命令定义，通过 `hooks.use_ex_command` 注册。
+#[derive(Clone)]
+pub struct Command {
+    pub name: String,
+    pub aliases: Vec<String>,
+    pub description: String,
+    /// 参数说明，如 `<path>`，用于帮助信息。
+    pub usage: String,
+    completer: Option<Completer>,
+}
+
+impl Command {
+    pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            aliases: Vec::new(),
+            description: description.into(),
+            usage: String::new(),
+            completer: None,
+        }
+    }
+
+    /// 添加别名，如 `write` 的别名 `w`。
+    pub fn alias(mut self, alias: impl Into<String>) -> Self {
+        self.aliases.push(alias.into());
+        self
+    }
+
+    /// 设置参数说明。
+    pub fn usage(mut self, usage: impl Into<String>) -> Self {
+        self.usage = usage.into();
+        self
+    }
+
+    /// 设置参数补全函数，参数为正在输入的参数前缀及其序号，返回候选项。
+    pub fn complete_with<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&str, usize) -> Vec<String> + Send + Sync + 'static,
+    {
+        self.completer = Some(Arc::new(f));
+        self
+    }
+
+    pub(crate) fn matches(&self, name: &str) -> bool {
+        self.name == name || self.aliases.iter().any(|alias| alias == name)
+    }
+
+    // 调用补全函数，没有补全函数时返回空
+    pub(crate) fn complete(&self, prefix: &str, index: usize) -> Vec<String> {
+        self.completer
+            .as_ref()
+            .map(|completer| completer(prefix, index))
+            .unwrap_or_default()
+    }
+}
+
+/// 解析后的命令行，如 `:w! a.txt "b c"` 解析为名称 `w`、`bang` 为 true、参数 `["a.txt", "b c"]`。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommandArgs {
+    pub name: String,
+    /// 名称后是否带 `!`。
+    pub bang: bool,
+    pub args: Vec<String>,
+}
+
+impl CommandArgs {
+    /// 解析命令行，参数以空白分隔，支持单双引号和 `\` 转义，空输入返回 None。
+    pub fn parse(input: &str) -> Option<Self> {
+        let mut words = split_words(input).into_iter();
+        let mut name = words.next()?;
+        let bang = name.ends_with('!');
+        if bang {
+            name.pop();
+        }
+        Some(Self {
+            name,
+            bang,
+            args: words.collect(),
+        })
+    }
+
+    /// 第 `index` 个参数。
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.args.get(index).map(String::as_str)
+    }
+
+    /// 全部参数以空格连接，适合接收一整段文本的命令。
+    pub fn rest(&self) -> String {
+        self.args.join(" ")
+    }
+}
+
+fn split_words(input: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = None::<String>;
+    let mut quote = None;
+    let mut chars = input.chars();
+    while let Some(c) = chars.next() {
+        match (c, quote) {
+            ('\\', _) => {
+                if let Some(next) = chars.next() {
+                    word.get_or_insert_default().push(next);
+                }
+            }
+            ('"' | '\'', None) => {
+                quote = Some(c);
+                word.get_or_insert_default();
+            }
+            (c, Some(q)) if c == q => quote = None,
+            (c, None) if c.is_whitespace() => words.extend(word.take()),
+            (c, _) => word.get_or_insert_default().push(c),
+        }
+    }
+    words.extend(word);
+    words
+}
+
+/// 补全文件路径，目录以 `/` 结尾，可作为 [`Command::complete_with`] 的参数。
+pub fn complete_path(prefix: &str, _index: usize) -> Vec<String> {
+    let (dir, file) = match prefix.rfind('/') {
+        Some(i) => (&prefix[..=i], &prefix[i + 1..]),
+        None => ("", prefix),
+    };
+    let Ok(entries) = std::fs::read_dir(if dir.is_empty() { "." } else { dir }) else {
+        return Vec::new();
+    };
+    let mut candidates = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            // 未输入 `.` 时不显示隐藏文件
+            if !name.starts_with(file) || (name.starts_with('.') && !file.starts_with('.')) {
+                return None;
+            }
+            let suffix = if entry.path().is_dir() { "/" } else { "" };
+            Some(format!("{dir}{name}{suffix}"))
+        })
+        .collect::<Vec<_>>();
+    candidates.sort();
+    candidates
+}
+
+struct Registered {
+    id: u64,
+    command: Command,
+    handler: Arc<Mutex<CommandHandler>>,
+}
+
+/// 命令行中的补全状态。
+pub(crate) struct Completion {
+    /// 被替换的词之前的文本。
+    pub(crate) base: String,
+    pub(crate) candidates: Vec<String>,
+    pub(crate) index: usize,
+}
+
+#[derive(Default)]
+pub(crate) struct CommandsShared {
+    commands: Vec<Registered>,
+    next_id: u64,
+    pub(crate) history: Vec<String>,
+    /// 命令行打开时为正在输入的内容。
+    pub(crate) input: Option<String>,
+    pub(crate) history_index: Option<usize>,
+    pub(crate) completion: Option<Completion>,
+    /// 底部显示的消息及是否为错误。
+    pub(crate) message: Option<(String, bool)>,
+    pub(crate) dirty: bool,
+    pub(crate) waker: Option<Waker>,
+}
+
+impl CommandsShared {
+    // 标记需要重新渲染并唤醒 CommandLine
+    pub(crate) fn wake(&mut self) {
+        self.dirty = true;
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// 命令注册表，由最近的 CommandLine 提供。
+///
+/// 命令行打开时其他组件通常应忽略按键，可通过 [`Commands::is_open`] 判断；
+/// `use_keymap` 在命令行打开时会自动暂停分发。
+#[derive(Clone, Default)]
+pub struct Commands {
+    pub(crate) shared: Arc<Mutex<CommandsShared>>,
+}
+
+impl Commands {
+    /// 命令行是否打开。
+    pub fn is_open(&self) -> bool {
+        self.shared.lock().unwrap().input.is_some()
+    }
+
+    /// 打开命令行，`input` 为预填的内容。
+    pub fn open(&self, input: impl Into<String>) {
+        let mut shared = self.shared.lock().unwrap();
+        shared.input = Some(input.into());
+        shared.history_index = None;
+        shared.completion = None;
+        shared.message = None;
+        shared.wake();
+    }
+
+    /// 关闭命令行。
+    pub fn close(&self) {
+        let mut shared = self.shared.lock().unwrap();
+        shared.input = None;
+        shared.completion = None;
+        shared.wake();
+    }
+
+    /// 在命令行位置显示消息。
+    pub fn echo(&self, message: impl Into<String>) {
+        let mut shared = self.shared.lock().unwrap();
+        shared.message = Some((message.into(), false));
+        shared.wake();
+    }
+
+    /// 所有已注册的命令。
+    pub fn commands(&self) -> Vec<Command> {
+        let shared = self.shared.lock().unwrap();
+        shared.commands.iter().map(|r| r.command.clone()).collect()
+    }
+
+    /// 执行命令行，如 `write a.txt`（不含开头的 `:`），并记入历史。
+    ///
+    /// 执行失败时返回错误信息，同时显示在命令行位置。
+    pub fn execute(&self, input: &str) -> Result<(), String> {
+        let input = input.trim();
+        let Some(args) = CommandArgs::parse(input) else {
+            return Ok(());
+        };
+        let handler = {
+            let mut shared = self.shared.lock().unwrap();
+            if shared.history.last().map(String::as_str) != Some(input) {
+                shared.history.push(input.to_string());
+            }
+            shared
+                .commands
+                .iter()
+                .find(|r| r.command.matches(&args.name))
+                .map(|r| r.handler.clone())
+        };
+        // 不持有锁调用处理函数，处理函数中可以再次使用命令注册表
+        let result = match handler {
+            Some(handler) => (handler.lock().unwrap())(args),
+            None => Err(format!("未知命令：{}", args.name)),
+        };
+        if let Err(err) = &result {
+            let mut shared = self.shared.lock().unwrap();
+            shared.message = Some((err.clone(), true));
+            shared.wake();
+        }
+        result
+    }
+
+    /// 补全候选项：输入命令名时补全命令，之后调用命令的补全函数。
+    pub(crate) fn complete(&self, input: &str) -> Completion {
+        let words = split_words(input);
+        let ends_with_space = input.ends_with(char::is_whitespace);
+        let (prefix, index) = match (words.last(), ends_with_space) {
+            (Some(word), false) => (word.clone(), words.len() - 1),
+            _ => (String::new(), words.len()),
+        };
+        let base = input[..input.len() - raw_suffix_len(input, ends_with_space)].to_string();
+        let shared = self.shared.lock().unwrap();
+        let mut candidates = if index == 0 {
+            shared
+                .commands
+                .iter()
+                .flat_map(|r| std::iter::once(&r.command.name).chain(&r.command.aliases))
+                .filter(|name| name.starts_with(&prefix))
+                .cloned()
+                .collect::<Vec<_>>()
+        } else {
+            let name = words[0].trim_end_matches('!');
+            shared
+                .commands
+                .iter()
+                .find(|r| r.command.matches(name))
+                .map(|r| r.command.complete(&prefix, index - 1))
+                .unwrap_or_default()
+        };
+        candidates.sort();
+        candidates.dedup();
+        Completion {
+            base,
+            candidates,
+            index: 0,
+        }
+    }
+
+    fn register(&self, command: Command, handler: CommandHandler) -> u64 {
+        let mut shared = self.shared.lock().unwrap();
+        let id = shared.next_id;
+        shared.next_id += 1;
+        shared.commands.push(Registered {
+            id,
+            command,
+            handler: Arc::new(Mutex::new(handler)),
+        });
+        id
+    }
+
+    fn update(&self, id: u64, command: Command, handler: CommandHandler) {
+        let mut shared = self.shared.lock().unwrap();
+        if let Some(registered) = shared.commands.iter_mut().find(|r| r.id == id) {
+            registered.command = command;
+            *registered.handler.lock().unwrap() = handler;
+        }
+    }
+
+    fn unregister(&self, id: u64) {
+        self.shared
+            .lock()
+            .unwrap()
+            .commands
+            .retain(|registered| registered.id != id);
+    }
+}
+
+// 正在输入的最后一个词在原始输入中的长度（包括引号）
+fn raw_suffix_len(input: &str, ends_with_space: bool) -> usize {
+    if ends_with_space {
+        return 0;
+    }
+    input
+        .char_indices()
+        .rev()
+        .find(|(_, c)| c.is_whitespace())
+        .map_or(input.len(), |(i, c)| input.len() - i - c.len_utf8())
+}
+
+struct UseCommandImpl {
+    commands: Commands,
+    id: Option<u64>,
+}
+
+impl Hook for UseCommandImpl {}
+
+impl Drop for UseCommandImpl {
+    fn drop(&mut self) {
+        if let Some(id) = self.id {
+            self.commands.unregister(id);
+        }
+    }
+}
+
+impl UseCommands for Hooks<'_, '_> {
+    fn use_commands(&self) -> Commands {
+        self.use_context::<Commands>().clone()
+    }
+
+    fn use_ex_command<F>(&mut self, command: Command, f: F)
+    where
+        F: FnMut(CommandArgs) -> Result<(), String> + Send + 'static,
+    {
+        let commands = self.use_commands();
+        let hook = self.use_hook(|| UseCommandImpl {
+            commands: commands.clone(),
+            id: None,
+        });
+        match hook.id {
+            Some(id) => hook.commands.update(id, command, Box::new(f)),
+            None => hook.id = Some(hook.commands.register(command, Box::new(f))),
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::{
+    Command, CommandArgs, ComponentUpdater, DevTools, Hook, Hooks, UseCommands, UseContext,
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+pub trait UseDevTools: private::Sealed {
+    /// 获取所在组件树的调试命令注册表，可用于实现命令面板或检查器。
+    fn use_dev_tools(&mut self) -> DevTools;
+
+    /// 登记调试命令，组件卸载时自动注销；每次渲染更新处理函数，使其捕获最新的值。
+    ///
+    /// # 示例
+    /// ```rust
+    /// hooks.use_dev_command(Command::new("clear-cache", "清空缓存"), move |_| {
+    ///     cache.write().clear();
+    ///     Ok(())
+    /// });
+    /// ```
+    fn use_dev_command<F>(&mut self, command: Command, f: F)
+    where
+        F: FnMut(CommandArgs) -> Result<(), String> + Send + 'static;
+
+    /// 在最近的 CommandLine 中登记 `:dev <command>` 命令，用于执行调试命令并补全命令名称，
+    /// 延迟执行的命令的结果显示在命令行位置。
+    fn use_dev_commands(&mut self);
+}
+
+struct UseDevToolsImpl {
+    dev_tools: DevTools,
+}
+
+impl Hook for UseDevToolsImpl {
+    fn poll_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        // 有等待执行的操作时触发一次更新，由组件树执行
+        if self.dev_tools.poll_pending(cx.waker()) {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+struct UseDevCommandImpl {
+    dev_tools: DevTools,
+    id: Option<u64>,
+}
+
+impl Hook for UseDevCommandImpl {}
+
+impl Drop for UseDevCommandImpl {
+    fn drop(&mut self) {
+        if let Some(id) = self.id {
+            self.dev_tools.unregister(id);
+        }
+    }
+}
+
+struct UseDevReportImpl {
+    dev_tools: DevTools,
+}
+
+impl Hook for UseDevReportImpl {
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        if let Some((message, error)) = self.dev_tools.take_report() {
+            if let Some(commands) = updater.get_context::<crate::Commands>() {
+                let mut shared = commands.shared.lock().unwrap();
+                shared.message = Some((message, error));
+                shared.wake();
+            }
+        }
+    }
+}
+
+impl UseDevTools for Hooks<'_, '_> {
+    fn use_dev_tools(&mut self) -> DevTools {
+        let dev_tools = self.use_context::<DevTools>().clone();
+        self.use_hook(move || UseDevToolsImpl { dev_tools })
+            .dev_tools
+            .clone()
+    }
+
+    fn use_dev_command<F>(&mut self, command: Command, f: F)
+    where
+        F: FnMut(CommandArgs) -> Result<(), String> + Send + 'static,
+    {
+        let dev_tools = self.use_dev_tools();
+        let hook = self.use_hook(|| UseDevCommandImpl {
+            dev_tools,
+            id: None,
+        });
+        match hook.id {
+            Some(id) => hook.dev_tools.update(id, command, Box::new(f)),
+            None => hook.id = Some(hook.dev_tools.register(command, Box::new(f))),
+        }
+    }
+
+    fn use_dev_commands(&mut self) {
+        let dev_tools = self.use_dev_tools();
+        self.use_hook({
+            let dev_tools = dev_tools.clone();
+            move || UseDevReportImpl { dev_tools }
+        });
+        let completer = dev_tools.clone();
+        let command = Command::new("dev", "执行调试命令")
+            .usage("<command> [args]")
+            .complete_with(move |prefix, index| {
+                // 补全函数只收到正在输入的参数，无法得知子命令，因此只补全子命令名称
+                if index > 0 {
+                    return Vec::new();
+                }
+                completer
+                    .commands()
+                    .iter()
+                    .flat_map(|command| std::iter::once(&command.name).chain(&command.aliases))
+                    .filter(|name| name.starts_with(prefix))
+                    .cloned()
+                    .collect()
+            });
+        self.use_ex_command(command, move |mut args| {
+            if args.args.is_empty() {
+                return Err("用法：dev <command> [args]".to_string());
+            }
+            // 第一个参数作为调试命令名，沿用已解析的参数，引号中的空白不会丢失
+            args.name = args.args.remove(0);
+            args.bang = false;
+            dev_tools.execute(args)
+        });
+    }
+}
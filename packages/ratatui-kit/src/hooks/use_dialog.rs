@@ -0,0 +1,129 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    task::Waker,
+};
+
+use futures::{channel::oneshot, future::BoxFuture};
+
+use crate::{UseContext, components::FocusScopeContext};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+pub trait UseDialog: private::Sealed {
+    /// 获取命令式对话框接口，需在 DialogProvider 内部使用。
+    fn use_dialog(&self) -> Dialog;
+}
+
+impl UseDialog for crate::Hooks<'_, '_> {
+    fn use_dialog(&self) -> Dialog {
+        self.use_context::<Dialog>().clone()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DialogKind {
+    Confirm,
+    Prompt,
+}
+
+/// 等待用户响应的对话框请求。
+pub(crate) struct DialogRequest {
+    pub(crate) kind: DialogKind,
+    pub(crate) message: String,
+    pub(crate) input: String,
+    pub(crate) responder: oneshot::Sender<Option<String>>,
+}
+
+#[derive(Default)]
+pub(crate) struct DialogShared {
+    pub(crate) queue: VecDeque<DialogRequest>,
+    pub(crate) tasks: Vec<BoxFuture<'static, ()>>,
+    pub(crate) dirty: bool,
+    pub(crate) waker: Option<Waker>,
+    // DialogProvider 子树的焦点作用域，有对话框时子树收不到按键
+    pub(crate) scope: FocusScopeContext,
+}
+
+/// 命令式对话框接口，类似浏览器的 `confirm` / `prompt`，但以 async 方式等待结果。
+///
+/// 对话框由最近的 DialogProvider 渲染在其内容之上，多个请求会依次显示。
+/// 对话框显示期间 DialogProvider 内的其他监听器收不到按键和粘贴事件。
+///
+/// # 示例
+/// ```rust
+/// let dialog = hooks.use_dialog();
+/// hooks.use_events(move |event| {
+///     if let Event::Key(key) = event {
+///         if key.code == KeyCode::Char('d') {
+///             let task_dialog = dialog.clone();
+///             dialog.spawn(async move {
+///                 if task_dialog.confirm("确定删除？").await {
+///                     // 执行删除
+///                 }
+///             });
+///         }
+///     }
+/// });
+/// ```
+#[derive(Clone, Default)]
+pub struct Dialog {
+    pub(crate) shared: Arc<Mutex<DialogShared>>,
+}
+
+impl Dialog {
+    /// 显示确认框，用户确认返回 true，取消返回 false。
+    pub fn confirm(
+        &self,
+        message: impl Into<String>,
+    ) -> impl Future<Output = bool> + Send + 'static {
+        let rx = self.request(DialogKind::Confirm, message.into());
+        async move { matches!(rx.await, Ok(Some(_))) }
+    }
+
+    /// 显示输入框，用户确认返回输入内容，取消返回 None。
+    pub fn prompt(
+        &self,
+        message: impl Into<String>,
+    ) -> impl Future<Output = Option<String>> + Send + 'static {
+        let rx = self.request(DialogKind::Prompt, message.into());
+        async move { rx.await.ok().flatten() }
+    }
+
+    /// 在 DialogProvider 上运行异步任务，便于在事件回调中等待对话框结果。
+    pub fn spawn<F>(&self, f: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let mut shared = self.shared.lock().unwrap();
+        shared.tasks.push(Box::pin(f));
+        shared.wake();
+    }
+
+    fn request(&self, kind: DialogKind, message: String) -> oneshot::Receiver<Option<String>> {
+        let (responder, rx) = oneshot::channel();
+        let mut shared = self.shared.lock().unwrap();
+        shared.queue.push_back(DialogRequest {
+            kind,
+            message,
+            input: String::new(),
+            responder,
+        });
+        shared.scope.set_active(false);
+        shared.wake();
+        rx
+    }
+}
+
+impl DialogShared {
+    // 标记需要重新渲染并唤醒 DialogProvider
+    pub(crate) fn wake(&mut self) {
+        self.dirty = true;
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
@@ -0,0 +1,164 @@
+//! 运行环境 hook：在任意组件中读取命令行参数、环境变量和工作目录，无需通过 props 逐层传递。
+//!
+//! 组件树默认提供启动时捕获的进程环境，可以用 [`EnvironmentProvider`](crate::components::EnvironmentProvider)
+//! 为子树替换，例如注入解析好的命令行参数，或在测试中使用固定的环境变量。
+
+use std::{
+    any::Any,
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::{Hooks, UseContext};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+/// 进程的运行环境：命令行参数、环境变量、工作目录及可选的解析后参数，可廉价克隆。
+#[derive(Clone, Default)]
+pub struct Environment {
+    args: Arc<Vec<String>>,
+    vars: Arc<HashMap<String, String>>,
+    cwd: PathBuf,
+    cli: Option<Arc<dyn Any + Send + Sync>>,
+}
+
+impl Environment {
+    /// 捕获当前进程的命令行参数、环境变量和工作目录，非 UTF-8 的内容按有损方式转换。
+    pub fn capture() -> Self {
+        Self {
+            args: Arc::new(
+                std::env::args_os()
+                    .map(|arg| arg.to_string_lossy().into_owned())
+                    .collect(),
+            ),
+            vars: Arc::new(
+                std::env::vars_os()
+                    .map(|(key, value)| {
+                        (
+                            key.to_string_lossy().into_owned(),
+                            value.to_string_lossy().into_owned(),
+                        )
+                    })
+                    .collect(),
+            ),
+            cwd: std::env::current_dir().unwrap_or_default(),
+            cli: None,
+        }
+    }
+
+    /// 替换命令行参数，第一个参数为程序名。
+    pub fn with_args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.args = Arc::new(args.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// 设置环境变量，只影响本对象，不修改进程的环境变量。
+    pub fn with_var(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        Arc::make_mut(&mut self.vars).insert(key.into(), value.into());
+        self
+    }
+
+    /// 替换工作目录，只影响本对象，不修改进程的工作目录。
+    pub fn with_cwd(mut self, cwd: impl Into<PathBuf>) -> Self {
+        self.cwd = cwd.into();
+        self
+    }
+
+    /// 附加解析好的命令行参数，之后可通过 [`Environment::cli`] 取出。
+    pub fn with_cli<T: Send + Sync + 'static>(mut self, cli: T) -> Self {
+        self.cli = Some(Arc::new(cli));
+        self
+    }
+
+    /// 用 clap 解析命令行参数并附加，解析失败或请求帮助时与 `Parser::parse` 一样打印信息后退出进程，
+    /// 应在进入终端界面之前调用。
+    ///
+    /// # 示例
+    /// ```rust
+    /// #[derive(clap::Parser)]
+    /// struct Cli {
+    ///     #[arg(long)]
+    ///     theme: Option<String>,
+    /// }
+    ///
+    /// let environment = Environment::capture().parse_cli::<Cli>();
+    /// element!(EnvironmentProvider(environment: Some(environment)) {
+    ///     App()
+    /// })
+    /// .fullscreen()
+    /// .await?;
+    ///
+    /// // 在任意子组件中
+    /// let environment = hooks.use_environment();
+    /// let theme = environment.cli::<Cli>().and_then(|cli| cli.theme.clone());
+    /// ```
+    #[cfg(feature = "clap")]
+    pub fn parse_cli<T>(self) -> Self
+    where
+        T: clap::Parser + Send + Sync + 'static,
+    {
+        let cli = T::parse_from(self.args.iter());
+        self.with_cli(cli)
+    }
+
+    /// 用 clap 解析命令行参数并附加，失败时返回 clap 的错误。
+    #[cfg(feature = "clap")]
+    pub fn try_parse_cli<T>(self) -> Result<Self, clap::Error>
+    where
+        T: clap::Parser + Send + Sync + 'static,
+    {
+        let cli = T::try_parse_from(self.args.iter())?;
+        Ok(self.with_cli(cli))
+    }
+
+    /// 命令行参数，第一个为程序名。
+    pub fn args(&self) -> &[String] {
+        &self.args
+    }
+
+    /// 读取环境变量。
+    pub fn var(&self, key: &str) -> Option<&str> {
+        self.vars.get(key).map(String::as_str)
+    }
+
+    /// 全部环境变量。
+    pub fn vars(&self) -> &HashMap<String, String> {
+        &self.vars
+    }
+
+    /// 工作目录，捕获时无法读取则为空路径。
+    pub fn cwd(&self) -> &Path {
+        &self.cwd
+    }
+
+    /// 附加的解析后参数，未附加或类型不符时为 None。
+    pub fn cli<T: 'static>(&self) -> Option<&T> {
+        self.cli.as_ref()?.downcast_ref()
+    }
+}
+
+pub trait UseEnvironment: private::Sealed {
+    /// 获取最近的 EnvironmentProvider 提供的运行环境，没有时为组件树启动时捕获的进程环境。
+    ///
+    /// # 示例
+    /// ```rust
+    /// let environment = hooks.use_environment();
+    /// let editor = environment.var("EDITOR").unwrap_or("vi");
+    /// let root = environment.cwd().display();
+    /// ```
+    fn use_environment(&self) -> Environment;
+}
+
+impl UseEnvironment for Hooks<'_, '_> {
+    fn use_environment(&self) -> Environment {
+        self.use_context::<Environment>().clone()
+    }
+}
@@ -1,10 +1,18 @@
-use std::{pin::pin, task::Poll};
+use std::{
+    pin::pin,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    task::Poll,
+};
 
-use crossterm::event::Event;
+use crossterm::event::{Event, MouseEventKind};
 use futures::Stream;
-use ratatui::layout::Rect;
+use ratatui::layout::{Position, Rect};
 
-use crate::{Hook, Hooks, TerminalEvents};
+use crate::components::FocusScopeContext;
+use crate::{EventInterest, Hook, Hooks, TerminalEvents, UpdatePriority, with_priority};
 
 mod private {
     pub trait Sealed {}
@@ -17,21 +25,38 @@ pub trait UseEvents: private::Sealed {
     where
         F: FnMut(Event) + Send + 'static;
 
+    /// 注册只接收指定类别事件的全局监听器，其他事件不会分发给它，
+    /// 例如只处理按键的监听器不必为每次鼠标移动付出开销。
+    fn use_events_of<F>(&mut self, interest: EventInterest, f: F)
+    where
+        F: FnMut(Event) + Send + 'static;
+
     /// 注册仅作用于当前组件的事件监听器，适合局部交互。
     fn use_local_events<F>(&mut self, f: F)
     where
         F: FnMut(Event) + Send + 'static;
+
+    /// 开启终端鼠标捕获，使组件能接收点击、拖拽等鼠标事件，程序退出时自动关闭。
+    fn use_mouse_capture(&mut self);
 }
 
 impl UseEvents for Hooks<'_, '_> {
     fn use_events<F>(&mut self, f: F)
+    where
+        F: FnMut(Event) + Send + 'static,
+    {
+        self.use_events_of(EventInterest::ALL, f);
+    }
+
+    fn use_events_of<F>(&mut self, interest: EventInterest, f: F)
     where
         F: FnMut(Event) + Send + 'static,
     {
         let h = self.use_hook(move || UseEventsImpl {
             events: None,
-            component_area: Default::default(),
-            in_component: false,
+            interest,
+            local: None,
+            scope: None,
             f: None,
         });
         h.f = Some(Box::new(f));
@@ -43,65 +68,134 @@ impl UseEvents for Hooks<'_, '_> {
     {
         let h = self.use_hook(move || UseEventsImpl {
             events: None,
-            component_area: Default::default(),
-            in_component: true,
+            interest: EventInterest::ALL,
+            local: Some(Arc::default()),
+            scope: None,
             f: None,
         });
         h.f = Some(Box::new(f));
     }
+
+    fn use_mouse_capture(&mut self) {
+        self.use_hook(|| UseMouseCaptureImpl { enabled: false });
+    }
+}
+
+// 局部监听器的组件区域和拖拽状态，与订阅过滤器共享，区域外的鼠标事件不会进入队列
+#[derive(Default)]
+struct LocalArea {
+    area: Mutex<Rect>,
+    // 鼠标在组件内按下后进入拖拽状态，直到抬起
+    dragging: AtomicBool,
+}
+
+impl LocalArea {
+    fn inside(&self, column: u16, row: u16) -> bool {
+        self.area
+            .lock()
+            .unwrap()
+            .contains(Position::new(column, row))
+    }
+
+    // 订阅过滤：只读判断，拖拽和抬起事件在拖拽状态下即使移出区域也放行
+    fn interested(&self, event: &Event) -> bool {
+        let Event::Mouse(mouse_event) = event else {
+            return true;
+        };
+        match mouse_event.kind {
+            MouseEventKind::Drag(_) | MouseEventKind::Up(_) => {
+                self.dragging.load(Ordering::Relaxed)
+                    || self.inside(mouse_event.column, mouse_event.row)
+            }
+            _ => self.inside(mouse_event.column, mouse_event.row),
+        }
+    }
+
+    // 判断事件是否属于当前组件并更新拖拽状态：鼠标事件需落在组件区域内，
+    // 在组件内按下后的拖拽和抬起事件即使移出区域也会继续派发
+    fn accept(&self, event: &Event) -> bool {
+        let Event::Mouse(mouse_event) = event else {
+            return true;
+        };
+        let inside = self.inside(mouse_event.column, mouse_event.row);
+        match mouse_event.kind {
+            MouseEventKind::Down(_) => {
+                self.dragging.store(inside, Ordering::Relaxed);
+                inside
+            }
+            MouseEventKind::Drag(_) => inside || self.dragging.load(Ordering::Relaxed),
+            MouseEventKind::Up(_) => self.dragging.swap(false, Ordering::Relaxed) || inside,
+            _ => inside,
+        }
+    }
 }
 
 struct UseEventsImpl {
     f: Option<Box<dyn FnMut(Event) + Send>>,
     events: Option<TerminalEvents<Event>>,
-    in_component: bool,
-    component_area: Rect,
+    interest: EventInterest,
+    local: Option<Arc<LocalArea>>,
+    // 所在的焦点作用域，不在焦点链上时不处理按键
+    scope: Option<FocusScopeContext>,
 }
 
 impl Hook for UseEventsImpl {
     fn poll_change(
-        mut self: std::pin::Pin<&mut Self>,
+        self: std::pin::Pin<&mut Self>,
         cx: &mut std::task::Context,
     ) -> std::task::Poll<()> {
-        while let Some(Poll::Ready(Some(event))) = self
+        let this = self.get_mut();
+        while let Some(Poll::Ready(Some(event))) = this
             .events
             .as_mut()
             .map(|events| pin!(events).poll_next(cx))
         {
-            let area = self.component_area;
-            let in_component = self.in_component;
-            if let Some(f) = &mut self.f {
-                if in_component {
-                    match event {
-                        Event::Mouse(mouse_event) => {
-                            if mouse_event.row >= area.y && mouse_event.column >= area.x {
-                                let row = mouse_event.row - area.y;
-                                let column = mouse_event.column - area.x;
-
-                                if row < area.height && column < area.width {
-                                    f(event);
-                                }
-                            }
-                        }
-                        _ => {
-                            f(event);
-                        }
-                    }
-                } else {
-                    f(event);
+            if !FocusScopeContext::accepts(this.scope.as_ref(), &event) {
+                continue;
+            }
+            if let Some(local) = &this.local {
+                if !local.accept(&event) {
+                    continue;
                 }
             }
+            if let Some(f) = &mut this.f {
+                with_priority(UpdatePriority::Input, || f(event));
+            }
         }
         Poll::Pending
     }
 
     fn post_component_update(&mut self, updater: &mut crate::ComponentUpdater) {
+        self.scope = FocusScopeContext::current(updater);
         if self.events.is_none() {
-            self.events = updater.terminal().events().ok();
+            self.events = match &self.local {
+                Some(local) => {
+                    let local = local.clone();
+                    updater.events_filtered(move |event| local.interested(event))
+                }
+                None => updater.events_of(self.interest),
+            }
+            .ok();
         }
     }
 
     fn pre_component_draw(&mut self, drawer: &mut crate::ComponentDrawer) {
-        self.component_area = drawer.area;
+        if let Some(local) = &self.local {
+            *local.area.lock().unwrap() = drawer.area;
+        }
+    }
+}
+
+struct UseMouseCaptureImpl {
+    enabled: bool,
+}
+
+impl Hook for UseMouseCaptureImpl {
+    fn post_component_update(&mut self, updater: &mut crate::ComponentUpdater) {
+        if !self.enabled {
+            self.enabled = updater
+                .with_terminal(|terminal| terminal.enable_mouse_capture())
+                .is_ok();
+        }
     }
 }
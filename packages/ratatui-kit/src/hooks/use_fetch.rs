@@ -0,0 +1,345 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::{Arc, Mutex, OnceLock},
+    task::Poll,
+    time::{Duration, Instant},
+};
+
+use futures::{FutureExt, future::BoxFuture};
+use reqwest::{Client, Method};
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::{Hook, Hooks, State, UseState, runtime::spawn_io};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+pub trait UseFetch: private::Sealed {
+    /// 发起 HTTP 请求并把 JSON 响应反序列化为 `T`，请求变化时取消旧请求重新发起。
+    ///
+    /// 成功的结果写入进程内的查询缓存，相同的请求再次挂载时直接使用缓存；组件卸载时取消未完成的请求。
+    fn use_fetch<T>(&mut self, request: impl Into<FetchRequest>) -> Fetch<T>
+    where
+        T: DeserializeOwned + Send + Sync + 'static;
+}
+
+/// HTTP 请求描述，方法、地址、请求头和请求体共同决定缓存键。
+///
+/// # 示例
+/// ```rust
+/// let user = hooks.use_fetch::<User>(
+///     FetchRequest::get(format!("https://api.github.com/users/{name}"))
+///         .header("User-Agent", "ratatui-kit")
+///         .max_age(Duration::from_secs(60)),
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct FetchRequest {
+    method: Method,
+    url: String,
+    headers: Vec<(String, String)>,
+    body: Option<Vec<u8>>,
+    max_age: Option<Duration>,
+    // 请求体序列化失败时在发送阶段报告
+    error: Option<String>,
+}
+
+impl FetchRequest {
+    pub fn new(method: Method, url: impl Into<String>) -> Self {
+        Self {
+            method,
+            url: url.into(),
+            headers: Vec::new(),
+            body: None,
+            max_age: None,
+            error: None,
+        }
+    }
+
+    pub fn get(url: impl Into<String>) -> Self {
+        Self::new(Method::GET, url)
+    }
+
+    pub fn post(url: impl Into<String>) -> Self {
+        Self::new(Method::POST, url)
+    }
+
+    /// 添加请求头。
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// 以 JSON 序列化请求体，并设置 `Content-Type`。
+    pub fn json<B: Serialize + ?Sized>(mut self, body: &B) -> Self {
+        match serde_json::to_vec(body) {
+            Ok(body) => self.body = Some(body),
+            Err(err) => self.error = Some(err.to_string()),
+        }
+        self.header("Content-Type", "application/json")
+    }
+
+    /// 缓存结果的有效期，超过后重新挂载时重新请求；缺省时缓存一直有效。
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// 移除该请求的缓存结果。
+    pub fn invalidate(&self) {
+        let key = self.key();
+        cache().lock().unwrap().retain(|(k, _), _| *k != key);
+    }
+
+    fn key(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.method.hash(&mut hasher);
+        self.url.hash(&mut hasher);
+        self.headers.hash(&mut hasher);
+        self.body.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn cached<T: Send + Sync + 'static>(&self) -> Option<Arc<T>> {
+        let cache = cache().lock().unwrap();
+        let entry = cache.get(&(self.key(), TypeId::of::<T>()))?;
+        if self
+            .max_age
+            .is_some_and(|max_age| entry.fetched_at.elapsed() > max_age)
+        {
+            return None;
+        }
+        entry.value.clone().downcast().ok()
+    }
+}
+
+impl From<&str> for FetchRequest {
+    fn from(url: &str) -> Self {
+        Self::get(url)
+    }
+}
+
+impl From<String> for FetchRequest {
+    fn from(url: String) -> Self {
+        Self::get(url)
+    }
+}
+
+struct CacheEntry {
+    value: Arc<dyn Any + Send + Sync>,
+    fetched_at: Instant,
+}
+
+// 以请求键和结果类型区分缓存项
+fn cache() -> &'static Mutex<HashMap<(u64, TypeId), CacheEntry>> {
+    static CACHE: OnceLock<Mutex<HashMap<(u64, TypeId), CacheEntry>>> = OnceLock::new();
+    CACHE.get_or_init(Default::default)
+}
+
+fn client() -> &'static Client {
+    static CLIENT: OnceLock<Client> = OnceLock::new();
+    CLIENT.get_or_init(Client::new)
+}
+
+/// 清空查询缓存。
+pub fn clear_fetch_cache() {
+    cache().lock().unwrap().clear();
+}
+
+/// 发起请求并反序列化响应，优先使用查询缓存，适合配合 Suspense 使用。
+///
+/// # 示例
+/// ```rust
+/// element!(View {
+///     #(await fetch::<Repo>(url) => |repo| match repo {
+///         Ok(repo) => element!(RepoCard(repo: repo.clone())),
+///         Err(err) => element!(Text(text: err.clone())),
+///     })
+/// })
+/// ```
+pub fn fetch<T>(
+    request: impl Into<FetchRequest>,
+) -> impl Future<Output = Result<Arc<T>, String>> + Send + 'static
+where
+    T: DeserializeOwned + Send + Sync + 'static,
+{
+    let request = request.into();
+    let cached = request.cached::<T>();
+    async move {
+        match cached {
+            Some(value) => Ok(value),
+            None => send(request).await,
+        }
+    }
+}
+
+async fn send<T>(request: FetchRequest) -> Result<Arc<T>, String>
+where
+    T: DeserializeOwned + Send + Sync + 'static,
+{
+    if let Some(err) = request.error {
+        return Err(err);
+    }
+    let mut builder = client().request(request.method.clone(), &request.url);
+    for (name, value) in &request.headers {
+        builder = builder.header(name, value);
+    }
+    if let Some(body) = request.body.clone() {
+        builder = builder.body(body);
+    }
+    let bytes = spawn_io(async move {
+        builder
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|err| err.to_string())?
+            .bytes()
+            .await
+            .map_err(|err| err.to_string())
+    })
+    .await?;
+    let value = Arc::new(serde_json::from_slice::<T>(&bytes).map_err(|err| err.to_string())?);
+    cache().lock().unwrap().insert(
+        (request.key(), TypeId::of::<T>()),
+        CacheEntry {
+            value: value.clone(),
+            fetched_at: Instant::now(),
+        },
+    );
+    Ok(value)
+}
+
+struct FetchState<T> {
+    data: Option<Arc<T>>,
+    error: Option<String>,
+    loading: bool,
+}
+
+/// 请求状态句柄，可复制到事件处理器中调用 [`Fetch::refetch`]。
+///
+/// # 示例
+/// ```rust
+/// let repo = hooks.use_fetch::<Repo>(url);
+/// let text = match (repo.data(), repo.error()) {
+///     (Some(repo), _) => repo.full_name.clone(),
+///     (None, Some(err)) => format!("请求失败：{err}"),
+///     (None, None) => "加载中...".to_string(),
+/// };
+/// ```
+pub struct Fetch<T: Send + Sync + 'static> {
+    state: State<FetchState<T>>,
+    generation: State<u64>,
+}
+
+impl<T: Send + Sync + 'static> Clone for Fetch<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync + 'static> Copy for Fetch<T> {}
+
+impl<T: Send + Sync + 'static> Fetch<T> {
+    /// 是否正在请求，重新请求期间仍保留上一次的数据。
+    pub fn is_loading(&self) -> bool {
+        self.state.read().loading
+    }
+
+    /// 最近一次成功的结果。
+    pub fn data(&self) -> Option<Arc<T>> {
+        self.state.read().data.clone()
+    }
+
+    /// 最近一次请求的错误信息。
+    pub fn error(&self) -> Option<String> {
+        self.state.read().error.clone()
+    }
+
+    /// 忽略缓存重新请求。
+    pub fn refetch(&self) {
+        let mut generation = self.generation;
+        generation.set(generation.get() + 1);
+    }
+}
+
+#[derive(Default)]
+struct UseFetchImpl {
+    future: Option<BoxFuture<'static, ()>>,
+    key: Option<(u64, u64)>,
+}
+
+impl Hook for UseFetchImpl {
+    fn poll_change(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context,
+    ) -> std::task::Poll<()> {
+        if let Some(future) = self.future.as_mut() {
+            if future.as_mut().poll(cx).is_ready() {
+                self.future = None;
+            }
+        }
+        Poll::Pending
+    }
+}
+
+impl UseFetch for Hooks<'_, '_> {
+    fn use_fetch<T>(&mut self, request: impl Into<FetchRequest>) -> Fetch<T>
+    where
+        T: DeserializeOwned + Send + Sync + 'static,
+    {
+        let request = request.into();
+        let mut state = self.use_state(|| FetchState::<T> {
+            data: None,
+            error: None,
+            loading: true,
+        });
+        let generation = self.use_state(|| 0u64);
+        let hook = self.use_hook(UseFetchImpl::default);
+
+        let key = (request.key(), generation.get());
+        if hook.key != Some(key) {
+            // 同一请求的 generation 变化说明调用了 refetch，此时跳过缓存
+            let refetch = hook
+                .key
+                .is_some_and(|(request_key, _)| request_key == key.0);
+            hook.key = Some(key);
+            match request.cached::<T>().filter(|_| !refetch) {
+                Some(data) => {
+                    hook.future = None;
+                    state.set(FetchState {
+                        data: Some(data),
+                        error: None,
+                        loading: false,
+                    });
+                }
+                None => {
+                    if !state.read().loading {
+                        state.write().loading = true;
+                    }
+                    // 替换 future 即取消旧请求
+                    hook.future = Some(
+                        async move {
+                            let result = send::<T>(request).await;
+                            let mut state = state.write();
+                            state.loading = false;
+                            match result {
+                                Ok(data) => {
+                                    state.data = Some(data);
+                                    state.error = None;
+                                }
+                                Err(err) => state.error = Some(err),
+                            }
+                        }
+                        .boxed(),
+                    );
+                }
+            }
+        }
+
+        Fetch { state, generation }
+    }
+}
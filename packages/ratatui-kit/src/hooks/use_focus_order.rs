@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+
+use crate::{Hooks, State, UseState};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+pub trait UseFocusOrder: private::Sealed {
+    /// 为一组交互组件创建 Tab 焦点顺序，`disabled[i]` 为 true 的组件不参与。
+    ///
+    /// `Tab`/`Shift+Tab` 按顺序切换焦点并跳过禁用的组件；当前聚焦的组件被禁用时，
+    /// 焦点落在其后第一个可用的组件上。
+    ///
+    /// ```rust
+    /// let order = hooks.use_focus_order(&[false, !agreed.get(), false]);
+    /// hooks.use_events({
+    ///     let mut order = order.clone();
+    ///     move |event| {
+    ///         if let Event::Key(key) = event {
+    ///             order.handle_key(&key);
+    ///         }
+    ///     }
+    /// });
+    /// element!(View {
+    ///     TextArea(is_focus: order.is_focus(0))
+    ///     Slider(is_focus: order.is_focus(1), disabled: !agreed.get())
+    ///     Rating(is_focus: order.is_focus(2))
+    /// })
+    /// ```
+    fn use_focus_order(&mut self, disabled: &[bool]) -> FocusOrder;
+}
+
+/// Tab 焦点顺序句柄，可克隆到事件处理器中使用。
+#[derive(Clone)]
+pub struct FocusOrder {
+    index: State<usize>,
+    disabled: Arc<[bool]>,
+}
+
+impl FocusOrder {
+    /// 当前聚焦的组件，全部禁用时为 None。
+    pub fn current(&self) -> Option<usize> {
+        let len = self.disabled.len();
+        let start = self.index.get().min(len.saturating_sub(1));
+        (0..len)
+            .map(|offset| (start + offset) % len)
+            .find(|&i| !self.disabled[i])
+    }
+
+    /// 第 `index` 个组件是否聚焦。
+    pub fn is_focus(&self, index: usize) -> bool {
+        self.current() == Some(index)
+    }
+
+    /// 聚焦指定组件，例如鼠标点击时，禁用的组件不能聚焦。
+    pub fn focus(&mut self, index: usize) {
+        if self.disabled.get(index) == Some(&false) && index != self.index.get() {
+            self.index.set(index);
+        }
+    }
+
+    /// 跳过禁用的组件，移动到下一个（`delta` 为正）或上一个组件，到达两端时循环。
+    pub fn move_by(&mut self, delta: isize) {
+        let Some(mut index) = self.current() else {
+            return;
+        };
+        let len = self.disabled.len() as isize;
+        let step = delta.signum();
+        let mut remaining = delta.abs();
+        while remaining > 0 {
+            index = (index as isize + step).rem_euclid(len) as usize;
+            if !self.disabled[index] {
+                remaining -= 1;
+            }
+        }
+        self.focus(index);
+    }
+
+    /// 处理 `Tab` 和 `Shift+Tab`，返回按键是否被处理。
+    pub fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        if key.kind == KeyEventKind::Release {
+            return false;
+        }
+        match key.code {
+            KeyCode::Tab => self.move_by(1),
+            KeyCode::BackTab => self.move_by(-1),
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl UseFocusOrder for Hooks<'_, '_> {
+    fn use_focus_order(&mut self, disabled: &[bool]) -> FocusOrder {
+        FocusOrder {
+            index: self.use_state(|| 0),
+            disabled: disabled.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{Event, KeyCode};
+
+    use crate::{
+        prelude::*,
+        test_util::{buffer_text, keys, run_events},
+    };
+
+    #[component]
+    fn Form(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let order = hooks.use_focus_order(&[false, true, false]);
+        hooks.use_events({
+            let mut order = order.clone();
+            move |event| {
+                if let Event::Key(key) = event {
+                    order.handle_key(&key);
+                }
+            }
+        });
+        element!(Text(text: format!("focus {:?}", order.current())))
+    }
+
+    fn focus_after(codes: impl IntoIterator<Item = KeyCode>) -> String {
+        buffer_text(&run_events(element!(Form), keys(codes)))
+    }
+
+    #[test]
+    fn tab_skips_disabled_components() {
+        assert!(focus_after([KeyCode::Tab]).contains("focus Some(2)"));
+        assert!(focus_after([KeyCode::Tab, KeyCode::Tab]).contains("focus Some(0)"));
+        assert!(focus_after([KeyCode::BackTab]).contains("focus Some(2)"));
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{component::short_type_name, components::FocusRingContext};
+
+use super::{Hook, Hooks, UseContext};
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+pub trait UseFocusRing: private::Sealed {
+    /// 向外层的 [`FocusRing`](crate::components::FocusRing) 上报组件是否聚焦，
+    /// 聚焦时 FocusRing 在组件区域周围绘制边框。
+    ///
+    /// 开启[无障碍输出](crate::AccessibilityOutput)时，焦点变化也会以文本形式输出。
+    ///
+    /// 自定义的交互组件在 `update` 中以 `is_focus` 调用即可接入焦点环。
+    fn use_focus_ring(&mut self, is_focus: bool);
+}
+
+struct UseFocusRingImpl {
+    component: &'static str,
+    ring: Option<FocusRingContext>,
+    is_focus: bool,
+}
+
+impl Hook for UseFocusRingImpl {
+    fn pre_component_draw(&mut self, drawer: &mut crate::ComponentDrawer) {
+        if !self.is_focus {
+            return;
+        }
+        crate::terminal::report_focus(self.component, drawer.area);
+        if let Some(ring) = &self.ring {
+            ring.report(drawer.area);
+        }
+    }
+}
+
+impl UseFocusRing for Hooks<'_, '_> {
+    fn use_focus_ring(&mut self, is_focus: bool) {
+        let component = short_type_name(self.component_name);
+        let ring = self
+            .try_use_context::<FocusRingContext>()
+            .map(|ring| ring.clone());
+        let hook = self.use_hook(|| UseFocusRingImpl {
+            component,
+            ring: None,
+            is_focus: false,
+        });
+        hook.is_focus = is_focus;
+        hook.ring = ring;
+    }
+}
@@ -0,0 +1,170 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use ratatui::text::Text;
+
+use crate::{Hook, Hooks, text::parse_ansi};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+pub trait UseHistory: private::Sealed {
+    /// 创建历史记录句柄，交给 History 组件输出到内联视口上方，适合 REPL、聊天等应用。
+    ///
+    /// 记录或回看视图状态变化时，调用该 hook 的组件也会重新渲染。
+    fn use_history(&mut self) -> HistoryHandle;
+}
+
+struct UseHistoryImpl {
+    history: HistoryHandle,
+    seen: u64,
+}
+
+impl Hook for UseHistoryImpl {
+    fn poll_change(mut self: std::pin::Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let this = &mut *self;
+        this.history.poll_changed(&mut this.seen, cx)
+    }
+}
+
+impl UseHistory for Hooks<'_, '_> {
+    fn use_history(&mut self) -> HistoryHandle {
+        self.use_hook(|| UseHistoryImpl {
+            history: HistoryHandle::default(),
+            seen: 0,
+        })
+        .history
+        .clone()
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct HistoryInner {
+    pub(crate) entries: Vec<Text<'static>>,
+    // 等待输出到视口上方的条目
+    pub(crate) pending: VecDeque<Text<'static>>,
+    pub(crate) viewer_open: bool,
+    // 回看视图距离底部的行数
+    pub(crate) scroll: usize,
+    version: u64,
+    wakers: Vec<Waker>,
+}
+
+impl HistoryInner {
+    fn notify(&mut self) {
+        self.version += 1;
+        self.wakers.drain(..).for_each(Waker::wake);
+    }
+}
+
+/// 历史记录句柄，可跨线程克隆。
+///
+/// `push` 的条目会由 History 组件插入到内联视口上方（进入终端回滚区），
+/// 同时保留在句柄中，可通过 `replay` 重新输出，或通过 `open_viewer` 打开全屏回看。
+///
+/// # 示例
+/// ```rust
+/// let history = hooks.use_history();
+/// history.push(Line::from(format!("> {input}")));
+/// element!(History(history: Some(history.clone()), height: 3u16) {
+///     TextArea(...)
+/// })
+/// ```
+#[derive(Clone, Default)]
+pub struct HistoryHandle {
+    pub(crate) inner: Arc<Mutex<HistoryInner>>,
+}
+
+impl HistoryHandle {
+    /// 追加一条记录。
+    pub fn push(&self, entry: impl Into<Text<'static>>) {
+        let entry = entry.into();
+        let mut inner = self.inner.lock().unwrap();
+        inner.entries.push(entry.clone());
+        inner.pending.push_back(entry);
+        inner.notify();
+    }
+
+    /// 追加一条带 ANSI 转义序列的记录，例如子进程的彩色输出。
+    pub fn push_ansi(&self, output: &str) {
+        self.push(parse_ansi(output));
+    }
+
+    /// 重新输出最近的 `n` 条记录，例如清屏之后恢复上下文。
+    pub fn replay(&self, n: usize) {
+        let mut inner = self.inner.lock().unwrap();
+        let start = inner.entries.len().saturating_sub(n);
+        let entries = inner.entries[start..].to_vec();
+        inner.pending.extend(entries);
+        inner.notify();
+    }
+
+    /// 清空全部记录（已输出到终端回滚区的内容不受影响）。
+    pub fn clear(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.entries.clear();
+        inner.pending.clear();
+        inner.scroll = 0;
+        inner.notify();
+    }
+
+    /// 全部记录的副本。
+    pub fn entries(&self) -> Vec<Text<'static>> {
+        self.inner.lock().unwrap().entries.clone()
+    }
+
+    /// 记录条数。
+    pub fn len(&self) -> usize {
+        self.inner.lock().unwrap().entries.len()
+    }
+
+    /// 是否没有任何记录。
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 打开全屏回看视图。
+    pub fn open_viewer(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.viewer_open = true;
+        inner.scroll = 0;
+        inner.notify();
+    }
+
+    /// 关闭全屏回看视图。
+    pub fn close_viewer(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.viewer_open = false;
+        inner.notify();
+    }
+
+    /// 回看视图是否打开。
+    pub fn is_viewer_open(&self) -> bool {
+        self.inner.lock().unwrap().viewer_open
+    }
+
+    /// 内容自 `seen` 版本后发生变化时返回 Ready 并更新 `seen`，否则登记唤醒。
+    pub(crate) fn poll_changed(&self, seen: &mut u64, cx: &mut Context) -> Poll<()> {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.version != *seen {
+            *seen = inner.version;
+            return Poll::Ready(());
+        }
+        if !inner.wakers.iter().any(|w| w.will_wake(cx.waker())) {
+            inner.wakers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+
+    /// 修改内部状态并唤醒 History 组件。
+    pub(crate) fn update(&self, f: impl FnOnce(&mut HistoryInner)) {
+        let mut inner = self.inner.lock().unwrap();
+        f(&mut inner);
+        inner.notify();
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{ComponentUpdater, Hook, Hooks};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+pub trait UseInlineHeight: private::Sealed {
+    /// 请求内联视口（`Viewport::Inline`）的高度，高度变化时终端会重新分配视口而不是裁剪内容。
+    ///
+    /// 全屏或固定视口下无效果；多个组件同时请求时以最后更新的组件为准。
+    ///
+    /// # 示例
+    /// ```rust
+    /// // 输入框随行数增长，加上边框占用的两行
+    /// let lines = value.read().lines().count().max(1) as u16;
+    /// hooks.use_inline_height(lines + 2);
+    /// ```
+    fn use_inline_height(&mut self, height: u16);
+}
+
+struct UseInlineHeightImpl {
+    height: u16,
+}
+
+impl Hook for UseInlineHeightImpl {
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        updater.with_terminal(|terminal| terminal.request_inline_height(self.height));
+    }
+}
+
+impl UseInlineHeight for Hooks<'_, '_> {
+    fn use_inline_height(&mut self, height: u16) {
+        self.use_hook(|| UseInlineHeightImpl { height }).height = height;
+    }
+}
@@ -6,7 +6,7 @@ use std::{
 
 use ratatui::{buffer::Buffer, widgets::Widget};
 
-use crate::{Hook, Hooks, Terminal};
+use crate::{AnyElement, Hook, Hooks, Terminal, tree::render_element_to_buffer};
 
 mod private {
     pub trait Sealed {}
@@ -15,9 +15,15 @@ mod private {
 
 type FnBox = Box<dyn FnOnce(&mut Buffer) + Send>;
 
+enum InsertItem {
+    Draw(u16, FnBox),
+    // 元素及其高度，高度为 None 时按内容测量
+    Element(AnyElement<'static>, Option<u16>),
+}
+
 #[derive(Clone, Default)]
 pub struct InsertBeforeHandler {
-    queue: Arc<Mutex<VecDeque<(u16, FnBox)>>>,
+    queue: Arc<Mutex<VecDeque<InsertItem>>>,
     waker: Arc<Mutex<Option<Waker>>>,
 }
 
@@ -37,20 +43,49 @@ impl Hook for InsertBeforeHandler {
     }
 
     fn post_component_update(&mut self, updater: &mut crate::ComponentUpdater) {
-        let mut queue = self.queue.lock().unwrap();
-        for (height, callback) in queue.drain(..) {
-            updater.terminal().insert_before(height, callback);
+        let items = self.queue.lock().unwrap().drain(..).collect::<Vec<_>>();
+        for item in items {
+            match item {
+                InsertItem::Draw(height, callback) => {
+                    let _ =
+                        updater.with_terminal(|terminal| terminal.insert_before(height, callback));
+                }
+                InsertItem::Element(mut element, height) => {
+                    let _ = updater
+                        .with_terminal(|terminal| insert_element(terminal, &mut element, height));
+                }
+            }
         }
     }
 }
 
+fn insert_element(
+    terminal: &mut Terminal,
+    element: &mut AnyElement<'static>,
+    height: Option<u16>,
+) -> std::io::Result<()> {
+    let width = terminal.size().map_or(80, |size| size.width);
+    let rendered = render_element_to_buffer(element, terminal, width, height)?;
+    if rendered.area.height == 0 {
+        return Ok(());
+    }
+    terminal.insert_before(rendered.area.height, move |buf| {
+        let area = buf.area.intersection(rendered.area);
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                buf[(x, y)] = rendered[(x, y)].clone();
+            }
+        }
+    })
+}
+
 impl InsertBeforeHandler {
     pub fn insert_before<F>(&self, height: u16, callback: F) -> &Self
     where
         F: FnOnce(&mut Buffer) + Send + 'static,
     {
         let mut queue = self.queue.lock().unwrap();
-        queue.push_back((height, Box::new(callback)));
+        queue.push_back(InsertItem::Draw(height, Box::new(callback)));
         self
     }
 
@@ -61,6 +96,26 @@ impl InsertBeforeHandler {
         self
     }
 
+    /// 通过组件管线渲染元素后插入到视口上方，高度按渲染内容测量（裁掉末尾空行）。
+    ///
+    /// 元素只渲染一次，适合输出日志、消息等静态内容。
+    pub fn insert_element(&self, element: impl Into<AnyElement<'static>>) -> &Self {
+        let mut queue = self.queue.lock().unwrap();
+        queue.push_back(InsertItem::Element(element.into(), None));
+        self
+    }
+
+    /// 以固定高度渲染元素后插入到视口上方，适合布局会撑满区域的元素。
+    pub fn insert_element_with_height(
+        &self,
+        element: impl Into<AnyElement<'static>>,
+        height: u16,
+    ) -> &Self {
+        let mut queue = self.queue.lock().unwrap();
+        queue.push_back(InsertItem::Element(element.into(), Some(height)));
+        self
+    }
+
     pub fn finish(&self) {
         if let Some(waker) = self.waker.lock().unwrap().take() {
             waker.wake();
@@ -69,7 +124,7 @@ impl InsertBeforeHandler {
 }
 
 pub trait UseInsertBefore: private::Sealed {
-    /// 在终端渲染区域前插入内容。
+    /// 在终端渲染区域前插入内容，支持绘制函数、ratatui 组件和元素树。
     fn use_insert_before(&mut self) -> InsertBeforeHandler;
 }
 
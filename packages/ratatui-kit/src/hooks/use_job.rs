@@ -0,0 +1,324 @@
+use std::{
+    fmt::Display,
+    pin::Pin,
+    sync::{Arc, Mutex, OnceLock, Weak},
+    task::{Context, Poll, Waker},
+    time::Instant,
+};
+
+use futures::{
+    FutureExt,
+    future::{AbortHandle, Abortable, BoxFuture},
+};
+
+use crate::{Hook, Hooks};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+pub trait UseJob: private::Sealed {
+    /// 创建可取消的后台任务，调用 [`JobHandle::start`] 后开始执行。
+    ///
+    /// 任务通过 [`JobProgress`] 报告进度和状态，变化时重新渲染当前组件，
+    /// 并出现在所有 [`JobsPanel`](crate::components::JobsPanel) 中。组件卸载时任务被取消。
+    fn use_job<F, Fut, E>(&mut self, name: impl Into<String>, f: F) -> JobHandle
+    where
+        F: Fn(JobProgress) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+        E: Display;
+
+    /// 当前所有已启动的任务，任务状态变化时重新渲染当前组件。
+    fn use_jobs(&mut self) -> Vec<JobInfo>;
+}
+
+/// 任务状态。
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum JobState {
+    /// 尚未启动。
+    #[default]
+    Idle,
+    Running,
+    Done,
+    /// 执行失败，附带错误信息。
+    Failed(String),
+    Cancelled,
+}
+
+impl JobState {
+    /// 是否已结束（完成、失败或取消）。
+    pub fn is_finished(&self) -> bool {
+        matches!(self, Self::Done | Self::Failed(_) | Self::Cancelled)
+    }
+}
+
+/// 任务的进度快照。
+#[derive(Debug, Clone, Default)]
+pub struct JobInfo {
+    pub name: String,
+    pub state: JobState,
+    /// 进度（0.0 ~ 1.0），未报告进度时为 None。
+    pub progress: Option<f64>,
+    /// 最近一次报告的状态文本。
+    pub status: String,
+    pub started_at: Option<Instant>,
+    pub finished_at: Option<Instant>,
+}
+
+type JobFactory = Box<dyn Fn(JobProgress) -> BoxFuture<'static, Result<(), String>> + Send + Sync>;
+
+struct JobShared {
+    info: Mutex<JobInfo>,
+    factory: Mutex<JobFactory>,
+    abort: Mutex<Option<AbortHandle>>,
+    // 等待所属 hook 接管并轮询的任务
+    pending: Mutex<Option<BoxFuture<'static, ()>>>,
+    watch: Watch,
+}
+
+impl JobShared {
+    fn update(&self, f: impl FnOnce(&mut JobInfo)) {
+        f(&mut self.info.lock().unwrap());
+        self.watch.notify();
+        registry().watch.notify();
+    }
+}
+
+// 版本号与等待唤醒的 waker，变化时唤醒全部订阅者
+#[derive(Default)]
+struct Watch {
+    inner: Mutex<(u64, Vec<Waker>)>,
+}
+
+impl Watch {
+    fn notify(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.0 += 1;
+        inner.1.drain(..).for_each(Waker::wake);
+    }
+
+    fn poll_changed(&self, seen: &mut u64, cx: &mut Context) -> Poll<()> {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.0 != *seen {
+            *seen = inner.0;
+            return Poll::Ready(());
+        }
+        if !inner.1.iter().any(|w| w.will_wake(cx.waker())) {
+            inner.1.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+// 进程内的任务列表，供 JobsPanel 等展示
+#[derive(Default)]
+struct Registry {
+    jobs: Mutex<Vec<Weak<JobShared>>>,
+    watch: Watch,
+}
+
+fn registry() -> &'static Registry {
+    static REGISTRY: OnceLock<Registry> = OnceLock::new();
+    REGISTRY.get_or_init(Registry::default)
+}
+
+/// 返回当前所有已启动且仍挂载的任务，按创建顺序排列。
+pub fn jobs() -> Vec<JobInfo> {
+    let mut jobs = registry().jobs.lock().unwrap();
+    jobs.retain(|job| job.strong_count() > 0);
+    jobs.iter()
+        .filter_map(Weak::upgrade)
+        .map(|job| job.info.lock().unwrap().clone())
+        .filter(|info| info.state != JobState::Idle)
+        .collect()
+}
+
+/// 任务内报告进度的句柄。
+///
+/// # 示例
+/// ```rust
+/// let job = hooks.use_job("下载", |progress| async move {
+///     for i in 0..=100 {
+///         progress.set_progress(i as f64 / 100.0);
+///         progress.set_status(format!("{i}/100 MB"));
+///         Delay::new(Duration::from_millis(30)).await;
+///     }
+///     Ok::<_, String>(())
+/// });
+/// ```
+#[derive(Clone)]
+pub struct JobProgress {
+    shared: Arc<JobShared>,
+}
+
+impl JobProgress {
+    /// 报告进度，范围 0.0 ~ 1.0。
+    pub fn set_progress(&self, progress: f64) {
+        self.shared
+            .update(|info| info.progress = Some(progress.clamp(0.0, 1.0)));
+    }
+
+    /// 报告状态文本。
+    pub fn set_status(&self, status: impl Into<String>) {
+        let status = status.into();
+        self.shared.update(|info| info.status = status);
+    }
+}
+
+/// 后台任务句柄，可克隆后在事件处理器中启动或取消任务。
+#[derive(Clone)]
+pub struct JobHandle {
+    shared: Arc<JobShared>,
+}
+
+impl JobHandle {
+    /// 启动任务，任务正在执行时先取消再重新启动。
+    pub fn start(&self) {
+        let (abort, registration) = AbortHandle::new_pair();
+        if let Some(previous) = self.shared.abort.lock().unwrap().replace(abort) {
+            previous.abort();
+        }
+        let future = (self.shared.factory.lock().unwrap())(JobProgress {
+            shared: self.shared.clone(),
+        });
+        let shared = self.shared.clone();
+        let task = async move {
+            let state = match Abortable::new(future, registration).await {
+                Ok(Ok(())) => JobState::Done,
+                Ok(Err(err)) => JobState::Failed(err),
+                // 取消时状态已由 cancel 设置
+                Err(_) => return,
+            };
+            shared.update(|info| {
+                if state == JobState::Done {
+                    info.progress = Some(1.0);
+                }
+                info.state = state;
+                info.finished_at = Some(Instant::now());
+            });
+        }
+        .boxed();
+        *self.shared.pending.lock().unwrap() = Some(task);
+        self.shared.update(|info| {
+            info.state = JobState::Running;
+            info.progress = None;
+            info.status.clear();
+            info.started_at = Some(Instant::now());
+            info.finished_at = None;
+        });
+    }
+
+    /// 取消正在执行的任务。
+    pub fn cancel(&self) {
+        if let Some(abort) = self.shared.abort.lock().unwrap().take() {
+            abort.abort();
+        }
+        self.shared.update(|info| {
+            if info.state == JobState::Running {
+                info.state = JobState::Cancelled;
+                info.finished_at = Some(Instant::now());
+            }
+        });
+    }
+
+    /// 当前进度快照。
+    pub fn info(&self) -> JobInfo {
+        self.shared.info.lock().unwrap().clone()
+    }
+
+    /// 当前状态。
+    pub fn state(&self) -> JobState {
+        self.shared.info.lock().unwrap().state.clone()
+    }
+
+    /// 是否正在执行。
+    pub fn is_running(&self) -> bool {
+        self.state() == JobState::Running
+    }
+}
+
+struct UseJobImpl {
+    shared: Arc<JobShared>,
+    future: Option<BoxFuture<'static, ()>>,
+    seen: u64,
+}
+
+impl Hook for UseJobImpl {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let this = &mut *self;
+        let changed = this.shared.watch.poll_changed(&mut this.seen, cx);
+        if let Some(task) = this.shared.pending.lock().unwrap().take() {
+            this.future = Some(task);
+        }
+        if let Some(future) = this.future.as_mut() {
+            if future.as_mut().poll(cx).is_ready() {
+                this.future = None;
+            }
+        }
+        changed
+    }
+}
+
+impl Drop for UseJobImpl {
+    fn drop(&mut self) {
+        if let Some(abort) = self.shared.abort.lock().unwrap().take() {
+            abort.abort();
+        }
+        registry().watch.notify();
+    }
+}
+
+#[derive(Default)]
+struct UseJobsImpl {
+    seen: u64,
+}
+
+impl Hook for UseJobsImpl {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        registry().watch.poll_changed(&mut self.seen, cx)
+    }
+}
+
+impl UseJob for Hooks<'_, '_> {
+    fn use_job<F, Fut, E>(&mut self, name: impl Into<String>, f: F) -> JobHandle
+    where
+        F: Fn(JobProgress) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+        E: Display,
+    {
+        let name = name.into();
+        let factory: JobFactory =
+            Box::new(move |progress| f(progress).map(|r| r.map_err(|e| e.to_string())).boxed());
+        let hook = self.use_hook(|| {
+            let shared = Arc::new(JobShared {
+                info: Mutex::new(JobInfo::default()),
+                factory: Mutex::new(Box::new(|_| async { Ok(()) }.boxed())),
+                abort: Mutex::new(None),
+                pending: Mutex::new(None),
+                watch: Watch::default(),
+            });
+            registry()
+                .jobs
+                .lock()
+                .unwrap()
+                .push(Arc::downgrade(&shared));
+            UseJobImpl {
+                shared,
+                future: None,
+                seen: 0,
+            }
+        });
+        // 每次渲染更新任务函数和名称，使其捕获最新的值
+        *hook.shared.factory.lock().unwrap() = factory;
+        hook.shared.info.lock().unwrap().name = name;
+        JobHandle {
+            shared: hook.shared.clone(),
+        }
+    }
+
+    fn use_jobs(&mut self) -> Vec<JobInfo> {
+        self.use_hook(UseJobsImpl::default);
+        jobs()
+    }
+}
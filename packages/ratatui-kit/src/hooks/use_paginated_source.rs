@@ -0,0 +1,338 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Range,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use futures::{FutureExt, StreamExt, future::BoxFuture, stream::FuturesUnordered};
+
+use crate::{Hook, Hooks};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+/// 按范围异步拉取数据的数据源，例如数据库查询或分页接口。
+///
+/// 返回的条目少于请求的数量时视为已到末尾。闭包 `Fn(Range<usize>) -> Future` 自动实现该 trait。
+///
+/// # 示例
+/// ```rust
+/// struct Users(Pool);
+///
+/// impl PaginatedSource for Users {
+///     type Item = User;
+///
+///     fn fetch(&self, range: Range<usize>) -> BoxFuture<'static, Result<Vec<User>, String>> {
+///         let pool = self.0.clone();
+///         async move {
+///             sqlx::query_as("SELECT * FROM users LIMIT ? OFFSET ?")
+///                 .bind(range.len() as i64)
+///                 .bind(range.start as i64)
+///                 .fetch_all(&pool)
+///                 .await
+///                 .map_err(|err| err.to_string())
+///         }
+///         .boxed()
+///     }
+/// }
+/// ```
+pub trait PaginatedSource: Send + Sync + 'static {
+    type Item: Clone + Send + Sync + 'static;
+
+    /// 拉取 `range` 范围内的条目。
+    fn fetch(&self, range: Range<usize>) -> BoxFuture<'static, Result<Vec<Self::Item>, String>>;
+}
+
+impl<F, Fut, T> PaginatedSource for F
+where
+    F: Fn(Range<usize>) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<Vec<T>, String>> + Send + 'static,
+    T: Clone + Send + Sync + 'static,
+{
+    type Item = T;
+
+    fn fetch(&self, range: Range<usize>) -> BoxFuture<'static, Result<Vec<T>, String>> {
+        self(range).boxed()
+    }
+}
+
+/// 分页加载选项。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaginationOptions {
+    /// 每页条目数。
+    pub page_size: usize,
+    /// 在可见范围前后预取的页数。
+    pub prefetch: usize,
+    /// 最多缓存的页数，超出时淘汰离可见范围最远的页。
+    pub max_pages: usize,
+}
+
+impl Default for PaginationOptions {
+    fn default() -> Self {
+        Self {
+            page_size: 50,
+            prefetch: 1,
+            max_pages: 20,
+        }
+    }
+}
+
+pub trait UsePaginatedSource: private::Sealed {
+    /// 按可见范围懒加载数据源，配合 VirtualList 或 DataTable 的 `on_viewport_change` 使用。
+    ///
+    /// 页面加载完成时重新渲染当前组件；数据源每次渲染都会更新，条件变化后调用 [`Paginated::refresh`] 重新加载。
+    fn use_paginated_source<S>(
+        &mut self,
+        source: S,
+        options: PaginationOptions,
+    ) -> Paginated<S::Item>
+    where
+        S: PaginatedSource;
+}
+
+struct PaginatedShared<T> {
+    options: PaginationOptions,
+    pages: HashMap<usize, Vec<T>>,
+    loading: HashSet<usize>,
+    // 加载失败的页在可见范围变化或刷新前不再重试
+    failed: HashSet<usize>,
+    // 已知的总条目数，遇到不满一页的结果时确定
+    total: Option<usize>,
+    viewport: Range<usize>,
+    error: Option<String>,
+    // refresh 后递增，用于丢弃旧请求的结果
+    generation: u64,
+    dirty: bool,
+    waker: Option<Waker>,
+}
+
+impl<T> PaginatedShared<T> {
+    fn wake(&mut self) {
+        self.dirty = true;
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+
+    // 可见范围及预取范围内需要加载的页
+    fn wanted_pages(&self) -> Range<usize> {
+        let size = self.options.page_size;
+        let first = (self.viewport.start / size).saturating_sub(self.options.prefetch);
+        let mut last = self
+            .viewport
+            .end
+            .max(self.viewport.start + 1)
+            .div_ceil(size)
+            + self.options.prefetch;
+        if let Some(total) = self.total {
+            last = last.min(total.div_ceil(size));
+        }
+        first..last.max(first)
+    }
+
+    fn evict(&mut self) {
+        if self.pages.len() <= self.options.max_pages {
+            return;
+        }
+        let center = (self.viewport.start + self.viewport.end) / 2 / self.options.page_size;
+        let mut pages = self.pages.keys().copied().collect::<Vec<_>>();
+        pages.sort_by_key(|page| std::cmp::Reverse(page.abs_diff(center)));
+        let excess = self.pages.len() - self.options.max_pages;
+        for page in pages.into_iter().take(excess) {
+            self.pages.remove(&page);
+        }
+    }
+}
+
+/// 分页数据句柄，可克隆到回调中更新可见范围。
+///
+/// # 示例
+/// ```rust
+/// let users = hooks.use_paginated_source(Users(pool), PaginationOptions::default());
+///
+/// element!(VirtualList(
+///     len: users.len(),
+///     render_item: move |index| users.get(index).map(|user| Line::from(user.name.clone())),
+///     on_viewport_change: move |range| users.set_viewport(range),
+///     is_focus: true,
+/// ))
+/// ```
+pub struct Paginated<T> {
+    shared: Arc<Mutex<PaginatedShared<T>>>,
+}
+
+impl<T> Clone for Paginated<T> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T: Clone> Paginated<T> {
+    /// 条目数。总数未知时包含已加载的部分和一页待加载的占位条目，滚动到占位条目时继续加载。
+    pub fn len(&self) -> usize {
+        let shared = self.shared.lock().unwrap();
+        shared.total.unwrap_or_else(|| {
+            let loaded = shared.pages.keys().max().map_or(0, |page| page + 1);
+            (loaded + 1) * shared.options.page_size
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 总条目数是否已确定。
+    pub fn is_complete(&self) -> bool {
+        self.shared.lock().unwrap().total.is_some()
+    }
+
+    /// 获取条目，所在页尚未加载或已被淘汰时返回 None。
+    pub fn get(&self, index: usize) -> Option<T> {
+        let shared = self.shared.lock().unwrap();
+        let size = shared.options.page_size;
+        shared
+            .pages
+            .get(&(index / size))?
+            .get(index % size)
+            .cloned()
+    }
+
+    /// 是否有页面正在加载。
+    pub fn is_loading(&self) -> bool {
+        !self.shared.lock().unwrap().loading.is_empty()
+    }
+
+    /// 最近一次加载失败的错误信息。
+    pub fn error(&self) -> Option<String> {
+        self.shared.lock().unwrap().error.clone()
+    }
+
+    /// 更新可见范围，加载其中及预取范围内缺少的页。
+    pub fn set_viewport(&self, viewport: Range<usize>) {
+        let mut shared = self.shared.lock().unwrap();
+        if shared.viewport != viewport {
+            shared.viewport = viewport;
+            shared.failed.clear();
+            shared.wake();
+        }
+    }
+
+    /// 清空缓存并重新加载可见范围。
+    pub fn refresh(&self) {
+        let mut shared = self.shared.lock().unwrap();
+        shared.pages.clear();
+        shared.loading.clear();
+        shared.failed.clear();
+        shared.total = None;
+        shared.error = None;
+        shared.generation += 1;
+        shared.wake();
+    }
+}
+
+type PageResult<T> = (u64, usize, Result<Vec<T>, String>);
+
+struct UsePaginatedSourceImpl<S: PaginatedSource> {
+    source: Arc<S>,
+    shared: Arc<Mutex<PaginatedShared<S::Item>>>,
+    requests: FuturesUnordered<BoxFuture<'static, PageResult<S::Item>>>,
+}
+
+impl<S: PaginatedSource> Hook for UsePaginatedSourceImpl<S> {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let this = &mut *self;
+        {
+            let mut shared = this.shared.lock().unwrap();
+            let generation = shared.generation;
+            let size = shared.options.page_size;
+            for page in shared.wanted_pages() {
+                if shared.pages.contains_key(&page)
+                    || shared.failed.contains(&page)
+                    || !shared.loading.insert(page)
+                {
+                    continue;
+                }
+                let future = this.source.fetch(page * size..(page + 1) * size);
+                this.requests
+                    .push(future.map(move |result| (generation, page, result)).boxed());
+            }
+        }
+
+        while let Poll::Ready(Some((generation, page, result))) = this.requests.poll_next_unpin(cx)
+        {
+            let mut shared = this.shared.lock().unwrap();
+            if generation != shared.generation {
+                continue;
+            }
+            shared.loading.remove(&page);
+            match result {
+                Ok(items) => {
+                    let size = shared.options.page_size;
+                    if items.len() < size {
+                        shared.total = Some(page * size + items.len());
+                    }
+                    shared.error = None;
+                    shared.pages.insert(page, items);
+                    shared.evict();
+                }
+                Err(err) => {
+                    shared.failed.insert(page);
+                    shared.error = Some(err);
+                }
+            }
+            shared.wake();
+        }
+
+        let mut shared = this.shared.lock().unwrap();
+        shared.waker = Some(cx.waker().clone());
+        if std::mem::take(&mut shared.dirty) {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl UsePaginatedSource for Hooks<'_, '_> {
+    fn use_paginated_source<S>(
+        &mut self,
+        source: S,
+        options: PaginationOptions,
+    ) -> Paginated<S::Item>
+    where
+        S: PaginatedSource,
+    {
+        let mut source = Some(source);
+        let hook = self.use_hook(|| UsePaginatedSourceImpl {
+            source: Arc::new(source.take().unwrap()),
+            shared: Arc::new(Mutex::new(PaginatedShared {
+                options: PaginationOptions {
+                    page_size: options.page_size.max(1),
+                    ..options.clone()
+                },
+                pages: HashMap::new(),
+                loading: HashSet::new(),
+                failed: HashSet::new(),
+                total: None,
+                viewport: 0..0,
+                error: None,
+                generation: 0,
+                dirty: false,
+                waker: None,
+            })),
+            requests: FuturesUnordered::new(),
+        });
+        if let Some(source) = source {
+            hook.source = Arc::new(source);
+        }
+        Paginated {
+            shared: hook.shared.clone(),
+        }
+    }
+}
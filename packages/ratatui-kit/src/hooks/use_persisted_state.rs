@@ -0,0 +1,172 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
+
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::Value;
+
+use super::{Hook, Hooks, StateArena, StateSlotKey, UseState};
+use crate::State;
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+pub trait UsePersistedState: private::Sealed {
+    /// 创建可持久化的响应式状态，用法与 `use_state` 相同。
+    ///
+    /// 状态会被 [`Tree::snapshot`](crate::render::tree::Tree::snapshot) 按组件在树中的位置保存，
+    /// 并在 [`Tree::restore`](crate::render::tree::Tree::restore) 时恢复，适合实现"从上次离开的地方继续"。
+    ///
+    /// # 示例
+    /// ```rust
+    /// let filter = hooks.use_persisted_state(String::new);
+    /// let selected = hooks.use_persisted_state(|| 0usize);
+    /// ```
+    fn use_persisted_state<T, F>(&mut self, init: F) -> State<T>
+    where
+        F: FnOnce() -> T,
+        T: Serialize + DeserializeOwned + Unpin + Send + Sync + 'static;
+}
+
+/// 可持久化的状态，按值的实际类型序列化和反序列化。
+pub(crate) trait PersistSlot: Send {
+    fn save(&self) -> Option<Value>;
+    /// 写入反序列化后的值，类型不符时返回 false。
+    fn load(&mut self, value: Value) -> bool;
+}
+
+impl<T> PersistSlot for State<T>
+where
+    T: Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    fn save(&self) -> Option<Value> {
+        serde_json::to_value(&*self.try_read()?).ok()
+    }
+
+    fn load(&mut self, value: Value) -> bool {
+        match serde_json::from_value(value) {
+            Ok(value) => {
+                self.set(value);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+/// 组件树中可持久化的状态，以及尚未找到对应状态、等待组件挂载后恢复的快照值。
+#[derive(Default)]
+pub(crate) struct PersistedStates {
+    pub slots: BTreeMap<StateSlotKey, Box<dyn PersistSlot>>,
+    pub pending: BTreeMap<String, Value>,
+    // 每登记一个状态加一，没有新状态时无需再次尝试恢复
+    pub generation: u64,
+    pub restored_generation: Option<u64>,
+}
+
+struct UsePersistedStateImpl {
+    slot: Option<(Arc<Mutex<PersistedStates>>, StateSlotKey)>,
+}
+
+impl Hook for UsePersistedStateImpl {}
+
+impl Drop for UsePersistedStateImpl {
+    fn drop(&mut self) {
+        if let Some((persisted, key)) = &self.slot {
+            persisted.lock().unwrap().slots.remove(key);
+        }
+    }
+}
+
+impl UsePersistedState for Hooks<'_, '_> {
+    fn use_persisted_state<T, F>(&mut self, init: F) -> State<T>
+    where
+        F: FnOnce() -> T,
+        T: Serialize + DeserializeOwned + Unpin + Send + Sync + 'static,
+    {
+        let key = StateSlotKey {
+            component_id: self.component_id,
+            hook_index: *self.hook_index,
+        };
+        let state = self.use_state(init);
+        let persisted = self
+            .first_update
+            .then(|| {
+                self.context?
+                    .get_context::<StateArena>()
+                    .map(|arena| arena.persisted())
+            })
+            .flatten();
+        self.use_hook(move || {
+            let slot = persisted.map(|persisted| {
+                let mut states = persisted.lock().unwrap();
+                states.slots.insert(key, Box::new(state));
+                states.generation += 1;
+                drop(states);
+                (persisted, key)
+            });
+            UsePersistedStateImpl { slot }
+        });
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, test_util::mount};
+
+    #[derive(Default, Props)]
+    struct CounterProps {
+        initial: i32,
+    }
+
+    struct Counter;
+
+    impl Component for Counter {
+        type Props<'a> = CounterProps;
+
+        fn new(_props: &Self::Props<'_>) -> Self {
+            Self
+        }
+
+        fn update(
+            &mut self,
+            props: &mut Self::Props<'_>,
+            mut hooks: Hooks,
+            _updater: &mut ComponentUpdater,
+        ) {
+            let initial = props.initial;
+            hooks.use_persisted_state(move || initial);
+        }
+    }
+
+    fn counter_values(app: &App) -> Vec<i32> {
+        app.tree()
+            .states()
+            .snapshot::<i32>()
+            .into_iter()
+            .map(|(_, value)| value)
+            .collect()
+    }
+
+    #[test]
+    fn restores_class_component_state() {
+        let mut saved = element!(View {
+            Counter(initial: 5)
+        });
+        let app = mount(&mut saved);
+        let snapshot = app.tree().snapshot();
+
+        let mut restored = element!(View {
+            Counter(initial: 0)
+        });
+        let app = mount(&mut restored);
+        assert_eq!(counter_values(&app), vec![0]);
+        app.tree().restore(snapshot);
+        assert_eq!(counter_values(&app), vec![5]);
+    }
+}
@@ -0,0 +1,110 @@
+//! 渲染自省 hook：查询组件是否首次渲染、已渲染次数，以及在 debug 构建中打印重新渲染的原因，
+//! 用于排查不必要的重新渲染。
+
+use std::fmt::Debug;
+
+use super::{Hook, Hooks};
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for crate::hooks::Hooks<'_, '_> {}
+}
+
+pub trait UseRenderInfo: private::Sealed {
+    /// 本次渲染是否为组件挂载后的首次渲染。
+    fn use_is_first_render(&self) -> bool;
+
+    /// 组件挂载以来的渲染次数，首次渲染时为 1。
+    fn use_render_count(&mut self) -> usize;
+
+    /// 在 debug 构建中把每次重新渲染时发生变化的依赖打印到 stderr，release 构建中不做任何事。
+    ///
+    /// 依赖以 `(名称, 值)` 的形式传入，值按 `Debug` 输出比较；没有依赖变化时也会打印一条，
+    /// 说明重新渲染来自父组件或未列出的状态。终端画面输出在 stdout，可用 `2> render.log` 单独保存。
+    ///
+    /// # 示例
+    /// ```rust
+    /// let count = hooks.use_state(|| 0);
+    /// hooks.use_why_did_you_render(&[("count", &count.get()), ("title", &props.title)]);
+    /// // [why-did-you-render] CounterPage 第 3 次渲染：count: 1 -> 2
+    /// ```
+    fn use_why_did_you_render(&mut self, deps: &[(&str, &dyn Debug)]);
+}
+
+struct UseRenderCountImpl {
+    count: usize,
+}
+
+impl Hook for UseRenderCountImpl {}
+
+#[cfg(debug_assertions)]
+#[derive(Default)]
+struct UseWhyDidYouRenderImpl {
+    renders: usize,
+    deps: Vec<(String, String)>,
+}
+
+#[cfg(debug_assertions)]
+impl Hook for UseWhyDidYouRenderImpl {}
+
+#[cfg(debug_assertions)]
+impl UseWhyDidYouRenderImpl {
+    // 记录本次的依赖值，返回相对上次发生变化的依赖描述
+    fn diff(&mut self, deps: &[(&str, &dyn Debug)]) -> Vec<String> {
+        let deps = deps
+            .iter()
+            .map(|(name, value)| (name.to_string(), format!("{value:?}")))
+            .collect::<Vec<_>>();
+        let changes = deps
+            .iter()
+            .filter_map(|(name, value)| {
+                match self.deps.iter().find(|(previous, _)| previous == name) {
+                    Some((_, previous)) if previous == value => None,
+                    Some((_, previous)) => Some(format!("{name}: {previous} -> {value}")),
+                    None => Some(format!("{name}: {value}")),
+                }
+            })
+            .collect();
+        self.deps = deps;
+        changes
+    }
+}
+
+impl UseRenderInfo for Hooks<'_, '_> {
+    fn use_is_first_render(&self) -> bool {
+        self.first_update
+    }
+
+    fn use_render_count(&mut self) -> usize {
+        let hook = self.use_hook(|| UseRenderCountImpl { count: 0 });
+        hook.count += 1;
+        hook.count
+    }
+
+    #[cfg(debug_assertions)]
+    fn use_why_did_you_render(&mut self, deps: &[(&str, &dyn Debug)]) {
+        let component_name = self.component_name;
+        let hook = self.use_hook(UseWhyDidYouRenderImpl::default);
+        hook.renders += 1;
+        let changes = hook.diff(deps);
+        if hook.renders == 1 {
+            return;
+        }
+        if changes.is_empty() {
+            eprintln!(
+                "[why-did-you-render] {component_name} 第 {} 次渲染：依赖均未变化，由父组件或未列出的状态触发",
+                hook.renders
+            );
+        } else {
+            eprintln!(
+                "[why-did-you-render] {component_name} 第 {} 次渲染：{}",
+                hook.renders,
+                changes.join("，")
+            );
+        }
+    }
+
+    #[cfg(not(debug_assertions))]
+    fn use_why_did_you_render(&mut self, _deps: &[(&str, &dyn Debug)]) {}
+}
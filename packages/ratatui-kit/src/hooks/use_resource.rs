@@ -0,0 +1,110 @@
+use futures::{FutureExt, future::BoxFuture};
+use std::{hash::Hash, task::Poll};
+
+use crate::{Hook, Hooks, State, StateRef, UseState, hash_deps};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+pub trait UseResource: private::Sealed {
+    /// 执行一次性异步任务并保存其结果，`deps` 变化时丢弃旧任务重新执行，适合读取文件、请求接口等场景。
+    fn use_resource<F, T, D>(&mut self, f: F, deps: D) -> Resource<T>
+    where
+        F: Future<Output = T> + Send + 'static,
+        T: Unpin + Send + Sync + 'static,
+        D: Hash;
+}
+
+#[derive(Default)]
+pub struct UseResourceImpl {
+    f: Option<BoxFuture<'static, ()>>,
+    deps_hash: Option<u64>,
+}
+
+impl Hook for UseResourceImpl {
+    fn poll_change(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context,
+    ) -> std::task::Poll<()> {
+        if let Some(future) = self.f.as_mut() {
+            if future.as_mut().poll(cx).is_ready() {
+                self.f = None;
+            }
+        }
+        Poll::Pending
+    }
+}
+
+/// 异步任务结果句柄，任务完成前为 `None`。
+///
+/// # 示例
+/// ```rust
+/// let content = hooks.use_resource(async { tokio::fs::read_to_string("README.md").await.ok() }, ());
+/// let text = match content.value() {
+///     Some(Some(text)) => text,
+///     Some(None) => "读取失败".to_string(),
+///     None => "加载中...".to_string(),
+/// };
+/// ```
+pub struct Resource<T: Send + Sync + 'static> {
+    state: State<Option<T>>,
+}
+
+impl<T: Send + Sync + 'static> Clone for Resource<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync + 'static> Copy for Resource<T> {}
+
+impl<T: Send + Sync + 'static> Resource<T> {
+    /// 任务是否已完成。
+    pub fn is_ready(&self) -> bool {
+        self.state.read().is_some()
+    }
+
+    /// 读取任务结果。
+    pub fn read(&self) -> StateRef<'_, Option<T>> {
+        self.state.read()
+    }
+
+    /// 克隆任务结果。
+    pub fn value(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.state.read().clone()
+    }
+}
+
+impl UseResource for Hooks<'_, '_> {
+    fn use_resource<F, T, D>(&mut self, f: F, deps: D) -> Resource<T>
+    where
+        F: Future<Output = T> + Send + 'static,
+        T: Unpin + Send + Sync + 'static,
+        D: Hash,
+    {
+        let mut state = self.use_state(|| None::<T>);
+        let dep_hash = hash_deps(deps);
+        let hook = self.use_hook(UseResourceImpl::default);
+
+        if hook.deps_hash != Some(dep_hash) {
+            if hook.deps_hash.is_some() && state.read().is_some() {
+                state.set(None);
+            }
+            hook.f = Some(
+                async move {
+                    let value = f.await;
+                    state.set(Some(value));
+                }
+                .boxed(),
+            );
+            hook.deps_hash = Some(dep_hash);
+        }
+
+        Resource { state }
+    }
+}
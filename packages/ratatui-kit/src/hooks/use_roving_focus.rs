@@ -0,0 +1,132 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use ratatui::layout::Direction;
+
+use crate::{Hooks, State, UseContext, UseState, components::FocusScopeContext};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+pub trait UseRovingFocus: private::Sealed {
+    /// 为 `len` 个子项创建漫游焦点（roving tabindex），适合工具栏、单选组、菜单栏等组合控件。
+    ///
+    /// 组合控件整体只占一个焦点位置，即所在的 [`FocusScope`](crate::components::FocusScope)：
+    /// 作用域处于焦点链上时方向键在子项之间移动，再次获得焦点时回到上次所在的子项；
+    /// 不在任何 FocusScope 中时组合控件不会获得焦点。
+    /// `Tab`/`Shift+Tab` 不在子项之间移动，交给 [`use_focus_order`](crate::UseFocusOrder::use_focus_order)
+    /// 切换作用域的焦点，因此只有当前子项是 Tab 停靠点。内置的 [`MenuBar`](crate::components::MenuBar) 即按此实现。
+    ///
+    /// ```rust
+    /// // 父组件中：FocusScope(is_focus: order.is_focus(0)) { Toolbar }
+    /// let mut roving = hooks.use_roving_focus(tools.len());
+    /// hooks.use_local_events(move |event| {
+    ///     if let Event::Key(key) = event {
+    ///         if roving.is_focus() && !roving.handle_key(&key) && key.code == KeyCode::Enter {
+    ///             run_tool(roving.index());
+    ///         }
+    ///     }
+    /// });
+    /// ```
+    fn use_roving_focus(&mut self, len: usize) -> RovingFocus;
+}
+
+/// 漫游焦点句柄，记录组合控件中当前可聚焦的子项，可克隆到事件处理器中使用。
+///
+/// 默认水平排列（`←/→`）、到达两端时循环，可通过 [`direction`](Self::direction)
+/// 和 [`wrap`](Self::wrap) 调整。子项数量减少时索引自动限制在范围内。
+#[derive(Clone)]
+pub struct RovingFocus {
+    index: State<usize>,
+    // 组合控件所在的焦点作用域，决定组合控件是否获得焦点
+    scope: Option<FocusScopeContext>,
+    len: usize,
+    direction: Direction,
+    wrap: bool,
+}
+
+impl RovingFocus {
+    /// 设置子项排列方向，水平时响应 `←/→`，垂直时响应 `↑/↓`。
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// 设置到达两端时是否循环到另一端。
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// 组合控件是否获得焦点，即所在的 FocusScope 是否处于焦点链上。
+    pub fn is_focus(&self) -> bool {
+        self.scope
+            .as_ref()
+            .is_some_and(FocusScopeContext::is_active)
+    }
+
+    /// 当前可聚焦的子项，没有子项时为 0。
+    pub fn index(&self) -> usize {
+        self.index.get().min(self.len.saturating_sub(1))
+    }
+
+    /// `index` 是否为当前可聚焦的子项，组合控件获得焦点时该子项显示为聚焦。
+    pub fn is_current(&self, index: usize) -> bool {
+        self.len > 0 && self.index() == index
+    }
+
+    /// 移动到指定子项，例如鼠标点击时。
+    pub fn focus(&mut self, index: usize) {
+        if index < self.len && index != self.index.get() {
+            self.index.set(index);
+        }
+    }
+
+    /// 移动到下一个（`delta` 为正）或上一个子项。
+    pub fn move_by(&mut self, delta: isize) {
+        if self.len == 0 {
+            return;
+        }
+        let len = self.len as isize;
+        let next = self.index() as isize + delta;
+        let next = if self.wrap {
+            next.rem_euclid(len)
+        } else {
+            next.clamp(0, len - 1)
+        };
+        self.focus(next as usize);
+    }
+
+    /// 处理方向键、`Home` 和 `End`，返回按键是否被处理；组合控件未获得焦点时不处理。
+    pub fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        if key.kind == KeyEventKind::Release || !self.is_focus() {
+            return false;
+        }
+        let (prev, next) = match self.direction {
+            Direction::Horizontal => (KeyCode::Left, KeyCode::Right),
+            Direction::Vertical => (KeyCode::Up, KeyCode::Down),
+        };
+        match key.code {
+            code if code == prev => self.move_by(-1),
+            code if code == next => self.move_by(1),
+            KeyCode::Home => self.focus(0),
+            KeyCode::End => self.focus(self.len.saturating_sub(1)),
+            _ => return false,
+        }
+        true
+    }
+}
+
+impl UseRovingFocus for Hooks<'_, '_> {
+    fn use_roving_focus(&mut self, len: usize) -> RovingFocus {
+        RovingFocus {
+            index: self.use_state(|| 0),
+            scope: self
+                .try_use_context::<FocusScopeContext>()
+                .map(|scope| scope.clone()),
+            len,
+            direction: Direction::Horizontal,
+            wrap: true,
+        }
+    }
+}
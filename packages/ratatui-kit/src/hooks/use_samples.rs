@@ -0,0 +1,169 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use crate::{Hooks, UseMemo};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+pub trait UseSamples: private::Sealed {
+    /// 创建固定容量的采样缓冲区，适合为 Sparkline、Meter 等组件持续推送数据。
+    fn use_samples(&mut self, capacity: usize) -> Samples;
+}
+
+impl UseSamples for Hooks<'_, '_> {
+    fn use_samples(&mut self, capacity: usize) -> Samples {
+        self.use_memo(|| Samples::new(capacity), capacity)
+    }
+}
+
+struct SampleRing {
+    data: VecDeque<f64>,
+    capacity: usize,
+    version: u64,
+    wakers: Vec<Waker>,
+}
+
+/// 采样环形缓冲区句柄，超出容量时丢弃最旧的数据。
+///
+/// 句柄可跨线程克隆，`push` 后会唤醒正在显示它的组件重新渲染。
+///
+/// # 示例
+/// ```rust
+/// let samples = hooks.use_samples(120);
+/// hooks.use_future({
+///     let samples = samples.clone();
+///     async move {
+///         loop {
+///             samples.push(read_cpu_usage());
+///             Delay::new(Duration::from_secs(1)).await;
+///         }
+///     }
+/// });
+/// element!(Sparkline(samples: Some(samples)))
+/// ```
+#[derive(Clone)]
+pub struct Samples {
+    inner: Arc<Mutex<SampleRing>>,
+}
+
+impl Samples {
+    /// 创建指定容量的缓冲区。
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(SampleRing {
+                data: VecDeque::with_capacity(capacity),
+                capacity: capacity.max(1),
+                version: 0,
+                wakers: Vec::new(),
+            })),
+        }
+    }
+
+    /// 追加一个采样值。
+    pub fn push(&self, value: f64) {
+        let mut ring = self.inner.lock().unwrap();
+        if ring.data.len() == ring.capacity {
+            ring.data.pop_front();
+        }
+        ring.data.push_back(value);
+        ring.version += 1;
+        ring.wakers.drain(..).for_each(Waker::wake);
+    }
+
+    /// 清空全部采样。
+    pub fn clear(&self) {
+        let mut ring = self.inner.lock().unwrap();
+        ring.data.clear();
+        ring.version += 1;
+        ring.wakers.drain(..).for_each(Waker::wake);
+    }
+
+    /// 按时间顺序返回全部采样的副本。
+    pub fn values(&self) -> Vec<f64> {
+        self.inner.lock().unwrap().data.iter().copied().collect()
+    }
+
+    /// 最新的采样值。
+    pub fn latest(&self) -> Option<f64> {
+        self.inner.lock().unwrap().data.back().copied()
+    }
+
+    /// 当前采样数量。
+    pub fn len(&self) -> usize {
+        self.inner.lock().unwrap().data.len()
+    }
+
+    /// 是否没有任何采样。
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 缓冲区内容自 `seen` 版本后发生变化时返回 Ready 并更新 `seen`，否则登记唤醒。
+    pub(crate) fn poll_changed(&self, seen: &mut u64, cx: &mut Context) -> Poll<()> {
+        let mut ring = self.inner.lock().unwrap();
+        if ring.version != *seen {
+            *seen = ring.version;
+            return Poll::Ready(());
+        }
+        if !ring.wakers.iter().any(|w| w.will_wake(cx.waker())) {
+            ring.wakers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+
+    /// 返回采样的最小值和最大值。
+    pub(crate) fn bounds(values: &[f64]) -> Option<(f64, f64)> {
+        values
+            .iter()
+            .copied()
+            .filter(|v| v.is_finite())
+            .fold(None, |acc, v| match acc {
+                None => Some((v, v)),
+                Some((min, max)) => Some((min.min(v), max.max(v))),
+            })
+    }
+}
+
+/// 组件的采样来源：优先使用外部传入的句柄，否则把 `value` 属性的变化记录到内部缓冲区。
+pub(crate) struct SampleSource {
+    own: Samples,
+    external: Option<Samples>,
+    last_value: Option<u64>,
+    seen: u64,
+}
+
+impl SampleSource {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            own: Samples::new(capacity),
+            external: None,
+            last_value: None,
+            seen: 0,
+        }
+    }
+
+    pub(crate) fn update(&mut self, samples: Option<Samples>, value: Option<f64>) {
+        self.external = samples;
+        if let Some(value) = value {
+            if self.last_value != Some(value.to_bits()) {
+                self.last_value = Some(value.to_bits());
+                self.own.push(value);
+            }
+        }
+    }
+
+    pub(crate) fn samples(&self) -> &Samples {
+        self.external.as_ref().unwrap_or(&self.own)
+    }
+
+    pub(crate) fn poll_change(&mut self, cx: &mut Context) -> Poll<()> {
+        let samples = self.external.as_ref().unwrap_or(&self.own);
+        samples.poll_changed(&mut self.seen, cx)
+    }
+}
@@ -1,4 +1,5 @@
-use super::{Hook, Hooks};
+use super::{Hook, Hooks, StateArena, StateSlotKey, wake};
+use crate::render::loop_guard;
 use generational_box::{
     AnyStorage, BorrowError, BorrowMutError, GenerationalBox, Owner, SyncStorage,
 };
@@ -7,6 +8,7 @@ use std::{
     fmt::{self, Debug, Display, Formatter},
     hash::{Hash, Hasher},
     ops::{self, Deref, DerefMut},
+    panic::Location,
     task::{Poll, Waker},
 };
 
@@ -28,24 +30,40 @@ where
     T: Unpin + Send + Sync + 'static,
 {
     state: State<T>,
-    _storage: Owner<SyncStorage>,
+    // 所在组件树的登记表及槽位
+    slot: Option<(StateArena, StateSlotKey)>,
 }
 
 impl<T> UseStateImpl<T>
 where
     T: Unpin + Send + Sync + 'static,
 {
-    pub fn new(initial_value: T) -> Self {
-        let storage = Owner::default();
+    // 值放入组件实例共用的 Owner，随组件卸载一起释放，不再为每个 hook 创建 Owner
+    #[track_caller]
+    pub fn new(initial_value: T, owner: &Owner<SyncStorage>) -> Self {
         UseStateImpl {
             state: State {
-                inner: storage.insert(StateValue {
-                    value: initial_value,
-                    waker: None,
-                    is_changed: false,
-                }),
+                inner: owner.insert_with_caller(
+                    StateValue {
+                        value: initial_value,
+                        waker: None,
+                        is_changed: false,
+                    },
+                    Location::caller(),
+                ),
             },
-            _storage: storage,
+            slot: None,
+        }
+    }
+}
+
+impl<T> Drop for UseStateImpl<T>
+where
+    T: Unpin + Send + Sync + 'static,
+{
+    fn drop(&mut self) {
+        if let Some((arena, key)) = &self.slot {
+            arena.remove(*key);
         }
     }
 }
@@ -70,6 +88,13 @@ where
             Poll::Pending
         }
     }
+
+    fn pre_component_update(&mut self, _updater: &mut crate::ComponentUpdater) {
+        // 本次更新会读到最新的值，清除标记，避免同一批修改在下一轮再触发一次渲染
+        if let Ok(mut value) = self.state.inner.try_write() {
+            value.is_changed = false;
+        }
+    }
 }
 
 impl UseState for Hooks<'_, '_> {
@@ -78,7 +103,33 @@ impl UseState for Hooks<'_, '_> {
         F: FnOnce() -> T,
         T: Unpin + Send + Sync + 'static,
     {
-        self.use_hook(move || UseStateImpl::new(init())).state
+        let key = StateSlotKey {
+            component_id: self.component_id,
+            hook_index: *self.hook_index,
+        };
+        let component_name = self.component_name;
+        // 只在首次渲染创建状态时登记到组件树
+        let arena = self
+            .first_update
+            .then(|| {
+                self.context?
+                    .get_context::<StateArena>()
+                    .map(|arena| arena.clone())
+            })
+            .flatten();
+        let owner = self
+            .first_update
+            .then(|| self.states.get_or_insert_with(SyncStorage::owner).clone());
+        self.use_hook(move || {
+            let owner = owner.expect("state owner is created on the first update");
+            let mut hook = UseStateImpl::new(init(), &owner);
+            if let Some(arena) = arena {
+                arena.insert(key, component_name, hook.state);
+                hook.slot = Some((arena, key));
+            }
+            hook
+        })
+        .state
     }
 }
 
@@ -124,13 +175,19 @@ impl<T: 'static> Drop for StateMutRef<'_, T> {
     fn drop(&mut self) {
         if self.is_deref_mut {
             self.inner.is_changed = true;
+            loop_guard::record_write();
             if let Some(waker) = self.inner.waker.take() {
-                waker.wake();
+                wake(waker);
             }
         }
     }
 }
 
+/// 组件状态句柄，可复制，修改后自动触发重新渲染。
+///
+/// `read`/`write` 返回的守卫在存活期间一直占用状态：不要把守卫保存到 `.await` 之后，
+/// 也不要在持有读守卫时写同一个状态（`try_read` 会一直等待写守卫释放）。
+/// 需要读取或修改一次时优先使用 `map_read`、`update`、`replace`、`take`，守卫只在闭包内存活。
 pub struct State<T: Send + Sync + 'static> {
     inner: GenerationalBox<StateValue<T>, SyncStorage>,
 }
@@ -192,6 +249,31 @@ impl<T: Send + Sync + 'static> State<T> {
             *v = value;
         }
     }
+
+    /// 以可变引用修改状态，触发重新渲染。
+    pub fn update(&mut self, f: impl FnOnce(&mut T)) {
+        if let Some(mut v) = self.try_write() {
+            f(&mut v);
+        }
+    }
+
+    /// 在读守卫内计算结果，守卫随闭包结束释放。
+    pub fn map_read<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(&self.read())
+    }
+
+    /// 写入新值并返回旧值。
+    pub fn replace(&mut self, value: T) -> T {
+        std::mem::replace(&mut *self.write(), value)
+    }
+
+    /// 取出当前值，原位置留下默认值。
+    pub fn take(&mut self) -> T
+    where
+        T: Default,
+    {
+        std::mem::take(&mut *self.write())
+    }
 }
 
 impl<T: Debug + Sync + Send + 'static> Debug for State<T> {
@@ -301,3 +383,59 @@ impl<T: cmp::PartialOrd<T> + Sync + Send + 'static> cmp::PartialOrd<State<T>> fo
 }
 
 impl<T: cmp::Eq + Sync + Send + 'static> cmp::Eq for State<T> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{prelude::*, test_util::mount};
+
+    #[derive(Default)]
+    struct Counter;
+
+    impl Component for Counter {
+        type Props<'a> = NoProps;
+
+        fn new(_props: &Self::Props<'_>) -> Self {
+            Self
+        }
+
+        fn update(
+            &mut self,
+            _props: &mut Self::Props<'_>,
+            mut hooks: Hooks,
+            _updater: &mut ComponentUpdater,
+        ) {
+            hooks.use_state(|| 7i32);
+        }
+    }
+
+    #[test]
+    fn registers_class_component_state() {
+        let mut root = element!(View { Counter });
+        let app = mount(&mut root);
+        let slots = app.tree().states().slots();
+        assert!(
+            slots
+                .iter()
+                .any(|slot| slot.component_name.ends_with("Counter"))
+        );
+        let values: Vec<i32> = app
+            .tree()
+            .states()
+            .snapshot::<i32>()
+            .into_iter()
+            .map(|(_, value)| value)
+            .collect();
+        assert_eq!(values, vec![7]);
+    }
+
+    #[test]
+    fn state_is_freed_with_its_component() {
+        let mut root = element!(View { Counter });
+        let app = mount(&mut root);
+        let key = app.tree().states().slots()[0].key;
+        let state = app.tree().states().get::<i32>(key).unwrap();
+        assert_eq!(state.get(), 7);
+        drop(app);
+        assert!(state.try_read().is_none());
+    }
+}
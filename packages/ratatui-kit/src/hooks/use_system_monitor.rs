@@ -0,0 +1,136 @@
+use std::{
+    sync::{
+        Arc, Mutex, Weak,
+        atomic::{AtomicU64, Ordering},
+    },
+    thread,
+    time::Duration,
+};
+
+use sysinfo::{Networks, System};
+
+use crate::{Hooks, Samples, UseMemo};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+pub trait UseSystemMonitor: private::Sealed {
+    /// 获取系统指标采样器，后台线程每秒采样一次 CPU、内存和网络数据。
+    ///
+    /// 所有组件共用同一个采样线程，最后一个句柄释放后线程退出。
+    fn use_system_monitor(&mut self) -> SystemMonitor;
+}
+
+/// 采样间隔。
+pub const SYSTEM_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+// 保留约两分钟的历史
+const CAPACITY: usize = 120;
+
+const GIB: f64 = (1u64 << 30) as f64;
+
+struct MonitorInner {
+    cpu: Samples,
+    memory: Samples,
+    memory_total: AtomicU64,
+    received: Samples,
+    transmitted: Samples,
+}
+
+/// 系统指标采样句柄，数据通过 [`Samples`] 提供，可直接交给 Meter、Sparkline 等组件。
+#[derive(Clone)]
+pub struct SystemMonitor {
+    inner: Arc<MonitorInner>,
+}
+
+impl SystemMonitor {
+    /// 获取共享的采样器，尚未启动时启动后台采样线程。
+    pub fn shared() -> Self {
+        static SHARED: Mutex<Weak<MonitorInner>> = Mutex::new(Weak::new());
+        let mut shared = SHARED.lock().unwrap();
+        if let Some(inner) = shared.upgrade() {
+            return Self { inner };
+        }
+        // 内存总量同步读取，使组件首次渲染时即可确定仪表范围
+        let mut system = System::new();
+        system.refresh_memory();
+        let inner = Arc::new(MonitorInner {
+            cpu: Samples::new(CAPACITY),
+            memory: Samples::new(CAPACITY),
+            memory_total: AtomicU64::new(system.total_memory()),
+            received: Samples::new(CAPACITY),
+            transmitted: Samples::new(CAPACITY),
+        });
+        *shared = Arc::downgrade(&inner);
+        let weak = Arc::downgrade(&inner);
+        let _ = thread::Builder::new()
+            .name("ratatui-kit-system-monitor".to_string())
+            .spawn(move || sample_loop(weak, system));
+        Self { inner }
+    }
+
+    /// 全局 CPU 使用率（0 ~ 100）。
+    pub fn cpu(&self) -> Samples {
+        self.inner.cpu.clone()
+    }
+
+    /// 已用内存（GiB）。
+    pub fn memory(&self) -> Samples {
+        self.inner.memory.clone()
+    }
+
+    /// 内存总量（GiB）。
+    pub fn memory_total(&self) -> f64 {
+        self.inner.memory_total.load(Ordering::Relaxed) as f64 / GIB
+    }
+
+    /// 所有网卡的接收速率（字节/秒）。
+    pub fn received(&self) -> Samples {
+        self.inner.received.clone()
+    }
+
+    /// 所有网卡的发送速率（字节/秒）。
+    pub fn transmitted(&self) -> Samples {
+        self.inner.transmitted.clone()
+    }
+}
+
+fn sample_loop(weak: Weak<MonitorInner>, mut system: System) {
+    let mut networks = Networks::new_with_refreshed_list();
+    // CPU 使用率需要两次刷新之间的差值，第一次刷新只建立基准
+    system.refresh_cpu_usage();
+    let mut first = true;
+    loop {
+        let Some(inner) = weak.upgrade() else {
+            break;
+        };
+        system.refresh_memory();
+        inner
+            .memory_total
+            .store(system.total_memory(), Ordering::Relaxed);
+        inner.memory.push(system.used_memory() as f64 / GIB);
+        if !first {
+            system.refresh_cpu_usage();
+            networks.refresh(true);
+            inner.cpu.push(system.global_cpu_usage() as f64);
+            let (received, transmitted) = networks.values().fold((0, 0), |(rx, tx), data| {
+                (rx + data.received(), tx + data.transmitted())
+            });
+            let seconds = SYSTEM_SAMPLE_INTERVAL.as_secs_f64();
+            inner.received.push(received as f64 / seconds);
+            inner.transmitted.push(transmitted as f64 / seconds);
+        }
+        first = false;
+        // 等待期间不持有句柄，使组件卸载后线程能够退出
+        drop(inner);
+        thread::sleep(SYSTEM_SAMPLE_INTERVAL);
+    }
+}
+
+impl UseSystemMonitor for Hooks<'_, '_> {
+    fn use_system_monitor(&mut self) -> SystemMonitor {
+        self.use_memo(SystemMonitor::shared, ())
+    }
+}
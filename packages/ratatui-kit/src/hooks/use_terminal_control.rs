@@ -0,0 +1,102 @@
+use std::{
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use crate::{ComponentUpdater, CursorShape, Hook, Hooks, TerminalCommand};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+pub trait UseTerminalControl: private::Sealed {
+    /// 获取终端控制句柄，用于设置窗口标题、光标形状、响铃和发送桌面通知。
+    ///
+    /// 标题和光标形状在程序退出时自动恢复。
+    fn use_terminal_control(&mut self) -> TerminalControl;
+}
+
+#[derive(Default)]
+struct ControlQueue {
+    commands: Vec<TerminalCommand>,
+    waker: Option<Waker>,
+}
+
+/// 终端控制句柄，可跨线程克隆，命令在组件下一次更新时发送给终端。
+///
+/// # 示例
+/// ```rust
+/// let control = hooks.use_terminal_control();
+/// control.set_title(format!("编辑中 - {file_name}"));
+/// control.set_cursor_shape(CursorShape::Bar, true);
+/// control.notify("构建完成");
+/// ```
+#[derive(Clone, Default)]
+pub struct TerminalControl {
+    queue: Arc<Mutex<ControlQueue>>,
+}
+
+impl TerminalControl {
+    /// 发送任意控制命令。
+    pub fn send(&self, command: TerminalCommand) {
+        let mut queue = self.queue.lock().unwrap();
+        queue.commands.push(command);
+        if let Some(waker) = queue.waker.take() {
+            waker.wake();
+        }
+    }
+
+    /// 设置窗口标题。
+    pub fn set_title(&self, title: impl Into<String>) {
+        self.send(TerminalCommand::SetTitle(title.into()));
+    }
+
+    /// 设置光标形状。
+    pub fn set_cursor_shape(&self, shape: CursorShape, blinking: bool) {
+        self.send(TerminalCommand::SetCursorShape { shape, blinking });
+    }
+
+    /// 响铃。
+    pub fn bell(&self) {
+        self.send(TerminalCommand::Bell);
+    }
+
+    /// 发送桌面通知。
+    pub fn notify(&self, message: impl Into<String>) {
+        self.send(TerminalCommand::Notify(message.into()));
+    }
+}
+
+struct UseTerminalControlImpl {
+    control: TerminalControl,
+}
+
+impl Hook for UseTerminalControlImpl {
+    fn poll_change(self: std::pin::Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let mut queue = self.control.queue.lock().unwrap();
+        if queue.commands.is_empty() {
+            queue.waker = Some(cx.waker().clone());
+            Poll::Pending
+        } else {
+            Poll::Ready(())
+        }
+    }
+
+    fn post_component_update(&mut self, updater: &mut ComponentUpdater) {
+        let commands = std::mem::take(&mut self.control.queue.lock().unwrap().commands);
+        for command in commands {
+            let _ = updater.with_terminal(|terminal| terminal.control(command));
+        }
+    }
+}
+
+impl UseTerminalControl for Hooks<'_, '_> {
+    fn use_terminal_control(&mut self) -> TerminalControl {
+        self.use_hook(|| UseTerminalControlImpl {
+            control: TerminalControl::default(),
+        })
+        .control
+        .clone()
+    }
+}
@@ -0,0 +1,185 @@
+use std::{
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+    time::{Duration, Instant},
+};
+
+use futures::FutureExt;
+use futures_timer::Delay;
+
+use crate::{Hook, Hooks, UpdatePriority, render::scheduler};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+pub trait UseTimer: private::Sealed {
+    /// 创建计时器，运行期间每隔 `tick` 重新渲染当前组件，启动、暂停、重置时也会重新渲染。
+    ///
+    /// 返回的 [`Timer`] 可以传给 [`Stopwatch`](crate::components::Stopwatch)、
+    /// [`Countdown`](crate::components::Countdown) 等组件，由外部控制启停。
+    fn use_timer(&mut self, tick: Duration) -> Timer;
+}
+
+struct TimerState {
+    // 暂停前累计的时长
+    accumulated: Duration,
+    started: Option<Instant>,
+    version: u64,
+    wakers: Vec<Waker>,
+}
+
+/// 可暂停的计时器句柄，可克隆后在事件处理器或异步任务中控制。
+#[derive(Clone)]
+pub struct Timer {
+    inner: Arc<Mutex<TimerState>>,
+}
+
+impl Default for Timer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Timer {
+    /// 创建未启动的计时器。
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(TimerState {
+                accumulated: Duration::ZERO,
+                started: None,
+                version: 0,
+                wakers: Vec::new(),
+            })),
+        }
+    }
+
+    fn update(&self, f: impl FnOnce(&mut TimerState)) {
+        let mut state = self.inner.lock().unwrap();
+        f(&mut state);
+        state.version += 1;
+        state.wakers.drain(..).for_each(Waker::wake);
+    }
+
+    /// 开始或继续计时。
+    pub fn start(&self) {
+        self.update(|state| {
+            state.started.get_or_insert_with(Instant::now);
+        });
+    }
+
+    /// 暂停计时，保留已累计的时长。
+    pub fn pause(&self) {
+        self.update(|state| {
+            if let Some(started) = state.started.take() {
+                state.accumulated += started.elapsed();
+            }
+        });
+    }
+
+    /// 在运行和暂停之间切换。
+    pub fn toggle(&self) {
+        if self.is_running() {
+            self.pause();
+        } else {
+            self.start();
+        }
+    }
+
+    /// 停止并清零。
+    pub fn reset(&self) {
+        self.update(|state| {
+            state.accumulated = Duration::ZERO;
+            state.started = None;
+        });
+    }
+
+    /// 清零后重新开始计时。
+    pub fn restart(&self) {
+        self.update(|state| {
+            state.accumulated = Duration::ZERO;
+            state.started = Some(Instant::now());
+        });
+    }
+
+    /// 是否正在计时。
+    pub fn is_running(&self) -> bool {
+        self.inner.lock().unwrap().started.is_some()
+    }
+
+    /// 累计的时长。
+    pub fn elapsed(&self) -> Duration {
+        let state = self.inner.lock().unwrap();
+        state.accumulated + state.started.map_or(Duration::ZERO, |s| s.elapsed())
+    }
+
+    fn poll_changed(&self, seen: &mut u64, cx: &mut Context) -> Poll<()> {
+        let mut state = self.inner.lock().unwrap();
+        if state.version != *seen {
+            *seen = state.version;
+            return Poll::Ready(());
+        }
+        if !state.wakers.iter().any(|w| w.will_wake(cx.waker())) {
+            state.wakers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+/// 订阅计时器：状态变化时以及运行期间每隔 `tick` 重新渲染所在组件。
+pub(crate) struct TimerWatch {
+    pub timer: Timer,
+    pub tick: Duration,
+    seen: u64,
+    delay: Option<Delay>,
+}
+
+impl TimerWatch {
+    pub fn new(timer: Timer, tick: Duration) -> Self {
+        Self {
+            timer,
+            tick,
+            seen: 0,
+            delay: None,
+        }
+    }
+}
+
+impl Hook for TimerWatch {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let this = &mut *self;
+        let mut changed = this.timer.poll_changed(&mut this.seen, cx).is_ready();
+        if !this.timer.is_running() {
+            this.delay = None;
+            return if changed {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            };
+        }
+        let tick = this.tick;
+        let delay = this.delay.get_or_insert_with(|| Delay::new(tick));
+        if delay.poll_unpin(cx).is_ready() {
+            delay.reset(tick);
+            // 重置后注册新的唤醒时间
+            let _ = delay.poll_unpin(cx);
+            scheduler::mark(UpdatePriority::Animation);
+            changed = true;
+        }
+        if changed {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl UseTimer for Hooks<'_, '_> {
+    fn use_timer(&mut self, tick: Duration) -> Timer {
+        let hook = self.use_hook(|| TimerWatch::new(Timer::new(), tick));
+        hook.tick = tick;
+        hook.timer.clone()
+    }
+}
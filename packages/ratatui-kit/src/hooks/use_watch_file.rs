@@ -0,0 +1,122 @@
+use std::{
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::{Hook, Hooks};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+pub trait UseWatchFile: private::Sealed {
+    /// 监视文件变化，文件被修改、创建、删除或替换后调用 `f` 并重新渲染当前组件。
+    ///
+    /// 监视的是文件所在目录，编辑器通过重命名保存文件时同样能检测到。
+    /// 短时间内的多次变化只触发一次回调，回调在组件所在的任务中执行，可以直接修改状态。
+    ///
+    /// # 示例
+    /// ```rust
+    /// let mut version = hooks.use_state(|| 0);
+    /// hooks.use_watch_file("README.md", move |_| version += 1);
+    /// let content = hooks.use_memo(|| std::fs::read_to_string("README.md"), version.get());
+    /// ```
+    fn use_watch_file<F>(&mut self, path: impl AsRef<Path>, f: F)
+    where
+        F: FnMut(&Path) + Send + 'static;
+}
+
+type WatchCallback = Box<dyn FnMut(&Path) + Send>;
+
+#[derive(Default)]
+struct WatchState {
+    changed: bool,
+    waker: Option<Waker>,
+}
+
+struct UseWatchFileImpl {
+    path: PathBuf,
+    // 持有 watcher 以保持监视，drop 时停止
+    watcher: Option<RecommendedWatcher>,
+    state: Arc<Mutex<WatchState>>,
+    callback: Option<WatchCallback>,
+}
+
+impl UseWatchFileImpl {
+    fn new(path: &Path) -> Self {
+        let state = Arc::new(Mutex::new(WatchState::default()));
+        Self {
+            path: path.to_path_buf(),
+            watcher: Self::watch(path, state.clone()),
+            state,
+            callback: None,
+        }
+    }
+
+    fn watch(path: &Path, state: Arc<Mutex<WatchState>>) -> Option<RecommendedWatcher> {
+        let file_name = path.file_name()?.to_owned();
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        let mut watcher =
+            notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+                let Ok(event) = event else {
+                    return;
+                };
+                if event.kind.is_access()
+                    || !event
+                        .paths
+                        .iter()
+                        .any(|p| p.file_name() == Some(file_name.as_os_str()))
+                {
+                    return;
+                }
+                let mut state = state.lock().unwrap();
+                state.changed = true;
+                if let Some(waker) = state.waker.take() {
+                    waker.wake();
+                }
+            })
+            .ok()?;
+        watcher.watch(dir, RecursiveMode::NonRecursive).ok()?;
+        Some(watcher)
+    }
+}
+
+impl Hook for UseWatchFileImpl {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let this = &mut *self;
+        let mut state = this.state.lock().unwrap();
+        if !state.changed {
+            state.waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+        state.changed = false;
+        drop(state);
+        if let Some(callback) = this.callback.as_mut() {
+            callback(&this.path);
+        }
+        Poll::Ready(())
+    }
+}
+
+impl UseWatchFile for Hooks<'_, '_> {
+    fn use_watch_file<F>(&mut self, path: impl AsRef<Path>, f: F)
+    where
+        F: FnMut(&Path) + Send + 'static,
+    {
+        let path = path.as_ref();
+        let hook = self.use_hook(|| UseWatchFileImpl::new(path));
+        // 路径变化时重新监视
+        if hook.path != path {
+            *hook = UseWatchFileImpl::new(path);
+        }
+        hook.callback = Some(Box::new(f));
+    }
+}
@@ -0,0 +1,443 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
+
+use futures::{
+    FutureExt, SinkExt, StreamExt,
+    channel::mpsc::{UnboundedReceiver, UnboundedSender, unbounded},
+    future::{BoxFuture, Either},
+    stream,
+};
+use futures_timer::Delay;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+use crate::{Hook, Hooks, runtime::spawn_io};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+pub trait UseWebSocket: private::Sealed {
+    /// 连接 WebSocket 服务，断开后按重连策略自动重连，收到消息或连接状态变化时重新渲染当前组件。
+    ///
+    /// 组件卸载时关闭连接；`config` 变化不会重新连接，需要时调用 [`WebSocket::reconnect`]。
+    fn use_websocket(&mut self, config: impl Into<WebSocketConfig>) -> WebSocket;
+}
+
+/// 断线重连策略，重连间隔按指数退避增长。
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReconnectStrategy {
+    /// 第一次重连前的等待时间。
+    pub initial_delay: Duration,
+    /// 最长等待时间。
+    pub max_delay: Duration,
+    /// 每次重连失败后等待时间的倍数。
+    pub multiplier: f64,
+    /// 最多连续重连次数，缺省时一直重连。
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectStrategy {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            multiplier: 2.0,
+            max_attempts: None,
+        }
+    }
+}
+
+impl ReconnectStrategy {
+    /// 断开后不再重连。
+    pub fn none() -> Self {
+        Self {
+            max_attempts: Some(0),
+            ..Default::default()
+        }
+    }
+
+    /// 第 `attempt` 次（从 1 开始）重连前的等待时间，超过最大次数时返回 None。
+    pub fn delay(&self, attempt: u32) -> Option<Duration> {
+        if self.max_attempts.is_some_and(|max| attempt > max) {
+            return None;
+        }
+        let factor = self
+            .multiplier
+            .max(1.0)
+            .powi(attempt.saturating_sub(1) as i32);
+        Some(
+            self.initial_delay
+                .mul_f64(factor.min(u32::MAX as f64))
+                .min(self.max_delay),
+        )
+    }
+}
+
+/// WebSocket 连接配置。
+///
+/// # 示例
+/// ```rust
+/// let socket = hooks.use_websocket(
+///     WebSocketConfig::new("wss://echo.websocket.org")
+///         .reconnect(ReconnectStrategy { max_attempts: Some(5), ..Default::default() })
+///         .capacity(200),
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct WebSocketConfig {
+    url: String,
+    reconnect: ReconnectStrategy,
+    capacity: usize,
+}
+
+impl WebSocketConfig {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            reconnect: ReconnectStrategy::default(),
+            capacity: 100,
+        }
+    }
+
+    /// 断线重连策略。
+    pub fn reconnect(mut self, reconnect: ReconnectStrategy) -> Self {
+        self.reconnect = reconnect;
+        self
+    }
+
+    /// 保留的最近消息数量。
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+}
+
+impl From<&str> for WebSocketConfig {
+    fn from(url: &str) -> Self {
+        Self::new(url)
+    }
+}
+
+impl From<String> for WebSocketConfig {
+    fn from(url: String) -> Self {
+        Self::new(url)
+    }
+}
+
+/// 连接状态。
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum WebSocketState {
+    #[default]
+    Connecting,
+    Open,
+    /// 等待第 `attempt` 次重连。
+    Reconnecting {
+        attempt: u32,
+    },
+    /// 已主动关闭或重连次数用尽。
+    Closed,
+}
+
+/// 收到或发送的消息。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebSocketMessage {
+    Text(String),
+    Binary(Vec<u8>),
+}
+
+impl From<&str> for WebSocketMessage {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<String> for WebSocketMessage {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<Vec<u8>> for WebSocketMessage {
+    fn from(data: Vec<u8>) -> Self {
+        Self::Binary(data)
+    }
+}
+
+impl From<WebSocketMessage> for Message {
+    fn from(message: WebSocketMessage) -> Self {
+        match message {
+            WebSocketMessage::Text(text) => Message::text(text),
+            WebSocketMessage::Binary(data) => Message::binary(data),
+        }
+    }
+}
+
+enum Outgoing {
+    Message(WebSocketMessage),
+    Close,
+}
+
+#[derive(Default)]
+struct SocketShared {
+    state: WebSocketState,
+    messages: VecDeque<WebSocketMessage>,
+    received: u64,
+    error: Option<String>,
+    sender: Option<UnboundedSender<Outgoing>>,
+    closed: bool,
+    restart: bool,
+    dirty: bool,
+    waker: Option<Waker>,
+}
+
+impl SocketShared {
+    fn wake(&mut self) {
+        self.dirty = true;
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// WebSocket 连接句柄，可克隆到事件处理器中发送消息。
+///
+/// # 示例
+/// ```rust
+/// let socket = hooks.use_websocket("wss://echo.websocket.org");
+/// let lines = socket
+///     .messages()
+///     .into_iter()
+///     .filter_map(|message| match message {
+///         WebSocketMessage::Text(text) => Some(text),
+///         WebSocketMessage::Binary(_) => None,
+///     });
+///
+/// // 事件处理器中
+/// socket.send("hello");
+/// ```
+#[derive(Clone)]
+pub struct WebSocket {
+    shared: Arc<Mutex<SocketShared>>,
+}
+
+impl WebSocket {
+    /// 发送消息，连接断开期间的消息在重连成功后发送，关闭后发送的消息被丢弃。
+    pub fn send(&self, message: impl Into<WebSocketMessage>) {
+        let shared = self.shared.lock().unwrap();
+        if let Some(sender) = shared.sender.as_ref() {
+            let _ = sender.unbounded_send(Outgoing::Message(message.into()));
+        }
+    }
+
+    /// 当前连接状态。
+    pub fn state(&self) -> WebSocketState {
+        self.shared.lock().unwrap().state.clone()
+    }
+
+    /// 连接是否可用。
+    pub fn is_open(&self) -> bool {
+        self.state() == WebSocketState::Open
+    }
+
+    /// 最近收到的消息，按接收顺序排列。
+    pub fn messages(&self) -> Vec<WebSocketMessage> {
+        self.shared
+            .lock()
+            .unwrap()
+            .messages
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    /// 最近收到的一条消息。
+    pub fn last_message(&self) -> Option<WebSocketMessage> {
+        self.shared.lock().unwrap().messages.back().cloned()
+    }
+
+    /// 累计收到的消息数，可作为 `use_effect` 等 hook 的依赖。
+    pub fn received(&self) -> u64 {
+        self.shared.lock().unwrap().received
+    }
+
+    /// 最近一次连接错误。
+    pub fn error(&self) -> Option<String> {
+        self.shared.lock().unwrap().error.clone()
+    }
+
+    /// 清空已收到的消息。
+    pub fn clear(&self) {
+        let mut shared = self.shared.lock().unwrap();
+        shared.messages.clear();
+        shared.wake();
+    }
+
+    /// 关闭连接，不再重连。
+    pub fn close(&self) {
+        let mut shared = self.shared.lock().unwrap();
+        if let Some(sender) = shared.sender.take() {
+            let _ = sender.unbounded_send(Outgoing::Close);
+        }
+        shared.closed = true;
+        shared.state = WebSocketState::Closed;
+        shared.wake();
+    }
+
+    /// 断开当前连接并立即重新连接，重连次数从零开始计算。
+    pub fn reconnect(&self) {
+        let mut shared = self.shared.lock().unwrap();
+        shared.restart = true;
+        shared.wake();
+    }
+}
+
+fn update(shared: &Mutex<SocketShared>, f: impl FnOnce(&mut SocketShared)) {
+    let mut shared = shared.lock().unwrap();
+    f(&mut shared);
+    shared.wake();
+}
+
+fn set_state(shared: &Mutex<SocketShared>, state: WebSocketState) {
+    update(shared, |shared| {
+        // 主动关闭后不再覆盖状态
+        if !shared.closed {
+            shared.state = state;
+        }
+    });
+}
+
+async fn run(
+    config: WebSocketConfig,
+    shared: Arc<Mutex<SocketShared>>,
+    mut outgoing: UnboundedReceiver<Outgoing>,
+) {
+    let mut attempt = 0;
+    loop {
+        match connect_async(config.url.as_str()).await {
+            Ok((socket, _)) => {
+                attempt = 0;
+                update(&shared, |shared| shared.error = None);
+                set_state(&shared, WebSocketState::Open);
+                let (mut sink, mut incoming) = socket.split();
+                let mut events = stream::select(
+                    incoming.by_ref().map(Either::Left),
+                    outgoing.by_ref().map(Either::Right),
+                );
+                while let Some(event) = events.next().await {
+                    match event {
+                        Either::Left(Ok(message)) => {
+                            let message = match message {
+                                Message::Text(text) => WebSocketMessage::Text(text.to_string()),
+                                Message::Binary(data) => WebSocketMessage::Binary(data.to_vec()),
+                                Message::Close(_) => break,
+                                _ => continue,
+                            };
+                            update(&shared, |shared| {
+                                if shared.messages.len() >= config.capacity {
+                                    shared.messages.pop_front();
+                                }
+                                shared.messages.push_back(message);
+                                shared.received += 1;
+                            });
+                        }
+                        Either::Left(Err(err)) => {
+                            update(&shared, |shared| shared.error = Some(err.to_string()));
+                            break;
+                        }
+                        Either::Right(Outgoing::Message(message)) => {
+                            if let Err(err) = sink.send(message.into()).await {
+                                update(&shared, |shared| shared.error = Some(err.to_string()));
+                                break;
+                            }
+                        }
+                        Either::Right(Outgoing::Close) => {
+                            let _ = sink.close().await;
+                            return;
+                        }
+                    }
+                }
+            }
+            Err(err) => update(&shared, |shared| shared.error = Some(err.to_string())),
+        }
+        if shared.lock().unwrap().closed {
+            return;
+        }
+        attempt += 1;
+        let Some(delay) = config.reconnect.delay(attempt) else {
+            set_state(&shared, WebSocketState::Closed);
+            return;
+        };
+        set_state(&shared, WebSocketState::Reconnecting { attempt });
+        Delay::new(delay).await;
+        set_state(&shared, WebSocketState::Connecting);
+    }
+}
+
+struct UseWebSocketImpl {
+    config: WebSocketConfig,
+    shared: Arc<Mutex<SocketShared>>,
+    future: Option<BoxFuture<'static, ()>>,
+}
+
+impl UseWebSocketImpl {
+    fn connect(&mut self) {
+        let (sender, receiver) = unbounded();
+        {
+            let mut shared = self.shared.lock().unwrap();
+            shared.sender = Some(sender);
+            shared.closed = false;
+            shared.state = WebSocketState::Connecting;
+            shared.wake();
+        }
+        // 替换 future 即断开旧连接
+        self.future = Some(spawn_io(run(
+            self.config.clone(),
+            self.shared.clone(),
+            receiver,
+        )));
+    }
+}
+
+impl Hook for UseWebSocketImpl {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let this = &mut *self;
+        if std::mem::take(&mut this.shared.lock().unwrap().restart) {
+            this.connect();
+        }
+        if let Some(future) = this.future.as_mut() {
+            if future.as_mut().poll(cx).is_ready() {
+                this.future = None;
+            }
+        }
+        let mut shared = this.shared.lock().unwrap();
+        shared.waker = Some(cx.waker().clone());
+        if std::mem::take(&mut shared.dirty) {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl UseWebSocket for Hooks<'_, '_> {
+    fn use_websocket(&mut self, config: impl Into<WebSocketConfig>) -> WebSocket {
+        let hook = self.use_hook(|| {
+            let mut hook = UseWebSocketImpl {
+                config: config.into(),
+                shared: Arc::new(Mutex::new(SocketShared::default())),
+                future: None,
+            };
+            hook.connect();
+            hook
+        });
+        WebSocket {
+            shared: hook.shared.clone(),
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//! 布局解释器：在运行时根据 JSON 或 RON 布局文档构建元素树，插件和主题系统无需重新编译即可提供界面。
+//!
+//! ## 文档格式
+//! 每个节点包含组件名 `type`、可选的 `key`、属性 `props` 和子节点 `children`，
+//! 字符串子节点是 `{"type": "Text", "props": {"text": ...}}` 的简写：
+//! ```json
+//! {
+//!   "type": "Border",
+//!   "props": { "title": "设置", "border_style": "cyan" },
+//!   "children": [
+//!     { "type": "View", "props": { "flex_direction": "Horizontal", "gap": 1 }, "children": ["左侧", "右侧"] }
+//!   ]
+//! }
+//! ```
+//!
+//! ## 用法示例
+//! ```rust
+//! let registry = ComponentRegistry::default();
+//! let element = registry.build_json(include_str!("ui.json"))?;
+//! ```
+//! 启用 `ron` 特性后可以用 RON 书写同样的结构，如 `(type: "View", children: ["左侧", "右侧"])`，
+//! 通过 [`ComponentRegistry::build_ron`] 构建。
+//! 通过 [`ComponentRegistry::register`] 可以注册自定义组件；属性值的写法见 [`LayoutProps`]。
+//! 第三方 crate 可以实现 [`Plugin`]，一次性提供组件、路由和共享数据，见 [`PluginHost`]。
+
+mod node;
+pub use node::*;
+mod props;
+pub use props::*;
+mod registry;
+pub use registry::*;
+mod schema;
+pub use schema::*;
+mod plugin;
+pub use plugin::*;
+
+use std::fmt;
+
+/// 解析或构建布局文档时的错误。
+#[derive(Debug)]
+pub enum LayoutError {
+    /// 文档不是合法的 JSON。
+    Json(serde_json::Error),
+    /// 文档不是合法的 RON。
+    #[cfg(feature = "ron")]
+    Ron(ron::error::SpannedError),
+    /// 节点结构不正确，`path` 为出错节点在文档中的位置。
+    InvalidNode { path: String, message: String },
+    /// 组件名未注册。
+    UnknownComponent(String),
+    /// 缺少组件声明中的必填属性。
+    MissingProp { component: String, prop: String },
+    /// 属性未在组件声明中出现。
+    UnknownProp { component: String, prop: String },
+    /// 属性值类型或格式不正确。
+    InvalidProp {
+        component: String,
+        prop: String,
+        expected: &'static str,
+    },
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LayoutError::Json(err) => write!(f, "invalid layout json: {err}"),
+            #[cfg(feature = "ron")]
+            LayoutError::Ron(err) => write!(f, "invalid layout ron: {err}"),
+            LayoutError::InvalidNode { path, message } => {
+                write!(f, "invalid layout node at {path}: {message}")
+            }
+            LayoutError::UnknownComponent(name) => write!(f, "unknown component `{name}`"),
+            LayoutError::MissingProp { component, prop } => {
+                write!(f, "missing required prop `{prop}` of `{component}`")
+            }
+            LayoutError::UnknownProp { component, prop } => {
+                write!(f, "unknown prop `{prop}` of `{component}`")
+            }
+            LayoutError::InvalidProp {
+                component,
+                prop,
+                expected,
+            } => write!(
+                f,
+                "invalid prop `{prop}` of `{component}`, expected {expected}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LayoutError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LayoutError::Json(err) => Some(err),
+            #[cfg(feature = "ron")]
+            LayoutError::Ron(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for LayoutError {
+    fn from(err: serde_json::Error) -> Self {
+        LayoutError::Json(err)
+    }
+}
+
+#[cfg(feature = "ron")]
+impl From<ron::error::SpannedError> for LayoutError {
+    fn from(err: ron::error::SpannedError) -> Self {
+        LayoutError::Ron(err)
+    }
+}
@@ -0,0 +1,106 @@
+use serde_json::{Map, Value};
+
+use super::LayoutError;
+
+/// 布局文档中的一个节点。
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutNode {
+    /// 组件名，对应注册表中的名称。
+    pub component: String,
+    /// 元素 key，缺省时按组件名和在父节点中的位置生成。
+    pub key: Option<String>,
+    /// 组件属性。
+    pub props: Map<String, Value>,
+    /// 子节点。
+    pub children: Vec<LayoutNode>,
+}
+
+impl LayoutNode {
+    /// 从 JSON 字符串解析。
+    pub fn from_json(source: &str) -> Result<Self, LayoutError> {
+        Self::from_value(&serde_json::from_str(source)?)
+    }
+
+    /// 从 RON 字符串解析，结构与 JSON 文档相同，节点可以写成 `(type: "View", props: {...})`。
+    #[cfg(feature = "ron")]
+    pub fn from_ron(source: &str) -> Result<Self, LayoutError> {
+        Self::from_value(&ron::from_str(source)?)
+    }
+
+    /// 从 JSON 值解析。
+    pub fn from_value(value: &Value) -> Result<Self, LayoutError> {
+        Self::parse(value, "$")
+    }
+
+    fn parse(value: &Value, path: &str) -> Result<Self, LayoutError> {
+        let invalid = |message: &str| LayoutError::InvalidNode {
+            path: path.to_string(),
+            message: message.to_string(),
+        };
+
+        let object = match value {
+            // 字符串节点是文本组件的简写
+            Value::String(text) => {
+                return Ok(Self {
+                    component: "Text".to_string(),
+                    key: None,
+                    props: Map::from_iter([("text".to_string(), Value::String(text.clone()))]),
+                    children: Vec::new(),
+                });
+            }
+            Value::Object(object) => object,
+            _ => return Err(invalid("expected an object or a string")),
+        };
+
+        let component = object
+            .get("type")
+            .and_then(Value::as_str)
+            .ok_or_else(|| invalid("missing string field `type`"))?
+            .to_string();
+        let key = match object.get("key") {
+            None | Some(Value::Null) => None,
+            Some(Value::String(key)) => Some(key.clone()),
+            Some(Value::Number(key)) => Some(key.to_string()),
+            Some(_) => return Err(invalid("`key` must be a string or a number")),
+        };
+        let props = match object.get("props") {
+            None | Some(Value::Null) => Map::new(),
+            Some(Value::Object(props)) => props.clone(),
+            Some(_) => return Err(invalid("`props` must be an object")),
+        };
+        let children = match object.get("children") {
+            None | Some(Value::Null) => Vec::new(),
+            Some(Value::Array(children)) => children
+                .iter()
+                .enumerate()
+                .map(|(i, child)| Self::parse(child, &format!("{path}.children[{i}]")))
+                .collect::<Result<_, _>>()?,
+            Some(_) => return Err(invalid("`children` must be an array")),
+        };
+
+        Ok(Self {
+            component,
+            key,
+            props,
+            children,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "ron"))]
+mod tests {
+    use super::LayoutNode;
+
+    #[test]
+    fn ron_matches_json() {
+        let json = LayoutNode::from_json(
+            r#"{ "type": "View", "key": "root", "props": { "gap": 1 }, "children": ["左侧"] }"#,
+        )
+        .unwrap();
+        let ron = LayoutNode::from_ron(
+            r#"(type: "View", key: "root", props: { "gap": 1 }, children: ["左侧"])"#,
+        )
+        .unwrap();
+        assert_eq!(ron, json);
+    }
+}
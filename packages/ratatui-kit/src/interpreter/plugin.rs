@@ -0,0 +1,136 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::Arc,
+};
+
+use super::ComponentRegistry;
+#[cfg(feature = "router")]
+use crate::components::{Route, Routes};
+
+/// 第三方扩展，安装时向宿主应用提供组件、路由和共享数据。
+///
+/// # 示例
+/// ```rust
+/// struct WeatherPlugin;
+///
+/// impl Plugin for WeatherPlugin {
+///     fn name(&self) -> &str {
+///         "weather"
+///     }
+///
+///     fn install(&self, host: &mut PluginHost) {
+///         host.registry_mut().register("Weather", |_props, _| Ok(element!(Weather).into_any()));
+///         host.provide(WeatherConfig::default());
+///     }
+/// }
+/// ```
+pub trait Plugin: Send + Sync + 'static {
+    /// 插件名称，同名插件只安装一次。
+    fn name(&self) -> &str;
+
+    /// 安装插件，注册组件、路由和共享数据。
+    fn install(&self, host: &mut PluginHost);
+
+    /// PluginProvider 挂载时调用。
+    fn on_mount(&self, _host: &PluginHost) {}
+
+    /// PluginProvider 卸载时调用。
+    fn on_unmount(&self, _host: &PluginHost) {}
+}
+
+/// 插件宿主，收集插件提供的组件、路由和共享数据。
+///
+/// 安装完成后包装为 `Arc` 交给 [`PluginProvider`](crate::components::PluginProvider)，
+/// 子组件通过 `hooks.use_context::<Arc<PluginHost>>()` 访问。
+///
+/// # 示例
+/// ```rust
+/// let mut host = PluginHost::default();
+/// host.install(WeatherPlugin);
+/// let routes = host.take_routes();
+///
+/// element!(PluginProvider(host: Arc::new(host)) {
+///     RouterProvider(routes: routes, index_path: "/")
+/// })
+/// ```
+pub struct PluginHost {
+    registry: ComponentRegistry,
+    plugins: Vec<Arc<dyn Plugin>>,
+    provided: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+    #[cfg(feature = "router")]
+    routes: Vec<Route>,
+}
+
+impl Default for PluginHost {
+    fn default() -> Self {
+        Self::new(ComponentRegistry::default())
+    }
+}
+
+impl PluginHost {
+    /// 基于已有的组件注册表创建宿主。
+    pub fn new(registry: ComponentRegistry) -> Self {
+        Self {
+            registry,
+            plugins: Vec::new(),
+            provided: HashMap::new(),
+            #[cfg(feature = "router")]
+            routes: Vec::new(),
+        }
+    }
+
+    /// 安装插件，已安装同名插件时忽略。
+    pub fn install(&mut self, plugin: impl Plugin) -> &mut Self {
+        if self.is_installed(plugin.name()) {
+            return self;
+        }
+        plugin.install(self);
+        self.plugins.push(Arc::new(plugin));
+        self
+    }
+
+    /// 是否已安装该插件。
+    pub fn is_installed(&self, name: &str) -> bool {
+        self.plugins.iter().any(|plugin| plugin.name() == name)
+    }
+
+    /// 已安装的插件。
+    pub fn plugins(&self) -> impl Iterator<Item = &dyn Plugin> {
+        self.plugins.iter().map(|plugin| &**plugin)
+    }
+
+    pub fn registry(&self) -> &ComponentRegistry {
+        &self.registry
+    }
+
+    pub fn registry_mut(&mut self) -> &mut ComponentRegistry {
+        &mut self.registry
+    }
+
+    /// 提供共享数据（如 Store、配置），同类型的值会被覆盖。
+    pub fn provide<T: Send + Sync + 'static>(&mut self, value: T) -> &mut Self {
+        self.provided.insert(TypeId::of::<T>(), Arc::new(value));
+        self
+    }
+
+    /// 获取插件或宿主提供的共享数据。
+    pub fn get<T: Send + Sync + 'static>(&self) -> Option<Arc<T>> {
+        self.provided
+            .get(&TypeId::of::<T>())
+            .and_then(|value| value.clone().downcast::<T>().ok())
+    }
+
+    /// 添加路由。
+    #[cfg(feature = "router")]
+    pub fn add_route(&mut self, route: Route) -> &mut Self {
+        self.routes.push(route);
+        self
+    }
+
+    /// 取出插件添加的全部路由，交给 RouterProvider。
+    #[cfg(feature = "router")]
+    pub fn take_routes(&mut self) -> Routes {
+        std::mem::take(&mut self.routes).into()
+    }
+}
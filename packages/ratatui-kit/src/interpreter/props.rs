@@ -0,0 +1,218 @@
+use std::str::FromStr;
+
+use ratatui::{
+    layout::{Constraint, Direction, Flex, Margin, Offset},
+    style::{Color, Modifier, Style},
+    text::Line,
+};
+use serde_json::{Map, Value};
+
+use super::LayoutError;
+use crate::render::layout_style::LayoutStyle;
+
+/// 布局节点的属性，提供按类型读取属性值的方法。
+///
+/// 属性缺失或为 `null` 时返回 `Ok(None)`，类型不符时返回 [`LayoutError::InvalidProp`]。
+///
+/// | 类型 | 写法 |
+/// | --- | --- |
+/// | 样式 | `"yellow on blue bold"`：前景色、`on` 加背景色、修饰符（bold、dim、italic、underlined、reversed、crossed_out 等） |
+/// | 颜色 | `"red"`、`"lightblue"`、`"#ff8800"`、`"208"` |
+/// | 尺寸约束 | 数字（等同 `length`）、`"length:3"`、`"min:1"`、`"max:10"`、`"percentage:50"`、`"fill:1"`、`"ratio:1/3"` |
+/// | 方向 | `"Horizontal"`、`"Vertical"` |
+/// | 对齐 | `"Start"`、`"End"`、`"Center"`、`"SpaceBetween"`、`"SpaceAround"`、`"Legacy"` |
+/// | 外边距 | 数字（四周相同）或 `[水平, 垂直]` |
+#[derive(Clone, Copy)]
+pub struct LayoutProps<'a> {
+    component: &'a str,
+    props: &'a Map<String, Value>,
+}
+
+impl<'a> LayoutProps<'a> {
+    pub fn new(component: &'a str, props: &'a Map<String, Value>) -> Self {
+        Self { component, props }
+    }
+
+    /// 原始 JSON 值。
+    pub fn value(&self, name: &str) -> Option<&'a Value> {
+        self.props.get(name).filter(|value| !value.is_null())
+    }
+
+    /// 节点上出现的全部属性名。
+    pub fn names(&self) -> impl Iterator<Item = &'a str> + 'a {
+        self.props.keys().map(String::as_str)
+    }
+
+    fn invalid(&self, name: &str, expected: &'static str) -> LayoutError {
+        LayoutError::InvalidProp {
+            component: self.component.to_string(),
+            prop: name.to_string(),
+            expected,
+        }
+    }
+
+    fn get<T>(
+        &self,
+        name: &str,
+        expected: &'static str,
+        parse: impl FnOnce(&Value) -> Option<T>,
+    ) -> Result<Option<T>, LayoutError> {
+        match self.value(name) {
+            Some(value) => parse(value)
+                .map(Some)
+                .ok_or_else(|| self.invalid(name, expected)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn string(&self, name: &str) -> Result<Option<String>, LayoutError> {
+        self.get(name, "a string", |v| v.as_str().map(str::to_string))
+    }
+
+    pub fn bool(&self, name: &str) -> Result<Option<bool>, LayoutError> {
+        self.get(name, "a boolean", Value::as_bool)
+    }
+
+    pub fn u16(&self, name: &str) -> Result<Option<u16>, LayoutError> {
+        self.get(name, "an integer in 0..=65535", |v| {
+            v.as_u64().and_then(|v| u16::try_from(v).ok())
+        })
+    }
+
+    pub fn i32(&self, name: &str) -> Result<Option<i32>, LayoutError> {
+        self.get(name, "a 32-bit integer", |v| {
+            v.as_i64().and_then(|v| i32::try_from(v).ok())
+        })
+    }
+
+    pub fn f64(&self, name: &str) -> Result<Option<f64>, LayoutError> {
+        self.get(name, "a number", Value::as_f64)
+    }
+
+    /// 单行文本。
+    pub fn line(&self, name: &str) -> Result<Option<Line<'static>>, LayoutError> {
+        Ok(self.string(name)?.map(Line::from))
+    }
+
+    pub fn color(&self, name: &str) -> Result<Option<Color>, LayoutError> {
+        self.get(name, "a color", |v| parse_color(v.as_str()?))
+    }
+
+    pub fn style(&self, name: &str) -> Result<Option<Style>, LayoutError> {
+        self.get(name, "a style such as \"yellow on blue bold\"", |v| {
+            parse_style(v.as_str()?)
+        })
+    }
+
+    pub fn constraint(&self, name: &str) -> Result<Option<Constraint>, LayoutError> {
+        self.get(name, "a constraint such as \"length:3\"", parse_constraint)
+    }
+
+    pub fn direction(&self, name: &str) -> Result<Option<Direction>, LayoutError> {
+        self.get(name, "\"Horizontal\" or \"Vertical\"", |v| {
+            Direction::from_str(v.as_str()?).ok()
+        })
+    }
+
+    pub fn flex(&self, name: &str) -> Result<Option<Flex>, LayoutError> {
+        self.get(name, "a flex mode such as \"SpaceBetween\"", |v| {
+            Flex::from_str(v.as_str()?).ok()
+        })
+    }
+
+    pub fn margin(&self, name: &str) -> Result<Option<Margin>, LayoutError> {
+        self.get(name, "a number or [horizontal, vertical]", |v| match v {
+            Value::Array(items) => match items.as_slice() {
+                [h, v] => Some(Margin::new(
+                    u16::try_from(h.as_u64()?).ok()?,
+                    u16::try_from(v.as_u64()?).ok()?,
+                )),
+                _ => None,
+            },
+            v => {
+                let value = u16::try_from(v.as_u64()?).ok()?;
+                Some(Margin::new(value, value))
+            }
+        })
+    }
+
+    pub fn offset(&self, name: &str) -> Result<Option<Offset>, LayoutError> {
+        self.get(name, "[x, y]", |v| match v.as_array()?.as_slice() {
+            [x, y] => Some(Offset {
+                x: i32::try_from(x.as_i64()?).ok()?,
+                y: i32::try_from(y.as_i64()?).ok()?,
+            }),
+            _ => None,
+        })
+    }
+
+    /// 读取 `#[with_layout_style]` 对应的布局属性，未设置的字段取默认值。
+    pub fn layout_style(&self) -> Result<LayoutStyle, LayoutError> {
+        let default = LayoutStyle::default();
+        Ok(LayoutStyle {
+            flex_direction: self
+                .direction("flex_direction")?
+                .unwrap_or(default.flex_direction),
+            justify_content: self
+                .flex("justify_content")?
+                .unwrap_or(default.justify_content),
+            gap: self.i32("gap")?.unwrap_or(default.gap),
+            margin: self.margin("margin")?.unwrap_or(default.margin),
+            offset: self.offset("offset")?.unwrap_or(default.offset),
+            width: self.constraint("width")?.unwrap_or(default.width),
+            height: self.constraint("height")?.unwrap_or(default.height),
+        })
+    }
+}
+
+fn parse_color(value: &str) -> Option<Color> {
+    Color::from_str(value).ok()
+}
+
+fn parse_style(value: &str) -> Option<Style> {
+    let mut style = Style::default();
+    let mut words = value.split_whitespace();
+    while let Some(word) = words.next() {
+        let modifier = match word.to_ascii_lowercase().as_str() {
+            "on" => {
+                style = style.bg(parse_color(words.next()?)?);
+                continue;
+            }
+            "bold" => Modifier::BOLD,
+            "dim" => Modifier::DIM,
+            "italic" => Modifier::ITALIC,
+            "underlined" | "underline" => Modifier::UNDERLINED,
+            "slow_blink" | "blink" => Modifier::SLOW_BLINK,
+            "rapid_blink" => Modifier::RAPID_BLINK,
+            "reversed" => Modifier::REVERSED,
+            "hidden" => Modifier::HIDDEN,
+            "crossed_out" => Modifier::CROSSED_OUT,
+            _ => {
+                style = style.fg(parse_color(word)?);
+                continue;
+            }
+        };
+        style = style.add_modifier(modifier);
+    }
+    Some(style)
+}
+
+fn parse_constraint(value: &Value) -> Option<Constraint> {
+    if let Some(length) = value.as_u64() {
+        return Some(Constraint::Length(u16::try_from(length).ok()?));
+    }
+    let (kind, amount) = value.as_str()?.split_once(':')?;
+    let number = || amount.trim().parse::<u16>().ok();
+    Some(match kind.trim().to_ascii_lowercase().as_str() {
+        "length" => Constraint::Length(number()?),
+        "min" => Constraint::Min(number()?),
+        "max" => Constraint::Max(number()?),
+        "percentage" => Constraint::Percentage(number()?),
+        "fill" => Constraint::Fill(number()?),
+        "ratio" => {
+            let (a, b) = amount.split_once('/')?;
+            Constraint::Ratio(a.trim().parse().ok()?, b.trim().parse().ok()?)
+        }
+        _ => return None,
+    })
+}
@@ -0,0 +1,380 @@
+use std::{collections::HashMap, sync::Arc};
+
+use ratatui::{
+    layout::Alignment,
+    style::{Style, Stylize},
+    widgets::{Padding, Paragraph, Wrap},
+};
+
+use super::{ComponentSchema, LayoutError, LayoutNode, LayoutProps, PropKind};
+use crate::{
+    AnyElement, Element, ElementKey,
+    components::{
+        Adapter, AdapterProps, Border, BorderKind, BorderProps, Fragment, FragmentProps, Link,
+        LinkProps, Text, TextProps, View, ViewProps,
+    },
+};
+
+/// 组件构造函数：根据节点属性和已构建的子元素创建元素，key 由注册表设置。
+pub type ComponentConstructor = Box<
+    dyn Fn(LayoutProps<'_>, Vec<AnyElement<'static>>) -> Result<AnyElement<'static>, LayoutError>
+        + Send
+        + Sync,
+>;
+
+// `#[with_layout_style]` 生成的布局属性名
+const LAYOUT_PROPS: [&str; 7] = [
+    "flex_direction",
+    "justify_content",
+    "gap",
+    "margin",
+    "offset",
+    "width",
+    "height",
+];
+
+// 把 `#[with_layout_style]` 生成的布局字段从节点属性写入组件属性
+macro_rules! apply_layout_style {
+    ($target:expr, $props:expr) => {{
+        let style = $props.layout_style()?;
+        $target.flex_direction = style.flex_direction;
+        $target.justify_content = style.justify_content;
+        $target.gap = style.gap;
+        $target.margin = style.margin;
+        $target.offset = style.offset;
+        $target.width = style.width;
+        $target.height = style.height;
+    }};
+}
+
+/// 组件注册表，把布局文档中的组件名映射到构造函数。
+///
+/// `ComponentRegistry::default()` 已注册内置组件：
+/// - `View`：布局属性（flex_direction、gap、width 等）。
+/// - `Fragment`：无属性。
+/// - `Border`：`title`、`bottom_title`、`border_style`、`style`、`padding` 及布局属性。
+/// - `Text`：`text`、`style`、`alignment`（Left/Center/Right）、`wrap` 及布局属性。
+/// - `Link`：`text`、`url`、`style` 及布局属性。
+///
+/// 通过 [`ComponentRegistry::register_with_schema`] 注册的组件在构建前按声明校验属性，
+/// 声明也可用于在命令面板等处列出可用组件。
+///
+/// # 示例
+/// ```rust
+/// let mut registry = ComponentRegistry::default();
+/// registry.register("Counter", |props, _children| {
+///     let initial = props.i32("initial")?.unwrap_or(0);
+///     Ok(element!(Counter(initial: initial)).into_any())
+/// });
+/// let element = registry.build_json(&source)?;
+/// ```
+#[derive(Clone)]
+pub struct ComponentRegistry {
+    components: HashMap<String, RegisteredComponent>,
+}
+
+#[derive(Clone)]
+struct RegisteredComponent {
+    constructor: Arc<ComponentConstructor>,
+    schema: Option<ComponentSchema>,
+}
+
+impl Default for ComponentRegistry {
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        registry.register_builtins();
+        registry
+    }
+}
+
+impl ComponentRegistry {
+    /// 不含任何组件的注册表。
+    pub fn empty() -> Self {
+        Self {
+            components: HashMap::new(),
+        }
+    }
+
+    /// 注册组件，同名组件会被覆盖。
+    pub fn register<F>(&mut self, name: impl Into<String>, constructor: F) -> &mut Self
+    where
+        F: Fn(
+                LayoutProps<'_>,
+                Vec<AnyElement<'static>>,
+            ) -> Result<AnyElement<'static>, LayoutError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.insert(name.into(), None, Box::new(constructor))
+    }
+
+    /// 注册带属性声明的组件，构建前按声明校验属性。
+    pub fn register_with_schema<F>(
+        &mut self,
+        name: impl Into<String>,
+        schema: ComponentSchema,
+        constructor: F,
+    ) -> &mut Self
+    where
+        F: Fn(
+                LayoutProps<'_>,
+                Vec<AnyElement<'static>>,
+            ) -> Result<AnyElement<'static>, LayoutError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.insert(name.into(), Some(schema), Box::new(constructor))
+    }
+
+    fn insert(
+        &mut self,
+        name: String,
+        schema: Option<ComponentSchema>,
+        constructor: ComponentConstructor,
+    ) -> &mut Self {
+        self.components.insert(
+            name,
+            RegisteredComponent {
+                constructor: Arc::new(constructor),
+                schema,
+            },
+        );
+        self
+    }
+
+    /// 合并另一个注册表，同名组件以 `other` 为准。
+    pub fn extend(&mut self, other: ComponentRegistry) -> &mut Self {
+        self.components.extend(other.components);
+        self
+    }
+
+    /// 是否已注册该组件。
+    pub fn contains(&self, name: &str) -> bool {
+        self.components.contains_key(name)
+    }
+
+    /// 已注册的组件名。
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.components.keys().map(String::as_str)
+    }
+
+    /// 组件的属性声明，未声明时返回 None。
+    pub fn schema(&self, name: &str) -> Option<&ComponentSchema> {
+        self.components.get(name)?.schema.as_ref()
+    }
+
+    /// 根据布局节点构建元素树。
+    pub fn build(&self, node: &LayoutNode) -> Result<AnyElement<'static>, LayoutError> {
+        self.build_node(node, 0)
+    }
+
+    /// 解析 JSON 布局文档并构建元素树。
+    pub fn build_json(&self, source: &str) -> Result<AnyElement<'static>, LayoutError> {
+        self.build(&LayoutNode::from_json(source)?)
+    }
+
+    /// 同 [`ComponentRegistry::build_json`]，出错时返回显示错误信息的元素，适合配合 HotReload 开发界面。
+    pub fn build_json_or_error(&self, source: &str) -> AnyElement<'static> {
+        self.build_json(source).unwrap_or_else(error_element)
+    }
+
+    /// 解析 RON 布局文档并构建元素树。
+    #[cfg(feature = "ron")]
+    pub fn build_ron(&self, source: &str) -> Result<AnyElement<'static>, LayoutError> {
+        self.build(&LayoutNode::from_ron(source)?)
+    }
+
+    /// 同 [`ComponentRegistry::build_ron`]，出错时返回显示错误信息的元素。
+    #[cfg(feature = "ron")]
+    pub fn build_ron_or_error(&self, source: &str) -> AnyElement<'static> {
+        self.build_ron(source).unwrap_or_else(error_element)
+    }
+
+    fn build_node(
+        &self,
+        node: &LayoutNode,
+        index: usize,
+    ) -> Result<AnyElement<'static>, LayoutError> {
+        let registered = self
+            .components
+            .get(&node.component)
+            .ok_or_else(|| LayoutError::UnknownComponent(node.component.clone()))?;
+        let props = LayoutProps::new(&node.component, &node.props);
+        if let Some(schema) = &registered.schema {
+            schema.validate(&node.component, props)?;
+        }
+        let children = node
+            .children
+            .iter()
+            .enumerate()
+            .map(|(i, child)| self.build_node(child, i))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut element = (registered.constructor)(props, children)?;
+        // 未指定 key 时按组件名和位置生成，重新加载后相同位置的组件保留状态
+        element.set_key(match &node.key {
+            Some(key) => ElementKey::new(key.clone()),
+            None => ElementKey::new(format!("{}#{index}", node.component)),
+        });
+        Ok(element)
+    }
+
+    fn register_builtins(&mut self) {
+        let view = ComponentSchema::new("布局容器").layout_props();
+        self.register_with_schema("View", view, |props, children| {
+            let mut view = ViewProps {
+                children,
+                ..Default::default()
+            };
+            apply_layout_style!(view, props);
+            Ok(element::<View>(view))
+        });
+
+        let fragment = ComponentSchema::new("透明容器，不产生布局节点");
+        self.register_with_schema("Fragment", fragment, |_props, children| {
+            Ok(element::<Fragment>(FragmentProps { children }))
+        });
+
+        let border = ComponentSchema::new("边框容器")
+            .prop("title", PropKind::String, "顶部标题")
+            .prop("bottom_title", PropKind::String, "底部标题")
+            .prop("border_style", PropKind::Style, "边框样式")
+            .prop("style", PropKind::Style, "整体样式")
+            .prop("padding", PropKind::Integer, "内边距")
+            .prop(
+                "kind",
+                PropKind::String,
+                "边框预设：Plain、Rounded、Double、Thick、Ascii",
+            )
+            .prop("collapse", PropKind::Bool, "与相邻边框共用边线")
+            .layout_props();
+        self.register_with_schema("Border", border, |props, children| {
+            let kind = match props.string("kind")?.as_deref() {
+                None => None,
+                Some("Plain") => Some(BorderKind::Plain),
+                Some("Rounded") => Some(BorderKind::Rounded),
+                Some("Double") => Some(BorderKind::Double),
+                Some("Thick") => Some(BorderKind::Thick),
+                Some("Ascii") => Some(BorderKind::Ascii),
+                Some(_) => {
+                    return Err(LayoutError::InvalidProp {
+                        component: "Border".to_string(),
+                        prop: "kind".to_string(),
+                        expected: "\"Plain\", \"Rounded\", \"Double\", \"Thick\" or \"Ascii\"",
+                    });
+                }
+            };
+            let mut border = BorderProps {
+                children,
+                top_title: props.line("title")?,
+                bottom_title: props.line("bottom_title")?,
+                border_style: props.style("border_style")?.unwrap_or_default(),
+                style: props.style("style")?.unwrap_or_default(),
+                padding: props
+                    .u16("padding")?
+                    .map(Padding::uniform)
+                    .unwrap_or_default(),
+                kind,
+                collapse: props.bool("collapse")?.unwrap_or_default(),
+                ..Default::default()
+            };
+            apply_layout_style!(border, props);
+            Ok(element::<Border>(border))
+        });
+
+        let text = ComponentSchema::new("文本段落")
+            .prop("text", PropKind::String, "文本内容")
+            .prop("style", PropKind::Style, "文本样式")
+            .prop(
+                "alignment",
+                PropKind::String,
+                "对齐方式：Left、Center、Right",
+            )
+            .prop("wrap", PropKind::Bool, "是否自动换行")
+            .layout_props();
+        self.register_with_schema("Text", text, |props, _children| {
+            let alignment = match props.string("alignment")?.as_deref() {
+                None | Some("Left") => Alignment::Left,
+                Some("Center") => Alignment::Center,
+                Some("Right") => Alignment::Right,
+                Some(_) => {
+                    return Err(LayoutError::InvalidProp {
+                        component: "Text".to_string(),
+                        prop: "alignment".to_string(),
+                        expected: "\"Left\", \"Center\" or \"Right\"",
+                    });
+                }
+            };
+            let text = element::<Text>(TextProps {
+                text: props.string("text")?.unwrap_or_default().into(),
+                style: props.style("style")?.unwrap_or_default(),
+                alignment,
+                wrap: props.bool("wrap")?.unwrap_or(false),
+                ..Default::default()
+            });
+            // 未指定布局属性时直接使用 Text，按内容测量尺寸
+            if props.names().any(|name| LAYOUT_PROPS.contains(&name)) {
+                with_layout(props, text)
+            } else {
+                Ok(text)
+            }
+        });
+
+        let link = ComponentSchema::new("超链接")
+            .required("url", PropKind::String, "链接地址")
+            .prop("text", PropKind::String, "显示的文本，缺省时显示链接地址")
+            .prop("style", PropKind::Style, "文本样式")
+            .layout_props();
+        self.register_with_schema("Link", link, |props, _children| {
+            let link = element::<Link>(LinkProps {
+                text: props.string("text")?.unwrap_or_default(),
+                url: props.string("url")?.unwrap_or_default(),
+                style: props
+                    .style("style")?
+                    .unwrap_or_else(|| Style::default().underlined()),
+                ..Default::default()
+            });
+            with_layout(props, link)
+        });
+    }
+}
+
+fn element<T>(props: T::Props<'static>) -> AnyElement<'static>
+where
+    T: crate::Component,
+{
+    Element::<T> {
+        key: ElementKey::new(0u8),
+        props,
+    }
+    .into_any()
+}
+
+fn text_element(paragraph: Paragraph<'static>) -> AnyElement<'static> {
+    element::<Adapter>(AdapterProps {
+        inner: Arc::new(paragraph),
+    })
+}
+
+// 以红色文本显示解析或构建错误
+fn error_element(err: LayoutError) -> AnyElement<'static> {
+    text_element(
+        Paragraph::new(err.to_string())
+            .red()
+            .wrap(Wrap { trim: false }),
+    )
+}
+
+// 没有布局属性的组件包一层 View，使布局属性（如 height）生效
+fn with_layout(
+    props: LayoutProps<'_>,
+    child: AnyElement<'static>,
+) -> Result<AnyElement<'static>, LayoutError> {
+    let mut view = ViewProps {
+        children: vec![child],
+        ..Default::default()
+    };
+    apply_layout_style!(view, props);
+    Ok(element::<View>(view))
+}
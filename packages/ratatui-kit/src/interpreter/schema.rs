@@ -0,0 +1,136 @@
+use super::{LayoutError, LayoutProps};
+
+/// 属性值的类型，对应 [`LayoutProps`] 的读取方法。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PropKind {
+    String,
+    Bool,
+    Integer,
+    Number,
+    Color,
+    Style,
+    Constraint,
+    Direction,
+    Flex,
+    Margin,
+    Offset,
+    /// 任意 JSON 值，不做校验。
+    Any,
+}
+
+/// 单个属性的描述。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropSchema {
+    pub name: String,
+    pub kind: PropKind,
+    pub required: bool,
+    pub description: String,
+}
+
+/// 组件的描述和属性列表，用于校验布局文档，以及在命令面板等处展示可用组件。
+///
+/// # 示例
+/// ```rust
+/// let schema = ComponentSchema::new("计数器")
+///     .prop("initial", PropKind::Integer, "初始值")
+///     .required("label", PropKind::String, "标签");
+/// registry.register_with_schema("Counter", schema, |props, _| { ... });
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ComponentSchema {
+    pub description: String,
+    pub props: Vec<PropSchema>,
+    /// 是否允许未声明的属性，默认不允许。
+    pub allow_unknown: bool,
+}
+
+impl ComponentSchema {
+    pub fn new(description: impl Into<String>) -> Self {
+        Self {
+            description: description.into(),
+            ..Default::default()
+        }
+    }
+
+    /// 声明可选属性。
+    pub fn prop(
+        mut self,
+        name: impl Into<String>,
+        kind: PropKind,
+        description: impl Into<String>,
+    ) -> Self {
+        self.props.push(PropSchema {
+            name: name.into(),
+            kind,
+            required: false,
+            description: description.into(),
+        });
+        self
+    }
+
+    /// 声明必填属性。
+    pub fn required(
+        mut self,
+        name: impl Into<String>,
+        kind: PropKind,
+        description: impl Into<String>,
+    ) -> Self {
+        self.props.push(PropSchema {
+            name: name.into(),
+            kind,
+            required: true,
+            description: description.into(),
+        });
+        self
+    }
+
+    /// 声明 `#[with_layout_style]` 对应的布局属性。
+    pub fn layout_props(self) -> Self {
+        self.prop("flex_direction", PropKind::Direction, "子元素排列方向")
+            .prop("justify_content", PropKind::Flex, "主轴对齐方式")
+            .prop("gap", PropKind::Integer, "子元素间距")
+            .prop("margin", PropKind::Margin, "外边距")
+            .prop("offset", PropKind::Offset, "偏移")
+            .prop("width", PropKind::Constraint, "宽度约束")
+            .prop("height", PropKind::Constraint, "高度约束")
+    }
+
+    /// 按声明校验节点属性。
+    pub fn validate(&self, component: &str, props: LayoutProps<'_>) -> Result<(), LayoutError> {
+        for prop in &self.props {
+            let name = prop.name.as_str();
+            if prop.required && props.value(name).is_none() {
+                return Err(LayoutError::MissingProp {
+                    component: component.to_string(),
+                    prop: prop.name.clone(),
+                });
+            }
+            match prop.kind {
+                PropKind::String => props.string(name).map(drop),
+                PropKind::Bool => props.bool(name).map(drop),
+                PropKind::Integer => props.i32(name).map(drop),
+                PropKind::Number => props.f64(name).map(drop),
+                PropKind::Color => props.color(name).map(drop),
+                PropKind::Style => props.style(name).map(drop),
+                PropKind::Constraint => props.constraint(name).map(drop),
+                PropKind::Direction => props.direction(name).map(drop),
+                PropKind::Flex => props.flex(name).map(drop),
+                PropKind::Margin => props.margin(name).map(drop),
+                PropKind::Offset => props.offset(name).map(drop),
+                PropKind::Any => Ok(()),
+            }?;
+        }
+        if !self.allow_unknown {
+            if let Some(unknown) = props
+                .names()
+                .find(|name| !self.props.iter().any(|prop| prop.name == *name))
+            {
+                return Err(LayoutError::UnknownProp {
+                    component: component.to_string(),
+                    prop: unknown.to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
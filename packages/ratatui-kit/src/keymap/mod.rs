@@ -0,0 +1,355 @@
+//! 快捷键模块：以动作名称登记快捷键，支持运行时重新绑定、从配置文件加载和多键序列（如 `g g`）。
+//!
+//! ## 用法示例
+//! ```rust
+//! static KEYMAP: LazyLock<Keymap> = LazyLock::new(|| {
+//!     Keymap::new()
+//!         .with_action("top", "跳到开头", &["g g", "home"])
+//!         .with_action("bottom", "跳到末尾", &["G", "end"])
+//!         .with_action("save", "保存", &["ctrl+s"])
+//! });
+//!
+//! hooks.use_keymap(&KEYMAP, move |action| match action {
+//!     "top" => offset.set(0),
+//!     "save" => save(),
+//!     _ => {}
+//! });
+//! ```
+//! - 按键格式见 [`KeyChord`] 和 [`KeySequence`]，一个动作可以绑定多组按键。
+//! - 按下的按键是某个序列的前缀时等待后续按键，超过 [`SEQUENCE_TIMEOUT`] 后按已输入的按键匹配。
+//! - 在 [`ModeProvider`](crate::components::ModeProvider) 内部使用时，通过 [`Keymap::in_modes`]
+//!   限定动作生效的输入模式，未限定的动作在所有模式下生效。
+//! - 启用 `json` 特性后可通过 [`Keymap::load_file`] / [`Keymap::save_file`] 读写用户的自定义绑定。
+
+use std::{
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+    time::{Duration, Instant},
+};
+
+mod key;
+pub use key::*;
+mod mode;
+pub use mode::*;
+mod use_keymap;
+pub use use_keymap::*;
+mod use_mode;
+pub use use_mode::*;
+
+/// 多键序列中两次按键的最长间隔。
+pub const SEQUENCE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// 一个可绑定快捷键的动作。
+#[derive(Debug, Clone)]
+pub struct KeyAction {
+    pub name: String,
+    pub description: String,
+    /// 当前绑定的按键。
+    pub keys: Vec<KeySequence>,
+    /// 默认绑定的按键。
+    pub default_keys: Vec<KeySequence>,
+    /// 生效的输入模式，为空时在所有模式下生效。
+    pub modes: Vec<InputMode>,
+}
+
+impl KeyAction {
+    /// 当前绑定是否与默认值不同。
+    pub fn is_modified(&self) -> bool {
+        self.keys != self.default_keys
+    }
+
+    /// 是否在指定输入模式下生效，`mode` 为 None 表示没有使用输入模式。
+    pub fn is_active(&self, mode: Option<InputMode>) -> bool {
+        match mode {
+            Some(mode) => self.modes.is_empty() || self.modes.contains(&mode),
+            None => true,
+        }
+    }
+
+    /// 两个动作是否可能在同一输入模式下生效，用于判断按键冲突。
+    pub fn shares_mode(&self, other: &KeyAction) -> bool {
+        self.modes.is_empty()
+            || other.modes.is_empty()
+            || self.modes.iter().any(|mode| other.modes.contains(mode))
+    }
+}
+
+#[derive(Default)]
+struct KeymapInner {
+    actions: Vec<KeyAction>,
+    // KeymapEditor 录制按键期间暂停分发
+    recording: bool,
+    version: u64,
+    wakers: Vec<Waker>,
+}
+
+/// 快捷键表，克隆后共享同一份绑定，修改后通知所有使用它的组件。
+#[derive(Clone, Default)]
+pub struct Keymap {
+    inner: Arc<Mutex<KeymapInner>>,
+}
+
+impl Keymap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 登记动作及其默认按键。
+    ///
+    /// # Panics
+    /// 按键格式无效时 panic，默认按键由开发者提供，应在开发阶段发现。
+    pub fn with_action(
+        self,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        keys: &[&str],
+    ) -> Self {
+        let keys = keys
+            .iter()
+            .map(|keys| {
+                keys.parse::<KeySequence>()
+                    .unwrap_or_else(|err| panic!("{err}"))
+            })
+            .collect::<Vec<_>>();
+        self.inner.lock().unwrap().actions.push(KeyAction {
+            name: name.into(),
+            description: description.into(),
+            keys: keys.clone(),
+            default_keys: keys,
+            modes: Vec::new(),
+        });
+        self
+    }
+
+    /// 限定最近登记的动作只在指定输入模式下生效。
+    ///
+    /// ```rust
+    /// Keymap::new()
+    ///     .with_action("delete_line", "删除行", &["d d"])
+    ///     .in_modes(&[InputMode::Normal])
+    /// ```
+    pub fn in_modes(self, modes: &[InputMode]) -> Self {
+        if let Some(action) = self.inner.lock().unwrap().actions.last_mut() {
+            action.modes = modes.to_vec();
+        }
+        self
+    }
+
+    /// 所有动作，按登记顺序排列。
+    pub fn actions(&self) -> Vec<KeyAction> {
+        self.inner.lock().unwrap().actions.clone()
+    }
+
+    /// 动作当前绑定的按键，可用于在菜单或帮助中显示。
+    pub fn keys(&self, action: &str) -> Vec<KeySequence> {
+        self.inner
+            .lock()
+            .unwrap()
+            .actions
+            .iter()
+            .find(|a| a.name == action)
+            .map(|a| a.keys.clone())
+            .unwrap_or_default()
+    }
+
+    /// 重新绑定动作的按键，动作不存在时忽略。
+    pub fn set_keys(&self, action: &str, keys: Vec<KeySequence>) {
+        self.update(|actions| {
+            if let Some(a) = actions.iter_mut().find(|a| a.name == action) {
+                a.keys = keys;
+            }
+        });
+    }
+
+    /// 把动作的按键恢复为默认值。
+    pub fn reset(&self, action: &str) {
+        self.update(|actions| {
+            if let Some(a) = actions.iter_mut().find(|a| a.name == action) {
+                a.keys = a.default_keys.clone();
+            }
+        });
+    }
+
+    /// 绑定了 `keys` 的所有动作，同一模式下多于一个时说明存在冲突。
+    pub fn actions_for(&self, keys: &KeySequence) -> Vec<KeyAction> {
+        self.inner
+            .lock()
+            .unwrap()
+            .actions
+            .iter()
+            .filter(|a| a.keys.contains(keys))
+            .cloned()
+            .collect()
+    }
+
+    /// 从 JSON 文件加载自定义绑定，格式为 `{ "动作": "按键" }` 或 `{ "动作": ["按键", ...] }`。
+    ///
+    /// 文件不存在时不做任何事，未登记的动作会被忽略。
+    #[cfg(feature = "json")]
+    pub fn load_file(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        use std::io;
+
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err),
+        };
+        let map: serde_json::Map<String, serde_json::Value> = serde_json::from_str(&content)?;
+        let invalid = |err: ParseKeyError| io::Error::new(io::ErrorKind::InvalidData, err);
+        let mut bindings = Vec::new();
+        for (action, value) in map {
+            let keys = match value {
+                serde_json::Value::String(keys) => vec![keys.parse().map_err(invalid)?],
+                serde_json::Value::Array(items) => items
+                    .iter()
+                    .map(|item| {
+                        let keys = item.as_str().ok_or_else(|| {
+                            io::Error::new(io::ErrorKind::InvalidData, "key must be a string")
+                        })?;
+                        keys.parse().map_err(invalid)
+                    })
+                    .collect::<io::Result<Vec<_>>>()?,
+                _ => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("invalid keys for action `{action}`"),
+                    ));
+                }
+            };
+            bindings.push((action, keys));
+        }
+        self.update(|actions| {
+            for (action, keys) in bindings {
+                if let Some(a) = actions.iter_mut().find(|a| a.name == action) {
+                    a.keys = keys;
+                }
+            }
+        });
+        Ok(())
+    }
+
+    /// 把与默认值不同的绑定写入 JSON 文件。
+    #[cfg(feature = "json")]
+    pub fn save_file(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        let map = self
+            .actions()
+            .into_iter()
+            .filter(KeyAction::is_modified)
+            .map(|a| {
+                let keys = a.keys.iter().map(|k| k.to_string().into()).collect();
+                (a.name, serde_json::Value::Array(keys))
+            })
+            .collect::<serde_json::Map<_, _>>();
+        let content = serde_json::to_string_pretty(&map)?;
+        std::fs::write(path, content + "\n")
+    }
+
+    pub(crate) fn set_recording(&self, recording: bool) {
+        self.inner.lock().unwrap().recording = recording;
+    }
+
+    pub(crate) fn is_recording(&self) -> bool {
+        self.inner.lock().unwrap().recording
+    }
+
+    // 按已输入的按键匹配：完全匹配的动作，以及是否还有更长的序列以它为前缀
+    pub(crate) fn lookup(
+        &self,
+        pending: &[KeyChord],
+        mode: Option<InputMode>,
+    ) -> (Option<String>, bool) {
+        let inner = self.inner.lock().unwrap();
+        let mut exact = None;
+        let mut longer = false;
+        for action in inner.actions.iter().filter(|a| a.is_active(mode)) {
+            for keys in action.keys.iter().filter(|k| k.starts_with(pending)) {
+                if keys.0.len() == pending.len() {
+                    exact.get_or_insert_with(|| action.name.clone());
+                } else {
+                    longer = true;
+                }
+            }
+        }
+        (exact, longer)
+    }
+
+    pub(crate) fn poll_changed(&self, seen: &mut u64, cx: &mut Context) -> Poll<()> {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.version != *seen {
+            *seen = inner.version;
+            return Poll::Ready(());
+        }
+        if !inner.wakers.iter().any(|w| w.will_wake(cx.waker())) {
+            inner.wakers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+
+    fn update(&self, f: impl FnOnce(&mut Vec<KeyAction>)) {
+        let mut inner = self.inner.lock().unwrap();
+        f(&mut inner.actions);
+        inner.version += 1;
+        inner.wakers.drain(..).for_each(Waker::wake);
+    }
+}
+
+/// 多键序列的匹配结果。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Resolved {
+    /// 匹配到动作。
+    Action(String),
+    /// 是某个序列的前缀，等待后续按键。
+    Pending,
+    /// 没有匹配的绑定。
+    Unmatched,
+}
+
+/// 逐个输入按键并匹配多键序列。
+#[derive(Default)]
+pub(crate) struct KeyResolver {
+    pending: Vec<KeyChord>,
+    last: Option<Instant>,
+}
+
+impl KeyResolver {
+    pub(crate) fn pending(&self) -> &[KeyChord] {
+        &self.pending
+    }
+
+    pub(crate) fn feed(
+        &mut self,
+        keymap: &Keymap,
+        mode: Option<InputMode>,
+        chord: KeyChord,
+    ) -> Resolved {
+        if self
+            .last
+            .is_some_and(|last| last.elapsed() > SEQUENCE_TIMEOUT)
+        {
+            self.pending.clear();
+        }
+        self.last = Some(Instant::now());
+        self.pending.push(chord);
+        match keymap.lookup(&self.pending, mode) {
+            (_, true) => return Resolved::Pending,
+            (Some(action), false) => {
+                self.pending.clear();
+                return Resolved::Action(action);
+            }
+            (None, false) => {}
+        }
+        // 序列中断时丢弃之前的按键，把当前按键作为新序列的开始
+        if self.pending.len() > 1 {
+            self.pending.clear();
+            return self.feed(keymap, mode, chord);
+        }
+        self.pending.clear();
+        Resolved::Unmatched
+    }
+
+    /// 等待超时，按已输入的按键匹配。
+    pub(crate) fn timeout(&mut self, keymap: &Keymap, mode: Option<InputMode>) -> Option<String> {
+        let pending = std::mem::take(&mut self.pending);
+        keymap.lookup(&pending, mode).0
+    }
+}
@@ -0,0 +1,75 @@
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+/// 类似 Vim 的输入模式。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum InputMode {
+    /// 普通模式，按键作为命令。
+    #[default]
+    Normal,
+    /// 插入模式，按键作为文本输入。
+    Insert,
+    /// 可视模式，用于选择。
+    Visual,
+}
+
+impl fmt::Display for InputMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Normal => "NORMAL",
+            Self::Insert => "INSERT",
+            Self::Visual => "VISUAL",
+        })
+    }
+}
+
+#[derive(Default)]
+struct ModeInner {
+    mode: InputMode,
+    version: u64,
+    wakers: Vec<Waker>,
+}
+
+/// 当前输入模式的共享句柄，由 [`ModeProvider`](crate::components::ModeProvider) 提供，
+/// 通过 `hooks.use_mode()` 获取。
+#[derive(Clone, Default)]
+pub struct Mode {
+    inner: Arc<Mutex<ModeInner>>,
+}
+
+impl Mode {
+    /// 当前模式。
+    pub fn get(&self) -> InputMode {
+        self.inner.lock().unwrap().mode
+    }
+
+    /// 切换模式，通知所有使用它的组件。
+    pub fn set(&self, mode: InputMode) {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.mode != mode {
+            inner.mode = mode;
+            inner.version += 1;
+            inner.wakers.drain(..).for_each(Waker::wake);
+        }
+    }
+
+    /// 是否处于指定模式。
+    pub fn is(&self, mode: InputMode) -> bool {
+        self.get() == mode
+    }
+
+    pub(crate) fn poll_changed(&self, seen: &mut u64, cx: &mut Context) -> Poll<()> {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.version != *seen {
+            *seen = inner.version;
+            return Poll::Ready(());
+        }
+        if !inner.wakers.iter().any(|w| w.will_wake(cx.waker())) {
+            inner.wakers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
@@ -0,0 +1,138 @@
+use std::{
+    pin::{Pin, pin},
+    task::{Context, Poll},
+};
+
+use crossterm::event::{Event, KeyEventKind};
+use futures::{FutureExt, Stream};
+use futures_timer::Delay;
+
+use super::{KeyChord, KeyResolver, Keymap, Mode, Resolved, SEQUENCE_TIMEOUT};
+use crate::components::FocusScopeContext;
+use crate::{Commands, EventInterest, Hook, Hooks, TerminalEvents, UseContext};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+pub trait UseKeymap: private::Sealed {
+    /// 按快捷键表分发按键，匹配到动作时以动作名称调用 `f`。
+    ///
+    /// 在 ModeProvider 内部使用时只匹配当前输入模式下生效的动作；在 CommandLine 内部使用时，
+    /// 命令行打开期间暂停分发。
+    ///
+    /// 返回多键序列中已输入但尚未匹配完成的按键，可用于在状态栏中提示；
+    /// 快捷键表被修改时重新渲染当前组件。
+    fn use_keymap<F>(&mut self, keymap: &Keymap, f: F) -> Vec<KeyChord>
+    where
+        F: FnMut(&str) + Send + 'static;
+}
+
+type ActionHandler = Box<dyn FnMut(&str) + Send>;
+
+struct UseKeymapImpl {
+    keymap: Keymap,
+    mode: Option<Mode>,
+    commands: Option<Commands>,
+    f: Option<ActionHandler>,
+    events: Option<TerminalEvents<Event>>,
+    resolver: KeyResolver,
+    // 等待多键序列后续按键的计时器
+    timeout: Option<Delay>,
+    seen: u64,
+    // 所在的焦点作用域，不在焦点链上时不处理按键
+    scope: Option<FocusScopeContext>,
+}
+
+impl UseKeymapImpl {
+    fn dispatch(&mut self, action: Option<String>) {
+        if let (Some(action), Some(f)) = (action, self.f.as_mut()) {
+            f(&action);
+        }
+    }
+}
+
+impl Hook for UseKeymapImpl {
+    fn poll_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let this = self.get_mut();
+        let mut changed = this.keymap.poll_changed(&mut this.seen, cx).is_ready();
+        while let Some(Poll::Ready(Some(event))) = this
+            .events
+            .as_mut()
+            .map(|events| pin!(events).poll_next(cx))
+        {
+            if !FocusScopeContext::accepts(this.scope.as_ref(), &event) {
+                continue;
+            }
+            let Event::Key(key) = event else {
+                continue;
+            };
+            let command_line_open = this.commands.as_ref().is_some_and(Commands::is_open);
+            if key.kind == KeyEventKind::Release || this.keymap.is_recording() || command_line_open
+            {
+                continue;
+            }
+            let had_pending = !this.resolver.pending().is_empty();
+            let mode = this.mode.as_ref().map(Mode::get);
+            match this.resolver.feed(&this.keymap, mode, key.into()) {
+                Resolved::Action(action) => this.dispatch(Some(action)),
+                Resolved::Pending => this.timeout = Some(Delay::new(SEQUENCE_TIMEOUT)),
+                Resolved::Unmatched => {}
+            }
+            changed |= had_pending || !this.resolver.pending().is_empty();
+        }
+        if this.resolver.pending().is_empty() {
+            this.timeout = None;
+        }
+        if let Some(timeout) = this.timeout.as_mut() {
+            if timeout.poll_unpin(cx).is_ready() {
+                this.timeout = None;
+                let mode = this.mode.as_ref().map(Mode::get);
+                let action = this.resolver.timeout(&this.keymap, mode);
+                this.dispatch(action);
+                changed = true;
+            }
+        }
+        if changed {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn post_component_update(&mut self, updater: &mut crate::ComponentUpdater) {
+        self.scope = FocusScopeContext::current(updater);
+        if self.events.is_none() {
+            self.events = updater.events_of(EventInterest::KEY).ok();
+        }
+    }
+}
+
+impl UseKeymap for Hooks<'_, '_> {
+    fn use_keymap<F>(&mut self, keymap: &Keymap, f: F) -> Vec<KeyChord>
+    where
+        F: FnMut(&str) + Send + 'static,
+    {
+        let mode = self.try_use_context::<Mode>().map(|mode| mode.clone());
+        let commands = self
+            .try_use_context::<Commands>()
+            .map(|commands| commands.clone());
+        let hook = self.use_hook(|| UseKeymapImpl {
+            keymap: keymap.clone(),
+            mode: None,
+            commands: None,
+            f: None,
+            events: None,
+            resolver: KeyResolver::default(),
+            timeout: None,
+            seen: 0,
+            scope: None,
+        });
+        hook.keymap = keymap.clone();
+        hook.mode = mode;
+        hook.commands = commands;
+        hook.f = Some(Box::new(f));
+        hook.resolver.pending().to_vec()
+    }
+}
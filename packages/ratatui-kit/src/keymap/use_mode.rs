@@ -0,0 +1,49 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use super::Mode;
+use crate::{Hook, Hooks, UseContext};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+pub trait UseMode: private::Sealed {
+    /// 获取当前输入模式，模式切换时重新渲染当前组件，需在 ModeProvider 内部使用。
+    ///
+    /// # 示例
+    /// ```rust
+    /// let mode = hooks.use_mode();
+    /// if mode.is(InputMode::Insert) {
+    ///     // 把按键作为文本输入
+    /// }
+    /// ```
+    fn use_mode(&mut self) -> Mode;
+}
+
+struct UseModeImpl {
+    mode: Mode,
+    seen: u64,
+}
+
+impl Hook for UseModeImpl {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let this = &mut *self;
+        this.mode.poll_changed(&mut this.seen, cx)
+    }
+}
+
+impl UseMode for Hooks<'_, '_> {
+    fn use_mode(&mut self) -> Mode {
+        let mode = self.use_context::<Mode>().clone();
+        let hook = self.use_hook(|| UseModeImpl {
+            mode: mode.clone(),
+            seen: 0,
+        });
+        hook.mode = mode.clone();
+        mode
+    }
+}
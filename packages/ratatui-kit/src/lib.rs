@@ -1,23 +1,38 @@
 #![allow(clippy::needless_update)]
 mod component;
 pub mod components;
+#[cfg(feature = "config")]
+mod config;
 mod context;
 mod element;
+pub mod export;
 mod handler;
 mod hooks;
+#[cfg(feature = "layout")]
+pub mod interpreter;
+mod keymap;
 mod multimap;
+pub mod pickers;
+pub mod progress;
 mod props;
 mod render;
+pub mod runtime;
 #[cfg(feature = "store")]
 mod store;
 mod terminal;
+#[cfg(test)]
+mod test_util;
+pub mod text;
 
 mod flatten_export {
     pub use crate::component::*;
+    #[cfg(feature = "config")]
+    pub use crate::config::*;
     pub use crate::context::*;
     pub use crate::element::*;
     pub use crate::handler::*;
     pub use crate::hooks::*;
+    pub use crate::keymap::*;
     pub use crate::props::*;
     pub use crate::render::*;
     #[cfg(feature = "store")]
@@ -25,10 +40,14 @@ mod flatten_export {
     pub use crate::terminal::*;
 }
 
+#[cfg(feature = "clap")]
+pub use clap;
 pub use crossterm;
 pub use flatten_export::*;
 pub use ratatui;
 pub use ratatui_kit_macros::*;
+#[cfg(feature = "fetch")]
+pub use reqwest;
 
 pub mod prelude {
     pub use crate::components::*;
@@ -1,11 +1,14 @@
-use std::{
-    collections::{HashMap, VecDeque},
-    hash::Hash,
-};
+use std::{collections::HashMap, hash::Hash};
+
+// 同一个键的多个值按插入顺序串成链表，键只记录首尾位置，避免为每个键单独分配队列
+struct Entry<V> {
+    value: Option<V>,
+    next: Option<usize>,
+}
 
 pub(crate) struct AppendOnlyMultimap<K, V> {
-    items: Vec<Option<V>>,
-    m: HashMap<K, VecDeque<usize>>,
+    items: Vec<Entry<V>>,
+    m: HashMap<K, (usize, usize)>,
 }
 
 impl<K, V> Default for AppendOnlyMultimap<K, V> {
@@ -21,17 +24,37 @@ impl<K, V> AppendOnlyMultimap<K, V>
 where
     K: Eq + Hash,
 {
+    /// 复用已清空的 multimap 的存储空间，避免每次更新重新分配。
+    pub fn recycle(mut map: RemoveOnlyMultimap<K, V>) -> Self {
+        map.clear();
+        Self {
+            items: map.items,
+            m: map.m,
+        }
+    }
+
     /// 向 multimap 末尾追加一个值，关联到指定的键。
     pub fn push_back(&mut self, key: K, value: V) {
         let index = self.items.len();
-        self.items.push(Some(value));
-        self.m.entry(key).or_default().push_back(index);
+        self.items.push(Entry {
+            value: Some(value),
+            next: None,
+        });
+        match self.m.get_mut(&key) {
+            Some((_, tail)) => {
+                self.items[*tail].next = Some(index);
+                *tail = index;
+            }
+            None => {
+                self.m.insert(key, (index, index));
+            }
+        }
     }
 }
 
 pub struct RemoveOnlyMultimap<K, V> {
-    items: Vec<Option<V>>,
-    m: HashMap<K, VecDeque<usize>>,
+    items: Vec<Entry<V>>,
+    m: HashMap<K, (usize, usize)>,
 }
 
 impl<K, V> Default for RemoveOnlyMultimap<K, V> {
@@ -60,15 +83,28 @@ where
     K: Eq + Hash,
 {
     pub fn pop_front(&mut self, key: &K) -> Option<V> {
-        let index = self.m.get_mut(key)?.pop_front()?;
-        self.items[index].take()
+        let (head, _) = self.m.get_mut(key)?;
+        let index = *head;
+        match self.items[index].next {
+            Some(next) => *head = next,
+            None => {
+                self.m.remove(key);
+            }
+        }
+        self.items[index].value.take()
     }
 
     pub fn iter(&self) -> impl Iterator<Item = &V> {
-        self.items.iter().filter_map(|item| item.as_ref())
+        self.items.iter().filter_map(|item| item.value.as_ref())
     }
 
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut V> {
-        self.items.iter_mut().filter_map(|item| item.as_mut())
+        self.items.iter_mut().filter_map(|item| item.value.as_mut())
+    }
+
+    /// 丢弃剩余的值，保留已分配的空间。
+    pub(crate) fn clear(&mut self) {
+        self.items.clear();
+        self.m.clear();
     }
 }
@@ -0,0 +1,492 @@
+//! 阻塞式提示：在内联视口中显示一个最小的交互界面并等待结果，
+//! 普通命令行程序无需编写组件即可嵌入选择、确认、输入和路径选择。
+//!
+//! ## 用法示例
+//! ```rust
+//! use ratatui_kit::pickers;
+//!
+//! let Some(env) = pickers::select(["dev", "staging", "prod"]).await? else {
+//!     return Ok(());
+//! };
+//! if pickers::confirm(format!("部署到 {env}？")).await? {
+//!     let note = pickers::input("备注").await?.unwrap_or_default();
+//!     let config = pickers::pick_path(".").await?;
+//! }
+//! ```
+//! - 选择列表可以直接输入文字筛选，`↑`/`↓` 移动，`Enter` 确认；
+//! - `Esc` 取消，`select`/`pick_one`/`input`/`pick_path` 返回 None，`confirm` 返回 false；
+//! - `Ctrl+C` 返回 [`io::ErrorKind::Interrupted`] 错误，调用方可以据此结束进程；
+//! - 完成后界面收起为一行摘要（如 `✔ 请选择 › prod`），保留在终端的输出中。
+
+use std::{
+    any::Any,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::{
+    TerminalOptions, Viewport,
+    style::{Style, Stylize},
+    text::{Line, Span},
+};
+use ratatui_kit_macros::{Props, component, element};
+
+use crate::{
+    AnyElement, ElementExt, EventInterest, Hooks, State, SystemContext, UseContext, UseEvents,
+    UseInlineHeight, UseMemo, UseState, components::Text,
+};
+
+// 列表最多同时显示的行数
+const VISIBLE_ROWS: usize = 8;
+
+/// 从列表中选择一项，提示文字为“请选择”。
+pub async fn select<T: Display>(items: impl IntoIterator<Item = T>) -> io::Result<Option<T>> {
+    pick_one("请选择", items).await
+}
+
+/// 显示提示文字并从列表中选择一项，列表为空时直接返回 None。
+///
+/// # 示例
+/// ```rust
+/// let branch = pickers::pick_one("切换到分支", branches).await?;
+/// ```
+pub async fn pick_one<T: Display>(
+    prompt: impl Into<String>,
+    items: impl IntoIterator<Item = T>,
+) -> io::Result<Option<T>> {
+    let mut items = items.into_iter().collect::<Vec<_>>();
+    if items.is_empty() {
+        return Ok(None);
+    }
+    let labels = items.iter().map(ToString::to_string).collect::<Vec<_>>();
+    let height = list_height(labels.len());
+    let index = run::<usize>(
+        element!(SelectPrompt(prompt: prompt.into(), items: labels)),
+        height,
+    )
+    .await?;
+    Ok(index.map(|index| items.swap_remove(index)))
+}
+
+/// 显示是/否确认，`y` 或 `Enter` 为是，`n` 或 `Esc` 为否。
+pub async fn confirm(message: impl Into<String>) -> io::Result<bool> {
+    let answer = run::<bool>(element!(ConfirmPrompt(message: message.into())), 1).await?;
+    Ok(answer.unwrap_or(false))
+}
+
+/// 读取一行文本，`Esc` 取消时返回 None。
+pub async fn input(prompt: impl Into<String>) -> io::Result<Option<String>> {
+    run::<String>(element!(InputPrompt(prompt: prompt.into())), 2).await
+}
+
+/// 从 `start` 目录开始浏览并选择文件或目录。
+///
+/// `Enter` 进入目录或选中文件，`Tab` 选中当前项（包括目录），`Backspace` 在筛选文字为空时返回上级目录。
+pub async fn pick_path(start: impl AsRef<Path>) -> io::Result<Option<PathBuf>> {
+    let start = fs::canonicalize(start.as_ref())?;
+    run::<PathBuf>(
+        element!(PathPrompt(start: start)),
+        list_height(VISIBLE_ROWS),
+    )
+    .await
+}
+
+// 在内联视口中运行提示组件，取出组件通过 exit_with 传出的结果
+async fn run<R: Any>(mut element: impl ElementExt, height: u16) -> io::Result<Option<R>> {
+    let exit = element
+        .render_loop(TerminalOptions {
+            viewport: Viewport::Inline(height),
+        })
+        .await?;
+    if exit.is_interrupted() {
+        return Err(io::Error::new(
+            io::ErrorKind::Interrupted,
+            "prompt interrupted",
+        ));
+    }
+    Ok(exit.value())
+}
+
+// 提示行、列表和按键提示的总高度
+fn list_height(len: usize) -> u16 {
+    len.clamp(1, VISIBLE_ROWS) as u16 + 2
+}
+
+fn prompt_line(prompt: &str, value: impl Into<String>) -> Line<'static> {
+    Line::from(vec![
+        Span::from("? ").cyan().bold(),
+        Span::from(prompt.to_string()).bold(),
+        Span::from(" › ").dark_gray(),
+        Span::from(value.into()),
+    ])
+}
+
+// 完成后保留在终端中的摘要，answer 为 None 表示已取消
+fn summary_line(prompt: &str, answer: Option<&str>) -> Line<'static> {
+    match answer {
+        Some(answer) => Line::from(vec![
+            Span::from("✔ ").green().bold(),
+            Span::from(prompt.to_string()).bold(),
+            Span::from(" › ").dark_gray(),
+            Span::from(answer.to_string()).cyan(),
+        ]),
+        None => Line::from(vec![
+            Span::from("✘ ").red().bold(),
+            Span::from(prompt.to_string()).bold(),
+            Span::from(" › 已取消").dark_gray(),
+        ]),
+    }
+}
+
+fn hint_line(hint: &str) -> Line<'static> {
+    Line::styled(hint.to_string(), Style::default().dark_gray())
+}
+
+fn lines(lines: Vec<Line<'static>>) -> AnyElement<'static> {
+    element!(Text(text: lines, wrap: false)).into()
+}
+
+// 不区分大小写地筛选列表，返回匹配项的下标
+fn filter(items: &[String], query: &str) -> Vec<usize> {
+    let query = query.to_lowercase();
+    items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| item.to_lowercase().contains(&query))
+        .map(|(index, _)| index)
+        .collect()
+}
+
+// 筛选后的列表行，光标所在项始终可见
+fn list_lines(items: &[String], matches: &[usize], cursor: usize) -> Vec<Line<'static>> {
+    if matches.is_empty() {
+        return vec![Line::styled("  无匹配项", Style::default().dark_gray())];
+    }
+    let offset = cursor.saturating_sub(VISIBLE_ROWS - 1);
+    matches
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(VISIBLE_ROWS)
+        .map(|(row, &index)| {
+            if row == cursor {
+                Line::from(format!("❯ {}", items[index])).cyan().bold()
+            } else {
+                Line::from(format!("  {}", items[index]))
+            }
+        })
+        .collect()
+}
+
+// 列表共用的按键
+enum ListKey {
+    Move(isize),
+    Query(Option<char>),
+    Submit,
+    Tab,
+    Cancel,
+}
+
+fn list_key(key: &KeyEvent) -> Option<ListKey> {
+    if key.kind == KeyEventKind::Release {
+        return None;
+    }
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    Some(match key.code {
+        KeyCode::Up => ListKey::Move(-1),
+        KeyCode::Down => ListKey::Move(1),
+        KeyCode::Char('p') if ctrl => ListKey::Move(-1),
+        KeyCode::Char('n') if ctrl => ListKey::Move(1),
+        KeyCode::Enter => ListKey::Submit,
+        KeyCode::Tab => ListKey::Tab,
+        KeyCode::Esc => ListKey::Cancel,
+        KeyCode::Backspace => ListKey::Query(None),
+        KeyCode::Char(c) if !ctrl => ListKey::Query(Some(c)),
+        _ => return None,
+    })
+}
+
+// 输入或删除一个字符
+fn edit_query(query: State<String>, c: Option<char>) {
+    match c {
+        Some(c) => query.write().push(c),
+        None => {
+            query.write().pop();
+        }
+    }
+}
+
+// 循环移动光标
+fn step(cursor: usize, delta: isize, len: usize) -> usize {
+    if len == 0 {
+        return 0;
+    }
+    (cursor as isize + delta).rem_euclid(len as isize) as usize
+}
+
+#[derive(Default, Props)]
+struct SelectPromptProps {
+    prompt: String,
+    items: Vec<String>,
+}
+
+#[component]
+fn SelectPrompt(props: &SelectPromptProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let mut system = hooks.use_context_mut::<SystemContext>();
+    let query = hooks.use_state(String::new);
+    let mut cursor = hooks.use_state(|| 0usize);
+    let mut done = hooks.use_state(|| None::<Option<usize>>);
+
+    let matches = filter(&props.items, &query.read());
+    hooks.use_events_of(EventInterest::KEY, {
+        let matches = matches.clone();
+        move |event| {
+            let Event::Key(key) = event else {
+                return;
+            };
+            match list_key(&key) {
+                Some(ListKey::Move(delta)) => cursor.set(step(cursor.get(), delta, matches.len())),
+                Some(ListKey::Query(c)) => {
+                    edit_query(query, c);
+                    cursor.set(0);
+                }
+                Some(ListKey::Submit | ListKey::Tab) => {
+                    if let Some(&index) = matches.get(cursor.get()) {
+                        done.set(Some(Some(index)));
+                    }
+                }
+                Some(ListKey::Cancel) => done.set(Some(None)),
+                None => {}
+            }
+        }
+    });
+
+    let rows = match done.get() {
+        Some(answer) => {
+            match answer {
+                Some(index) => system.exit_with(index),
+                None => system.exit(),
+            }
+            let answer = answer.map(|index| props.items[index].as_str());
+            vec![summary_line(&props.prompt, answer)]
+        }
+        None => {
+            let mut rows = vec![prompt_line(&props.prompt, query.read().clone())];
+            rows.extend(list_lines(&props.items, &matches, cursor.get()));
+            rows.push(hint_line("↑↓ 移动  输入筛选  Enter 确认  Esc 取消"));
+            rows
+        }
+    };
+    hooks.use_inline_height(rows.len() as u16);
+    lines(rows)
+}
+
+#[derive(Default, Props)]
+struct ConfirmPromptProps {
+    message: String,
+}
+
+#[component]
+fn ConfirmPrompt(props: &ConfirmPromptProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let mut system = hooks.use_context_mut::<SystemContext>();
+    let mut done = hooks.use_state(|| None::<bool>);
+
+    hooks.use_events_of(EventInterest::KEY, move |event| {
+        let Event::Key(key) = event else {
+            return;
+        };
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+        match key.code {
+            KeyCode::Char('y' | 'Y') | KeyCode::Enter => done.set(Some(true)),
+            KeyCode::Char('n' | 'N') | KeyCode::Esc => done.set(Some(false)),
+            _ => {}
+        }
+    });
+
+    let row = match done.get() {
+        Some(answer) => {
+            system.exit_with(answer);
+            summary_line(&props.message, Some(if answer { "是" } else { "否" }))
+        }
+        None => prompt_line(&props.message, "(Y/n)"),
+    };
+    lines(vec![row])
+}
+
+#[derive(Default, Props)]
+struct InputPromptProps {
+    prompt: String,
+}
+
+#[component]
+fn InputPrompt(props: &InputPromptProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let mut system = hooks.use_context_mut::<SystemContext>();
+    let value = hooks.use_state(String::new);
+    let mut done = hooks.use_state(|| None::<bool>);
+
+    hooks.use_events_of(EventInterest::KEY, move |event| {
+        let Event::Key(key) = event else {
+            return;
+        };
+        if key.kind == KeyEventKind::Release {
+            return;
+        }
+        match key.code {
+            KeyCode::Enter => done.set(Some(true)),
+            KeyCode::Esc => done.set(Some(false)),
+            KeyCode::Backspace => edit_query(value, None),
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                edit_query(value, Some(c))
+            }
+            _ => {}
+        }
+    });
+
+    let rows = match done.get() {
+        Some(submitted) => {
+            let value = value.read().clone();
+            let summary = summary_line(&props.prompt, submitted.then_some(value.as_str()));
+            if submitted {
+                system.exit_with(value);
+            } else {
+                system.exit();
+            }
+            vec![summary]
+        }
+        None => vec![
+            prompt_line(&props.prompt, format!("{}█", *value.read())),
+            hint_line("Enter 确认  Esc 取消"),
+        ],
+    };
+    hooks.use_inline_height(rows.len() as u16);
+    lines(rows)
+}
+
+#[derive(Default, Props)]
+struct PathPromptProps {
+    start: PathBuf,
+}
+
+// 目录中的一项，目录名以 `/` 结尾显示
+#[derive(Clone)]
+struct PathEntry {
+    name: String,
+    is_dir: bool,
+}
+
+// 读取目录内容，目录在前并按名称排序，无法读取时为空
+fn read_entries(dir: &Path) -> Vec<PathEntry> {
+    let mut entries = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| PathEntry {
+                    name: entry.file_name().to_string_lossy().into_owned(),
+                    is_dir: entry.path().is_dir(),
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    entries.sort_by(|a, b| b.is_dir.cmp(&a.is_dir).then_with(|| a.name.cmp(&b.name)));
+    entries
+}
+
+#[component]
+fn PathPrompt(props: &PathPromptProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+    let mut system = hooks.use_context_mut::<SystemContext>();
+    let start = props.start.clone();
+    let mut dir = hooks.use_state(move || start);
+    let mut query = hooks.use_state(String::new);
+    let mut cursor = hooks.use_state(|| 0usize);
+    let mut done = hooks.use_state(|| None::<Option<PathBuf>>);
+
+    let current = dir.read().clone();
+    let entries = hooks.use_memo(|| Arc::new(read_entries(&current)), &current);
+    let labels = entries
+        .iter()
+        .map(|entry| {
+            if entry.is_dir {
+                format!("{}/", entry.name)
+            } else {
+                entry.name.clone()
+            }
+        })
+        .collect::<Vec<_>>();
+    let matches = filter(&labels, &query.read());
+
+    hooks.use_events_of(EventInterest::KEY, {
+        let matches = matches.clone();
+        let entries = entries.clone();
+        move |event| {
+            let Event::Key(key) = event else {
+                return;
+            };
+            let selected = matches.get(cursor.get()).map(|&index| &entries[index]);
+            match list_key(&key) {
+                Some(ListKey::Move(delta)) => cursor.set(step(cursor.get(), delta, matches.len())),
+                Some(ListKey::Query(None)) if query.read().is_empty() => {
+                    let parent = dir.read().parent().map(Path::to_path_buf);
+                    if let Some(parent) = parent {
+                        dir.set(parent);
+                        cursor.set(0);
+                    }
+                }
+                Some(ListKey::Query(c)) => {
+                    edit_query(query, c);
+                    cursor.set(0);
+                }
+                Some(ListKey::Submit) => {
+                    let Some(entry) = selected else {
+                        return;
+                    };
+                    let path = dir.read().join(&entry.name);
+                    if entry.is_dir {
+                        dir.set(path);
+                        query.set(String::new());
+                        cursor.set(0);
+                    } else {
+                        done.set(Some(Some(path)));
+                    }
+                }
+                Some(ListKey::Tab) => {
+                    if let Some(entry) = selected {
+                        let path = dir.read().join(&entry.name);
+                        done.set(Some(Some(path)));
+                    }
+                }
+                Some(ListKey::Cancel) => done.set(Some(None)),
+                None => {}
+            }
+        }
+    });
+
+    let answer = done.read().clone();
+    let rows = match answer {
+        Some(answer) => {
+            let summary = answer.as_ref().map(|path| path.display().to_string());
+            match answer {
+                Some(path) => system.exit_with(path),
+                None => system.exit(),
+            }
+            vec![summary_line("选择路径", summary.as_deref())]
+        }
+        None => {
+            let mut rows = vec![prompt_line(
+                &current.display().to_string(),
+                query.read().clone(),
+            )];
+            rows.extend(list_lines(&labels, &matches, cursor.get()));
+            rows.push(hint_line(
+                "Enter 打开/选择  Tab 选择  Backspace 上级目录  Esc 取消",
+            ));
+            rows
+        }
+    };
+    hooks.use_inline_height(rows.len() as u16);
+    lines(rows)
+}
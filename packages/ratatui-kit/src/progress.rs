@@ -0,0 +1,372 @@
+//! 多任务进度：包装耗时的命令行操作，在内联视口中显示每个任务的进度条。
+//!
+//! ## 用法示例
+//! ```rust
+//! use ratatui_kit::progress::{self, ProgressTask};
+//!
+//! let tasks = urls.into_iter().map(|url| {
+//!     ProgressTask::new(url.clone(), move |handle| async move {
+//!         let mut response = client.get(&url).send().await?;
+//!         handle.set_total(response.content_length().unwrap_or(0));
+//!         while let Some(chunk) = response.chunk().await? {
+//!             handle.inc(chunk.len() as u64);
+//!         }
+//!         handle.set_message("完成");
+//!         Ok::<_, reqwest::Error>(())
+//!     })
+//! });
+//! let results = progress::run(tasks).await?;
+//! ```
+//! - 所有任务并发执行，`run` 按传入顺序返回各任务的结果；
+//! - 视口只显示进行中的任务，任务结束后收起为一行摘要，通过 insert_before 输出到视口上方；
+//! - [`ProgressHandle::println`] 在视口上方输出日志，不会打乱进度条；
+//! - `Ctrl+C` 时取消全部任务并返回 [`io::ErrorKind::Interrupted`] 错误。
+
+use std::{
+    io,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+    time::Instant,
+};
+
+use futures::{
+    FutureExt,
+    future::{BoxFuture, join_all},
+};
+use ratatui::{
+    TerminalOptions, Viewport,
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::Widget,
+};
+use ratatui_kit_macros::{Props, element};
+
+use crate::{Component, ElementExt, SystemContext, text};
+
+// 进度条的宽度
+const BAR_WIDTH: usize = 20;
+
+type StartFn<T> = Box<dyn FnOnce(ProgressHandle) -> BoxFuture<'static, T> + Send>;
+
+/// 带进度的任务。
+pub struct ProgressTask<T> {
+    label: String,
+    start: StartFn<T>,
+}
+
+impl<T: Send + 'static> ProgressTask<T> {
+    /// 创建任务，`f` 通过传入的 [`ProgressHandle`] 报告进度。
+    pub fn new<F, Fut>(label: impl Into<String>, f: F) -> Self
+    where
+        F: FnOnce(ProgressHandle) -> Fut + Send + 'static,
+        Fut: Future<Output = T> + Send + 'static,
+    {
+        Self {
+            label: label.into(),
+            start: Box::new(move |handle| f(handle).boxed()),
+        }
+    }
+}
+
+/// 并发执行全部任务并显示进度，全部完成后按传入顺序返回结果。
+pub async fn run<T: Send + 'static>(
+    tasks: impl IntoIterator<Item = ProgressTask<T>>,
+) -> io::Result<Vec<T>> {
+    let tasks = tasks.into_iter().collect::<Vec<_>>();
+    if tasks.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let shared = Arc::new(Shared::default());
+    shared.update(|inner| {
+        inner.tasks = tasks
+            .iter()
+            .map(|task| TaskInfo::new(task.label.clone()))
+            .collect();
+    });
+    let results = Arc::new(Mutex::new(Vec::new()));
+    let futures = tasks.into_iter().enumerate().map(|(index, task)| {
+        let handle = ProgressHandle {
+            shared: shared.clone(),
+            index,
+        };
+        let shared = shared.clone();
+        async move {
+            let result = (task.start)(handle).await;
+            shared.update(|inner| inner.tasks[index].finished_at = Some(Instant::now()));
+            result
+        }
+    });
+    let future = {
+        let results = results.clone();
+        join_all(futures).map(move |values| *results.lock().unwrap() = values)
+    };
+
+    let height = shared.inner.lock().unwrap().tasks.len() as u16;
+    let exit = element!(ProgressView(
+        shared: Some(shared),
+        future: Mutex::new(Some(future.boxed())),
+    ))
+    .render_loop(TerminalOptions {
+        viewport: Viewport::Inline(height),
+    })
+    .await?;
+    if exit.is_interrupted() {
+        return Err(io::Error::new(
+            io::ErrorKind::Interrupted,
+            "progress interrupted",
+        ));
+    }
+    Ok(std::mem::take(&mut *results.lock().unwrap()))
+}
+
+/// 任务内报告进度的句柄，可克隆后传给子任务。
+#[derive(Clone)]
+pub struct ProgressHandle {
+    shared: Arc<Shared>,
+    index: usize,
+}
+
+impl ProgressHandle {
+    /// 设置总量，为 0 时表示总量未知，只显示当前数量。
+    pub fn set_total(&self, total: u64) {
+        self.update(|task| task.total = total);
+    }
+
+    /// 设置当前数量。
+    pub fn set_position(&self, position: u64) {
+        self.update(|task| task.position = position);
+    }
+
+    /// 增加当前数量。
+    pub fn inc(&self, delta: u64) {
+        self.update(|task| task.position = task.position.saturating_add(delta));
+    }
+
+    /// 设置显示在进度条后的状态文本，任务结束后保留在摘要中。
+    pub fn set_message(&self, message: impl Into<String>) {
+        let message = message.into();
+        self.update(|task| task.message = message);
+    }
+
+    /// 在视口上方输出一行日志。
+    pub fn println(&self, line: impl Into<String>) {
+        let line = line.into();
+        self.shared.update(|inner| inner.logs.push(line));
+    }
+
+    fn update(&self, f: impl FnOnce(&mut TaskInfo)) {
+        self.shared.update(|inner| f(&mut inner.tasks[self.index]));
+    }
+}
+
+struct TaskInfo {
+    label: String,
+    position: u64,
+    total: u64,
+    message: String,
+    started_at: Instant,
+    finished_at: Option<Instant>,
+    // 摘要是否已输出到视口上方
+    reported: bool,
+}
+
+impl TaskInfo {
+    fn new(label: String) -> Self {
+        Self {
+            label,
+            position: 0,
+            total: 0,
+            message: String::new(),
+            started_at: Instant::now(),
+            finished_at: None,
+            reported: false,
+        }
+    }
+
+    fn row(&self, label_width: usize) -> Line<'static> {
+        let label = text::fit(&self.label, label_width, Alignment::Left);
+        let mut spans = vec![Span::raw(format!("{label} "))];
+        if self.total > 0 {
+            let progress = (self.position as f64 / self.total as f64).clamp(0.0, 1.0);
+            let filled = (BAR_WIDTH as f64 * progress).round() as usize;
+            spans.push(Span::styled("━".repeat(filled), Style::default().green()));
+            spans.push(Span::styled(
+                "━".repeat(BAR_WIDTH - filled),
+                Style::default().dark_gray(),
+            ));
+            spans.push(Span::raw(format!(
+                " {:>3.0}% {}/{}",
+                progress * 100.0,
+                self.position,
+                self.total
+            )));
+        } else if self.position > 0 {
+            spans.push(Span::raw(self.position.to_string()));
+        }
+        if !self.message.is_empty() {
+            spans.push(Span::styled(
+                format!("  {}", self.message),
+                Style::default().dark_gray(),
+            ));
+        }
+        Line::from(spans)
+    }
+
+    fn summary(&self) -> Line<'static> {
+        let elapsed = self
+            .finished_at
+            .map(|finished| finished - self.started_at)
+            .unwrap_or_default();
+        let mut spans = vec![
+            Span::styled("✔ ", Style::default().green().bold()),
+            Span::raw(self.label.clone()),
+        ];
+        if !self.message.is_empty() {
+            spans.push(Span::styled(
+                format!("  {}", self.message),
+                Style::default().dark_gray(),
+            ));
+        }
+        spans.push(Span::styled(
+            format!(" ({:.1}s)", elapsed.as_secs_f64()),
+            Style::default().dark_gray(),
+        ));
+        Line::from(spans)
+    }
+}
+
+#[derive(Default)]
+struct Inner {
+    tasks: Vec<TaskInfo>,
+    // 等待输出到视口上方的日志
+    logs: Vec<String>,
+    changed: bool,
+}
+
+#[derive(Default)]
+struct Shared {
+    inner: Mutex<Inner>,
+    waker: Mutex<Option<Waker>>,
+}
+
+impl Shared {
+    fn update(&self, f: impl FnOnce(&mut Inner)) {
+        let mut inner = self.inner.lock().unwrap();
+        f(&mut inner);
+        inner.changed = true;
+        if let Some(waker) = self.waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+}
+
+#[derive(Default, Props)]
+struct ProgressViewProps {
+    shared: Option<Arc<Shared>>,
+    // BoxFuture 不是 Sync，用 Mutex 包裹以满足 Props 的要求
+    future: Mutex<Option<BoxFuture<'static, ()>>>,
+}
+
+// 显示进行中的任务，全部结束后显示总用时并退出
+struct ProgressView {
+    shared: Arc<Shared>,
+    // 组件需要满足 Sync，同样用 Mutex 包裹
+    future: Mutex<Option<BoxFuture<'static, ()>>>,
+    started_at: Instant,
+    rows: Vec<Line<'static>>,
+}
+
+impl Component for ProgressView {
+    type Props<'a> = ProgressViewProps;
+
+    fn new(props: &Self::Props<'_>) -> Self {
+        Self {
+            shared: props.shared.clone().unwrap_or_default(),
+            future: Mutex::new(props.future.lock().unwrap().take()),
+            started_at: Instant::now(),
+            rows: Vec::new(),
+        }
+    }
+
+    fn update(
+        &mut self,
+        _props: &mut Self::Props<'_>,
+        _hooks: crate::Hooks,
+        updater: &mut crate::ComponentUpdater,
+    ) {
+        let mut inner = self.shared.inner.lock().unwrap();
+        let mut output = inner.logs.drain(..).map(Line::raw).collect::<Vec<_>>();
+        for task in inner.tasks.iter_mut() {
+            if task.finished_at.is_some() && !task.reported {
+                task.reported = true;
+                output.push(task.summary());
+            }
+        }
+        if !output.is_empty() {
+            let _ = updater.with_terminal(|terminal| {
+                terminal.insert_before(output.len() as u16, move |buf| {
+                    for (line, row) in output.into_iter().zip(buf.area.rows()) {
+                        line.render(row, buf);
+                    }
+                })
+            });
+        }
+
+        if self.future.get_mut().unwrap().is_none() {
+            let total = inner.tasks.len();
+            self.rows = vec![Line::from(vec![
+                Span::styled("✔ ", Style::default().green().bold()),
+                Span::raw(format!("{total} 个任务已完成")),
+                Span::styled(
+                    format!(" ({:.1}s)", self.started_at.elapsed().as_secs_f64()),
+                    Style::default().dark_gray(),
+                ),
+            ])];
+            if let Some(mut system) = updater.get_context_mut::<SystemContext>() {
+                system.exit();
+            }
+        } else {
+            let active = inner
+                .tasks
+                .iter()
+                .filter(|task| task.finished_at.is_none())
+                .collect::<Vec<_>>();
+            let label_width = active
+                .iter()
+                .map(|task| text::width(&task.label))
+                .max()
+                .unwrap_or(0);
+            self.rows = active.iter().map(|task| task.row(label_width)).collect();
+        }
+        let height = self.rows.len().max(1) as u16;
+        updater.with_terminal(|terminal| terminal.request_inline_height(height));
+    }
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        for (line, row) in self.rows.iter().zip(area.rows()) {
+            line.render(row, buf);
+        }
+    }
+
+    fn poll_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let this = self.get_mut();
+        *this.shared.waker.lock().unwrap() = Some(cx.waker().clone());
+        let future = this.future.get_mut().unwrap();
+        if let Some(task) = future.as_mut() {
+            if task.as_mut().poll(cx).is_ready() {
+                *future = None;
+                return Poll::Ready(());
+            }
+        }
+        let mut inner = this.shared.inner.lock().unwrap();
+        if std::mem::take(&mut inner.changed) {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
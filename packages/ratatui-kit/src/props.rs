@@ -7,7 +7,39 @@ use ratatui_kit_macros::Props;
 ///
 /// # Safety
 /// 实现者需保证类型安全和线程安全。
-pub unsafe trait Props: Send + Sync {}
+pub unsafe trait Props: Send + Sync {
+    /// 实现了 `Debug` 的字段名及其调试输出，供检查器等工具使用，`#[derive(Props)]` 会自动实现。
+    fn debug_fields(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+}
+
+// 以下供 `#[derive(Props)]` 生成的代码使用：借助自动引用的方法查找顺序，
+// 字段实现了 Debug 时得到调试输出，否则得到 None
+#[doc(hidden)]
+pub struct PropDebug<'a, T: ?Sized>(pub &'a T);
+
+#[doc(hidden)]
+pub trait PropDebugSome {
+    fn prop_debug(&self) -> Option<String>;
+}
+
+impl<T: std::fmt::Debug + ?Sized> PropDebugSome for &PropDebug<'_, T> {
+    fn prop_debug(&self) -> Option<String> {
+        Some(format!("{:?}", self.0))
+    }
+}
+
+#[doc(hidden)]
+pub trait PropDebugNone {
+    fn prop_debug(&self) -> Option<String>;
+}
+
+impl<T: ?Sized> PropDebugNone for PropDebug<'_, T> {
+    fn prop_debug(&self) -> Option<String> {
+        None
+    }
+}
 
 // 用于处理原始指针释放的trait
 // 通过类型擦除实现对未知类型的内存释放
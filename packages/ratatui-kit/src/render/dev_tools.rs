@@ -0,0 +1,345 @@
+//! 调试命令注册表：把手动调试时常用的操作登记为命名命令，供命令行、命令面板或检查器调用。
+//!
+//! 每棵组件树有一个注册表，通过 `hooks.use_dev_tools()` 获取，内置以下命令：
+//! - `redraw`：清空屏幕并完整重绘一帧，排查残留字符等绘制问题；
+//! - `dump-tree [path]`：把组件树结构导出到文件，开启 `json` 特性时为 JSON，否则为缩进文本；
+//! - `reset-store <name>`：把通过 [`DevTools::register_store`] 登记的 store 恢复为初始值；
+//! - `resize [width height]`：把组件树的绘制区域限制为指定尺寸并发送 `Resize` 事件，
+//!   用于检查小尺寸终端下的布局，不带参数时恢复实际尺寸。
+//!
+//! 组件可以通过 `hooks.use_dev_command` 登记自己的调试命令，在 CommandLine 中调用
+//! `hooks.use_dev_commands()` 后即可用 `:dev <command>` 执行。
+//!
+//! ## 用法示例
+//! ```rust
+//! let dev_tools = hooks.use_dev_tools();
+//! dev_tools.run("dump-tree tree.json")?;
+//! dev_tools.run("resize 60 20")?;
+//! ```
+
+use std::{
+    fs, io,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    task::Waker,
+};
+
+use crossterm::event::Event;
+use ratatui::layout::{Rect, Size};
+
+use crate::{Command, CommandArgs, InstantiatedComponent, Terminal, TerminalCommand};
+
+type DevHandler = Box<dyn FnMut(CommandArgs) -> Result<(), String> + Send>;
+
+struct Registered {
+    id: u64,
+    command: Command,
+    handler: Arc<Mutex<DevHandler>>,
+}
+
+#[derive(Default)]
+struct DevToolsShared {
+    commands: Vec<Registered>,
+    next_id: u64,
+    #[cfg(feature = "store")]
+    stores: Vec<(String, Box<dyn FnMut() + Send>)>,
+    redraw: bool,
+    dumps: Vec<PathBuf>,
+    // 等待应用的模拟尺寸，Some(None) 表示恢复实际尺寸
+    resize: Option<Option<Size>>,
+    viewport: Option<Size>,
+    // 最近一次在组件树更新后执行的操作结果及是否为错误
+    report: Option<(String, bool)>,
+    waker: Option<Waker>,
+}
+
+impl DevToolsShared {
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// 组件树的调试命令注册表，可跨线程克隆。
+#[derive(Clone, Default)]
+pub struct DevTools {
+    shared: Arc<Mutex<DevToolsShared>>,
+}
+
+impl DevTools {
+    /// 所有可用的调试命令，内置命令在前。
+    pub fn commands(&self) -> Vec<Command> {
+        let shared = self.shared.lock().unwrap();
+        builtin_commands(self)
+            .into_iter()
+            .chain(shared.commands.iter().map(|r| r.command.clone()))
+            .collect()
+    }
+
+    /// 执行调试命令，如 `resize 60 20`，未知命令或参数错误时返回错误信息。
+    ///
+    /// 需要访问组件树或终端的命令（重绘、导出、调整尺寸）在下一次更新后执行，
+    /// 结果可通过 [`DevTools::take_report`] 读取。
+    pub fn run(&self, input: &str) -> Result<(), String> {
+        match CommandArgs::parse(input) {
+            Some(args) => self.execute(args),
+            None => Ok(()),
+        }
+    }
+
+    /// 执行已解析的调试命令。
+    pub(crate) fn execute(&self, args: CommandArgs) -> Result<(), String> {
+        match args.name.as_str() {
+            "redraw" => {
+                self.redraw();
+                Ok(())
+            }
+            "dump-tree" => {
+                self.dump_tree(args.get(0).unwrap_or(DEFAULT_DUMP_PATH));
+                Ok(())
+            }
+            "reset-store" => self.reset_store(args.get(0).ok_or("缺少 store 名称")?),
+            "resize" => match (args.get(0), args.get(1)) {
+                (None, _) => {
+                    self.resize(None);
+                    Ok(())
+                }
+                (Some(width), Some(height)) => {
+                    let width = width.parse().map_err(|_| format!("无效的宽度：{width}"))?;
+                    let height = height
+                        .parse()
+                        .map_err(|_| format!("无效的高度：{height}"))?;
+                    self.resize(Some(Size::new(width, height)));
+                    Ok(())
+                }
+                (Some(_), None) => Err("用法：resize [width height]".to_string()),
+            },
+            name => {
+                let handler = {
+                    let shared = self.shared.lock().unwrap();
+                    shared
+                        .commands
+                        .iter()
+                        .find(|r| r.command.matches(name))
+                        .map(|r| r.handler.clone())
+                };
+                // 不持有锁调用处理函数，处理函数中可以再次使用注册表
+                match handler {
+                    Some(handler) => (handler.lock().unwrap())(args),
+                    None => Err(format!("未知调试命令：{name}")),
+                }
+            }
+        }
+    }
+
+    /// 清空屏幕并在下一帧完整重绘。
+    pub fn redraw(&self) {
+        let mut shared = self.shared.lock().unwrap();
+        shared.redraw = true;
+        shared.wake();
+    }
+
+    /// 在下一次更新后把组件树结构导出到 `path`。
+    pub fn dump_tree(&self, path: impl Into<PathBuf>) {
+        let mut shared = self.shared.lock().unwrap();
+        shared.dumps.push(path.into());
+        shared.wake();
+    }
+
+    /// 模拟终端尺寸：组件树只绘制在左上角 `size` 大小的区域内，并收到对应的 `Resize` 事件；
+    /// None 恢复实际尺寸。
+    pub fn resize(&self, size: Option<Size>) {
+        let mut shared = self.shared.lock().unwrap();
+        shared.resize = Some(size);
+        shared.wake();
+    }
+
+    /// 当前模拟的终端尺寸。
+    pub fn viewport(&self) -> Option<Size> {
+        self.shared.lock().unwrap().viewport
+    }
+
+    /// 登记 store，之后可通过 `reset-store <name>` 恢复为 `init` 返回的值，同名时替换。
+    ///
+    /// # 示例
+    /// ```rust
+    /// static TODOS: LazyLock<StoreState<Vec<Todo>>> = LazyLock::new(|| StoreState::new(Vec::new()));
+    /// dev_tools.register_store("todos", *TODOS, Vec::new);
+    /// ```
+    #[cfg(feature = "store")]
+    pub fn register_store<T, F>(
+        &self,
+        name: impl Into<String>,
+        mut store: crate::StoreState<T>,
+        init: F,
+    ) where
+        T: Send + Sync + 'static,
+        F: Fn() -> T + Send + 'static,
+    {
+        let name = name.into();
+        let mut shared = self.shared.lock().unwrap();
+        shared.stores.retain(|(registered, _)| *registered != name);
+        shared
+            .stores
+            .push((name, Box::new(move || store.set(init()))));
+    }
+
+    /// 把登记的 store 恢复为初始值，会像修改 store 一样触发重新渲染。
+    pub fn reset_store(&self, name: &str) -> Result<(), String> {
+        #[cfg(feature = "store")]
+        {
+            let mut shared = self.shared.lock().unwrap();
+            if let Some((_, reset)) = shared.stores.iter_mut().find(|(store, _)| store == name) {
+                reset();
+                return Ok(());
+            }
+        }
+        Err(format!("未登记的 store：{name}"))
+    }
+
+    /// 取出最近一次延迟执行的命令的结果及是否为错误。
+    pub fn take_report(&self) -> Option<(String, bool)> {
+        self.shared.lock().unwrap().report.take()
+    }
+
+    // 登记的 store 名称，用于补全
+    fn store_names(&self) -> Vec<String> {
+        #[cfg(feature = "store")]
+        {
+            let shared = self.shared.lock().unwrap();
+            shared.stores.iter().map(|(name, _)| name.clone()).collect()
+        }
+        #[cfg(not(feature = "store"))]
+        Vec::new()
+    }
+
+    pub(crate) fn register(&self, command: Command, handler: DevHandler) -> u64 {
+        let mut shared = self.shared.lock().unwrap();
+        let id = shared.next_id;
+        shared.next_id += 1;
+        shared.commands.push(Registered {
+            id,
+            command,
+            handler: Arc::new(Mutex::new(handler)),
+        });
+        id
+    }
+
+    pub(crate) fn update(&self, id: u64, command: Command, handler: DevHandler) {
+        let mut shared = self.shared.lock().unwrap();
+        if let Some(registered) = shared.commands.iter_mut().find(|r| r.id == id) {
+            registered.command = command;
+            *registered.handler.lock().unwrap() = handler;
+        }
+    }
+
+    pub(crate) fn unregister(&self, id: u64) {
+        self.shared
+            .lock()
+            .unwrap()
+            .commands
+            .retain(|registered| registered.id != id);
+    }
+
+    /// 有等待执行的操作时返回 true，否则登记唤醒器。
+    pub(crate) fn poll_pending(&self, waker: &Waker) -> bool {
+        let mut shared = self.shared.lock().unwrap();
+        let pending = shared.redraw || !shared.dumps.is_empty() || shared.resize.is_some();
+        if !pending {
+            shared.waker = Some(waker.clone());
+        }
+        pending
+    }
+
+    /// 组件树更新后执行等待中的操作。
+    pub(crate) fn apply(&self, terminal: &mut Terminal, root: &InstantiatedComponent) {
+        let mut shared = self.shared.lock().unwrap();
+        if shared.redraw {
+            shared.redraw = false;
+            let _ = terminal.control(TerminalCommand::Redraw);
+        }
+        if let Some(size) = shared.resize.take() {
+            shared.viewport = size;
+            let size = size.or_else(|| terminal.size().ok());
+            if let Some(size) = size {
+                terminal.dispatch(Event::Resize(size.width, size.height));
+            }
+        }
+        for path in std::mem::take(&mut shared.dumps) {
+            shared.report = Some(match write_dump(&path, root) {
+                Ok(()) => (format!("组件树已导出到 {}", path.display()), false),
+                Err(err) => (format!("导出组件树失败：{err}"), true),
+            });
+            shared.wake();
+        }
+    }
+
+    /// 按模拟尺寸裁剪绘制区域。
+    pub(crate) fn clip(&self, area: Rect) -> Rect {
+        match self.viewport() {
+            Some(size) => Rect::new(area.x, area.y, size.width, size.height).intersection(area),
+            None => area,
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+const DEFAULT_DUMP_PATH: &str = "ratatui-kit-tree.json";
+#[cfg(not(feature = "json"))]
+const DEFAULT_DUMP_PATH: &str = "ratatui-kit-tree.txt";
+
+fn builtin_commands(dev_tools: &DevTools) -> Vec<Command> {
+    let stores = dev_tools.clone();
+    vec![
+        Command::new("redraw", "清空屏幕并完整重绘"),
+        Command::new("dump-tree", "把组件树结构导出到文件")
+            .usage("[path]")
+            .complete_with(crate::complete_path),
+        Command::new("reset-store", "把 store 恢复为初始值")
+            .usage("<name>")
+            .complete_with(move |prefix, _| {
+                stores
+                    .store_names()
+                    .into_iter()
+                    .filter(|name| name.starts_with(prefix))
+                    .collect()
+            }),
+        Command::new("resize", "模拟终端尺寸，不带参数时恢复").usage("[width height]"),
+    ]
+}
+
+fn write_dump(path: &PathBuf, root: &InstantiatedComponent) -> io::Result<()> {
+    #[cfg(feature = "json")]
+    let dump = serde_json::to_string_pretty(&root.to_json()).map_err(io::Error::other)?;
+    #[cfg(not(feature = "json"))]
+    let dump = {
+        let mut dump = String::new();
+        outline(root, 0, &mut dump);
+        dump
+    };
+    fs::write(path, dump)
+}
+
+// 缩进文本格式：每行一个组件，包含类型名、键和最近一次绘制的区域
+#[cfg(not(feature = "json"))]
+fn outline(component: &InstantiatedComponent, depth: usize, out: &mut String) {
+    use std::fmt::Write;
+
+    let area = component.area();
+    let _ = writeln!(
+        out,
+        "{:indent$}{} [{}] {}x{} @ {},{}",
+        "",
+        component.name(),
+        component.key().label(),
+        area.width,
+        area.height,
+        area.x,
+        area.y,
+        indent = depth * 2,
+    );
+    for child in component.children() {
+        outline(child, depth + 1, out);
+    }
+}
@@ -8,6 +8,8 @@ pub struct ComponentDrawer<'a, 'b: 'a> {
     pub area: ratatui::layout::Rect,
     pub frame: &'a mut ratatui::Frame<'b>,
     pub scroll_buffer: Option<Buffer>,
+    // 由 calc_children_areas 设置：缓冲区保留了上一帧的内容，未变化的子组件可以跳过绘制
+    pub(crate) retain_children: bool,
 }
 
 impl<'a, 'b> ComponentDrawer<'a, 'b> {
@@ -16,6 +18,7 @@ impl<'a, 'b> ComponentDrawer<'a, 'b> {
             area,
             frame,
             scroll_buffer: None,
+            retain_children: false,
         }
     }
 
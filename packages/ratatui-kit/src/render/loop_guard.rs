@@ -0,0 +1,101 @@
+//! 渲染循环检测：组件在渲染期间修改状态会立即触发下一次渲染，如果每次渲染都修改，
+//! 组件树就会不停地重新渲染、占满 CPU 却没有任何提示。
+//!
+//! 连续多次更新都在渲染期间修改了状态时直接 panic，并列出在渲染期间修改状态的组件。
+
+use std::cell::{Cell, RefCell};
+
+// 允许连续在渲染期间修改状态的更新次数
+const MAX_CONSECUTIVE_UPDATES: usize = 50;
+
+thread_local! {
+    // 正在执行更新的组件，None 表示不在更新阶段
+    static UPDATING: Cell<Option<&'static str>> = const { Cell::new(None) };
+    // 本次更新中在渲染期间修改了状态的组件
+    static WRITERS: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+/// 以组件 `component` 的名义执行更新，期间的状态修改计入该组件。可以嵌套，内层组件优先。
+pub(crate) fn updating<R>(component: &'static str, f: impl FnOnce() -> R) -> R {
+    let previous = UPDATING.with(|updating| updating.replace(Some(component)));
+    // 更新 panic 时也要恢复
+    let _guard = UpdatingGuard(previous);
+    f()
+}
+
+struct UpdatingGuard(Option<&'static str>);
+
+impl Drop for UpdatingGuard {
+    fn drop(&mut self) {
+        UPDATING.with(|updating| updating.set(self.0));
+    }
+}
+
+/// 记录一次状态修改，只在组件更新期间生效。
+pub(crate) fn record_write() {
+    let Some(component) = UPDATING.with(Cell::get) else {
+        return;
+    };
+    push_writer(component);
+}
+
+fn push_writer(component: &'static str) {
+    WRITERS.with(|writers| {
+        let mut writers = writers.borrow_mut();
+        if !writers.contains(&component) {
+            writers.push(component);
+        }
+    });
+}
+
+/// 替换当前线程记录的修改者并返回原有记录，并行更新在工作线程中以此隔离记录。
+#[cfg(feature = "parallel")]
+pub(crate) fn replace_writers(writers: Vec<&'static str>) -> Vec<&'static str> {
+    WRITERS.with(|current| std::mem::replace(&mut *current.borrow_mut(), writers))
+}
+
+/// 并入其他线程中记录的修改者。
+#[cfg(feature = "parallel")]
+pub(crate) fn extend_writers(writers: Vec<&'static str>) {
+    writers.into_iter().for_each(push_writer);
+}
+
+/// 每棵组件树一个，在每次更新后检查。
+#[derive(Default)]
+pub(crate) struct RenderLoopGuard {
+    consecutive: usize,
+    writers: Vec<&'static str>,
+}
+
+impl RenderLoopGuard {
+    /// 检查刚结束的更新，连续超过上限的更新都在渲染期间修改了状态时 panic。
+    pub(crate) fn check(&mut self) {
+        let writers = WRITERS.with(|writers| std::mem::take(&mut *writers.borrow_mut()));
+        if writers.is_empty() {
+            self.consecutive = 0;
+            self.writers.clear();
+            return;
+        }
+        self.consecutive += 1;
+        for writer in writers {
+            if !self.writers.contains(&writer) {
+                self.writers.push(writer);
+            }
+        }
+        if self.consecutive > MAX_CONSECUTIVE_UPDATES {
+            let writers = self
+                .writers
+                .iter()
+                .map(|writer| format!("`{writer}`"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            panic!(
+                "Render loop detected: state was modified during render in {} consecutive updates, \
+                 so the tree never settles. Components modifying state while rendering: {writers}. \
+                 Move the state change into an event handler, `use_effect` or `use_future`, \
+                 or only set it when the value actually changes.",
+                self.consecutive,
+            );
+        }
+    }
+}
@@ -1,6 +1,23 @@
+mod dev_tools;
 mod drawer;
 mod updater;
+pub use dev_tools::DevTools;
 pub use drawer::ComponentDrawer;
 pub use updater::ComponentUpdater;
+pub(crate) use updater::TerminalAccess;
+#[cfg(feature = "parallel")]
+pub use updater::TerminalGuard;
 pub mod layout_style;
+pub(crate) mod loop_guard;
+pub(crate) mod scheduler;
+#[cfg(feature = "parallel")]
+mod thread_state;
+pub use scheduler::{UpdatePriority, current_priority, with_priority};
+#[cfg(feature = "persist")]
+mod snapshot;
 pub mod tree;
+#[cfg(feature = "persist")]
+pub use snapshot::TreeSnapshot;
+pub(crate) mod why_render;
+pub use tree::{App, Roots};
+pub use why_render::RenderCause;
@@ -0,0 +1,219 @@
+//! 渲染调度：按唤醒来源给状态更新分级，输入优先于动画，动画优先于后台数据。
+//!
+//! 每一级有各自的帧预算（距上一帧的最短间隔），渲染循环按待处理更新中最高的优先级等待，
+//! 等待期间出现更高优先级的更新时立即重绘。标记为后台的更新（如日志刷屏）因此被合并成较低帧率，
+//! 不会拖慢按键的响应。
+//!
+//! 待处理的更新按组件树记录：组件树轮询时交给 hook 的 waker 被唤醒时，
+//! 按唤醒线程的当前优先级记入该树，同一进程中的多个组件树互不影响。
+
+use std::{
+    cell::{Cell, RefCell},
+    future::poll_fn,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU8, Ordering},
+    },
+    task::{Poll, Waker},
+    time::Duration,
+};
+
+use futures::task::{ArcWake, waker};
+
+/// 状态更新的优先级。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum UpdatePriority {
+    /// 后台数据：线程推送的日志、网络响应等，需要通过 [`with_priority`] 显式标记。
+    Background,
+    /// 动画、计时器和其他未指定优先级的更新。
+    #[default]
+    Animation,
+    /// 用户输入，事件处理器中的修改属于此级。
+    Input,
+}
+
+impl UpdatePriority {
+    /// 默认的帧预算：输入立即重绘，动画约 60 帧每秒，后台数据约 10 帧每秒。
+    pub fn default_budget(self) -> Duration {
+        match self {
+            UpdatePriority::Input => Duration::ZERO,
+            UpdatePriority::Animation => Duration::from_millis(16),
+            UpdatePriority::Background => Duration::from_millis(100),
+        }
+    }
+
+    fn from_pending(pending: u8) -> Option<Self> {
+        match pending {
+            1 => Some(UpdatePriority::Background),
+            2 => Some(UpdatePriority::Animation),
+            3 => Some(UpdatePriority::Input),
+            _ => None,
+        }
+    }
+
+    fn to_pending(self) -> u8 {
+        self as u8 + 1
+    }
+}
+
+thread_local! {
+    static CURRENT: Cell<UpdatePriority> = const { Cell::new(UpdatePriority::Animation) };
+    // 正在轮询或更新的组件树
+    static TREE: RefCell<Option<Arc<PendingUpdates>>> = const { RefCell::new(None) };
+}
+
+/// 以指定优先级执行闭包，闭包内修改 [`State`](crate::State) 或 store 触发的重绘按该优先级调度。
+///
+/// 事件处理器已自动以 [`UpdatePriority::Input`] 执行；其他场景默认是 [`UpdatePriority::Animation`]，
+/// 大量且不紧急的更新（如后台线程推送的日志）可以降为 [`UpdatePriority::Background`] 以合并成较低帧率。
+/// 作用域可以嵌套，只对当前线程生效。
+///
+/// # 示例
+/// ```rust
+/// std::thread::spawn(move || {
+///     for line in reader.lines() {
+///         with_priority(UpdatePriority::Background, || logs.write().push(line));
+///     }
+/// });
+/// ```
+pub fn with_priority<R>(priority: UpdatePriority, f: impl FnOnce() -> R) -> R {
+    let previous = CURRENT.with(|current| current.replace(priority));
+    // 闭包 panic 时也要恢复
+    let _guard = PriorityGuard(previous);
+    f()
+}
+
+struct PriorityGuard(UpdatePriority);
+
+impl Drop for PriorityGuard {
+    fn drop(&mut self) {
+        CURRENT.with(|current| current.set(self.0));
+    }
+}
+
+/// 当前线程的更新优先级。
+pub fn current_priority() -> UpdatePriority {
+    CURRENT.with(Cell::get)
+}
+
+/// 记录一次待处理的更新到当前组件树，不在组件树轮询或更新期间时忽略。
+pub(crate) fn mark(priority: UpdatePriority) {
+    TREE.with(|tree| {
+        if let Some(tree) = tree.borrow().as_ref() {
+            tree.mark(priority);
+        }
+    });
+}
+
+/// 当前正在轮询或更新的组件树的待处理更新。
+pub(crate) fn current_tree() -> Option<Arc<PendingUpdates>> {
+    TREE.with(|tree| tree.borrow().clone())
+}
+
+/// 一棵组件树的待处理更新：上一帧之后最高的优先级，以及渲染循环等待帧预算时注册的 waker。
+#[derive(Default)]
+pub(crate) struct PendingUpdates {
+    // 0 表示没有
+    priority: AtomicU8,
+    preempt: Mutex<Option<Waker>>,
+}
+
+impl PendingUpdates {
+    /// 记录一次待处理的更新，优先级高于已记录的更新时打断渲染循环的等待。
+    pub(crate) fn mark(&self, priority: UpdatePriority) {
+        let previous = self
+            .priority
+            .fetch_max(priority.to_pending(), Ordering::SeqCst);
+        if previous < priority.to_pending() {
+            if let Some(waker) = self.preempt.lock().unwrap().take() {
+                waker.wake();
+            }
+        }
+    }
+
+    /// 取出并清空待处理更新的最高优先级。
+    pub(crate) fn take(&self) -> Option<UpdatePriority> {
+        UpdatePriority::from_pending(self.priority.swap(0, Ordering::SeqCst))
+    }
+
+    pub(crate) fn get(&self) -> Option<UpdatePriority> {
+        UpdatePriority::from_pending(self.priority.load(Ordering::SeqCst))
+    }
+
+    /// 出现优先级高于 `priority` 的待处理更新时完成。
+    pub(crate) async fn preempted(&self, priority: UpdatePriority) {
+        let is_preempted = || self.get().is_some_and(|pending| pending > priority);
+        poll_fn(|cx| {
+            if is_preempted() {
+                return Poll::Ready(());
+            }
+            *self.preempt.lock().unwrap() = Some(cx.waker().clone());
+            // 注册 waker 前可能已被标记
+            if is_preempted() {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        })
+        .await
+    }
+
+    /// 以此组件树的名义执行 `f`，期间的 [`mark`] 记入该树。
+    pub(crate) fn enter<R>(self: &Arc<Self>, f: impl FnOnce() -> R) -> R {
+        enter_tree(Some(self.clone()), f)
+    }
+
+    /// 包装渲染循环的 waker，被唤醒时按唤醒线程的当前优先级记录一次待处理的更新。
+    pub(crate) fn waker(self: &Arc<Self>, inner: &Waker) -> Waker {
+        waker(Arc::new(TreeWaker {
+            pending: self.clone(),
+            inner: inner.clone(),
+        }))
+    }
+}
+
+/// 在指定组件树的作用域中执行 `f`，`None` 表示不属于任何组件树。
+pub(crate) fn enter_tree<R>(tree: Option<Arc<PendingUpdates>>, f: impl FnOnce() -> R) -> R {
+    // f panic 时也要恢复
+    struct Restore(Option<Arc<PendingUpdates>>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            TREE.with(|tree| *tree.borrow_mut() = self.0.take());
+        }
+    }
+    let _restore = Restore(TREE.with(|current| current.replace(tree)));
+    f()
+}
+
+struct TreeWaker {
+    pending: Arc<PendingUpdates>,
+    inner: Waker,
+}
+
+impl ArcWake for TreeWaker {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.pending.mark(current_priority());
+        arc_self.inner.wake_by_ref();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, task::Waker};
+
+    use super::{PendingUpdates, UpdatePriority, with_priority};
+
+    #[test]
+    fn pending_updates_are_per_tree() {
+        let first = Arc::new(PendingUpdates::default());
+        let second = Arc::new(PendingUpdates::default());
+        let waker = first.waker(Waker::noop());
+        waker.wake_by_ref();
+        with_priority(UpdatePriority::Background, || {
+            second.waker(Waker::noop()).wake()
+        });
+        assert_eq!(first.take(), Some(UpdatePriority::Animation));
+        assert_eq!(second.take(), Some(UpdatePriority::Background));
+        assert_eq!(first.take(), None);
+    }
+}
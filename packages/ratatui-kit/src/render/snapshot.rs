@@ -0,0 +1,189 @@
+//! 组件树状态快照：保存 `use_persisted_state` 创建的状态和指定的 store，下次启动时恢复，
+//! 实现"从上次离开的地方继续"。
+//!
+//! 状态按组件在树中的位置索引：从根组件往下每一层的类型名和指定的键，加上 hook 序号，例如
+//! `/Sidebar/CounterPage[counter]#0`，同类型同键的兄弟组件按出现顺序加上 `~1`、`~2` 区分。
+//! 修改了组件结构或 hook 顺序后，对不上的快照值会被忽略。
+//!
+//! ## 用法示例
+//! ```rust
+//! // 退出前保存
+//! let snapshot = app.tree().snapshot();
+//! std::fs::write("session.json", snapshot.to_json().to_string())?;
+//!
+//! // 启动时恢复，尚未挂载的组件（如未访问的路由页面）在挂载后恢复
+//! let value = serde_json::from_str(&std::fs::read_to_string("session.json")?)?;
+//! if let Some(snapshot) = TreeSnapshot::from_json(value) {
+//!     app.tree().restore(snapshot);
+//! }
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde_json::{Map, Value};
+
+use crate::{InstantiatedComponent, StateSlotKey};
+
+use super::tree::Tree;
+
+/// 组件树状态的快照。
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TreeSnapshot {
+    /// 持久化状态的值，按组件位置和 hook 序号索引。
+    pub states: BTreeMap<String, Value>,
+    /// store 的值，按保存时指定的名称索引。
+    pub stores: BTreeMap<String, Value>,
+}
+
+impl TreeSnapshot {
+    /// 转换为 JSON，格式为 `{ "states": { .. }, "stores": { .. } }`。
+    pub fn to_json(&self) -> Value {
+        let map = |values: &BTreeMap<String, Value>| {
+            values
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect::<Map<_, _>>()
+        };
+        let mut snapshot = Map::new();
+        snapshot.insert("states".into(), map(&self.states).into());
+        snapshot.insert("stores".into(), map(&self.stores).into());
+        snapshot.into()
+    }
+
+    /// 从 [`TreeSnapshot::to_json`] 的输出还原，格式不符时返回 None。
+    pub fn from_json(value: Value) -> Option<Self> {
+        let Value::Object(mut snapshot) = value else {
+            return None;
+        };
+        let mut take = |name: &str| match snapshot.remove(name) {
+            Some(Value::Object(values)) => Some(values.into_iter().collect()),
+            None => Some(BTreeMap::new()),
+            Some(_) => None,
+        };
+        Some(Self {
+            states: take("states")?,
+            stores: take("stores")?,
+        })
+    }
+
+    /// 保存一个 store 的当前值。
+    #[cfg(feature = "store")]
+    pub fn save_store<T>(&mut self, name: &str, store: &crate::StoreState<T>)
+    where
+        T: serde::Serialize + Send + Sync + 'static,
+    {
+        if let Some(value) = store
+            .try_read()
+            .and_then(|value| serde_json::to_value(&*value).ok())
+        {
+            self.stores.insert(name.to_string(), value);
+        }
+    }
+
+    /// 把快照中的值写回 store，会像修改 store 一样触发重新渲染；没有该名称或类型不符时返回 false。
+    #[cfg(feature = "store")]
+    pub fn restore_store<T>(&self, name: &str, store: &mut crate::StoreState<T>) -> bool
+    where
+        T: serde::de::DeserializeOwned + Send + Sync + 'static,
+    {
+        match self
+            .stores
+            .get(name)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+        {
+            Some(value) => {
+                store.set(value);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+// 组件实例 id 到其在树中位置的映射
+fn component_paths(root: &InstantiatedComponent) -> HashMap<u64, String> {
+    fn visit(component: &InstantiatedComponent, path: String, paths: &mut HashMap<u64, String>) {
+        // 键相同的兄弟组件按出现顺序区分
+        let mut seen = HashMap::<String, usize>::new();
+        for child in component.children() {
+            // 自动生成的键每次编译都会变化，只使用指定的键
+            let segment = match child.key().user_label() {
+                Some(key) => format!("{}[{key}]", child.name()),
+                None => child.name().to_string(),
+            };
+            let nth = seen.entry(segment.clone()).or_default();
+            let child_path = match *nth {
+                0 => format!("{path}/{segment}"),
+                n => format!("{path}/{segment}~{n}"),
+            };
+            *nth += 1;
+            visit(child, child_path, paths);
+        }
+        paths.insert(component.id(), path);
+    }
+
+    let mut paths = HashMap::new();
+    visit(root, String::new(), &mut paths);
+    paths
+}
+
+fn state_path(paths: &HashMap<u64, String>, key: &StateSlotKey) -> Option<String> {
+    paths
+        .get(&key.component_id)
+        .map(|path| format!("{path}#{}", key.hook_index))
+}
+
+impl Tree<'_> {
+    /// 保存树中所有 `use_persisted_state` 状态的当前值。
+    ///
+    /// 尚未恢复的快照值（对应的组件还没有挂载）也会保留在结果中。
+    pub fn snapshot(&self) -> TreeSnapshot {
+        let paths = component_paths(self.root());
+        let persisted = self.states().persisted();
+        let persisted = persisted.lock().unwrap();
+        let mut states = persisted.pending.clone();
+        for (key, slot) in persisted.slots.iter() {
+            if let (Some(path), Some(value)) = (state_path(&paths, key), slot.save()) {
+                states.insert(path, value);
+            }
+        }
+        TreeSnapshot {
+            states,
+            stores: BTreeMap::new(),
+        }
+    }
+
+    /// 恢复快照中的状态：已挂载的状态立即写入并触发重新渲染，其余的在对应组件挂载后写入。
+    ///
+    /// 快照中的 store 需要通过 [`TreeSnapshot::restore_store`] 恢复。
+    pub fn restore(&self, snapshot: TreeSnapshot) {
+        {
+            let persisted = self.states().persisted();
+            let mut persisted = persisted.lock().unwrap();
+            persisted.pending = snapshot.states;
+            persisted.restored_generation = None;
+        }
+        self.apply_pending_restore();
+    }
+
+    /// 把等待中的快照值写入已挂载的状态，每次更新后调用，没有新登记的状态时直接返回。
+    pub(crate) fn apply_pending_restore(&self) {
+        let persisted = self.states().persisted();
+        let mut persisted = persisted.lock().unwrap();
+        if persisted.pending.is_empty()
+            || persisted.restored_generation == Some(persisted.generation)
+        {
+            return;
+        }
+        persisted.restored_generation = Some(persisted.generation);
+        let paths = component_paths(self.root());
+        let persisted = &mut *persisted;
+        for (key, slot) in persisted.slots.iter_mut() {
+            if let Some(value) =
+                state_path(&paths, key).and_then(|path| persisted.pending.remove(&path))
+            {
+                slot.load(value);
+            }
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//! 并行更新的线程状态：组件更新依赖若干线程局部状态，在 rayon 工作线程中更新子树前需要重建。
+//!
+//! - 进入工作线程时沿用调用线程的任务作用域、组件树、批量作用域、更新优先级和属性记录开关；
+//! - 工作线程中记录的数据（渲染期间修改状态的组件、渲染来源、推迟的唤醒、无障碍通知）
+//!   在更新结束后交回调用线程，由调用线程统一处理。
+
+use std::sync::Arc;
+
+use crate::{
+    component::TaskScope,
+    hooks,
+    render::{RenderCause, loop_guard, scheduler, why_render},
+    terminal,
+};
+
+/// 在调用线程上捕获的线程局部状态。
+pub(crate) struct ThreadState {
+    scope: Option<TaskScope>,
+    tree: Option<Arc<scheduler::PendingUpdates>>,
+    priority: scheduler::UpdatePriority,
+    batching: bool,
+    #[cfg(feature = "json")]
+    capture_props: bool,
+}
+
+/// 工作线程中记录的数据，通过 [`ThreadRecords::restore`] 交回调用线程。
+#[derive(Default)]
+pub(crate) struct ThreadRecords {
+    writers: Vec<&'static str>,
+    causes: Vec<RenderCause>,
+    wakers: Vec<hooks::Deferred>,
+    announcements: Vec<String>,
+}
+
+impl ThreadState {
+    pub(crate) fn capture() -> Self {
+        Self {
+            scope: TaskScope::current(),
+            tree: scheduler::current_tree(),
+            priority: scheduler::current_priority(),
+            batching: hooks::is_batching(),
+            #[cfg(feature = "json")]
+            capture_props: crate::element::capturing_props(),
+        }
+    }
+
+    /// 在捕获的状态中执行 `f`，返回结果和期间记录的数据。
+    ///
+    /// 工作线程原有的状态在返回或 panic 后恢复；rayon 可能在调用线程上执行 `f`，此时同样适用。
+    pub(crate) fn enter<R>(&self, f: impl FnOnce() -> R) -> (R, ThreadRecords) {
+        #[cfg(feature = "json")]
+        let f = || crate::element::with_capture_props(self.capture_props, f);
+        let mut saved = Saved::swap(self.batching);
+        let result = TaskScope::enter_scope(self.scope.clone(), || {
+            scheduler::enter_tree(self.tree.clone(), || {
+                scheduler::with_priority(self.priority, f)
+            })
+        });
+        (result, saved.restore().unwrap_or_default())
+    }
+}
+
+impl ThreadRecords {
+    /// 把记录并入当前线程。
+    pub(crate) fn restore(self) {
+        loop_guard::extend_writers(self.writers);
+        self.causes.into_iter().for_each(why_render::record);
+        self.wakers.into_iter().for_each(hooks::defer);
+        self.announcements
+            .into_iter()
+            .for_each(terminal::queue_announcement);
+    }
+}
+
+// 进入前工作线程原有的记录，None 表示已恢复
+struct Saved(Option<Outer>);
+
+struct Outer {
+    writers: Vec<&'static str>,
+    causes: Vec<RenderCause>,
+    pending: Option<Vec<hooks::Deferred>>,
+    announcements: Vec<String>,
+}
+
+impl Saved {
+    fn swap(batching: bool) -> Self {
+        Saved(Some(Outer {
+            writers: loop_guard::replace_writers(Vec::new()),
+            causes: why_render::replace_causes(Vec::new()),
+            pending: hooks::replace_pending(batching.then(Vec::new)),
+            announcements: terminal::replace_announcements(Vec::new()),
+        }))
+    }
+
+    // 恢复原有的记录，返回期间产生的记录
+    fn restore(&mut self) -> Option<ThreadRecords> {
+        let saved = self.0.take()?;
+        Some(ThreadRecords {
+            writers: loop_guard::replace_writers(saved.writers),
+            causes: why_render::replace_causes(saved.causes),
+            wakers: hooks::replace_pending(saved.pending).unwrap_or_default(),
+            announcements: terminal::replace_announcements(saved.announcements),
+        })
+    }
+}
+
+impl Drop for Saved {
+    fn drop(&mut self) {
+        // 更新 panic 时丢弃记录，只恢复工作线程原有的状态
+        self.restore();
+    }
+}
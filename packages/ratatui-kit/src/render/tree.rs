@@ -1,21 +1,52 @@
-use futures::{FutureExt, future::select};
-use std::io::{self};
+use futures::{
+    FutureExt,
+    future::{select, select_all},
+    task::{ArcWake, waker},
+};
+use futures_timer::Delay;
+use ratatui::{
+    TerminalOptions,
+    backend::TestBackend,
+    buffer::{Buffer, Cell},
+    layout::Rect,
+};
+use std::{
+    future::poll_fn,
+    io::{self},
+    pin::Pin,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    task,
+    time::Instant,
+};
 
 use crate::{
-    ElementKey,
+    AnyElement, CrossTerminal, ElementKey, Environment,
     component::{ComponentHelperExt, InstantiatedComponent},
-    context::{ContextStack, SystemContext},
+    context::{AppExit, Context, ContextStack, SystemContext},
     element::ElementExt,
+    hooks::StateArena,
     props::AnyProps,
-    terminal::Terminal,
+    terminal::{RenderMode, Terminal},
 };
 
-use super::ComponentDrawer;
+use super::{
+    ComponentDrawer, DevTools, RenderCause, UpdatePriority, loop_guard::RenderLoopGuard,
+    scheduler::PendingUpdates, why_render,
+};
 
 pub struct Tree<'a> {
     root_component: InstantiatedComponent,
     props: AnyProps<'a>,
     system_context: SystemContext,
+    state_arena: StateArena,
+    render_causes: Vec<RenderCause>,
+    loop_guard: RenderLoopGuard,
+    dev_tools: DevTools,
+    environment: Environment,
+    pending: Arc<PendingUpdates>,
 }
 
 impl<'a> Tree<'a> {
@@ -28,49 +59,546 @@ impl<'a> Tree<'a> {
             ),
             props,
             system_context: SystemContext::new(),
+            state_arena: StateArena::default(),
+            render_causes: Vec::new(),
+            loop_guard: RenderLoopGuard::default(),
+            dev_tools: DevTools::default(),
+            environment: Environment::capture(),
+            pending: Arc::default(),
         }
     }
 
+    /// 根组件。
+    pub fn root(&self) -> &InstantiatedComponent {
+        &self.root_component
+    }
+
+    /// 按键路径查找已挂载的组件，供测试和调试工具查询区域、属性类型和 hook 数量。
+    ///
+    /// # 示例
+    /// ```rust
+    /// // element!(View { Sidebar(key: "sidebar") { List(key: "list") } })
+    /// let list = tree.find("/sidebar/list").expect("list is mounted");
+    /// assert_eq!(list.name(), "List");
+    /// assert!(list.area().height > 0);
+    /// ```
+    /// 路径规则见 [`InstantiatedComponent::find`]。
+    pub fn find(&self, path: &str) -> Option<&InstantiatedComponent> {
+        self.root_component.find(path)
+    }
+
+    /// 树中所有 `use_state` 状态的登记表，可用于状态快照和调试工具。
+    pub fn states(&self) -> &StateArena {
+        &self.state_arena
+    }
+
+    /// 上一帧的触发来源；设置环境变量 `RATATUI_KIT_WHY_RENDER=1` 可把每一帧的来源打印到 stderr。
+    pub fn render_causes(&self) -> &[RenderCause] {
+        &self.render_causes
+    }
+
+    /// 调试命令注册表，见 [`DevTools`]。
+    pub fn dev_tools(&self) -> &DevTools {
+        &self.dev_tools
+    }
+
+    fn update(&mut self, terminal: &mut Terminal) {
+        // 更新期间订阅的事件和记录的待处理更新归属于本树
+        let pending = self.pending.clone();
+        pending.enter(|| self.update_components(terminal));
+        self.loop_guard.check();
+        #[cfg(feature = "persist")]
+        self.apply_pending_restore();
+        self.dev_tools.apply(terminal, &self.root_component);
+    }
+
+    fn update_components(&mut self, terminal: &mut Terminal) {
+        let capabilities = terminal.capabilities();
+        let preferences = terminal.preferences();
+        let profiler = terminal.profiler();
+        let component_context_stack = ContextStack::root(&mut self.system_context);
+        // 终端能力、用户偏好、性能统计、状态登记表、调试命令注册表和运行环境作为根上下文提供给所有组件
+        component_context_stack.with_context(Some(Context::owned(capabilities)), |stack| {
+            stack.with_context(Some(Context::owned(preferences)), |stack| {
+                stack.with_context(Some(Context::owned(profiler)), |stack| {
+                    stack.with_context(Some(Context::form_ref(&self.state_arena)), |stack| {
+                        stack.with_context(Some(Context::form_ref(&self.dev_tools)), |stack| {
+                            stack.with_context(
+                                Some(Context::form_ref(&self.environment)),
+                                |stack| {
+                                    self.root_component.update(
+                                        terminal,
+                                        stack,
+                                        self.props.borrow(),
+                                    );
+                                },
+                            );
+                        });
+                    });
+                });
+            });
+        });
+    }
+
+    // 轮询组件树直到有变化，交给 hook 的 waker 被唤醒时记录待处理的更新
+    async fn wait(&mut self) {
+        let pending = self.pending.clone();
+        let mut root = Pin::new(&mut self.root_component);
+        poll_fn(|cx| {
+            let waker = pending.waker(cx.waker());
+            let mut cx = task::Context::from_waker(&waker);
+            pending.enter(|| root.as_mut().poll_change(&mut cx))
+        })
+        .await;
+    }
+
+    fn draw(&mut self, frame: &mut ratatui::Frame, area: Rect) {
+        let area = self.dev_tools.clip(area);
+        let mut drawer = ComponentDrawer::new(frame, area);
+        self.root_component.draw(&mut drawer);
+    }
+
     fn render(&mut self, terminal: &mut Terminal) -> io::Result<()> {
-        let mut component_context_stack = ContextStack::root(&mut self.system_context);
-        self.root_component
-            .update(terminal, &mut component_context_stack, self.props.borrow());
+        terminal.begin_frame();
+        self.render_causes = why_render::take_frame();
+        self.update(terminal);
+        terminal.apply_inline_height()?;
 
         terminal
             .draw(|frame| {
                 let area = frame.area();
-                let mut drawer = ComponentDrawer::new(frame, area);
-                self.root_component.draw(&mut drawer);
+                self.draw(frame, area);
             })
             .expect("Failed to draw the terminal");
 
         Ok(())
     }
 
-    async fn render_loop(&mut self, terminal: &mut Terminal) -> io::Result<()> {
+    async fn render_loop(&mut self, terminal: &mut Terminal) -> io::Result<AppExit> {
         loop {
+            self.pending.take();
             self.render(terminal)?;
+            let rendered = Instant::now();
             if self.system_context.should_exit() || terminal.received_ctrl_c() {
                 break;
             }
-            select(self.root_component.wait().boxed(), terminal.wait().boxed()).await;
+            select(self.wait().boxed(), terminal.wait().boxed()).await;
+            if terminal.received_ctrl_c() {
+                break;
+            }
+            wait_frame_budget(std::slice::from_mut(self), terminal, rendered).await;
             if terminal.received_ctrl_c() {
                 break;
             }
+            terminal.throttle().await;
+        }
+        Ok(self.system_context.take_exit(terminal.received_ctrl_c()))
+    }
+}
+
+/// 手动驱动的应用，适合把 ratatui-kit 嵌入自定义的事件循环（如游戏循环或其他异步运行时），
+/// 而不是交给 `fullscreen`/`render_loop` 接管。
+///
+/// 由调用方读取输入并通过 [`App::handle_event`] 传入，[`App::poll_until_idle`] 推进 hook 中的状态变化，
+/// [`App::render_once`] 绘制一帧。App 不会自行读取终端事件。
+///
+/// # 示例
+/// ```rust
+/// let mut root = element!(Game);
+/// let mut app = App::new(&mut root, Terminal::new(CrossTerminal::new()?)?)?;
+/// app.render_once()?;
+/// while !app.should_exit() {
+///     if crossterm::event::poll(Duration::from_millis(16))? {
+///         app.handle_event(crossterm::event::read()?);
+///     }
+///     world.step();
+///     app.poll_until_idle();
+///     app.render_once()?;
+/// }
+/// ```
+pub struct App<'a> {
+    tree: Tree<'a>,
+    terminal: Terminal,
+}
+
+impl<'a> App<'a> {
+    /// 挂载根元素，此时尚未渲染，需要调用 [`App::render_once`]。
+    ///
+    /// 终端无法开始接收事件时返回错误。
+    pub fn new<E: ElementExt>(element: &'a mut E, mut terminal: Terminal) -> io::Result<Self> {
+        let helper = element.helper();
+        let tree = Tree::new(element.props_mut(), helper);
+        terminal.events()?;
+        Ok(Self { tree, terminal })
+    }
+
+    /// 更新组件树并绘制一帧。
+    pub fn render_once(&mut self) -> io::Result<()> {
+        self.tree.render(&mut self.terminal)
+    }
+
+    /// 把输入事件分发给组件，在下一次 [`App::poll_until_idle`] 时处理。
+    ///
+    /// 组件在首次渲染时订阅事件，此前传入的事件会被丢弃。Ctrl+C 不会分发，只使 [`App::should_exit`] 返回 true。
+    pub fn handle_event(&mut self, event: crossterm::event::Event) {
+        self.terminal.dispatch(event);
+    }
+
+    /// 轮询所有 hook 直到没有新的变化，每次有变化时更新组件树，返回期间是否发生过变化。
+    ///
+    /// 不会阻塞；异步任务（如 `use_future`）只在调用时推进，需要在循环中定期调用。
+    pub fn poll_until_idle(&mut self) -> bool {
+        // 防止每次轮询都立即就绪的 hook 造成死循环
+        const MAX_ROUNDS: usize = 64;
+
+        // 轮询期间的状态修改会唤醒 waker，此时即使本轮未就绪也要再轮询一次
+        let woken = Arc::new(WakeFlag::default());
+        let pending = self.tree.pending.clone();
+        let waker = pending.waker(&waker(woken.clone()));
+        let mut cx = task::Context::from_waker(&waker);
+        let mut changed = false;
+        for _ in 0..MAX_ROUNDS {
+            woken.0.store(false, Ordering::SeqCst);
+            let root = Pin::new(&mut self.tree.root_component);
+            if pending.enter(|| root.poll_change(&mut cx)).is_ready() {
+                changed = true;
+                self.tree.update(&mut self.terminal);
+            } else if !woken.0.load(Ordering::SeqCst) {
+                break;
+            }
+        }
+        changed
+    }
+
+    /// 等待下一次状态变化，适合在其他异步运行时中与自己的事件源一起 `select`。
+    pub async fn changed(&mut self) {
+        self.tree.wait().await;
+    }
+
+    /// 是否有组件调用了 `SystemContext::exit` 或收到了 Ctrl+C。
+    pub fn should_exit(&self) -> bool {
+        self.tree.system_context.should_exit() || self.terminal.received_ctrl_c()
+    }
+
+    /// 取出退出结果，通常在 [`App::should_exit`] 返回 true 后调用，见 [`SystemContext::exit_with`]。
+    pub fn take_exit(&mut self) -> AppExit {
+        self.tree
+            .system_context
+            .take_exit(self.terminal.received_ctrl_c())
+    }
+
+    /// 已挂载的组件树，可用 [`Tree::find`] 查找组件。
+    pub fn tree(&self) -> &Tree<'a> {
+        &self.tree
+    }
+
+    /// 终端，可用于开启鼠标捕获、录制等。
+    pub fn terminal_mut(&mut self) -> &mut Terminal {
+        &mut self.terminal
+    }
+}
+
+#[derive(Default)]
+struct WakeFlag(AtomicBool);
+
+impl ArcWake for WakeFlag {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+// 按待处理更新的最高优先级等待帧预算，期间继续轮询组件、分发输入事件，变化合并到同一帧；
+// 出现更高优先级的更新时按新的预算重新计算，输入的预算为 0 即立即返回
+/// 等待任意一棵组件树发生变化，唤醒经过各树的 [`Tree::wait`] 按优先级记入该树。
+async fn wait_any(trees: &mut [Tree<'_>]) {
+    let waits = trees
+        .iter_mut()
+        .map(|tree| tree.wait().boxed())
+        .collect::<Vec<_>>();
+    select_all(waits).await;
+}
+
+async fn wait_frame_budget(trees: &mut [Tree<'_>], terminal: &mut Terminal, rendered: Instant) {
+    loop {
+        // 不经过状态的变化（组件自身的轮询）按动画处理
+        let pending = trees
+            .iter()
+            .map(|tree| tree.pending.clone())
+            .collect::<Vec<_>>();
+        let priority = pending
+            .iter()
+            .filter_map(|pending| pending.get())
+            .max()
+            .unwrap_or(UpdatePriority::Animation);
+        let Some(delay) = terminal
+            .frame_budget(priority)
+            .checked_sub(rendered.elapsed())
+            .filter(|delay| !delay.is_zero())
+        else {
+            return;
+        };
+        let preempted = pending
+            .iter()
+            .map(|pending| pending.preempted(priority).boxed())
+            .collect::<Vec<_>>();
+        let deadline = select(Delay::new(delay), select_all(preempted));
+        let progress = select(wait_any(trees).boxed(), terminal.wait().boxed());
+        select(deadline, progress).await;
+        if terminal.received_ctrl_c() {
+            return;
         }
-        Ok(())
     }
 }
 
 pub(crate) async fn render_loop<E: ElementExt>(
     mut element: E,
     mut terminal: Terminal,
-) -> io::Result<()> {
+) -> io::Result<AppExit> {
     let helper = element.helper();
     let mut tree = Tree::new(element.props_mut(), helper);
 
     terminal.events()?;
 
-    tree.render_loop(&mut terminal).await?;
-    Ok(())
+    tree.render_loop(&mut terminal).await
+}
+
+/// 挂载元素并更新一次组件树，不绘制。
+#[cfg(feature = "json")]
+pub(crate) fn mount_element<'a>(element: &'a mut AnyElement, terminal: &mut Terminal) -> Tree<'a> {
+    let helper = element.helper();
+    let mut tree = Tree::new(element.props_mut(), helper);
+    tree.update(terminal);
+    tree
+}
+
+/// 把元素完整渲染一次到独立缓冲区，`height` 为 None 时按内容裁掉末尾的空行。
+///
+/// 元素只渲染一帧，其中的异步副作用不会继续执行。
+pub(crate) fn render_element_to_buffer(
+    element: &mut AnyElement,
+    terminal: &mut Terminal,
+    width: u16,
+    height: Option<u16>,
+) -> io::Result<Buffer> {
+    // 未指定高度时在足够高的区域内渲染，再按内容测量
+    const MEASURE_HEIGHT: u16 = 256;
+
+    let helper = element.helper();
+    let mut tree = Tree::new(element.props_mut(), helper);
+    tree.update(terminal);
+
+    let mut offscreen =
+        ratatui::Terminal::new(TestBackend::new(width, height.unwrap_or(MEASURE_HEIGHT)))?;
+    offscreen.draw(|frame| {
+        let area = frame.area();
+        tree.draw(frame, area);
+    })?;
+    let mut buffer = offscreen.backend().buffer().clone();
+
+    if height.is_none() {
+        let area = buffer.area;
+        let used = (area.top()..area.bottom())
+            .rev()
+            .find(|&y| (area.left()..area.right()).any(|x| buffer[(x, y)] != Cell::EMPTY))
+            .map_or(0, |y| y - area.top() + 1);
+        buffer.resize(Rect {
+            height: used,
+            ..area
+        });
+    }
+    Ok(buffer)
+}
+
+type AreaFn<'a> = Box<dyn Fn(Rect) -> Rect + Send + Sync + 'a>;
+
+struct RootSurface<'a> {
+    element: AnyElement<'a>,
+    area: Option<AreaFn<'a>>,
+}
+
+/// 多根渲染：在同一个终端上挂载多个互相独立的元素树。
+///
+/// - 每个根拥有独立的组件树和系统上下文，共享同一个终端事件流。
+/// - 按挂载顺序绘制，后挂载的根覆盖在前面的根之上，适合主界面加独立的状态浮层、插件提供的界面等场景。
+/// - 任意一个根调用 `SystemContext::exit` 或收到 Ctrl+C 时全部退出，`exit_with` 传出的结果由退出时的返回值带回。
+///
+/// # 示例
+/// ```rust
+/// Roots::new()
+///     .mount(element!(MainView))
+///     .mount_in(element!(StatusOverlay), |area| Rect {
+///         y: area.bottom().saturating_sub(1),
+///         height: 1,
+///         ..area
+///     })
+///     .fullscreen()
+///     .await?;
+/// ```
+#[derive(Default)]
+pub struct Roots<'a> {
+    surfaces: Vec<RootSurface<'a>>,
+    render_mode: RenderMode,
+}
+
+impl<'a> Roots<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 挂载一个占满整个终端区域的根元素。
+    pub fn mount(mut self, element: impl Into<AnyElement<'a>>) -> Self {
+        self.surfaces.push(RootSurface {
+            element: element.into(),
+            area: None,
+        });
+        self
+    }
+
+    /// 挂载一个根元素，`area` 根据终端区域计算它的绘制区域。
+    pub fn mount_in<F>(mut self, element: impl Into<AnyElement<'a>>, area: F) -> Self
+    where
+        F: Fn(Rect) -> Rect + Send + Sync + 'a,
+    {
+        self.surfaces.push(RootSurface {
+            element: element.into(),
+            area: Some(Box::new(area)),
+        });
+        self
+    }
+
+    /// 设置重绘模式，默认每次变化立即重绘。
+    pub fn render_mode(mut self, mode: RenderMode) -> Self {
+        self.render_mode = mode;
+        self
+    }
+
+    /// 启动渲染主循环，传入终端选项，适合自定义Viewport场景。
+    pub async fn render_loop(&mut self, options: TerminalOptions) -> io::Result<AppExit> {
+        let terminal = Terminal::new(CrossTerminal::with_options(options)?)?;
+        self.run(terminal).await
+    }
+
+    /// 以全屏模式运行全部根元素。
+    pub async fn fullscreen(&mut self) -> io::Result<AppExit> {
+        let terminal = Terminal::new(CrossTerminal::new()?)?;
+        self.run(terminal).await
+    }
+
+    async fn run(&mut self, mut terminal: Terminal) -> io::Result<AppExit> {
+        let mut trees = Vec::with_capacity(self.surfaces.len());
+        let mut areas = Vec::with_capacity(self.surfaces.len());
+        for surface in self.surfaces.iter_mut() {
+            let helper = surface.element.helper();
+            trees.push(Tree::new(surface.element.props_mut(), helper));
+            areas.push(surface.area.as_deref());
+        }
+
+        if trees.is_empty() {
+            return Ok(AppExit::default());
+        }
+
+        terminal.set_render_mode(self.render_mode);
+        terminal.events()?;
+
+        loop {
+            for tree in trees.iter() {
+                tree.pending.take();
+            }
+            terminal.begin_frame();
+            // 多个根共用一个线程，来源无法按根区分
+            let causes = why_render::take_frame();
+            for tree in trees.iter_mut() {
+                tree.render_causes = causes.clone();
+                tree.update(&mut terminal);
+            }
+            terminal.apply_inline_height()?;
+            terminal
+                .draw(|frame| {
+                    let full = frame.area();
+                    for (tree, area) in trees.iter_mut().zip(areas.iter()) {
+                        let area = area.map_or(full, |area| area(full).intersection(full));
+                        tree.draw(frame, area);
+                    }
+                })
+                .expect("Failed to draw the terminal");
+            let rendered = Instant::now();
+
+            if trees.iter().any(|tree| tree.system_context.should_exit())
+                || terminal.received_ctrl_c()
+            {
+                break;
+            }
+
+            select(wait_any(&mut trees).boxed(), terminal.wait().boxed()).await;
+            if terminal.received_ctrl_c() {
+                break;
+            }
+            wait_frame_budget(&mut trees, &mut terminal, rendered).await;
+            if terminal.received_ctrl_c() {
+                break;
+            }
+            terminal.throttle().await;
+        }
+        // 多个根都带有结果时取最先挂载的
+        let interrupted = terminal.received_ctrl_c();
+        let mut exits = trees
+            .iter_mut()
+            .map(|tree| tree.system_context.take_exit(interrupted))
+            .collect::<Vec<_>>();
+        let index = exits.iter().position(AppExit::has_value).unwrap_or(0);
+        Ok(exits.swap_remove(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use futures::FutureExt;
+
+    use super::{Tree, wait_any};
+    use crate::{CrossTerminal, UpdatePriority, prelude::*, with_priority};
+
+    type Slot = Arc<Mutex<Option<State<i32>>>>;
+
+    #[derive(Default, Props)]
+    struct CounterProps {
+        slot: Slot,
+    }
+
+    #[component]
+    fn Counter(props: &CounterProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let count = hooks.use_state(|| 0);
+        *props.slot.lock().unwrap() = Some(count);
+        element!(Text(text: count.get().to_string()))
+    }
+
+    #[test]
+    fn roots_record_update_priority_per_tree() {
+        let slots = [Slot::default(), Slot::default()];
+        let mut elements = slots
+            .each_ref()
+            .map(|slot| element!(Counter(slot: slot.clone())));
+        let mut terminal = Terminal::new(CrossTerminal::headless(40, 10).unwrap()).unwrap();
+        let mut trees = elements
+            .iter_mut()
+            .map(|element| {
+                let helper = element.helper();
+                Tree::new(element.props_mut(), helper)
+            })
+            .collect::<Vec<_>>();
+        for tree in trees.iter_mut() {
+            tree.render(&mut terminal).unwrap();
+            tree.pending.take();
+        }
+
+        // 与 Roots 的主循环一样等待所有根，注册各树的 waker
+        assert!(wait_any(&mut trees).now_or_never().is_none());
+        let mut count = slots[1].lock().unwrap().unwrap();
+        with_priority(UpdatePriority::Background, || count.set(1));
+
+        assert_eq!(trees[0].pending.get(), None);
+        assert_eq!(trees[1].pending.get(), Some(UpdatePriority::Background));
+        assert!(wait_any(&mut trees).now_or_never().is_some());
+    }
 }
@@ -1,34 +1,51 @@
 use std::{
     any::Any,
     cell::{Ref, RefMut},
+    io,
 };
+#[cfg(feature = "parallel")]
+use std::{
+    ops::{Deref, DerefMut},
+    sync::{Mutex, MutexGuard},
+};
+
+use crossterm::event::Event;
 
+#[cfg(feature = "parallel")]
+use crate::render::thread_state::{ThreadRecords, ThreadState};
 use crate::{
-    ElementKey,
+    ElementKey, EventInterest, TerminalEvents,
     component::{Components, InstantiatedComponent},
+    components::ActivityContext,
     context::{Context, ContextStack},
     element::ElementExt,
     layout_style::LayoutStyle,
-    multimap::AppendOnlyMultimap,
     terminal::Terminal,
 };
 
 pub struct ComponentUpdater<'a, 'c: 'a> {
     key: ElementKey,
-    component_context_stack: &'a mut ContextStack<'c>,
-    terminal: &'a mut Terminal,
+    component_context_stack: &'a ContextStack<'c>,
+    terminal: TerminalAccess<'a>,
     components: &'a mut Components,
     transparent_layout: bool,
+    measured_child: Option<usize>,
+    children_suspended: bool,
+    // 严格模式的空跑更新中不更新子组件
+    dry_run: bool,
+    // 本组件发生了变化，子组件随之视为变化
+    changed: bool,
     layout_style: &'a mut LayoutStyle,
 }
 
 impl<'a, 'c: 'a> ComponentUpdater<'a, 'c> {
     pub(crate) fn new(
         key: ElementKey,
-        component_context_stack: &'a mut ContextStack<'c>,
-        terminal: &'a mut Terminal,
+        component_context_stack: &'a ContextStack<'c>,
+        terminal: TerminalAccess<'a>,
         components: &'a mut Components,
         layout_style: &'a mut LayoutStyle,
+        changed: bool,
     ) -> ComponentUpdater<'a, 'c> {
         ComponentUpdater {
             key,
@@ -36,6 +53,10 @@ impl<'a, 'c: 'a> ComponentUpdater<'a, 'c> {
             terminal,
             components,
             transparent_layout: false,
+            measured_child: None,
+            children_suspended: false,
+            dry_run: false,
+            changed,
             layout_style,
         }
     }
@@ -56,8 +77,34 @@ impl<'a, 'c: 'a> ComponentUpdater<'a, 'c> {
         self.component_context_stack.get_context_mut()
     }
 
+    /// 访问终端。
+    ///
+    /// 在 [`ComponentUpdater::update_children_parallel`] 并行更新的子树中终端由多个线程共享，
+    /// 首次调用时锁住终端，直到本组件开始更新子组件或更新结束才释放，期间其他线程访问终端时会等待。
+    /// 只需短暂访问时可改用 [`ComponentUpdater::terminal_guard`]。
     pub fn terminal(&mut self) -> &mut Terminal {
-        self.terminal
+        self.terminal.lock()
+    }
+
+    /// 访问终端，并行更新的子树中守卫释放时即解锁终端，应避免长时间持有返回的守卫。
+    #[cfg(feature = "parallel")]
+    pub fn terminal_guard(&mut self) -> TerminalGuard<'_> {
+        match &mut self.terminal {
+            TerminalAccess::Exclusive(terminal) => TerminalGuard::Exclusive(terminal),
+            TerminalAccess::Shared(terminal) => TerminalGuard::Locked(terminal.lock()),
+            TerminalAccess::Locked(_, terminal) => TerminalGuard::Exclusive(terminal),
+        }
+    }
+
+    /// 在任意子树中访问终端，供内置 hook 使用，并行更新的子树中只在 `f` 执行期间锁住终端。
+    pub(crate) fn with_terminal<R>(&mut self, f: impl FnOnce(&mut Terminal) -> R) -> R {
+        match &mut self.terminal {
+            TerminalAccess::Exclusive(terminal) => f(terminal),
+            #[cfg(feature = "parallel")]
+            TerminalAccess::Shared(terminal) => f(&mut terminal.lock()),
+            #[cfg(feature = "parallel")]
+            TerminalAccess::Locked(_, terminal) => f(terminal),
+        }
     }
 
     pub fn set_transparent_layout(&mut self, transparent: bool) {
@@ -68,6 +115,54 @@ impl<'a, 'c: 'a> ComponentUpdater<'a, 'c> {
         self.transparent_layout
     }
 
+    /// 透明组件以第 `index` 个子组件的测量结果作为自身尺寸，其余子组件不影响布局。
+    pub(crate) fn set_measured_child(&mut self, index: usize) {
+        self.measured_child = Some(index);
+    }
+
+    pub(crate) fn measured_child(&self) -> Option<usize> {
+        self.measured_child
+    }
+
+    /// 暂停子组件：子组件保持挂载但不再轮询和绘制，切换时通知子树中的 hook，
+    /// 见 [`Hook::on_active_change`](crate::Hook::on_active_change)。
+    pub fn set_children_suspended(&mut self, suspended: bool) {
+        self.children_suspended = suspended;
+    }
+
+    pub(crate) fn children_suspended(&self) -> bool {
+        self.children_suspended
+    }
+
+    pub(crate) fn set_dry_run(&mut self, dry_run: bool) {
+        self.dry_run = dry_run;
+    }
+
+    /// 订阅指定类别的终端事件，所在子树被 [`Activity`](crate::components::Activity) 停用期间
+    /// 事件不会进入队列，重新启用后也不会收到停用期间的事件。
+    pub fn events_of(&mut self, interest: EventInterest) -> io::Result<TerminalEvents<Event>> {
+        match ActivityContext::current(self) {
+            Some(activity) => self.with_terminal(|terminal| {
+                terminal
+                    .events_filtered(move |event| activity.is_active() && interest.matches(event))
+            }),
+            None => self.with_terminal(|terminal| terminal.events_of(interest)),
+        }
+    }
+
+    /// 同 [`ComponentUpdater::events_of`]，按自定义条件过滤。
+    pub fn events_filtered<F>(&mut self, filter: F) -> io::Result<TerminalEvents<Event>>
+    where
+        F: Fn(&Event) -> bool + Send + Sync + 'static,
+    {
+        match ActivityContext::current(self) {
+            Some(activity) => self.with_terminal(|terminal| {
+                terminal.events_filtered(move |event| activity.is_active() && filter(event))
+            }),
+            None => self.with_terminal(|terminal| terminal.events_filtered(filter)),
+        }
+    }
+
     pub fn set_layout_style(&mut self, layout_style: LayoutStyle) {
         *self.layout_style = layout_style;
     }
@@ -77,9 +172,12 @@ impl<'a, 'c: 'a> ComponentUpdater<'a, 'c> {
         I: IntoIterator<Item = T>,
         T: ElementExt,
     {
+        if self.dry_run {
+            return;
+        }
         self.component_context_stack
             .with_context(context, |context_stack| {
-                let mut used_components = AppendOnlyMultimap::default();
+                let mut used_components = self.components.begin_update();
 
                 for mut child in elements {
                     let mut component = match self.components.pop_front(child.key()) {
@@ -95,11 +193,207 @@ impl<'a, 'c: 'a> ComponentUpdater<'a, 'c> {
                         }
                     };
 
-                    component.update(self.terminal, context_stack, child.props_mut());
+                    component.update_with(
+                        self.terminal.reborrow(),
+                        context_stack,
+                        child.props_mut(),
+                        self.changed,
+                    );
                     used_components.push_back(child.key().clone(), component);
                 }
 
-                self.components.components = used_components.into();
+                self.components.finish_update(used_components);
             });
     }
+
+    /// 同 [`ComponentUpdater::update_children`]，但子组件在 rayon 线程池中并行更新。
+    ///
+    /// 适合包含大量计算（如解析 Markdown、计算差异）的相互独立的子树。
+    /// 并行更新的子树中：
+    /// - 祖先提供的上下文只能读取，包括 [`SystemContext`](crate::SystemContext)：
+    ///   `get_context_mut` 返回 None，`use_context_mut` 会 panic，可改用 `try_use_context_mut`，
+    ///   或通过 store、`State` 修改共享状态；
+    /// - 终端通过互斥锁共享，[`ComponentUpdater::terminal`] 会锁住终端直到组件更新结束，
+    ///   只需短暂访问时可用 [`ComponentUpdater::terminal_guard`]；
+    /// - 任务作用域、批量作用域、更新优先级等线程状态沿用调用线程的设置，
+    ///   渲染循环检测和渲染来源等记录会交回调用线程；
+    /// - 绘制仍按顺序串行进行。
+    #[cfg(feature = "parallel")]
+    pub fn update_children_parallel<I, T>(&mut self, elements: I, context: Option<Context>)
+    where
+        I: IntoIterator<Item = T>,
+        T: ElementExt,
+    {
+        if self.dry_run {
+            return;
+        }
+        use rayon::prelude::*;
+
+        let mut elements = elements.into_iter().collect::<Vec<_>>();
+        // 先按顺序匹配或创建组件实例，再并行更新
+        let components = elements
+            .iter_mut()
+            .map(|child| match self.components.pop_front(child.key()) {
+                Some(component)
+                    if component.component().type_id() == child.helper().component_type_id() =>
+                {
+                    component
+                }
+                _ => {
+                    let h = child.helper();
+                    InstantiatedComponent::new(child.key().clone(), child.props_mut(), h)
+                }
+            })
+            .collect::<Vec<_>>();
+        let mut children = elements
+            .iter_mut()
+            .zip(components)
+            .map(|(child, component)| (child.key().clone(), component, child.props_mut()))
+            .collect::<Vec<_>>();
+
+        let terminal = self.terminal.reborrow();
+        self.component_context_stack
+            .with_context(context, |context_stack| {
+                context_stack.with_shared(|contexts| {
+                    let changed = self.changed;
+                    // 工作线程中沿用调用线程的线程局部状态
+                    let state = ThreadState::capture();
+                    let mut update = |shared: &(dyn SharedTerminal + '_)| {
+                        children
+                            .par_iter_mut()
+                            .map(|(_, component, props)| {
+                                let context_stack = ContextStack::read_only(contexts);
+                                let ((), records) = state.enter(|| {
+                                    component.update_with(
+                                        TerminalAccess::Shared(shared),
+                                        &context_stack,
+                                        props.borrow(),
+                                        changed,
+                                    )
+                                });
+                                records
+                            })
+                            .collect::<Vec<_>>()
+                    };
+                    let records = match terminal {
+                        TerminalAccess::Exclusive(terminal) => update(&Mutex::new(terminal)),
+                        TerminalAccess::Shared(shared) => update(shared),
+                        TerminalAccess::Locked(..) => unreachable!("reborrow releases the lock"),
+                    };
+                    records.into_iter().for_each(ThreadRecords::restore);
+                });
+            });
+
+        let mut used_components = self.components.begin_update();
+        for (key, component, _) in children {
+            used_components.push_back(key, component);
+        }
+        self.components.finish_update(used_components);
+    }
+}
+
+/// 组件更新期间对终端的访问方式。
+pub(crate) enum TerminalAccess<'a> {
+    Exclusive(&'a mut Terminal),
+    // 并行更新时多个线程通过互斥锁共享终端
+    #[cfg(feature = "parallel")]
+    Shared(&'a (dyn SharedTerminal + 'a)),
+    // 通过 ComponentUpdater::terminal 锁住的共享终端
+    #[cfg(feature = "parallel")]
+    Locked(
+        &'a (dyn SharedTerminal + 'a),
+        Box<dyn DerefMut<Target = Terminal> + 'a>,
+    ),
+}
+
+impl TerminalAccess<'_> {
+    /// 借给子组件，已锁住的共享终端先解锁，避免子组件访问终端时死锁。
+    pub(crate) fn reborrow(&mut self) -> TerminalAccess<'_> {
+        #[cfg(feature = "parallel")]
+        if let TerminalAccess::Locked(shared, _) = *self {
+            *self = TerminalAccess::Shared(shared);
+        }
+        match self {
+            TerminalAccess::Exclusive(terminal) => TerminalAccess::Exclusive(terminal),
+            #[cfg(feature = "parallel")]
+            TerminalAccess::Shared(terminal) => TerminalAccess::Shared(*terminal),
+            #[cfg(feature = "parallel")]
+            TerminalAccess::Locked(..) => unreachable!(),
+        }
+    }
+
+    // 借出终端，共享终端锁住后一直持有到下一次 reborrow 或本次更新结束
+    fn lock(&mut self) -> &mut Terminal {
+        #[cfg(feature = "parallel")]
+        if let TerminalAccess::Shared(shared) = *self {
+            *self = TerminalAccess::Locked(shared, shared.lock());
+        }
+        match self {
+            TerminalAccess::Exclusive(terminal) => terminal,
+            #[cfg(feature = "parallel")]
+            TerminalAccess::Shared(_) => unreachable!(),
+            #[cfg(feature = "parallel")]
+            TerminalAccess::Locked(_, terminal) => terminal,
+        }
+    }
+}
+
+#[cfg(feature = "parallel")]
+pub(crate) trait SharedTerminal: Sync {
+    fn lock(&self) -> Box<dyn DerefMut<Target = Terminal> + '_>;
+}
+
+#[cfg(feature = "parallel")]
+impl SharedTerminal for Mutex<&mut Terminal> {
+    fn lock(&self) -> Box<dyn DerefMut<Target = Terminal> + '_> {
+        Box::new(LockedTerminal(Mutex::lock(self).unwrap()))
+    }
+}
+
+#[cfg(feature = "parallel")]
+struct LockedTerminal<'g, 't>(MutexGuard<'g, &'t mut Terminal>);
+
+#[cfg(feature = "parallel")]
+impl Deref for LockedTerminal<'_, '_> {
+    type Target = Terminal;
+
+    fn deref(&self) -> &Terminal {
+        &self.0
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl DerefMut for LockedTerminal<'_, '_> {
+    fn deref_mut(&mut self) -> &mut Terminal {
+        &mut self.0
+    }
+}
+
+/// [`ComponentUpdater::terminal_guard`] 返回的终端守卫。
+#[cfg(feature = "parallel")]
+pub enum TerminalGuard<'a> {
+    Exclusive(&'a mut Terminal),
+    Locked(Box<dyn DerefMut<Target = Terminal> + 'a>),
+}
+
+#[cfg(feature = "parallel")]
+impl Deref for TerminalGuard<'_> {
+    type Target = Terminal;
+
+    fn deref(&self) -> &Terminal {
+        match self {
+            TerminalGuard::Exclusive(terminal) => terminal,
+            TerminalGuard::Locked(terminal) => terminal,
+        }
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl DerefMut for TerminalGuard<'_> {
+    fn deref_mut(&mut self) -> &mut Terminal {
+        match self {
+            TerminalGuard::Exclusive(terminal) => terminal,
+            TerminalGuard::Locked(terminal) => terminal,
+        }
+    }
 }
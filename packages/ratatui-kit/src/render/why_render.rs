@@ -0,0 +1,97 @@
+//! 渲染来源诊断：记录每一帧是由哪个组件的哪个 hook 触发的，用于排查多余的重绘和渲染循环。
+//!
+//! 组件树轮询时，返回就绪的 hook（`use_state`、`use_store`、`use_events` 等）被记为本帧的来源，
+//! 通过 [`Tree::render_causes`](crate::render::tree::Tree::render_causes) 读取上一帧的来源；
+//! 设置环境变量 `RATATUI_KIT_WHY_RENDER=1` 会把每一帧的来源打印到 stderr：
+//!
+//! ```text
+//! [why-render] 第 12 帧：CounterPage hook #2 (UseStateImpl)
+//! ```
+
+use std::{
+    cell::RefCell,
+    env, fmt,
+    sync::{
+        OnceLock,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+// 一帧最多记录的来源数
+const MAX_CAUSES: usize = 64;
+
+/// 触发重绘的来源。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderCause {
+    /// 组件实例 id，见 [`InstantiatedComponent::id`](crate::InstantiatedComponent::id)。
+    pub component_id: u64,
+    /// 组件类型名，不含模块路径和泛型参数。
+    pub component: &'static str,
+    /// 组件的键。
+    pub key: String,
+    /// 就绪的 hook 在组件中的序号（从 0 开始），None 表示组件自身的轮询。
+    pub hook_index: Option<usize>,
+    /// 就绪的 hook 类型名，不含模块路径和泛型参数。
+    pub hook: Option<&'static str>,
+}
+
+impl fmt::Display for RenderCause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.hook_index, self.hook) {
+            (Some(index), Some(hook)) => {
+                write!(f, "{} hook #{} ({hook})", self.component, index + 1)
+            }
+            _ => write!(f, "{}", self.component),
+        }
+    }
+}
+
+thread_local! {
+    // 上一帧之后记录的来源
+    static CAUSES: RefCell<Vec<RenderCause>> = const { RefCell::new(Vec::new()) };
+}
+
+static FRAME: AtomicU64 = AtomicU64::new(0);
+
+fn enabled() -> bool {
+    static ENABLED: OnceLock<bool> = OnceLock::new();
+    *ENABLED.get_or_init(|| env::var_os("RATATUI_KIT_WHY_RENDER").is_some_and(|value| value != "0"))
+}
+
+/// 记录一个来源，同一组件的同一 hook 在一帧内只记录一次。
+pub(crate) fn record(cause: RenderCause) {
+    CAUSES.with(|causes| {
+        let mut causes = causes.borrow_mut();
+        let recorded = causes.iter().any(|recorded| {
+            recorded.component_id == cause.component_id && recorded.hook_index == cause.hook_index
+        });
+        if !recorded && causes.len() < MAX_CAUSES {
+            causes.push(cause);
+        }
+    });
+}
+
+/// 替换当前线程记录的来源并返回原有记录，并行更新在工作线程中以此隔离记录。
+#[cfg(feature = "parallel")]
+pub(crate) fn replace_causes(causes: Vec<RenderCause>) -> Vec<RenderCause> {
+    CAUSES.with(|current| std::mem::replace(&mut *current.borrow_mut(), causes))
+}
+
+/// 取出本帧的来源，开启诊断时打印到 stderr。
+pub(crate) fn take_frame() -> Vec<RenderCause> {
+    let causes = CAUSES.with(|causes| std::mem::take(&mut *causes.borrow_mut()));
+    let frame = FRAME.fetch_add(1, Ordering::Relaxed) + 1;
+    if enabled() {
+        if causes.is_empty() {
+            eprintln!("[why-render] 第 {frame} 帧：没有 hook 触发（首帧、终端事件或显式重绘）");
+        } else {
+            let causes = causes
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("，");
+            eprintln!("[why-render] 第 {frame} 帧：{causes}");
+        }
+    }
+    causes
+}
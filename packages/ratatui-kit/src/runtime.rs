@@ -0,0 +1,154 @@
+//! 异步运行时适配。
+//!
+//! 渲染循环自行轮询组件、hook 和异步处理器派生的任务，计时使用 `futures-timer`，
+//! 因此核心功能不依赖特定的运行时，可以在 tokio、async-std、smol 或 `futures::executor::block_on` 中运行。
+//!
+//! 少数 hook 封装了基于 tokio 的 I/O 库（[`use_fetch`]、[`use_websocket`]、[`use_command`]），需要 tokio 的反应器。
+//! 这些 hook 的 I/O 任务按以下顺序选择运行位置：
+//!
+//! 1. 通过 [`set_spawner`] 设置的 [`Spawner`]；
+//! 2. 当前线程所在的 tokio 运行时，此时直接在渲染循环中轮询；
+//! 3. 内置的单线程后台 tokio 运行时，首次使用时创建。
+//!
+//! 因此在 async-std 或 smol 中无需额外配置即可使用这些 hook；
+//! 已有 tokio 运行时但渲染循环运行在其他线程时，可以把它的 [`Handle`](tokio::runtime::Handle) 设为派生器。
+//!
+//! ```rust,ignore
+//! let runtime = tokio::runtime::Runtime::new()?;
+//! ratatui_kit::runtime::set_spawner(runtime.handle().clone());
+//! smol::block_on(element!(App).fullscreen())?;
+//! ```
+//!
+//! [`use_fetch`]: crate::UseFetch::use_fetch
+//! [`use_websocket`]: crate::UseWebSocket::use_websocket
+//! [`use_command`]: crate::UseCommand::use_command
+use futures::future::BoxFuture;
+use std::sync::{Arc, Mutex};
+
+static SPAWNER: Mutex<Option<Arc<dyn Spawner>>> = Mutex::new(None);
+
+/// 任务派生器，把需要特定运行时的任务交给该运行时执行。
+///
+/// 已为 tokio 的 `Handle` 和 `Fn(BoxFuture<'static, ()>)` 闭包实现，例如 `|task| smol::spawn(task).detach()`。
+pub trait Spawner: Send + Sync + 'static {
+    /// 在后台运行任务，任务完成前不会被丢弃。
+    fn spawn(&self, task: BoxFuture<'static, ()>);
+}
+
+impl<F> Spawner for F
+where
+    F: Fn(BoxFuture<'static, ()>) + Send + Sync + 'static,
+{
+    fn spawn(&self, task: BoxFuture<'static, ()>) {
+        self(task)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl Spawner for tokio::runtime::Handle {
+    fn spawn(&self, task: BoxFuture<'static, ()>) {
+        drop(tokio::runtime::Handle::spawn(self, task));
+    }
+}
+
+/// 设置全局任务派生器，替换之前设置的派生器，只影响之后启动的任务。
+pub fn set_spawner(spawner: impl Spawner) {
+    *SPAWNER.lock().unwrap() = Some(Arc::new(spawner));
+}
+
+/// 清除通过 [`set_spawner`] 设置的任务派生器。
+pub fn clear_spawner() {
+    *SPAWNER.lock().unwrap() = None;
+}
+
+/// 在后台运行不属于任何组件的任务：优先交给 [`set_spawner`] 设置的派生器，未设置时在独立线程中运行。
+pub(crate) fn spawn_detached(task: BoxFuture<'static, ()>) {
+    if let Some(spawner) = SPAWNER.lock().unwrap().clone() {
+        spawner.spawn(task);
+        return;
+    }
+    std::thread::Builder::new()
+        .name("ratatui-kit-task".into())
+        .spawn(move || futures::executor::block_on(task))
+        .expect("failed to spawn a thread for the detached task");
+}
+
+#[cfg(feature = "tokio")]
+pub(crate) use tokio_compat::spawn_io;
+
+#[cfg(feature = "tokio")]
+mod tokio_compat {
+    use super::{SPAWNER, Spawner};
+    use futures::{
+        FutureExt,
+        channel::oneshot,
+        future::{AbortHandle, BoxFuture, abortable, pending},
+    };
+    use std::sync::OnceLock;
+    use tokio::runtime::{Builder, Handle, Runtime};
+
+    // 丢弃时取消后台任务，与直接丢弃 future 的语义一致
+    struct AbortOnDrop(AbortHandle);
+
+    impl Drop for AbortOnDrop {
+        fn drop(&mut self) {
+            self.0.abort();
+        }
+    }
+
+    fn fallback() -> &'static Handle {
+        static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+        RUNTIME
+            .get_or_init(|| {
+                Builder::new_multi_thread()
+                    .worker_threads(1)
+                    .thread_name("ratatui-kit-runtime")
+                    .enable_all()
+                    .build()
+                    .expect("failed to build the fallback tokio runtime")
+            })
+            .handle()
+    }
+
+    fn remote<F>(spawner: &dyn Spawner, future: F) -> BoxFuture<'static, F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send,
+    {
+        let (future, handle) = abortable(future);
+        let (sender, receiver) = oneshot::channel();
+        spawner.spawn(
+            async move {
+                if let Ok(output) = future.await {
+                    let _ = sender.send(output);
+                }
+            }
+            .boxed(),
+        );
+        let guard = AbortOnDrop(handle);
+        async move {
+            let _guard = guard;
+            match receiver.await {
+                Ok(output) => output,
+                // 派生器丢弃了任务（如运行时已关闭），保持挂起
+                Err(_) => pending().await,
+            }
+        }
+        .boxed()
+    }
+
+    /// 在 tokio 反应器中运行 I/O 任务，返回的 future 可以由任意运行时轮询，丢弃时取消任务。
+    pub(crate) fn spawn_io<F>(future: F) -> BoxFuture<'static, F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send,
+    {
+        if let Some(spawner) = SPAWNER.lock().unwrap().clone() {
+            return remote(&*spawner, future);
+        }
+        if Handle::try_current().is_ok() {
+            return future.boxed();
+        }
+        remote(fallback(), future)
+    }
+}
@@ -11,7 +11,7 @@ use std::{
     task::Waker,
 };
 
-use crate::ElementKey;
+use crate::{ElementKey, hooks::wake, render::loop_guard};
 
 mod use_store;
 pub use use_store::UseStore;
@@ -100,8 +100,9 @@ where
     fn drop(&mut self) {
         if self.is_deref_mut {
             self.inner.is_changed = true;
+            loop_guard::record_write();
             for waker in self.inner.wakers.values() {
-                waker.wake_by_ref();
+                wake(waker.clone());
             }
         }
     }
@@ -0,0 +1,155 @@
+//! 无障碍输出：在正常渲染的同时，把焦点变化和通知以线性文本逐行写出，供屏幕阅读器、盲文点显器等辅助工具读取。
+//!
+//! 设置环境变量 `RATATUI_KIT_A11Y=a11y.log` 即可开启，路径也可以是命名管道（`mkfifo`），
+//! 由屏幕阅读器的桥接脚本读取后朗读；也可以通过 [`Terminal::set_accessibility`](crate::Terminal::set_accessibility)
+//! 写入任意实现了 `Write` 的目标。
+//!
+//! 每行一条记录：
+//! ```text
+//! 焦点: TextArea 第一行 / 第二行
+//! 通知: 已保存
+//! ```
+//! - 通过 [`use_focus_ring`](crate::UseFocusRing::use_focus_ring) 上报聚焦的组件（内置交互组件均已接入）
+//!   在焦点变化时输出组件名及其区域内的文本，多行之间用 ` / ` 连接；
+//! - [`use_announce`](crate::UseAnnounce::use_announce) 类似 live region，消息变化时输出一次；
+//! - 目前只提供文本输出，平台的无障碍接口需要由读取输出的桥接程序接入；
+//! - 写入命名管道时，打开会阻塞到读取端就绪，需先启动读取端。
+
+use std::{
+    cell::RefCell,
+    fs::OpenOptions,
+    io::{self, Write},
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use ratatui::{buffer::Buffer, layout::Rect};
+use unicode_width::UnicodeWidthStr;
+
+// 两帧之间最多缓存的通知数
+const MAX_ANNOUNCEMENTS: usize = 64;
+
+#[derive(Default)]
+struct FrameReport {
+    focus: Option<(&'static str, Rect)>,
+    announcements: Vec<String>,
+}
+
+thread_local! {
+    // 本帧中上报的焦点和通知
+    static REPORT: RefCell<FrameReport> = RefCell::new(FrameReport::default());
+}
+
+/// 上报聚焦的组件，同一帧中以最后上报的为准。
+pub(crate) fn report_focus(component: &'static str, area: Rect) {
+    REPORT.with(|report| report.borrow_mut().focus = Some((component, area)));
+}
+
+/// 加入一条通知，在下一帧输出。
+pub(crate) fn queue_announcement(message: String) {
+    REPORT.with(|report| {
+        let mut report = report.borrow_mut();
+        if report.announcements.len() < MAX_ANNOUNCEMENTS {
+            report.announcements.push(message);
+        }
+    });
+}
+
+/// 替换当前线程待输出的通知并返回原有的，并行更新在工作线程中以此隔离通知。
+#[cfg(feature = "parallel")]
+pub(crate) fn replace_announcements(announcements: Vec<String>) -> Vec<String> {
+    REPORT.with(|report| std::mem::replace(&mut report.borrow_mut().announcements, announcements))
+}
+
+// 取出本帧的上报，未开启无障碍输出时也需调用以免堆积
+fn take_frame() -> FrameReport {
+    REPORT.with(|report| std::mem::take(&mut *report.borrow_mut()))
+}
+
+struct AccessibilityInner {
+    writer: Box<dyn Write + Send>,
+    // 上次输出的焦点
+    focus: Option<(&'static str, Rect)>,
+}
+
+/// 无障碍输出句柄，可克隆，所有克隆写入同一个目标。
+///
+/// # 示例
+/// ```rust
+/// let output = AccessibilityOutput::create("/tmp/a11y.fifo")?;
+/// updater.terminal().set_accessibility(Some(output.clone()));
+/// output.announce("欢迎使用");
+/// ```
+#[derive(Clone)]
+pub struct AccessibilityOutput {
+    inner: Arc<Mutex<AccessibilityInner>>,
+}
+
+impl AccessibilityOutput {
+    /// 写入任意目标，例如管道或套接字。
+    pub fn new(writer: impl Write + Send + 'static) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(AccessibilityInner {
+                writer: Box::new(writer),
+                focus: None,
+            })),
+        }
+    }
+
+    /// 追加写入文件，文件不存在时创建。
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self::new(file))
+    }
+
+    /// 立即输出一条通知。
+    pub fn announce(&self, message: &str) {
+        let mut inner = self.inner.lock().unwrap();
+        let _ = writeln!(inner.writer, "通知: {message}");
+        let _ = inner.writer.flush();
+    }
+
+    // 输出本帧的通知和焦点变化，buffer 为绘制完成的画面
+    fn capture(&self, buffer: &Buffer, report: FrameReport) {
+        let mut inner = self.inner.lock().unwrap();
+        for message in &report.announcements {
+            let _ = writeln!(inner.writer, "通知: {message}");
+        }
+        if report.focus != inner.focus {
+            if let Some((component, area)) = report.focus {
+                let text = linearize(buffer, area);
+                let _ = writeln!(inner.writer, "焦点: {component} {text}");
+            }
+            inner.focus = report.focus;
+        }
+        let _ = inner.writer.flush();
+    }
+}
+
+/// 输出本帧的无障碍信息，每次绘制后调用。
+pub(crate) fn capture_frame(output: Option<&AccessibilityOutput>, buffer: &Buffer) {
+    let report = take_frame();
+    if let Some(output) = output {
+        output.capture(buffer, report);
+    }
+}
+
+// 按行读取区域内的文本，去掉行首尾空白并跳过空行，宽字符只取一次
+fn linearize(buffer: &Buffer, area: Rect) -> String {
+    let area = area.intersection(buffer.area);
+    let mut lines = Vec::new();
+    for y in area.top()..area.bottom() {
+        let mut line = String::new();
+        let mut x = area.left();
+        while x < area.right() {
+            let symbol = buffer[(x, y)].symbol();
+            line.push_str(symbol);
+            x += symbol.width().max(1) as u16;
+        }
+        let line = line.trim();
+        if !line.is_empty() {
+            lines.push(line.to_string());
+        }
+    }
+    lines.join(" / ")
+}
@@ -0,0 +1,139 @@
+//! 终端能力检测，供组件在不支持某些特性的终端上降级显示。
+//!
+//! 能力在创建终端时检测一次，并作为上下文提供给所有组件：
+//! ```rust
+//! let caps = hooks.use_context::<TerminalCapabilities>();
+//! let frames = if caps.unicode { BRAILLE_FRAMES } else { ASCII_FRAMES };
+//! ```
+
+use std::{env, sync::OnceLock};
+
+use super::ColorDepth;
+
+/// 终端支持的扩展特性。
+///
+/// 大部分字段通过环境变量推断，无法确定时保守地视为不支持。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TerminalCapabilities {
+    /// 是否支持 24 位真彩色，不支持时应使用 256 色或基本色。
+    pub truecolor: bool,
+    /// 是否支持 256 色调色板。
+    pub ansi256: bool,
+    /// 是否可以安全输出非 ASCII 字符（UTF-8 环境），如框线、盲文点阵等。
+    pub unicode: bool,
+    /// 带 VS16 的 emoji 是否按双宽显示，为 false 时这类字符可能导致列错位。
+    pub wide_emoji: bool,
+    /// 是否支持 Sixel 图像。
+    pub sixel: bool,
+    /// 是否支持 Kitty 图像协议。
+    pub kitty_graphics: bool,
+    /// 是否支持 OSC 8 超链接。
+    pub hyperlinks: bool,
+    /// 是否支持 Kitty 键盘增强协议（区分 Esc、修饰键组合、按键释放等）。
+    pub keyboard_enhancement: bool,
+}
+
+impl TerminalCapabilities {
+    /// 根据环境变量检测当前终端的能力。
+    ///
+    /// 键盘增强需要向终端发送查询，只在 [`CrossTerminal`](crate::CrossTerminal) 创建时检测，此处恒为 false。
+    pub fn detect() -> Self {
+        let term = env::var("TERM").unwrap_or_default();
+        let program = env::var("TERM_PROGRAM").unwrap_or_default();
+        let is_kitty = env::var("KITTY_WINDOW_ID").is_ok() || term.contains("kitty");
+        let is_wezterm = program == "WezTerm" || term.contains("wezterm");
+        let is_ghostty = program == "ghostty" || term.contains("ghostty");
+        let is_foot = term.starts_with("foot");
+        // Linux 控制台和 macOS 自带终端的字符宽度与 unicode 标准不一致
+        let quirky_width = term == "linux" || program == "Apple_Terminal";
+
+        let truecolor = detect_truecolor(&term, &program);
+        Self {
+            truecolor,
+            ansi256: truecolor || term.contains("256color"),
+            unicode: detect_unicode(),
+            wide_emoji: !quirky_width,
+            sixel: is_foot
+                || is_wezterm
+                || term.contains("sixel")
+                || term.starts_with("mlterm")
+                || matches!(program.as_str(), "iTerm.app" | "mlterm"),
+            kitty_graphics: is_kitty || is_wezterm || is_ghostty,
+            hyperlinks: detect_hyperlinks(&term, &program),
+            keyboard_enhancement: false,
+        }
+    }
+
+    /// 按颜色能力得出的颜色深度，用于绘制时的颜色降级。
+    pub fn color_depth(&self) -> ColorDepth {
+        if self.truecolor {
+            ColorDepth::TrueColor
+        } else if self.ansi256 {
+            ColorDepth::Ansi256
+        } else {
+            ColorDepth::Ansi16
+        }
+    }
+
+    /// 进程内缓存的环境变量检测结果，供组件树之外直接使用 widget 时参考。
+    pub fn current() -> Self {
+        static CURRENT: OnceLock<TerminalCapabilities> = OnceLock::new();
+        *CURRENT.get_or_init(Self::detect)
+    }
+}
+
+fn detect_truecolor(term: &str, program: &str) -> bool {
+    if env::var("COLORTERM").is_ok_and(|value| matches!(value.as_str(), "truecolor" | "24bit")) {
+        return true;
+    }
+    if env::var("WT_SESSION").is_ok() {
+        return true;
+    }
+    term.contains("truecolor")
+        || term.contains("direct")
+        || term.contains("kitty")
+        || matches!(
+            program,
+            "iTerm.app" | "WezTerm" | "vscode" | "ghostty" | "Hyper" | "rio"
+        )
+}
+
+fn detect_unicode() -> bool {
+    if cfg!(windows) {
+        return true;
+    }
+    // 按 locale 的优先级取第一个非空值
+    ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .find_map(|name| env::var(name).ok().filter(|value| !value.is_empty()))
+        .is_some_and(|locale| {
+            let locale = locale.to_ascii_lowercase();
+            locale.contains("utf-8") || locale.contains("utf8")
+        })
+}
+
+fn detect_hyperlinks(term: &str, program: &str) -> bool {
+    // FORCE_HYPERLINK 为社区通用约定，可强制开启或关闭
+    if let Ok(value) = env::var("FORCE_HYPERLINK") {
+        return !matches!(value.as_str(), "" | "0" | "false");
+    }
+    if env::var("TMUX").is_ok() {
+        // tmux 默认不透传 OSC 8
+        return false;
+    }
+    if matches!(
+        program,
+        "iTerm.app" | "WezTerm" | "vscode" | "ghostty" | "Hyper" | "rio"
+    ) {
+        return true;
+    }
+    if env::var("VTE_VERSION").is_ok_and(|v| v.parse::<u32>().is_ok_and(|v| v >= 5000)) {
+        return true;
+    }
+    if env::var("KITTY_WINDOW_ID").is_ok() || env::var("WT_SESSION").is_ok() {
+        return true;
+    }
+    ["kitty", "alacritty", "foot", "wezterm", "ghostty"]
+        .iter()
+        .any(|name| term.contains(name))
+}
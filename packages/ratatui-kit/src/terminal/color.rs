@@ -0,0 +1,127 @@
+//! 颜色降级：在只支持 256 色或 16 色的终端上，把 RGB 颜色映射到最接近的调色板颜色。
+
+use ratatui::{buffer::Buffer, style::Color};
+
+/// 终端的颜色深度。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorDepth {
+    /// 24 位真彩色，不做任何转换。
+    #[default]
+    TrueColor,
+    /// xterm 256 色调色板。
+    Ansi256,
+    /// 16 色基本调色板。
+    Ansi16,
+}
+
+// xterm 默认的 16 色调色板
+const ANSI16: [(Color, [u8; 3]); 16] = [
+    (Color::Black, [0, 0, 0]),
+    (Color::Red, [205, 0, 0]),
+    (Color::Green, [0, 205, 0]),
+    (Color::Yellow, [205, 205, 0]),
+    (Color::Blue, [0, 0, 238]),
+    (Color::Magenta, [205, 0, 205]),
+    (Color::Cyan, [0, 205, 205]),
+    (Color::Gray, [229, 229, 229]),
+    (Color::DarkGray, [127, 127, 127]),
+    (Color::LightRed, [255, 0, 0]),
+    (Color::LightGreen, [0, 255, 0]),
+    (Color::LightYellow, [255, 255, 0]),
+    (Color::LightBlue, [92, 92, 255]),
+    (Color::LightMagenta, [255, 0, 255]),
+    (Color::LightCyan, [0, 255, 255]),
+    (Color::White, [255, 255, 255]),
+];
+
+// 6x6x6 色彩立方体每一级的取值
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn distance(a: [u8; 3], b: [u8; 3]) -> u32 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(&a, &b)| (a as i32 - b as i32).pow(2) as u32)
+        .sum()
+}
+
+// 256 色调色板中索引对应的 RGB 值
+fn indexed_rgb(index: u8) -> [u8; 3] {
+    match index {
+        0..16 => ANSI16[index as usize].1,
+        16..232 => {
+            let i = index - 16;
+            [
+                CUBE_LEVELS[(i / 36) as usize],
+                CUBE_LEVELS[(i / 6 % 6) as usize],
+                CUBE_LEVELS[(i % 6) as usize],
+            ]
+        }
+        _ => {
+            let level = 8 + (index - 232) * 10;
+            [level; 3]
+        }
+    }
+}
+
+fn nearest_ansi256(rgb: [u8; 3]) -> u8 {
+    let cube_index = |value: u8| {
+        CUBE_LEVELS
+            .iter()
+            .enumerate()
+            .min_by_key(|&(_, &level)| (level as i32 - value as i32).abs())
+            .map_or(0, |(i, _)| i as u8)
+    };
+    let cube = 16 + 36 * cube_index(rgb[0]) + 6 * cube_index(rgb[1]) + cube_index(rgb[2]);
+
+    let average = (rgb.iter().map(|&c| c as u32).sum::<u32>() / 3) as u8;
+    let gray = 232 + (average.saturating_sub(3) / 10).min(23);
+
+    if distance(indexed_rgb(gray), rgb) < distance(indexed_rgb(cube), rgb) {
+        gray
+    } else {
+        cube
+    }
+}
+
+fn nearest_ansi16(rgb: [u8; 3]) -> Color {
+    ANSI16
+        .iter()
+        .min_by_key(|(_, value)| distance(*value, rgb))
+        .map_or(Color::Reset, |(color, _)| *color)
+}
+
+/// 颜色在 xterm 默认调色板下的 RGB 值，`Reset` 返回 None。
+pub(crate) fn color_rgb(color: Color) -> Option<[u8; 3]> {
+    match color {
+        Color::Reset => None,
+        Color::Rgb(r, g, b) => Some([r, g, b]),
+        Color::Indexed(index) => Some(indexed_rgb(index)),
+        named => ANSI16
+            .iter()
+            .find(|(color, _)| *color == named)
+            .map(|(_, rgb)| *rgb),
+    }
+}
+
+/// 把颜色转换为指定颜色深度下最接近的颜色，命名颜色和 `Reset` 保持不变。
+pub fn downgrade_color(color: Color, depth: ColorDepth) -> Color {
+    match (color, depth) {
+        (_, ColorDepth::TrueColor) => color,
+        (Color::Rgb(r, g, b), ColorDepth::Ansi256) => Color::Indexed(nearest_ansi256([r, g, b])),
+        (Color::Rgb(r, g, b), ColorDepth::Ansi16) => nearest_ansi16([r, g, b]),
+        (Color::Indexed(index), ColorDepth::Ansi16) => nearest_ansi16(indexed_rgb(index)),
+        _ => color,
+    }
+}
+
+/// 对缓冲区中所有单元格的前景色、背景色和下划线颜色做降级处理。
+pub fn downgrade_buffer(buffer: &mut Buffer, depth: ColorDepth) {
+    if depth == ColorDepth::TrueColor {
+        return;
+    }
+    for cell in buffer.content.iter_mut() {
+        cell.fg = downgrade_color(cell.fg, depth);
+        cell.bg = downgrade_color(cell.bg, depth);
+        cell.underline_color = downgrade_color(cell.underline_color, depth);
+    }
+}
@@ -0,0 +1,30 @@
+//! 终端控制命令：窗口标题、光标形状、响铃和桌面通知。
+
+/// 光标形状。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CursorShape {
+    /// 终端用户配置的默认形状。
+    #[default]
+    Default,
+    /// 方块。
+    Block,
+    /// 下划线。
+    Underline,
+    /// 竖线，常用于文本输入。
+    Bar,
+}
+
+/// 发送给终端的控制命令。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TerminalCommand {
+    /// 设置窗口标题，退出时恢复原标题。
+    SetTitle(String),
+    /// 设置光标形状及是否闪烁，退出时恢复默认形状。
+    SetCursorShape { shape: CursorShape, blinking: bool },
+    /// 响铃。
+    Bell,
+    /// 发送桌面通知（OSC 9），不支持的终端会忽略。
+    Notify(String),
+    /// 清空屏幕，下一帧完整重绘而不是只输出变化的部分。
+    Redraw,
+}
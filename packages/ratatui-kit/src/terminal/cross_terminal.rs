@@ -1,8 +1,15 @@
-use super::TerminalImpl;
-use crossterm::event::{self, EventStream};
+use super::{CursorShape, TerminalCapabilities, TerminalCommand, TerminalImpl};
+use crossterm::{
+    cursor::SetCursorStyle,
+    event::{self, DisableMouseCapture, EnableMouseCapture, EventStream},
+    execute,
+    terminal::SetTitle,
+};
 use futures::{StreamExt, stream::BoxStream};
-use ratatui::{Frame, TerminalOptions};
-use std::io::{self};
+use ratatui::{
+    Frame, TerminalOptions, Viewport, backend::TestBackend, layout::Size, prelude::CrosstermBackend,
+};
+use std::io::{self, Write};
 
 // ================== 终端核心功能实现 ==================
 
@@ -13,7 +20,25 @@ use std::io::{self};
 // enabled_keyboard_enhancement: 键盘增强功能状态
 // fullscreen: 是否启用全屏模式
 pub struct CrossTerminal {
-    terminal: ratatui::DefaultTerminal,
+    terminal: Backend,
+    mouse_capture: bool,
+    // 内联视口的当前高度，非内联视口为 None
+    inline_height: Option<u16>,
+    // 是否修改过标题/光标形状，退出时据此恢复
+    title_changed: bool,
+    cursor_changed: bool,
+}
+
+// 真实终端或用于离屏渲染的无头终端
+enum Backend {
+    Crossterm(ratatui::DefaultTerminal),
+    Headless(ratatui::Terminal<TestBackend>),
+}
+
+impl Backend {
+    fn is_headless(&self) -> bool {
+        matches!(self, Backend::Headless(_))
+    }
 }
 
 impl CrossTerminal {
@@ -21,14 +46,37 @@ impl CrossTerminal {
     // fullscreen: 是否启用备用屏幕（全屏模式）
     pub fn new() -> io::Result<Self> {
         Ok(Self {
-            terminal: ratatui::init(),
+            terminal: Backend::Crossterm(ratatui::init()),
+            mouse_capture: false,
+            inline_height: None,
+            title_changed: false,
+            cursor_changed: false,
         })
     }
 
     // 启用/禁用原始模式
     pub fn with_options(options: TerminalOptions) -> io::Result<Self> {
+        let inline_height = match options.viewport {
+            Viewport::Inline(height) => Some(height),
+            _ => None,
+        };
+        Ok(Self {
+            terminal: Backend::Crossterm(ratatui::init_with_options(options)),
+            mouse_capture: false,
+            inline_height,
+            title_changed: false,
+            cursor_changed: false,
+        })
+    }
+
+    // 创建不接管真实终端的无头实例，没有输入事件，用于导出截图等离屏渲染
+    pub fn headless(width: u16, height: u16) -> io::Result<Self> {
         Ok(Self {
-            terminal: ratatui::init_with_options(options),
+            terminal: Backend::Headless(ratatui::Terminal::new(TestBackend::new(width, height))?),
+            mouse_capture: false,
+            inline_height: None,
+            title_changed: false,
+            cursor_changed: false,
         })
     }
 }
@@ -38,6 +86,20 @@ impl CrossTerminal {
 impl Drop for CrossTerminal {
     // 析构函数：自动恢复终端原始状态
     fn drop(&mut self) {
+        if self.terminal.is_headless() {
+            return;
+        }
+        if self.mouse_capture {
+            let _ = execute!(io::stdout(), DisableMouseCapture);
+        }
+        if self.cursor_changed {
+            let _ = execute!(io::stdout(), SetCursorStyle::DefaultUserShape);
+        }
+        if self.title_changed {
+            // 从标题栈中恢复进入时保存的标题（XTWINOPS）
+            let _ = write!(io::stdout(), "\x1b[23;0t");
+            let _ = io::stdout().flush();
+        }
         ratatui::restore();
     }
 }
@@ -49,6 +111,9 @@ impl TerminalImpl for CrossTerminal {
 
     // 创建事件流
     fn event_stream(&mut self) -> io::Result<BoxStream<'static, Self::Event>> {
+        if self.terminal.is_headless() {
+            return Ok(futures::stream::pending().boxed());
+        }
         // 创建事件流并过滤错误
         Ok(EventStream::new()
             .filter_map(|event| async move { event.ok() })
@@ -68,19 +133,141 @@ impl TerminalImpl for CrossTerminal {
         )
     }
 
+    // 连续的鼠标移动、同一按键的拖拽（修饰键相同）以及尺寸变化只保留最新一个，
+    // 拖拽的处理器都以按下时的位置为基准，丢弃中间位置不影响结果
+    fn can_coalesce(previous: &Self::Event, next: &Self::Event) -> bool {
+        use event::{Event, MouseEventKind};
+        match (previous, next) {
+            (Event::Resize(..), Event::Resize(..)) => true,
+            (Event::Mouse(previous), Event::Mouse(next)) => {
+                previous.modifiers == next.modifiers
+                    && match (previous.kind, next.kind) {
+                        (MouseEventKind::Moved, MouseEventKind::Moved) => true,
+                        (MouseEventKind::Drag(a), MouseEventKind::Drag(b)) => a == b,
+                        _ => false,
+                    }
+            }
+            _ => false,
+        }
+    }
+
     fn draw<F>(&mut self, f: F) -> io::Result<()>
     where
         F: FnOnce(&mut Frame),
     {
-        self.terminal.draw(f)?;
-        Ok(())
+        match &mut self.terminal {
+            Backend::Crossterm(terminal) => terminal.draw(f).map(|_| ()),
+            Backend::Headless(terminal) => terminal.draw(f).map(|_| ()),
+        }
     }
 
     fn insert_before<F>(&mut self, height: u16, draw_fn: F) -> io::Result<()>
     where
         F: FnOnce(&mut ratatui::prelude::Buffer),
     {
-        self.terminal.insert_before(height, draw_fn)?;
+        match &mut self.terminal {
+            Backend::Crossterm(terminal) => terminal.insert_before(height, draw_fn),
+            Backend::Headless(terminal) => terminal.insert_before(height, draw_fn),
+        }
+    }
+
+    // ratatui 不支持直接修改内联视口高度：先清除旧视口，再从旧视口顶部按新高度重建终端
+    fn set_inline_height(&mut self, height: u16) -> io::Result<()> {
+        match (self.inline_height, &mut self.terminal) {
+            (Some(current), Backend::Crossterm(terminal)) if current != height => {
+                terminal.clear()?;
+                *terminal = ratatui::Terminal::with_options(
+                    CrosstermBackend::new(io::stdout()),
+                    TerminalOptions {
+                        viewport: Viewport::Inline(height),
+                    },
+                )?;
+                self.inline_height = Some(height);
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn size(&self) -> io::Result<Size> {
+        match &self.terminal {
+            Backend::Crossterm(terminal) => terminal.size(),
+            Backend::Headless(terminal) => terminal.size(),
+        }
+    }
+
+    fn capabilities(&mut self) -> TerminalCapabilities {
+        if self.terminal.is_headless() {
+            // 离屏渲染保留原始颜色，链接以纯文本输出
+            return TerminalCapabilities {
+                truecolor: true,
+                ansi256: true,
+                unicode: true,
+                wide_emoji: true,
+                ..TerminalCapabilities::default()
+            };
+        }
+        TerminalCapabilities {
+            keyboard_enhancement: crossterm::terminal::supports_keyboard_enhancement()
+                .unwrap_or(false),
+            ..TerminalCapabilities::detect()
+        }
+    }
+
+    fn control(&mut self, command: TerminalCommand) -> io::Result<()> {
+        if command == TerminalCommand::Redraw {
+            return match &mut self.terminal {
+                Backend::Crossterm(terminal) => terminal.clear(),
+                Backend::Headless(terminal) => terminal.clear(),
+            };
+        }
+        if self.terminal.is_headless() {
+            return Ok(());
+        }
+        let mut stdout = io::stdout();
+        match command {
+            TerminalCommand::SetTitle(title) => {
+                if !self.title_changed {
+                    // 首次修改前把原标题压入标题栈
+                    write!(stdout, "\x1b[22;0t")?;
+                    self.title_changed = true;
+                }
+                execute!(stdout, SetTitle(title))?;
+            }
+            TerminalCommand::SetCursorShape { shape, blinking } => {
+                let style = match (shape, blinking) {
+                    (CursorShape::Default, _) => SetCursorStyle::DefaultUserShape,
+                    (CursorShape::Block, true) => SetCursorStyle::BlinkingBlock,
+                    (CursorShape::Block, false) => SetCursorStyle::SteadyBlock,
+                    (CursorShape::Underline, true) => SetCursorStyle::BlinkingUnderScore,
+                    (CursorShape::Underline, false) => SetCursorStyle::SteadyUnderScore,
+                    (CursorShape::Bar, true) => SetCursorStyle::BlinkingBar,
+                    (CursorShape::Bar, false) => SetCursorStyle::SteadyBar,
+                };
+                self.cursor_changed = true;
+                execute!(stdout, style)?;
+            }
+            TerminalCommand::Bell => {
+                write!(stdout, "\x07")?;
+                stdout.flush()?;
+            }
+            TerminalCommand::Notify(message) => {
+                // 去掉控制字符，避免提前结束转义序列
+                let message = message.replace(|c: char| c.is_control(), " ");
+                write!(stdout, "\x1b]9;{message}\x07")?;
+                stdout.flush()?;
+            }
+            TerminalCommand::Redraw => {}
+        }
+        Ok(())
+    }
+
+    // 开启鼠标捕获，退出时在 Drop 中关闭
+    fn enable_mouse_capture(&mut self) -> io::Result<()> {
+        if !self.mouse_capture && !self.terminal.is_headless() {
+            execute!(io::stdout(), EnableMouseCapture)?;
+            self.mouse_capture = true;
+        }
         Ok(())
     }
 }
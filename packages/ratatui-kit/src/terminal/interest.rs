@@ -0,0 +1,75 @@
+//! 事件订阅的类别过滤：只把订阅者关心的事件复制到它的队列中，
+//! 减少鼠标移动等高频事件在大量订阅者之间分发的开销。
+
+use std::{io, ops::BitOr};
+
+use crossterm::event::Event;
+
+use super::{CrossTerminal, Terminal, TerminalEvents};
+
+/// 事件类别的集合，可用 `|` 组合。
+///
+/// # 示例
+/// ```rust
+/// hooks.use_events_of(EventInterest::KEY | EventInterest::RESIZE, move |event| {
+///     // 不会收到鼠标事件
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EventInterest(u8);
+
+impl EventInterest {
+    /// 按键事件。
+    pub const KEY: Self = Self(1);
+    /// 鼠标事件。
+    pub const MOUSE: Self = Self(1 << 1);
+    /// 终端尺寸变化。
+    pub const RESIZE: Self = Self(1 << 2);
+    /// 终端获得或失去焦点。
+    pub const FOCUS: Self = Self(1 << 3);
+    /// 粘贴。
+    pub const PASTE: Self = Self(1 << 4);
+    /// 全部事件。
+    pub const ALL: Self = Self(0b11111);
+
+    /// 是否包含 `other` 中的全部类别。
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// 事件是否属于集合中的类别。
+    pub fn matches(self, event: &Event) -> bool {
+        let kind = match event {
+            Event::Key(_) => Self::KEY,
+            Event::Mouse(_) => Self::MOUSE,
+            Event::Resize(..) => Self::RESIZE,
+            Event::FocusGained | Event::FocusLost => Self::FOCUS,
+            Event::Paste(_) => Self::PASTE,
+        };
+        self.contains(kind)
+    }
+}
+
+impl BitOr for EventInterest {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl Default for EventInterest {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl Terminal<CrossTerminal> {
+    // 只订阅指定类别的事件
+    pub fn events_of(&mut self, interest: EventInterest) -> io::Result<TerminalEvents<Event>> {
+        if interest == EventInterest::ALL {
+            return self.events();
+        }
+        self.events_filtered(move |event| interest.matches(event))
+    }
+}
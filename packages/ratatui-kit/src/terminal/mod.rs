@@ -1,20 +1,58 @@
-use futures::{Stream, StreamExt, stream::BoxStream};
-use ratatui::buffer::Buffer;
+use futures::{FutureExt, Stream, StreamExt, future, stream::BoxStream};
+use ratatui::{buffer::Buffer, layout::Size};
 use std::{
     collections::VecDeque,
+    env,
     fmt::Debug,
     io,
+    path::PathBuf,
     sync::{Arc, Mutex, Weak},
     task::{Poll, Waker},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    UpdatePriority,
+    render::scheduler::{self, PendingUpdates},
 };
 
 mod cross_terminal;
 pub use cross_terminal::CrossTerminal;
+mod clipboard;
+pub use clipboard::copy_to_clipboard;
+mod control;
+pub use control::{CursorShape, TerminalCommand};
+mod capabilities;
+pub use capabilities::TerminalCapabilities;
+mod preferences;
+pub use preferences::UserPreferences;
+mod color;
+pub(crate) use color::color_rgb;
+pub use color::{ColorDepth, downgrade_buffer, downgrade_color};
+mod throttle;
+use throttle::FrameThrottle;
+pub use throttle::RenderMode;
+mod recorder;
+pub use recorder::Recorder;
+mod accessibility;
+pub use accessibility::AccessibilityOutput;
+#[cfg(feature = "parallel")]
+pub(crate) use accessibility::replace_announcements;
+pub(crate) use accessibility::{queue_announcement, report_focus};
+mod interest;
+mod profiler;
+pub use interest::EventInterest;
+pub use profiler::{DurationStats, FrameSample, FrameStats, Profiler};
 
 pub trait TerminalImpl: Send {
     type Event: Clone + Debug;
     fn event_stream(&mut self) -> io::Result<BoxStream<'static, Self::Event>>;
     fn received_ctrl_c(event: Self::Event) -> bool;
+
+    // 紧随 previous 之后的 next 能否取代它（如连续的鼠标移动），默认不合并
+    fn can_coalesce(_previous: &Self::Event, _next: &Self::Event) -> bool {
+        false
+    }
     fn draw<F>(&mut self, f: F) -> io::Result<()>
     where
         F: FnOnce(&mut ratatui::Frame);
@@ -22,6 +60,39 @@ pub trait TerminalImpl: Send {
     fn insert_before<F>(&mut self, height: u16, draw_fn: F) -> io::Result<()>
     where
         F: FnOnce(&mut Buffer);
+
+    // 开启鼠标捕获，默认不支持
+    fn enable_mouse_capture(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    // 调整内联视口高度，非内联视口时忽略
+    fn set_inline_height(&mut self, _height: u16) -> io::Result<()> {
+        Ok(())
+    }
+
+    // 终端尺寸，默认不支持
+    fn size(&self) -> io::Result<Size> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    // 执行控制命令（标题、光标形状、响铃等），默认忽略
+    fn control(&mut self, _command: TerminalCommand) -> io::Result<()> {
+        Ok(())
+    }
+
+    // 检测终端能力，默认只根据环境变量推断
+    fn capabilities(&mut self) -> TerminalCapabilities {
+        TerminalCapabilities::detect()
+    }
+}
+
+// 追加事件，可以取代队尾事件时直接替换
+fn push_coalesced<T: TerminalImpl>(queue: &mut VecDeque<T::Event>, event: T::Event) {
+    match queue.back_mut() {
+        Some(last) if T::can_coalesce(last, &event) => *last = event,
+        _ => queue.push_back(event),
+    }
 }
 
 // ================== 发布订阅模式核心组件 ==================
@@ -34,6 +105,15 @@ struct TerminalEventsInner<T> {
     waker: Option<Waker>,
 }
 
+type EventFilter<E> = Box<dyn Fn(&E) -> bool + Send + Sync>;
+
+// 订阅者：弱引用的事件队列、可选的事件过滤器，以及订阅时所在的组件树
+struct Subscriber<E> {
+    inner: Weak<Mutex<TerminalEventsInner<E>>>,
+    filter: Option<EventFilter<E>>,
+    tree: Option<Arc<PendingUpdates>>,
+}
+
 // 事件流封装结构
 // inner: 使用Arc+Mutex实现线程安全的事件队列共享
 pub struct TerminalEvents<T> {
@@ -69,8 +149,25 @@ where
 {
     inner: Box<T>,
     event_stream: BoxStream<'static, T::Event>,
-    subscribers: Vec<Weak<Mutex<TerminalEventsInner<T::Event>>>>,
+    // 事件流已结束，之后不再轮询
+    events_closed: bool,
+    subscribers: Vec<Subscriber<T::Event>>,
     received_ctrl_c: bool,
+    inline_height: Option<u16>,
+    capabilities: TerminalCapabilities,
+    color_depth: ColorDepth,
+    render_mode: RenderMode,
+    throttle: FrameThrottle,
+    // 各优先级的帧预算，按 UpdatePriority 的顺序
+    frame_budgets: [Duration; 3],
+    recorder: Option<Recorder>,
+    profiler: Profiler,
+    // 是否在画面右上角显示性能统计
+    hud: bool,
+    // 通过环境变量开启录制时，退出时保存到的路径
+    record_path: Option<PathBuf>,
+    accessibility: Option<AccessibilityOutput>,
+    preferences: UserPreferences,
 }
 
 impl<T> Terminal<T>
@@ -79,10 +176,31 @@ where
 {
     pub fn new(inner: T) -> io::Result<Self> {
         let mut inner = Box::new(inner);
+        // 需在开始读取事件前检测，避免查询的响应被事件流读走
+        let capabilities = inner.capabilities();
+        let record_path = env::var_os("RATATUI_KIT_RECORD").map(PathBuf::from);
         Ok(Self {
             event_stream: inner.event_stream()?,
+            events_closed: false,
             subscribers: Vec::new(),
             received_ctrl_c: false,
+            inline_height: None,
+            color_depth: capabilities.color_depth(),
+            render_mode: RenderMode::default(),
+            throttle: FrameThrottle::default(),
+            frame_budgets: [
+                UpdatePriority::Background.default_budget(),
+                UpdatePriority::Animation.default_budget(),
+                UpdatePriority::Input.default_budget(),
+            ],
+            recorder: record_path.as_ref().map(|_| Recorder::new()),
+            profiler: Profiler::new(),
+            hud: env::var_os("RATATUI_KIT_HUD").is_some_and(|value| value != "0"),
+            record_path,
+            accessibility: env::var_os("RATATUI_KIT_A11Y")
+                .and_then(|path| AccessibilityOutput::create(path).ok()),
+            preferences: UserPreferences::detect(&capabilities),
+            capabilities,
             inner,
         })
     }
@@ -95,18 +213,180 @@ where
     where
         F: FnOnce(&mut ratatui::Frame),
     {
-        self.inner.draw(f)
+        let depth = self.color_depth;
+        let started = Instant::now();
+        let recorder = self.recorder.as_ref();
+        let accessibility = self.accessibility.as_ref();
+        let preferences = self.preferences;
+        let hud = self.hud.then(|| self.profiler.stats());
+        // 闭包返回后 ratatui 才把差异写入后端并刷新，限速只统计这部分耗时
+        let mut flushing = started;
+        let result = self.inner.draw(|frame| {
+            f(frame);
+            accessibility::capture_frame(accessibility, frame.buffer_mut());
+            preferences.apply(frame.buffer_mut());
+            if let Some(stats) = &hud {
+                profiler::draw_hud(frame, stats);
+            }
+            downgrade_buffer(frame.buffer_mut(), depth);
+            if let Some(recorder) = recorder {
+                recorder.capture(frame.buffer_mut());
+            }
+            flushing = Instant::now();
+        });
+        self.throttle.record(flushing);
+        self.profiler.record_frame(started);
+        result
     }
 
     pub fn insert_before<F>(&mut self, height: u16, draw_fn: F) -> io::Result<()>
     where
         F: FnOnce(&mut Buffer),
     {
-        self.inner.insert_before(height, draw_fn)
+        let depth = self.color_depth;
+        let preferences = self.preferences;
+        self.inner.insert_before(height, |buffer| {
+            draw_fn(buffer);
+            preferences.apply(buffer);
+            downgrade_buffer(buffer, depth);
+        })
+    }
+
+    // 绘制时使用的颜色深度，默认按终端能力检测，设为 TrueColor 可关闭颜色降级
+    pub fn color_depth(&self) -> ColorDepth {
+        self.color_depth
+    }
+
+    pub fn set_color_depth(&mut self, depth: ColorDepth) {
+        self.color_depth = depth;
+    }
+
+    // 重绘模式，默认立即重绘，可设为 RenderMode::detect() 在 SSH 会话中自适应限速
+    pub fn render_mode(&self) -> RenderMode {
+        self.render_mode
+    }
+
+    pub fn set_render_mode(&mut self, mode: RenderMode) {
+        self.render_mode = mode;
+    }
+
+    // 开始录制之后绘制的每一帧，已在录制时返回同一个录制器
+    pub fn start_recording(&mut self) -> Recorder {
+        self.recorder.get_or_insert_with(Recorder::new).clone()
+    }
+
+    // 停止录制并返回录制器，可用于保存 .cast 文件
+    pub fn stop_recording(&mut self) -> Option<Recorder> {
+        self.record_path = None;
+        self.recorder.take()
+    }
+
+    // 无障碍输出，开启后每帧输出焦点变化和通知
+    pub fn accessibility(&self) -> Option<AccessibilityOutput> {
+        self.accessibility.clone()
+    }
+
+    pub fn set_accessibility(&mut self, output: Option<AccessibilityOutput>) {
+        self.accessibility = output;
+    }
+
+    // 某个优先级的帧预算，即该优先级的更新距上一帧至少间隔多久才重绘
+    pub fn frame_budget(&self, priority: UpdatePriority) -> Duration {
+        self.frame_budgets[priority as usize]
+    }
+
+    // 设置帧预算，例如把动画和后台数据的预算设为 0 以恢复每次变化立即重绘
+    pub fn set_frame_budget(&mut self, priority: UpdatePriority, budget: Duration) {
+        self.frame_budgets[priority as usize] = budget;
+    }
+
+    // 帧性能统计，记录更新、绘制耗时和输入延迟
+    pub fn profiler(&self) -> Profiler {
+        self.profiler.clone()
+    }
+
+    // 是否在画面右上角显示性能统计面板，也可通过环境变量 RATATUI_KIT_HUD=1 开启
+    pub fn set_hud(&mut self, show: bool) {
+        self.hud = show;
+    }
+
+    // 标记一帧开始更新组件树，用于统计更新耗时
+    pub(crate) fn begin_frame(&self) {
+        self.profiler.begin_frame();
+    }
+
+    // 按重绘模式等待到下一帧允许绘制的时间，期间的状态变化会合并到同一帧
+    pub(crate) async fn throttle(&self) {
+        if let Some(delay) = self.throttle.delay(self.render_mode) {
+            futures_timer::Delay::new(delay).await;
+        }
+    }
+
+    // 开启鼠标捕获，之后可接收点击、拖拽等鼠标事件
+    pub fn enable_mouse_capture(&mut self) -> io::Result<()> {
+        self.inner.enable_mouse_capture()
+    }
+
+    // 终端尺寸
+    pub fn size(&self) -> io::Result<Size> {
+        self.inner.size()
+    }
+
+    // 启动时检测到的终端能力
+    pub fn capabilities(&self) -> TerminalCapabilities {
+        self.capabilities
+    }
+
+    // 用户的显示偏好，默认从环境变量读取
+    pub fn preferences(&self) -> UserPreferences {
+        self.preferences
+    }
+
+    pub fn set_preferences(&mut self, preferences: UserPreferences) {
+        self.preferences = preferences;
+    }
+
+    // 执行终端控制命令，减少动画时光标不闪烁
+    pub fn control(&mut self, command: TerminalCommand) -> io::Result<()> {
+        let command = match command {
+            TerminalCommand::SetCursorShape { shape, .. } if self.preferences.reduced_motion => {
+                TerminalCommand::SetCursorShape {
+                    shape,
+                    blinking: false,
+                }
+            }
+            command => command,
+        };
+        self.inner.control(command)
+    }
+
+    // 请求调整内联视口高度，在下一次绘制前生效
+    pub fn request_inline_height(&mut self, height: u16) {
+        self.inline_height = Some(height);
+    }
+
+    // 应用最近一次请求的内联视口高度
+    pub(crate) fn apply_inline_height(&mut self) -> io::Result<()> {
+        match self.inline_height.take() {
+            Some(height) => self.inner.set_inline_height(height),
+            None => Ok(()),
+        }
     }
 
     // 事件订阅方法
     pub fn events(&mut self) -> io::Result<TerminalEvents<T::Event>> {
+        Ok(self.subscribe(None))
+    }
+
+    // 只订阅 filter 返回 true 的事件，其余事件不会复制到该订阅者的队列
+    pub fn events_filtered<F>(&mut self, filter: F) -> io::Result<TerminalEvents<T::Event>>
+    where
+        F: Fn(&T::Event) -> bool + Send + Sync + 'static,
+    {
+        Ok(self.subscribe(Some(Box::new(filter))))
+    }
+
+    fn subscribe(&mut self, filter: Option<EventFilter<T::Event>>) -> TerminalEvents<T::Event> {
         // 创建新的事件队列实例
         let inner = Arc::new(Mutex::new(TerminalEventsInner {
             pending: VecDeque::new(),
@@ -114,37 +394,131 @@ where
         }));
 
         // 添加弱引用订阅者
-        self.subscribers.push(Arc::downgrade(&inner));
+        self.subscribers.push(Subscriber {
+            inner: Arc::downgrade(&inner),
+            filter,
+            tree: scheduler::current_tree(),
+        });
 
-        Ok(TerminalEvents { inner })
+        TerminalEvents { inner }
     }
 
-    // 异步事件分发主循环
+    // 异步事件分发主循环，事件流结束时返回，之后的调用不再返回
     pub async fn wait(&mut self) {
+        if self.events_closed {
+            return future::pending().await;
+        }
         while let Some(event) = self.event_stream.next().await {
-            // 检查是否收到Ctrl+C
-            self.received_ctrl_c = T::received_ctrl_c(event.clone());
-            if self.received_ctrl_c {
-                return; // 终止循环
+            // 取出已经到达的一批事件，合并连续的鼠标移动、拖拽和尺寸变化后再分发
+            let mut burst = VecDeque::from([event]);
+            loop {
+                match self.event_stream.next().now_or_never() {
+                    Some(Some(event)) => push_coalesced::<T>(&mut burst, event),
+                    Some(None) => {
+                        self.events_closed = true;
+                        break;
+                    }
+                    None => break,
+                }
             }
+            for event in burst {
+                self.dispatch(event);
+                if self.received_ctrl_c {
+                    return; // 终止循环
+                }
+            }
+            if self.events_closed {
+                return;
+            }
+        }
+        self.events_closed = true;
+    }
 
-            // 遍历所有订阅者分发事件
-            self.subscribers.retain(|subscriber| {
-                if let Some(subscriber) = subscriber.upgrade() {
-                    let mut subscriber = subscriber.lock().unwrap();
-                    // 将事件加入订阅者队列
-                    subscriber.pending.push_back(event.clone());
+    // 输入事件流是否已结束
+    pub fn events_closed(&self) -> bool {
+        self.events_closed
+    }
 
-                    // 唤醒订阅者任务
-                    if let Some(waker) = subscriber.waker.take() {
-                        waker.wake(); // 触发任务继续执行
-                    }
+    // 把事件分发给所有订阅者，收到 Ctrl+C 时只记录标记，不分发
+    pub fn dispatch(&mut self, event: T::Event) {
+        // 检查是否收到Ctrl+C
+        self.received_ctrl_c = T::received_ctrl_c(event.clone());
+        if self.received_ctrl_c {
+            return;
+        }
+        self.profiler.record_input();
+
+        // 遍历所有订阅者分发事件
+        self.subscribers.retain(|subscriber| {
+            if let Some(filter) = &subscriber.filter {
+                // 不关心的事件不复制，但仍要检查订阅者是否已失效
+                if !filter(&event) {
+                    return subscriber.inner.strong_count() > 0;
+                }
+            }
+            if let Some(inner) = subscriber.inner.upgrade() {
+                // 收到事件的组件树按输入优先级调度
+                if let Some(tree) = &subscriber.tree {
+                    tree.mark(UpdatePriority::Input);
+                }
+                let mut subscriber = inner.lock().unwrap();
+                // 将事件加入订阅者队列，与尚未处理的同类事件合并，每帧只处理最新的一个
+                push_coalesced::<T>(&mut subscriber.pending, event.clone());
 
-                    true // 保留有效订阅者
-                } else {
-                    false // 移除失效订阅者
+                // 唤醒订阅者任务
+                if let Some(waker) = subscriber.waker.take() {
+                    waker.wake(); // 触发任务继续执行
                 }
-            });
+
+                true // 保留有效订阅者
+            } else {
+                false // 移除失效订阅者
+            }
+        });
+    }
+}
+
+impl<T> Drop for Terminal<T>
+where
+    T: TerminalImpl,
+{
+    fn drop(&mut self) {
+        // 通过 RATATUI_KIT_RECORD 开启的录制在退出时自动保存
+        if let (Some(recorder), Some(path)) = (&self.recorder, &self.record_path) {
+            let _ = recorder.save(path);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::KeyCode;
+    use futures::{FutureExt, StreamExt, executor::block_on, stream};
+
+    use super::{CrossTerminal, Terminal};
+    use crate::test_util::keys;
+
+    #[test]
+    fn wait_stops_at_end_of_stream() {
+        let mut terminal = Terminal::new(CrossTerminal::headless(10, 2).unwrap()).unwrap();
+        // unfold 结束后再被轮询会 panic
+        terminal.event_stream = stream::unfold(
+            keys([KeyCode::Char('b'), KeyCode::Char('a')]),
+            |mut events| async move { events.pop().map(|event| (event, events)) },
+        )
+        .boxed();
+        let mut events = terminal.events().unwrap();
+
+        block_on(terminal.wait());
+        assert!(terminal.events_closed());
+        assert_eq!(
+            events.next().now_or_never().flatten(),
+            keys([KeyCode::Char('a')]).pop()
+        );
+        assert_eq!(
+            events.next().now_or_never().flatten(),
+            keys([KeyCode::Char('b')]).pop()
+        );
+        assert!(terminal.wait().now_or_never().is_none());
+    }
+}
@@ -0,0 +1,154 @@
+//! 用户偏好：高对比度、减少动画和仅 ASCII 字符，与终端能力一起作为根上下文提供给所有组件。
+//!
+//! 偏好在创建终端时从环境变量读取，也可以通过 [`Terminal::set_preferences`](crate::Terminal::set_preferences) 修改：
+//! - `RATATUI_KIT_HIGH_CONTRAST=1`：去掉暗淡修饰，深灰色文字改用终端默认前景色；
+//! - `RATATUI_KIT_REDUCED_MOTION=1`：关闭侧边栏展开、平滑滚动等过渡动画，直接显示最终状态，
+//!   闪烁的文字和光标改为常亮；
+//! - `RATATUI_KIT_ASCII=1`：把框线、方块、盲文点阵以及圆点、星号、对勾等控件符号替换为 ASCII 字符，
+//!   箭头、省略号等文字中常见的字符保持不变，不设置时在非 UTF-8 环境下自动开启。
+//!
+//! 高对比度、闪烁和 ASCII 替换在每帧绘制完成后作用于整个画面，内置组件和自定义组件无需处理；
+//! 带动画的组件通过上下文读取 `reduced_motion`：
+//! ```rust
+//! let preferences = UserPreferences::current(updater);
+//! let frames = if preferences.reduced_motion { 0 } else { props.animation_frames };
+//! ```
+
+use std::env;
+
+use ratatui::{
+    buffer::Buffer,
+    style::{Color, Modifier},
+};
+
+use super::TerminalCapabilities;
+use crate::ComponentUpdater;
+
+/// 用户的显示偏好。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct UserPreferences {
+    /// 高对比度：去掉暗淡修饰，深灰色文字改用默认前景色。
+    pub high_contrast: bool,
+    /// 减少动画：过渡动画直接跳到最终状态，文字不闪烁。
+    pub reduced_motion: bool,
+    /// 仅使用 ASCII 字符绘制框线和控件符号。
+    pub ascii_only: bool,
+}
+
+impl UserPreferences {
+    /// 从环境变量读取偏好，未设置 `RATATUI_KIT_ASCII` 时按终端是否支持 unicode 决定。
+    pub fn detect(capabilities: &TerminalCapabilities) -> Self {
+        Self {
+            high_contrast: flag("RATATUI_KIT_HIGH_CONTRAST").unwrap_or(false),
+            reduced_motion: flag("RATATUI_KIT_REDUCED_MOTION").unwrap_or(false),
+            ascii_only: flag("RATATUI_KIT_ASCII").unwrap_or(!capabilities.unicode),
+        }
+    }
+
+    /// 当前组件所在树的偏好，不在组件树中时返回默认值。
+    pub fn current(updater: &ComponentUpdater) -> Self {
+        updater
+            .get_context::<UserPreferences>()
+            .map(|preferences| *preferences)
+            .unwrap_or_default()
+    }
+
+    /// 按高对比度、减少动画和 ASCII 偏好处理缓冲区中的所有单元格。
+    pub fn apply(&self, buffer: &mut Buffer) {
+        if !self.high_contrast && !self.reduced_motion && !self.ascii_only {
+            return;
+        }
+        for cell in buffer.content.iter_mut() {
+            if self.reduced_motion {
+                cell.modifier
+                    .remove(Modifier::SLOW_BLINK | Modifier::RAPID_BLINK);
+            }
+            if self.high_contrast {
+                cell.modifier.remove(Modifier::DIM);
+                if cell.fg == Color::DarkGray {
+                    cell.fg = Color::Reset;
+                }
+            }
+            if self.ascii_only {
+                let mut chars = cell.symbol().chars();
+                if let (Some(c), None) = (chars.next(), chars.next()) {
+                    if let Some(ascii) = ascii_glyph(c) {
+                        cell.set_char(ascii);
+                    }
+                }
+            }
+        }
+    }
+}
+
+// 读取布尔型环境变量，未设置时返回 None
+fn flag(name: &str) -> Option<bool> {
+    env::var(name)
+        .ok()
+        .map(|value| !matches!(value.as_str(), "" | "0" | "false"))
+}
+
+// 框线和控件符号对应的 ASCII 字符，文字中也会出现的字符（箭头、省略号、乘号等）返回 None
+fn ascii_glyph(c: char) -> Option<char> {
+    let ascii = match c {
+        '─' | '━' | '═' | '╌' | '╍' | '┄' | '┅' | '┈' | '┉' | '╴' | '╶' | '╸' | '╺' => {
+            '-'
+        }
+        '│' | '┃' | '║' | '╎' | '╏' | '┆' | '┇' | '┊' | '┋' | '╵' | '╷' | '╹' | '╻' => {
+            '|'
+        }
+        '\u{2500}'..='\u{257f}' => '+',
+        '\u{2580}'..='\u{259f}' => '#',
+        '\u{2800}' => ' ',
+        '\u{2801}'..='\u{28ff}' => '.',
+        // 实心和空心符号分开替换，保留选中、评分等状态的区别
+        '●' | '◉' | '•' | '★' => '*',
+        '○' | '◯' => 'o',
+        '■' | '▪' => '#',
+        '□' | '▫' | '☆' => '.',
+        '✔' | '✓' => 'v',
+        '✘' | '✗' => 'x',
+        '▲' | '△' | '▴' => '^',
+        '▼' | '▽' | '▾' => 'v',
+        '▶' | '▸' | '►' => '>',
+        '◀' | '◂' | '◄' => '<',
+        _ => return None,
+    };
+    Some(ascii)
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{
+        buffer::Buffer,
+        layout::Rect,
+        style::{Modifier, Style},
+    };
+
+    use super::UserPreferences;
+
+    #[test]
+    fn ascii_keeps_text_characters() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        buffer.set_string(0, 0, "┌●→…×·─┐", Style::default());
+        let preferences = UserPreferences {
+            ascii_only: true,
+            ..Default::default()
+        };
+        preferences.apply(&mut buffer);
+        let text: String = buffer.content.iter().map(|cell| cell.symbol()).collect();
+        assert_eq!(text, "+*→…×·-+");
+    }
+
+    #[test]
+    fn reduced_motion_removes_blink() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 1));
+        buffer.content[0].modifier = Modifier::SLOW_BLINK | Modifier::BOLD;
+        let preferences = UserPreferences {
+            reduced_motion: true,
+            ..Default::default()
+        };
+        preferences.apply(&mut buffer);
+        assert_eq!(buffer.content[0].modifier, Modifier::BOLD);
+    }
+}
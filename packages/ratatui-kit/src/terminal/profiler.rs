@@ -0,0 +1,240 @@
+//! 帧性能统计：记录每一帧的更新、绘制耗时以及输入事件到画面呈现的延迟。
+//!
+//! 通过 [`Terminal::profiler`](crate::Terminal::profiler) 或组件中的 `hooks.use_context::<Profiler>()`
+//! 读取统计；设置环境变量 `RATATUI_KIT_HUD=1` 或调用
+//! [`Terminal::set_hud`](crate::Terminal::set_hud) 可在右上角显示统计面板。
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+
+// 保留最近的帧数
+const WINDOW: usize = 240;
+
+/// 一帧的耗时记录。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameSample {
+    /// 绘制完成的时间。
+    pub presented_at: Instant,
+    /// 更新组件树的耗时。
+    pub update: Duration,
+    /// 绘制并输出到终端的耗时。
+    pub draw: Duration,
+    /// 本帧呈现的最早一个输入事件从分发到呈现的延迟，没有新输入时为 None。
+    pub input_latency: Option<Duration>,
+}
+
+impl FrameSample {
+    /// 整帧耗时，即更新与绘制之和。
+    pub fn total(&self) -> Duration {
+        self.update + self.draw
+    }
+}
+
+/// 一组耗时的统计值。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DurationStats {
+    pub count: usize,
+    pub mean: Duration,
+    pub p50: Duration,
+    pub p95: Duration,
+    pub max: Duration,
+}
+
+impl DurationStats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+        samples.sort_unstable();
+        let count = samples.len();
+        let percentile = |p: usize| samples[((count - 1) * p).div_ceil(100)];
+        Self {
+            count,
+            mean: samples.iter().sum::<Duration>() / count as u32,
+            p50: percentile(50),
+            p95: percentile(95),
+            max: samples[count - 1],
+        }
+    }
+}
+
+/// 最近若干帧（最多 240 帧）的统计。
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FrameStats {
+    /// 开始统计以来绘制的总帧数。
+    pub frames: u64,
+    /// 统计窗口内的平均帧率。
+    pub fps: f64,
+    /// 整帧耗时。
+    pub frame_time: DurationStats,
+    /// 更新组件树的耗时。
+    pub update_time: DurationStats,
+    /// 绘制并输出到终端的耗时。
+    pub draw_time: DurationStats,
+    /// 输入事件到画面呈现的延迟。
+    pub input_latency: DurationStats,
+}
+
+#[derive(Default)]
+struct ProfilerInner {
+    frames: u64,
+    samples: VecDeque<FrameSample>,
+    frame_started: Option<Instant>,
+    // 尚未呈现的最早一个输入事件的分发时间
+    pending_input: Option<Instant>,
+}
+
+/// 帧性能统计句柄，可克隆，所有克隆共享同一份数据。
+///
+/// # 示例
+/// ```rust
+/// let profiler = hooks.use_context::<Profiler>().clone();
+/// let stats = profiler.stats();
+/// println!("{:.1} fps, 输入延迟 p95 {:?}", stats.fps, stats.input_latency.p95);
+/// ```
+#[derive(Clone, Default)]
+pub struct Profiler {
+    inner: Arc<Mutex<ProfilerInner>>,
+}
+
+impl Profiler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 标记一帧开始更新组件树。
+    pub(crate) fn begin_frame(&self) {
+        self.inner.lock().unwrap().frame_started = Some(Instant::now());
+    }
+
+    /// 记录一个输入事件的分发时间，同一帧内只保留最早的一个。
+    pub(crate) fn record_input(&self) {
+        self.inner
+            .lock()
+            .unwrap()
+            .pending_input
+            .get_or_insert_with(Instant::now);
+    }
+
+    /// 记录一帧的绘制，`draw_started` 为开始绘制的时间。
+    pub(crate) fn record_frame(&self, draw_started: Instant) {
+        let now = Instant::now();
+        let mut inner = self.inner.lock().unwrap();
+        let update = inner
+            .frame_started
+            .take()
+            .map_or(Duration::ZERO, |started| {
+                draw_started.saturating_duration_since(started)
+            });
+        let input_latency = inner.pending_input.take().map(|input| now - input);
+        inner.frames += 1;
+        if inner.samples.len() == WINDOW {
+            inner.samples.pop_front();
+        }
+        inner.samples.push_back(FrameSample {
+            presented_at: now,
+            update,
+            draw: now - draw_started,
+            input_latency,
+        });
+    }
+
+    /// 最近若干帧的耗时记录，按时间顺序排列。
+    pub fn samples(&self) -> Vec<FrameSample> {
+        self.inner.lock().unwrap().samples.iter().copied().collect()
+    }
+
+    /// 最近若干帧的统计。
+    pub fn stats(&self) -> FrameStats {
+        let inner = self.inner.lock().unwrap();
+        let samples = &inner.samples;
+        let collect = |f: fn(&FrameSample) -> Option<Duration>| {
+            DurationStats::from_samples(samples.iter().filter_map(f).collect())
+        };
+        let fps = match (samples.front(), samples.back()) {
+            (Some(first), Some(last)) if samples.len() > 1 => {
+                let span = (last.presented_at - first.presented_at).as_secs_f64();
+                if span > 0.0 {
+                    (samples.len() - 1) as f64 / span
+                } else {
+                    0.0
+                }
+            }
+            _ => 0.0,
+        };
+        FrameStats {
+            frames: inner.frames,
+            fps,
+            frame_time: collect(|sample| Some(sample.total())),
+            update_time: collect(|sample| Some(sample.update)),
+            draw_time: collect(|sample| Some(sample.draw)),
+            input_latency: collect(|sample| sample.input_latency),
+        }
+    }
+
+    /// 清空统计。
+    pub fn reset(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.frames = 0;
+        inner.samples.clear();
+        inner.pending_input = None;
+    }
+}
+
+fn millis(duration: Duration) -> String {
+    format!("{:.1}ms", duration.as_secs_f64() * 1000.0)
+}
+
+/// 在画面右上角绘制统计面板，显示的是此前各帧的统计。
+pub(crate) fn draw_hud(frame: &mut Frame, stats: &FrameStats) {
+    const WIDTH: u16 = 34;
+    const HEIGHT: u16 = 6;
+
+    let full = frame.area();
+    let area = Rect::new(
+        full.right().saturating_sub(WIDTH),
+        full.y,
+        WIDTH.min(full.width),
+        HEIGHT.min(full.height),
+    );
+    let lines = vec![
+        Line::from(format!("{:.1} fps，共 {} 帧", stats.fps, stats.frames)),
+        Line::from(format!(
+            "帧   {} p95 {}",
+            millis(stats.frame_time.mean),
+            millis(stats.frame_time.p95)
+        )),
+        Line::from(format!(
+            "更新 {} 绘制 {}",
+            millis(stats.update_time.mean),
+            millis(stats.draw_time.mean)
+        )),
+        Line::from(format!(
+            "输入 {} p95 {}",
+            millis(stats.input_latency.mean),
+            millis(stats.input_latency.p95)
+        )),
+    ];
+    let buffer = frame.buffer_mut();
+    Clear.render(area, buffer);
+    Paragraph::new(lines)
+        .style(Style::default().reset())
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().dim())
+                .title(Line::from("性能")),
+        )
+        .render(area, buffer);
+}
@@ -0,0 +1,230 @@
+//! 会话录制：记录每一帧的渲染结果和时间，导出为 asciinema v2 格式（.cast）。
+//!
+//! 设置环境变量 `RATATUI_KIT_RECORD=demo.cast` 即可在程序退出时自动保存录制文件，
+//! 也可以通过 [`Terminal::start_recording`](crate::Terminal::start_recording) 手动控制。
+//!
+//! 只记录视口内的内容，通过 `insert_before` 插入到视口上方的内容不会被录制。
+
+use std::{
+    fmt::Write as _,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+    sync::{Arc, Mutex},
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use ratatui::{
+    buffer::{Buffer, Cell},
+    style::{Color, Modifier},
+};
+
+struct CastEvent {
+    time: f64,
+    kind: &'static str,
+    data: String,
+}
+
+#[derive(Default)]
+struct RecorderInner {
+    started: Option<Instant>,
+    timestamp: u64,
+    size: Option<(u16, u16)>,
+    previous: Option<Buffer>,
+    events: Vec<CastEvent>,
+}
+
+/// 帧录制器句柄，可克隆，所有克隆共享同一份录制数据。
+///
+/// # 示例
+/// ```rust
+/// let recorder = updater.terminal().start_recording();
+/// // ...
+/// recorder.save("demo.cast")?;
+/// ```
+#[derive(Clone, Default)]
+pub struct Recorder {
+    inner: Arc<Mutex<RecorderInner>>,
+}
+
+impl Recorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 记录一帧，只输出与上一帧相比发生变化的单元格。
+    pub fn capture(&self, buffer: &Buffer) {
+        let mut inner = self.inner.lock().unwrap();
+        let started = match inner.started {
+            Some(started) => started,
+            None => {
+                inner.timestamp = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |d| d.as_secs());
+                *inner.started.insert(Instant::now())
+            }
+        };
+        let time = started.elapsed().as_secs_f64();
+        let area = buffer.area;
+        let size = (area.width, area.height);
+
+        let mut data = String::new();
+        match inner.size {
+            None => inner.size = Some(size),
+            Some(previous) if previous != size => {
+                inner.size = Some(size);
+                inner.previous = None;
+                inner.events.push(CastEvent {
+                    time,
+                    kind: "r",
+                    data: format!("{}x{}", size.0, size.1),
+                });
+            }
+            _ => {}
+        }
+
+        let blank;
+        let previous = match &inner.previous {
+            Some(previous) => previous,
+            None => {
+                // 尺寸变化或第一帧时清屏后完整绘制
+                data.push_str("\x1b[0m\x1b[2J");
+                blank = Buffer::empty(area);
+                &blank
+            }
+        };
+
+        let mut last_pos: Option<(u16, u16)> = None;
+        let mut style = None;
+        for (x, y, cell) in previous.diff(buffer) {
+            let (x, y) = (x - area.x, y - area.y);
+            if !matches!(last_pos, Some((px, py)) if x == px + 1 && y == py) {
+                let _ = write!(data, "\x1b[{};{}H", y + 1, x + 1);
+            }
+            last_pos = Some((x, y));
+            let cell_style = (cell.fg, cell.bg, cell.modifier);
+            if style != Some(cell_style) {
+                push_sgr(&mut data, cell);
+                style = Some(cell_style);
+            }
+            data.push_str(cell.symbol());
+        }
+
+        inner.previous = Some(buffer.clone());
+        if !data.is_empty() {
+            inner.events.push(CastEvent {
+                time,
+                kind: "o",
+                data,
+            });
+        }
+    }
+
+    /// 最近录制的一帧。
+    #[cfg(test)]
+    pub(crate) fn last_frame(&self) -> Option<Buffer> {
+        self.inner.lock().unwrap().previous.clone()
+    }
+
+    /// 是否已录制到任何帧。
+    pub fn is_empty(&self) -> bool {
+        self.inner.lock().unwrap().events.is_empty()
+    }
+
+    /// 以 asciinema v2 格式写出录制内容。
+    pub fn write_to(&self, mut writer: impl Write) -> io::Result<()> {
+        let inner = self.inner.lock().unwrap();
+        let (width, height) = inner.size.unwrap_or((80, 24));
+        writeln!(
+            writer,
+            "{{\"version\": 2, \"width\": {width}, \"height\": {height}, \"timestamp\": {}}}",
+            inner.timestamp
+        )?;
+        for event in &inner.events {
+            writeln!(
+                writer,
+                "[{:.6}, \"{}\", \"{}\"]",
+                event.time,
+                event.kind,
+                escape_json(&event.data)
+            )?;
+        }
+        writer.flush()
+    }
+
+    /// 保存为 .cast 文件。
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.write_to(BufWriter::new(File::create(path)?))
+    }
+}
+
+fn escape_json(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn color_sgr(color: Color, foreground: bool) -> String {
+    let base = if foreground { 30 } else { 40 };
+    match color {
+        Color::Reset => (base + 9).to_string(),
+        Color::Black => base.to_string(),
+        Color::Red => (base + 1).to_string(),
+        Color::Green => (base + 2).to_string(),
+        Color::Yellow => (base + 3).to_string(),
+        Color::Blue => (base + 4).to_string(),
+        Color::Magenta => (base + 5).to_string(),
+        Color::Cyan => (base + 6).to_string(),
+        Color::Gray => (base + 7).to_string(),
+        Color::DarkGray => (base + 60).to_string(),
+        Color::LightRed => (base + 61).to_string(),
+        Color::LightGreen => (base + 62).to_string(),
+        Color::LightYellow => (base + 63).to_string(),
+        Color::LightBlue => (base + 64).to_string(),
+        Color::LightMagenta => (base + 65).to_string(),
+        Color::LightCyan => (base + 66).to_string(),
+        Color::White => (base + 67).to_string(),
+        Color::Indexed(index) => format!("{};5;{index}", base + 8),
+        Color::Rgb(r, g, b) => format!("{};2;{r};{g};{b}", base + 8),
+    }
+}
+
+// 先重置再设置单元格的完整样式
+fn push_sgr(data: &mut String, cell: &Cell) {
+    const MODIFIERS: [(Modifier, u8); 9] = [
+        (Modifier::BOLD, 1),
+        (Modifier::DIM, 2),
+        (Modifier::ITALIC, 3),
+        (Modifier::UNDERLINED, 4),
+        (Modifier::SLOW_BLINK, 5),
+        (Modifier::RAPID_BLINK, 6),
+        (Modifier::REVERSED, 7),
+        (Modifier::HIDDEN, 8),
+        (Modifier::CROSSED_OUT, 9),
+    ];
+
+    data.push_str("\x1b[0");
+    for (modifier, code) in MODIFIERS {
+        if cell.modifier.contains(modifier) {
+            let _ = write!(data, ";{code}");
+        }
+    }
+    let _ = write!(
+        data,
+        ";{};{}m",
+        color_sgr(cell.fg, true),
+        color_sgr(cell.bg, false)
+    );
+}
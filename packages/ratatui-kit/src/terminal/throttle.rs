@@ -0,0 +1,87 @@
+//! 帧率自适应：根据终端输出耗时限制重绘频率，适合通过慢速 SSH 连接使用。
+//!
+//! 默认每次变化立即重绘，需通过 [`Roots::render_mode`](crate::Roots::render_mode) 或
+//! [`Terminal::set_render_mode`](crate::Terminal::set_render_mode) 显式开启。
+
+use std::{
+    env,
+    time::{Duration, Instant},
+};
+
+/// 重绘模式。
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RenderMode {
+    /// 每次状态变化后立即重绘。
+    #[default]
+    Immediate,
+    /// 根据写入终端的耗时（输出阻塞时会变长）限制帧率，期间的多次变化合并为一帧。
+    ///
+    /// 两帧之间的间隔约为写入终端耗时的 4 倍，最长不超过 `max_interval`。
+    Adaptive { max_interval: Duration },
+}
+
+impl RenderMode {
+    /// 在 SSH 会话中使用 [`RenderMode::Adaptive`]，否则使用 [`RenderMode::Immediate`]。
+    ///
+    /// 不会自动生效，需把结果传给 [`Roots::render_mode`](crate::Roots::render_mode) 等。
+    pub fn detect() -> Self {
+        if env::var("SSH_CONNECTION").is_ok() || env::var("SSH_TTY").is_ok() {
+            Self::adaptive()
+        } else {
+            Self::Immediate
+        }
+    }
+
+    /// 默认参数的自适应模式，最长间隔 500ms。
+    pub fn adaptive() -> Self {
+        Self::Adaptive {
+            max_interval: Duration::from_millis(500),
+        }
+    }
+}
+
+// 帧间隔与绘制耗时的比例，即输出最多占用约 1/4 的时间
+const INTERVAL_FACTOR: u32 = 4;
+
+#[derive(Default)]
+pub(crate) struct FrameThrottle {
+    // 写入终端耗时的指数滑动平均
+    latency: Option<Duration>,
+    last_frame: Option<Instant>,
+}
+
+impl FrameThrottle {
+    /// 记录一次写入终端的耗时，`started` 为组件绘制完成、开始输出的时刻。
+    pub(crate) fn record(&mut self, started: Instant) {
+        let now = Instant::now();
+        let elapsed = now - started;
+        self.latency = Some(match self.latency {
+            Some(latency) => (latency * 7 + elapsed * 3) / 10,
+            None => elapsed,
+        });
+        self.last_frame = Some(now);
+    }
+
+    /// 距离下一帧允许绘制还需等待的时间。
+    pub(crate) fn delay(&self, mode: RenderMode) -> Option<Duration> {
+        let RenderMode::Adaptive { max_interval } = mode else {
+            return None;
+        };
+        let interval = (self.latency? * INTERVAL_FACTOR).min(max_interval);
+        interval.checked_sub(self.last_frame?.elapsed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn immediate_by_default() {
+        let mut throttle = FrameThrottle::default();
+        throttle.record(Instant::now() - Duration::from_millis(100));
+        assert_eq!(RenderMode::default(), RenderMode::Immediate);
+        assert_eq!(throttle.delay(RenderMode::default()), None);
+        assert!(throttle.delay(RenderMode::adaptive()).is_some());
+    }
+}
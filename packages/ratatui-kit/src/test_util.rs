@@ -0,0 +1,63 @@
+//! 单元测试共用的无头渲染辅助函数。
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use ratatui::buffer::Buffer;
+
+use crate::{App, CrossTerminal, ElementExt, Terminal};
+
+/// 在 40x10 的无头终端中挂载元素并渲染第一帧。
+pub(crate) fn mount<E: ElementExt>(element: &mut E) -> App<'_> {
+    let terminal = Terminal::new(CrossTerminal::headless(40, 10).unwrap()).unwrap();
+    let mut app = App::new(element, terminal).unwrap();
+    app.terminal_mut().start_recording();
+    app.render_once().unwrap();
+    app
+}
+
+/// 依次分发事件，每个事件处理完后渲染一帧。
+pub(crate) fn send(app: &mut App, events: impl IntoIterator<Item = Event>) {
+    for event in events {
+        app.handle_event(event);
+        app.poll_until_idle();
+        app.render_once().unwrap();
+    }
+}
+
+/// 挂载元素，依次分发事件，返回最后一帧的画面。
+pub(crate) fn run_events<E: ElementExt>(
+    mut element: E,
+    events: impl IntoIterator<Item = Event>,
+) -> Buffer {
+    let mut app = mount(&mut element);
+    send(&mut app, events);
+    last_frame(&mut app)
+}
+
+/// 最近渲染的一帧。
+pub(crate) fn last_frame(app: &mut App) -> Buffer {
+    app.terminal_mut()
+        .start_recording()
+        .last_frame()
+        .expect("no frame rendered")
+}
+
+/// 无修饰键的按键事件。
+pub(crate) fn keys(codes: impl IntoIterator<Item = KeyCode>) -> Vec<Event> {
+    codes
+        .into_iter()
+        .map(|code| Event::Key(KeyEvent::new(code, KeyModifiers::NONE)))
+        .collect()
+}
+
+/// 画面中的文本，每行一行。
+pub(crate) fn buffer_text(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    (area.top()..area.bottom())
+        .map(|y| {
+            (area.left()..area.right())
+                .map(|x| buffer[(x, y)].symbol())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
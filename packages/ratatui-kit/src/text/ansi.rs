@@ -0,0 +1,280 @@
+use std::mem;
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+};
+
+use super::width;
+
+// 制表符展开到的列宽
+const TAB_WIDTH: usize = 8;
+
+/// ANSI 转义序列解析器，把带颜色的终端输出转换为 ratatui 的样式文本。
+///
+/// 支持 SGR 样式（粗体、斜体、下划线等）、16 色、256 色和真彩色，
+/// 其余控制序列（光标移动、清屏、OSC 等）被忽略。
+/// 可以分多次调用 [`AnsiParser::push`] 输入，被截断在两次输入之间的转义序列会被正确拼接。
+///
+/// # 示例
+/// ```rust
+/// let mut parser = AnsiParser::new();
+/// parser.push("\x1b[31merror\x1b[0m: ");
+/// parser.push("file not found\n");
+/// let lines = parser.take_lines();
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct AnsiParser {
+    base: Style,
+    style: Style,
+    lines: Vec<Line<'static>>,
+    spans: Vec<Span<'static>>,
+    text: String,
+    line_width: usize,
+    // 尚未结束的转义序列
+    pending: String,
+}
+
+impl AnsiParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 指定基础样式，`ESC[0m` 重置后回到该样式。
+    pub fn with_style(style: Style) -> Self {
+        Self {
+            base: style,
+            style,
+            ..Default::default()
+        }
+    }
+
+    /// 当前样式。
+    pub fn style(&self) -> Style {
+        self.style
+    }
+
+    pub(crate) fn set_style(&mut self, style: Style) {
+        if style != self.style {
+            self.flush_span();
+            self.style = style;
+        }
+    }
+
+    /// 输入一段文本。
+    pub fn push(&mut self, input: &str) {
+        let input = if self.pending.is_empty() {
+            input.to_string()
+        } else {
+            mem::take(&mut self.pending) + input
+        };
+        let mut rest = input.as_str();
+        while let Some(index) = rest.find(['\x1b', '\n', '\r', '\t']) {
+            self.push_text(&rest[..index]);
+            rest = &rest[index..];
+            match rest.as_bytes()[0] {
+                b'\n' => {
+                    self.new_line();
+                    rest = &rest[1..];
+                }
+                // 回车只用于覆盖当前行（如进度条），这里直接忽略
+                b'\r' => rest = &rest[1..],
+                b'\t' => {
+                    let spaces = TAB_WIDTH - self.line_width % TAB_WIDTH;
+                    self.push_text(&" ".repeat(spaces));
+                    rest = &rest[1..];
+                }
+                _ => match escape_len(rest) {
+                    Some(len) => {
+                        if let Some(params) = rest[..len]
+                            .strip_prefix("\x1b[")
+                            .and_then(|s| s.strip_suffix('m'))
+                        {
+                            let style = apply_sgr(self.style, self.base, params);
+                            self.set_style(style);
+                        }
+                        rest = &rest[len..];
+                    }
+                    None => {
+                        self.pending = rest.to_string();
+                        return;
+                    }
+                },
+            }
+        }
+        self.push_text(rest);
+    }
+
+    /// 取出已经结束（遇到换行）的行。
+    pub fn take_lines(&mut self) -> Vec<Line<'static>> {
+        mem::take(&mut self.lines)
+    }
+
+    /// 结束输入，返回全部内容，最后一行即使没有换行符也会包含在内。
+    pub fn finish(mut self) -> Text<'static> {
+        self.flush_span();
+        if !self.spans.is_empty() || self.lines.is_empty() {
+            self.lines.push(Line::from(mem::take(&mut self.spans)));
+        }
+        Text::from(self.lines)
+    }
+
+    fn push_text(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        // 去掉剩余的控制字符，避免破坏终端状态
+        for c in text.chars().filter(|c| !c.is_control()) {
+            self.text.push(c);
+        }
+        self.line_width += width(text);
+    }
+
+    fn flush_span(&mut self) {
+        if !self.text.is_empty() {
+            self.spans
+                .push(Span::styled(mem::take(&mut self.text), self.style));
+        }
+    }
+
+    fn new_line(&mut self) {
+        self.flush_span();
+        self.lines.push(Line::from(mem::take(&mut self.spans)));
+        self.line_width = 0;
+    }
+}
+
+/// 解析带 ANSI 转义序列的文本。
+pub fn parse_ansi(input: &str) -> Text<'static> {
+    let mut parser = AnsiParser::new();
+    parser.push(input);
+    parser.finish()
+}
+
+/// 去掉文本中的 ANSI 转义序列。
+pub fn strip_ansi(input: &str) -> String {
+    let mut stripped = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(index) = rest.find('\x1b') {
+        stripped.push_str(&rest[..index]);
+        rest = &rest[index..];
+        let len = escape_len(rest).unwrap_or(rest.len());
+        rest = &rest[len..];
+    }
+    stripped.push_str(rest);
+    stripped
+}
+
+// 以 ESC 开头的转义序列的字节长度，序列不完整时返回 None
+fn escape_len(input: &str) -> Option<usize> {
+    let bytes = input.as_bytes();
+    match bytes.get(1)? {
+        // CSI：参数和中间字节之后以 0x40..=0x7e 结束
+        b'[' => bytes[2..]
+            .iter()
+            .position(|b| (0x40..=0x7e).contains(b))
+            .map(|i| i + 3),
+        // OSC：以 BEL 或 ESC \ 结束
+        b']' => {
+            let body = &input[2..];
+            let bel = body.find('\x07').map(|i| i + 3);
+            let st = body.find("\x1b\\").map(|i| i + 4);
+            match (bel, st) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            }
+        }
+        // 其余两字节序列，如 ESC ( B
+        b'(' | b')' => bytes.get(2).map(|_| 3),
+        _ => Some(2),
+    }
+}
+
+// 按 SGR 参数更新样式，`base` 为重置后的样式
+fn apply_sgr(mut style: Style, base: Style, params: &str) -> Style {
+    // `ESC[m` 等同于 `ESC[0m`
+    if params.is_empty() {
+        return base;
+    }
+    let mut codes = params
+        .split([';', ':'])
+        .map(|code| code.parse::<u16>().unwrap_or(0));
+    while let Some(code) = codes.next() {
+        style = match code {
+            0 => base,
+            1 => style.add_modifier(Modifier::BOLD),
+            2 => style.add_modifier(Modifier::DIM),
+            3 => style.add_modifier(Modifier::ITALIC),
+            4 => style.add_modifier(Modifier::UNDERLINED),
+            5 => style.add_modifier(Modifier::SLOW_BLINK),
+            6 => style.add_modifier(Modifier::RAPID_BLINK),
+            7 => style.add_modifier(Modifier::REVERSED),
+            8 => style.add_modifier(Modifier::HIDDEN),
+            9 => style.add_modifier(Modifier::CROSSED_OUT),
+            22 => style.remove_modifier(Modifier::BOLD | Modifier::DIM),
+            23 => style.remove_modifier(Modifier::ITALIC),
+            24 => style.remove_modifier(Modifier::UNDERLINED),
+            25 => style.remove_modifier(Modifier::SLOW_BLINK | Modifier::RAPID_BLINK),
+            27 => style.remove_modifier(Modifier::REVERSED),
+            28 => style.remove_modifier(Modifier::HIDDEN),
+            29 => style.remove_modifier(Modifier::CROSSED_OUT),
+            30..=37 => style.fg(indexed_color((code - 30) as u8)),
+            90..=97 => style.fg(indexed_color((code - 90 + 8) as u8)),
+            40..=47 => style.bg(indexed_color((code - 40) as u8)),
+            100..=107 => style.bg(indexed_color((code - 100 + 8) as u8)),
+            38 => match extended_color(&mut codes) {
+                Some(color) => style.fg(color),
+                None => style,
+            },
+            48 => match extended_color(&mut codes) {
+                Some(color) => style.bg(color),
+                None => style,
+            },
+            39 => Style {
+                fg: base.fg,
+                ..style
+            },
+            49 => Style {
+                bg: base.bg,
+                ..style
+            },
+            _ => style,
+        };
+    }
+    style
+}
+
+// 解析 `5;n`（256 色）或 `2;r;g;b`（真彩色）
+fn extended_color(codes: &mut impl Iterator<Item = u16>) -> Option<Color> {
+    match codes.next()? {
+        5 => Some(indexed_color(codes.next()? as u8)),
+        2 => {
+            let (r, g, b) = (codes.next()?, codes.next()?, codes.next()?);
+            Some(Color::Rgb(r as u8, g as u8, b as u8))
+        }
+        _ => None,
+    }
+}
+
+// 前 16 色映射为具名颜色，以便按终端主题显示
+fn indexed_color(index: u8) -> Color {
+    match index {
+        0 => Color::Black,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        7 => Color::Gray,
+        8 => Color::DarkGray,
+        9 => Color::LightRed,
+        10 => Color::LightGreen,
+        11 => Color::LightYellow,
+        12 => Color::LightBlue,
+        13 => Color::LightMagenta,
+        14 => Color::LightCyan,
+        15 => Color::White,
+        index => Color::Indexed(index),
+    }
+}
@@ -0,0 +1,102 @@
+use std::str::FromStr;
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::Text,
+};
+
+use super::AnsiParser;
+
+/// 解析轻量的行内标记，文本中的 ANSI 转义序列同样生效。
+///
+/// 标记语法：
+/// - `[b]`/`[bold]`、`[i]`/`[italic]`、`[u]`/`[underline]`、`[dim]`、`[s]`/`[strike]`、
+///   `[reverse]`、`[blink]`：文字修饰。
+/// - `[red]`、`[lightblue]`、`[#ff8800]`：前景色，`[on blue]` 为背景色。
+/// - 一个标签中可以组合多项，如 `[b red on black]`。
+/// - `[/]` 或 `[/名称]` 结束最近的标签，`[[` 输出字面的 `[`。
+///
+/// 无法识别的标签按原样输出。
+///
+/// # 示例
+/// ```rust
+/// let text = parse_markup("[b]bold[/b] and [red]colored[/red]");
+/// ```
+pub fn parse_markup(input: &str) -> Text<'static> {
+    parse_markup_with_style(input, Style::default())
+}
+
+/// 同 [`parse_markup`]，以 `style` 作为基础样式。
+pub fn parse_markup_with_style(input: &str, style: Style) -> Text<'static> {
+    let mut parser = AnsiParser::with_style(style);
+    let mut stack = Vec::new();
+    let mut rest = input;
+    while let Some(index) = rest.find('[') {
+        parser.push(&rest[..index]);
+        rest = &rest[index..];
+        if let Some(after) = rest.strip_prefix("[[") {
+            parser.push("[");
+            rest = after;
+            continue;
+        }
+        let tag = rest[1..]
+            .find(']')
+            .map(|end| &rest[1..end + 1])
+            .filter(|tag| !tag.contains('['));
+        let Some(tag) = tag else {
+            parser.push("[");
+            rest = &rest[1..];
+            continue;
+        };
+        if is_closing_tag(tag) {
+            if let Some(style) = stack.pop() {
+                parser.set_style(style);
+            }
+        } else if let Some(style) = tag_style(tag) {
+            stack.push(parser.style());
+            parser.set_style(parser.style().patch(style));
+        } else {
+            parser.push(&rest[..tag.len() + 2]);
+        }
+        rest = &rest[tag.len() + 2..];
+    }
+    parser.push(rest);
+    parser.finish()
+}
+
+/// 转义文本中的 `[`，使其在标记中按原样显示，适合拼接用户输入或外部数据。
+pub fn escape_markup(text: &str) -> String {
+    text.replace('[', "[[")
+}
+
+// `[/]` 或 `[/名称]`，名称可以包含空格，如 `[/b red]`
+fn is_closing_tag(tag: &str) -> bool {
+    tag.strip_prefix('/').is_some_and(|name| {
+        name.chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == ' ' || c == '#')
+    })
+}
+
+// 标签对应的样式，包含无法识别的项时返回 None
+fn tag_style(tag: &str) -> Option<Style> {
+    let mut style = Style::default();
+    let mut words = tag.split_whitespace();
+    let mut any = false;
+    while let Some(word) = words.next() {
+        any = true;
+        style = match word.to_ascii_lowercase().as_str() {
+            "b" | "bold" => style.add_modifier(Modifier::BOLD),
+            "i" | "italic" => style.add_modifier(Modifier::ITALIC),
+            "u" | "underline" => style.add_modifier(Modifier::UNDERLINED),
+            "d" | "dim" => style.add_modifier(Modifier::DIM),
+            "s" | "strike" => style.add_modifier(Modifier::CROSSED_OUT),
+            "r" | "reverse" => style.add_modifier(Modifier::REVERSED),
+            "blink" => style.add_modifier(Modifier::SLOW_BLINK),
+            "on" => style.bg(Color::from_str(words.next()?).ok()?),
+            // 纯数字不作为颜色，避免 `array[0]` 之类的文本被当作标签
+            color if color.parse::<u8>().is_err() => style.fg(Color::from_str(color).ok()?),
+            _ => return None,
+        };
+    }
+    any.then_some(style)
+}
@@ -0,0 +1,115 @@
+//! 文本工具：按字素和显示宽度（而不是字节数或字符数）计算、截断和对齐文本，
+//! 以及解析 ANSI 转义序列和行内标记。
+//!
+//! 中日韩文字和大多数 emoji 占两列，组合字符不占列，直接用 `len()` 或 `chars().count()`
+//! 计算宽度会导致边框、表格错位。
+//!
+//! ## 用法示例
+//! ```rust
+//! use ratatui_kit::{ratatui::layout::Alignment, text};
+//!
+//! assert_eq!(text::width("你好ab"), 6);
+//! assert_eq!(text::truncate_with_ellipsis("你好世界", 5), "你好…");
+//! assert_eq!(text::pad("ab", 4, Alignment::Right), "  ab");
+//! ```
+
+mod ansi;
+pub use ansi::*;
+mod markup;
+pub use markup::*;
+
+use std::borrow::Cow;
+
+use ratatui::layout::Alignment;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// 默认省略号。
+pub const ELLIPSIS: &str = "…";
+
+/// 文本的显示宽度（列数）。
+pub fn width(text: &str) -> usize {
+    text.width()
+}
+
+/// 按字素切分文本，同时返回每个字素的显示宽度。
+pub fn graphemes(text: &str) -> impl Iterator<Item = (&str, usize)> {
+    text.graphemes(true).map(|g| (g, g.width()))
+}
+
+/// 截断到不超过 `max_width` 列，不会拆开字素或宽字符。
+pub fn truncate(text: &str, max_width: usize) -> Cow<'_, str> {
+    let mut used = 0;
+    for (index, grapheme) in text.grapheme_indices(true) {
+        let grapheme_width = grapheme.width();
+        if used + grapheme_width > max_width {
+            return Cow::Borrowed(&text[..index]);
+        }
+        used += grapheme_width;
+    }
+    Cow::Borrowed(text)
+}
+
+/// 截断到不超过 `max_width` 列，被截断时在末尾加上 [`ELLIPSIS`]。
+pub fn truncate_with_ellipsis(text: &str, max_width: usize) -> Cow<'_, str> {
+    truncate_with(text, max_width, ELLIPSIS)
+}
+
+/// 截断到不超过 `max_width` 列，被截断时在末尾加上指定的省略标记。
+pub fn truncate_with<'a>(text: &'a str, max_width: usize, ellipsis: &str) -> Cow<'a, str> {
+    if width(text) <= max_width {
+        return Cow::Borrowed(text);
+    }
+    let ellipsis_width = width(ellipsis);
+    if ellipsis_width > max_width {
+        return truncate(text, max_width);
+    }
+    let mut truncated = truncate(text, max_width - ellipsis_width).into_owned();
+    truncated.push_str(ellipsis);
+    Cow::Owned(truncated)
+}
+
+/// 从开头截断，保留末尾部分，适合显示文件路径等。
+pub fn truncate_start_with_ellipsis(text: &str, max_width: usize) -> Cow<'_, str> {
+    if width(text) <= max_width {
+        return Cow::Borrowed(text);
+    }
+    let ellipsis_width = width(ELLIPSIS);
+    if ellipsis_width > max_width {
+        return Cow::Borrowed("");
+    }
+    let budget = max_width - ellipsis_width;
+    let mut used = 0;
+    let mut start = text.len();
+    for (index, grapheme) in text.grapheme_indices(true).rev() {
+        let grapheme_width = grapheme.width();
+        if used + grapheme_width > budget {
+            break;
+        }
+        used += grapheme_width;
+        start = index;
+    }
+    Cow::Owned(format!("{ELLIPSIS}{}", &text[start..]))
+}
+
+/// 用空格填充到 `target_width` 列，超出时原样返回。
+pub fn pad(text: &str, target_width: usize, alignment: Alignment) -> Cow<'_, str> {
+    let text_width = width(text);
+    if text_width >= target_width {
+        return Cow::Borrowed(text);
+    }
+    let space = target_width - text_width;
+    let (left, right) = match alignment {
+        Alignment::Left => (0, space),
+        Alignment::Center => (space / 2, space - space / 2),
+        Alignment::Right => (space, 0),
+    };
+    Cow::Owned(format!("{}{text}{}", " ".repeat(left), " ".repeat(right)))
+}
+
+/// 截断（带省略号）并填充到恰好 `target_width` 列，适合表格单元格。
+pub fn fit(text: &str, target_width: usize, alignment: Alignment) -> String {
+    let truncated = truncate_with_ellipsis(text, target_width);
+    // 宽字符被截断时可能比目标宽度少一列，再用空格补齐
+    pad(&truncated, target_width, alignment).into_owned()
+}